@@ -0,0 +1,234 @@
+#!/usr/bin/env python3
+"""Generate a self-consistent Groth16 (BN254) fixture for contract tests.
+
+No circuit or trusted setup is involved: every verification-key and proof
+point is a known scalar multiple of the curve generators, and the proof's C
+is solved so the Groth16 pairing equation
+
+    e(A, B) = e(alpha, beta) * e(L, gamma) * e(C, delta)
+    L = IC[0] + sum(x_i * IC[i])
+
+holds by bilinearity. The output therefore exercises the on-chain verifier
+exactly like a real proof, but it must never be used as a production key.
+
+Encoding matches the Soroban BN254 host functions (EVM precompile layout):
+G1 = x || y, G2 = x.c1 || x.c0 || y.c1 || y.c0, all 32-byte big-endian.
+
+The proofs are bound to fixed contract and player addresses (printed as
+strkeys), so tests must register the contract at that address.
+
+Usage: python3 circuit/scripts/groth16_fixture.py
+"""
+
+import base64
+import hashlib
+
+P = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+R = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+
+G1 = (1, 2)
+G2 = (
+    (
+        10857046999023057135944570762232829481370756359578518086990519993285655852781,
+        11559732032986387107991004021392285783925812861821192530917403151452391805634,
+    ),
+    (
+        8495653923123431417604973247489272438418190587263600148770280649306958101930,
+        4082367875863433681332203403145435568316851327593401208105741076214120093531,
+    ),
+)
+
+
+# Fp2 = Fp[u] / (u^2 + 1), elements are (c0, c1)
+def f2_add(a, b):
+    return ((a[0] + b[0]) % P, (a[1] + b[1]) % P)
+
+
+def f2_sub(a, b):
+    return ((a[0] - b[0]) % P, (a[1] - b[1]) % P)
+
+
+def f2_mul(a, b):
+    return ((a[0] * b[0] - a[1] * b[1]) % P, (a[0] * b[1] + a[1] * b[0]) % P)
+
+
+def f2_inv(a):
+    norm = pow(a[0] * a[0] + a[1] * a[1], P - 2, P)
+    return (a[0] * norm % P, -a[1] * norm % P)
+
+
+class Field:
+    def __init__(self, add, sub, mul, inv, zero, one):
+        self.add, self.sub, self.mul, self.inv = add, sub, mul, inv
+        self.zero, self.one = zero, one
+
+
+FP = Field(
+    lambda a, b: (a + b) % P,
+    lambda a, b: (a - b) % P,
+    lambda a, b: a * b % P,
+    lambda a: pow(a, P - 2, P),
+    0,
+    1,
+)
+FP2 = Field(f2_add, f2_sub, f2_mul, f2_inv, (0, 0), (1, 0))
+
+
+def point_add(f, p1, p2):
+    if p1 is None:
+        return p2
+    if p2 is None:
+        return p1
+    (x1, y1), (x2, y2) = p1, p2
+    if x1 == x2:
+        if f.add(y1, y2) == f.zero:
+            return None
+        three_x2 = f.mul(f.add(f.add(f.one, f.one), f.one), f.mul(x1, x1))
+        lam = f.mul(three_x2, f.inv(f.add(y1, y1)))
+    else:
+        lam = f.mul(f.sub(y2, y1), f.inv(f.sub(x2, x1)))
+    x3 = f.sub(f.sub(f.mul(lam, lam), x1), x2)
+    y3 = f.sub(f.mul(lam, f.sub(x1, x3)), y1)
+    return (x3, y3)
+
+
+def point_mul(f, point, k):
+    result = None
+    while k:
+        if k & 1:
+            result = point_add(f, result, point)
+        point = point_add(f, point, point)
+        k >>= 1
+    return result
+
+
+def on_curve(f, point, b):
+    x, y = point
+    return f.sub(f.mul(y, y), f.add(f.mul(f.mul(x, x), x), b)) == f.zero
+
+
+B1 = 3
+B2 = f2_mul((3, 0), f2_inv((9, 1)))
+
+
+def g1(k):
+    point = point_mul(FP, G1, k % R)
+    assert on_curve(FP, point, B1)
+    return point
+
+
+def g2(k):
+    point = point_mul(FP2, G2, k % R)
+    assert on_curve(FP2, point, B2)
+    return point
+
+
+def enc_fp(v):
+    return v.to_bytes(32, "big").hex()
+
+
+def enc_g1(point):
+    return enc_fp(point[0]) + enc_fp(point[1])
+
+
+def enc_g2(point):
+    (x, y) = point
+    return enc_fp(x[1]) + enc_fp(x[0]) + enc_fp(y[1]) + enc_fp(y[0])
+
+
+def crc16_xmodem(data):
+    crc = 0
+    for byte in data:
+        crc ^= byte << 8
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x1021 if crc & 0x8000 else crc << 1) & 0xFFFF
+    return crc
+
+
+def strkey(version, payload):
+    data = bytes([version]) + payload
+    return base64.b32encode(data + crc16_xmodem(data).to_bytes(2, "little")).decode()
+
+
+# Address XDR as `Address::to_xdr` produces it (an ScVal)
+def contract_xdr(contract_id):
+    return (18).to_bytes(4, "big") + (1).to_bytes(4, "big") + contract_id
+
+
+def account_xdr(public_key):
+    return (18).to_bytes(4, "big") + bytes(8) + public_key
+
+
+def player_binding(contract_id, public_key):
+    digest = hashlib.sha256(contract_xdr(contract_id) + account_xdr(public_key)).digest()
+    return int.from_bytes(bytes(1) + digest[1:], "big")
+
+
+# Fixed addresses the proofs are bound to
+CONTRACT_ID = bytes([0x11] * 32)
+PLAYERS = {2: bytes([0xA1] * 32), 1: bytes([0xB2] * 32)}
+
+# Toxic waste: fixed so the fixture is reproducible
+ALPHA, BETA, GAMMA, DELTA = 11, 13, 17, 19
+# IC[0] + session_id * IC[1] + player_binding * IC[2] + valid_until_ledger * IC[3]
+#   + commitment * IC[4] + nullifier * IC[5] + tactic_hash * IC[6]
+IC = [23, 29, 31, 37, 41, 43, 47]
+
+# Last ledger the proofs are valid for (tests run at sequence 100)
+VALID_UNTIL_LEDGER = 1_000
+
+# Stand-ins for the circuit's Poseidon2 outputs (any field element works here)
+COMMITMENTS = {
+    2: 0x0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF,
+    1: 0x0FEDCBA9876543210FEDCBA9876543210FEDCBA9876543210FEDCBA987654321,
+}
+NULLIFIERS = {
+    2: 0x1111111111111111111111111111111111111111111111111111111111111111,
+    1: 0x2222222222222222222222222222222222222222222222222222222222222222,
+}
+# Player secrets, opened with the tactic by `reveal_tactic`
+SECRETS = {
+    2: 0x00A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2A2,
+    1: 0x00B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1B1,
+}
+
+
+# sha256(tactic (u32 BE) || secret (32 bytes) || session_id (u32 BE)) with the
+# top byte cleared, as the circuit and the contract's `tactic_hash` compute it
+def tactic_hash(tactic, secret, session_id):
+    preimage = tactic.to_bytes(4, "big") + secret.to_bytes(32, "big")
+    digest = hashlib.sha256(preimage + session_id.to_bytes(4, "big")).digest()
+    return int.from_bytes(bytes(1) + digest[1:], "big")
+
+
+def proof(session_id, tactic, r, s):
+    binding = player_binding(CONTRACT_ID, PLAYERS[tactic])
+    commitment, nullifier = COMMITMENTS[tactic], NULLIFIERS[tactic]
+    hashed = tactic_hash(tactic, SECRETS[tactic], session_id)
+    inputs = [session_id, binding, VALID_UNTIL_LEDGER, commitment, nullifier, hashed]
+    l = (IC[0] + sum(x * k for x, k in zip(inputs, IC[1:]))) % R
+    t = (r * s - ALPHA * BETA - l * GAMMA) * pow(DELTA, R - 2, R) % R
+    return enc_g1(g1(r)) + enc_g2(g2(s)) + enc_g1(g1(t))
+
+
+def main():
+    assert point_mul(FP2, G2, R) is None, "G2 generator not in the r-torsion"
+    vk = enc_g1(g1(ALPHA)) + enc_g2(g2(BETA)) + enc_g2(g2(GAMMA)) + enc_g2(g2(DELTA))
+    vk += "".join(enc_g1(g1(k)) for k in IC)
+    print(f"VK ({len(vk) // 2} bytes):\n{vk}\n")
+    print(f"contract: {strkey(2 << 3, CONTRACT_ID)}\n")
+    for session_id, tactic, r, s in [(7, 2, 101, 103), (7, 1, 107, 109)]:
+        print(
+            f"proof session={session_id} tactic={tactic} "
+            f"player={strkey(6 << 3, PLAYERS[tactic])} "
+            f"player_binding={enc_fp(player_binding(CONTRACT_ID, PLAYERS[tactic]))} "
+            f"valid_until_ledger={VALID_UNTIL_LEDGER} "
+            f"commitment={enc_fp(COMMITMENTS[tactic])} nullifier={enc_fp(NULLIFIERS[tactic])} "
+            f"secret={enc_fp(SECRETS[tactic])} "
+            f"tactic_hash={enc_fp(tactic_hash(tactic, SECRETS[tactic], session_id))}:"
+        )
+        print(proof(session_id, tactic, r, s) + "\n")
+
+
+if __name__ == "__main__":
+    main()
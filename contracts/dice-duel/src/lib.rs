@@ -33,6 +33,8 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn allocate_session(env: Env, game_id: Address) -> u32;
 }
 
 // ============================================================================
@@ -120,6 +122,22 @@ impl DiceDuelContract {
             .set(&DataKey::GameHubAddress, &game_hub);
     }
 
+    /// Allocate this game a session ID at the Game Hub, for the players to
+    /// sign into `start_game`. The hub opens sessions only under IDs it
+    /// allocated to the game. Anyone may call it.
+    ///
+    /// # Returns
+    /// * `u32` - The session ID, unique across every game on the hub
+    pub fn allocate_session(env: Env) -> u32 {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.allocate_session(&env.current_contract_address())
+    }
+
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
     ///
@@ -127,7 +145,7 @@ impl DiceDuelContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Session ID from `allocate_session`
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
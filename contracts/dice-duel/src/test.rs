@@ -1,41 +1,14 @@
 #![cfg(test)]
 
-// Unit tests for the dice-duel contract using a simple mock GameHub.
+// Unit tests for the dice-duel contract using the shared mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 
 use crate::{DiceDuelContract, DiceDuelContractClient, Error};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
-
-// ============================================================================
-// Mock GameHub for Unit Testing
-// ============================================================================
-
-#[contract]
-pub struct MockGameHub;
-
-#[contractimpl]
-impl MockGameHub {
-    pub fn start_game(
-        _env: Env,
-        _game_id: Address,
-        _session_id: u32,
-        _player1: Address,
-        _player2: Address,
-        _player1_points: i128,
-        _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
-    }
-
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
-    }
-
-    pub fn add_game(_env: Env, _game_address: Address) {
-        // Mock implementation - does nothing
-    }
-}
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+use game_hub::{GameHub, GameHubClient, GameMetadata, SessionStatus};
+use soroban_sdk::token::StellarAssetClient;
+use stellar_game_testutils::{setup_ledger, MockGameHub, MockGameHubClient};
 
 // ============================================================================
 // Test Helpers
@@ -51,17 +24,7 @@ fn setup_test() -> (
     let env = Env::default();
     env.mock_all_auths();
 
-    // Set ledger info for time-based operations
-    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
-        timestamp: 1441065600,
-        protocol_version: 25,
-        sequence_number: 100,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: u32::MAX / 2,
-        min_persistent_entry_ttl: u32::MAX / 2,
-        max_entry_ttl: u32::MAX / 2,
-    });
+    setup_ledger(&env);
 
     // Deploy mock GameHub contract
     let hub_addr = env.register(MockGameHub, ());
@@ -138,8 +101,8 @@ fn test_complete_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
+    assert!(!game.player1_rolled);
+    assert!(!game.player2_rolled);
     assert!(game.player1_die1.is_none());
     assert!(game.player1_die2.is_none());
     assert!(game.player2_die1.is_none());
@@ -243,3 +206,72 @@ fn test_upgrade_function_exists() {
     // Should fail (WASM doesn't exist) but confirms function signature is correct
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Game Hub Integration Tests
+// ============================================================================
+
+/// Deploy the game against the real Game Hub, registered there, with both
+/// players holding 1000 points at the hub
+fn setup_hub_test() -> (
+    Env,
+    DiceDuelContractClient<'static>,
+    GameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    let admin = Address::generate(&env);
+    let hub = GameHubClient::new(&env, &env.register(GameHub, (&admin,)));
+    let contract_id = env.register(DiceDuelContract, (&admin, &hub.address));
+    let client = DiceDuelContractClient::new(&env, &contract_id);
+    let metadata = GameMetadata {
+        name: String::from_str(&env, "dice-duel"),
+        version: String::from_str(&env, "1.0.0"),
+    };
+    hub.register_game(&contract_id, &metadata);
+
+    let points = env.register_stellar_asset_contract_v2(admin).address();
+    hub.set_points_token(&points);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    for player in [&player1, &player2] {
+        StellarAssetClient::new(&env, &points).mint(player, &1_000);
+        hub.deposit(player, &1_000);
+    }
+
+    (env, client, hub, player1, player2)
+}
+
+#[test]
+fn test_sessions_are_allocated_by_the_game_hub() {
+    let (_env, client, hub, player1, player2) = setup_hub_test();
+
+    // The hub opens no session under an ID it did not allocate the game
+    assert!(client.try_start_game(&1, &player1, &player2, &100, &100).is_err());
+
+    let session_id = client.allocate_session();
+    assert_eq!(session_id, 1);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert_eq!(hub.balance(&player1), 900);
+    assert_eq!(hub.balance(&player2), 900);
+
+    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player2);
+    let winner = client.reveal_winner(&session_id);
+
+    // The hub settles the session and pays the winner the pot
+    let status = if winner == player1 {
+        SessionStatus::Player1Won
+    } else {
+        SessionStatus::Player2Won
+    };
+    assert_eq!(hub.get_session(&session_id).status, status);
+    assert_eq!(hub.balance(&winner), 1_100);
+
+    // Each session gets a fresh ID
+    assert_eq!(client.allocate_session(), 2);
+}
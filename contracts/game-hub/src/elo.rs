@@ -0,0 +1,58 @@
+//! ELO ratings in integer math.
+//!
+//! A player's expected score against an opponent rated `diff` below them is
+//! `1 / (1 + 10^(-diff / 400))`. Soroban contracts have no floating point,
+//! so the curve is tabulated in basis points every 25 rating points and
+//! interpolated between them; beyond 800 points the expected score is
+//! held at the last entry.
+//!
+//! After a game each player's rating moves by
+//! `k_factor * (score - expected)`, where a win scores 1, a draw 0.5 and a
+//! loss 0. The two changes cancel out, so ratings are zero-sum.
+
+/// Rating of a player's first game in a contract
+pub const INITIAL_RATING: u32 = 1200;
+
+/// K-factor until the admin sets one (`set_k_factor`)
+pub const DEFAULT_K_FACTOR: u32 = 32;
+
+/// Largest K-factor `set_k_factor` accepts
+pub const MAX_K_FACTOR: u32 = 100;
+
+const STEP: i64 = 25;
+
+/// Expected score in bps for a rating lead of 0, 25, .., 800 points
+const EXPECTED_BPS: [i64; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823, 8965,
+    9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848, 9868, 9886,
+    9901,
+];
+
+/// Expected score in bps of a player rated `rating` against `opponent`
+pub fn expected_bps(rating: u32, opponent: u32) -> i64 {
+    let lead = rating as i64 - opponent as i64;
+    let max_lead = STEP * (EXPECTED_BPS.len() as i64 - 1);
+    let distance = lead.abs().min(max_lead);
+
+    let index = (distance / STEP) as usize;
+    let offset = distance % STEP;
+    let mut expected = EXPECTED_BPS[index];
+    if offset > 0 {
+        expected += (EXPECTED_BPS[index + 1] - expected) * offset / STEP;
+    }
+
+    if lead < 0 {
+        10_000 - expected
+    } else {
+        expected
+    }
+}
+
+/// New ratings of players rated `rating1` and `rating2` after a game in
+/// which player 1 scored `score1_bps` (10_000 for a win, 5_000 for a
+/// draw, 0 for a loss)
+pub fn rate(k_factor: u32, rating1: u32, rating2: u32, score1_bps: i64) -> (u32, u32) {
+    let change = k_factor as i64 * (score1_bps - expected_bps(rating1, rating2)) / 10_000;
+    let moved = |rating: u32, change: i64| (rating as i64 + change).max(0) as u32;
+    (moved(rating1, change), moved(rating2, -change))
+}
@@ -0,0 +1,86 @@
+//! Bounded on-chain leaderboards.
+//!
+//! A board holds the top `LEADERBOARD_SIZE` players by rating, highest
+//! first, as one storage entry. Every rating change re-places the player:
+//! their old standing is dropped and the new one inserted below anyone on
+//! the same rating, who got there first. A player pushed past the last
+//! place falls off until their rating brings them back.
+
+use soroban_sdk::{contracttype, vec, Address, Env, Vec};
+
+use crate::DataKey;
+
+/// Players kept on each board
+pub const LEADERBOARD_SIZE: u32 = 100;
+
+/// A player's place on a board, as listed by `get_leaderboard`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub rank: u32, // From 1; pass as the next cursor
+    pub player: Address,
+    pub rating: u32,
+}
+
+/// A stored standing; its rank is its position on the board
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Standing {
+    pub player: Address,
+    pub rating: u32,
+}
+
+fn load(env: &Env, key: &DataKey) -> Vec<Standing> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| vec![env])
+}
+
+/// Place `player` on the board at `key` with their new `rating`, keeping
+/// the board for `ttl_ledgers`
+pub fn update(env: &Env, key: &DataKey, player: &Address, rating: u32, ttl_ledgers: u32) {
+    let mut board = load(env, key);
+    if let Some(index) = board.iter().position(|s| s.player == *player) {
+        board.remove(index as u32);
+    }
+
+    let place = board
+        .iter()
+        .position(|s| s.rating < rating)
+        .unwrap_or(board.len() as usize) as u32;
+    if place >= LEADERBOARD_SIZE {
+        // A full board the player is not good enough for
+        return;
+    }
+    let standing = Standing {
+        player: player.clone(),
+        rating,
+    };
+    board.insert(place, standing);
+    if board.len() > LEADERBOARD_SIZE {
+        board.pop_back();
+    }
+
+    env.storage().persistent().set(key, &board);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, ttl_ledgers, ttl_ledgers);
+}
+
+/// Up to `limit` entries of the board at `key`, ranked after `cursor`
+pub fn page(env: &Env, key: &DataKey, cursor: Option<u32>, limit: u32) -> Vec<LeaderboardEntry> {
+    let board = load(env, key);
+    let start = cursor.unwrap_or(0);
+    let end = start.saturating_add(limit).min(board.len());
+    let mut entries = vec![env];
+    for index in start..end {
+        let standing = board.get_unchecked(index);
+        entries.push_back(LeaderboardEntry {
+            rank: index + 1,
+            player: standing.player,
+            rating: standing.rating,
+        });
+    }
+    entries
+}
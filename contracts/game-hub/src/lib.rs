@@ -0,0 +1,1224 @@
+#![no_std]
+
+//! # Game Hub
+//!
+//! The Studio's session registry and points accounting, behind the
+//! `GameHub` interface every Studio game already calls.
+//!
+//! A game contract opens a session with `start_game`, naming the players
+//! and the points each puts at stake, and closes it with `end_game` (or
+//! `end_game_with_outcome` for a draw). Only the game that started a
+//! session can end it. Each session is kept with its result, and every
+//! player has a running record across all games: games played, wins,
+//! losses, draws and net points, where a win takes the loser's points and
+//! a draw moves none.
+//!
+//! Only games the admin has registered (`register_game`) can open
+//! sessions, so points move only through audited game contracts. Session
+//! IDs are shared by every game, so each game draws its IDs from
+//! `allocate_session` and can open sessions only under IDs allocated to
+//! it.
+//!
+//! Points are backed by a token the admin sets once (`set_points_token`).
+//! Players `deposit` it into a balance at the hub; `start_game` debits each
+//! player's stake from their balance, failing with `InsufficientBalance`
+//! back to the game, and the session holds both stakes until it ends. The
+//! winner is credited the whole pot, a draw or cancellation hands each
+//! stake back, and players `withdraw` their balance whenever they like.
+//! Games whose stakes stay hidden until settlement open the session with
+//! the most at stake and lower it with `reduce_stakes` before ending it.
+//!
+//! The hub is also the Studio token's minter. With rewards set
+//! (`set_rewards`), every ended session mints the winner the win reward,
+//! or each player the draw reward. Mints the token's emission caps refuse
+//! are skipped, so settlement never waits on them.
+//!
+//! The hub also answers the optional calls games make: `treasury`, where
+//! games send the hub's share of their fees, and `ratings_root`, the root
+//! of the published player ratings tree.
+//!
+//! Players keep one profile for every game (`set_profile`): a username,
+//! unique across the hub, and the content hash of an avatar.
+//!
+//! Every `end_game` also updates both players' ELO ratings in that game
+//! (`get_rating`), with a K-factor the admin configures, and keeps each
+//! game's top players on a leaderboard (`get_leaderboard`).
+//!
+//! The admin runs the hub in seasons (`start_season`). Each season has its
+//! own player stats and leaderboards, archived when the next season starts
+//! so past rankings stay queryable.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, token, vec,
+    Address, BytesN, Env, String, Symbol, Vec,
+};
+
+mod elo;
+mod leaderboard;
+mod seasons;
+
+pub use elo::{DEFAULT_K_FACTOR, INITIAL_RATING, MAX_K_FACTOR};
+pub use leaderboard::{LeaderboardEntry, LEADERBOARD_SIZE};
+pub use seasons::{Season, ARCHIVE_TTL_LEDGERS};
+
+/// The part of the Studio token the hub calls as its minter
+#[contractclient(name = "RewardTokenClient")]
+pub trait RewardToken {
+    /// Mint `amount` to `to` within the token's emission caps
+    fn mint(env: Env, to: Address, amount: i128);
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 1,
+    SessionExists = 2,
+    SessionNotFound = 3,
+    SessionEnded = 4,
+    InvalidPoints = 5,
+    SelfPlay = 6,
+    TreasuryNotSet = 7,
+    GameNotRegistered = 8,
+    PointsTokenNotSet = 9,
+    PointsTokenAlreadySet = 10,
+    InsufficientBalance = 11,
+    UsernameTaken = 12,
+    ProfileNotFound = 13,
+    InvalidKFactor = 14,
+    InvalidSeason = 15,
+    SeasonNotFound = 16,
+    InvalidRewards = 17,
+    SessionNotAllocated = 18,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// Hub interface version: 1 for `start_game` and `end_game`, 2 adds
+/// `end_game_with_outcome`, 3 adds `reduce_stakes`, 4 has games open
+/// sessions only under IDs from `allocate_session`
+pub const HUB_VERSION: u32 = 4;
+
+/// Three-way result of a game session
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Player1Win = 0,
+    Player2Win = 1,
+    Draw = 2,
+}
+
+/// Where a session stands
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active = 0,
+    Player1Won = 1,
+    Player2Won = 2,
+    Drawn = 3,
+    Cancelled = 4,
+}
+
+/// A game session, from `start_game` until its ID is started again
+/// (`get_session`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub started_ledger: u32,
+    /// Ledger the result came in, or it was cancelled; 0 while active
+    pub ended_ledger: u32,
+    pub status: SessionStatus,
+}
+
+/// A player's record across every game (`get_player`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Points won less points lost
+    pub points: i128,
+}
+
+/// What the admin records about a registered game (`register_game`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameMetadata {
+    pub name: String,
+    /// Audited release of the game contract, e.g. `"1.2.0"`
+    pub version: String,
+}
+
+/// A player's identity across every game (`get_profile`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// Unique across the hub
+    pub username: Symbol,
+    /// Content hash of the avatar image, e.g. its IPFS digest
+    pub avatar_hash: Option<BytesN<32>>,
+    /// Ledger timestamp of the first `set_profile`
+    pub created_at: u64,
+}
+
+/// What the hub mints as sessions end (`set_rewards`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rewards {
+    /// Token the hub is minter of
+    pub token: Address,
+    /// Minted to the winner
+    pub win: i128,
+    /// Minted to each player of a draw
+    pub draw: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Treasury,
+    RatingsRoot,
+    Session(u32),
+    Player(Address),
+    Game(Address),
+    PointsToken,
+    Balance(Address),
+    Profile(Address),
+    Username(Symbol),
+    NextSession,
+    SessionOwner(u32),
+    KFactor,
+    Rating(Address, Address),
+    Leaderboard(Address),
+    CurrentSeason,
+    Season(u32),
+    SeasonGames(u32),
+    SeasonPlayer(u32, Address),
+    SeasonLeaderboard(Address, u32),
+    Rewards,
+}
+
+#[contractevent]
+pub struct GameStarted {
+    #[topic]
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contractevent]
+pub struct GameEnded {
+    #[topic]
+    pub session_id: u32,
+    pub game_id: Address,
+    pub outcome: Outcome,
+}
+
+#[contractevent]
+pub struct SessionCancelled {
+    #[topic]
+    pub session_id: u32,
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct GameRegistered {
+    #[topic]
+    pub game_id: Address,
+    pub name: String,
+    pub version: String,
+}
+
+#[contractevent]
+pub struct GameRemoved {
+    #[topic]
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct Deposited {
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct Withdrawn {
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct ProfileUpdated {
+    #[topic]
+    pub player: Address,
+    pub username: Symbol,
+    pub avatar_hash: Option<BytesN<32>>,
+}
+
+#[contractevent]
+pub struct RatingChanged {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub game_id: Address,
+    pub session_id: u32,
+    pub old_rating: u32,
+    pub new_rating: u32,
+}
+
+#[contractevent]
+pub struct RewardMinted {
+    #[topic]
+    pub player: Address,
+    pub session_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct SeasonStarted {
+    #[topic]
+    pub number: u32,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+#[contractevent]
+pub struct KFactorChanged {
+    pub k_factor: u32,
+}
+
+#[contractevent]
+pub struct PointsTokenSet {
+    pub token: Address,
+}
+
+#[contractevent]
+pub struct RewardsChanged {
+    pub rewards: Option<Rewards>,
+}
+
+#[contractevent]
+pub struct TreasuryChanged {
+    pub treasury: Address,
+}
+
+#[contractevent]
+pub struct RatingsRootChanged {
+    pub root: BytesN<32>,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+/// Sessions and player records back frontends and indexers long after a
+/// game ends, so keep them for ~30 days per touch
+const RECORD_TTL_LEDGERS: u32 = 518_400;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct GameHub;
+
+#[contractimpl]
+impl GameHub {
+    /// Initialize the hub
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (sets the treasury and ratings, can upgrade)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ========================================================================
+    // Game Interface
+    // ========================================================================
+
+    /// Open a game session, debiting each player's stake from their
+    /// balance. Requires the game contract's auth, which a game calling the
+    /// hub itself gives implicitly.
+    ///
+    /// # Arguments
+    /// * `game_id` - A registered game contract (else `GameNotRegistered`)
+    /// * `session_id` - An ID allocated to `game_id` (else
+    ///   `SessionNotAllocated`) and not an active session (else
+    ///   `SessionExists`); an ended session's ID may be started again,
+    ///   replacing its record
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player (else `SelfPlay`)
+    /// * `player1_points` - Points player 1 puts at stake, at least 0
+    /// * `player2_points` - Points player 2 puts at stake (else
+    ///   `InvalidPoints` for either, or `InsufficientBalance` for a stake
+    ///   above the player's balance)
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+
+        if !Self::is_registered(&env, &game_id) {
+            return Err(Error::GameNotRegistered);
+        }
+        Self::check_owner(&env, session_id, &game_id)?;
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+        if player1_points < 0 || player2_points < 0 {
+            return Err(Error::InvalidPoints);
+        }
+        if Self::active_session(&env, session_id).is_ok() {
+            return Err(Error::SessionExists);
+        }
+        Self::debit(&env, &player1, player1_points)?;
+        Self::debit(&env, &player2, player2_points)?;
+
+        let session = Session {
+            game_id: game_id.clone(),
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            started_ledger: env.ledger().sequence(),
+            ended_ledger: 0,
+            status: SessionStatus::Active,
+        };
+        Self::save_session(&env, session_id, &session);
+
+        GameStarted {
+            session_id,
+            game_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Allocate `game_id` a session ID unique across every game, counting
+    /// up from 1. Only that game can open sessions under it. Requires the
+    /// game contract's auth, which a game calling the hub itself gives
+    /// implicitly.
+    ///
+    /// # Arguments
+    /// * `game_id` - A registered game contract (else `GameNotRegistered`)
+    pub fn allocate_session(env: Env, game_id: Address) -> Result<u32, Error> {
+        game_id.require_auth();
+        if !Self::is_registered(&env, &game_id) {
+            return Err(Error::GameNotRegistered);
+        }
+
+        let session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSession)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSession, &(session_id + 1));
+
+        let key = DataKey::SessionOwner(session_id);
+        env.storage().persistent().set(&key, &game_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        Ok(session_id)
+    }
+
+    /// Lower an active session's stakes, handing each player back the
+    /// difference. Requires the auth of the game that started it.
+    ///
+    /// Games whose stakes stay hidden until settlement open the session
+    /// with the most either player could have staked, so it is reserved
+    /// from their balances, and lower it to the stakes revealed before
+    /// ending it.
+    ///
+    /// # Arguments
+    /// * `session_id` - An active session (else `SessionNotFound` or
+    ///   `SessionEnded`)
+    /// * `player1_points` - Player 1's stake, from 0 up to the current one
+    /// * `player2_points` - Player 2's stake (else `InvalidPoints` for
+    ///   either)
+    pub fn reduce_stakes(
+        env: Env,
+        session_id: u32,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        let mut session = Self::active_session(&env, session_id)?;
+        session.game_id.require_auth();
+
+        let sides = [
+            (&session.player1, session.player1_points, player1_points),
+            (&session.player2, session.player2_points, player2_points),
+        ];
+        if sides
+            .iter()
+            .any(|(_, current, points)| *points < 0 || points > current)
+        {
+            return Err(Error::InvalidPoints);
+        }
+        for (player, current, points) in sides {
+            Self::credit(&env, player, current - points);
+        }
+
+        session.player1_points = player1_points;
+        session.player2_points = player2_points;
+        Self::save_session(&env, session_id, &session);
+        Ok(())
+    }
+
+    /// Close a session with a win. Requires the auth of the game that
+    /// started it, which can end its sessions even after being removed.
+    ///
+    /// # Arguments
+    /// * `session_id` - An active session (else `SessionNotFound` or
+    ///   `SessionEnded`)
+    /// * `player1_won` - True if player1 won, false if player2 won
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        let outcome = if player1_won {
+            Outcome::Player1Win
+        } else {
+            Outcome::Player2Win
+        };
+        Self::end_game_with_outcome(env, session_id, outcome)
+    }
+
+    /// Close a session with a win for either player or a draw. Requires
+    /// the auth of the game that started it.
+    ///
+    /// The winner is credited both stakes, so their net points rise by the
+    /// loser's stake and the loser's fall by it; a draw hands each player
+    /// their stake back. Both players' ratings in the game are updated,
+    /// publishing a `RatingChanged` event for each, and any rewards set
+    /// are minted.
+    ///
+    /// # Arguments
+    /// * `session_id` - An active session (else `SessionNotFound` or
+    ///   `SessionEnded`)
+    /// * `outcome` - Which player won, or `Outcome::Draw`
+    pub fn end_game_with_outcome(env: Env, session_id: u32, outcome: Outcome) -> Result<(), Error> {
+        let mut session = Self::active_session(&env, session_id)?;
+        session.game_id.require_auth();
+
+        session.status = match outcome {
+            Outcome::Player1Win => SessionStatus::Player1Won,
+            Outcome::Player2Win => SessionStatus::Player2Won,
+            Outcome::Draw => SessionStatus::Drawn,
+        };
+        session.ended_ledger = env.ledger().sequence();
+        Self::save_session(&env, session_id, &session);
+        Self::record(&env, &session, outcome);
+        Self::pay_out(&env, &session, outcome);
+        Self::update_ratings(&env, session_id, &session, outcome);
+        Self::mint_rewards(&env, session_id, &session, outcome);
+
+        GameEnded {
+            session_id,
+            game_id: session.game_id,
+            outcome,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Hub interface version, checked by games when they are pointed at a
+    /// hub
+    pub fn version(_env: Env) -> u32 {
+        HUB_VERSION
+    }
+
+    /// Where games send the hub's share of their fees; `TreasuryNotSet`
+    /// until the admin sets one, so games keep the whole fee
+    pub fn treasury(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .ok_or(Error::TreasuryNotSet)
+    }
+
+    /// Root of the player ratings tree, if one has been published
+    pub fn ratings_root(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::RatingsRoot)
+    }
+
+    // ========================================================================
+    // Points Ledger
+    // ========================================================================
+
+    /// Move `amount` of the points token from `player` into their balance.
+    /// Requires the player's auth.
+    ///
+    /// # Arguments
+    /// * `player` - Player depositing
+    /// * `amount` - Positive amount (else `InvalidPoints`); fails with
+    ///   `PointsTokenNotSet` until the admin sets the token
+    pub fn deposit(env: Env, player: Address, amount: i128) -> Result<i128, Error> {
+        player.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidPoints);
+        }
+
+        let token = Self::points_token(env.clone())?;
+        token::Client::new(&env, &token).transfer(&player, env.current_contract_address(), &amount);
+        let balance = Self::balance(env.clone(), player.clone()) + amount;
+        Self::save_balance(&env, &player, balance);
+
+        Deposited { player, amount }.publish(&env);
+        Ok(balance)
+    }
+
+    /// Move `amount` of `player`'s balance back to them in the points
+    /// token. Stakes in active sessions are not part of the balance.
+    /// Requires the player's auth.
+    ///
+    /// # Arguments
+    /// * `player` - Player withdrawing
+    /// * `amount` - Positive amount (else `InvalidPoints`) up to their
+    ///   balance (else `InsufficientBalance`)
+    pub fn withdraw(env: Env, player: Address, amount: i128) -> Result<i128, Error> {
+        player.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidPoints);
+        }
+
+        let token = Self::points_token(env.clone())?;
+        Self::debit(&env, &player, amount)?;
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &player,
+            &amount,
+        );
+
+        Withdrawn {
+            player: player.clone(),
+            amount,
+        }
+        .publish(&env);
+        Ok(Self::balance(env, player))
+    }
+
+    /// A player's balance: deposits and winnings not staked or withdrawn
+    pub fn balance(env: Env, player: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(player))
+            .unwrap_or(0)
+    }
+
+    /// The token points are deposited and withdrawn in
+    pub fn points_token(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PointsToken)
+            .ok_or(Error::PointsTokenNotSet)
+    }
+
+    // ========================================================================
+    // Profiles
+    // ========================================================================
+
+    /// Create or update `player`'s profile. Requires the player's auth.
+    ///
+    /// Changing username frees the old one for others; the creation date
+    /// is kept from the first call.
+    ///
+    /// # Arguments
+    /// * `player` - Player whose profile this is
+    /// * `username` - Not held by another player (else `UsernameTaken`)
+    /// * `avatar_hash` - Content hash of the avatar, or `None` for none
+    pub fn set_profile(
+        env: Env,
+        player: Address,
+        username: Symbol,
+        avatar_hash: Option<BytesN<32>>,
+    ) -> Result<Profile, Error> {
+        player.require_auth();
+
+        let name_key = DataKey::Username(username.clone());
+        let holder: Option<Address> = env.storage().persistent().get(&name_key);
+        if holder.is_some_and(|holder| holder != player) {
+            return Err(Error::UsernameTaken);
+        }
+
+        let created_at = match Self::get_profile(env.clone(), player.clone()) {
+            Ok(old) => {
+                if old.username != username {
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::Username(old.username));
+                }
+                old.created_at
+            }
+            Err(_) => env.ledger().timestamp(),
+        };
+        let profile = Profile {
+            username: username.clone(),
+            avatar_hash: avatar_hash.clone(),
+            created_at,
+        };
+
+        let profile_key = DataKey::Profile(player.clone());
+        env.storage().persistent().set(&name_key, &player);
+        env.storage().persistent().set(&profile_key, &profile);
+        for key in [name_key, profile_key] {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        }
+
+        ProfileUpdated {
+            player,
+            username,
+            avatar_hash,
+        }
+        .publish(&env);
+        Ok(profile)
+    }
+
+    /// Get a player's profile
+    pub fn get_profile(env: Env, player: Address) -> Result<Profile, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Profile(player))
+            .ok_or(Error::ProfileNotFound)
+    }
+
+    /// Look up the player holding `username`
+    pub fn find_player(env: Env, username: Symbol) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Username(username))
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Get a session, active or ended
+    pub fn get_session(env: Env, session_id: u32) -> Result<Session, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    /// Get a registered game's metadata
+    pub fn get_game(env: Env, game_id: Address) -> Result<GameMetadata, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Game(game_id))
+            .ok_or(Error::GameNotRegistered)
+    }
+
+    /// Get a player's ELO rating in `game_id`; `INITIAL_RATING` before
+    /// their first game in it
+    pub fn get_rating(env: Env, player: Address, game_id: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rating(player, game_id))
+            .unwrap_or(INITIAL_RATING)
+    }
+
+    /// Page through `game_id`'s leaderboard, its top `LEADERBOARD_SIZE`
+    /// players by rating, highest first
+    ///
+    /// # Arguments
+    /// * `game_id` - Game whose ratings to rank
+    /// * `cursor` - `None` for the top, else the `rank` of the last entry
+    ///   of the previous page
+    /// * `limit` - Entries per page; `LEADERBOARD_SIZE` lists the whole
+    ///   board
+    pub fn get_leaderboard(
+        env: Env,
+        game_id: Address,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        leaderboard::page(&env, &DataKey::Leaderboard(game_id), cursor, limit)
+    }
+
+    /// The season running in this ledger, if any
+    pub fn current_season(env: Env) -> Option<Season> {
+        seasons::current(&env)
+    }
+
+    /// Get season `number`, running or closed
+    pub fn get_season(env: Env, number: u32) -> Result<Season, Error> {
+        seasons::get(&env, number).ok_or(Error::SeasonNotFound)
+    }
+
+    /// Get a player's record across every game in season `number`
+    pub fn get_season_player(env: Env, number: u32, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SeasonPlayer(number, player))
+            .unwrap_or_default()
+    }
+
+    /// Page through `game_id`'s leaderboard for season `number`: the
+    /// season's top `LEADERBOARD_SIZE` players by rating, highest first
+    ///
+    /// # Arguments
+    /// * `game_id` - Game whose ratings to rank
+    /// * `number` - Season, running or closed
+    /// * `cursor` - `None` for the top, else the `rank` of the last entry
+    ///   of the previous page
+    /// * `limit` - Entries per page
+    pub fn get_season_leaderboard(
+        env: Env,
+        game_id: Address,
+        number: u32,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        let key = DataKey::SeasonLeaderboard(game_id, number);
+        leaderboard::page(&env, &key, cursor, limit)
+    }
+
+    /// K-factor of rating updates: the most a rating moves in one game
+    pub fn k_factor(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KFactor)
+            .unwrap_or(DEFAULT_K_FACTOR)
+    }
+
+    /// What the hub mints as sessions end, if anything
+    pub fn get_rewards(env: Env) -> Option<Rewards> {
+        env.storage().instance().get(&DataKey::Rewards)
+    }
+
+    /// Get a player's record across every game
+    pub fn get_player(env: Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Player(player))
+            .unwrap_or_default()
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Allow `game_id` to open sessions, or update a registered game's
+    /// metadata. Requires the admin's auth.
+    pub fn register_game(env: Env, game_id: Address, metadata: GameMetadata) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+
+        let key = DataKey::Game(game_id.clone());
+        env.storage().persistent().set(&key, &metadata);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+
+        GameRegistered {
+            game_id,
+            name: metadata.name,
+            version: metadata.version,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Stop `game_id` opening sessions. Its active sessions can still be
+    /// ended, so players' stakes are not stranded. Requires the admin's
+    /// auth.
+    pub fn remove_game(env: Env, game_id: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+
+        if !Self::is_registered(&env, &game_id) {
+            return Err(Error::GameNotRegistered);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Game(game_id.clone()));
+
+        GameRemoved { game_id }.publish(&env);
+        Ok(())
+    }
+
+    /// Close an active session its game will never end, say after the
+    /// game's own entry expired. Each player gets their stake back.
+    /// Requires the admin's auth.
+    pub fn cancel_session(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+
+        let mut session = Self::active_session(&env, session_id)?;
+        session.status = SessionStatus::Cancelled;
+        session.ended_ledger = env.ledger().sequence();
+        Self::save_session(&env, session_id, &session);
+        Self::pay_out(&env, &session, Outcome::Draw);
+
+        SessionCancelled {
+            session_id,
+            game_id: session.game_id,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Set the token points are deposited and withdrawn in. It can be set
+    /// only once (else `PointsTokenAlreadySet`), since balances are held in
+    /// it. Requires the admin's auth.
+    pub fn set_points_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        if env.storage().instance().has(&DataKey::PointsToken) {
+            return Err(Error::PointsTokenAlreadySet);
+        }
+        env.storage().instance().set(&DataKey::PointsToken, &token);
+        PointsTokenSet { token }.publish(&env);
+        Ok(())
+    }
+
+    /// Roll the hub into a new season, closing the current one at this
+    /// ledger and archiving its leaderboards. Requires the admin's auth.
+    ///
+    /// # Arguments
+    /// * `end_ledger` - First ledger after the new season, after this one
+    ///   (else `InvalidSeason`); games ended from then on count in no
+    ///   season until the next rollover
+    pub fn start_season(env: Env, end_ledger: u32) -> Result<Season, Error> {
+        Self::get_admin(env.clone())?.require_auth();
+
+        let now = env.ledger().sequence();
+        if end_ledger <= now {
+            return Err(Error::InvalidSeason);
+        }
+
+        let mut number = 1;
+        if let Some(mut previous) = seasons::latest(&env) {
+            if previous.end_ledger > now {
+                previous.end_ledger = now;
+                seasons::save(&env, &previous);
+            }
+            seasons::archive(&env, previous.number);
+            number = previous.number + 1;
+        }
+
+        let season = Season {
+            number,
+            start_ledger: now,
+            end_ledger,
+        };
+        seasons::save(&env, &season);
+
+        SeasonStarted {
+            number,
+            start_ledger: now,
+            end_ledger,
+        }
+        .publish(&env);
+        Ok(season)
+    }
+
+    /// Set the K-factor of rating updates, 1 to `MAX_K_FACTOR` (else
+    /// `InvalidKFactor`). Requires the admin's auth.
+    pub fn set_k_factor(env: Env, k_factor: u32) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        if k_factor == 0 || k_factor > MAX_K_FACTOR {
+            return Err(Error::InvalidKFactor);
+        }
+        env.storage().instance().set(&DataKey::KFactor, &k_factor);
+        KFactorChanged { k_factor }.publish(&env);
+        Ok(())
+    }
+
+    /// Set what the hub mints as sessions end (None stops minting).
+    /// Requires the admin's auth.
+    ///
+    /// The hub must be the token's minter. Amounts are at least 0 (else
+    /// `InvalidRewards`), and a 0 reward is not minted.
+    pub fn set_rewards(env: Env, rewards: Option<Rewards>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        match &rewards {
+            Some(new) => {
+                if new.win < 0 || new.draw < 0 {
+                    return Err(Error::InvalidRewards);
+                }
+                env.storage().instance().set(&DataKey::Rewards, new);
+            }
+            None => env.storage().instance().remove(&DataKey::Rewards),
+        }
+        RewardsChanged { rewards }.publish(&env);
+        Ok(())
+    }
+
+    /// Set where games send the hub's share of their fees. Requires the
+    /// admin's auth.
+    pub fn set_treasury(env: Env, treasury: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        TreasuryChanged { treasury }.publish(&env);
+        Ok(())
+    }
+
+    /// Publish the root of the player ratings tree, a Merkle tree of
+    /// `(rating_key, rating)` leaves. Requires the admin's auth.
+    pub fn set_ratings_root(env: Env, root: BytesN<32>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::RatingsRoot, &root);
+        RatingsRootChanged { root }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Internal Helpers
+// ============================================================================
+
+impl GameHub {
+    /// `session_id`'s session, if it is still active
+    fn active_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+        let session = Self::get_session(env.clone(), session_id)?;
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionEnded);
+        }
+        Ok(session)
+    }
+
+    /// Check `session_id` was allocated to `game_id`, keeping the
+    /// allocation as long as the session record
+    fn check_owner(env: &Env, session_id: u32, game_id: &Address) -> Result<(), Error> {
+        let key = DataKey::SessionOwner(session_id);
+        let owner: Option<Address> = env.storage().persistent().get(&key);
+        if owner.as_ref() != Some(game_id) {
+            return Err(Error::SessionNotAllocated);
+        }
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        Ok(())
+    }
+
+    fn is_registered(env: &Env, game_id: &Address) -> bool {
+        let key = DataKey::Game(game_id.clone());
+        let registered = env.storage().persistent().has(&key);
+        if registered {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+        }
+        registered
+    }
+
+    fn save_session(env: &Env, session_id: u32, session: &Session) {
+        let key = DataKey::Session(session_id);
+        env.storage().persistent().set(&key, session);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+    }
+
+    /// Take `amount` from `player`'s balance
+    fn debit(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+        let balance = Self::balance(env.clone(), player.clone());
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+        Self::save_balance(env, player, balance - amount);
+        Ok(())
+    }
+
+    fn credit(env: &Env, player: &Address, amount: i128) {
+        let balance = Self::balance(env.clone(), player.clone());
+        Self::save_balance(env, player, balance + amount);
+    }
+
+    /// Store `player`'s balance, removing it once it is empty
+    fn save_balance(env: &Env, player: &Address, balance: i128) {
+        let key = DataKey::Balance(player.clone());
+        if balance == 0 {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        env.storage().persistent().set(&key, &balance);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+    }
+
+    /// Release an ended session's stakes: the pot to the winner, or each
+    /// stake back to its player for a draw
+    fn pay_out(env: &Env, session: &Session, outcome: Outcome) {
+        let pot = session.player1_points + session.player2_points;
+        match outcome {
+            Outcome::Player1Win => Self::credit(env, &session.player1, pot),
+            Outcome::Player2Win => Self::credit(env, &session.player2, pot),
+            Outcome::Draw => {
+                Self::credit(env, &session.player1, session.player1_points);
+                Self::credit(env, &session.player2, session.player2_points);
+            }
+        }
+    }
+
+    /// Mint an ended session's rewards: the win reward to the winner, or
+    /// the draw reward to each player. A mint the token refuses, say past
+    /// its emission caps, is skipped.
+    fn mint_rewards(env: &Env, session_id: u32, session: &Session, outcome: Outcome) {
+        let Some(rewards) = Self::get_rewards(env.clone()) else {
+            return;
+        };
+        let (players, amount) = match outcome {
+            Outcome::Player1Win => (vec![env, session.player1.clone()], rewards.win),
+            Outcome::Player2Win => (vec![env, session.player2.clone()], rewards.win),
+            Outcome::Draw => (
+                vec![env, session.player1.clone(), session.player2.clone()],
+                rewards.draw,
+            ),
+        };
+        if amount == 0 {
+            return;
+        }
+
+        let token = RewardTokenClient::new(env, &rewards.token);
+        for player in players.iter() {
+            if token.try_mint(&player, &amount).is_ok() {
+                RewardMinted {
+                    player,
+                    session_id,
+                    amount,
+                }
+                .publish(env);
+            }
+        }
+    }
+
+    /// Move both players' ratings in the session's game by its outcome,
+    /// re-placing them on the game's boards
+    fn update_ratings(env: &Env, session_id: u32, session: &Session, outcome: Outcome) {
+        let game_id = &session.game_id;
+        let season = seasons::current(env);
+        if let Some(season) = &season {
+            seasons::track_game(env, season.number, game_id);
+        }
+        let players = [&session.player1, &session.player2];
+        let old =
+            players.map(|player| Self::get_rating(env.clone(), player.clone(), game_id.clone()));
+        let score1_bps = match outcome {
+            Outcome::Player1Win => 10_000,
+            Outcome::Player2Win => 0,
+            Outcome::Draw => 5_000,
+        };
+        let (new1, new2) = elo::rate(Self::k_factor(env.clone()), old[0], old[1], score1_bps);
+
+        for (player, (old_rating, new_rating)) in
+            players.into_iter().zip(old.into_iter().zip([new1, new2]))
+        {
+            let key = DataKey::Rating(player.clone(), game_id.clone());
+            env.storage().persistent().set(&key, &new_rating);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, RECORD_TTL_LEDGERS, RECORD_TTL_LEDGERS);
+            let board_key = DataKey::Leaderboard(game_id.clone());
+            leaderboard::update(env, &board_key, player, new_rating, RECORD_TTL_LEDGERS);
+            if let Some(season) = &season {
+                let board_key = DataKey::SeasonLeaderboard(game_id.clone(), season.number);
+                leaderboard::update(env, &board_key, player, new_rating, ARCHIVE_TTL_LEDGERS);
+            }
+
+            RatingChanged {
+                player: player.clone(),
+                game_id: game_id.clone(),
+                session_id,
+                old_rating,
+                new_rating,
+            }
+            .publish(env);
+        }
+    }
+
+    /// Count an ended session in both players' records, overall and for
+    /// the running season
+    fn record(env: &Env, session: &Session, outcome: Outcome) {
+        let season = seasons::current(env);
+        let sides = [
+            (
+                &session.player1,
+                session.player1_points,
+                session.player2_points,
+                Outcome::Player1Win,
+            ),
+            (
+                &session.player2,
+                session.player2_points,
+                session.player1_points,
+                Outcome::Player2Win,
+            ),
+        ];
+        for (player, stake, opponent_stake, win) in sides {
+            let count = |key: DataKey, ttl_ledgers: u32| {
+                let mut stats: PlayerStats =
+                    env.storage().persistent().get(&key).unwrap_or_default();
+                stats.games_played += 1;
+                if outcome == Outcome::Draw {
+                    stats.draws += 1;
+                } else if outcome == win {
+                    stats.wins += 1;
+                    stats.points += opponent_stake;
+                } else {
+                    stats.losses += 1;
+                    stats.points -= stake;
+                }
+
+                env.storage().persistent().set(&key, &stats);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+            };
+
+            count(DataKey::Player(player.clone()), RECORD_TTL_LEDGERS);
+            if let Some(season) = &season {
+                let key = DataKey::SeasonPlayer(season.number, player.clone());
+                count(key, ARCHIVE_TTL_LEDGERS);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,100 @@
+//! Seasons.
+//!
+//! The admin rolls the hub into a new season with `start_season`, which
+//! closes the current one. While a season runs, every ended session is
+//! also counted in the players' stats for the season, and every rating
+//! change places the player on the season's leaderboard for that game.
+//! Season boards start empty, so they rank the players of that season by
+//! their rating in the game.
+//!
+//! Season entries are kept for `ARCHIVE_TTL_LEDGERS` from their last
+//! write, and closing a season extends its record and every one of its
+//! boards by as much again, so past rankings stay queryable.
+
+use soroban_sdk::{contracttype, vec, Address, Env, Vec};
+
+use crate::DataKey;
+
+/// Season records, stats and boards outlive the season by ~180 days
+pub const ARCHIVE_TTL_LEDGERS: u32 = 3_110_400;
+
+/// A season of play (`get_season`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub number: u32,       // From 1
+    pub start_ledger: u32, // First ledger of the season
+    pub end_ledger: u32,   // First ledger after it; moved earlier by a rollover
+}
+
+impl Season {
+    pub fn is_running(&self, ledger: u32) -> bool {
+        self.start_ledger <= ledger && ledger < self.end_ledger
+    }
+}
+
+/// Season `number`, if it has been started
+pub fn get(env: &Env, number: u32) -> Option<Season> {
+    env.storage().persistent().get(&DataKey::Season(number))
+}
+
+/// The latest season, running or not
+pub fn latest(env: &Env) -> Option<Season> {
+    let number: u32 = env.storage().instance().get(&DataKey::CurrentSeason)?;
+    get(env, number)
+}
+
+/// The season running in this ledger, if any
+pub fn current(env: &Env) -> Option<Season> {
+    latest(env).filter(|season| season.is_running(env.ledger().sequence()))
+}
+
+pub fn save(env: &Env, season: &Season) {
+    let key = DataKey::Season(season.number);
+    env.storage().persistent().set(&key, season);
+    extend(env, &key);
+    env.storage()
+        .instance()
+        .set(&DataKey::CurrentSeason, &season.number);
+}
+
+/// Note that `game_id` has a board in season `number`
+pub fn track_game(env: &Env, number: u32, game_id: &Address) {
+    let key = DataKey::SeasonGames(number);
+    let mut games: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| vec![env]);
+    if !games.contains(game_id) {
+        games.push_back(game_id.clone());
+        env.storage().persistent().set(&key, &games);
+    }
+    extend(env, &key);
+}
+
+/// Keep a closed season's record and boards for `ARCHIVE_TTL_LEDGERS`
+pub fn archive(env: &Env, number: u32) {
+    let games_key = DataKey::SeasonGames(number);
+    let games: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&games_key)
+        .unwrap_or_else(|| vec![env]);
+    for game_id in games.iter() {
+        let board_key = DataKey::SeasonLeaderboard(game_id, number);
+        if env.storage().persistent().has(&board_key) {
+            extend(env, &board_key);
+        }
+    }
+    if !games.is_empty() {
+        extend(env, &games_key);
+    }
+    extend(env, &DataKey::Season(number));
+}
+
+pub fn extend(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, ARCHIVE_TTL_LEDGERS, ARCHIVE_TTL_LEDGERS);
+}
@@ -0,0 +1,818 @@
+#![cfg(test)]
+
+use crate::{
+    DataKey, Error, GameHub, GameHubClient, GameMetadata, LeaderboardEntry, Outcome, PlayerStats,
+    Profile, Rewards, Season, SessionStatus, ARCHIVE_TTL_LEDGERS, HUB_VERSION, INITIAL_RATING,
+    LEADERBOARD_SIZE,
+};
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, vec, Address, BytesN, Env, String};
+use stellar_game_testutils::{
+    balance, fund, mock_commitment, mock_nullifier, mock_player_proof, mock_proof, mock_secret,
+    wager_token, MockVerifier,
+};
+use studio_token::{EmissionSchedule, StudioToken, StudioTokenClient};
+use zk_tactical_match::{
+    player_binding, stake_hash, tactic_hash, Error as GameError, PublicInputs, StakeCommitment,
+    ZkTacticalMatchContract, ZkTacticalMatchContractClient,
+};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+/// Session IDs allocated to the test game, from 1
+const TEST_SESSIONS: u32 = 200;
+
+struct Setup {
+    env: Env,
+    hub: GameHubClient<'static>,
+    token: TokenClient<'static>,
+    game: Address,
+    player1: Address,
+    player2: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let admin = Address::generate(&env);
+    let hub_id = env.register(GameHub, (&admin,));
+    let hub = GameHubClient::new(&env, &hub_id);
+    let game = Address::generate(&env);
+    hub.register_game(&game, &metadata(&env, "Test Game"));
+    for _ in 0..TEST_SESSIONS {
+        hub.allocate_session(&game);
+    }
+
+    // Both players start with 1000 points at the hub
+    let asset = env.register_stellar_asset_contract_v2(admin);
+    let token = TokenClient::new(&env, &asset.address());
+    hub.set_points_token(&asset.address());
+    let (player1, player2) = (Address::generate(&env), Address::generate(&env));
+    for player in [&player1, &player2] {
+        StellarAssetClient::new(&env, &asset.address()).mint(player, &1_000);
+        hub.deposit(player, &1_000);
+    }
+
+    Setup {
+        env,
+        hub,
+        token,
+        game,
+        player1,
+        player2,
+    }
+}
+
+fn metadata(env: &Env, name: &str) -> GameMetadata {
+    GameMetadata {
+        name: String::from_str(env, name),
+        version: String::from_str(env, "1.0.0"),
+    }
+}
+
+fn stats(games_played: u32, wins: u32, losses: u32, draws: u32, points: i128) -> PlayerStats {
+    PlayerStats {
+        games_played,
+        wins,
+        losses,
+        draws,
+        points,
+    }
+}
+
+// ============================================================================
+// Session Tests
+// ============================================================================
+
+#[test]
+fn test_win_moves_the_losers_points() {
+    let s = setup_test();
+    s.hub
+        .start_game(&s.game, &1, &s.player1, &s.player2, &300, &100);
+    let session = s.hub.get_session(&1);
+    assert_eq!(session.status, SessionStatus::Active);
+    assert_eq!(session.started_ledger, 100);
+
+    s.hub.end_game(&1, &false);
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, s.game);
+
+    assert_eq!(s.hub.get_session(&1).status, SessionStatus::Player2Won);
+    assert_eq!(s.hub.get_player(&s.player1), stats(1, 0, 1, 0, -300));
+    assert_eq!(s.hub.get_player(&s.player2), stats(1, 1, 0, 0, 300));
+    assert_eq!(s.hub.balance(&s.player1), 700);
+    assert_eq!(s.hub.balance(&s.player2), 1_300);
+}
+
+#[test]
+fn test_draw_moves_no_points() {
+    let s = setup_test();
+    s.hub
+        .start_game(&s.game, &2, &s.player1, &s.player2, &100, &100);
+    s.hub.end_game_with_outcome(&2, &Outcome::Draw);
+    assert_eq!(s.hub.get_session(&2).status, SessionStatus::Drawn);
+    assert_eq!(s.hub.get_player(&s.player1), stats(1, 0, 0, 1, 0));
+    assert_eq!(s.hub.version(), HUB_VERSION);
+}
+
+#[test]
+fn test_sessions_end_once() {
+    let s = setup_test();
+    let result = s.hub.try_end_game(&3, &true);
+    assert_eq!(result, Err(Ok(Error::SessionNotFound)));
+
+    s.hub
+        .start_game(&s.game, &3, &s.player1, &s.player2, &100, &100);
+    let result = s
+        .hub
+        .try_start_game(&s.game, &3, &s.player1, &s.player2, &100, &100);
+    assert_eq!(result, Err(Ok(Error::SessionExists)));
+
+    s.hub.end_game(&3, &true);
+    let result = s.hub.try_end_game(&3, &true);
+    assert_eq!(result, Err(Ok(Error::SessionEnded)));
+
+    // An ended session's ID can be started again
+    s.hub
+        .start_game(&s.game, &3, &s.player1, &s.player2, &50, &50);
+    assert_eq!(s.hub.get_session(&3).player1_points, 50);
+}
+
+#[test]
+fn test_games_open_only_sessions_allocated_to_them() {
+    let s = setup_test();
+    let other = Address::generate(&s.env);
+    s.hub.register_game(&other, &metadata(&s.env, "Other Game"));
+
+    // IDs count on from the test game's
+    let session_id = s.hub.allocate_session(&other);
+    assert_eq!(s.env.auths()[0].0, other);
+    assert_eq!(session_id, TEST_SESSIONS + 1);
+
+    // Neither game can open the other's sessions, or an unallocated one
+    for (game, session_id) in [(&s.game, session_id), (&other, 1), (&other, session_id + 1)] {
+        let result = s
+            .hub
+            .try_start_game(game, &session_id, &s.player1, &s.player2, &10, &10);
+        assert_eq!(result, Err(Ok(Error::SessionNotAllocated)));
+    }
+    s.hub
+        .start_game(&other, &session_id, &s.player1, &s.player2, &10, &10);
+    assert_eq!(s.hub.get_session(&session_id).game_id, other);
+
+    let unaudited = Address::generate(&s.env);
+    let result = s.hub.try_allocate_session(&unaudited);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+}
+
+#[test]
+fn test_start_game_validates_inputs() {
+    let s = setup_test();
+    let result = s
+        .hub
+        .try_start_game(&s.game, &4, &s.player1, &s.player1, &100, &100);
+    assert_eq!(result, Err(Ok(Error::SelfPlay)));
+
+    let result = s
+        .hub
+        .try_start_game(&s.game, &4, &s.player1, &s.player2, &-1, &100);
+    assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+}
+
+#[test]
+fn test_admin_cancels_a_stuck_session() {
+    let s = setup_test();
+    s.hub
+        .start_game(&s.game, &5, &s.player1, &s.player2, &100, &100);
+    s.hub.cancel_session(&5);
+    assert_eq!(s.hub.get_session(&5).status, SessionStatus::Cancelled);
+    assert_eq!(s.hub.get_player(&s.player1), PlayerStats::default());
+    assert_eq!(s.hub.balance(&s.player1), 1_000);
+
+    let result = s.hub.try_end_game(&5, &true);
+    assert_eq!(result, Err(Ok(Error::SessionEnded)));
+}
+
+#[test]
+fn test_treasury_and_ratings_root() {
+    let s = setup_test();
+    assert_eq!(s.hub.try_treasury(), Err(Ok(Error::TreasuryNotSet)));
+    assert_eq!(s.hub.ratings_root(), None);
+
+    let treasury = Address::generate(&s.env);
+    let root = BytesN::from_array(&s.env, &[7; 32]);
+    s.hub.set_treasury(&treasury);
+    s.hub.set_ratings_root(&root);
+    assert_eq!(s.hub.treasury(), treasury);
+    assert_eq!(s.hub.ratings_root(), Some(root));
+}
+
+#[test]
+fn test_only_registered_games_open_sessions() {
+    let s = setup_test();
+    let unaudited = Address::generate(&s.env);
+    let result = s
+        .hub
+        .try_start_game(&unaudited, &6, &s.player1, &s.player2, &100, &100);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+    assert_eq!(
+        s.hub.try_get_game(&unaudited),
+        Err(Ok(Error::GameNotRegistered))
+    );
+    assert_eq!(s.hub.get_game(&s.game), metadata(&s.env, "Test Game"));
+
+    // A removed game opens nothing new but can end what it opened
+    s.hub
+        .start_game(&s.game, &6, &s.player1, &s.player2, &100, &100);
+    s.hub.remove_game(&s.game);
+    let result = s
+        .hub
+        .try_start_game(&s.game, &7, &s.player1, &s.player2, &100, &100);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+    s.hub.end_game(&6, &true);
+    assert_eq!(s.hub.get_player(&s.player1), stats(1, 1, 0, 0, 100));
+
+    let result = s.hub.try_remove_game(&s.game);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+}
+
+// ============================================================================
+// Points Ledger Tests
+// ============================================================================
+
+#[test]
+fn test_stakes_are_held_until_the_session_ends() {
+    let s = setup_test();
+    s.hub
+        .start_game(&s.game, &8, &s.player1, &s.player2, &600, &200);
+    assert_eq!(s.hub.balance(&s.player1), 400);
+    assert_eq!(s.hub.balance(&s.player2), 800);
+
+    // Staked points cannot be staked again or withdrawn
+    let result = s
+        .hub
+        .try_start_game(&s.game, &9, &s.player1, &s.player2, &401, &100);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    let result = s.hub.try_withdraw(&s.player1, &401);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+
+    s.hub.end_game_with_outcome(&8, &Outcome::Draw);
+    assert_eq!(s.hub.balance(&s.player1), 1_000);
+    assert_eq!(s.hub.balance(&s.player2), 1_000);
+}
+
+#[test]
+fn test_games_lower_stakes_before_settling() {
+    let s = setup_test();
+    s.hub
+        .start_game(&s.game, &10, &s.player1, &s.player2, &600, &200);
+
+    // The difference goes back to each player
+    s.hub.reduce_stakes(&10, &250, &100);
+    assert_eq!(s.env.auths()[0].0, s.game);
+    assert_eq!(s.hub.balance(&s.player1), 750);
+    assert_eq!(s.hub.balance(&s.player2), 900);
+
+    for (player1_points, player2_points) in [(251, 100), (250, -1)] {
+        let result = s
+            .hub
+            .try_reduce_stakes(&10, &player1_points, &player2_points);
+        assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+    }
+
+    s.hub.end_game(&10, &false);
+    assert_eq!(s.hub.get_session(&10).player1_points, 250);
+    assert_eq!(s.hub.get_player(&s.player2), stats(1, 1, 0, 0, 250));
+    assert_eq!(s.hub.balance(&s.player2), 1_250);
+    let result = s.hub.try_reduce_stakes(&10, &0, &0);
+    assert_eq!(result, Err(Ok(Error::SessionEnded)));
+}
+
+#[test]
+fn test_players_withdraw_their_winnings() {
+    let s = setup_test();
+    s.hub
+        .start_game(&s.game, &9, &s.player1, &s.player2, &100, &250);
+    s.hub.end_game(&9, &true);
+
+    assert_eq!(s.hub.withdraw(&s.player1, &1_250), 0);
+    assert_eq!(s.token.balance(&s.player1), 1_250);
+    assert_eq!(s.token.balance(&s.hub.address), 750);
+
+    let result = s.hub.try_deposit(&s.player1, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+    let result = s.hub.try_set_points_token(&s.token.address);
+    assert_eq!(result, Err(Ok(Error::PointsTokenAlreadySet)));
+}
+
+#[test]
+fn test_points_need_a_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let hub = GameHubClient::new(&env, &env.register(GameHub, (&admin,)));
+
+    let result = hub.try_deposit(&admin, &100);
+    assert_eq!(result, Err(Ok(Error::PointsTokenNotSet)));
+}
+
+// ============================================================================
+// Profile Tests
+// ============================================================================
+
+#[test]
+fn test_usernames_are_unique() {
+    let s = setup_test();
+    let avatar = BytesN::from_array(&s.env, &[1; 32]);
+    assert_eq!(
+        s.hub.try_get_profile(&s.player1),
+        Err(Ok(Error::ProfileNotFound))
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    s.hub
+        .set_profile(&s.player1, &symbol_short!("ada"), &Some(avatar.clone()));
+    let result = s
+        .hub
+        .try_set_profile(&s.player2, &symbol_short!("ada"), &None);
+    assert_eq!(result, Err(Ok(Error::UsernameTaken)));
+
+    // Renaming keeps the creation date and frees the old name
+    s.env.ledger().with_mut(|li| li.timestamp = 2_000);
+    s.hub.set_profile(
+        &s.player1,
+        &symbol_short!("lovelace"),
+        &Some(avatar.clone()),
+    );
+    assert_eq!(
+        s.hub.get_profile(&s.player1),
+        Profile {
+            username: symbol_short!("lovelace"),
+            avatar_hash: Some(avatar),
+            created_at: 1_000,
+        }
+    );
+    s.hub.set_profile(&s.player2, &symbol_short!("ada"), &None);
+    assert_eq!(
+        s.hub.find_player(&symbol_short!("ada")),
+        Some(s.player2.clone())
+    );
+    assert_eq!(
+        s.hub.find_player(&symbol_short!("lovelace")),
+        Some(s.player1)
+    );
+    assert_eq!(s.hub.get_profile(&s.player2).created_at, 2_000);
+}
+
+// ============================================================================
+// Rating Tests
+// ============================================================================
+
+#[test]
+fn test_ratings_move_per_game() {
+    let s = setup_test();
+    let other = Address::generate(&s.env);
+    s.hub.register_game(&other, &metadata(&s.env, "Other Game"));
+    let rating = |player: &Address, game: &Address| s.hub.get_rating(player, game);
+
+    // Even ratings: the winner takes half the K-factor
+    s.hub
+        .start_game(&s.game, &20, &s.player1, &s.player2, &0, &0);
+    s.hub.end_game(&20, &true);
+    assert_eq!(rating(&s.player1, &s.game), 1216);
+    assert_eq!(rating(&s.player2, &s.game), 1184);
+    assert_eq!(rating(&s.player1, &other), INITIAL_RATING);
+
+    // The favourite gains less for the same result
+    s.hub
+        .start_game(&s.game, &21, &s.player1, &s.player2, &0, &0);
+    s.hub.end_game(&21, &true);
+    assert_eq!(rating(&s.player1, &s.game), 1230);
+    assert_eq!(rating(&s.player2, &s.game), 1170);
+
+    // A draw pulls the ratings together
+    s.hub.set_k_factor(&20);
+    s.hub
+        .start_game(&s.game, &22, &s.player1, &s.player2, &0, &0);
+    s.hub.end_game_with_outcome(&22, &Outcome::Draw);
+    assert_eq!(rating(&s.player1, &s.game), 1229);
+    assert_eq!(rating(&s.player2, &s.game), 1171);
+
+    for k_factor in [0, 101] {
+        let result = s.hub.try_set_k_factor(&k_factor);
+        assert_eq!(result, Err(Ok(Error::InvalidKFactor)));
+    }
+    assert_eq!(s.hub.k_factor(), 20);
+}
+
+#[test]
+fn test_leaderboard_ranks_and_pages() {
+    let s = setup_test();
+    let player3 = Address::generate(&s.env);
+    s.hub
+        .start_game(&s.game, &23, &s.player1, &s.player2, &0, &0);
+    s.hub.end_game(&23, &true);
+    s.hub.start_game(&s.game, &24, &player3, &s.player2, &0, &0);
+    s.hub.end_game(&24, &true);
+
+    // Equal ratings keep the order they were reached in
+    let board = s.hub.get_leaderboard(&s.game, &None, &LEADERBOARD_SIZE);
+    let entry = |rank: u32, player: &Address, rating: u32| LeaderboardEntry {
+        rank,
+        player: player.clone(),
+        rating,
+    };
+    assert_eq!(
+        board,
+        vec![
+            &s.env,
+            entry(1, &s.player1, 1216),
+            entry(2, &player3, 1215),
+            entry(3, &s.player2, 1169),
+        ]
+    );
+
+    let page = s.hub.get_leaderboard(&s.game, &Some(1), &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).player, player3);
+    assert_eq!(s.hub.get_leaderboard(&s.game, &Some(3), &10).len(), 0);
+}
+
+#[test]
+fn test_leaderboard_keeps_the_top_players() {
+    let s = setup_test();
+
+    // Every game puts two new players on the board until it is full
+    let mut last_loser = None;
+    for session_id in 0..LEADERBOARD_SIZE / 2 + 1 {
+        let (winner, loser) = (Address::generate(&s.env), Address::generate(&s.env));
+        s.hub
+            .start_game(&s.game, &(100 + session_id), &winner, &loser, &0, &0);
+        s.hub.end_game(&(100 + session_id), &true);
+        last_loser = Some(loser);
+    }
+
+    let board = s
+        .hub
+        .get_leaderboard(&s.game, &None, &(LEADERBOARD_SIZE * 2));
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    assert_eq!(board.get_unchecked(0).rating, 1216);
+    assert_eq!(board.get_unchecked(LEADERBOARD_SIZE - 1).rating, 1184);
+    assert!(!board.iter().any(|e| Some(e.player) == last_loser));
+}
+
+// ============================================================================
+// Season Tests
+// ============================================================================
+
+fn set_sequence(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+#[test]
+fn test_seasons_keep_their_own_stats_and_boards() {
+    let s = setup_test();
+    let play = |session_id: u32, player1_won: bool| {
+        s.hub
+            .start_game(&s.game, &session_id, &s.player1, &s.player2, &10, &10);
+        s.hub.end_game(&session_id, &player1_won);
+    };
+    let top = |season: u32| {
+        let board = s.hub.get_season_leaderboard(&s.game, &season, &None, &1);
+        board.get_unchecked(0).player
+    };
+
+    // Before the first season games count only overall
+    play(30, true);
+    assert_eq!(s.hub.current_season(), None);
+    let result = s.hub.try_start_season(&100);
+    assert_eq!(result, Err(Ok(Error::InvalidSeason)));
+
+    let first = s.hub.start_season(&200);
+    assert_eq!(s.hub.current_season(), Some(first));
+    play(31, true);
+    assert_eq!(
+        s.hub.get_season_player(&1, &s.player1),
+        stats(1, 1, 0, 0, 10)
+    );
+    assert_eq!(s.hub.get_player(&s.player1), stats(2, 2, 0, 0, 20));
+    assert_eq!(top(1), s.player1);
+
+    // The rollover closes season 1 early and archives its board
+    set_sequence(&s.env, 150);
+    s.hub.start_season(&300);
+    let first = s.hub.get_season(&1);
+    assert_eq!((first.start_ledger, first.end_ledger), (100, 150));
+    s.env.as_contract(&s.hub.address, || {
+        let key = DataKey::SeasonLeaderboard(s.game.clone(), 1);
+        assert_eq!(
+            s.env.storage().persistent().get_ttl(&key),
+            ARCHIVE_TTL_LEDGERS
+        );
+    });
+
+    // Season 2 starts with an empty board and fresh stats
+    assert_eq!(
+        s.hub.get_season_leaderboard(&s.game, &2, &None, &10).len(),
+        0
+    );
+    play(32, false);
+    play(33, false);
+    assert_eq!(top(2), s.player2);
+    assert_eq!(top(1), s.player1);
+    assert_eq!(
+        s.hub.get_season_player(&2, &s.player1),
+        stats(2, 0, 2, 0, -20)
+    );
+
+    // Once season 2 is over games count in no season
+    set_sequence(&s.env, 300);
+    assert_eq!(s.hub.current_season(), None);
+    play(34, true);
+    assert_eq!(s.hub.get_season_player(&2, &s.player1).games_played, 2);
+    assert_eq!(s.hub.try_get_season(&3), Err(Ok(Error::SeasonNotFound)));
+    assert_eq!(
+        s.hub.get_season(&2),
+        Season {
+            number: 2,
+            start_ledger: 150,
+            end_ledger: 300,
+        }
+    );
+}
+
+// ============================================================================
+// Reward Tests
+// ============================================================================
+
+#[test]
+fn test_sessions_mint_rewards_within_the_emission_caps() {
+    let s = setup_test();
+    let sgs = StudioTokenClient::new(&s.env, &s.env.register(StudioToken, (&s.hub.get_admin(),)));
+    sgs.set_minter(&s.hub.address);
+    sgs.set_emission_schedule(&Some(EmissionSchedule {
+        per_period: 150,
+        period_ledgers: 100,
+        max_supply: 1_000,
+    }));
+    let rewards = Rewards {
+        token: sgs.address.clone(),
+        win: 100,
+        draw: 50,
+    };
+    s.hub.set_rewards(&Some(rewards.clone()));
+    assert_eq!(s.hub.get_rewards(), Some(rewards));
+
+    // The winner is minted the win reward
+    s.hub
+        .start_game(&s.game, &1, &s.player1, &s.player2, &100, &100);
+    s.hub.end_game(&1, &true);
+    assert_eq!(sgs.balance(&s.player1), 100);
+    assert_eq!(sgs.balance(&s.player2), 0);
+
+    // A draw mints each player the draw reward, but the period's cap
+    // leaves room for one; the session settles all the same
+    s.hub
+        .start_game(&s.game, &2, &s.player1, &s.player2, &100, &100);
+    s.hub.end_game_with_outcome(&2, &Outcome::Draw);
+    assert_eq!(s.hub.get_session(&2).status, SessionStatus::Drawn);
+    assert_eq!(sgs.balance(&s.player1), 150);
+    assert_eq!(sgs.balance(&s.player2), 0);
+
+    // The next period mints again
+    set_sequence(&s.env, 200);
+    s.hub
+        .start_game(&s.game, &3, &s.player1, &s.player2, &100, &100);
+    s.hub.end_game(&3, &false);
+    assert_eq!(sgs.balance(&s.player2), 100);
+    assert_eq!(sgs.total_supply(), 250);
+
+    let negative = Rewards {
+        token: sgs.address.clone(),
+        win: -1,
+        draw: 0,
+    };
+    let result = s.hub.try_set_rewards(&Some(negative));
+    assert_eq!(result, Err(Ok(Error::InvalidRewards)));
+
+    // Without rewards, nothing is minted
+    s.hub.set_rewards(&None);
+    s.hub
+        .start_game(&s.game, &4, &s.player1, &s.player2, &100, &100);
+    s.hub.end_game(&4, &true);
+    assert_eq!(sgs.total_supply(), 250);
+}
+
+// ============================================================================
+// Integration Tests
+// ============================================================================
+
+/// A tactical match pointed at the hub, accepting every proof
+fn tactical_match(s: &Setup) -> ZkTacticalMatchContractClient<'static> {
+    let admin = Address::generate(&s.env);
+    let game_id = s
+        .env
+        .register(ZkTacticalMatchContract, (&admin, &s.hub.address));
+    let game = ZkTacticalMatchContractClient::new(&s.env, &game_id);
+    s.hub
+        .register_game(&game_id, &metadata(&s.env, "ZK Tactical Match"));
+    let verifier = s.env.register(MockVerifier, (true,));
+    game.set_verifier(&verifier);
+    game.set_stake_verifier(&verifier);
+    game
+}
+
+/// Submit and reveal `tactic` for `player` with mock proofs
+fn play(game: &ZkTacticalMatchContractClient, session_id: u32, player: &Address, tactic: u32) {
+    let env = &game.env;
+    let secret = mock_secret(env, player, session_id);
+    let inputs = PublicInputs {
+        session_id,
+        player_binding: player_binding(env, &game.address, player),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(env, tactic, session_id),
+        nullifier: mock_nullifier(env, player, session_id),
+        tactic_hash: tactic_hash(env, tactic, &secret, session_id),
+    };
+    let proof = mock_player_proof(env, player, tactic, session_id);
+    game.submit_tactic(&session_id, player, &inputs, &proof);
+}
+
+fn reveal(game: &ZkTacticalMatchContractClient, session_id: u32, player: &Address, tactic: u32) {
+    let secret = mock_secret(&game.env, player, session_id);
+    game.reveal_tactic(&session_id, player, &tactic, &secret);
+}
+
+#[test]
+fn test_tactical_match_reports_to_the_hub() {
+    let s = setup_test();
+    let game = tactical_match(&s);
+
+    // The game draws its session IDs from the hub
+    let session_id = game.allocate_session();
+    assert_eq!(session_id, TEST_SESSIONS + 1);
+    game.start_game(&session_id, &s.player1, &s.player2, &100, &40);
+    let session = s.hub.get_session(&session_id);
+    assert_eq!(session.game_id, game.address);
+    assert_eq!(session.status, SessionStatus::Active);
+
+    // Aggressive (2) beats Balanced (1)
+    for (player, tactic) in [(&s.player1, 2), (&s.player2, 1)] {
+        play(&game, session_id, player, tactic);
+    }
+    for (player, tactic) in [(&s.player1, 2), (&s.player2, 1)] {
+        reveal(&game, session_id, player, tactic);
+    }
+    game.resolve_match(&session_id);
+    assert_eq!(
+        s.hub.get_session(&session_id).status,
+        SessionStatus::Player1Won
+    );
+    assert_eq!(s.hub.get_player(&s.player1), stats(1, 1, 0, 0, 40));
+    assert_eq!(s.hub.get_player(&s.player2), stats(1, 0, 1, 0, -40));
+    assert_eq!(s.hub.balance(&s.player1), 1_040);
+    assert_eq!(s.hub.balance(&s.player2), 960);
+
+    // A cancelled match reaches the hub as a draw
+    let session_id = game.allocate_session();
+    game.start_game(&session_id, &s.player1, &s.player2, &100, &100);
+    game.cancel_game(&session_id);
+    assert_eq!(s.hub.get_session(&session_id).status, SessionStatus::Drawn);
+    assert_eq!(s.hub.get_player(&s.player1), stats(2, 1, 0, 1, 40));
+    assert_eq!(s.hub.balance(&s.player1), 1_040);
+
+    // A stake above the player's balance fails the game's start_game
+    let session_id = game.allocate_session();
+    let result = game.try_start_game(&session_id, &s.player1, &s.player2, &100, &961);
+    assert_eq!(result, Err(Ok(GameError::InsufficientBalance)));
+    assert_eq!(
+        s.hub.try_get_session(&session_id),
+        Err(Ok(Error::SessionNotFound))
+    );
+}
+
+#[test]
+fn test_wagered_match_pays_out_through_the_hub() {
+    let s = setup_test();
+    let game = tactical_match(&s);
+    let session_id = game.allocate_session();
+    let wager = wager_token(&s.env);
+    game.set_wager_token(&game.get_admin(), &Some(wager.clone()));
+    fund(&s.env, &wager, &[&s.player1, &s.player2], 500);
+
+    // The game escrows each stake in its wager token, so the hub holds
+    // none of the players' points
+    game.start_game(&session_id, &s.player1, &s.player2, &200, &150);
+    assert_eq!(balance(&s.env, &wager, &s.player1), 300);
+    assert_eq!(balance(&s.env, &wager, &s.player2), 350);
+    assert_eq!(balance(&s.env, &wager, &game.address), 350);
+    assert_eq!(s.hub.get_session(&session_id).player1_points, 0);
+    assert_eq!(s.hub.balance(&s.player1), 1_000);
+    assert_eq!(s.hub.balance(&s.player2), 1_000);
+
+    // Aggressive (2) beats Balanced (1)
+    for (player, tactic) in [(&s.player1, 1), (&s.player2, 2)] {
+        play(&game, session_id, player, tactic);
+    }
+    for (player, tactic) in [(&s.player1, 1), (&s.player2, 2)] {
+        reveal(&game, session_id, player, tactic);
+    }
+    game.resolve_match(&session_id);
+
+    // The game pays the winner the escrowed pot...
+    assert_eq!(game.get_escrow(&session_id), None);
+    assert_eq!(balance(&s.env, &wager, &s.player1), 300);
+    assert_eq!(balance(&s.env, &wager, &s.player2), 700);
+    assert_eq!(balance(&s.env, &wager, &game.address), 0);
+
+    // ...and the hub records the result, with both points balances
+    // untouched: the loser pays once, in the wager token
+    assert_eq!(
+        s.hub.get_session(&session_id).status,
+        SessionStatus::Player2Won
+    );
+    assert_eq!(s.hub.get_player(&s.player1), stats(1, 0, 1, 0, 0));
+    assert_eq!(s.hub.get_player(&s.player2), stats(1, 1, 0, 0, 0));
+    assert_eq!(s.hub.balance(&s.player1), 1_000);
+    assert_eq!(s.hub.balance(&s.player2), 1_000);
+}
+
+#[test]
+fn test_hub_keeps_sessions_to_the_game_that_opened_them() {
+    let s = setup_test();
+    let game = tactical_match(&s);
+    s.hub
+        .start_game(&s.game, &12, &s.player1, &s.player2, &100, &100);
+
+    // The tactical match cannot open a session another game holds, nor
+    // one the hub has not allocated it
+    for session_id in [12, TEST_SESSIONS + 1] {
+        let result = game.try_start_game(&session_id, &s.player1, &s.player2, &100, &100);
+        assert!(result.is_err());
+    }
+    assert_eq!(s.hub.get_session(&12).game_id, s.game);
+    let result = s.hub.try_get_session(&(TEST_SESSIONS + 1));
+    assert_eq!(result, Err(Ok(Error::SessionNotFound)));
+}
+
+#[test]
+fn test_hidden_stake_match_reserves_the_hub_balance() {
+    let s = setup_test();
+    let game = tactical_match(&s);
+    let session_id = game.allocate_session();
+    let stake = |stake: i128, salt: u8| StakeCommitment {
+        stake_hash: stake_hash(
+            &s.env,
+            stake,
+            &BytesN::from_array(&s.env, &[salt; 32]),
+            session_id,
+        ),
+        proof: mock_proof(&s.env, salt as u32, session_id),
+    };
+
+    // Both players reserve the most they could have staked
+    game.start_hidden_stake_game(
+        &session_id,
+        &s.player1,
+        &s.player2,
+        &10,
+        &500,
+        &stake(300, 0xa1),
+        &stake(200, 0xb2),
+    );
+    assert_eq!(s.hub.balance(&s.player1), 500);
+    assert_eq!(s.hub.balance(&s.player2), 500);
+
+    // So the loser cannot withdraw their stake from under the result
+    s.hub.withdraw(&s.player2, &500);
+    for (player, tactic) in [(&s.player1, 2), (&s.player2, 1)] {
+        play(&game, session_id, player, tactic);
+    }
+    for (player, tactic) in [(&s.player1, 2), (&s.player2, 1)] {
+        reveal(&game, session_id, player, tactic);
+    }
+    for (player, revealed, salt) in [(&s.player1, 300, 0xa1), (&s.player2, 200, 0xb2)] {
+        game.reveal_stake(
+            &session_id,
+            player,
+            &revealed,
+            &BytesN::from_array(&s.env, &[salt; 32]),
+        );
+    }
+    game.resolve_match(&session_id);
+    assert!(game.get_game(&session_id).hub_notified);
+
+    // The hub settles the revealed stakes and hands back the rest
+    let session = s.hub.get_session(&session_id);
+    assert_eq!(session.status, SessionStatus::Player1Won);
+    assert_eq!((session.player1_points, session.player2_points), (300, 200));
+    assert_eq!(s.hub.get_player(&s.player1), stats(1, 1, 0, 0, 200));
+    assert_eq!(s.hub.balance(&s.player1), 1_200);
+    assert_eq!(s.hub.balance(&s.player2), 300);
+}
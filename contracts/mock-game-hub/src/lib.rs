@@ -1,15 +1,23 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Address, BytesN, Env};
 
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
+/// (start_game, end_game, end_game_with_outcome, reduce_stakes, allocate_session, version) but does nothing internally. It exists purely
 /// for game contracts to compile and integrate during development.
 #[contract]
 pub struct MockGameHub;
 
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    RatingsRoot,
+    Treasury,
+    NextSession,
+}
+
 #[contractevent]
 pub struct GameStarted {
     pub session_id: u32,
@@ -20,12 +28,27 @@ pub struct GameStarted {
     pub player2_points: i128,
 }
 
+/// Three-way result of a game session
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Player1Win = 0,
+    Player2Win = 1,
+    Draw = 2,
+}
+
 #[contractevent]
 pub struct GameEnded {
     pub session_id: u32,
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameEndedWithOutcome {
+    pub session_id: u32,
+    pub outcome: Outcome,
+}
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -77,6 +100,93 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// End a game session with a win for either player or a draw
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `outcome` - Which player won, or `Outcome::Draw`
+    pub fn end_game_with_outcome(env: Env, session_id: u32, outcome: Outcome) {
+        // No auth required for mock
+        GameEndedWithOutcome {
+            session_id,
+            outcome,
+        }
+        .publish(&env);
+    }
+
+    /// Lower an active session's stakes to those a hidden-stake game
+    /// revealed
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being settled
+    /// * `player1_points` - Player 1's stake (ignored in mock)
+    /// * `player2_points` - Player 2's stake (ignored in mock)
+    pub fn reduce_stakes(
+        _env: Env,
+        _session_id: u32,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    /// Allocate a session ID, counting up from 1
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract the ID is for (any ID
+    ///   opens a session in the mock)
+    pub fn allocate_session(env: Env, _game_id: Address) -> u32 {
+        // No auth required for mock
+        let session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSession)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSession, &(session_id + 1));
+        session_id
+    }
+
+    /// Hub interface version, checked by games when they are pointed at a
+    /// hub: 1 for `start_game` and `end_game`, 2 adds
+    /// `end_game_with_outcome`, 3 adds `reduce_stakes`, 4 `allocate_session`
+    pub fn version(_env: Env) -> u32 {
+        4
+    }
+
+    /// Publish the root of the player ratings tree
+    ///
+    /// # Arguments
+    /// * `root` - Merkle root of `(rating_key, rating)` leaves
+    pub fn set_ratings_root(env: Env, root: BytesN<32>) {
+        // No auth required for mock
+        env.storage().instance().set(&DataKey::RatingsRoot, &root);
+    }
+
+    /// Root of the player ratings tree, if one has been published
+    pub fn ratings_root(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::RatingsRoot)
+    }
+
+    /// Name where the hub collects its share of game revenue
+    ///
+    /// # Arguments
+    /// * `treasury` - Address games send the hub's share of their fees to
+    pub fn set_treasury(env: Env, treasury: Address) {
+        // No auth required for mock
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    /// Where the hub collects its share of game revenue; fails until one
+    /// is set, so games keep the whole fee
+    pub fn treasury(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .expect("no treasury set")
+    }
 }
 
 #[cfg(test)]
@@ -86,7 +196,7 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
@@ -94,4 +204,42 @@ mod test {
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
     }
+
+    #[test]
+    fn test_end_game_with_draw() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        assert_eq!(client.allocate_session(&game_id), 1);
+        assert_eq!(client.allocate_session(&game_id), 2);
+        client.start_game(&game_id, &2, &player1, &player2, &1000, &1000);
+        client.reduce_stakes(&2, &400, &700);
+        client.end_game_with_outcome(&2, &Outcome::Draw);
+        assert_eq!(client.version(), 4);
+    }
+
+    #[test]
+    fn test_ratings_root() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        assert_eq!(client.ratings_root(), None);
+        let root = BytesN::from_array(&env, &[7; 32]);
+        client.set_ratings_root(&root);
+        assert_eq!(client.ratings_root(), Some(root));
+    }
+
+    #[test]
+    fn test_treasury() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        assert!(client.try_treasury().is_err());
+        let treasury = Address::generate(&env);
+        client.set_treasury(&treasury);
+        assert_eq!(client.treasury(), treasury);
+    }
 }
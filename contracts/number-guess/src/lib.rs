@@ -32,6 +32,8 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn allocate_session(env: Env, game_id: Address) -> u32;
 }
 
 // ============================================================================
@@ -106,6 +108,22 @@ impl NumberGuessContract {
             .set(&DataKey::GameHubAddress, &game_hub);
     }
 
+    /// Allocate this game a session ID at the Game Hub, for the players to
+    /// sign into `start_game`. The hub opens sessions only under IDs it
+    /// allocated to the game. Anyone may call it.
+    ///
+    /// # Returns
+    /// * `u32` - The session ID, unique across every game on the hub
+    pub fn allocate_session(env: Env) -> u32 {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.allocate_session(&env.current_contract_address())
+    }
+
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
     ///
@@ -113,7 +131,7 @@ impl NumberGuessContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Session ID from `allocate_session`
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
@@ -193,7 +211,7 @@ impl NumberGuessContract {
         player.require_auth();
 
         // Validate guess is in range
-        if guess < 1 || guess > 10 {
+        if !(1..=10).contains(&guess) {
             panic!("Guess must be between 1 and 10");
         }
 
@@ -293,17 +311,8 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
-
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
+        let distance1 = guess1.abs_diff(winning_number);
+        let distance2 = guess2.abs_diff(winning_number);
 
         // Determine winner (if equal distance, player1 wins)
         let winner = if distance1 <= distance2 {
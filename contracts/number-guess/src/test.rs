@@ -1,44 +1,17 @@
 #![cfg(test)]
 
-// Unit tests for the number-guess contract using a simple mock GameHub.
+// Unit tests for the number-guess contract using the shared mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 //
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
 use crate::{Error, NumberGuessContract, NumberGuessContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
-
-// ============================================================================
-// Mock GameHub for Unit Testing
-// ============================================================================
-
-#[contract]
-pub struct MockGameHub;
-
-#[contractimpl]
-impl MockGameHub {
-    pub fn start_game(
-        _env: Env,
-        _game_id: Address,
-        _session_id: u32,
-        _player1: Address,
-        _player2: Address,
-        _player1_points: i128,
-        _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
-    }
-
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
-    }
-
-    pub fn add_game(_env: Env, _game_address: Address) {
-        // Mock implementation - does nothing
-    }
-}
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String};
+use game_hub::{GameHub, GameHubClient, GameMetadata, SessionStatus};
+use soroban_sdk::token::StellarAssetClient;
+use stellar_game_testutils::{setup_ledger, MockGameHub, MockGameHubClient};
 
 // ============================================================================
 // Test Helpers
@@ -54,17 +27,7 @@ fn setup_test() -> (
     let env = Env::default();
     env.mock_all_auths();
 
-    // Set ledger info for time-based operations
-    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
-        timestamp: 1441065600,
-        protocol_version: 25,
-        sequence_number: 100,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: u32::MAX / 2,
-        min_persistent_entry_ttl: u32::MAX / 2,
-        max_entry_ttl: u32::MAX / 2,
-    });
+    setup_ledger(&env);
 
     // Deploy mock GameHub contract
     let hub_addr = env.register(MockGameHub, ());
@@ -176,7 +139,7 @@ fn test_complete_game() {
     assert_eq!(final_game.winner.unwrap(), winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
@@ -196,7 +159,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -244,16 +207,8 @@ fn test_closest_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
 
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
@@ -298,16 +253,8 @@ fn test_exact_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
     } else {
@@ -537,3 +484,72 @@ fn test_upgrade_function_exists() {
     // This confirms the authorization check passed
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Game Hub Integration Tests
+// ============================================================================
+
+/// Deploy the game against the real Game Hub, registered there, with both
+/// players holding 1000 points at the hub
+fn setup_hub_test() -> (
+    Env,
+    NumberGuessContractClient<'static>,
+    GameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    let admin = Address::generate(&env);
+    let hub = GameHubClient::new(&env, &env.register(GameHub, (&admin,)));
+    let contract_id = env.register(NumberGuessContract, (&admin, &hub.address));
+    let client = NumberGuessContractClient::new(&env, &contract_id);
+    let metadata = GameMetadata {
+        name: String::from_str(&env, "number-guess"),
+        version: String::from_str(&env, "1.0.0"),
+    };
+    hub.register_game(&contract_id, &metadata);
+
+    let points = env.register_stellar_asset_contract_v2(admin).address();
+    hub.set_points_token(&points);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    for player in [&player1, &player2] {
+        StellarAssetClient::new(&env, &points).mint(player, &1_000);
+        hub.deposit(player, &1_000);
+    }
+
+    (env, client, hub, player1, player2)
+}
+
+#[test]
+fn test_sessions_are_allocated_by_the_game_hub() {
+    let (_env, client, hub, player1, player2) = setup_hub_test();
+
+    // The hub opens no session under an ID it did not allocate the game
+    assert!(client.try_start_game(&1, &player1, &player2, &100, &100).is_err());
+
+    let session_id = client.allocate_session();
+    assert_eq!(session_id, 1);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert_eq!(hub.balance(&player1), 900);
+    assert_eq!(hub.balance(&player2), 900);
+
+    client.make_guess(&session_id, &player1, &3);
+    client.make_guess(&session_id, &player2, &8);
+    let winner = client.reveal_winner(&session_id);
+
+    // The hub settles the session and pays the winner the pot
+    let status = if winner == player1 {
+        SessionStatus::Player1Won
+    } else {
+        SessionStatus::Player2Won
+    };
+    assert_eq!(hub.get_session(&session_id).status, status);
+    assert_eq!(hub.balance(&winner), 1_100);
+
+    // Each session gets a fresh ID
+    assert_eq!(client.allocate_session(), 2);
+}
@@ -0,0 +1,283 @@
+#![no_std]
+
+//! # Randomness Beacon
+//!
+//! Commit-reveal randomness provider shared by Studio games.
+//!
+//! An operator commits `sha256(seed)` for a round before any game binds to it,
+//! then reveals `seed` once the games that bound to the round are locked in.
+//! The round's randomness is `keccak256(seed || round || previous)`, chaining
+//! every round to the one before it so a single reveal cannot be replayed.
+//!
+//! Games bind to `pending_round()` when a session starts and read
+//! `get_randomness(round)` when they need tiebreaks, arenas or card draws, then
+//! seed `env.prng()` with it. This keeps every game on one audited source
+//! instead of each one deriving its own PRNG seed.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address,
+    Bytes, BytesN, Env,
+};
+
+/// Interface games consume. Import it with `#[contractclient]` (or depend on
+/// this crate and use `RandomnessClient`) instead of calling the beacon's
+/// admin surface.
+#[contractclient(name = "RandomnessClient")]
+pub trait RandomnessProvider {
+    /// Latest committed round that has not been revealed yet.
+    fn pending_round(env: Env) -> u64;
+
+    /// Revealed randomness for `round`; traps if the round is not revealed.
+    fn get_randomness(env: Env, round: u64) -> BytesN<32>;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    RoundNotFound = 1,
+    RoundOutOfOrder = 2,
+    RoundNotRevealed = 3,
+    RoundAlreadyRevealed = 4,
+    RevealMismatch = 5,
+    NoPendingRound = 6,
+    PreviousRoundNotRevealed = 7,
+    NotInitialized = 8,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Round {
+    pub commitment: BytesN<32>,
+    pub committed_ledger: u32,
+    pub randomness: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Operator,
+    LatestRound,
+    Round(u64),
+}
+
+#[contractevent]
+pub struct RoundCommitted {
+    pub round: u64,
+    pub commitment: BytesN<32>,
+}
+
+#[contractevent]
+pub struct RoundRevealed {
+    pub round: u64,
+    pub randomness: BytesN<32>,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+/// Rounds must stay readable for as long as a game session can reference
+/// them, so they live in persistent storage with the same 30-day horizon.
+const ROUND_TTL_LEDGERS: u32 = 518_400;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct RandomnessBeacon;
+
+#[contractimpl]
+impl RandomnessBeacon {
+    /// Initialize the beacon. The admin is also the initial operator.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can rotate the operator and upgrade)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Operator, &admin);
+        env.storage().instance().set(&DataKey::LatestRound, &0u64);
+    }
+
+    /// Commit to the seed for the next round.
+    ///
+    /// Rounds are numbered from 1 and must be committed in order. The previous
+    /// round has to be revealed first so at most one round is pending.
+    ///
+    /// # Arguments
+    /// * `round` - Round number, exactly `latest_round() + 1`
+    /// * `commitment` - `sha256(seed)`
+    pub fn commit(env: Env, round: u64, commitment: BytesN<32>) -> Result<(), Error> {
+        Self::operator(&env)?.require_auth();
+
+        let latest = Self::latest_round(env.clone());
+        if round != latest + 1 {
+            return Err(Error::RoundOutOfOrder);
+        }
+        if latest > 0 && Self::load_round(&env, latest)?.randomness.is_none() {
+            return Err(Error::PreviousRoundNotRevealed);
+        }
+
+        let entry = Round {
+            commitment: commitment.clone(),
+            committed_ledger: env.ledger().sequence(),
+            randomness: None,
+        };
+        Self::store_round(&env, round, &entry);
+        env.storage().instance().set(&DataKey::LatestRound, &round);
+
+        RoundCommitted { round, commitment }.publish(&env);
+        Ok(())
+    }
+
+    /// Reveal the seed for a committed round.
+    ///
+    /// Anyone holding the seed may reveal it; the commitment is what binds the
+    /// operator, not the caller.
+    ///
+    /// # Returns
+    /// * `BytesN<32>` - The round's randomness
+    pub fn reveal(env: Env, round: u64, seed: BytesN<32>) -> Result<BytesN<32>, Error> {
+        let mut entry = Self::load_round(&env, round)?;
+        if entry.randomness.is_some() {
+            return Err(Error::RoundAlreadyRevealed);
+        }
+
+        let seed_bytes: Bytes = seed.into();
+        let hashed: BytesN<32> = env.crypto().sha256(&seed_bytes).into();
+        if hashed != entry.commitment {
+            return Err(Error::RevealMismatch);
+        }
+
+        // Chain to the previous round's output (zero for round 1).
+        let previous = if round > 1 {
+            Self::load_round(&env, round - 1)?
+                .randomness
+                .ok_or(Error::PreviousRoundNotRevealed)?
+        } else {
+            BytesN::from_array(&env, &[0u8; 32])
+        };
+
+        let mut preimage = seed_bytes;
+        preimage.append(&Bytes::from_array(&env, &round.to_be_bytes()));
+        preimage.append(&previous.into());
+        let randomness: BytesN<32> = env.crypto().keccak256(&preimage).into();
+
+        entry.randomness = Some(randomness.clone());
+        Self::store_round(&env, round, &entry);
+
+        RoundRevealed {
+            round,
+            randomness: randomness.clone(),
+        }
+        .publish(&env);
+        Ok(randomness)
+    }
+
+    /// Latest committed round that has not been revealed yet.
+    ///
+    /// Games record this at session start; the operator cannot know which
+    /// sessions bound to it when it committed.
+    pub fn pending_round(env: Env) -> Result<u64, Error> {
+        let latest = Self::latest_round(env.clone());
+        if latest == 0 || Self::load_round(&env, latest)?.randomness.is_some() {
+            return Err(Error::NoPendingRound);
+        }
+        Ok(latest)
+    }
+
+    /// Revealed randomness for `round`.
+    pub fn get_randomness(env: Env, round: u64) -> Result<BytesN<32>, Error> {
+        Self::load_round(&env, round)?
+            .randomness
+            .ok_or(Error::RoundNotRevealed)
+    }
+
+    /// Full round record, for UIs and auditors.
+    pub fn get_round(env: Env, round: u64) -> Result<Round, Error> {
+        Self::load_round(&env, round)
+    }
+
+    /// Highest round number committed so far (0 before the first commit).
+    pub fn latest_round(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LatestRound)
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    pub fn get_operator(env: Env) -> Result<Address, Error> {
+        Self::operator(&env)
+    }
+
+    /// Rotate the account allowed to commit rounds.
+    pub fn set_operator(env: Env, new_operator: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::Operator, &new_operator);
+        Ok(())
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    // ========================================================================
+    // Internal
+    // ========================================================================
+
+    fn operator(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Operator)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn load_round(env: &Env, round: u64) -> Result<Round, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Round(round))
+            .ok_or(Error::RoundNotFound)
+    }
+
+    fn store_round(env: &Env, round: u64, entry: &Round) {
+        let key = DataKey::Round(round);
+        env.storage().persistent().set(&key, entry);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, ROUND_TTL_LEDGERS, ROUND_TTL_LEDGERS);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::{Error, RandomnessBeacon, RandomnessBeaconClient, RandomnessClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, RandomnessBeaconClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RandomnessBeacon, (&admin,));
+    let client = RandomnessBeaconClient::new(&env, &contract_id);
+
+    (env, client, admin)
+}
+
+fn seed(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+fn commitment(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    let bytes: Bytes = seed.clone().into();
+    env.crypto().sha256(&bytes).into()
+}
+
+fn commit_and_reveal(
+    env: &Env,
+    client: &RandomnessBeaconClient,
+    round: u64,
+    byte: u8,
+) -> BytesN<32> {
+    let seed = seed(env, byte);
+    client.commit(&round, &commitment(env, &seed));
+    client.reveal(&round, &seed)
+}
+
+fn assert_beacon_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => assert_eq!(*actual_error, expected_error),
+        _ => panic!("Expected beacon error {:?}", expected_error),
+    }
+}
+
+// ============================================================================
+// Commit / Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_commit_then_reveal() {
+    let (env, client, _admin) = setup_test();
+
+    let seed = seed(&env, 7);
+    client.commit(&1, &commitment(&env, &seed));
+    assert_eq!(client.latest_round(), 1);
+    assert_eq!(client.pending_round(), 1);
+
+    let randomness = client.reveal(&1, &seed);
+    assert_eq!(client.get_randomness(&1), randomness);
+    assert_eq!(client.get_round(&1).randomness, Some(randomness));
+    assert_beacon_error(&client.try_pending_round(), Error::NoPendingRound);
+}
+
+#[test]
+fn test_reveal_rejects_wrong_seed() {
+    let (env, client, _admin) = setup_test();
+
+    client.commit(&1, &commitment(&env, &seed(&env, 1)));
+    let result = client.try_reveal(&1, &seed(&env, 2));
+    assert_beacon_error(&result, Error::RevealMismatch);
+    assert_beacon_error(&client.try_get_randomness(&1), Error::RoundNotRevealed);
+}
+
+#[test]
+fn test_reveal_twice_rejected() {
+    let (env, client, _admin) = setup_test();
+
+    commit_and_reveal(&env, &client, 1, 1);
+    let result = client.try_reveal(&1, &seed(&env, 1));
+    assert_beacon_error(&result, Error::RoundAlreadyRevealed);
+}
+
+#[test]
+fn test_rounds_must_be_sequential() {
+    let (env, client, _admin) = setup_test();
+
+    let result = client.try_commit(&2, &commitment(&env, &seed(&env, 1)));
+    assert_beacon_error(&result, Error::RoundOutOfOrder);
+
+    client.commit(&1, &commitment(&env, &seed(&env, 1)));
+    let result = client.try_commit(&2, &commitment(&env, &seed(&env, 2)));
+    assert_beacon_error(&result, Error::PreviousRoundNotRevealed);
+}
+
+#[test]
+fn test_unknown_round() {
+    let (_env, client, _admin) = setup_test();
+
+    assert_beacon_error(&client.try_get_randomness(&1), Error::RoundNotFound);
+    assert_beacon_error(&client.try_pending_round(), Error::NoPendingRound);
+}
+
+#[test]
+fn test_rounds_are_chained() {
+    let (env, client, _admin) = setup_test();
+
+    let first = commit_and_reveal(&env, &client, 1, 9);
+    let second = commit_and_reveal(&env, &client, 2, 9);
+
+    // Same seed, different round and predecessor: outputs must differ.
+    assert_ne!(first, second);
+
+    // Round 2 output is keccak256(seed || round || round 1 output).
+    let mut preimage: Bytes = seed(&env, 9).into();
+    preimage.append(&Bytes::from_array(&env, &2u64.to_be_bytes()));
+    preimage.append(&first.into());
+    let expected: BytesN<32> = env.crypto().keccak256(&preimage).into();
+    assert_eq!(second, expected);
+}
+
+// ============================================================================
+// Consumer Interface Tests
+// ============================================================================
+
+#[test]
+fn test_provider_interface() {
+    let (env, client, _admin) = setup_test();
+    let provider = RandomnessClient::new(&env, &client.address);
+
+    let seed = seed(&env, 3);
+    client.commit(&1, &commitment(&env, &seed));
+    assert_eq!(provider.pending_round(), 1);
+
+    let randomness = client.reveal(&1, &seed);
+    assert_eq!(provider.get_randomness(&1), randomness);
+}
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[test]
+fn test_set_operator() {
+    let (env, client, admin) = setup_test();
+    assert_eq!(client.get_operator(), admin);
+
+    let operator = Address::generate(&env);
+    client.set_operator(&operator);
+    assert_eq!(client.get_operator(), operator);
+
+    client.commit(&1, &commitment(&env, &seed(&env, 4)));
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, operator);
+}
+
+#[test]
+#[should_panic]
+fn test_commit_requires_operator_auth() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RandomnessBeacon, (&admin,));
+    let client = RandomnessBeaconClient::new(&env, &contract_id);
+
+    client.commit(&1, &commitment(&env, &seed(&env, 1)));
+}
@@ -0,0 +1,269 @@
+#![no_std]
+
+//! # Reward Claims
+//!
+//! Claimable-balance style reward distribution for season prizes and quest
+//! rewards.
+//!
+//! A funder (the hub, a season operator, a sponsor) escrows tokens into a
+//! claimable entry for one claimant. The claimant pulls the reward once the
+//! `claim_after_ledger` predicate holds; if the entry has an expiration and
+//! is still unclaimed after it, the funder can reclaim it. Settlement code
+//! only creates entries and never pushes transfers to players itself.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, Address, BytesN, Env,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    ClaimableNotFound = 1,
+    InvalidAmount = 2,
+    InvalidPredicate = 3,
+    NotClaimableYet = 4,
+    Expired = 5,
+    NotExpired = 6,
+    NotInitialized = 7,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claimable {
+    pub funder: Address,
+    pub claimant: Address,
+    pub asset: Address,
+    pub amount: i128,
+    /// First ledger sequence at which the claimant may claim
+    pub claim_after_ledger: u32,
+    /// Last ledger the claimant may claim at; afterwards the funder may reclaim
+    pub expiration_ledger: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    NextId,
+    Claimable(u64),
+}
+
+#[contractevent]
+pub struct ClaimableCreated {
+    #[topic]
+    pub claimant: Address,
+    pub id: u64,
+    pub asset: Address,
+    pub amount: i128,
+    pub claim_after_ledger: u32,
+}
+
+#[contractevent]
+pub struct ClaimableClaimed {
+    #[topic]
+    pub claimant: Address,
+    pub id: u64,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct ClaimableReclaimed {
+    #[topic]
+    pub funder: Address,
+    pub id: u64,
+    pub amount: i128,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+/// Entries can be scheduled a season ahead, so keep them for ~120 days
+const CLAIMABLE_TTL_LEDGERS: u32 = 2_073_600;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct RewardClaims;
+
+#[contractimpl]
+impl RewardClaims {
+    /// Initialize the contract
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextId, &0u64);
+    }
+
+    /// Escrow a reward for `claimant`.
+    ///
+    /// # Arguments
+    /// * `funder` - Account paying the reward (and allowed to reclaim it)
+    /// * `claimant` - Only address that can claim
+    /// * `asset` - Token contract (e.g. a Stellar Asset Contract)
+    /// * `amount` - Amount escrowed, in the asset's base units
+    /// * `claim_after_ledger` - First ledger the claim is allowed at
+    /// * `expiration_ledger` - Optional last ledger the claim is allowed at
+    ///
+    /// # Returns
+    /// * `u64` - Claimable entry ID
+    pub fn create(
+        env: Env,
+        funder: Address,
+        claimant: Address,
+        asset: Address,
+        amount: i128,
+        claim_after_ledger: u32,
+        expiration_ledger: Option<u32>,
+    ) -> Result<u64, Error> {
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if let Some(expiration) = expiration_ledger {
+            if expiration < claim_after_ledger || expiration < env.ledger().sequence() {
+                return Err(Error::InvalidPredicate);
+            }
+        }
+
+        token::Client::new(&env, &asset).transfer(
+            &funder,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let id: u64 = env.storage().instance().get(&DataKey::NextId).unwrap_or(0);
+        env.storage().instance().set(&DataKey::NextId, &(id + 1));
+
+        let entry = Claimable {
+            funder,
+            claimant: claimant.clone(),
+            asset: asset.clone(),
+            amount,
+            claim_after_ledger,
+            expiration_ledger,
+        };
+        let key = DataKey::Claimable(id);
+        env.storage().persistent().set(&key, &entry);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, CLAIMABLE_TTL_LEDGERS, CLAIMABLE_TTL_LEDGERS);
+
+        ClaimableCreated {
+            claimant,
+            id,
+            asset,
+            amount,
+            claim_after_ledger,
+        }
+        .publish(&env);
+        Ok(id)
+    }
+
+    /// Claim a reward. Requires the claimant's auth.
+    pub fn claim(env: Env, id: u64) -> Result<i128, Error> {
+        let entry = Self::get_claimable(env.clone(), id)?;
+        entry.claimant.require_auth();
+
+        let current = env.ledger().sequence();
+        if current < entry.claim_after_ledger {
+            return Err(Error::NotClaimableYet);
+        }
+        if entry
+            .expiration_ledger
+            .is_some_and(|expiration| current > expiration)
+        {
+            return Err(Error::Expired);
+        }
+
+        env.storage().persistent().remove(&DataKey::Claimable(id));
+        token::Client::new(&env, &entry.asset).transfer(
+            &env.current_contract_address(),
+            &entry.claimant,
+            &entry.amount,
+        );
+
+        ClaimableClaimed {
+            claimant: entry.claimant,
+            id,
+            amount: entry.amount,
+        }
+        .publish(&env);
+        Ok(entry.amount)
+    }
+
+    /// Return an expired, unclaimed reward to its funder. Requires the
+    /// funder's auth.
+    pub fn reclaim(env: Env, id: u64) -> Result<i128, Error> {
+        let entry = Self::get_claimable(env.clone(), id)?;
+        entry.funder.require_auth();
+
+        match entry.expiration_ledger {
+            Some(expiration) if env.ledger().sequence() > expiration => {}
+            _ => return Err(Error::NotExpired),
+        }
+
+        env.storage().persistent().remove(&DataKey::Claimable(id));
+        token::Client::new(&env, &entry.asset).transfer(
+            &env.current_contract_address(),
+            &entry.funder,
+            &entry.amount,
+        );
+
+        ClaimableReclaimed {
+            funder: entry.funder,
+            id,
+            amount: entry.amount,
+        }
+        .publish(&env);
+        Ok(entry.amount)
+    }
+
+    /// Get a pending claimable entry
+    pub fn get_claimable(env: Env, id: u64) -> Result<Claimable, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(id))
+            .ok_or(Error::ClaimableNotFound)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
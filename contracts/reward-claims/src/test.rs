@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use crate::{Error, RewardClaims, RewardClaimsClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    client: RewardClaimsClient<'static>,
+    token: TokenClient<'static>,
+    funder: Address,
+    claimant: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RewardClaims, (&admin,));
+    let client = RewardClaimsClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(admin);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let funder = Address::generate(&env);
+    let claimant = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset.address()).mint(&funder, &1_000);
+
+    Setup {
+        env,
+        client,
+        token,
+        funder,
+        claimant,
+    }
+}
+
+fn set_sequence(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+// ============================================================================
+// Claim Tests
+// ============================================================================
+
+#[test]
+fn test_create_escrows_funds() {
+    let s = setup_test();
+
+    let id = s
+        .client
+        .create(&s.funder, &s.claimant, &s.token.address, &400, &200, &None);
+    assert_eq!(id, 0);
+    assert_eq!(s.token.balance(&s.funder), 600);
+    assert_eq!(s.token.balance(&s.client.address), 400);
+
+    let entry = s.client.get_claimable(&id);
+    assert_eq!(entry.claimant, s.claimant);
+    assert_eq!(entry.amount, 400);
+}
+
+#[test]
+fn test_claim_after_ledger() {
+    let s = setup_test();
+    let id = s
+        .client
+        .create(&s.funder, &s.claimant, &s.token.address, &400, &200, &None);
+
+    let result = s.client.try_claim(&id);
+    assert_eq!(result, Err(Ok(Error::NotClaimableYet)));
+
+    set_sequence(&s.env, 200);
+    assert_eq!(s.client.claim(&id), 400);
+    assert_eq!(s.token.balance(&s.claimant), 400);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+
+    // Entries are single-use
+    let result = s.client.try_claim(&id);
+    assert_eq!(result, Err(Ok(Error::ClaimableNotFound)));
+}
+
+#[test]
+fn test_claim_requires_claimant_auth() {
+    let s = setup_test();
+    let id = s
+        .client
+        .create(&s.funder, &s.claimant, &s.token.address, &400, &100, &None);
+
+    s.client.claim(&id);
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, s.claimant);
+}
+
+#[test]
+fn test_expired_entry_is_reclaimed_by_funder() {
+    let s = setup_test();
+    let id = s.client.create(
+        &s.funder,
+        &s.claimant,
+        &s.token.address,
+        &400,
+        &100,
+        &Some(150),
+    );
+
+    let result = s.client.try_reclaim(&id);
+    assert_eq!(result, Err(Ok(Error::NotExpired)));
+
+    set_sequence(&s.env, 151);
+    let result = s.client.try_claim(&id);
+    assert_eq!(result, Err(Ok(Error::Expired)));
+
+    assert_eq!(s.client.reclaim(&id), 400);
+    assert_eq!(s.token.balance(&s.funder), 1_000);
+    assert_eq!(s.token.balance(&s.claimant), 0);
+}
+
+#[test]
+fn test_entry_without_expiration_cannot_be_reclaimed() {
+    let s = setup_test();
+    let id = s
+        .client
+        .create(&s.funder, &s.claimant, &s.token.address, &400, &100, &None);
+
+    set_sequence(&s.env, 10_000);
+    let result = s.client.try_reclaim(&id);
+    assert_eq!(result, Err(Ok(Error::NotExpired)));
+}
+
+#[test]
+fn test_create_validates_inputs() {
+    let s = setup_test();
+
+    let result = s
+        .client
+        .try_create(&s.funder, &s.claimant, &s.token.address, &0, &100, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    // Expires before it becomes claimable
+    let result = s.client.try_create(
+        &s.funder,
+        &s.claimant,
+        &s.token.address,
+        &10,
+        &200,
+        &Some(150),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidPredicate)));
+}
+
+#[test]
+fn test_ids_are_sequential() {
+    let s = setup_test();
+    for expected in 0..3u64 {
+        let id = s
+            .client
+            .create(&s.funder, &s.claimant, &s.token.address, &10, &100, &None);
+        assert_eq!(id, expected);
+    }
+}
@@ -0,0 +1,464 @@
+#![no_std]
+
+//! # Studio Token
+//!
+//! The Studio's SEP-41 fungible token, earned by playing and spent on fee
+//! discounts.
+//!
+//! Only the minter (the Game Hub) mints, rewarding match activity as games
+//! settle. Emissions are capped by the schedule the admin sets: the minter
+//! may mint at most `per_period` in each period of `period_ledgers`, and
+//! never past `max_supply` in total. Without a schedule, minting is closed.
+//! Everything else is a standard SEP-41 token: balances, allowances with an
+//! expiration ledger, transfers and burns, so wallets, DEXes and game
+//! contracts use it through `token::Client` like any Stellar asset.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error,
+    token::TokenInterface, Address, BytesN, Env, MuxedAddress, String,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 1,
+    InvalidAmount = 2,
+    InsufficientBalance = 3,
+    InsufficientAllowance = 4,
+    InvalidExpiration = 5,
+    MinterNotSet = 6,
+    MintingClosed = 7,
+    EmissionCapExceeded = 8,
+    SupplyCapExceeded = 9,
+    InvalidSchedule = 10,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// Token metadata, fixed at deployment
+pub const NAME: &str = "Stellar Game Studio";
+pub const SYMBOL: &str = "SGS";
+pub const DECIMALS: u32 = 7;
+
+/// How much the minter may mint (`set_emission_schedule`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionSchedule {
+    /// Most minted in one period
+    pub per_period: i128,
+    /// Length of a period, counted from when the schedule was set
+    pub period_ledgers: u32,
+    /// Total supply is never minted past this
+    pub max_supply: i128,
+}
+
+/// What has been minted in the running period (`get_emissions`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Emissions {
+    /// Periods since the schedule was set, from 0
+    pub period: u32,
+    pub minted: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allowance {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Minter,
+    TotalSupply,
+    Schedule,
+    ScheduleStart,
+    Emissions,
+    Balance(Address),
+    Allowance(Address, Address),
+}
+
+// Events follow SEP-41, so indexers read them as for any Stellar asset
+
+#[contractevent(data_format = "single-value")]
+pub struct Mint {
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// A transfer to a muxed account, carrying its ID
+#[contractevent(topics = ["transfer"])]
+pub struct TransferMuxed {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: u64,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "vec")]
+pub struct Approve {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contractevent]
+pub struct MinterChanged {
+    pub minter: Address,
+}
+
+#[contractevent]
+pub struct EmissionScheduleChanged {
+    pub schedule: Option<EmissionSchedule>,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+/// Balances are held indefinitely, so keep them for ~120 days per touch
+const BALANCE_TTL_LEDGERS: u32 = 2_073_600;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct StudioToken;
+
+#[contractimpl]
+impl StudioToken {
+    /// Initialize the contract
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (sets the minter and schedule, can upgrade)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::TotalSupply, &0i128);
+    }
+
+    /// Mint `amount` to `to`. Requires the minter's auth.
+    ///
+    /// Counts toward the running period's emissions: minting past the
+    /// schedule's `per_period` returns `EmissionCapExceeded`, and past its
+    /// `max_supply` `SupplyCapExceeded`. With no schedule set, minting
+    /// returns `MintingClosed`.
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        Self::get_minter(env.clone())?.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let schedule = Self::get_emission_schedule(env.clone()).ok_or(Error::MintingClosed)?;
+        let mut emissions = Self::get_emissions(env.clone());
+        if emissions.minted + amount > schedule.per_period {
+            return Err(Error::EmissionCapExceeded);
+        }
+        let supply = Self::total_supply(env.clone()) + amount;
+        if supply > schedule.max_supply {
+            return Err(Error::SupplyCapExceeded);
+        }
+
+        emissions.minted += amount;
+        env.storage().instance().set(&DataKey::Emissions, &emissions);
+        env.storage().instance().set(&DataKey::TotalSupply, &supply);
+        Self::credit(&env, &to, amount);
+
+        Mint { to, amount }.publish(&env);
+        Ok(())
+    }
+
+    /// Tokens in circulation: minted less burned
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
+    /// What has been minted in the running period of the schedule
+    pub fn get_emissions(env: Env) -> Emissions {
+        let Some(schedule) = Self::get_emission_schedule(env.clone()) else {
+            return Emissions::default();
+        };
+        let start: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ScheduleStart)
+            .unwrap_or(0);
+        let period = (env.ledger().sequence() - start) / schedule.period_ledgers;
+        let emissions: Emissions = env
+            .storage()
+            .instance()
+            .get(&DataKey::Emissions)
+            .unwrap_or_default();
+        if emissions.period == period {
+            emissions
+        } else {
+            Emissions { period, minted: 0 }
+        }
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_minter(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Minter)
+            .ok_or(Error::MinterNotSet)
+    }
+
+    /// Set the minter, normally the Game Hub. Requires the admin's auth.
+    pub fn set_minter(env: Env, minter: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Minter, &minter);
+        MinterChanged { minter }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_emission_schedule(env: Env) -> Option<EmissionSchedule> {
+        env.storage().instance().get(&DataKey::Schedule)
+    }
+
+    /// Set the emission schedule (None closes minting). Requires the
+    /// admin's auth.
+    ///
+    /// The new schedule's first period starts now, with nothing minted in
+    /// it. Returns `InvalidSchedule` if `per_period` or `period_ledgers` is
+    /// not positive, or `max_supply` is below the current supply.
+    pub fn set_emission_schedule(
+        env: Env,
+        schedule: Option<EmissionSchedule>,
+    ) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+
+        let storage = env.storage().instance();
+        match &schedule {
+            Some(new) => {
+                if new.per_period <= 0
+                    || new.period_ledgers == 0
+                    || new.max_supply < Self::total_supply(env.clone())
+                {
+                    return Err(Error::InvalidSchedule);
+                }
+                storage.set(&DataKey::Schedule, new);
+                storage.set(&DataKey::ScheduleStart, &env.ledger().sequence());
+            }
+            None => {
+                storage.remove(&DataKey::Schedule);
+                storage.remove(&DataKey::ScheduleStart);
+            }
+        }
+        storage.remove(&DataKey::Emissions);
+
+        EmissionScheduleChanged { schedule }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// SEP-41 Token Interface
+// ============================================================================
+
+#[contractimpl(contracttrait)]
+impl TokenInterface for StudioToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Self::read_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        Self::check_amount(&env, amount);
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            panic_with_error!(&env, Error::InvalidExpiration);
+        }
+
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        let allowance = Allowance { amount, expiration_ledger };
+        env.storage().temporary().set(&key, &allowance);
+        if amount > 0 {
+            let ttl = expiration_ledger - env.ledger().sequence();
+            env.storage().temporary().extend_ttl(&key, ttl, ttl);
+        }
+
+        Approve { from, spender, amount, expiration_ledger }.publish(&env);
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
+        from.require_auth();
+        Self::check_amount(&env, amount);
+        let to_address = to.address();
+        Self::debit(&env, &from, amount);
+        Self::credit(&env, &to_address, amount);
+
+        match to.id() {
+            Some(to_muxed_id) => TransferMuxed { from, to: to_address, to_muxed_id, amount }
+                .publish(&env),
+            None => Transfer { from, to: to_address, amount }.publish(&env),
+        }
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        Self::check_amount(&env, amount);
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::debit(&env, &from, amount);
+        Self::credit(&env, &to, amount);
+        Transfer { from, to, amount }.publish(&env);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        Self::check_amount(&env, amount);
+        Self::debit(&env, &from, amount);
+        Self::reduce_supply(&env, amount);
+        Burn { from, amount }.publish(&env);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        Self::check_amount(&env, amount);
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::debit(&env, &from, amount);
+        Self::reduce_supply(&env, amount);
+        Burn { from, amount }.publish(&env);
+    }
+
+    fn decimals(_env: Env) -> u32 {
+        DECIMALS
+    }
+
+    fn name(env: Env) -> String {
+        String::from_str(&env, NAME)
+    }
+
+    fn symbol(env: Env) -> String {
+        String::from_str(&env, SYMBOL)
+    }
+}
+
+// ============================================================================
+// Internal Helpers
+// ============================================================================
+
+impl StudioToken {
+    /// Trap on a negative amount, as SEP-41 requires
+    fn check_amount(env: &Env, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+    }
+
+    fn credit(env: &Env, id: &Address, amount: i128) {
+        let balance = Self::balance(env.clone(), id.clone());
+        Self::write_balance(env, id, balance + amount);
+    }
+
+    /// Take `amount` from `id`'s balance, trapping if it falls short
+    fn debit(env: &Env, id: &Address, amount: i128) {
+        let balance = Self::balance(env.clone(), id.clone());
+        if balance < amount {
+            panic_with_error!(env, Error::InsufficientBalance);
+        }
+        Self::write_balance(env, id, balance - amount);
+    }
+
+    fn write_balance(env: &Env, id: &Address, balance: i128) {
+        let key = DataKey::Balance(id.clone());
+        env.storage().persistent().set(&key, &balance);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_TTL_LEDGERS, BALANCE_TTL_LEDGERS);
+    }
+
+    fn reduce_supply(env: &Env, amount: i128) {
+        let supply = Self::total_supply(env.clone()) - amount;
+        env.storage().instance().set(&DataKey::TotalSupply, &supply);
+    }
+
+    /// `spender`'s allowance from `from`, as 0 once expired
+    fn read_allowance(env: &Env, from: &Address, spender: &Address) -> Allowance {
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        match env.storage().temporary().get::<_, Allowance>(&key) {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+            _ => Allowance { amount: 0, expiration_ledger: 0 },
+        }
+    }
+
+    /// Take `amount` from `spender`'s allowance, trapping if it falls short
+    fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+        let mut allowance = Self::read_allowance(env, from, spender);
+        if allowance.amount < amount {
+            panic_with_error!(env, Error::InsufficientAllowance);
+        }
+        if amount == 0 {
+            return;
+        }
+        allowance.amount -= amount;
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        env.storage().temporary().set(&key, &allowance);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+use crate::{EmissionSchedule, Emissions, Error, StudioToken, StudioTokenClient, DECIMALS};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, String};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    client: StudioTokenClient<'static>,
+    token: TokenClient<'static>,
+    admin: Address,
+    hub: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(StudioToken, (&admin,));
+    let client = StudioTokenClient::new(&env, &contract_id);
+    let token = TokenClient::new(&env, &contract_id);
+
+    let hub = Address::generate(&env);
+    client.set_minter(&hub);
+
+    Setup {
+        env,
+        client,
+        token,
+        admin,
+        hub,
+    }
+}
+
+fn set_sequence(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+fn schedule(per_period: i128, period_ledgers: u32, max_supply: i128) -> Option<EmissionSchedule> {
+    Some(EmissionSchedule {
+        per_period,
+        period_ledgers,
+        max_supply,
+    })
+}
+
+// ============================================================================
+// Emission Tests
+// ============================================================================
+
+#[test]
+fn test_metadata() {
+    let s = setup_test();
+    assert_eq!(s.token.name(), String::from_str(&s.env, "Stellar Game Studio"));
+    assert_eq!(s.token.symbol(), String::from_str(&s.env, "SGS"));
+    assert_eq!(s.token.decimals(), DECIMALS);
+    assert_eq!(s.client.get_admin(), s.admin);
+}
+
+#[test]
+fn test_minting_is_closed_without_a_schedule() {
+    let s = setup_test();
+    let player = Address::generate(&s.env);
+
+    let result = s.client.try_mint(&player, &10);
+    assert_eq!(result, Err(Ok(Error::MintingClosed)));
+
+    s.client.set_emission_schedule(&schedule(100, 10, 1_000));
+    s.client.mint(&player, &10);
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, s.hub);
+
+    s.client.set_emission_schedule(&None);
+    let result = s.client.try_mint(&player, &10);
+    assert_eq!(result, Err(Ok(Error::MintingClosed)));
+}
+
+#[test]
+fn test_emissions_are_capped_per_period_and_in_total() {
+    let s = setup_test();
+    let player = Address::generate(&s.env);
+    s.client.set_emission_schedule(&schedule(100, 10, 250));
+
+    s.client.mint(&player, &60);
+    let result = s.client.try_mint(&player, &50);
+    assert_eq!(result, Err(Ok(Error::EmissionCapExceeded)));
+    assert_eq!(s.client.get_emissions(), Emissions { period: 0, minted: 60 });
+
+    // A new period starts with nothing minted
+    set_sequence(&s.env, 110);
+    assert_eq!(s.client.get_emissions(), Emissions { period: 1, minted: 0 });
+    s.client.mint(&player, &100);
+
+    set_sequence(&s.env, 120);
+    let result = s.client.try_mint(&player, &100);
+    assert_eq!(result, Err(Ok(Error::SupplyCapExceeded)));
+    s.client.mint(&player, &90);
+    assert_eq!(s.client.total_supply(), 250);
+    assert_eq!(s.token.balance(&player), 250);
+}
+
+#[test]
+fn test_schedule_is_validated() {
+    let s = setup_test();
+    let player = Address::generate(&s.env);
+    s.client.set_emission_schedule(&schedule(100, 10, 1_000));
+    s.client.mint(&player, &100);
+
+    for invalid in [schedule(0, 10, 1_000), schedule(100, 0, 1_000), schedule(100, 10, 99)] {
+        let result = s.client.try_set_emission_schedule(&invalid);
+        assert_eq!(result, Err(Ok(Error::InvalidSchedule)));
+    }
+
+    let result = s.client.try_mint(&player, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_mint_requires_a_minter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(StudioToken, (&admin,));
+    let client = StudioTokenClient::new(&env, &contract_id);
+
+    client.set_emission_schedule(&schedule(100, 10, 1_000));
+    let result = client.try_mint(&admin, &10);
+    assert_eq!(result, Err(Ok(Error::MinterNotSet)));
+}
+
+// ============================================================================
+// SEP-41 Tests
+// ============================================================================
+
+#[test]
+fn test_transfer_and_burn() {
+    let s = setup_test();
+    let (alice, bob) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.client.set_emission_schedule(&schedule(1_000, 10, 1_000));
+    s.client.mint(&alice, &500);
+
+    s.token.transfer(&alice, &bob, &200);
+    assert_eq!(s.token.balance(&alice), 300);
+    assert_eq!(s.token.balance(&bob), 200);
+
+    s.token.burn(&bob, &50);
+    assert_eq!(s.token.balance(&bob), 150);
+    assert_eq!(s.client.total_supply(), 450);
+
+    let result = s.token.try_transfer(&alice, &bob, &301);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_allowances_expire_and_are_spent() {
+    let s = setup_test();
+    let (alice, bob) = (Address::generate(&s.env), Address::generate(&s.env));
+    let spender = Address::generate(&s.env);
+    s.client.set_emission_schedule(&schedule(1_000, 10, 1_000));
+    s.client.mint(&alice, &500);
+
+    s.token.approve(&alice, &spender, &300, &200);
+    assert_eq!(s.token.allowance(&alice, &spender), 300);
+
+    s.token.transfer_from(&spender, &alice, &bob, &100);
+    s.token.burn_from(&spender, &alice, &100);
+    assert_eq!(s.token.allowance(&alice, &spender), 100);
+    assert_eq!(s.token.balance(&alice), 300);
+    assert_eq!(s.token.balance(&bob), 100);
+    assert_eq!(s.client.total_supply(), 400);
+
+    let result = s.token.try_transfer_from(&spender, &alice, &bob, &101);
+    assert!(result.is_err());
+
+    set_sequence(&s.env, 201);
+    assert_eq!(s.token.allowance(&alice, &spender), 0);
+    let result = s.token.try_transfer_from(&spender, &alice, &bob, &1);
+    assert!(result.is_err());
+
+    // An allowance cannot be granted for a ledger already past
+    let result = s.token.try_approve(&alice, &spender, &10, &200);
+    assert!(result.is_err());
+}
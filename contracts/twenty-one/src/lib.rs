@@ -30,6 +30,8 @@ pub trait GameHub {
     );
 
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn allocate_session(env: Env, game_id: Address) -> u32;
 }
 
 // ============================================================================
@@ -156,6 +158,22 @@ impl TwentyOneContract {
             .set(&DataKey::GameHubAddress, &game_hub);
     }
 
+    /// Allocate this game a session ID at the Game Hub, for the players to
+    /// sign into `start_game`. The hub opens sessions only under IDs it
+    /// allocated to the game. Anyone may call it.
+    ///
+    /// # Returns
+    /// * `u32` - The session ID, unique across every game on the hub
+    pub fn allocate_session(env: Env) -> u32 {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.allocate_session(&env.current_contract_address())
+    }
+
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
     /// Each player is dealt 2 cards to start.
@@ -164,7 +182,7 @@ impl TwentyOneContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Session ID from `allocate_session`
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
@@ -320,7 +338,7 @@ impl TwentyOneContract {
             game.player2_hand.len()
         };
 
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(&env, &card_count.to_be_bytes()));
         seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
 
         let card_seed = env.crypto().keccak256(&seed_bytes);
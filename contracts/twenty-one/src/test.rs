@@ -1,45 +1,17 @@
 #![cfg(test)]
 
-// Unit tests for the twenty-one contract using a simple mock GameHub.
+// Unit tests for the twenty-one contract using the shared mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 //
 // Note: These tests use a minimal mock for isolation and speed.
-// For full integration tests with the real GameHub contract, see:
-// contracts/game_hub/src/tests/twenty_one_integration.rs
+// Integration tests with the real GameHub contract are at the end of this file.
 
 use crate::{Error, TwentyOneContract, TwentyOneContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
-
-// ============================================================================
-// Mock GameHub for Unit Testing
-// ============================================================================
-
-#[contract]
-pub struct MockGameHub;
-
-#[contractimpl]
-impl MockGameHub {
-    pub fn start_game(
-        _env: Env,
-        _game_id: Address,
-        _session_id: u32,
-        _player1: Address,
-        _player2: Address,
-        _player1_points: i128,
-        _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
-    }
-
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
-    }
-
-    pub fn add_game(_env: Env, _game_address: Address) {
-        // Mock implementation - does nothing
-    }
-}
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+use game_hub::{GameHub, GameHubClient, GameMetadata, SessionStatus};
+use soroban_sdk::token::StellarAssetClient;
+use stellar_game_testutils::{setup_ledger, MockGameHub, MockGameHubClient};
 
 // ============================================================================
 // Test Helpers
@@ -55,17 +27,7 @@ fn setup_test() -> (
     let env = Env::default();
     env.mock_all_auths();
 
-    // Set ledger info for time-based operations
-    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
-        timestamp: 1441065600,
-        protocol_version: 25,
-        sequence_number: 100,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: u32::MAX / 2,
-        min_persistent_entry_ttl: u32::MAX / 2,
-        max_entry_ttl: u32::MAX / 2,
-    });
+    setup_ledger(&env);
 
     // Deploy mock GameHub contract
     let hub_addr = env.register(MockGameHub, ());
@@ -174,8 +136,8 @@ fn test_complete_game_simple() {
     assert_eq!(game.player2_points, points);
     assert_eq!(game.player1_hand.len(), 2); // 2 cards dealt
     assert_eq!(game.player2_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player1_stuck, false);
-    assert_eq!(game.player2_stuck, false);
+    assert!(!game.player1_stuck);
+    assert!(!game.player2_stuck);
 
     // Both players stick immediately (no hits)
     client.stick(&session_id, &player1);
@@ -207,11 +169,11 @@ fn test_initial_cards_dealt() {
     // Cards should be in valid range (1-13)
     for i in 0..game.player1_hand.len() {
         let card = game.player1_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
     for i in 0..game.player2_hand.len() {
         let card = game.player2_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
 }
 
@@ -227,8 +189,8 @@ fn test_get_hand_value() {
     let player2_value = client.get_hand_value(&session_id, &player2);
 
     // Hand values should be reasonable (2-20 for 2 cards, since max is 10 per card)
-    assert!(player1_value >= 2 && player1_value <= 20);
-    assert!(player2_value >= 2 && player2_value <= 20);
+    assert!((2..=20).contains(&player1_value));
+    assert!((2..=20).contains(&player2_value));
 
     // Verify hand value matches calculation
     let game = client.get_game(&session_id);
@@ -365,9 +327,9 @@ fn test_bust_detection() {
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             busted = true;
-            assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
+            assert_eq!(winner, player2, "Player 2 should win when player 1 busts");
 
             // After a bust, subsequent operations should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -393,9 +355,9 @@ fn test_cannot_hit_after_bust() {
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             did_bust = true;
-            assert_eq!(game.winner.unwrap(), player2);
+            assert_eq!(winner, player2);
 
             // Try to hit again after busting - should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -435,8 +397,8 @@ fn test_draw_starts_new_round() {
         // Verify new round was created
         let game_after = client.get_game(&session_id);
         assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
+        assert!(!game_after.player1_stuck); // Flags reset
+        assert!(!game_after.player2_stuck);
         assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
         assert_eq!(game_after.player2_hand.len(), 2);
     }
@@ -592,8 +554,8 @@ fn test_reveal_winner_idempotent() {
 
     // First reveal
     let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
+    if let Ok(winner1) = result1 {
+        let winner1 = winner1.unwrap();
 
         // Second reveal should return same winner (idempotent)
         let winner2 = client.reveal_winner(&session_id);
@@ -708,7 +670,7 @@ fn test_face_cards_worth_10() {
         let expected_value = if card >= 10 { 10 } else { card };
 
         // Verify this matches our expectation
-        assert!(expected_value >= 1 && expected_value <= 10);
+        assert!((1..=10).contains(&expected_value));
     }
 }
 
@@ -870,3 +832,77 @@ fn test_cannot_play_against_self() {
     let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
+
+// ============================================================================
+// Game Hub Integration Tests
+// ============================================================================
+
+/// Deploy the game against the real Game Hub, registered there, with both
+/// players holding 1000 points at the hub
+fn setup_hub_test() -> (
+    Env,
+    TwentyOneContractClient<'static>,
+    GameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    let admin = Address::generate(&env);
+    let hub = GameHubClient::new(&env, &env.register(GameHub, (&admin,)));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub.address));
+    let client = TwentyOneContractClient::new(&env, &contract_id);
+    let metadata = GameMetadata {
+        name: String::from_str(&env, "twenty-one"),
+        version: String::from_str(&env, "1.0.0"),
+    };
+    hub.register_game(&contract_id, &metadata);
+
+    let points = env.register_stellar_asset_contract_v2(admin).address();
+    hub.set_points_token(&points);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    for player in [&player1, &player2] {
+        StellarAssetClient::new(&env, &points).mint(player, &1_000);
+        hub.deposit(player, &1_000);
+    }
+
+    (env, client, hub, player1, player2)
+}
+
+#[test]
+fn test_sessions_are_allocated_by_the_game_hub() {
+    let (_env, client, hub, player1, player2) = setup_hub_test();
+
+    // The hub opens no session under an ID it did not allocate the game
+    assert!(client.try_start_game(&1, &player1, &player2, &100, &100).is_err());
+
+    let session_id = client.allocate_session();
+    assert_eq!(session_id, 1);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert_eq!(hub.balance(&player1), 900);
+    assert_eq!(hub.balance(&player2), 900);
+
+    // A drawn round deals a new one, until someone wins
+    let winner = loop {
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        if let Ok(Ok(winner)) = client.try_reveal_winner(&session_id) {
+            break winner;
+        }
+    };
+
+    // The hub settles the session and pays the winner the pot
+    let status = if winner == player1 {
+        SessionStatus::Player1Won
+    } else {
+        SessionStatus::Player2Won
+    };
+    assert_eq!(hub.get_session(&session_id).status, status);
+    assert_eq!(hub.balance(&winner), 1_100);
+
+    // Each session gets a fresh ID
+    assert_eq!(client.allocate_session(), 2);
+}
@@ -0,0 +1,197 @@
+#![no_std]
+
+//! # Verification Key Registry
+//!
+//! Shared store of circuit verification keys for Studio games.
+//!
+//! The admin registers each trusted-setup output once, under a circuit ID
+//! (e.g. `tactic`, `rounds`) and a version numbered from 1. Entries are
+//! immutable: a new setup is registered as the next version, never written
+//! over an old one, so every game pinned to `(circuit_id, version)` keeps
+//! verifying against exactly the key it was configured with.
+//!
+//! Games resolve keys with `get_vk(circuit_id, version)` instead of storing
+//! their own copy, so one upload serves every deployment of a circuit.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address,
+    Bytes, BytesN, Env, Symbol,
+};
+
+/// Interface games consume. Import it with `#[contractclient]` (or depend on
+/// this crate and use `VkClient`) instead of calling the registry's admin
+/// surface.
+#[contractclient(name = "VkClient")]
+pub trait VkProvider {
+    /// Verification key registered for `circuit_id` at `version`; traps if
+    /// there is none.
+    fn get_vk(env: Env, circuit_id: Symbol, version: u32) -> Bytes;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    VkNotFound = 1,
+    VersionOutOfOrder = 2,
+    EmptyVerificationKey = 3,
+    NotInitialized = 4,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VkEntry {
+    pub vk: Bytes,
+    pub vk_hash: BytesN<32>,
+    pub registered_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    LatestVersion(Symbol),
+    Vk(Symbol, u32),
+}
+
+#[contractevent]
+pub struct VkRegistered {
+    #[topic]
+    pub circuit_id: Symbol,
+    pub version: u32,
+    pub vk_hash: BytesN<32>,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+/// Keys are read by every proof a game verifies, so they live in persistent
+/// storage and are extended on each registration and read.
+const VK_TTL_LEDGERS: u32 = 518_400;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct VkRegistry;
+
+#[contractimpl]
+impl VkRegistry {
+    /// Initialize the registry.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (registers keys and upgrades)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Register the verification key for the next version of a circuit.
+    ///
+    /// Versions are numbered from 1 and registered in order; a registered
+    /// version can never be replaced.
+    ///
+    /// # Arguments
+    /// * `circuit_id` - Circuit the key belongs to
+    /// * `version` - Exactly `latest_version(circuit_id) + 1`
+    /// * `vk` - Serialized key, in the layout the consuming verifier expects
+    pub fn register(env: Env, circuit_id: Symbol, version: u32, vk: Bytes) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+
+        if version != Self::latest_version(env.clone(), circuit_id.clone()) + 1 {
+            return Err(Error::VersionOutOfOrder);
+        }
+        if vk.is_empty() {
+            return Err(Error::EmptyVerificationKey);
+        }
+
+        let vk_hash: BytesN<32> = env.crypto().sha256(&vk).into();
+        let entry = VkEntry {
+            vk,
+            vk_hash: vk_hash.clone(),
+            registered_ledger: env.ledger().sequence(),
+        };
+        let key = DataKey::Vk(circuit_id.clone(), version);
+        env.storage().persistent().set(&key, &entry);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, VK_TTL_LEDGERS, VK_TTL_LEDGERS);
+
+        let latest_key = DataKey::LatestVersion(circuit_id.clone());
+        env.storage().persistent().set(&latest_key, &version);
+        env.storage()
+            .persistent()
+            .extend_ttl(&latest_key, VK_TTL_LEDGERS, VK_TTL_LEDGERS);
+
+        VkRegistered {
+            circuit_id,
+            version,
+            vk_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Verification key registered for `circuit_id` at `version`.
+    pub fn get_vk(env: Env, circuit_id: Symbol, version: u32) -> Result<Bytes, Error> {
+        Ok(Self::get_entry(env, circuit_id, version)?.vk)
+    }
+
+    /// Full entry (key, its sha256 and registration ledger), for auditors
+    /// comparing against a published setup transcript.
+    pub fn get_entry(env: Env, circuit_id: Symbol, version: u32) -> Result<VkEntry, Error> {
+        let key = DataKey::Vk(circuit_id, version);
+        let entry: VkEntry = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::VkNotFound)?;
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, VK_TTL_LEDGERS, VK_TTL_LEDGERS);
+        Ok(entry)
+    }
+
+    /// Highest version registered for `circuit_id` (0 before the first).
+    pub fn latest_version(env: Env, circuit_id: Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LatestVersion(circuit_id))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::{Error, VkClient, VkRegistry, VkRegistryClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, VkRegistryClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(VkRegistry, (&admin,));
+    let client = VkRegistryClient::new(&env, &contract_id);
+
+    (env, client, admin)
+}
+
+fn vk(env: &Env, byte: u8) -> Bytes {
+    Bytes::from_array(env, &[byte; 64])
+}
+
+fn tactic() -> Symbol {
+    symbol_short!("tactic")
+}
+
+fn assert_registry_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => assert_eq!(*actual_error, expected_error),
+        _ => panic!("Expected registry error {:?}", expected_error),
+    }
+}
+
+// ============================================================================
+// Registration Tests
+// ============================================================================
+
+#[test]
+fn test_register_then_get() {
+    let (env, client, _admin) = setup_test();
+    env.ledger().set_sequence_number(100);
+    assert_eq!(client.latest_version(&tactic()), 0);
+
+    client.register(&tactic(), &1, &vk(&env, 1));
+    assert_eq!(client.latest_version(&tactic()), 1);
+    assert_eq!(client.get_vk(&tactic(), &1), vk(&env, 1));
+
+    let entry = client.get_entry(&tactic(), &1);
+    let expected_hash: BytesN<32> = env.crypto().sha256(&vk(&env, 1)).into();
+    assert_eq!(entry.vk_hash, expected_hash);
+    assert_eq!(entry.registered_ledger, 100);
+}
+
+#[test]
+fn test_versions_are_sequential_and_immutable() {
+    let (env, client, _admin) = setup_test();
+
+    let result = client.try_register(&tactic(), &2, &vk(&env, 2));
+    assert_registry_error(&result, Error::VersionOutOfOrder);
+
+    client.register(&tactic(), &1, &vk(&env, 1));
+    let result = client.try_register(&tactic(), &1, &vk(&env, 9));
+    assert_registry_error(&result, Error::VersionOutOfOrder);
+
+    // A new setup is a new version; the old key stays resolvable
+    client.register(&tactic(), &2, &vk(&env, 2));
+    assert_eq!(client.get_vk(&tactic(), &1), vk(&env, 1));
+    assert_eq!(client.get_vk(&tactic(), &2), vk(&env, 2));
+}
+
+#[test]
+fn test_circuits_are_versioned_independently() {
+    let (env, client, _admin) = setup_test();
+    let rounds = symbol_short!("rounds");
+
+    client.register(&tactic(), &1, &vk(&env, 1));
+    client.register(&tactic(), &2, &vk(&env, 2));
+    client.register(&rounds, &1, &vk(&env, 3));
+
+    assert_eq!(client.latest_version(&rounds), 1);
+    assert_eq!(client.get_vk(&rounds, &1), vk(&env, 3));
+    assert_registry_error(&client.try_get_vk(&rounds, &2), Error::VkNotFound);
+}
+
+#[test]
+fn test_empty_key_rejected() {
+    let (env, client, _admin) = setup_test();
+
+    let result = client.try_register(&tactic(), &1, &Bytes::new(&env));
+    assert_registry_error(&result, Error::EmptyVerificationKey);
+    assert_eq!(client.latest_version(&tactic()), 0);
+}
+
+// ============================================================================
+// Consumer Interface Tests
+// ============================================================================
+
+#[test]
+fn test_provider_interface() {
+    let (env, client, _admin) = setup_test();
+    let provider = VkClient::new(&env, &client.address);
+
+    client.register(&tactic(), &1, &vk(&env, 5));
+    assert_eq!(provider.get_vk(&tactic(), &1), vk(&env, 5));
+}
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[test]
+fn test_register_requires_admin() {
+    let (env, client, admin) = setup_test();
+
+    client.register(&tactic(), &1, &vk(&env, 1));
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, admin);
+}
+
+#[test]
+#[should_panic]
+fn test_register_without_auth_panics() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(VkRegistry, (&admin,));
+    let client = VkRegistryClient::new(&env, &contract_id);
+
+    client.register(&tactic(), &1, &vk(&env, 1));
+}
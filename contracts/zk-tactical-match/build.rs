@@ -0,0 +1,24 @@
+use std::process::Command;
+
+// Exposes the commit the contract is built from as `GIT_HASH`, for the
+// `contractmeta!` entries in `src/lib.rs`. A `GIT_HASH` already in the
+// environment wins, for builds outside a checkout
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_HASH");
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+    println!("cargo:rerun-if-changed=../../.git/refs/heads");
+
+    let hash = std::env::var("GIT_HASH").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|hash| hash.trim().to_string())
+    });
+    println!(
+        "cargo:rustc-env=GIT_HASH={}",
+        hash.unwrap_or_else(|| "unknown".to_string())
+    );
+}
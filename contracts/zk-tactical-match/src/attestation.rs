@@ -0,0 +1,100 @@
+//! Canonical result attestations for bridges.
+//!
+//! A settled session is encoded as four fixed-size leaves and committed to
+//! with a keccak256 Merkle root, so a verifier on another chain can check the
+//! whole record or prove a single field against the root.
+//!
+//! ```text
+//! leaf 0  header    version u8 | network_id [32] | game contract [33] | session_id u32 BE
+//! leaf 1  player1   address [33] | points i128 BE
+//! leaf 2  player2   address [33] | points i128 BE
+//! leaf 3  outcome   winner u8 (0 = draw, 1 = player1, 2 = player2) | score1 u32 BE | score2 u32 BE
+//!
+//! address  = tag u8 (0 = ed25519 account, 1 = contract) | 32-byte key/hash
+//! node     = keccak256(leaf)
+//! root     = keccak256(keccak256(node0 | node1) | keccak256(node2 | node3))
+//! ```
+
+use soroban_sdk::{
+    address_payload::AddressPayload, contracttype, Address, Bytes, BytesN, Env, Vec,
+};
+
+use crate::{Error, Game, Outcome};
+
+/// Version byte at the start of the header leaf (2 added draws)
+pub const ATTESTATION_VERSION: u8 = 2;
+
+/// Canonical, Merkle-committed record of a settled session
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    /// Leaves in order: header, player1, player2, outcome
+    pub leaves: Vec<Bytes>,
+    /// keccak256 Merkle root over the leaves
+    pub root: BytesN<32>,
+}
+
+fn append_address(out: &mut Bytes, address: &Address) -> Result<(), Error> {
+    let (tag, key) = match address.to_payload() {
+        Some(AddressPayload::AccountIdPublicKeyEd25519(key)) => (0u8, key),
+        Some(AddressPayload::ContractIdHash(hash)) => (1u8, hash),
+        None => return Err(Error::UnsupportedAddress),
+    };
+    out.push_back(tag);
+    out.append(&Bytes::from(key));
+    Ok(())
+}
+
+fn player_leaf(env: &Env, player: &Address, points: i128) -> Result<Bytes, Error> {
+    let mut leaf = Bytes::new(env);
+    append_address(&mut leaf, player)?;
+    leaf.append(&Bytes::from_array(env, &points.to_be_bytes()));
+    Ok(leaf)
+}
+
+fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut pair = Bytes::from(left.clone());
+    pair.append(&Bytes::from(right.clone()));
+    env.crypto().keccak256(&pair).into()
+}
+
+/// Build the attestation for a settled game
+pub fn attest(env: &Env, session_id: u32, game: &Game) -> Result<Attestation, Error> {
+    let result = game.outcome().ok_or(Error::GameNotSettled)?;
+    let score1 = game.player1_score.ok_or(Error::GameNotSettled)?;
+    let score2 = game.player2_score.ok_or(Error::GameNotSettled)?;
+
+    let mut header = Bytes::new(env);
+    header.push_back(ATTESTATION_VERSION);
+    header.append(&Bytes::from(env.ledger().network_id()));
+    append_address(&mut header, &env.current_contract_address())?;
+    header.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+
+    let mut outcome = Bytes::new(env);
+    outcome.push_back(match result {
+        Outcome::Draw => 0,
+        Outcome::Player1Win => 1,
+        Outcome::Player2Win => 2,
+    });
+    outcome.append(&Bytes::from_array(env, &score1.to_be_bytes()));
+    outcome.append(&Bytes::from_array(env, &score2.to_be_bytes()));
+
+    let leaves = Vec::from_array(
+        env,
+        [
+            header,
+            player_leaf(env, &game.player1, game.player1_points)?,
+            player_leaf(env, &game.player2, game.player2_points)?,
+            outcome,
+        ],
+    );
+
+    let node = |i: u32| -> BytesN<32> { env.crypto().keccak256(&leaves.get_unchecked(i)).into() };
+    let root = hash_pair(
+        env,
+        &hash_pair(env, &node(0), &node(1)),
+        &hash_pair(env, &node(2), &node(3)),
+    );
+
+    Ok(Attestation { leaves, root })
+}
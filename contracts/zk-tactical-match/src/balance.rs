@@ -0,0 +1,48 @@
+//! Prepaid player balances.
+//!
+//! A player can deposit a token into the contract once (`deposit_balance`)
+//! and start wagered games against that balance: when a game's escrow is
+//! funded, a player whose balance in the token covers their whole amount
+//! has it debited from the balance instead of authorizing a token
+//! transfer, so signing the game itself is enough. A balance that falls
+//! short is left alone and the transfer goes ahead as usual. Payouts are
+//! still pushed to the player, and `withdraw_balance` takes back whatever
+//! is left, at any time.
+
+use soroban_sdk::{Address, Env};
+
+use crate::{DataKey, OPEN_SESSION_TTL_LEDGERS};
+
+/// `player`'s balance in `token`
+pub fn get(env: &Env, player: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(player.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Set `player`'s balance in `token`, removing the entry at 0
+pub fn set(env: &Env, player: &Address, token: &Address, amount: i128) {
+    let key = DataKey::Balance(player.clone(), token.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &amount);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// Debit `amount` from `player`'s balance in `token`, if it covers it.
+/// Returns whether it did
+pub fn debit(env: &Env, player: &Address, token: &Address, amount: i128) -> bool {
+    let held = get(env, player, token);
+    if held < amount {
+        return false;
+    }
+    set(env, player, token, held - amount);
+    true
+}
@@ -0,0 +1,117 @@
+//! House bankroll for games against the contract.
+//!
+//! Liquidity providers deposit a token into its bankroll and receive
+//! shares (`deposit_bankroll`). A house game (`start_house_game`) locks
+//! the house's stake from the bankroll, and the contract plays player2:
+//! its tactic comes from the house beacon's round that is pending when the
+//! player commits, and it wins drawn scores. When the game settles, the
+//! bankroll takes whatever the house's escrow dues are, so it grows when
+//! the house wins and shrinks when it loses. Shares track the bankroll's
+//! value:
+//!
+//! ```text
+//! shares minted = amount * total_shares / assets   (1:1 when empty)
+//! withdrawn     = shares * assets / total_shares   (at most the unlocked part)
+//! ```
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{DataKey, OPEN_SESSION_TTL_LEDGERS};
+
+/// A token's house bankroll (`get_bankroll`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bankroll {
+    pub assets: i128, // held for providers, locked stakes included
+    pub locked: i128, // staked on house games in play
+    pub shares: i128, // issued to providers
+}
+
+impl Bankroll {
+    /// Assets not staked on a game in play
+    pub fn free(&self) -> i128 {
+        self.assets - self.locked
+    }
+
+    /// Shares `amount` buys
+    pub fn shares_for(&self, amount: i128) -> i128 {
+        if self.shares == 0 || self.assets == 0 {
+            return amount;
+        }
+        amount * self.shares / self.assets
+    }
+
+    /// Assets `shares` redeem for
+    pub fn value_of(&self, shares: i128) -> i128 {
+        if self.shares == 0 {
+            return 0;
+        }
+        shares * self.assets / self.shares
+    }
+}
+
+/// The bankroll in `token` (empty if nobody has deposited)
+pub fn get(env: &Env, token: &Address) -> Bankroll {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Bankroll(token.clone()))
+        .unwrap_or_default()
+}
+
+/// Store the bankroll in `token`
+pub fn save(env: &Env, token: &Address, bankroll: &Bankroll) {
+    let key = DataKey::Bankroll(token.clone());
+    env.storage().persistent().set(&key, bankroll);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// `provider`'s shares of the bankroll in `token`
+pub fn shares(env: &Env, token: &Address, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BankrollShares(token.clone(), provider.clone()))
+        .unwrap_or(0)
+}
+
+/// Set `provider`'s shares of the bankroll in `token`, removing the entry
+/// at 0
+pub fn set_shares(env: &Env, token: &Address, provider: &Address, shares: i128) {
+    let key = DataKey::BankrollShares(token.clone(), provider.clone());
+    if shares == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &shares);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// Stake `amount` of the bankroll in `token` on a house game
+pub fn lock(env: &Env, token: &Address, amount: i128) {
+    let mut bankroll = get(env, token);
+    bankroll.locked += amount;
+    save(env, token, &bankroll);
+}
+
+/// Take a settled house game's stake out of the bankroll; its escrow dues
+/// decide what comes back (`credit`)
+pub fn release(env: &Env, token: &Address, amount: i128) {
+    let mut bankroll = get(env, token);
+    bankroll.locked -= amount;
+    bankroll.assets -= amount;
+    save(env, token, &bankroll);
+}
+
+/// Add `amount` the house is due to the bankroll in `token`
+pub fn credit(env: &Env, token: &Address, amount: i128) {
+    let mut bankroll = get(env, token);
+    bankroll.assets += amount;
+    save(env, token, &bankroll);
+}
@@ -0,0 +1,76 @@
+//! Aggregated BLS12-381 signatures for team moves, using the Protocol 25
+//! host functions.
+//!
+//! Keys are G1 points and signatures G2 points, both uncompressed
+//! (big-endian coordinates, G2 as `x.c1 || x.c0 || y.c1 || y.c0`):
+//!
+//! ```text
+//! key       = sk * g1                       (G1, 96 bytes)
+//! signature = sk * hash_to_g2(message, DST) (G2, 192 bytes)
+//! aggregate = sum(signature_i)
+//! ```
+//!
+//! An aggregate over messages `m_i` signed by keys `pk_i` is accepted iff
+//! `e(-g1, aggregate) * prod e(pk_i, hash_to_g2(m_i)) == 1`.
+//!
+//! Every message names its signer (see `TeamMove`), so no two keys ever sign
+//! the same message and a rogue key cannot cancel out an honest signature;
+//! keys need no proof of possession.
+
+use soroban_sdk::{
+    crypto::bls12_381::{G1Affine, G2Affine},
+    vec, Bytes, BytesN, Env, Vec,
+};
+
+/// Domain separation tag of the standard ciphersuite with keys in G1
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// `-g1`: the G1 generator with `y` replaced by `p - y`
+const NEG_G1_GENERATOR: [u8; 96] = [
+    0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c, 0x4f, 0xa9, 0xac, 0x0f,
+    0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05, 0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58,
+    0x6c, 0x55, 0xe8, 0x3f, 0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+    0x11, 0x4d, 0x1d, 0x68, 0x55, 0xd5, 0x45, 0xa8, 0xaa, 0x7d, 0x76, 0xc8, 0xcf, 0x2e, 0x21, 0xf2,
+    0x67, 0x81, 0x6a, 0xef, 0x1d, 0xb5, 0x07, 0xc9, 0x66, 0x55, 0xb9, 0xd5, 0xca, 0xac, 0x42, 0x36,
+    0x4e, 0x6f, 0x38, 0xba, 0x0e, 0xcb, 0x75, 0x1b, 0xad, 0x54, 0xdc, 0xd6, 0xb9, 0x39, 0xc2, 0xca,
+];
+
+/// Flag bit of the point at infinity in the first byte of an encoding
+const INFINITY_FLAG: u8 = 0x40;
+
+/// Whether `key` is a usable public key: a G1 point in the prime-order
+/// subgroup other than the identity, which would let anyone sign for it.
+/// Points that are not on the curve make the host trap.
+pub fn is_valid_key(env: &Env, key: &BytesN<96>) -> bool {
+    if key.to_array()[0] & INFINITY_FLAG != 0 {
+        return false;
+    }
+    env.crypto()
+        .bls12_381()
+        .g1_is_in_subgroup(&G1Affine::from_bytes(key.clone()))
+}
+
+/// Verify one aggregate `signature` over `messages[i]` signed by `keys[i]`.
+///
+/// Returns `false` for mismatched or empty inputs and for signatures that
+/// fail the pairing check.
+pub fn verify_aggregate(
+    env: &Env,
+    keys: &Vec<BytesN<96>>,
+    messages: &Vec<Bytes>,
+    signature: &BytesN<192>,
+) -> bool {
+    if keys.is_empty() || keys.len() != messages.len() {
+        return false;
+    }
+
+    let bls = env.crypto().bls12_381();
+    let dst = Bytes::from_slice(env, DST);
+    let mut g1_points = vec![env, G1Affine::from_array(env, &NEG_G1_GENERATOR)];
+    let mut g2_points = vec![env, G2Affine::from_bytes(signature.clone())];
+    for (key, message) in keys.iter().zip(messages.iter()) {
+        g1_points.push_back(G1Affine::from_bytes(key));
+        g2_points.push_back(bls.hash_to_g2(&message, &dst));
+    }
+    bls.pairing_check(g1_points, g2_points)
+}
@@ -0,0 +1,123 @@
+//! Disputed settlements and winner bonds.
+//!
+//! With a dispute module configured (`set_dispute_config`), every won pot
+//! opens a dispute window of `window_ledgers`. Players back their wins by
+//! posting a bond (`post_bond`): as a pot settles, as much of the winner's
+//! free bond as they were paid in that token is locked against it. Within
+//! the window the loser may dispute the settlement (`dispute_settlement`),
+//! and the dispute module, an account or contract trusted to rule on
+//! cheating, decides it (`resolve_dispute`):
+//!
+//! ```text
+//! upheld   -> the locked bond is clawed back and paid to the loser
+//! rejected -> the lock is released
+//! ```
+//!
+//! A window that closes undisputed releases its lock (`finalize_settlement`).
+//! Only the locked part of a bond is at stake, so a winner with no free
+//! bond cannot be clawed back from.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{DataKey, OPEN_SESSION_TTL_LEDGERS};
+
+/// Who rules on disputes, and for how long a settlement can be disputed
+/// (`set_dispute_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeConfig {
+    pub module: Address,
+    pub window_ledgers: u32,
+}
+
+/// A player's bond in one token (`get_bond`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bond {
+    pub amount: i128, // posted, locked part included
+    pub locked: i128, // held against pots still in their dispute window
+}
+
+impl Bond {
+    /// The part not held against any pot
+    pub fn free(&self) -> i128 {
+        self.amount - self.locked
+    }
+}
+
+/// A won pot in its dispute window (`get_dispute`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub winner: Address,
+    pub loser: Address,
+    pub token: Address,
+    pub locked: i128,         // of the winner's bond, clawed back if upheld
+    pub deadline_ledger: u32, // last ledger to dispute in
+    pub module: Address,      // rules on it, as configured when it opened
+    pub status: DisputeStatus,
+}
+
+/// Where a dispute window stands
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Open,  // no dispute filed yet
+    Filed, // awaiting the module's ruling
+}
+
+/// `player`'s bond in `token`
+pub fn bond(env: &Env, player: &Address, token: &Address) -> Bond {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Bond(player.clone(), token.clone()))
+        .unwrap_or_default()
+}
+
+/// Store `player`'s bond in `token`, removing it once empty
+pub fn save_bond(env: &Env, player: &Address, token: &Address, bond: &Bond) {
+    let key = DataKey::Bond(player.clone(), token.clone());
+    if bond.amount == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, bond);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// The dispute window of `session_id`'s settlement, if still open
+pub fn get(env: &Env, session_id: u32) -> Option<Dispute> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(session_id))
+}
+
+/// Store `session_id`'s dispute window (None: close it)
+pub fn save(env: &Env, session_id: u32, dispute: Option<&Dispute>) {
+    let key = DataKey::Dispute(session_id);
+    let Some(dispute) = dispute else {
+        env.storage().persistent().remove(&key);
+        return;
+    };
+    env.storage().persistent().set(&key, dispute);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// Release `amount` of `player`'s bond in `token` held against a pot, or
+/// with `claw_back`, take it out of the bond
+pub fn unlock(env: &Env, player: &Address, token: &Address, amount: i128, claw_back: bool) {
+    let mut bond = bond(env, player, token);
+    bond.locked -= amount;
+    if claw_back {
+        bond.amount -= amount;
+    }
+    save_bond(env, player, token, &bond);
+}
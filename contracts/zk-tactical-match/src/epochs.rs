@@ -0,0 +1,176 @@
+//! Epoch rewards for the leaderboard.
+//!
+//! With epochs configured (`set_epoch_config`), time is cut into epochs of
+//! `length_ledgers`, and `share_bps` of every protocol fee taken in the
+//! epoch token (after any referral and hub share) goes to the running
+//! epoch's pool. Each token-wagered win counts for the winner in the epoch
+//! it settles in, and the epoch keeps its top `top_n` players by wins. Once
+//! the epoch is over, the pool is shared among them pro rata to their wins
+//! (`claim_epoch_reward`):
+//!
+//! ```text
+//! reward = pool * wins / leaders' total wins   (the last claim takes the dust)
+//! ```
+//!
+//! For leaderboards too large to rank on-chain, a fee manager can instead
+//! publish the root of a Merkle tree of rewards for an epoch that is over
+//! and has paid nothing yet (`set_epoch_root`). Its players then claim with
+//! an inclusion proof (`claim_epoch_reward_with_proof`), up to the pool:
+//!
+//! ```text
+//! leaf = sha256(epoch (u32 BE) | player address XDR | amount (i128 BE))
+//! tree = as `merkle::strategy_root`, over the leaves in any order
+//! ```
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{DataKey, HISTORY_TTL_LEDGERS};
+
+/// How epochs run and fill their pools (`set_epoch_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochConfig {
+    pub token: Address,      // fees in this token feed the pools
+    pub length_ledgers: u32, // of each epoch
+    pub share_bps: u32,      // of each fee, paid into the running epoch's pool
+    pub top_n: u32,          // players ranked and rewarded per epoch
+}
+
+/// When the epochs of the current configuration began. Reconfiguring ends
+/// the running epoch, and the next one starts from then
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochSchedule {
+    pub first_epoch: u32,
+    pub start_ledger: u32,
+}
+
+/// One epoch's pool and leaderboard (`get_epoch`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochRewards {
+    pub token: Address,
+    pub pool: i128,
+    pub claimed: i128,
+    pub leaders: Vec<EpochLeader>, // by wins, most first
+    pub claims: u32,               // leaders paid so far
+    pub root: Option<BytesN<32>>,  // set: claims need a proof instead
+    pub leaves: u32,               // in the tree under `root`
+}
+
+/// A ranked player and their wins in an epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochLeader {
+    pub player: Address,
+    pub wins: u32,
+}
+
+impl EpochSchedule {
+    /// The epoch running at `ledger`
+    pub fn epoch_at(&self, config: &EpochConfig, ledger: u32) -> u32 {
+        self.first_epoch + (ledger - self.start_ledger) / config.length_ledgers
+    }
+}
+
+impl EpochRewards {
+    pub fn new(env: &Env, token: &Address) -> Self {
+        Self {
+            token: token.clone(),
+            pool: 0,
+            claimed: 0,
+            leaders: Vec::new(env),
+            claims: 0,
+            root: None,
+            leaves: 0,
+        }
+    }
+
+    /// Count a win for `player`, who now has `wins`, keeping the `top_n`
+    /// players with the most wins (the first to reach a count ranks higher)
+    pub fn rank(&mut self, player: &Address, wins: u32, top_n: u32) {
+        if let Some(i) = self.leaders.iter().position(|leader| leader.player == *player) {
+            self.leaders.remove(i as u32);
+        }
+        let at = self
+            .leaders
+            .iter()
+            .position(|leader| leader.wins < wins)
+            .map_or(self.leaders.len(), |i| i as u32);
+        if at < top_n {
+            let player = player.clone();
+            self.leaders.insert(at, EpochLeader { player, wins });
+        }
+        while self.leaders.len() > top_n {
+            self.leaders.pop_back();
+        }
+    }
+
+    /// What `player` is owed as one of the leaders, or 0 if they are not
+    pub fn share_of(&self, player: &Address) -> i128 {
+        let Some(leader) = self.leaders.iter().find(|leader| leader.player == *player) else {
+            return 0;
+        };
+        if self.claims + 1 == self.leaders.len() {
+            return self.pool - self.claimed;
+        }
+        let total: u32 = self.leaders.iter().map(|leader| leader.wins).sum();
+        self.pool * leader.wins as i128 / total as i128
+    }
+}
+
+/// The leaf a reward tree commits to for `player`'s `amount` in `epoch`
+pub fn epoch_leaf(env: &Env, epoch: u32, player: &Address, amount: i128) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &epoch.to_be_bytes());
+    data.append(&player.clone().to_xdr(env));
+    data.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+    env.crypto().sha256(&data).into()
+}
+
+/// Keep an epoch entry alive as long as match history
+fn extend(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+}
+
+/// `epoch`'s pool and leaderboard, if anything has accrued to it
+pub fn rewards(env: &Env, epoch: u32) -> Option<EpochRewards> {
+    env.storage().persistent().get(&DataKey::EpochRewards(epoch))
+}
+
+/// Store `epoch`'s pool and leaderboard
+pub fn save_rewards(env: &Env, epoch: u32, rewards: &EpochRewards) {
+    let key = DataKey::EpochRewards(epoch);
+    env.storage().persistent().set(&key, rewards);
+    extend(env, &key);
+}
+
+/// `player`'s wins in `epoch`
+pub fn wins(env: &Env, epoch: u32, player: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EpochWins(epoch, player.clone()))
+        .unwrap_or(0)
+}
+
+/// Set `player`'s wins in `epoch`
+pub fn set_wins(env: &Env, epoch: u32, player: &Address, wins: u32) {
+    let key = DataKey::EpochWins(epoch, player.clone());
+    env.storage().persistent().set(&key, &wins);
+    extend(env, &key);
+}
+
+/// Whether `player` has claimed their reward for `epoch`
+pub fn claimed(env: &Env, epoch: u32, player: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::EpochClaimed(epoch, player.clone()))
+}
+
+/// Record that `player` has claimed their reward for `epoch`
+pub fn set_claimed(env: &Env, epoch: u32, player: &Address) {
+    let key = DataKey::EpochClaimed(epoch, player.clone());
+    env.storage().persistent().set(&key, &true);
+    extend(env, &key);
+}
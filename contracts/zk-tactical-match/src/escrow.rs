@@ -0,0 +1,195 @@
+//! Token escrow for wagers.
+//!
+//! While a wager token is set (`set_wager_token`), a game's points are
+//! token units: starting it moves each player's points from the player to
+//! this contract. The `Escrow` record lives in persistent storage, apart
+//! from the temporary game entry, so the tokens stay accounted for even if
+//! the game expires. In a mixed-asset game (`start_mixed_game`) each player
+//! deposits their own token, and each deposit is paid out in its token.
+//!
+//! Settling a game turns the locked amounts into what each player is due:
+//! the pot for a win, their own stake back for a draw, cancellation or
+//! expiry, and a timeout's forfeiture share. Dues are pushed to the players
+//! straight away. A push the token refuses (the player's balance is frozen,
+//! or the account lacks a trustline) stays due until `claim_winnings` or
+//! `claim_refund` pulls it; the record is removed once nothing is.
+//!
+//! In a house game (`start_house_game`) player2 is the contract itself: its
+//! stake is locked in the token's bankroll rather than transferred, and
+//! what it is due on settlement goes back to the bankroll.
+//!
+//! Players with a prepaid balance in the token (`deposit_balance`) fund
+//! their side from it instead, without a transfer.
+//!
+//! Native XLM is wagered through its Stellar Asset Contract, whose ID
+//! `native_token` derives for the network the contract runs on. Its
+//! amounts are stroops (1 XLM = 10,000,000).
+//!
+//! A won pot pays the protocol fee (`set_fee_bps`) first. Fees are counted
+//! per token (`add_fees`) until `withdraw_fees` sends them to the fee
+//! collector.
+
+use soroban_sdk::{contracttype, token, Address, Bytes, Env, Vec};
+
+use crate::{balance, bankroll, DataKey, Error, OPEN_SESSION_TTL_LEDGERS};
+
+/// Tokens a game's players have locked in the contract (`get_escrow`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_token: Address,
+    pub player2_token: Address, // the same as `player1_token` unless mixed-asset
+    pub player1_amount: i128,
+    pub player2_amount: i128,
+    pub fee_bps: u32, // rake on a won pot, fixed when the game starts
+    pub settlement: Settlement,
+    pub dues: Vec<Due>, // unpaid once settled
+}
+
+/// An amount the escrow owes a player
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Due {
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// How a game's escrow was paid out
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Settlement {
+    Pending,  // the game is still in play
+    Winnings, // won: the winner takes the pot
+    Refund,   // drawn, cancelled, timed out or expired
+}
+
+impl Escrow {
+    /// Nothing left to pay out
+    pub fn is_paid(&self) -> bool {
+        self.settlement != Settlement::Pending && self.dues.is_empty()
+    }
+
+    /// Add `amount` of `token` to what is owed `player`
+    pub fn owe(&mut self, player: &Address, token: &Address, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+        for (i, mut due) in self.dues.iter().enumerate() {
+            if due.player == *player && due.token == *token {
+                due.amount += amount;
+                self.dues.set(i as u32, due);
+                return;
+            }
+        }
+        self.dues.push_back(Due {
+            player: player.clone(),
+            token: token.clone(),
+            amount,
+        });
+    }
+}
+
+/// XDR of `Asset::Native`
+const NATIVE_ASSET: [u8; 4] = [0; 4];
+
+/// The native XLM Stellar Asset Contract. Its ID is derived from the network
+/// passphrase, so it differs between testnet, futurenet and mainnet
+pub fn native_token(env: &Env) -> Address {
+    env.deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET))
+        .deployed_address()
+}
+
+/// Move both players' amounts into the contract and record them under
+/// `session_id`. Each player's amount comes out of their prepaid balance
+/// if it covers it, else they authorize their own transfer; the house's
+/// stake is locked in its bankroll instead, if that much is free
+pub fn deposit(env: &Env, session_id: u32, escrow: &Escrow) -> Result<(), Error> {
+    let contract = env.current_contract_address();
+    for (player, token, amount) in [
+        (&escrow.player1, &escrow.player1_token, escrow.player1_amount),
+        (&escrow.player2, &escrow.player2_token, escrow.player2_amount),
+    ] {
+        if *player == contract {
+            if bankroll::get(env, token).free() < amount {
+                return Err(Error::InsufficientBankroll);
+            }
+            bankroll::lock(env, token, amount);
+        } else if amount > 0 && !balance::debit(env, player, token, amount) {
+            token::Client::new(env, token).transfer(player, &contract, &amount);
+        }
+    }
+
+    save(env, session_id, escrow);
+    Ok(())
+}
+
+/// Move `amount` of `token` from `from` into the contract; `from` must
+/// have authorized it
+pub fn take(env: &Env, token: &Address, from: &Address, amount: i128) {
+    token::Client::new(env, token).transfer(from, env.current_contract_address(), &amount);
+}
+
+/// Send `amount` of `token` from the contract to `to`. Returns false,
+/// leaving the tokens where they are, if the token refuses the transfer
+pub fn push(env: &Env, token: &Address, to: &Address, amount: i128) -> bool {
+    let token = token::Client::new(env, token);
+    matches!(
+        token.try_transfer(&env.current_contract_address(), to, &amount),
+        Ok(Ok(()))
+    )
+}
+
+/// Send `amount` of `token` from the contract to `to`, failing the call if
+/// the token refuses
+pub fn send(env: &Env, token: &Address, to: &Address, amount: i128) {
+    token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+}
+
+/// Fees accrued in `token` and not yet withdrawn
+pub fn accrued_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccruedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `amount` to the fees accrued in `token` (a negative amount takes
+/// them off)
+pub fn add_fees(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::AccruedFees(token.clone());
+    let total = accrued_fees(env, token) + amount;
+    if total == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &total);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// Store `escrow` under `session_id`, or remove it once fully paid out
+pub fn save(env: &Env, session_id: u32, escrow: &Escrow) {
+    let key = DataKey::Escrow(session_id);
+    if escrow.is_paid() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, escrow);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// The escrow held for `session_id`, if any
+pub fn get(env: &Env, session_id: u32) -> Option<Escrow> {
+    env.storage().persistent().get(&DataKey::Escrow(session_id))
+}
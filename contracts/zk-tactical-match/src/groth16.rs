@@ -0,0 +1,180 @@
+//! Groth16 verification over BN254 using the Protocol 25 host functions.
+//!
+//! Byte layouts (all coordinates 32-byte big-endian, G2 as `x.c1 || x.c0 ||
+//! y.c1 || y.c0`, the same as the EVM precompiles and snarkjs exports):
+//!
+//! ```text
+//! proof = A (G1, 64) || B (G2, 128) || C (G1, 64)
+//! vk    = alpha (G1) || beta (G2) || gamma (G2) || delta (G2) || IC[0..=n] (G1)
+//! ```
+//!
+//! The proof is accepted iff
+//! `e(-A, B) * e(alpha, beta) * e(L, gamma) * e(C, delta) == 1`
+//! with `L = IC[0] + sum(x_i * IC[i + 1])`.
+//!
+//! Several proofs against the same key can share one pairing check: each
+//! proof's equation is raised to a weight `r_i` and the fixed-base terms are
+//! merged, giving `n + 3` pairings instead of `4n`:
+//!
+//! ```text
+//! prod e(-r_i A_i, B_i) * e(sum(r_i) alpha, beta)
+//!     * e(sum(r_i L_i), gamma) * e(sum(r_i C_i), delta) == 1
+//! ```
+//!
+//! The first weight is 1 and the others are 128-bit values hashed from every
+//! proof and input in the batch, so an invalid proof cannot be offset by
+//! another one.
+//!
+//! Points that are not on the curve make the host trap rather than return
+//! `false`, so malformed keys should be rejected when they are stored.
+
+use soroban_sdk::{
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
+    vec, Bytes, BytesN, Env, Vec,
+};
+
+const G1_LEN: u32 = 64;
+const G2_LEN: u32 = 128;
+
+/// Serialized proof length
+pub const PROOF_LEN: u32 = 2 * G1_LEN + G2_LEN;
+
+/// BN254 base field modulus, big-endian
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// BN254 scalar field modulus (public inputs must be below it), big-endian
+const SCALAR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Whether `value` (big-endian) is a canonical BN254 scalar field element
+pub fn is_field_element(value: &BytesN<32>) -> bool {
+    value.to_array() < SCALAR_MODULUS
+}
+
+/// Serialized verification key length for `n` public inputs
+pub fn vk_len(n: u32) -> u32 {
+    G1_LEN + 3 * G2_LEN + (n + 1) * G1_LEN
+}
+
+fn g1_at(env: &Env, bytes: &Bytes, offset: u32) -> Bn254G1Affine {
+    let mut buf = [0u8; G1_LEN as usize];
+    bytes
+        .slice(offset..offset + G1_LEN)
+        .copy_into_slice(&mut buf);
+    Bn254G1Affine::from_array(env, &buf)
+}
+
+fn g2_at(env: &Env, bytes: &Bytes, offset: u32) -> Bn254G2Affine {
+    let mut buf = [0u8; G2_LEN as usize];
+    bytes
+        .slice(offset..offset + G2_LEN)
+        .copy_into_slice(&mut buf);
+    Bn254G2Affine::from_array(env, &buf)
+}
+
+/// Negate a G1 point given as bytes: `(x, y) -> (x, p - y)`
+fn neg_g1_at(env: &Env, bytes: &Bytes, offset: u32) -> Bn254G1Affine {
+    let mut buf = [0u8; G1_LEN as usize];
+    bytes
+        .slice(offset..offset + G1_LEN)
+        .copy_into_slice(&mut buf);
+
+    let y = &mut buf[32..];
+    if y.iter().any(|b| *b != 0) {
+        let mut borrow = 0u16;
+        for (byte, modulus) in y.iter_mut().zip(FIELD_MODULUS.iter()).rev() {
+            let diff = *modulus as u16 + 0x100 - *byte as u16 - borrow;
+            *byte = diff as u8;
+            borrow = u16::from(diff < 0x100);
+        }
+    }
+    Bn254G1Affine::from_array(env, &buf)
+}
+
+/// `L = IC[0] + sum(x_i * IC[i + 1])`
+fn input_commitment(env: &Env, vk: &Bytes, public_inputs: &Vec<BytesN<32>>) -> Bn254G1Affine {
+    let bn254 = env.crypto().bn254();
+    let ic_offset = G1_LEN + 3 * G2_LEN;
+    let mut l = g1_at(env, vk, ic_offset);
+    for (i, input) in public_inputs.iter().enumerate() {
+        let ic = g1_at(env, vk, ic_offset + (i as u32 + 1) * G1_LEN);
+        l = bn254.g1_add(&l, &bn254.g1_mul(&ic, &Fr::from_bytes(input)));
+    }
+    l
+}
+
+/// Weight of the `index`-th proof (from 1) in a batch: a 128-bit value
+/// hashed from `seed`, the digest of every proof and input in the batch
+fn batch_weight(env: &Env, seed: &BytesN<32>, index: u32) -> Fr {
+    let mut data = Bytes::from_array(env, &seed.to_array());
+    data.append(&Bytes::from_array(env, &index.to_be_bytes()));
+    let mut weight = env.crypto().sha256(&data).to_array();
+    weight[..16].fill(0);
+    Fr::from_bytes(BytesN::from_array(env, &weight))
+}
+
+/// Verify `proof` against `vk` for the given public inputs.
+///
+/// Returns `false` for wrongly sized proofs/keys and for proofs that fail
+/// the pairing check.
+pub fn verify(env: &Env, vk: &Bytes, proof: &Bytes, public_inputs: &Vec<BytesN<32>>) -> bool {
+    verify_batch(env, vk, &[(proof.clone(), public_inputs.clone())])
+}
+
+/// Verify every `(proof, public_inputs)` in `batch` against `vk` with a
+/// single pairing check.
+///
+/// Returns `false` if any proof is wrongly sized or invalid, without saying
+/// which; check them one by one with [`verify`] to find out.
+pub fn verify_batch(env: &Env, vk: &Bytes, batch: &[(Bytes, Vec<BytesN<32>>)]) -> bool {
+    let Some(((first_proof, first_inputs), rest)) = batch.split_first() else {
+        return false;
+    };
+    let mut transcript = Bytes::new(env);
+    for (proof, public_inputs) in batch {
+        if proof.len() != PROOF_LEN || vk.len() != vk_len(public_inputs.len()) {
+            return false;
+        }
+        transcript.append(proof);
+        for input in public_inputs.iter() {
+            transcript.append(&input.into());
+        }
+    }
+    let seed: BytesN<32> = env.crypto().sha256(&transcript).into();
+
+    let bn254 = env.crypto().bn254();
+
+    // The first proof has weight 1, so a batch of one is the plain check
+    let mut g1_points = vec![env, neg_g1_at(env, first_proof, 0)];
+    let mut g2_points = vec![env, g2_at(env, first_proof, G1_LEN)];
+    let mut alpha = g1_at(env, vk, 0);
+    let mut l = input_commitment(env, vk, first_inputs);
+    let mut c = g1_at(env, first_proof, G1_LEN + G2_LEN);
+
+    for (i, (proof, public_inputs)) in rest.iter().enumerate() {
+        let r = batch_weight(env, &seed, i as u32 + 1);
+        g1_points.push_back(bn254.g1_mul(&neg_g1_at(env, proof, 0), &r));
+        g2_points.push_back(g2_at(env, proof, G1_LEN));
+
+        alpha = bn254.g1_add(&alpha, &bn254.g1_mul(&g1_at(env, vk, 0), &r));
+        l = bn254.g1_add(
+            &l,
+            &bn254.g1_mul(&input_commitment(env, vk, public_inputs), &r),
+        );
+        c = bn254.g1_add(&c, &bn254.g1_mul(&g1_at(env, proof, G1_LEN + G2_LEN), &r));
+    }
+
+    g1_points.push_back(alpha);
+    g2_points.push_back(g2_at(env, vk, G1_LEN));
+    g1_points.push_back(l);
+    g2_points.push_back(g2_at(env, vk, G1_LEN + G2_LEN));
+    g1_points.push_back(c);
+    g2_points.push_back(g2_at(env, vk, G1_LEN + 2 * G2_LEN));
+
+    bn254.pairing_check(g1_points, g2_points)
+}
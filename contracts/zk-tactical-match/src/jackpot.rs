@@ -0,0 +1,91 @@
+//! Progressive jackpot.
+//!
+//! With a jackpot configured (`set_jackpot_config`), `share_bps` of every
+//! protocol fee (after any referral and hub share) is set aside in a
+//! jackpot per token instead of accruing to the fee collector. The jackpot
+//! pays out on a rare run: a player who wins `wins_in_a_row` consecutive
+//! games on a tiebreak with both sides playing `tactic` (say AllOut against
+//! AllOut, three times in a row) is owed `payout_bps` of the jackpot in the
+//! token of the game that completes the run. Only games wagered in a token
+//! count toward a run; any other result ends it.
+//!
+//! A run must be won on luck, not arranged: only `Random` tiebreaks count
+//! (under `Player1` two colluding accounts could hand each other every tie),
+//! and only when both stakes are at least `min_stake`, so farming a run
+//! costs real fees.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{
+    DataKey, Game, Outcome, TiebreakPolicy, HISTORY_TTL_LEDGERS, OPEN_SESSION_TTL_LEDGERS,
+};
+
+/// How the jackpot fills and what wins it (`set_jackpot_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JackpotConfig {
+    pub share_bps: u32,     // of each fee, set aside in the jackpot
+    pub tactic: u32,        // the tactic both sides must play
+    pub wins_in_a_row: u32, // tiebreak wins with it that trigger a payout
+    pub payout_bps: u32,    // of the jackpot paid to the winner
+    pub min_stake: i128,    // each side's stake for a game to count
+}
+
+impl JackpotConfig {
+    /// Whether `game`, settled as `outcome`, is a random tiebreak win with
+    /// both sides playing the trigger tactic and staking at least `min_stake`
+    pub fn is_trigger(&self, game: &Game, outcome: Outcome) -> bool {
+        outcome != Outcome::Draw
+            && game.tiebreak == TiebreakPolicy::Random
+            && game.player1_points.min(game.player2_points) >= self.min_stake
+            && game.player1_score.is_some()
+            && game.player1_score == game.player2_score
+            && game.player1_tactic == Some(self.tactic)
+            && game.player2_tactic == Some(self.tactic)
+    }
+}
+
+/// The jackpot in `token`
+pub fn get(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Jackpot(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `amount` to the jackpot in `token` (a negative amount pays it out)
+pub fn add(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::Jackpot(token.clone());
+    let total = get(env, token) + amount;
+    if total == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &total);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// `player`'s current run of trigger wins
+pub fn run(env: &Env, player: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::JackpotRun(player.clone()))
+        .unwrap_or(0)
+}
+
+/// Set `player`'s run of trigger wins, removing the entry at 0
+pub fn set_run(env: &Env, player: &Address, run: u32) {
+    let key = DataKey::JackpotRun(player.clone());
+    if run == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &run);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+}
@@ -1,4 +1,7 @@
 #![no_std]
+// `#[contractimpl]` generates an argument helper per entry point that the
+// entry point's own `#[allow(clippy::too_many_arguments)]` does not reach
+#![allow(clippy::too_many_arguments)]
 
 //! # ZK Tactical Match
 //!
@@ -7,15 +10,94 @@
 //!
 //! **ZK Integration:**
 //! Players submit ZK proofs validating tactic ∈ [0-3] without revealing choice.
+//! Only a hash of each tactic is stored until both players have submitted;
+//! each then opens theirs with `reveal_tactic`.
 //! Uses Stellar Protocol 25 (X-Ray) primitives for on-chain verification.
 //!
 //! **Game Hub Integration:**
-//! Calls start_game() and end_game() on Game Hub contract.
+//! Calls start_game() and end_game() on Game Hub contract, and
+//! end_game_with_outcome() for draws.
+//!
+//! **Simulated Matches:**
+//! With a simulation image ID set, new games are scored by an off-chain zkVM
+//! program and resolved with its receipt (`resolve_with_receipt`, see
+//! `zkvm.rs`).
+//!
+//! **Multi-Round Matches:**
+//! `start_multi_round_game` starts a best-of-N match. Each player submits one
+//! recursive proof covering all N rounds (`submit_rounds`), whose public
+//! outputs are the N round tactic hashes, and opens them with `reveal_rounds`.
+//! With `start_committed_multi_round_game` players instead commit a Merkle
+//! root of all their rounds at the start and open one round at a time
+//! (`reveal_round`, see `merkle.rs`).
+//!
+//! **Hidden Stakes:**
+//! `start_hidden_stake_game` takes salted stake hashes with range proofs
+//! instead of stakes; they are revealed with `reveal_stake` and reach the hub
+//! only when the match is resolved.
+//!
+//! **Team Matches:**
+//! `start_team_game` starts a 2v2 match, played as a two-round match where
+//! each teammate duels their opposite number. A team moves in one
+//! transaction: `submit_team_move` takes both teammates' tactic hashes with
+//! one aggregated BLS12-381 signature (see `bls.rs`).
+//!
+//! **Anti-Sybil Mode:**
+//! With a `SybilGate` set, `start_ranked_game` takes a Semaphore-style
+//! uniqueness proof from each player: membership of a registered identity
+//! set, plus a nullifier of that identity in the current bracket, so one
+//! person cannot enter a bracket under several addresses.
+//!
+//! **Rating Brackets:**
+//! `start_bracket_game` restricts a match to a rating range: each player
+//! proves their rating lies within it against the ratings Merkle root the
+//! Game Hub publishes, without revealing the rating itself.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec, address_payload::AddressPayload, contract,
+    contractclient, contracterror, contractevent, contractimpl, contractmeta, contracttype,
+    panic_with_error, vec, xdr::ToXdr, Val
 };
 
+// Build info, read from the WASM to match a deployment to its source and
+// circuits. `circuit_version` follows `PROOF_VERSION`
+contractmeta!(key = "version", val = env!("CARGO_PKG_VERSION"));
+contractmeta!(key = "git_hash", val = env!("GIT_HASH"));
+contractmeta!(key = "circuit_version", val = "1");
+
+mod attestation;
+mod balance;
+mod bankroll;
+mod bls;
+mod disputes;
+mod epochs;
+mod escrow;
+mod groth16;
+mod jackpot;
+mod merkle;
+mod migration;
+mod oracle;
+mod referral;
+mod side_bets;
+mod staking;
+mod streak;
+mod ultrahonk;
+mod zkvm;
+
+pub use attestation::{Attestation, ATTESTATION_VERSION};
+pub use bankroll::Bankroll;
+pub use disputes::{Bond, Dispute, DisputeConfig, DisputeStatus};
+pub use epochs::{epoch_leaf, EpochConfig, EpochLeader, EpochRewards, EpochSchedule};
+pub use escrow::{Due, Escrow, Settlement};
+pub use jackpot::JackpotConfig;
+pub use merkle::{strategy_proof, strategy_root};
+pub use migration::{GameV0, GameV1, GameV2, SCHEMA_VERSION};
+pub use oracle::{Asset, OracleConfig, PriceData};
+pub use referral::{ReferralRewards, ReferralStats};
+pub use side_bets::{SideBet, SideBetPool, SideBetResult};
+pub use staking::{Stake, StakeTier, StakingConfig};
+pub use streak::{Streak, StreakBonus};
+
 // Import GameHub contract interface
 // This allows us to call into the GameHub contract
 #[contractclient(name = "GameHubClient")]
@@ -35,6 +117,62 @@ pub trait GameHub {
         session_id: u32,
         player1_won: bool
     );
+
+    fn end_game_with_outcome(
+        env: Env,
+        session_id: u32,
+        outcome: Outcome
+    );
+
+    /// Lower an active session's stakes, handing players the difference.
+    /// Version 3; hidden-stake results wait on older hubs
+    fn reduce_stakes(
+        env: Env,
+        session_id: u32,
+        player1_points: i128,
+        player2_points: i128
+    );
+
+    /// Allocate `game_id` a session ID unique across every game. Version
+    /// 4 hubs open sessions only under IDs allocated to the game
+    fn allocate_session(env: Env, game_id: Address) -> u32;
+
+    /// Interface version: 1 for `start_game` and `end_game`, 2 adds
+    /// `end_game_with_outcome`, 3 `reduce_stakes`, 4 `allocate_session`
+    fn version(env: Env) -> u32;
+
+    /// Where the hub collects its share of game revenue. Optional: a hub
+    /// without one takes no share (see `set_hub_share_bps`)
+    fn treasury(env: Env) -> Address;
+}
+
+// External ZK verifier interface
+// When a verifier is configured, proof verification is delegated to it
+#[contractclient(name = "VerifierClient")]
+pub trait Verifier {
+    fn verify(env: Env, proof: Bytes, public_inputs: Vec<BytesN<32>>) -> bool;
+}
+
+// Shared verification key registry (`contracts/vk-registry`)
+// When a key source is configured, the Groth16 key is resolved from it
+#[contractclient(name = "VkRegistryClient")]
+pub trait VkRegistry {
+    fn get_vk(env: Env, circuit_id: Symbol, version: u32) -> Bytes;
+}
+
+// Game Hubs that publish player ratings, as the root of a Merkle tree of
+// `(rating_key, rating)` leaves (`circuit/rating_bracket`)
+#[contractclient(name = "RatingsHubClient")]
+pub trait RatingsHub {
+    fn ratings_root(env: Env) -> Option<BytesN<32>>;
+}
+
+// Randomness beacon (`contracts/randomness-beacon`) the house draws its
+// tactics from
+#[contractclient(name = "BeaconClient")]
+pub trait Beacon {
+    fn pending_round(env: Env) -> u64;
+    fn get_randomness(env: Env, round: u64) -> BytesN<32>;
 }
 
 // ============================================================================
@@ -52,6 +190,109 @@ pub enum Error {
     GameAlreadyEnded = 5,
     InvalidTactic = 6,
     InvalidProof = 7,
+    InvalidSessionKey = 8,
+    InvalidSigner = 9,
+    SignatureExpired = 10,
+    InvalidNonce = 11,
+    GameNotSettled = 12,
+    InvalidVerificationKey = 13,
+    InvalidCommitment = 14,
+    NullifierUsed = 15,
+    ProofSessionMismatch = 16,
+    ProofPlayerMismatch = 17,
+    ProofExpired = 18,
+    ProofValidityTooLong = 19,
+    InvalidReveal = 20,
+    AlreadyRevealed = 21,
+    TacticNotRevealed = 22,
+    WrongProofSystem = 23,
+    InvalidStakeRange = 24,
+    StakeNotRevealed = 25,
+    StakesNotHidden = 26,
+    GameAlreadyExists = 27,
+    InvalidReceipt = 28,
+    ReceiptRequired = 29,
+    InvalidRoundCount = 30,
+    NotMultiRound = 31,
+    UnsupportedProofVersion = 32,
+    ProofTooLarge = 33,
+    DuplicateProof = 34,
+    SybilGateDisabled = 35,
+    IdentityAlreadyPlayed = 36,
+    InvalidTeam = 37,
+    NotTeamGame = 38,
+    InvalidTeamSignature = 39,
+    RatingsUnavailable = 40,
+    InvalidRatingBracket = 41,
+    DeadlineNotReached = 42,
+    NoForfeit = 43,
+    SessionNotExpired = 44,
+    InvalidDeadlines = 45,
+    SelfPlayNotAllowed = 46,
+    NotInitialized = 47,
+    HubNotSet = 48,
+    SessionAlreadyExists = 49,
+    SubmitDeadlinePassed = 50,
+    RevealDeadlinePassed = 51,
+    VerifierNotConfigured = 52,
+    UnsupportedAddress = 53,
+    InvalidTtl = 54,
+    InvalidPhase = 55,
+    Paused = 56,
+    HubNotNotified = 57,
+    TooManyOpenGames = 58,
+    InvalidGameLimit = 59,
+    WagerTooSmall = 60,
+    WagerTooLarge = 61,
+    InvalidWagerLimits = 62,
+    NoPendingAdmin = 63,
+    NoPendingUpgrade = 64,
+    UpgradeNotReady = 65,
+    InvalidUpgradeDelay = 66,
+    InvalidCouncil = 67,
+    NotCouncilMember = 68,
+    NotApproved = 69,
+    MissingRole = 70,
+    SchemaTooNew = 71,
+    AlreadyInitialized = 72,
+    NoPendingReinit = 73,
+    ReinitNotReady = 74,
+    InvalidHub = 75,
+    NothingToClaim = 76,
+    InvalidFee = 77,
+    OracleNotConfigured = 78,
+    PriceUnavailable = 79,
+    StakesNotEquivalent = 80,
+    InvalidOracleConfig = 81,
+    SideBetsClosed = 82,
+    SideBetsUnavailable = 83,
+    InvalidSideBet = 84,
+    InsufficientBankroll = 85,
+    InvalidBankrollAmount = 86,
+    ReferrerAlreadySet = 87,
+    InvalidReferrer = 88,
+    InvalidStreakBonus = 89,
+    InvalidJackpotConfig = 90,
+    InvalidBalanceAmount = 91,
+    InsufficientBalance = 92,
+    InvalidBatch = 93,
+    InvalidEpochConfig = 94,
+    EpochNotOver = 95,
+    InvalidEpochRoot = 96,
+    InvalidEpochProof = 97,
+    InvalidDisputeConfig = 98,
+    DisputeNotFound = 99,
+    DisputeWindowClosed = 100,
+    DisputeWindowOpen = 101,
+    InvalidBondAmount = 102,
+    InvalidOdds = 103,
+    InvalidStakingConfig = 104,
+    StakingNotConfigured = 105,
+    InvalidStakeAmount = 106,
+    StakeLocked = 107,
+    StakeCoolingDown = 108,
+    HouseBeaconNotSet = 109,
+    RandomnessNotReady = 110,
 }
 
 // ============================================================================
@@ -68,418 +309,8815 @@ pub enum Tactic {
     AllOut = 3,
 }
 
+/// One cell of the score matrix (`get_score_matrix`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreEntry {
+    pub tactic1: u32,
+    pub tactic2: u32,
+    pub score1: u32,
+    pub score2: u32,
+}
+
+/// Proving backend a game's proofs are checked with
+///
+/// `CommitReveal` games take no proof at all: players `commit_tactic` a
+/// salted `tactic_hash` and open it with `reveal_tactic`, for clients that
+/// cannot generate ZK proofs.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProofSystem {
+    Groth16 = 0,
+    UltraHonk = 1,
+    CommitReveal = 2,
+}
+
+/// How a drawn match is decided
+///
+/// `Draw` (the default) reports it to the hub as a draw. `Player1` is the
+/// original rule. `Random` seeds `env.prng()` with both tactic hashes and
+/// both revealed secrets, so neither player can steer it: each secret is
+/// committed before the other's is revealed. House games ignore the
+/// policy: the house wins their ties. Only `Random` ties count toward a
+/// jackpot run.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TiebreakPolicy {
+    Player1 = 0,
+    Random = 1,
+    Draw = 2,
+}
+
+/// Result of a settled game. Draws are reported to the hub's
+/// `end_game_with_outcome`, wins to its `end_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Player1Win = 0,
+    Player2Win = 1,
+    Draw = 2,
+}
+
+/// Where a game is in its lifecycle. Entrypoints only act on games in the
+/// phases they expect, failing with `InvalidPhase` otherwise
+///
+/// Games start `Created` and move to `AwaitingOpponent` and `BothSubmitted`
+/// as players submit (committed strategies start `BothSubmitted`). Reveals
+/// happen in `BothSubmitted`, and settling ends the game `Resolved`, or
+/// `TimedOut` for a forfeit by `resolve_timeout`, or `Cancelled` for games
+/// called off before play with `cancel_game`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GamePhase {
+    Created = 0,
+    AwaitingOpponent = 1,
+    BothSubmitted = 2,
+    Resolved = 3,
+    Cancelled = 4,
+    TimedOut = 5,
+}
+
+/// Whether a game has been settled, and how. A contract type can't hold an
+/// `Option` of another contract type, so `Game` keeps this instead; read it
+/// through `Game::outcome`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameResult {
+    Pending,
+    Settled(Outcome),
+}
+
+/// Public inputs of the tactic circuit, in the order the circuit declares
+/// them (public parameters, then its return values). The tactic itself is a
+/// private input; `tactic_hash` commits to it until `reveal_tactic`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicInputs {
+    pub session_id: u32,
+    pub player_binding: BytesN<32>, // See `player_binding`
+    pub valid_until_ledger: u32,    // 0 = no expiry, see `submit_tactic`
+    pub commitment: BytesN<32>,
+    pub nullifier: BytesN<32>,
+    pub tactic_hash: BytesN<32>, // See `tactic_hash`
+}
+
+impl PublicInputs {
+    /// One 32-byte big-endian field element per input
+    pub fn to_fields(&self, env: &Env) -> Vec<BytesN<32>> {
+        vec![
+            env,
+            u32_to_field(env, self.session_id),
+            self.player_binding.clone(),
+            u32_to_field(env, self.valid_until_ledger),
+            self.commitment.clone(),
+            self.nullifier.clone(),
+            self.tactic_hash.clone(),
+        ]
+    }
+
+    /// The same field elements concatenated, as UltraHonk verifiers take them
+    pub fn to_bytes(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        for field in self.to_fields(env).iter() {
+            bytes.append(&field.into());
+        }
+        bytes
+    }
+}
+
+/// Public inputs of the recursive rounds circuit, which verifies one tactic
+/// proof per round of a multi-round match. `commitment` and `nullifier`
+/// aggregate the inner proofs' outputs; `tactic_hashes` are the rounds' tactic
+/// hashes in order, opened by `reveal_rounds`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundInputs {
+    pub session_id: u32,
+    pub player_binding: BytesN<32>, // See `player_binding`
+    pub valid_until_ledger: u32,    // As for `PublicInputs`
+    pub commitment: BytesN<32>,
+    pub nullifier: BytesN<32>,
+    pub tactic_hashes: Vec<BytesN<32>>, // One per round, see `tactic_hash`
+}
+
+impl RoundInputs {
+    /// One 32-byte big-endian field element per input, round hashes last
+    pub fn to_fields(&self, env: &Env) -> Vec<BytesN<32>> {
+        let mut fields = vec![
+            env,
+            u32_to_field(env, self.session_id),
+            self.player_binding.clone(),
+            u32_to_field(env, self.valid_until_ledger),
+            self.commitment.clone(),
+            self.nullifier.clone(),
+        ];
+        fields.append(&self.tactic_hashes);
+        fields
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
     pub player1: Address,
     pub player2: Address,
-    pub player1_points: i128,
+    pub player1_points: i128, // 0 until resolved in hidden-stake games
     pub player2_points: i128,
-    pub player1_proof_hash: Option<BytesN<32>>, // Hash of ZK proof
+    pub proof_system: ProofSystem, // Fixed when the game starts
+    pub batch_verification: bool,  // Likewise, see `set_batch_verification`
+    pub simulation_image_id: Option<BytesN<32>>, // Likewise; set = scored by a zkVM receipt
+    pub tiebreak: TiebreakPolicy,  // Likewise, see `set_tiebreak_policy`
+    pub auto_resolve: bool,        // Likewise, see `set_auto_resolve`
+    pub submit_deadline_ledger: u32, // Last ledger to submit in, see `resolve_timeout`
+    pub resolve_deadline_ledger: u32, // Last ledger to reveal in, likewise
+    pub player1_commitment: Option<BytesN<32>>, // Poseidon2 commitment (circuit output)
+    pub player2_commitment: Option<BytesN<32>>,
+    pub player1_proof_hash: Option<BytesN<32>>, // keccak256 of the submitted proof
     pub player2_proof_hash: Option<BytesN<32>>,
+    pub player1_tactic_hash: Option<BytesN<32>>, // Opened by `reveal_tactic`
+    pub player2_tactic_hash: Option<BytesN<32>>,
     pub player1_tactic: Option<u32>, // Revealed after both submit
     pub player2_tactic: Option<u32>,
-    pub player1_score: Option<u32>,
+    pub player1_score: Option<u32>, // Rounds won in multi-round games
     pub player2_score: Option<u32>,
-    pub winner: Option<Address>,
+    pub hub_notified: bool, // The hub has the result, see `retry_hub_notify`
+    pub phase: GamePhase,   // See `GamePhase`
+    pub result: GameResult, // Settled once resolved
+    pub last_activity_ledger: u32, // Ledger of the last change, see `get_last_activity`
+    pub wager_token: Option<Address>, // Token escrowing the points, if any
 }
 
+/// A game as anyone may see it (`get_game_public`)
+///
+/// Until the game is settled, `game` has its tactics and proof hashes
+/// masked to `None`; the reveal flags say who has revealed everything
+/// resolution needs from them.
 #[contracttype]
-#[derive(Clone)]
-pub enum DataKey {
-    Game(u32),
-    GameHubAddress,
-    Admin,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicGame {
+    pub game: Game,
+    pub player1_revealed: bool,
+    pub player2_revealed: bool,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
+/// How one session of a batch went (`resolve_batch`, `claim_batch`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchResult {
+    Resolved(u32, Outcome), // session ID, who won
+    Claimed(u32, i128),     // session ID, amount paid out
+    Failed(u32, u32),       // session ID, the `Error` code it failed with
+}
 
-const GAME_TTL_LEDGERS: u32 = 518_400;
+impl Game {
+    /// The game's result, once settled
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self.result {
+            GameResult::Pending => None,
+            GameResult::Settled(outcome) => Some(outcome),
+        }
+    }
 
-// ============================================================================
-// Score Matrix
-// ============================================================================
-// Strategic interaction: [Player1 Tactic][Player2 Tactic] = (P1 Score, P2 Score)
-// Matrix creates rock-paper-scissors-like dynamics with scoring depth
+    /// Whether the game has ended, settled or not
+    pub fn is_over(&self) -> bool {
+        matches!(
+            self.phase,
+            GamePhase::Resolved | GamePhase::Cancelled | GamePhase::TimedOut
+        )
+    }
 
-fn get_score(tactic1: u32, tactic2: u32) -> (u32, u32) {
-    match (tactic1, tactic2) {
-        // Defensive vs X
-        (0, 0) => (0, 0), // Both defensive = stalemate
-        (0, 1) => (0, 1), // Defensive loses to Balanced
-        (0, 2) => (1, 1), // Defensive draws with Aggressive
-        (0, 3) => (2, 2), // Defensive draws with AllOut (chaos)
-        
-        // Balanced vs X
-        (1, 0) => (1, 0), // Balanced beats Defensive
-        (1, 1) => (1, 1), // Both balanced = draw
-        (1, 2) => (1, 2), // Balanced loses to Aggressive
-        (1, 3) => (2, 3), // Balanced loses to AllOut
-        
-        // Aggressive vs X
-        (2, 0) => (1, 1), // Aggressive draws with Defensive
-        (2, 1) => (2, 1), // Aggressive beats Balanced
-        (2, 2) => (2, 2), // Both aggressive = high scoring draw
-        (2, 3) => (3, 3), // Aggressive draws with AllOut (chaos)
-        
-        // AllOut vs X
-        (3, 0) => (2, 2), // AllOut draws with Defensive (chaos)
-        (3, 1) => (3, 2), // AllOut beats Balanced
-        (3, 2) => (3, 3), // AllOut draws with Aggressive (chaos)
-        (3, 3) => (4, 4), // Both AllOut = maximum chaos draw
-        
-        _ => (0, 0), // Invalid tactics
+    /// Fail with `InvalidPhase` unless the game is in `phase`
+    fn require_phase(&self, phase: GamePhase) -> Result<(), Error> {
+        if self.phase != phase {
+            return Err(Error::InvalidPhase);
+        }
+        Ok(())
+    }
+
+    /// Fail with `InvalidPhase` once both players have submitted, or the
+    /// game is over
+    fn require_submissions_open(&self) -> Result<(), Error> {
+        match self.phase {
+            GamePhase::Created | GamePhase::AwaitingOpponent => Ok(()),
+            _ => Err(Error::InvalidPhase),
+        }
+    }
+
+    /// Advance past one player's submission
+    fn advance_submission(&mut self) {
+        self.phase = match self.phase {
+            GamePhase::Created => GamePhase::AwaitingOpponent,
+            _ => GamePhase::BothSubmitted,
+        };
+    }
+
+    /// Record the result, ending the game in `phase`
+    fn settle(&mut self, outcome: Outcome, phase: GamePhase) {
+        self.result = GameResult::Settled(outcome);
+        self.phase = phase;
     }
 }
 
-// ============================================================================
-// Contract Definition
-// ============================================================================
+/// Rounds of a game started with `start_multi_round_game` or
+/// `start_committed_multi_round_game`. The game's own tactic hash fields hold
+/// the sha256 of each player's round hashes, or their strategy roots, and its
+/// tactics stay `None`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiRound {
+    pub rounds: u32,
+    pub strategy_committed: bool, // Rounds opened one by one with `reveal_round`
+    pub player1_tactic_hashes: Option<Vec<BytesN<32>>>, // From `submit_rounds`
+    pub player2_tactic_hashes: Option<Vec<BytesN<32>>>,
+    pub player1_tactics: Option<Vec<u32>>, // Revealed so far, in round order
+    pub player2_tactics: Option<Vec<u32>>,
+}
 
-#[contract]
-pub struct ZkTacticalMatchContract;
+impl MultiRound {
+    /// Rounds won by each player once both have revealed. Each round is
+    /// scored with the matrix; a drawn round counts for neither
+    pub fn rounds_won(&self) -> Option<(u32, u32)> {
+        let (Some(tactics1), Some(tactics2)) = (&self.player1_tactics, &self.player2_tactics)
+        else {
+            return None;
+        };
+        if tactics1.len() != self.rounds || tactics2.len() != self.rounds {
+            return None;
+        }
 
-#[contractimpl]
-impl ZkTacticalMatchContract {
-    /// Initialize the contract with GameHub address and admin
-    ///
-    /// # Arguments
-    /// * `admin` - Admin address (can upgrade contract)
-    /// * `game_hub` - Address of the GameHub contract
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        let mut won = (0, 0);
+        for (tactic1, tactic2) in tactics1.iter().zip(tactics2.iter()) {
+            let (score1, score2) = get_score(tactic1, tactic2);
+            if score1 > score2 {
+                won.0 += 1;
+            } else if score2 > score1 {
+                won.1 += 1;
+            }
+        }
+        Some(won)
     }
+}
 
-    /// Start a new ZK Tactical Match between two players.
-    ///
-    /// # Arguments
-    /// * `session_id` - Unique session identifier
-    /// * `player1` - First player address
-    /// * `player2` - Second player address
-    /// * `player1_points` - Betting amount for player 1
-    /// * `player2_points` - Betting amount for player 2
-    pub fn start_game(
-        env: Env,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    ) -> Result<(), Error> {
-        if player1 == player2 {
-            panic!("Cannot play against yourself");
+/// A submission whose proof is checked at `resolve_match` (batch
+/// verification), kept until the game resolves
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingProof {
+    pub inputs: PublicInputs,
+    pub proof: Bytes,
+}
+
+/// Where the Groth16 verification key is resolved from: the entry
+/// `(circuit_id, version)` of a `vk-registry` contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VkSource {
+    pub registry: Address,
+    pub circuit_id: Symbol,
+    pub version: u32,
+}
+
+/// A tactic proof tagged with the circuit version and backend it was made
+/// for, as taken by `submit_tactic_envelope`. Versions below
+/// `get_min_proof_version()` or above `PROOF_VERSION` are rejected, so a
+/// circuit upgrade can retire proofs from the old one
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofEnvelope {
+    pub version: u32,
+    pub system: ProofSystem,
+    pub payload: Bytes,
+}
+
+/// A player's stake in a hidden-stake game: the hash it is committed to
+/// (see `stake_hash`) and a proof that it lies within the game's bounds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeCommitment {
+    pub stake_hash: BytesN<32>,
+    pub proof: Bytes,
+}
+
+/// What the hub was told when a session started. Kept in persistent storage
+/// until the result is reported, so a session whose game entry expired
+/// before it was resolved can still be reconciled (`abandon_expired`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenSession {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+/// A game's deadlines, for countdowns (`get_deadlines`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deadlines {
+    pub submit_deadline_ledger: u32,
+    pub resolve_deadline_ledger: u32,
+}
+
+/// Smallest and largest points a player may stake on a game
+/// (`set_wager_limits`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WagerLimits {
+    pub min_wager: i128,
+    pub max_wager: i128,
+}
+
+/// The contract's operational settings in one read (`get_config`)
+///
+/// The TTLs are the ledgers each game entry, and each player's match
+/// history and stats, are kept alive for on every write.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    pub admin: Address,
+    pub hub: Address,
+    pub verifier: Option<Address>,
+    pub proof_system: ProofSystem,
+    pub paused: bool,
+    pub wager_limits: WagerLimits,
+    pub wager_token: Option<Address>,
+    pub fee_bps: u32,
+    pub fee_collector: Address,
+    pub side_bet_rake_bps: u32,
+    pub referral_share_bps: u32,
+    pub hub_share_bps: u32,
+    pub oracle: Option<Address>,
+    pub max_open_games: Option<u32>,
+    pub timeout_ledgers: u32,
+    pub game_ttl_ledgers: u32,
+    pub history_ttl_ledgers: u32,
+    pub upgrade_delay_ledgers: u32,
+}
+
+/// A settled game as kept in each player's match history, after the game
+/// entry itself has expired (`get_match_record`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchRecord {
+    pub session_id: u32,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub player1_score: Option<u32>, // None for forfeits
+    pub player2_score: Option<u32>,
+    pub outcome: Outcome,
+    pub ledger: u32, // Settled in
+}
+
+/// A player's lifetime record, for profile pages
+///
+/// `forfeits` are the `losses` that came from `resolve_timeout`.
+/// `tactic_counts` holds how often each revealed tactic was played, indexed
+/// by `Tactic`; multi-round games and unrevealed tactics are not counted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub forfeits: u32,
+    pub total_wagered: i128,
+    pub tactic_counts: Vec<u32>,
+    pub favorite_tactic: Option<u32>, // Most played; the lower tactic on ties
+}
+
+impl PlayerStats {
+    fn new(env: &Env) -> Self {
+        PlayerStats {
+            games: 0,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+            forfeits: 0,
+            total_wagered: 0,
+            tactic_counts: vec![env, 0, 0, 0, 0],
+            favorite_tactic: None,
+        }
+    }
+
+    /// Count a settled game in which the player wagered `points` and played
+    /// `tactic`, if revealed
+    fn record(&mut self, result: MatchResult, forfeit: bool, points: i128, tactic: Option<u32>) {
+        self.games += 1;
+        match result {
+            MatchResult::Won => self.wins += 1,
+            MatchResult::Lost => self.losses += 1,
+            MatchResult::Drawn => self.draws += 1,
+        }
+        if forfeit && result == MatchResult::Lost {
+            self.forfeits += 1;
+        }
+        self.total_wagered = self.total_wagered.saturating_add(points);
+
+        let Some(tactic) = tactic else {
+            return;
+        };
+        let count = self.tactic_counts.get(tactic).unwrap_or(0) + 1;
+        self.tactic_counts.set(tactic, count);
+
+        let mut favorite = 0;
+        for (tactic, count) in self.tactic_counts.iter().enumerate() {
+            if count > self.tactic_counts.get(favorite).unwrap_or(0) {
+                favorite = tactic as u32;
+            }
+        }
+        self.favorite_tactic = Some(favorite);
+    }
+}
+
+/// Contract-wide totals for dashboards (`get_global_stats`)
+///
+/// `total_volume` is the points committed across every game started.
+/// `resolution_rate_bps` is the share of ended games that were played out
+/// (resolved or timed out) rather than cancelled or abandoned, in basis
+/// points. Games that expire unresolved stay in `active_sessions` until
+/// `abandon_expired` closes them.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GlobalStats {
+    pub games_started: u32,
+    pub games_resolved: u32,
+    pub games_timed_out: u32,
+    pub games_cancelled: u32,
+    pub games_abandoned: u32,
+    pub total_volume: i128,
+    pub active_sessions: u32,
+    pub resolution_rate_bps: u32,
+}
+
+impl GlobalStats {
+    /// Recompute the derived fields from the counters
+    fn refresh(&mut self) {
+        let played = self.games_resolved + self.games_timed_out;
+        let ended = played + self.games_cancelled + self.games_abandoned;
+        self.active_sessions = self.games_started.saturating_sub(ended);
+        self.resolution_rate_bps = match ended {
+            0 => 0,
+            _ => (played as u64 * 10_000 / ended as u64) as u32,
+        };
+    }
+}
+
+/// How a game ended for one player
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchResult {
+    Won = 0,
+    Lost = 1,
+    Drawn = 2,
+}
+
+/// A `MatchRecord` from one player's side, as listed by `get_history`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSummary {
+    pub index: u32, // In the player's history; pass as the next cursor
+    pub session_id: u32,
+    pub opponent: Address,
+    pub points: i128,
+    pub opponent_points: i128,
+    pub score: Option<u32>, // None for forfeits
+    pub opponent_score: Option<u32>,
+    pub result: MatchResult,
+    pub ledger: u32,
+}
+
+impl MatchRecord {
+    /// This record as `player` saw it
+    pub fn summary(&self, index: u32, player: &Address) -> GameSummary {
+        let as_player1 = self.player1 == *player;
+        let result = match (self.outcome, as_player1) {
+            (Outcome::Draw, _) => MatchResult::Drawn,
+            (Outcome::Player1Win, true) | (Outcome::Player2Win, false) => MatchResult::Won,
+            _ => MatchResult::Lost,
+        };
+        let (opponent, points, opponent_points, score, opponent_score) = if as_player1 {
+            (
+                self.player2.clone(),
+                self.player1_points,
+                self.player2_points,
+                self.player1_score,
+                self.player2_score,
+            )
+        } else {
+            (
+                self.player1.clone(),
+                self.player2_points,
+                self.player1_points,
+                self.player2_score,
+                self.player1_score,
+            )
+        };
+        GameSummary {
+            index,
+            session_id: self.session_id,
+            opponent,
+            points,
+            opponent_points,
+            score,
+            opponent_score,
+            result,
+            ledger: self.ledger,
         }
+    }
+}
+
+/// Published when a game starts, by every `start_*` entrypoint. Like the
+/// other lifecycle events it is indexed by session and players
+#[contractevent]
+pub struct GameStarted {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player1: Address,
+    #[topic]
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+/// Published when a player (or team captain) submits, with the phase the
+/// game moved to. Says nothing about the tactic
+#[contractevent]
+pub struct TacticSubmitted {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub phase: GamePhase,
+}
 
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+/// Published when a game is settled by resolution, before the hub is told
+#[contractevent]
+pub struct MatchResolved {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player1: Address,
+    #[topic]
+    pub player2: Address,
+    pub outcome: Outcome,
+    pub player1_score: Option<u32>,
+    pub player2_score: Option<u32>,
+}
+
+/// Published when a game's wagers are locked in escrow, right after
+/// `GameStarted`
+#[contractevent]
+pub struct EscrowFunded {
+    #[topic]
+    pub session_id: u32,
+    pub player1_token: Address,
+    pub player2_token: Address,
+    pub player1_amount: i128,
+    pub player2_amount: i128,
+}
+
+/// Published when a settled game's escrow pays a player straight away
+#[contractevent]
+pub struct WinningsPaid {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when the token refuses a payout at settlement; the amount
+/// stays in escrow for `claim_winnings`
+#[contractevent]
+pub struct WinningsHeld {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `claim_winnings` pays out an amount held at settlement
+#[contractevent]
+pub struct WinningsClaimed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when a drawn, cancelled, timed-out or expired game's escrow
+/// refunds a player straight away
+#[contractevent]
+pub struct RefundPaid {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when the token refuses a refund; the amount stays in escrow
+/// for `claim_refund`
+#[contractevent]
+pub struct RefundHeld {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `claim_refund` pays out a held refund
+#[contractevent]
+pub struct RefundClaimed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when both players call a game off with `cancel_game`
+#[contractevent]
+pub struct GameCancelled {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player1: Address,
+    #[topic]
+    pub player2: Address,
+}
+
+/// Published when `resolve_timeout` settles a game by forfeit
+#[contractevent]
+pub struct TimedOut {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player1: Address,
+    #[topic]
+    pub player2: Address,
+    pub winner: Address,
+    pub outcome: Outcome,
+}
+
+/// Published by `abandon_expired`: the hub holds these points for a session
+/// that can no longer be resolved
+#[contractevent]
+pub struct SessionAbandoned {
+    pub session_id: u32,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+/// Published when a settled game's result could not be reported to the hub,
+/// which has to be retried with `retry_hub_notify`
+#[contractevent]
+pub struct HubNotifyFailed {
+    pub session_id: u32,
+    pub outcome: Outcome,
+}
+
+/// Published when the admin proposes handing over to `new_admin`, who has
+/// to `accept_admin` before anything changes
+#[contractevent]
+pub struct AdminProposed {
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+/// Published when a proposed admin accepts and takes over
+#[contractevent]
+pub struct AdminTransferred {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Published when an upgrade is proposed, giving players until
+/// `executable_ledger` to settle their games and leave
+#[contractevent]
+pub struct UpgradeProposed {
+    pub wasm_hash: BytesN<32>,
+    pub executable_ledger: u32,
+}
+
+/// Published when a pending upgrade is withdrawn
+#[contractevent]
+pub struct UpgradeCancelled {
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Published when `execute_upgrade` swaps the contract's code
+#[contractevent]
+pub struct UpgradeExecuted {
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Published when `set_hub` points the contract at another hub
+#[contractevent]
+pub struct HubChanged {
+    pub previous_hub: Option<Address>,
+    pub new_hub: Address,
+}
+
+/// Published by `pause` and `unpause`, including calls that change
+/// nothing
+#[contractevent]
+pub struct PauseChanged {
+    pub was_paused: bool,
+    pub paused: bool,
+}
+
+/// Published when `caller` sets new wager limits
+#[contractevent]
+pub struct WagerLimitsChanged {
+    pub caller: Address,
+    pub previous: WagerLimits,
+    pub limits: WagerLimits,
+}
+
+/// Published when `caller` changes the token new games are wagered in
+#[contractevent]
+pub struct WagerTokenChanged {
+    pub caller: Address,
+    pub previous: Option<Address>,
+    pub token: Option<Address>,
+}
+
+/// Published when `caller` changes the protocol fee on new games
+#[contractevent]
+pub struct FeeChanged {
+    pub caller: Address,
+    pub previous_bps: u32,
+    pub fee_bps: u32,
+}
+
+/// Published when `caller` changes where withdrawn fees are sent
+#[contractevent]
+pub struct FeeCollectorChanged {
+    pub caller: Address,
+    pub previous: Address,
+    pub collector: Address,
+}
+
+/// Published when `caller` sends a token's accrued fees to the collector
+#[contractevent]
+pub struct FeesWithdrawn {
+    #[topic]
+    pub token: Address,
+    pub caller: Address,
+    pub collector: Address,
+    pub amount: i128,
+}
+
+/// Published when `caller` changes the price oracle for mixed-asset games
+/// (`oracle` None: mixed-asset games are off, and the limits read 0)
+#[contractevent]
+pub struct OracleConfigChanged {
+    pub caller: Address,
+    pub previous: Option<Address>,
+    pub oracle: Option<Address>,
+    pub tolerance_bps: u32,
+    pub max_age_seconds: u64,
+}
+
+/// Published when `bettor` stakes `amount` on `player` winning a game
+#[contractevent]
+pub struct SideBetPlaced {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub bettor: Address,
+    pub player: Address,
+    pub amount: i128,
+}
+
+/// Published when a game's side bets settle, `rake` going to the winner
+#[contractevent]
+pub struct SideBetsSettled {
+    #[topic]
+    pub session_id: u32,
+    pub result: SideBetResult,
+    pub rake: i128,
+}
+
+/// Published when a side-bet pool pays `recipient`: a bettor's payout or
+/// refund, or the rake to the match winner
+#[contractevent]
+pub struct SideBetPaid {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `caller` changes the rake on new side-bet pools
+#[contractevent]
+pub struct SideBetRakeChanged {
+    pub caller: Address,
+    pub previous_bps: u32,
+    pub rake_bps: u32,
+}
+
+/// Published when `provider` adds `amount` to a token's house bankroll,
+/// for `shares`
+#[contractevent]
+pub struct BankrollDeposited {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub provider: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+
+/// Published when `provider` redeems `shares` of a token's house bankroll
+/// for `amount`
+#[contractevent]
+pub struct BankrollWithdrawn {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub provider: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+
+/// Published when `player` adds `amount` to their prepaid balance in
+/// `token`, bringing it to `balance`
+#[contractevent]
+pub struct BalanceDeposited {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Published when `player` takes `amount` out of their prepaid balance in
+/// `token`, leaving `balance`
+#[contractevent]
+pub struct BalanceWithdrawn {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Published when `player` names the address that referred them
+#[contractevent]
+pub struct ReferrerRegistered {
+    #[topic]
+    pub player: Address,
+    pub referrer: Address,
+}
+
+/// Published when `referrer` is credited a share of the fee taken from
+/// `referee`'s stake
+#[contractevent]
+pub struct ReferralRewarded {
+    #[topic]
+    pub referrer: Address,
+    pub referee: Address,
+    pub session_id: u32,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when a referrer's rewards in `token` are sent to them
+#[contractevent]
+pub struct ReferralRewardsClaimed {
+    #[topic]
+    pub referrer: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when a won pot's fee sends the hub's share, `amount`, to its
+/// `treasury`
+#[contractevent]
+pub struct HubRevenuePaid {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub treasury: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `caller` changes the epoch rules (`token` None and the
+/// rest 0: no epochs). The next epoch is `first_epoch`
+#[contractevent]
+pub struct EpochConfigChanged {
+    pub caller: Address,
+    pub token: Option<Address>,
+    pub length_ledgers: u32,
+    pub share_bps: u32,
+    pub top_n: u32,
+    pub first_epoch: u32,
+}
+
+/// Published when `caller` publishes the reward tree for an epoch
+#[contractevent]
+pub struct EpochRootSet {
+    #[topic]
+    pub epoch: u32,
+    pub caller: Address,
+    pub root: BytesN<32>,
+    pub leaves: u32,
+}
+
+/// Published when `player` claims `amount` of an epoch's pool
+#[contractevent]
+pub struct EpochRewardClaimed {
+    #[topic]
+    pub epoch: u32,
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `caller` changes who rules on disputes (`module` None:
+/// settlements cannot be disputed)
+#[contractevent]
+pub struct DisputeConfigChanged {
+    pub caller: Address,
+    pub module: Option<Address>,
+    pub window_ledgers: u32,
+}
+
+/// Published when `player` adds `amount` to their bond in `token`,
+/// bringing it to `bond`
+#[contractevent]
+pub struct BondPosted {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub bond: i128,
+}
+
+/// Published when `player` takes `amount` out of their bond in `token`,
+/// leaving `bond`
+#[contractevent]
+pub struct BondWithdrawn {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub bond: i128,
+}
+
+/// Published when a won pot settles into its dispute window, locking
+/// `locked` of the winner's bond until `deadline_ledger`
+#[contractevent]
+pub struct DisputeWindowOpened {
+    #[topic]
+    pub session_id: u32,
+    pub winner: Address,
+    pub token: Address,
+    pub locked: i128,
+    pub deadline_ledger: u32,
+}
+
+/// Published when the loser disputes a settlement
+#[contractevent]
+pub struct SettlementDisputed {
+    #[topic]
+    pub session_id: u32,
+    pub loser: Address,
+    pub module: Address,
+}
+
+/// Published when the dispute module rules; an upheld dispute pays the
+/// loser `clawed_back` out of the winner's bond
+#[contractevent]
+pub struct DisputeResolved {
+    #[topic]
+    pub session_id: u32,
+    pub upheld: bool,
+    pub winner: Address,
+    pub loser: Address,
+    pub token: Address,
+    pub clawed_back: i128,
+}
+
+/// Published when a dispute window closes undisputed, releasing
+/// `released` of the winner's bond
+#[contractevent]
+pub struct SettlementFinalized {
+    #[topic]
+    pub session_id: u32,
+    pub winner: Address,
+    pub released: i128,
+}
+
+/// Published when `caller` changes staking (`token` None, the rest 0: no
+/// staking)
+#[contractevent]
+pub struct StakingConfigChanged {
+    pub caller: Address,
+    pub token: Option<Address>,
+    pub tiers: u32,
+    pub lockup_ledgers: u32,
+    pub cooldown_ledgers: u32,
+}
+
+/// Published when `player` stakes `amount`, bringing their active stake
+/// to `stake`, all locked until `locked_until_ledger`
+#[contractevent]
+pub struct Staked {
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+    pub stake: i128,
+    pub locked_until_ledger: u32,
+}
+
+/// Published when `player` unstakes `amount`, bringing what is in its
+/// cooldown to `unstaking`, withdrawable from `cooldown_end_ledger`
+#[contractevent]
+pub struct Unstaked {
+    #[topic]
+    pub player: Address,
+    pub amount: i128,
+    pub unstaking: i128,
+    pub cooldown_end_ledger: u32,
+}
+
+/// Published when `player` withdraws `amount` of `token` after its cooldown
+#[contractevent]
+pub struct StakeWithdrawn {
+    #[topic]
+    pub player: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when a pot's winner pays a fee cut by `discount_bps` for
+/// their staking tier
+#[contractevent]
+pub struct FeeDiscounted {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+    pub discount_bps: u32,
+}
+
+/// Published when `caller` changes the hub's share of the fee
+#[contractevent]
+pub struct HubShareChanged {
+    pub caller: Address,
+    pub previous_bps: u32,
+    pub share_bps: u32,
+}
+
+/// Published when `caller` changes the referrers' share of the fee
+#[contractevent]
+pub struct ReferralShareChanged {
+    pub caller: Address,
+    pub previous_bps: u32,
+    pub share_bps: u32,
+}
+
+/// Published when `player` wins a pot on a streak of `streak` wins and is
+/// owed a bonus out of the accrued fees
+#[contractevent]
+pub struct StreakBonusAwarded {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub streak: u32,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `caller` changes the streak bonus (all 0: no bonus)
+#[contractevent]
+pub struct StreakBonusChanged {
+    pub caller: Address,
+    pub min_streak: u32,
+    pub step_bps: u32,
+    pub max_bps: u32,
+}
+
+/// Published when `player` completes a jackpot run and is owed `amount`
+/// of the jackpot in `token`
+#[contractevent]
+pub struct JackpotWon {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub run: u32,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `caller` changes the jackpot rules (`enabled` false and
+/// the rest 0: no jackpot)
+#[contractevent]
+pub struct JackpotConfigChanged {
+    pub caller: Address,
+    pub enabled: bool,
+    pub share_bps: u32,
+    pub tactic: u32,
+    pub wins_in_a_row: u32,
+    pub payout_bps: u32,
+    pub min_stake: i128,
+}
+
+/// Published when a reinitialization is proposed, executable from
+/// `executable_ledger`
+#[contractevent]
+pub struct ReinitProposed {
+    pub admin: Address,
+    pub game_hub: Address,
+    pub executable_ledger: u32,
+}
+
+/// Published when `reinitialize` replaces the admin and hub
+#[contractevent]
+pub struct Reinitialized {
+    pub previous_admin: Address,
+    pub previous_hub: Option<Address>,
+    pub admin: Address,
+    pub game_hub: Address,
+}
+
+/// Admins who must approve sensitive actions, `threshold` of them at a
+/// time (`set_admin_council`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminCouncil {
+    pub members: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// Day-to-day duties the admin can delegate with `grant_role`, so they do
+/// not need the key that controls upgrades. The admin holds every role, and
+/// `Admin` holders may do whatever the other roles can
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Admin = 0,
+    /// Game settings: timeouts, auto-resolve, tiebreaks and open game limits
+    Operator = 1,
+    /// Pausing during an incident
+    Pauser = 2,
+    /// Economic settings: wager limits
+    FeeManager = 3,
+}
+
+/// An action that needs the admin council's approval once a council is set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    SetHub(Address),
+    Pause,
+    Unpause,
+    ProposeUpgrade(BytesN<32>),
+    Reinitialize(Address, Address),
+    SetCouncil(AdminCouncil),
+    RemoveCouncil,
+    ProposeAdmin(Address),
+    AcceptAdmin(Address),
+    SetVerifier(Address),
+    SetVerificationKey(BytesN<32>), // sha256 of the key
+    GrantRole(Address, Role),
+    RevokeRole(Address, Role),
+    SetDisputeConfig(DisputeConfig),
+    RemoveDisputeConfig,
+    SetFeeBps(u32),
+    SetFeeCollector(Address),
+    SetOracleConfig(OracleConfig),
+    RemoveOracleConfig,
+    SetHouseBeacon(Address),
+    SetVkSource(VkSource),
+    RemoveVkSource,
+    SetUltraHonkVerifier(Address),
+    SetProofSystem(ProofSystem),
+    SetStakeVerifier(Address),
+    SetRatingVerifier(Address),
+    SetSybilGate(BytesN<32>), // sha256 of the gate's XDR
+    RemoveSybilGate,
+    SetSimulationImageId(BytesN<32>),
+    RemoveSimulationImageId,
+    SetReceiptVerifier(Address),
+    SetRoundsVerifier(Address),
+    SetBatchVerification(bool),
+    Migrate,
+    SetUpgradeDelay(u32),
+}
+
+/// An upgrade waiting out its delay (`get_pending_upgrade`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    /// First ledger `execute_upgrade` may run in
+    pub executable_ledger: u32,
+}
+
+/// A reinitialization waiting out the upgrade delay
+/// (`get_pending_reinit`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingReinit {
+    pub admin: Address,
+    pub game_hub: Address,
+    /// First ledger `reinitialize` may run in
+    pub executable_ledger: u32,
+}
+
+/// Bounds and committed stakes of a game started with
+/// `start_hidden_stake_game`. Each stake stays `None` until its player opens
+/// the hash with `reveal_stake`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HiddenStakes {
+    pub min_stake: i128,
+    pub max_stake: i128,
+    pub player1_stake_hash: BytesN<32>,
+    pub player2_stake_hash: BytesN<32>,
+    pub player1_stake: Option<i128>,
+    pub player2_stake: Option<i128>,
+}
+
+/// The odds of a `start_game_with_odds` match: player1 risks `player1`
+/// units for every `player2` units player2 risks, so at 2:1 player1 is the
+/// favourite and wins half what they stake, and player2 twice theirs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Odds {
+    pub player1: u32,
+    pub player2: u32,
+}
+
+/// A member of a 2v2 team (`start_team_game`) and the BLS12-381 public key
+/// (G1, uncompressed) their part of each team move is signed with
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamMember {
+    pub player: Address,
+    pub bls_key: BytesN<96>,
+}
+
+/// Both sides of a 2v2 match, in member order. Each team's first member is
+/// its captain and holds the game's `player1`/`player2` slot; member `i`
+/// plays round `i` against the opposing member `i`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Teams {
+    pub team1: Vec<TeamMember>,
+    pub team2: Vec<TeamMember>,
+}
+
+/// What each teammate signs for `submit_team_move`: their own tactic hash
+/// in one session. The signed message is the XDR encoding of this struct
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamMove {
+    pub contract: Address,
+    pub session_id: u32,
+    pub player: Address,
+    pub tactic_hash: BytesN<32>,
+}
+
+/// Anti-sybil configuration for `start_ranked_game`: the verifier of
+/// uniqueness proofs, the identity set they prove membership of and the
+/// bracket their nullifiers are scoped to. Moving to a new bracket lets every
+/// identity play again
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SybilGate {
+    pub verifier: Address,
+    pub identity_root: BytesN<32>, // Merkle root of the registered identity commitments
+    pub bracket: u32,
+}
+
+/// A player's uniqueness proof for `start_ranked_game`. The nullifier is
+/// derived from the player's identity and the gate's bracket, so an identity
+/// has exactly one per bracket whichever address it plays from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UniquenessProof {
+    pub nullifier: BytesN<32>,
+    pub proof: Bytes,
+}
+
+/// Inclusive rating range a `start_bracket_game` match is restricted to
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RatingBracket {
+    pub min_rating: u32,
+    pub max_rating: u32,
+}
+
+/// Session key grant: lets `key` sign `submit_tactic` on the player's behalf
+/// for one session, as long as the player's stake is within `max_stake` and
+/// the grant has not expired.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionKeyGrant {
+    pub key: Address,
+    pub max_stake: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Payload a player signs for `submit_tactic_signed`. The signed message is
+/// the XDR encoding of this struct (an `ScMap` keyed by field name).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedSubmission {
+    pub contract: Address,
+    pub session_id: u32,
+    pub player: Address,
+    pub inputs: PublicInputs,
+    pub proof_hash: BytesN<32>,
+    pub nonce: u64,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+    Verifier,
+    SessionKey(u32, Address),
+    Nonce(Address),
+    VerificationKey,
+    ProofSystem,
+    UltraHonkVerifier,
+    Nullifier(BytesN<32>),
+    ProofFreshnessWindow,
+    BatchVerification,
+    PendingProof(u32, Address),
+    HiddenStakes(u32),
+    StakeVerifier,
+    SimulationImageId,
+    ReceiptVerifier,
+    TiebreakPolicy,
+    TiebreakEntropy(u32),
+    MultiRound(u32),
+    RoundsVerifier,
+    MinProofVersion,
+    VkSource,
+    MaxProofSize,
+    SybilGate,
+    IdentityNullifier(u32, BytesN<32>),
+    Teams(u32),
+    RatingVerifier,
+    RatingBracket(u32),
+    TimeoutLedgers,
+    OpenSession(u32),
+    AutoResolve,
+    MatchCount(Address),
+    MatchRecord(Address, u32),
+    Paused,
+    MaxOpenGames,
+    PlayerGames(Address),
+    WagerLimits,
+    PendingAdmin,
+    UpgradeDelay,
+    PendingUpgrade,
+    AdminCouncil,
+    Approvals(CouncilAction),
+    Role(Address, Role),
+    SchemaVersion,
+    Initialized,
+    PendingReinit,
+    PlayerStats(Address),
+    GlobalStats,
+    WagerToken,
+    Escrow(u32),
+    FeeBps,
+    FeeCollector,
+    AccruedFees(Address),
+    OracleConfig,
+    SideBetRakeBps,
+    SideBetPool(u32),
+    SideBet(u32, Address),
+    Bankroll(Address),
+    BankrollShares(Address, Address),
+    HouseBeacon,
+    HouseRound(u32),
+    ReferralShareBps,
+    Referrer(Address),
+    ReferralStats(Address),
+    ReferralRewards(Address, Address),
+    Streak(Address),
+    StreakBonus,
+    Jackpot(Address),
+    JackpotConfig,
+    JackpotRun(Address),
+    Balance(Address, Address),
+    HubShareBps,
+    EpochConfig,
+    EpochSchedule,
+    EpochRewards(u32),
+    EpochWins(u32, Address),
+    EpochClaimed(u32, Address),
+    DisputeConfig,
+    Bond(Address, Address),
+    Dispute(u32),
+    Odds(u32),
+    StakingConfig,
+    Stake(Address),
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+
+const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Open session records outlive their game entry, which is what lets
+/// `abandon_expired` find them
+const OPEN_SESSION_TTL_LEDGERS: u32 = 2 * GAME_TTL_LEDGERS;
+
+/// Match history outlives the game entry it was copied from. Older records
+/// that lapse are archived by the network rather than deleted, and can be
+/// restored
+const HISTORY_TTL_LEDGERS: u32 = 2 * GAME_TTL_LEDGERS;
+
+/// Oldest hub interface version `set_hub` accepts. Version 1 hubs cannot
+/// record draws, which are then left for `retry_hub_notify`
+pub const MIN_HUB_VERSION: u32 = 1;
+
+/// The hub's error for a stake above the player's hub balance
+const HUB_INSUFFICIENT_BALANCE: u32 = 11;
+
+/// Most members an admin council may have
+pub const MAX_COUNCIL_SIZE: u32 = 10;
+
+/// Highest open game limit `set_max_open_games` accepts. Starting a game
+/// reads each player's open games, which must fit in one transaction's
+/// footprint
+pub const MAX_OPEN_GAMES_LIMIT: u32 = 32;
+
+/// Most games one `get_history` call returns, as each is its own storage
+/// entry
+pub const MAX_HISTORY_PAGE: u32 = 50;
+
+/// Most sessions one `resolve_batch` or `claim_batch` call takes
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Most players an epoch ranks on-chain (`EpochConfig::top_n`); larger
+/// leaderboards are paid through `set_epoch_root`
+pub const MAX_EPOCH_LEADERS: u32 = 25;
+
+/// Most discount tiers staking can have (`StakingConfig::tiers`)
+pub const MAX_STAKE_TIERS: u32 = 10;
+
+/// Share of a timed-out player's escrowed amount (basis points) that goes
+/// to their opponent; the rest is refunded to them
+pub const FORFEIT_SHARE_BPS: u32 = 5_000;
+
+/// Highest protocol fee `set_fee_bps` accepts (5% of each pot)
+pub const MAX_FEE_BPS: u32 = 500;
+
+/// Referrers' share of the fee on their referees' stakes until
+/// `set_referral_share_bps` changes it (10%)
+pub const DEFAULT_REFERRAL_SHARE_BPS: u32 = 1_000;
+
+/// Rake on the losing side of a side-bet pool until `set_side_bet_rake_bps`
+/// changes it (1%)
+pub const DEFAULT_SIDE_BET_RAKE_BPS: u32 = 100;
+
+/// Most rounds a multi-round match may have
+pub const MAX_ROUNDS: u32 = 9;
+
+/// Members per team in a 2v2 match
+pub const TEAM_SIZE: u32 = 2;
+
+/// Newest `ProofEnvelope` version this contract verifies
+pub const PROOF_VERSION: u32 = 1;
+
+/// Crate version as `(major, minor, patch)`, see `get_version`
+pub const CONTRACT_VERSION: (u32, u32, u32) = (
+    parse_version(env!("CARGO_PKG_VERSION_MAJOR")),
+    parse_version(env!("CARGO_PKG_VERSION_MINOR")),
+    parse_version(env!("CARGO_PKG_VERSION_PATCH")),
+);
+
+const fn parse_version(part: &str) -> u32 {
+    let bytes = part.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    value
+}
+
+/// Largest proof accepted until the admin sets another limit: room for an
+/// UltraHonk proof (about 14 KB), far above Groth16's 256 bytes
+pub const DEFAULT_MAX_PROOF_SIZE: u32 = 16_384;
+
+/// Ledgers players have to submit after a game starts until the admin sets
+/// another timeout: about a day at 5 seconds per ledger
+pub const DEFAULT_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Ledgers between `propose_upgrade` and `execute_upgrade` until the admin
+/// sets another delay: about a day at 5 seconds per ledger
+pub const DEFAULT_UPGRADE_DELAY_LEDGERS: u32 = 17_280;
+
+/// Shortest upgrade delay the admin may set: about an hour
+pub const MIN_UPGRADE_DELAY_LEDGERS: u32 = 720;
+
+/// Version of proofs submitted without an envelope (`submit_tactic`,
+/// `submit_tactic_signed`), which predate versioning
+const BARE_PROOF_VERSION: u32 = 1;
+
+/// Encode a u32 as a 32-byte big-endian field element (proof public input)
+fn u32_to_field(env: &Env, value: u32) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[28..].copy_from_slice(&value.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+/// Encode a non-negative i128 as a 32-byte big-endian field element
+fn i128_to_field(env: &Env, value: i128) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[16..].copy_from_slice(&value.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+/// Public input that ties a proof to one player of one deployment:
+/// sha256 of the contract's and the player's address XDR, with the top byte
+/// cleared so it is a BN254 field element
+pub fn player_binding(env: &Env, contract: &Address, player: &Address) -> BytesN<32> {
+    let mut data = contract.clone().to_xdr(env);
+    data.append(&player.clone().to_xdr(env));
+    let mut bytes = env.crypto().sha256(&data).to_array();
+    bytes[0] = 0;
+    BytesN::from_array(env, &bytes)
+}
+
+/// Public input that names a player in the Game Hub's ratings tree:
+/// sha256 of the player's address XDR, with the top byte cleared so it is a
+/// BN254 field element. Unlike `player_binding` it is the same for every
+/// game contract, since the hub rates players across all of them
+pub fn rating_key(env: &Env, player: &Address) -> BytesN<32> {
+    let mut bytes = env.crypto().sha256(&player.clone().to_xdr(env)).to_array();
+    bytes[0] = 0;
+    BytesN::from_array(env, &bytes)
+}
+
+/// Public input that hides a tactic until it is revealed:
+/// sha256 of `tactic (u32 BE) || secret (32 bytes) || session_id (u32 BE)`,
+/// with the top byte cleared so it is a BN254 field element
+pub fn tactic_hash(env: &Env, tactic: u32, secret: &BytesN<32>, session_id: u32) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &tactic.to_be_bytes());
+    data.append(&secret.clone().into());
+    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    let mut bytes = env.crypto().sha256(&data).to_array();
+    bytes[0] = 0;
+    BytesN::from_array(env, &bytes)
+}
+
+/// Commitment to a hidden stake:
+/// sha256 of `stake (i128 BE) || salt (32 bytes) || session_id (u32 BE)`,
+/// with the top byte cleared so it is a BN254 field element
+pub fn stake_hash(env: &Env, stake: i128, salt: &BytesN<32>, session_id: u32) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &stake.to_be_bytes());
+    data.append(&salt.clone().into());
+    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    let mut bytes = env.crypto().sha256(&data).to_array();
+    bytes[0] = 0;
+    BytesN::from_array(env, &bytes)
+}
+
+// ============================================================================
+// Score Matrix
+// ============================================================================
+// Strategic interaction: [Player1 Tactic][Player2 Tactic] = (P1 Score, P2 Score)
+// Matrix creates rock-paper-scissors-like dynamics with scoring depth
+
+pub fn get_score(tactic1: u32, tactic2: u32) -> (u32, u32) {
+    match (tactic1, tactic2) {
+        // Defensive vs X
+        (0, 0) => (0, 0), // Both defensive = stalemate
+        (0, 1) => (0, 1), // Defensive loses to Balanced
+        (0, 2) => (1, 1), // Defensive draws with Aggressive
+        (0, 3) => (2, 2), // Defensive draws with AllOut (chaos)
+        
+        // Balanced vs X
+        (1, 0) => (1, 0), // Balanced beats Defensive
+        (1, 1) => (1, 1), // Both balanced = draw
+        (1, 2) => (1, 2), // Balanced loses to Aggressive
+        (1, 3) => (2, 3), // Balanced loses to AllOut
+        
+        // Aggressive vs X
+        (2, 0) => (1, 1), // Aggressive draws with Defensive
+        (2, 1) => (2, 1), // Aggressive beats Balanced
+        (2, 2) => (2, 2), // Both aggressive = high scoring draw
+        (2, 3) => (3, 3), // Aggressive draws with AllOut (chaos)
+        
+        // AllOut vs X
+        (3, 0) => (2, 2), // AllOut draws with Defensive (chaos)
+        (3, 1) => (3, 2), // AllOut beats Balanced
+        (3, 2) => (3, 3), // AllOut draws with Aggressive (chaos)
+        (3, 3) => (4, 4), // Both AllOut = maximum chaos draw
+        
+        _ => (0, 0), // Invalid tactics
+    }
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct ZkTacticalMatchContract;
+
+#[contractimpl]
+impl ZkTacticalMatchContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// Panics with `AlreadyInitialized` if the contract was initialized
+    /// before; the admin and hub can only be replaced afterwards through
+    /// `propose_reinitialize`.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        if Self::is_initialized(env.clone()) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Initialized, &true);
+
+        // Store admin and GameHub address
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &game_hub);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+    }
+
+    /// Allocate this game a session ID at the Game Hub, unique across
+    /// every game, for the players to sign into `start_game` or another
+    /// `start_*` entrypoint. Version 4 hubs open sessions only under IDs
+    /// allocated to the game; a hub that allocates none fails with
+    /// `InvalidHub`. Anyone may call it.
+    pub fn allocate_session(env: Env) -> Result<u32, Error> {
+        let game_hub = GameHubClient::new(&env, &Self::get_hub(env.clone())?);
+        match game_hub.try_allocate_session(&env.current_contract_address()) {
+            Ok(Ok(session_id)) => Ok(session_id),
+            _ => Err(Error::InvalidHub),
+        }
+    }
+
+    /// Start a new ZK Tactical Match between two players.
+    ///
+    /// Both players sign the full terms: the session, both players, both
+    /// points and the wager token (`get_wager_token`), so neither signature
+    /// can be paired with another opponent or stake. Returns
+    /// `SelfPlayNotAllowed` if both players are the same address, and
+    /// `InsufficientBalance` if the Game Hub holds less than a player's
+    /// points for them, as do the other `start_*` entrypoints. A game
+    /// wagered in a token stakes nothing at the hub: its escrow holds the
+    /// stakes instead.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier, from `allocate_session`
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1
+    /// * `player2_points` - Betting amount for player 2
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let token = Self::get_wager_token(env.clone());
+        let terms = vec![&env, token.into_val(&env)];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        let game = Self::new_game(&env, player1, player2, player1_points, player2_points);
+        Self::register_game(&env, session_id, &game, None)
+    }
+
+    /// Start a match with its own submit and reveal windows.
+    ///
+    /// Plays as `start_game`, but instead of the admin's timeout players
+    /// have `submit_ledgers` to submit and a further `resolve_ledgers` to
+    /// reveal, after which `resolve_timeout` settles the game by forfeit.
+    /// Both players sign the windows with the terms. Returns `InvalidDeadlines` if either
+    /// is 0 or together they outlast the game's storage.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1
+    /// * `player2_points` - Betting amount for player 2
+    /// * `submit_ledgers` - Ledgers from now to submit in
+    /// * `resolve_ledgers` - Ledgers after the submit deadline to reveal in
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_game_with_deadlines(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        submit_ledgers: u32,
+        resolve_ledgers: u32,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let terms = vec![
+            &env,
+            Self::get_wager_token(env.clone()).into_val(&env),
+            submit_ledgers.into_val(&env),
+            resolve_ledgers.into_val(&env),
+        ];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        if submit_ledgers == 0
+            || resolve_ledgers == 0
+            || submit_ledgers.saturating_add(resolve_ledgers) >= GAME_TTL_LEDGERS
+        {
+            return Err(Error::InvalidDeadlines);
+        }
+
+        let mut game = Self::new_game(&env, player1, player2, player1_points, player2_points);
+        game.submit_deadline_ledger = env.ledger().sequence().saturating_add(submit_ledgers);
+        game.resolve_deadline_ledger = game.submit_deadline_ledger.saturating_add(resolve_ledgers);
+        Self::register_game(&env, session_id, &game, None)
+    }
+
+    /// Start a match wagered in a token of the players' choosing.
+    ///
+    /// Plays as `start_game`, but the points are escrowed in `token`
+    /// instead of the contract-wide `get_wager_token` (see `get_escrow`), so
+    /// a match can be played for USDC, XLM or a community token. Both
+    /// players sign this token in the terms. The token is kept in
+    /// the game's `wager_token` and pays out the result.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1, in `token` units
+    /// * `player2_points` - Betting amount for player 2, in `token` units
+    /// * `token` - A Stellar Asset Contract or other SEP-41 token
+    pub fn start_game_with_token(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        token: Address,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let terms = vec![&env, token.into_val(&env)];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        let mut game = Self::new_game(&env, player1, player2, player1_points, player2_points);
+        game.wager_token = Some(token);
+        Self::register_game(&env, session_id, &game, None)
+    }
+
+    /// Start a match wagered in native XLM.
+    ///
+    /// As `start_game_with_token` with the native Stellar Asset Contract
+    /// (`get_native_token`) as the token, which players sign along with
+    /// their points. Amounts are stroops (1 XLM = 10,000,000).
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1, in stroops
+    /// * `player2_points` - Betting amount for player 2, in stroops
+    pub fn start_native_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        let token = escrow::native_token(&env);
+        Self::start_game_with_token(
+            env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            token,
+        )
+    }
+
+    /// Start a match against the house.
+    ///
+    /// The contract itself is player2, staking `points` of `token` from the
+    /// token's bankroll (see `deposit_bankroll`) against the player's
+    /// `points`; a bankroll without that much unlocked returns
+    /// `InsufficientBankroll`. The player submits or commits and reveals
+    /// as usual. The house's tactic comes from the round of the house
+    /// beacon (`set_house_beacon`, else `HouseBeaconNotSet`) that is pending
+    /// when the player commits, so it is fixed before the player's tactic
+    /// but unknown until the round is revealed; with no round pending the
+    /// commit returns `RandomnessNotReady`. The house wins drawn scores
+    /// whatever the game's tiebreak, which is its edge. A player who never
+    /// reveals forfeits their whole stake to `resolve_timeout`. The
+    /// bankroll takes the house's share of the result when the game
+    /// settles. The player signs the token along with their points.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player` - The player, as player1
+    /// * `points` - Each side's stake, in `token` units
+    /// * `token` - A token with a house bankroll
+    pub fn start_house_game(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        points: i128,
+        token: Address,
+    ) -> Result<(), Error> {
+        player.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            points.into_val(&env),
+            token.into_val(&env),
+        ]);
+
+        if !env.storage().instance().has(&DataKey::HouseBeacon) {
+            return Err(Error::HouseBeaconNotSet);
+        }
+        let house = env.current_contract_address();
+        let mut game = Self::new_game(&env, player, house, points, points);
+        game.wager_token = Some(token);
+        // The house submits no proof, so nothing can be deferred or
+        // simulated against it
+        game.batch_verification = false;
+        game.simulation_image_id = None;
+        Self::register_game(&env, session_id, &game, None)
+    }
+
+    /// Start a match where each player stakes a different token.
+    ///
+    /// Plays as `start_game_with_token`, but player1 deposits
+    /// `player1_points` of `player1_token` and player2 `player2_points` of
+    /// `player2_token`. The price oracle (`get_oracle_config`) values both
+    /// stakes when the game starts, and they must be worth the same within
+    /// its tolerance, else `StakesNotEquivalent`. A missing or stale price
+    /// returns `PriceUnavailable`, and no oracle `OracleNotConfigured`.
+    /// Each deposit is paid out in its own token: the winner gets both,
+    /// and a refund returns each player's own. Both players sign both
+    /// tokens along with their points.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1, in `player1_token`
+    /// * `player2_points` - Betting amount for player 2, in `player2_token`
+    /// * `player1_token` - The token player 1 stakes
+    /// * `player2_token` - The token player 2 stakes
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_mixed_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        player1_token: Address,
+        player2_token: Address,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let terms = vec![&env, player1_token.into_val(&env), player2_token.into_val(&env)];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        let config = Self::get_oracle_config(env.clone()).ok_or(Error::OracleNotConfigured)?;
+        oracle::check_equivalent(
+            &env,
+            &config,
+            (&player1_token, player1_points),
+            (&player2_token, player2_points),
+        )?;
+
+        let mut game = Self::new_game(&env, player1, player2, player1_points, player2_points);
+        game.wager_token = Some(player1_token);
+        Self::register_game(&env, session_id, &game, Some(player2_token))
+    }
+
+    /// Start a match at handicap odds.
+    ///
+    /// Plays as `start_game_with_token`, but the stakes are not assumed to
+    /// be even: each player's points are the most they will risk, and the
+    /// game is played for the largest stakes within them that are exactly
+    /// at `odds`:
+    ///
+    /// ```text
+    /// units          = min(player1_points / odds.player1, player2_points / odds.player2)
+    /// player1 stakes = units * odds.player1
+    /// player2 stakes = units * odds.player2
+    /// ```
+    ///
+    /// Only those stakes are escrowed, and the winner takes the pot, so a
+    /// win pays exactly what the odds give. Odds with a zero side, or
+    /// points too small to cover one unit, return `InvalidOdds`. Both
+    /// players sign the token and the odds with the terms; the
+    /// odds are kept for the game (`get_odds`).
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - The most player 1 risks, in `token` units
+    /// * `player2_points` - The most player 2 risks, in `token` units
+    /// * `token` - A Stellar Asset Contract or other SEP-41 token
+    /// * `odds` - Units player 1 risks against player 2's
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_game_with_odds(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        token: Address,
+        odds: Odds,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let terms = vec![&env, token.into_val(&env), odds.into_val(&env)];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        if odds.player1 == 0 || odds.player2 == 0 {
+            return Err(Error::InvalidOdds);
+        }
+        let units = (player1_points / odds.player1 as i128)
+            .min(player2_points / odds.player2 as i128);
+        if units <= 0 {
+            return Err(Error::InvalidOdds);
+        }
+
+        let stake1 = units * odds.player1 as i128;
+        let stake2 = units * odds.player2 as i128;
+        let mut game = Self::new_game(&env, player1, player2, stake1, stake2);
+        game.wager_token = Some(token);
+        Self::register_game(&env, session_id, &game, None)?;
+
+        let odds_key = DataKey::Odds(session_id);
+        env.storage().temporary().set(&odds_key, &odds);
+        env.storage()
+            .temporary()
+            .extend_ttl(&odds_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Register a single-round game with the hub and store it, escrowing
+    /// player2's points in `player2_token` if given (see `escrow_wagers`)
+    fn register_game(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        player2_token: Option<Address>,
+    ) -> Result<(), Error> {
+        Self::require_unpaused(env)?;
+
+        // A hidden-stake game's stakes outlive its settlement, and would be
+        // read as this game's
+        if env
+            .storage()
+            .temporary()
+            .has(&DataKey::HiddenStakes(session_id))
+        {
+            return Err(Error::GameAlreadyExists);
+        }
+        Self::require_free_session(env, session_id)?;
+        Self::check_wagers(env, game.player1_points, game.player2_points)?;
+        Self::track_open_game(env, session_id, game)?;
+        Self::start_hub_session(env, session_id, game, (game.player1_points, game.player2_points))?;
+
+        Self::store_new_game(env, session_id, game);
+        Self::escrow_wagers(
+            env,
+            session_id,
+            game,
+            (game.player1_points, game.player2_points),
+            player2_token,
+        )?;
+        Ok(())
+    }
+
+    /// Open `session_id` at the hub with `points` at stake (see
+    /// `hub_stakes`), keeping an open record of it until its result is
+    /// reported. A stake above a player's hub balance returns
+    /// `InsufficientBalance`; other hub failures trap with the hub's error
+    fn start_hub_session(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        points: (i128, i128),
+    ) -> Result<(), Error> {
+        let (player1_points, player2_points) = Self::hub_stakes(game, points);
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::HubNotSet)?;
+
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        let started = game_hub.try_start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &game.player1,
+            &game.player2,
+            &player1_points,
+            &player2_points,
+        );
+        match started {
+            Ok(_) => {}
+            Err(Ok(err)) if err == soroban_sdk::Error::from_contract_error(HUB_INSUFFICIENT_BALANCE) => {
+                return Err(Error::InsufficientBalance)
+            }
+            Err(Ok(err)) => panic_with_error!(env, err),
+            Err(Err(_)) => panic_with_error!(env, Error::InvalidHub),
+        }
+
+        Self::open_session(env, session_id, game, (player1_points, player2_points));
+        Ok(())
+    }
+
+    /// The stakes the hub holds for a game: none for a wagered game, whose
+    /// escrow already holds them, so the loser is not charged twice
+    fn hub_stakes(game: &Game, points: (i128, i128)) -> (i128, i128) {
+        if game.wager_token.is_some() {
+            (0, 0)
+        } else {
+            points
+        }
+    }
+
+    /// Start a best-of-N match.
+    ///
+    /// Instead of one tactic per player, each player submits a single
+    /// recursive proof attesting to all `rounds` tactics (`submit_rounds`)
+    /// and opens them together with `reveal_rounds`. `resolve_match` scores
+    /// every round with the matrix; the player who wins more rounds wins the
+    /// match, and a tie in rounds won is decided by the `TiebreakPolicy`.
+    ///
+    /// Round proofs are checked by the verifier set with
+    /// `set_rounds_verifier`, whatever the game's `ProofSystem`; multi-round
+    /// games are never batch-verified or scored by a simulation.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1
+    /// * `player2_points` - Betting amount for player 2
+    /// * `rounds` - Number of rounds, 1 to `MAX_ROUNDS`
+    pub fn start_multi_round_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        rounds: u32,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let terms = vec![
+            &env,
+            Self::get_wager_token(env.clone()).into_val(&env),
+            rounds.into_val(&env),
+        ];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        let game = Self::new_game(&env, player1, player2, player1_points, player2_points);
+        let multi_round = MultiRound {
+            rounds,
+            strategy_committed: false,
+            player1_tactic_hashes: None,
+            player2_tactic_hashes: None,
+            player1_tactics: None,
+            player2_tactics: None,
+        };
+        Self::store_multi_round_game(&env, session_id, game, multi_round)
+    }
+
+    /// Start a best-of-N match with every round committed up front.
+    ///
+    /// Both players sign the start with the roots of Merkle trees over their
+    /// round tactics (see `strategy_root`), so no submissions follow: rounds
+    /// are opened one at a time, in order, with `reveal_round`. Otherwise it
+    /// plays as `start_multi_round_game`.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1
+    /// * `player2_points` - Betting amount for player 2
+    /// * `rounds` - Number of rounds, 1 to `MAX_ROUNDS`
+    /// * `player1_root` - Root of player 1's strategy tree
+    /// * `player2_root` - Root of player 2's strategy tree
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_committed_multi_round_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        rounds: u32,
+        player1_root: BytesN<32>,
+        player2_root: BytesN<32>,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let terms = vec![
+            &env,
+            Self::get_wager_token(env.clone()).into_val(&env),
+            rounds.into_val(&env),
+            player1_root.into_val(&env),
+            player2_root.into_val(&env),
+        ];
+        Self::require_players_auth(
+            &env,
+            session_id,
+            (&player1, player1_points),
+            (&player2, player2_points),
+            terms,
+        );
+
+        // Reveals need no signature, so a copied root would let a player
+        // echo each of the opponent's reveals
+        if player1_root == player2_root {
+            return Err(Error::InvalidCommitment);
+        }
+
+        let mut game = Self::new_game(&env, player1, player2, player1_points, player2_points);
+        game.player1_tactic_hash = Some(player1_root);
+        game.player2_tactic_hash = Some(player2_root);
+        game.phase = GamePhase::BothSubmitted;
+        let multi_round = MultiRound {
+            rounds,
+            strategy_committed: true,
+            player1_tactic_hashes: None,
+            player2_tactic_hashes: None,
+            player1_tactics: None,
+            player2_tactics: None,
+        };
+        Self::store_multi_round_game(&env, session_id, game, multi_round)
+    }
+
+    /// Start a 2v2 match.
+    ///
+    /// Played as a `TEAM_SIZE`-round match: member `i` of each team plays
+    /// round `i`, the team winning more rounds wins, and a tie is decided by
+    /// the `TiebreakPolicy`. The hub sees the captains (each team's first
+    /// member) and the team stakes. Teams move with `submit_team_move` and
+    /// captains reveal every member's tactic with `reveal_rounds`.
+    ///
+    /// Every member signs `(session_id, team points, bls_key)`.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `team1` - Team 1's members, captain first
+    /// * `team2` - Team 2's members, captain first
+    /// * `team1_points` - Betting amount for team 1
+    /// * `team2_points` - Betting amount for team 2
+    ///
+    /// Fails with `InvalidTeam` unless both teams have `TEAM_SIZE` distinct
+    /// members with valid keys.
+    pub fn start_team_game(
+        env: Env,
+        session_id: u32,
+        team1: Vec<TeamMember>,
+        team2: Vec<TeamMember>,
+        team1_points: i128,
+        team2_points: i128,
+    ) -> Result<(), Error> {
+        if team1.len() != TEAM_SIZE || team2.len() != TEAM_SIZE {
+            return Err(Error::InvalidTeam);
+        }
+
+        let mut players: Vec<Address> = Vec::new(&env);
+        for (team, points) in [(&team1, team1_points), (&team2, team2_points)] {
+            for member in team.iter() {
+                if players.contains(&member.player) || !bls::is_valid_key(&env, &member.bls_key) {
+                    return Err(Error::InvalidTeam);
+                }
+                member.player.require_auth_for_args(vec![
+                    &env,
+                    session_id.into_val(&env),
+                    points.into_val(&env),
+                    member.bls_key.into_val(&env),
+                ]);
+                players.push_back(member.player);
+            }
+        }
+
+        let game = Self::new_game(
+            &env,
+            team1.get_unchecked(0).player,
+            team2.get_unchecked(0).player,
+            team1_points,
+            team2_points,
+        );
+        let multi_round = MultiRound {
+            rounds: TEAM_SIZE,
+            strategy_committed: false,
+            player1_tactic_hashes: None,
+            player2_tactic_hashes: None,
+            player1_tactics: None,
+            player2_tactics: None,
+        };
+        Self::store_multi_round_game(&env, session_id, game, multi_round)?;
+
+        let teams_key = DataKey::Teams(session_id);
+        env.storage()
+            .temporary()
+            .set(&teams_key, &Teams { team1, team2 });
+        env.storage()
+            .temporary()
+            .extend_ttl(&teams_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Register a multi-round game with the hub and store it
+    fn store_multi_round_game(
+        env: &Env,
+        session_id: u32,
+        mut game: Game,
+        multi_round: MultiRound,
+    ) -> Result<(), Error> {
+        Self::require_unpaused(env)?;
+        if multi_round.rounds == 0 || multi_round.rounds > MAX_ROUNDS {
+            return Err(Error::InvalidRoundCount);
+        }
+        if env
+            .storage()
+            .temporary()
+            .has(&DataKey::HiddenStakes(session_id))
+        {
+            return Err(Error::GameAlreadyExists);
+        }
+        Self::require_free_session(env, session_id)?;
+        Self::check_wagers(env, game.player1_points, game.player2_points)?;
+        Self::track_open_game(env, session_id, &game)?;
+        Self::start_hub_session(env, session_id, &game, (game.player1_points, game.player2_points))?;
+
+        game.batch_verification = false;
+        game.simulation_image_id = None;
+        Self::store_new_game(env, session_id, &game);
+        Self::escrow_wagers(
+            env,
+            session_id,
+            &game,
+            (game.player1_points, game.player2_points),
+            None,
+        )?;
+
+        let rounds_key = DataKey::MultiRound(session_id);
+        env.storage().temporary().set(&rounds_key, &multi_round);
+        env.storage()
+            .temporary()
+            .extend_ttl(&rounds_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Start a match whose stakes stay hidden until settlement.
+    ///
+    /// Each player commits to their stake with `stake_hash` and proves it
+    /// lies within `[min_stake, max_stake]`; the range proof's public inputs
+    /// are `[session_id, player_binding, min_stake, max_stake, stake_hash]`
+    /// as 32-byte field elements, checked by the verifier set with
+    /// `set_stake_verifier` (without one, `VerifierNotConfigured`). The game
+    /// then plays as usual, with stakes of 0 until each player opens their
+    /// hash with `reveal_stake`. Unless the game is wagered in a token, the
+    /// Game Hub session opens with `max_stake` from each player, so a player
+    /// cannot withdraw their hub balance from under the result, and is lowered to the revealed stakes at
+    /// settlement (`reduce_stakes`, which needs a version 3 hub).
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier, not yet in use
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `min_stake` - Lowest stake either player may have committed to
+    /// * `max_stake` - Highest stake either player may have committed to
+    /// * `player1_stake` - Player 1's stake hash and range proof
+    /// * `player2_stake` - Player 2's stake hash and range proof
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_hidden_stake_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        min_stake: i128,
+        max_stake: i128,
+        player1_stake: StakeCommitment,
+        player2_stake: StakeCommitment,
+    ) -> Result<(), Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        for (player, stake) in [(&player1, &player1_stake), (&player2, &player2_stake)] {
+            player.require_auth_for_args(vec![
+                &env,
+                session_id.into_val(&env),
+                min_stake.into_val(&env),
+                max_stake.into_val(&env),
+                stake.stake_hash.into_val(&env),
+            ]);
+        }
+
+        Self::require_unpaused(&env)?;
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::GameAlreadyExists);
+        }
+        Self::require_free_session(&env, session_id)?;
+
+        if min_stake < 0 || min_stake > max_stake {
+            return Err(Error::InvalidStakeRange);
+        }
+        // Whatever was staked within the range is then within the limits
+        Self::check_wagers(&env, min_stake, max_stake)?;
+
+        for (player, stake) in [(&player1, &player1_stake), (&player2, &player2_stake)] {
+            Self::verify_stake_proof(&env, session_id, player, min_stake, max_stake, stake)?;
+        }
+
+        let game = Self::new_game(&env, player1, player2, 0, 0);
+        Self::track_open_game(&env, session_id, &game)?;
+        // Both reserve the most they could have staked at the hub too
+        Self::start_hub_session(&env, session_id, &game, (max_stake, max_stake))?;
+        Self::store_new_game(&env, session_id, &game);
+        // Both lock the most they could have staked; what they did not
+        // stake is theirs again at settlement
+        Self::escrow_wagers(&env, session_id, &game, (max_stake, max_stake), None)?;
+
+        let stakes_key = DataKey::HiddenStakes(session_id);
+        let stakes = HiddenStakes {
+            min_stake,
+            max_stake,
+            player1_stake_hash: player1_stake.stake_hash,
+            player2_stake_hash: player2_stake.stake_hash,
+            player1_stake: None,
+            player2_stake: None,
+        };
+        env.storage().temporary().set(&stakes_key, &stakes);
+        env.storage()
+            .temporary()
+            .extend_ttl(&stakes_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Check a hidden-stake range proof (see `start_hidden_stake_game`)
+    fn verify_stake_proof(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        min_stake: i128,
+        max_stake: i128,
+        stake: &StakeCommitment,
+    ) -> Result<(), Error> {
+        // `stake_hash` clears the top byte, so nothing else can be opened
+        if !groth16::is_field_element(&stake.stake_hash) {
+            return Err(Error::InvalidCommitment);
+        }
+        if stake.proof.len() < 32 {
+            return Err(Error::InvalidProof);
+        }
+
+        let verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::StakeVerifier)
+            .ok_or(Error::VerifierNotConfigured)?;
+        let public_inputs = vec![
+            env,
+            u32_to_field(env, session_id),
+            player_binding(env, &env.current_contract_address(), player),
+            i128_to_field(env, min_stake),
+            i128_to_field(env, max_stake),
+            stake.stake_hash.clone(),
+        ];
+        let verifier = VerifierClient::new(env, &verifier_addr);
+        if !verifier.verify(&stake.proof, &public_inputs) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Start a ranked match, open only to players who prove they are unique.
+    ///
+    /// Each player supplies a uniqueness proof against the current
+    /// `SybilGate`: that they hold an identity in its identity set, and that
+    /// `nullifier` is that identity's nullifier for its bracket. The
+    /// nullifiers are consumed, so an identity plays one ranked game per
+    /// bracket, and a second address of the same person returns
+    /// `IdentityAlreadyPlayed`. The game itself then plays as one started
+    /// with `start_game`.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1
+    /// * `player2_points` - Betting amount for player 2
+    /// * `player1_identity` - Player 1's uniqueness proof
+    /// * `player2_identity` - Player 2's uniqueness proof
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_ranked_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        player1_identity: UniquenessProof,
+        player2_identity: UniquenessProof,
+    ) -> Result<(), Error> {
+        let gate: SybilGate = env
+            .storage()
+            .instance()
+            .get(&DataKey::SybilGate)
+            .ok_or(Error::SybilGateDisabled)?;
+
+        // One person cannot sit on both sides either
+        if player1_identity.nullifier == player2_identity.nullifier {
+            return Err(Error::IdentityAlreadyPlayed);
+        }
+        for (player, identity) in [(&player1, &player1_identity), (&player2, &player2_identity)] {
+            Self::verify_uniqueness_proof(&env, &gate, player, identity)?;
+        }
+
+        Self::start_game(
+            env.clone(),
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        )?;
+
+        // Consume the nullifiers; like tactic nullifiers they outlive the
+        // game, and brackets are expected to turn over well within that
+        for identity in [player1_identity, player2_identity] {
+            let key = DataKey::IdentityNullifier(gate.bracket, identity.nullifier);
+            env.storage().persistent().set(&key, &session_id);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+        Ok(())
+    }
+
+    /// Check a uniqueness proof (see `start_ranked_game`)
+    ///
+    /// Public inputs are `[identity_root, bracket, player_binding,
+    /// nullifier]`: the Semaphore Merkle root, scope, message and nullifier,
+    /// with the player binding as the message so the proof cannot be reused
+    /// from another address.
+    fn verify_uniqueness_proof(
+        env: &Env,
+        gate: &SybilGate,
+        player: &Address,
+        identity: &UniquenessProof,
+    ) -> Result<(), Error> {
+        Self::check_proof_size(env, &identity.proof)?;
+        if !groth16::is_field_element(&identity.nullifier) {
+            return Err(Error::InvalidProof);
+        }
+        Self::check_proof_bytes(&identity.proof)?;
+
+        let key = DataKey::IdentityNullifier(gate.bracket, identity.nullifier.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::IdentityAlreadyPlayed);
+        }
+
+        let public_inputs = vec![
+            env,
+            gate.identity_root.clone(),
+            u32_to_field(env, gate.bracket),
+            player_binding(env, &env.current_contract_address(), player),
+            identity.nullifier.clone(),
+        ];
+        let verifier = VerifierClient::new(env, &gate.verifier);
+        if !verifier.verify(&identity.proof, &public_inputs) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Start a match restricted to players rated within `bracket`.
+    ///
+    /// Each player proves their rating lies in the bracket against the
+    /// ratings root the Game Hub currently publishes, without revealing it.
+    /// The proof's public inputs are `[ratings_root, min_rating, max_rating,
+    /// rating_key]`, checked by the verifier set with `set_rating_verifier`
+    /// (without one, `VerifierNotConfigured`). The game itself then plays as
+    /// one started with `start_game`.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier
+    /// * `player1` - First player address
+    /// * `player2` - Second player address
+    /// * `player1_points` - Betting amount for player 1
+    /// * `player2_points` - Betting amount for player 2
+    /// * `bracket` - Inclusive rating range both players must be within
+    /// * `player1_proof` - Player 1's rating proof
+    /// * `player2_proof` - Player 2's rating proof
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_bracket_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        bracket: RatingBracket,
+        player1_proof: Bytes,
+        player2_proof: Bytes,
+    ) -> Result<(), Error> {
+        if bracket.min_rating > bracket.max_rating {
+            return Err(Error::InvalidRatingBracket);
+        }
+
+        // Hubs that do not rate players have no `ratings_root` at all
+        let hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::HubNotSet)?;
+        let ratings_root = match RatingsHubClient::new(&env, &hub_addr).try_ratings_root() {
+            Ok(Ok(Some(root))) => root,
+            _ => return Err(Error::RatingsUnavailable),
+        };
+
+        for (player, proof) in [(&player1, &player1_proof), (&player2, &player2_proof)] {
+            Self::verify_rating_proof(&env, &ratings_root, &bracket, player, proof)?;
+        }
+
+        Self::start_game(
+            env.clone(),
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        )?;
+
+        let bracket_key = DataKey::RatingBracket(session_id);
+        env.storage().temporary().set(&bracket_key, &bracket);
+        env.storage()
+            .temporary()
+            .extend_ttl(&bracket_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Check a rating proof (see `start_bracket_game`)
+    fn verify_rating_proof(
+        env: &Env,
+        ratings_root: &BytesN<32>,
+        bracket: &RatingBracket,
+        player: &Address,
+        proof: &Bytes,
+    ) -> Result<(), Error> {
+        Self::check_proof_size(env, proof)?;
+        Self::check_proof_bytes(proof)?;
+
+        let verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::RatingVerifier)
+            .ok_or(Error::VerifierNotConfigured)?;
+        let public_inputs = vec![
+            env,
+            ratings_root.clone(),
+            u32_to_field(env, bracket.min_rating),
+            u32_to_field(env, bracket.max_rating),
+            rating_key(env, player),
+        ];
+        let verifier = VerifierClient::new(env, &verifier_addr);
+        if !verifier.verify(proof, &public_inputs) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Require both players' signatures on the terms of a match between
+    /// them: the session, each player and their points, then `terms`, the
+    /// rest of what the entrypoint starts the game on. Each player signs
+    /// the opponent's side as well as their own.
+    fn require_players_auth(
+        env: &Env,
+        session_id: u32,
+        (player1, player1_points): (&Address, i128),
+        (player2, player2_points): (&Address, i128),
+        terms: Vec<Val>,
+    ) {
+        let mut args = vec![
+            env,
+            session_id.into_val(env),
+            player1.into_val(env),
+            player2.into_val(env),
+            player1_points.into_val(env),
+            player2_points.into_val(env),
+        ];
+        args.append(&terms);
+        player1.require_auth_for_args(args.clone());
+        player2.require_auth_for_args(args);
+    }
+
+    /// A game that has not been played yet, with the current defaults
+    fn new_game(
+        env: &Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Game {
+        // New games use the current default backend; switching it later
+        // does not affect games already in flight
+        let proof_system = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProofSystem)
+            .unwrap_or(ProofSystem::Groth16);
+
+        // Only Groth16 proofs can share a pairing check
+        let batch_verification = proof_system == ProofSystem::Groth16
+            && env
+                .storage()
+                .instance()
+                .get(&DataKey::BatchVerification)
+                .unwrap_or(false);
+
+        let simulation_image_id = env.storage().instance().get(&DataKey::SimulationImageId);
+        let tiebreak = env
+            .storage()
+            .instance()
+            .get(&DataKey::TiebreakPolicy)
+            .unwrap_or(TiebreakPolicy::Draw);
+        let auto_resolve = env
+            .storage()
+            .instance()
+            .get(&DataKey::AutoResolve)
+            .unwrap_or(false);
+
+        // Players get the timeout to submit, then as long again to reveal
+        let timeout_ledgers = Self::get_timeout_ledgers(env.clone());
+        let submit_deadline_ledger = env.ledger().sequence().saturating_add(timeout_ledgers);
+        let resolve_deadline_ledger = submit_deadline_ledger.saturating_add(timeout_ledgers);
+
+        Game {
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            proof_system,
+            batch_verification,
+            simulation_image_id,
+            tiebreak,
+            auto_resolve,
+            submit_deadline_ledger,
+            resolve_deadline_ledger,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_proof_hash: None,
+            player2_proof_hash: None,
+            player1_tactic_hash: None,
+            player2_tactic_hash: None,
+            player1_tactic: None,
+            player2_tactic: None,
+            player1_score: None,
+            player2_score: None,
+            hub_notified: false,
+            phase: GamePhase::Created,
+            result: GameResult::Pending,
+            last_activity_ledger: env.ledger().sequence(),
+            wager_token: Self::get_wager_token(env.clone()),
+        }
+    }
+
+    /// Reject a session ID still in play: its game is unresolved, or the hub
+    /// has not yet heard how it ended (see `abandon_expired`). A settled
+    /// game's ID may be started again
+    fn require_free_session(env: &Env, session_id: u32) -> Result<(), Error> {
+        let game: Option<Game> = env.storage().temporary().get(&DataKey::Game(session_id));
+        let storage = env.storage().persistent();
+        if game.is_some_and(|game| !game.is_over())
+            || storage.has(&DataKey::OpenSession(session_id))
+            || storage.has(&DataKey::Escrow(session_id))
+            || storage.has(&DataKey::SideBetPool(session_id))
+            || storage.has(&DataKey::Dispute(session_id))
+        {
+            return Err(Error::SessionAlreadyExists);
+        }
+        Ok(())
+    }
+
+    /// Lock a new game's wagers in escrow if it is wagered in a token; a
+    /// no-op otherwise. `player2_token` is player2's token in a mixed-asset
+    /// game, else both deposit the game's `wager_token`
+    fn escrow_wagers(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        (player1_amount, player2_amount): (i128, i128),
+        player2_token: Option<Address>,
+    ) -> Result<(), Error> {
+        let Some(player1_token) = game.wager_token.clone() else {
+            return Ok(());
+        };
+        let escrow = Escrow {
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            player2_token: player2_token.unwrap_or(player1_token.clone()),
+            player1_token,
+            player1_amount,
+            player2_amount,
+            fee_bps: Self::get_fee_bps(env.clone()),
+            settlement: Settlement::Pending,
+            dues: Vec::new(env),
+        };
+        escrow::deposit(env, session_id, &escrow)?;
+        EscrowFunded {
+            session_id,
+            player1_token: escrow.player1_token,
+            player2_token: escrow.player2_token,
+            player1_amount,
+            player2_amount,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Pay out a game's escrow once the game is over, or once its entry
+    /// expired unresolved (`game` = None), deposit by deposit in the token
+    /// it was made in. A win pays the winner both stakes less the escrow's
+    /// `fee_bps` of each, and each player anything they locked beyond their
+    /// stake (hidden stakes lock `max_stake`). A draw, cancellation or
+    /// expiry refunds each player what they locked; a timeout refunds the
+    /// non-offender and adds `FORFEIT_SHARE_BPS` of the offender's locked
+    /// amount, or all of it against the house. Payouts are pushed now, and any the token refuses are held
+    /// for `claim_winnings` or `claim_refund`. Returns the amount pushed
+    fn settle_escrow(env: &Env, session_id: u32, game: Option<&Game>) -> i128 {
+        let Some(mut escrow) = escrow::get(env, session_id) else {
+            return 0;
+        };
+        if escrow.settlement != Settlement::Pending {
+            return 0;
+        }
+        // The house's stake comes back to the bankroll through its dues
+        if escrow.player2 == env.current_contract_address() {
+            bankroll::release(env, &escrow.player2_token, escrow.player2_amount);
+        }
+        // Whether player1 won, where the game has a winner
+        let (settlement, player1_won) = match game.map(|game| (game.phase, game.outcome())) {
+            Some((GamePhase::Resolved, Some(Outcome::Player1Win))) => {
+                (Settlement::Winnings, Some(true))
+            }
+            Some((GamePhase::Resolved, Some(Outcome::Player2Win))) => {
+                (Settlement::Winnings, Some(false))
+            }
+            Some((GamePhase::TimedOut, Some(Outcome::Player1Win))) => {
+                (Settlement::Refund, Some(true))
+            }
+            Some((GamePhase::TimedOut, Some(Outcome::Player2Win))) => {
+                (Settlement::Refund, Some(false))
+            }
+            _ => (Settlement::Refund, None),
+        };
+        escrow.settlement = settlement;
+        let fee_bps = match (settlement, player1_won) {
+            (Settlement::Winnings, Some(player1_won)) => {
+                Self::discount_fee_bps(env, session_id, &escrow, player1_won)
+            }
+            _ => escrow.fee_bps,
+        };
+
+        let (stake1, stake2) = game
+            .map(|game| (game.player1_points, game.player2_points))
+            .unwrap_or_default();
+        let deposits = [
+            (true, escrow.player1_token.clone(), escrow.player1_amount, stake1),
+            (false, escrow.player2_token.clone(), escrow.player2_amount, stake2),
+        ];
+        for (is_player1, token, amount, stake) in deposits {
+            let (owner, opponent) = if is_player1 {
+                (escrow.player1.clone(), escrow.player2.clone())
+            } else {
+                (escrow.player2.clone(), escrow.player1.clone())
+            };
+            let lost = player1_won == Some(!is_player1);
+            let (to_opponent, fee) = match (settlement, lost) {
+                (Settlement::Winnings, lost) => {
+                    let fee = stake * fee_bps as i128 / 10_000;
+                    (if lost { stake - fee } else { 0 }, fee)
+                }
+                // The house cannot miss its reveal, and a player who skips
+                // theirs against it forfeits their whole stake
+                (_, true) if opponent == env.current_contract_address() => (amount, 0),
+                (_, true) => (amount * FORFEIT_SHARE_BPS as i128 / 10_000, 0),
+                _ => (0, 0),
+            };
+            escrow.owe(&owner, &token, amount - to_opponent - fee);
+            escrow.owe(&opponent, &token, to_opponent);
+            if fee > 0 {
+                let share = Self::reward_referrer(env, session_id, &owner, &token, fee);
+                let to_hub = Self::pay_hub(env, session_id, &token, fee - share);
+                let to_epoch = Self::feed_epoch(env, &token, fee - share - to_hub);
+                let rest = fee - share - to_hub - to_epoch;
+                let to_jackpot = Self::feed_jackpot(env, &token, rest);
+                escrow::add_fees(env, &token, rest - to_jackpot);
+            }
+        }
+
+        if let (Settlement::Winnings, Some(player1_won)) = (settlement, player1_won) {
+            Self::award_streak_bonus(env, session_id, &mut escrow, player1_won, (stake1, stake2));
+            Self::award_jackpot(env, session_id, &mut escrow, player1_won);
+            Self::open_dispute_window(env, session_id, &escrow, player1_won);
+        }
+
+        let paid = Self::pay_dues(env, session_id, &mut escrow, false);
+        escrow::save(env, session_id, &escrow);
+        paid
+    }
+
+    /// The fee rate for a won pot: the escrow's `fee_bps`, less the
+    /// discount of the winner's staking tier
+    fn discount_fee_bps(env: &Env, session_id: u32, escrow: &Escrow, player1_won: bool) -> u32 {
+        let winner = if player1_won { &escrow.player1 } else { &escrow.player2 };
+        let Some(tier) = Self::get_stake_tier(env.clone(), winner.clone()) else {
+            return escrow.fee_bps;
+        };
+        if tier.discount_bps == 0 || escrow.fee_bps == 0 {
+            return escrow.fee_bps;
+        }
+        FeeDiscounted {
+            session_id,
+            player: winner.clone(),
+            discount_bps: tier.discount_bps,
+        }
+        .publish(env);
+        (escrow.fee_bps as u64 * (10_000 - tier.discount_bps) as u64 / 10_000) as u32
+    }
+
+    /// Owe the winner of a pot their streak bonus, if one is configured and
+    /// their streak qualifies, in the token they won and out of the fees
+    /// accrued in it
+    fn award_streak_bonus(
+        env: &Env,
+        session_id: u32,
+        escrow: &mut Escrow,
+        player1_won: bool,
+        (stake1, stake2): (i128, i128),
+    ) {
+        let Some(bonus) = Self::get_streak_bonus(env.clone()) else {
+            return;
+        };
+        let (winner, token, won) = if player1_won {
+            (escrow.player1.clone(), escrow.player2_token.clone(), stake2)
+        } else {
+            (escrow.player2.clone(), escrow.player1_token.clone(), stake1)
+        };
+        if winner == env.current_contract_address() {
+            return;
+        }
+        let streak = streak::get(env, &winner).current;
+        let amount = (won * bonus.bonus_bps(streak) as i128 / 10_000)
+            .min(escrow::accrued_fees(env, &token));
+        if amount <= 0 {
+            return;
+        }
+        escrow::add_fees(env, &token, -amount);
+        escrow.owe(&winner, &token, amount);
+        StreakBonusAwarded {
+            session_id,
+            player: winner,
+            streak,
+            token,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Send the hub's share of a `fee` in `token` to the hub's treasury.
+    /// A hub without a treasury, or a treasury the token refuses, takes no
+    /// share, leaving it to the fee collector. Returns the share sent
+    fn pay_hub(env: &Env, session_id: u32, token: &Address, fee: i128) -> i128 {
+        let amount = fee * Self::get_hub_share_bps(env.clone()) as i128 / 10_000;
+        if amount == 0 {
+            return 0;
+        }
+        let Some(hub) = env.storage().instance().get(&DataKey::GameHubAddress) else {
+            return 0;
+        };
+        let Ok(Ok(treasury)) = GameHubClient::new(env, &hub).try_treasury() else {
+            return 0;
+        };
+        if !escrow::push(env, token, &treasury, amount) {
+            return 0;
+        }
+        HubRevenuePaid {
+            session_id,
+            treasury,
+            token: token.clone(),
+            amount,
+        }
+        .publish(env);
+        amount
+    }
+
+    /// Open a won pot's dispute window, if a dispute module is configured,
+    /// locking as much of the winner's free bond in the token they won as
+    /// they are owed in it
+    fn open_dispute_window(env: &Env, session_id: u32, escrow: &Escrow, player1_won: bool) {
+        let Some(config) = Self::get_dispute_config(env.clone()) else {
+            return;
+        };
+        let (winner, loser, token) = if player1_won {
+            (&escrow.player1, &escrow.player2, &escrow.player2_token)
+        } else {
+            (&escrow.player2, &escrow.player1, &escrow.player1_token)
+        };
+        let house = env.current_contract_address();
+        if *winner == house || *loser == house {
+            return;
+        }
+        let won = escrow
+            .dues
+            .iter()
+            .find(|due| due.player == *winner && due.token == *token)
+            .map_or(0, |due| due.amount);
+        let mut bond = disputes::bond(env, winner, token);
+        let locked = won.min(bond.free()).max(0);
+        bond.locked += locked;
+        disputes::save_bond(env, winner, token, &bond);
+        let dispute = Dispute {
+            winner: winner.clone(),
+            loser: loser.clone(),
+            token: token.clone(),
+            locked,
+            deadline_ledger: env.ledger().sequence() + config.window_ledgers,
+            module: config.module,
+            status: DisputeStatus::Open,
+        };
+        disputes::save(env, session_id, Some(&dispute));
+        DisputeWindowOpened {
+            session_id,
+            winner: dispute.winner,
+            token: dispute.token,
+            locked,
+            deadline_ledger: dispute.deadline_ledger,
+        }
+        .publish(env);
+    }
+
+    /// The epoch rules and the running epoch, if epochs are configured
+    fn current_epoch(env: &Env) -> Option<(EpochConfig, u32)> {
+        let config: EpochConfig = env.storage().instance().get(&DataKey::EpochConfig)?;
+        let schedule: EpochSchedule = env.storage().instance().get(&DataKey::EpochSchedule)?;
+        let epoch = schedule.epoch_at(&config, env.ledger().sequence());
+        Some((config, epoch))
+    }
+
+    /// Pay the running epoch's share of a `fee` into its pool, if epochs
+    /// are configured and `token` is theirs. Returns the share
+    fn feed_epoch(env: &Env, token: &Address, fee: i128) -> i128 {
+        let Some((config, epoch)) = Self::current_epoch(env) else {
+            return 0;
+        };
+        let share = fee * config.share_bps as i128 / 10_000;
+        if config.token != *token || share == 0 {
+            return 0;
+        }
+        let mut rewards =
+            epochs::rewards(env, epoch).unwrap_or_else(|| EpochRewards::new(env, token));
+        rewards.pool += share;
+        epochs::save_rewards(env, epoch, &rewards);
+        share
+    }
+
+    /// Count a win for `player` in the running epoch, if epochs are
+    /// configured and `game` was wagered in their token
+    fn count_epoch_win(env: &Env, game: &Game, player: &Address) {
+        let Some((config, epoch)) = Self::current_epoch(env) else {
+            return;
+        };
+        if game.wager_token.as_ref() != Some(&config.token) {
+            return;
+        }
+        let wins = epochs::wins(env, epoch, player) + 1;
+        epochs::set_wins(env, epoch, player, wins);
+        let mut rewards =
+            epochs::rewards(env, epoch).unwrap_or_else(|| EpochRewards::new(env, &config.token));
+        rewards.rank(player, wins, config.top_n);
+        epochs::save_rewards(env, epoch, &rewards);
+    }
+
+    /// Set aside the jackpot's share of a `fee` in `token`, if a jackpot is
+    /// configured. Returns the share
+    fn feed_jackpot(env: &Env, token: &Address, fee: i128) -> i128 {
+        let Some(config) = Self::get_jackpot_config(env.clone()) else {
+            return 0;
+        };
+        let share = fee * config.share_bps as i128 / 10_000;
+        if share > 0 {
+            jackpot::add(env, token, share);
+        }
+        share
+    }
+
+    /// Owe the winner of a pot the jackpot's payout, if a jackpot is
+    /// configured and the win completed their run, in the token they won
+    fn award_jackpot(env: &Env, session_id: u32, escrow: &mut Escrow, player1_won: bool) {
+        let Some(config) = Self::get_jackpot_config(env.clone()) else {
+            return;
+        };
+        let (winner, token) = if player1_won {
+            (escrow.player1.clone(), escrow.player2_token.clone())
+        } else {
+            (escrow.player2.clone(), escrow.player1_token.clone())
+        };
+        let run = jackpot::run(env, &winner);
+        if winner == env.current_contract_address() || run < config.wins_in_a_row {
+            return;
+        }
+        jackpot::set_run(env, &winner, 0);
+        let amount = jackpot::get(env, &token) * config.payout_bps as i128 / 10_000;
+        if amount <= 0 {
+            return;
+        }
+        jackpot::add(env, &token, -amount);
+        escrow.owe(&winner, &token, amount);
+        JackpotWon {
+            session_id,
+            player: winner,
+            run,
+            token,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Credit `player`'s referrer, if they have one, their share of a `fee`
+    /// taken from `player`'s stake. Returns the share
+    fn reward_referrer(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        token: &Address,
+        fee: i128,
+    ) -> i128 {
+        let Some(referrer) = referral::referrer(env, player) else {
+            return 0;
+        };
+        let share = fee * Self::get_referral_share_bps(env.clone()) as i128 / 10_000;
+        if share == 0 {
+            return 0;
+        }
+        let mut rewards = referral::rewards(env, &referrer, token);
+        rewards.earned += share;
+        rewards.claimable += share;
+        referral::save_rewards(env, &referrer, token, &rewards);
+        referral::update_stats(env, &referrer, |stats| stats.raked_games += 1);
+        ReferralRewarded {
+            referrer,
+            referee: player.clone(),
+            session_id,
+            token: token.clone(),
+            amount: share,
+        }
+        .publish(env);
+        share
+    }
+
+    /// Settle a game's side-bet pool, if it has one: a resolved win pays the
+    /// winner's backers, anything else refunds every bet. The rake is pushed
+    /// to the winner, or kept for `claim_side_bet` if the token refuses it
+    fn settle_side_bets(env: &Env, session_id: u32, game: Option<&Game>) {
+        let Some(mut pool) = side_bets::pool(env, session_id) else {
+            return;
+        };
+        if pool.result != SideBetResult::Open {
+            return;
+        }
+        pool.settle(match game.map(|game| (game.phase, game.outcome())) {
+            Some((GamePhase::Resolved, Some(Outcome::Player1Win))) => Some(true),
+            Some((GamePhase::Resolved, Some(Outcome::Player2Win))) => Some(false),
+            _ => None,
+        });
+        SideBetsSettled {
+            session_id,
+            result: pool.result,
+            rake: pool.rake,
+        }
+        .publish(env);
+
+        let winner = match pool.result {
+            SideBetResult::Player1Won => pool.player1.clone(),
+            _ => pool.player2.clone(),
+        };
+        if pool.rake > 0 && escrow::push(env, &pool.token, &winner, pool.rake) {
+            SideBetPaid {
+                session_id,
+                recipient: winner,
+                token: pool.token.clone(),
+                amount: pool.rake,
+            }
+            .publish(env);
+            pool.rake = 0;
+        }
+        side_bets::save_pool(env, session_id, &pool);
+    }
+
+    /// Push what a settled escrow still owes each player, publishing an
+    /// event per payout (`claimed` = pulled by `claim_winnings` or
+    /// `claim_refund`). Dues the token refuses stay on `escrow`. Returns
+    /// the amount paid, summed over tokens
+    fn pay_dues(env: &Env, session_id: u32, escrow: &mut Escrow, claimed: bool) -> i128 {
+        let settlement = escrow.settlement;
+        let mut paid = 0;
+        let mut held = Vec::new(env);
+        for due in escrow.dues.iter() {
+            let pushed = if due.player == env.current_contract_address() {
+                bankroll::credit(env, &due.token, due.amount);
+                true
+            } else {
+                escrow::push(env, &due.token, &due.player, due.amount)
+            };
+            if pushed {
+                paid += due.amount;
+            } else {
+                held.push_back(due.clone());
+            }
+            let Due { player, token, amount } = due;
+            match (settlement, claimed, pushed) {
+                (Settlement::Winnings, false, true) => {
+                    WinningsPaid { session_id, player, token, amount }.publish(env)
+                }
+                (Settlement::Winnings, false, false) => {
+                    WinningsHeld { session_id, player, token, amount }.publish(env)
+                }
+                (Settlement::Winnings, true, true) => {
+                    WinningsClaimed { session_id, player, token, amount }.publish(env)
+                }
+                (Settlement::Refund, false, true) => {
+                    RefundPaid { session_id, player, token, amount }.publish(env)
+                }
+                (Settlement::Refund, false, false) => {
+                    RefundHeld { session_id, player, token, amount }.publish(env)
+                }
+                (Settlement::Refund, true, true) => {
+                    RefundClaimed { session_id, player, token, amount }.publish(env)
+                }
+                // Still refused: it stays held
+                (_, true, false) | (Settlement::Pending, _, _) => {}
+            }
+        }
+        escrow.dues = held;
+        paid
+    }
+
+    /// Check points about to be staked against `get_wager_limits`
+    fn check_wagers(env: &Env, lowest: i128, highest: i128) -> Result<(), Error> {
+        let limits = Self::get_wager_limits(env.clone());
+        if lowest.min(highest) < limits.min_wager {
+            return Err(Error::WagerTooSmall);
+        }
+        if lowest.max(highest) > limits.max_wager {
+            return Err(Error::WagerTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Add a new game to both players' open games, returning
+    /// `TooManyOpenGames` if a limit is set and either already has
+    /// `get_max_open_games` in play.
+    ///
+    /// Games leave the lists when they end (`untrack_open_game`). Under a
+    /// limit, games that expired unresolved are also pruned here so they
+    /// stop counting; that reads every listed game, which the limit's cap
+    /// keeps within one transaction's footprint.
+    fn track_open_game(env: &Env, session_id: u32, game: &Game) -> Result<(), Error> {
+        let limit = Self::get_max_open_games(env.clone());
+        for player in [&game.player1, &game.player2] {
+            // The house plays any number of games
+            if *player == env.current_contract_address() {
+                continue;
+            }
+            let key = DataKey::PlayerGames(player.clone());
+            let mut sessions = match limit {
+                Some(limit) => {
+                    let open = Self::get_open_games(env.clone(), player.clone());
+                    if open.len() >= limit {
+                        return Err(Error::TooManyOpenGames);
+                    }
+                    open
+                }
+                None => env.storage().persistent().get(&key).unwrap_or(vec![env]),
+            };
+            sessions.push_back(session_id);
+
+            env.storage().persistent().set(&key, &sessions);
+            env.storage().persistent().extend_ttl(
+                &key,
+                OPEN_SESSION_TTL_LEDGERS,
+                OPEN_SESSION_TTL_LEDGERS,
+            );
+        }
+        Ok(())
+    }
+
+    /// Drop a game that ended, or was abandoned, from its players' open
+    /// games
+    fn untrack_open_game(env: &Env, session_id: u32, players: [&Address; 2]) {
+        for player in players {
+            let key = DataKey::PlayerGames(player.clone());
+            let Some(mut sessions) = env.storage().persistent().get::<_, Vec<u32>>(&key) else {
+                continue;
+            };
+            let Some(index) = sessions.first_index_of(session_id) else {
+                continue;
+            };
+            sessions.remove(index);
+            if sessions.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &sessions);
+            }
+        }
+    }
+
+    /// Apply `update` to the contract-wide totals
+    fn update_global_stats(env: &Env, update: impl FnOnce(&mut GlobalStats)) {
+        let mut stats = Self::get_global_stats(env.clone());
+        update(&mut stats);
+        stats.refresh();
+        let key = DataKey::GlobalStats;
+        env.storage().persistent().set(&key, &stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+    }
+
+    /// Write back a game that changed, stamping it with the current ledger
+    fn save_game(env: &Env, session_id: u32, game: &mut Game) {
+        game.last_activity_ledger = env.ledger().sequence();
+        env.storage().temporary().set(&DataKey::Game(session_id), game);
+    }
+
+    /// Store a freshly started game and announce it
+    fn store_new_game(env: &Env, session_id: u32, game: &Game) {
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::update_global_stats(env, |stats| {
+            stats.games_started += 1;
+            stats.total_volume = stats
+                .total_volume
+                .saturating_add(game.player1_points)
+                .saturating_add(game.player2_points);
+        });
+        GameStarted {
+            session_id,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            player1_points: game.player1_points,
+            player2_points: game.player2_points,
+        }
+        .publish(env);
+    }
+
+    /// Record a session the hub has been told about, with the points it
+    /// holds, until `close_session`
+    fn open_session(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        (player1_points, player2_points): (i128, i128),
+    ) {
+        let key = DataKey::OpenSession(session_id);
+        let record = OpenSession {
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            player1_points,
+            player2_points,
+        };
+        env.storage().persistent().set(&key, &record);
+        env.storage().persistent().extend_ttl(
+            &key,
+            OPEN_SESSION_TTL_LEDGERS,
+            OPEN_SESSION_TTL_LEDGERS,
+        );
+    }
+
+    /// Temporary entries that live and die with a game: the game itself,
+    /// its optional per-session state and any deferred proofs
+    fn game_entries(session_id: u32, game: &Game) -> [DataKey; 9] {
+        [
+            DataKey::Game(session_id),
+            DataKey::MultiRound(session_id),
+            DataKey::HiddenStakes(session_id),
+            DataKey::Odds(session_id),
+            DataKey::Teams(session_id),
+            DataKey::RatingBracket(session_id),
+            DataKey::TiebreakEntropy(session_id),
+            DataKey::PendingProof(session_id, game.player1.clone()),
+            DataKey::PendingProof(session_id, game.player2.clone()),
+        ]
+    }
+
+    /// Append a settled game to both players' match history
+    ///
+    /// The temporary game entry is deliberately left to expire rather than
+    /// dropped here: the settled entry is what keeps `resolve_match`
+    /// idempotent (a second call, or a hub re-entering it, returns the stored
+    /// outcome instead of `GameNotFound`), what `get_attestation` commits to,
+    /// and what `get_game` shows right after settlement. The record written
+    /// here is what outlives it, whether the entry expires or a player
+    /// drops it with `purge_game`.
+    fn archive(env: &Env, session_id: u32, game: &Game, outcome: Outcome) {
+        let record = MatchRecord {
+            session_id,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            player1_points: game.player1_points,
+            player2_points: game.player2_points,
+            player1_score: game.player1_score,
+            player2_score: game.player2_score,
+            outcome,
+            ledger: env.ledger().sequence(),
+        };
+        let forfeit = game.phase == GamePhase::TimedOut;
+        Self::update_global_stats(env, |stats| {
+            if forfeit {
+                stats.games_timed_out += 1;
+            } else {
+                stats.games_resolved += 1;
+            }
+        });
+        let storage = env.storage().persistent();
+        let tactics = [game.player1_tactic, game.player2_tactic];
+        for (player, tactic) in [&game.player1, &game.player2].into_iter().zip(tactics) {
+            let stats_key = DataKey::PlayerStats(player.clone());
+            let mut stats = storage
+                .get(&stats_key)
+                .unwrap_or_else(|| PlayerStats::new(env));
+            let summary = record.summary(0, player);
+            stats.record(summary.result, forfeit, summary.points, tactic);
+            streak::record(env, player, summary.result);
+            if summary.result == MatchResult::Won && *player != env.current_contract_address() {
+                Self::count_epoch_win(env, game, player);
+            }
+            if let (Some(_), Some(config)) =
+                (&game.wager_token, Self::get_jackpot_config(env.clone()))
+            {
+                let run = jackpot::run(env, player);
+                let extended = summary.result == MatchResult::Won && config.is_trigger(game, outcome);
+                jackpot::set_run(env, player, if extended { run + 1 } else { 0 });
+            }
+            storage.set(&stats_key, &stats);
+            storage.extend_ttl(&stats_key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+
+            let count_key = DataKey::MatchCount(player.clone());
+            let count: u32 = storage.get(&count_key).unwrap_or(0);
+            let record_key = DataKey::MatchRecord(player.clone(), count);
+            storage.set(&record_key, &record);
+            storage.set(&count_key, &(count + 1));
+            for key in [&record_key, &count_key] {
+                storage.extend_ttl(key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+            }
+        }
+    }
+
+    /// Handshake with a hub before pointing the contract at it: anything
+    /// that is not a contract, does not export `version()` or is too old
+    /// fails with `InvalidHub`
+    fn check_hub(env: &Env, hub: &Address) -> Result<(), Error> {
+        match GameHubClient::new(env, hub).try_version() {
+            Ok(Ok(version)) if version >= MIN_HUB_VERSION => Ok(()),
+            _ => Err(Error::InvalidHub),
+        }
+    }
+
+    /// Report a settled game to the hub, returning whether it accepted the
+    /// result. Wins go through `end_game`, which every hub exports; only
+    /// draws need `end_game_with_outcome`
+    fn end_hub_game(game_hub: &GameHubClient, session_id: u32, outcome: Outcome) -> bool {
+        match outcome {
+            Outcome::Player1Win => game_hub.try_end_game(&session_id, &true).is_ok(),
+            Outcome::Player2Win => game_hub.try_end_game(&session_id, &false).is_ok(),
+            Outcome::Draw => game_hub
+                .try_end_game_with_outcome(&session_id, &outcome)
+                .is_ok(),
+        }
+    }
+
+    /// Tell the hub how a settled game ended.
+    ///
+    /// A hub that traps does not undo the settlement: the game keeps
+    /// `hub_notified` false and its open session record, and a
+    /// `HubNotifyFailed` event asks for `retry_hub_notify`. The failed hub
+    /// call's own changes are rolled back, so retrying cannot report twice.
+    fn notify_hub(env: &Env, session_id: u32, game: &mut Game) -> Result<(), Error> {
+        let outcome = game.outcome().ok_or(Error::GameNotSettled)?;
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::HubNotSet)?;
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+
+        // A hidden-stake session holds `max_stake` from each player at the
+        // hub, lowered now to the stakes revealed (or 0 after a forfeit).
+        // Lowering to the same stakes again is a no-op, so retries can
+        let hidden = env
+            .storage()
+            .temporary()
+            .has(&DataKey::HiddenStakes(session_id));
+        let mut reported = true;
+        if hidden {
+            let points = Self::hub_stakes(game, (game.player1_points, game.player2_points));
+            reported = game_hub
+                .try_reduce_stakes(&session_id, &points.0, &points.1)
+                .is_ok();
+            if reported {
+                Self::open_session(env, session_id, game, points);
+            }
+        }
+        reported = reported && Self::end_hub_game(&game_hub, session_id, outcome);
+
+        if !reported {
+            HubNotifyFailed {
+                session_id,
+                outcome,
+            }
+            .publish(env);
+            return Ok(());
+        }
+        Self::close_session(env, session_id);
+        game.hub_notified = true;
+        Self::save_game(env, session_id, game);
+        Ok(())
+    }
+
+    /// Drop a session's open record once its result goes to the hub
+    fn close_session(env: &Env, session_id: u32) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OpenSession(session_id));
+    }
+
+    /// Verify a ZK proof for tactical choice submission
+    ///
+    /// **Verification steps:**
+    /// 1. Basic proof validation (length, format)
+    /// 2. Commitment, nullifier and tactic hash field checks
+    /// 3. Depending on the game's `ProofSystem`:
+    ///    - Groth16: over BN254 (Protocol 25 host functions) if a
+    ///      verification key is stored or resolved from the registry (see
+    ///      `set_vk_source`), otherwise the external verifier contract if
+    ///      one is configured via `set_verifier`
+    ///    - UltraHonk: the verifier contract set via `set_ultrahonk_verifier`
+    ///
+    /// Public inputs are `[session_id, player_binding, valid_until_ledger,
+    /// commitment, nullifier, tactic_hash]` as 32-byte field elements (see
+    /// `PublicInputs`), so a valid proof shows it was made for this player in
+    /// this deployment with this expiry, and that the stored commitment, the
+    /// consumed nullifier and the tactic hash are exactly what the circuit
+    /// computed for a tactic in range.
+    ///
+    /// # Arguments
+    /// * `env` - Environment
+    /// * `proof_system` - Backend the game was started with
+    /// * `proof` - ZK proof bytes from Noir circuit
+    /// * `inputs` - The claimed public inputs, already checked against the
+    ///   session and player
+    ///
+    /// # Returns
+    /// * `Ok(())` if proof is valid
+    /// * `Err(Error::InvalidProof)` if proof is invalid
+    fn verify_zk_proof(
+        env: &Env,
+        proof_system: ProofSystem,
+        proof: &Bytes,
+        inputs: &PublicInputs,
+    ) -> Result<(), Error> {
+        Self::check_proof_format(proof, inputs)?;
+
+        if proof_system == ProofSystem::UltraHonk {
+            return Self::verify_ultrahonk(env, proof, inputs);
+        }
+
+        let public_inputs = inputs.to_fields(env);
+
+        // Native Groth16 verification when a verification key is stored or
+        // resolved from the registry
+        let vk = Self::verification_key(env);
+        if let Some(vk) = vk {
+            if !groth16::verify(env, &vk, proof, &public_inputs) {
+                return Err(Error::InvalidProof);
+            }
+            return Ok(());
+        }
+
+        // Otherwise delegate to the external verifier contract; with neither
+        // configured no proof is accepted
+        let verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Verifier)
+            .ok_or(Error::VerifierNotConfigured)?;
+        let verifier = VerifierClient::new(env, &verifier_addr);
+        if !verifier.verify(proof, &public_inputs) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Checks on the proof and its public inputs that need no verifier
+    /// (steps 1 and 2 of `verify_zk_proof`)
+    fn check_proof_format(proof: &Bytes, inputs: &PublicInputs) -> Result<(), Error> {
+        // Basic validation: proof should not be empty
+        if proof.is_empty() {
+            return Err(Error::InvalidProof);
+        }
+
+        // The commitment is a circuit output, so it must be a field element
+        if !groth16::is_field_element(&inputs.commitment) {
+            return Err(Error::InvalidCommitment);
+        }
+
+        // Same for the nullifier and tactic hash; no proof can attest to a
+        // larger value
+        if !groth16::is_field_element(&inputs.nullifier)
+            || !groth16::is_field_element(&inputs.tactic_hash)
+        {
+            return Err(Error::InvalidProof);
+        }
+
+        Self::check_proof_bytes(proof)
+    }
+
+    /// Reject a proof over `get_max_proof_size()` bytes before anything
+    /// hashes, scans or stores it
+    fn check_proof_size(env: &Env, proof: &Bytes) -> Result<(), Error> {
+        if proof.len() > Self::get_max_proof_size(env.clone()) {
+            return Err(Error::ProofTooLarge);
+        }
+        Ok(())
+    }
+
+    /// keccak256 of `proof`, which must differ from the opponent's
+    ///
+    /// A player who resubmits the opponent's proof bytes is rejected with
+    /// `DuplicateProof` before any verification.
+    fn distinct_proof_hash(
+        env: &Env,
+        game: &Game,
+        is_player1: bool,
+        proof: &Bytes,
+    ) -> Result<BytesN<32>, Error> {
+        let proof_hash: BytesN<32> = env.crypto().keccak256(proof).into();
+        let opponent_hash = if is_player1 {
+            &game.player2_proof_hash
+        } else {
+            &game.player1_proof_hash
+        };
+        if opponent_hash.as_ref() == Some(&proof_hash) {
+            return Err(Error::DuplicateProof);
+        }
+        Ok(proof_hash)
+    }
+
+    /// Length and non-zero checks any proof must pass
+    fn check_proof_bytes(proof: &Bytes) -> Result<(), Error> {
+        // Validate proof minimum length (Barretenberg proofs are typically > 100 bytes)
+        if proof.len() < 32 {
+            return Err(Error::InvalidProof);
+        }
+
+        // Additional validation: check that proof is not all zeros
+        let mut all_zeros = true;
+        for i in 0..proof.len() {
+            if proof.get(i).unwrap_or(0) != 0 {
+                all_zeros = false;
+                break;
+            }
+        }
+
+        if all_zeros {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(())
+    }
+
+    /// Check the deferred proofs of a batch-verified game
+    ///
+    /// With a verification key stored, both proofs share one pairing check;
+    /// only if that fails is each checked on its own to find the invalid
+    /// one. Otherwise each goes through `verify_zk_proof`.
+    ///
+    /// # Returns
+    /// * Whether player1's and player2's proofs are valid, or
+    ///   `VerifierNotConfigured` if nothing can check them
+    fn verify_pending(env: &Env, session_id: u32, game: &Game) -> Result<(bool, bool), Error> {
+        let pending = |player: &Address| -> Result<PendingProof, Error> {
+            env.storage()
+                .temporary()
+                .get(&DataKey::PendingProof(session_id, player.clone()))
+                .ok_or(Error::BothPlayersNotSubmitted)
+        };
+        let proof1 = pending(&game.player1)?;
+        let proof2 = pending(&game.player2)?;
+
+        let vk = Self::verification_key(env);
+        let Some(vk) = vk else {
+            let verify = |pending: &PendingProof| match Self::verify_zk_proof(
+                env,
+                ProofSystem::Groth16,
+                &pending.proof,
+                &pending.inputs,
+            ) {
+                Err(Error::VerifierNotConfigured) => Err(Error::VerifierNotConfigured),
+                result => Ok(result.is_ok()),
+            };
+            return Ok((verify(&proof1)?, verify(&proof2)?));
+        };
+
+        let batch = [
+            (proof1.proof, proof1.inputs.to_fields(env)),
+            (proof2.proof, proof2.inputs.to_fields(env)),
+        ];
+        if groth16::verify_batch(env, &vk, &batch) {
+            return Ok((true, true));
+        }
+        let [(proof1, inputs1), (proof2, inputs2)] = &batch;
+        Ok((
+            groth16::verify(env, &vk, proof1, inputs1),
+            groth16::verify(env, &vk, proof2, inputs2),
+        ))
+    }
+
+    /// The Groth16 verification key in force: the configured registry entry,
+    /// else the stored key
+    fn verification_key(env: &Env) -> Option<Bytes> {
+        let source: Option<VkSource> = env.storage().instance().get(&DataKey::VkSource);
+        match source {
+            Some(source) => {
+                let registry = VkRegistryClient::new(env, &source.registry);
+                Some(registry.get_vk(&source.circuit_id, &source.version))
+            }
+            None => env.storage().instance().get(&DataKey::VerificationKey),
+        }
+    }
+
+    /// UltraHonk branch of `verify_zk_proof`, after the shared format checks
+    fn verify_ultrahonk(env: &Env, proof: &Bytes, inputs: &PublicInputs) -> Result<(), Error> {
+        if !ultrahonk::is_well_formed(proof) {
+            return Err(Error::InvalidProof);
+        }
+
+        let verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::UltraHonkVerifier)
+            .ok_or(Error::VerifierNotConfigured)?;
+        if !ultrahonk::verify(env, &verifier_addr, proof, &inputs.to_bytes(env)) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Submit tactical choice with ZK proof.
+    ///
+    /// **ZK Proof validates:**
+    /// - tactic ∈ [0-3]
+    /// - player identity
+    /// - hasn't already submitted
+    ///
+    /// The tactic is not part of the submission: only its hash is stored,
+    /// and the player opens it with `reveal_tactic` once both have submitted.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `inputs` - The proof's public inputs:
+    ///   - `session_id` - must equal `session_id`
+    ///   - `player_binding` - must equal `get_player_binding(player)`
+    ///   - `valid_until_ledger` - Last ledger the proof may be submitted in,
+    ///     at most `get_proof_freshness_window()` ledgers ahead; 0 for no
+    ///     expiry, only accepted while no window is set
+    ///   - `commitment` - Poseidon2(tactic, secret, session_id), the circuit's output
+    ///   - `nullifier` - Poseidon2(secret, session_id), consumed on success so
+    ///     the same proof cannot be submitted twice
+    ///   - `tactic_hash` - `tactic_hash(tactic, secret, session_id)`, where
+    ///     tactic is the choice (0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut)
+    /// * `proof` - ZK proof bytes (Noir-generated proof for on-chain verification)
+    ///
+    /// Signed by `player`, or by their session key if a valid grant exists
+    /// (see `authorize_session_key`).
+    pub fn submit_tactic(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        inputs: PublicInputs,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+        Self::record_submission(
+            &env,
+            session_id,
+            &player,
+            &inputs,
+            &proof,
+            BARE_PROOF_VERSION,
+            None,
+        )
+    }
+
+    /// Submit a tactic with a versioned proof.
+    ///
+    /// As `submit_tactic`, but the proof comes in a `ProofEnvelope`: its
+    /// version must lie between `get_min_proof_version()` and
+    /// `PROOF_VERSION`, and its system must be the game's.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `inputs` - The proof's public inputs (as for `submit_tactic`)
+    /// * `envelope` - The proof with its version and proof system
+    ///
+    /// Fails with `UnsupportedProofVersion` for a retired or unknown
+    /// version, and `WrongProofSystem` for another backend's proof.
+    pub fn submit_tactic_envelope(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        inputs: PublicInputs,
+        envelope: ProofEnvelope,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+        Self::record_submission(
+            &env,
+            session_id,
+            &player,
+            &inputs,
+            &envelope.payload,
+            envelope.version,
+            Some(envelope.system),
+        )
+    }
+
+    /// Submit a tactic on a player's behalf using their ed25519 signature.
+    ///
+    /// Any relayer can call this and pay the fee, so players without XLM can
+    /// still play. The player signs the XDR of `SignedSubmission` (see
+    /// `submission_payload`) with their account's ed25519 key; the nonce must
+    /// equal `get_nonce(player)` and is consumed on success.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address (must be a `G...` account)
+    /// * `inputs` - The proof's public inputs (as for `submit_tactic`)
+    /// * `proof` - ZK proof bytes
+    /// * `nonce` - The player's current submission nonce
+    /// * `expiration_ledger` - Last ledger sequence the signature is valid for
+    /// * `signature` - ed25519 signature over the payload
+    ///
+    /// Traps if the signature does not verify.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_tactic_signed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        inputs: PublicInputs,
+        proof: Bytes,
+        nonce: u64,
+        expiration_ledger: u32,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        let public_key = match player.to_payload() {
+            Some(AddressPayload::AccountIdPublicKeyEd25519(key)) => key,
+            _ => return Err(Error::InvalidSigner),
+        };
+
+        if env.ledger().sequence() > expiration_ledger {
+            return Err(Error::SignatureExpired);
+        }
+
+        if nonce != Self::get_nonce(env.clone(), player.clone()) {
+            return Err(Error::InvalidNonce);
+        }
+
+        // Before the proof is hashed into the signed payload
+        Self::check_proof_size(&env, &proof)?;
+
+        let message = Self::submission_payload(
+            env.clone(),
+            session_id,
+            player.clone(),
+            inputs.clone(),
+            proof.clone(),
+            nonce,
+            expiration_ledger,
+        );
+        env.crypto().ed25519_verify(&public_key, &message, &signature);
+
+        let nonce_key = DataKey::Nonce(player.clone());
+        env.storage().persistent().set(&nonce_key, &(nonce + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&nonce_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::record_submission(
+            &env,
+            session_id,
+            &player,
+            &inputs,
+            &proof,
+            BARE_PROOF_VERSION,
+            None,
+        )
+    }
+
+    /// Bytes a player signs for `submit_tactic_signed`
+    ///
+    /// Binds the signature to this contract, the session, the proof's public
+    /// inputs and the exact proof (by keccak256 hash), so a relayer cannot
+    /// alter any of them.
+    pub fn submission_payload(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        inputs: PublicInputs,
+        proof: Bytes,
+        nonce: u64,
+        expiration_ledger: u32,
+    ) -> Bytes {
+        SignedSubmission {
+            contract: env.current_contract_address(),
+            session_id,
+            player,
+            inputs,
+            proof_hash: env.crypto().keccak256(&proof).into(),
+            nonce,
+            expiration_ledger,
+        }
+        .to_xdr(&env)
+    }
+
+    /// The `player_binding` public input a proof for `player` must carry
+    pub fn get_player_binding(env: Env, player: Address) -> BytesN<32> {
+        player_binding(&env, &env.current_contract_address(), &player)
+    }
+
+    /// Whether `nullifier` has already been consumed by a submission
+    pub fn is_nullifier_used(env: Env, nullifier: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Nullifier(nullifier))
+    }
+
+    /// The `rating_key` public input a rating proof for `player` must carry
+    pub fn get_rating_key(env: Env, player: Address) -> BytesN<32> {
+        rating_key(&env, &player)
+    }
+
+    /// Whether an identity has already played a ranked game in `bracket`
+    pub fn is_identity_used(env: Env, bracket: u32, nullifier: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::IdentityNullifier(bracket, nullifier))
+    }
+
+    /// Next nonce `submit_tactic_signed` expects for `player`
+    pub fn get_nonce(env: Env, player: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Nonce(player))
+            .unwrap_or(0)
+    }
+
+    /// Fail once `game`'s submit window has closed; from then on only
+    /// `resolve_timeout` can move it forward
+    fn check_submit_window(env: &Env, game: &Game) -> Result<(), Error> {
+        if env.ledger().sequence() > game.submit_deadline_ledger {
+            return Err(Error::SubmitDeadlinePassed);
+        }
+        Ok(())
+    }
+
+    /// Fail once `game`'s reveal window has closed
+    fn check_reveal_window(env: &Env, game: &Game) -> Result<(), Error> {
+        if env.ledger().sequence() > game.resolve_deadline_ledger {
+            return Err(Error::RevealDeadlinePassed);
+        }
+        Ok(())
+    }
+
+    /// Fail while the admin has paused new games and submissions
+    /// Require `caller`'s signature and that they are the admin or hold
+    /// `role`
+    fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+        caller.require_auth();
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if *caller != admin && !Self::has_role(env.clone(), caller.clone(), role) {
+            return Err(Error::MissingRole);
+        }
+        Ok(())
+    }
+
+    /// Like `require_role`, but once an admin council is set the admin's own
+    /// signature no longer does: `caller` must hold `role` itself, or the
+    /// council must have approved `action`
+    fn require_role_or_council(
+        env: &Env,
+        caller: &Address,
+        role: Role,
+        action: CouncilAction,
+    ) -> Result<(), Error> {
+        if Self::get_admin_council(env.clone()).is_none()
+            || Self::has_role(env.clone(), caller.clone(), role)
+        {
+            return Self::require_role(env, caller, role);
+        }
+        caller.require_auth();
+        Self::authorize(env, action)
+    }
+
+    /// Authorize an admin action: by the admin's signature, or once an admin
+    /// council is set, by `threshold` of its members having approved exactly
+    /// this action with `approve_action`. Approvals are used up by the
+    /// action they authorize.
+    fn authorize(env: &Env, action: CouncilAction) -> Result<(), Error> {
+        let Some(council) = Self::get_admin_council(env.clone()) else {
+            let admin: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(Error::NotInitialized)?;
+            admin.require_auth();
+            return Ok(());
+        };
+
+        // Only members still on the council count
+        let approvals = Self::get_approvals(env.clone(), action.clone());
+        let approved = approvals
+            .iter()
+            .filter(|member| council.members.contains(member))
+            .count() as u32;
+        if approved < council.threshold {
+            return Err(Error::NotApproved);
+        }
+        env.storage().temporary().remove(&DataKey::Approvals(action));
+        Ok(())
+    }
+
+    fn require_unpaused(env: &Env) -> Result<(), Error> {
+        if Self::is_paused(env.clone()) {
+            return Err(Error::Paused);
+        }
+        Ok(())
+    }
+
+    /// Check the session, player binding and expiry a proof was made for
+    fn check_proof_scope(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        proof_session_id: u32,
+        proof_binding: &BytesN<32>,
+        valid_until_ledger: u32,
+    ) -> Result<(), Error> {
+        // The proof must be for this session and this player of this
+        // contract, so it cannot be replayed elsewhere
+        if proof_session_id != session_id {
+            return Err(Error::ProofSessionMismatch);
+        }
+        let expected_binding = player_binding(env, &env.current_contract_address(), player);
+        if *proof_binding != expected_binding {
+            return Err(Error::ProofPlayerMismatch);
+        }
+
+        // A proof is only usable up to the expiry it was generated with, so
+        // one leaked before submission (e.g. from a simulation) goes stale
+        let now = env.ledger().sequence();
+        let window: Option<u32> = env.storage().instance().get(&DataKey::ProofFreshnessWindow);
+        if valid_until_ledger == 0 {
+            if window.is_some() {
+                return Err(Error::ProofValidityTooLong);
+            }
+        } else if now > valid_until_ledger {
+            return Err(Error::ProofExpired);
+        } else if window.is_some_and(|window| valid_until_ledger - now > window) {
+            return Err(Error::ProofValidityTooLong);
+        }
+
+        Ok(())
+    }
+
+    /// Validate and store a submission once its signer has been checked
+    ///
+    /// `system` is the backend an enveloped proof claims; bare proofs are
+    /// taken to be for the game's.
+    fn record_submission(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        inputs: &PublicInputs,
+        proof: &Bytes,
+        version: u32,
+        system: Option<ProofSystem>,
+    ) -> Result<(), Error> {
+        Self::require_unpaused(env)?;
+        Self::check_proof_size(env, proof)?;
+
+        // Retired circuits stay retired however the proof is submitted
+        let min_version = Self::get_min_proof_version(env.clone());
+        if version < min_version || version > PROOF_VERSION {
+            return Err(Error::UnsupportedProofVersion);
+        }
+
+        Self::check_proof_scope(
+            env,
+            session_id,
+            player,
+            inputs.session_id,
+            &inputs.player_binding,
+            inputs.valid_until_ledger,
+        )?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_submissions_open()?;
+        Self::check_submit_window(env, &game)?;
+
+        let is_player1 = if *player == game.player1 {
+            true
+        } else if *player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Commit-reveal games have no proof to check, and multi-round games
+        // take one proof for all rounds (`submit_rounds`)
+        if game.proof_system == ProofSystem::CommitReveal || Self::is_multi_round(env, session_id) {
+            return Err(Error::WrongProofSystem);
+        }
+        if system.is_some_and(|system| system != game.proof_system) {
+            return Err(Error::WrongProofSystem);
+        }
+
+        let submitted = if is_player1 {
+            game.player1_tactic_hash.is_some()
+        } else {
+            game.player2_tactic_hash.is_some()
+        };
+        if submitted {
+            return Err(Error::AlreadySubmitted);
+        }
+        let proof_hash = Self::distinct_proof_hash(env, &game, is_player1, proof)?;
+
+        let nullifier_key = DataKey::Nullifier(inputs.nullifier.clone());
+        if env.storage().persistent().has(&nullifier_key) {
+            return Err(Error::NullifierUsed);
+        }
+
+        // Verify ZK proof with the game's backend, binding the commitment and
+        // nullifier as public inputs. Batch-verified games only get the
+        // format checks here; the proof itself is checked at resolution
+        if game.batch_verification {
+            Self::check_proof_format(proof, inputs)?;
+
+            let pending_key = DataKey::PendingProof(session_id, player.clone());
+            let pending = PendingProof {
+                inputs: inputs.clone(),
+                proof: proof.clone(),
+            };
+            env.storage().temporary().set(&pending_key, &pending);
+            env.storage()
+                .temporary()
+                .extend_ttl(&pending_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        } else {
+            Self::verify_zk_proof(env, game.proof_system, proof, inputs)?;
+        }
+
+        if is_player1 {
+            game.player1_commitment = Some(inputs.commitment.clone());
+            game.player1_proof_hash = Some(proof_hash);
+            game.player1_tactic_hash = Some(inputs.tactic_hash.clone());
+        } else {
+            game.player2_commitment = Some(inputs.commitment.clone());
+            game.player2_proof_hash = Some(proof_hash);
+            game.player2_tactic_hash = Some(inputs.tactic_hash.clone());
+        }
+        game.advance_submission();
+        TacticSubmitted {
+            session_id,
+            player: player.clone(),
+            phase: game.phase,
+        }
+        .publish(env);
+        Self::play_house(env, session_id, &mut game)?;
+
+        // Consume the nullifier; it outlives the game so the proof cannot be
+        // replayed if the session ID is ever reused. A deferred proof's
+        // nullifier is only consumed once the proof verifies
+        if !game.batch_verification {
+            Self::consume_nullifier(env, &nullifier_key, session_id);
+        }
+
+        Self::save_game(env, session_id, &mut game);
+        Ok(())
+    }
+
+    /// Mark a proof's nullifier used, for longer than any game lasts
+    fn consume_nullifier(env: &Env, nullifier_key: &DataKey, session_id: u32) {
+        env.storage().persistent().set(nullifier_key, &session_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(nullifier_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Drop `player`'s deferred proof once their batch-verified game is
+    /// settled, consuming its nullifier if the proof verified
+    fn settle_pending_proof(env: &Env, session_id: u32, player: &Address, valid: bool) {
+        let pending_key = DataKey::PendingProof(session_id, player.clone());
+        let pending: Option<PendingProof> = env.storage().temporary().get(&pending_key);
+        if let Some(pending) = pending {
+            if valid {
+                let nullifier_key = DataKey::Nullifier(pending.inputs.nullifier);
+                Self::consume_nullifier(env, &nullifier_key, session_id);
+            }
+            env.storage().temporary().remove(&pending_key);
+        }
+    }
+
+    /// Commit to a tactic without a proof, in a `CommitReveal` game.
+    ///
+    /// Nothing checks the tactic until `reveal_tactic`, so a player who
+    /// commits to an out-of-range tactic simply cannot reveal.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `hash` - `tactic_hash(tactic, salt, session_id)` for a random
+    ///   32-byte salt the player keeps until the reveal
+    ///
+    /// Signed by `player`, or by their session key (as for `submit_tactic`).
+    pub fn commit_tactic(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+        Self::require_unpaused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_submissions_open()?;
+        Self::check_submit_window(&env, &game)?;
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if game.proof_system != ProofSystem::CommitReveal || Self::is_multi_round(&env, session_id)
+        {
+            return Err(Error::WrongProofSystem);
+        }
+
+        let (committed, opponent) = if is_player1 {
+            (&game.player1_tactic_hash, &game.player2_tactic_hash)
+        } else {
+            (&game.player2_tactic_hash, &game.player1_tactic_hash)
+        };
+        if committed.is_some() {
+            return Err(Error::AlreadySubmitted);
+        }
+
+        // `tactic_hash` clears the top byte, so nothing else can be opened.
+        // Copying the opponent's hash would let a player echo their reveal
+        if !groth16::is_field_element(&hash) || opponent.as_ref() == Some(&hash) {
+            return Err(Error::InvalidCommitment);
+        }
+
+        if is_player1 {
+            game.player1_tactic_hash = Some(hash);
+        } else {
+            game.player2_tactic_hash = Some(hash);
+        }
+        game.advance_submission();
+        TacticSubmitted {
+            session_id,
+            player,
+            phase: game.phase,
+        }
+        .publish(&env);
+        Self::play_house(&env, session_id, &mut game)?;
+
+        Self::save_game(&env, session_id, &mut game);
+        Ok(())
+    }
+
+    /// In a house game, mark the house submitted once the player has
+    /// committed, binding the house's tactic to the beacon's pending round.
+    /// The round's seed was committed before the player's tactic and stays
+    /// secret until it is revealed, so neither side can choose the other's
+    /// tactic. The hash is a placeholder until `draw_house_tactic`
+    fn play_house(env: &Env, session_id: u32, game: &mut Game) -> Result<(), Error> {
+        if game.player2 != env.current_contract_address() || game.player2_tactic_hash.is_some() {
+            return Ok(());
+        }
+        let beacon: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::HouseBeacon)
+            .ok_or(Error::HouseBeaconNotSet)?;
+        let round = match BeaconClient::new(env, &beacon).try_pending_round() {
+            Ok(Ok(round)) => round,
+            _ => return Err(Error::RandomnessNotReady),
+        };
+        let round_key = DataKey::HouseRound(session_id);
+        env.storage().temporary().set(&round_key, &(beacon, round));
+        env.storage()
+            .temporary()
+            .extend_ttl(&round_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        game.player2_tactic_hash = Some(BytesN::from_array(env, &[0; 32]));
+        game.advance_submission();
+        TacticSubmitted {
+            session_id,
+            player: game.player2.clone(),
+            phase: game.phase,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// In a house game, open the house's tactic once its beacon round is
+    /// revealed: `sha256(randomness || session_id)` is the salt, and its
+    /// last byte modulo 4 the tactic. Does nothing before then, or outside
+    /// house games
+    fn draw_house_tactic(env: &Env, session_id: u32, game: &mut Game) {
+        if game.player2 != env.current_contract_address() || game.player2_tactic.is_some() {
+            return;
+        }
+        let Some((beacon, round)) = env
+            .storage()
+            .temporary()
+            .get::<_, (Address, u64)>(&DataKey::HouseRound(session_id))
+        else {
+            return;
+        };
+        let Ok(Ok(randomness)) = BeaconClient::new(env, &beacon).try_get_randomness(&round) else {
+            return;
+        };
+
+        let mut seed_bytes: Bytes = randomness.into();
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        let salt: BytesN<32> = env.crypto().sha256(&seed_bytes).into();
+        let tactic = u32::from(salt.to_array()[31] % 4);
+        game.player2_tactic_hash = Some(tactic_hash(env, tactic, &salt, session_id));
+        game.player2_tactic = Some(tactic);
+    }
+
+    /// Reveal a submitted tactic.
+    ///
+    /// Only accepted once both players have submitted, so neither can see
+    /// the other's tactic before committing to their own.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `tactic` - The tactic proven at submission (0-3)
+    /// * `secret` - The player secret proven with it (32-byte big-endian
+    ///   field element), or the salt committed to in a `CommitReveal` game
+    ///
+    /// Signed by `player`, or by their session key (as for `submit_tactic`).
+    /// Fails with `InvalidReveal` unless `tactic_hash(tactic, secret,
+    /// session_id)` equals the hash the player's proof committed to.
+    pub fn reveal_tactic(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        tactic: u32,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_phase(GamePhase::BothSubmitted)?;
+        Self::check_reveal_window(&env, &game)?;
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Multi-round games open all rounds at once (`reveal_rounds`)
+        if Self::is_multi_round(&env, session_id) {
+            return Err(Error::WrongProofSystem);
+        }
+
+        let (Some(hash1), Some(hash2)) = (&game.player1_tactic_hash, &game.player2_tactic_hash)
+        else {
+            return Err(Error::InvalidPhase);
+        };
+        let (committed, revealed) = if is_player1 {
+            (hash1, game.player1_tactic)
+        } else {
+            (hash2, game.player2_tactic)
+        };
+
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if tactic > 3 {
+            return Err(Error::InvalidTactic);
+        }
+        if tactic_hash(&env, tactic, &secret, session_id) != *committed {
+            return Err(Error::InvalidReveal);
+        }
+
+        if is_player1 {
+            game.player1_tactic = Some(tactic);
+        } else {
+            game.player2_tactic = Some(tactic);
+        }
+
+        if game.tiebreak == TiebreakPolicy::Random {
+            Self::mix_tiebreak_entropy(&env, session_id, &secret);
+        }
+        Self::draw_house_tactic(&env, session_id, &mut game);
+
+        Self::save_game(&env, session_id, &mut game);
+        Self::auto_resolve(&env, session_id)
+    }
+
+    /// Fold a revealed secret into the game's tiebreak entropy. XOR keeps
+    /// the seed independent of who reveals first
+    fn mix_tiebreak_entropy(env: &Env, session_id: u32, secret: &BytesN<32>) {
+        let entropy_key = DataKey::TiebreakEntropy(session_id);
+        let mut entropy: [u8; 32] = env
+            .storage()
+            .temporary()
+            .get::<_, BytesN<32>>(&entropy_key)
+            .map_or([0; 32], |entropy| entropy.to_array());
+        for (byte, secret_byte) in entropy.iter_mut().zip(secret.to_array()) {
+            *byte ^= secret_byte;
+        }
+        env.storage()
+            .temporary()
+            .set(&entropy_key, &BytesN::from_array(env, &entropy));
+        env.storage()
+            .temporary()
+            .extend_ttl(&entropy_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Whether `session_id` was started with `start_multi_round_game`
+    fn is_multi_round(env: &Env, session_id: u32) -> bool {
+        env.storage()
+            .temporary()
+            .has(&DataKey::MultiRound(session_id))
+    }
+
+    /// Submit one recursive proof for every round of a multi-round match.
+    ///
+    /// The proof verifies one tactic proof per round (see `submit_tactic`)
+    /// and exposes their tactic hashes in round order; only the hashes are
+    /// stored, and the player opens them with `reveal_rounds` once both have
+    /// submitted. Public inputs are `RoundInputs::to_fields`, checked by the
+    /// verifier set with `set_rounds_verifier` (without one,
+    /// `VerifierNotConfigured`).
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `inputs` - The proof's public inputs; `session_id`,
+    ///   `player_binding` and `valid_until_ledger` are checked as for
+    ///   `submit_tactic`, and there must be one tactic hash per round
+    /// * `proof` - Recursive proof bytes
+    ///
+    /// Signed by `player`, or by their session key (as for `submit_tactic`).
+    pub fn submit_rounds(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        inputs: RoundInputs,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+        Self::require_unpaused(&env)?;
+        Self::check_proof_size(&env, &proof)?;
+        Self::check_proof_scope(
+            &env,
+            session_id,
+            &player,
+            inputs.session_id,
+            &inputs.player_binding,
+            inputs.valid_until_ledger,
+        )?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_submissions_open()?;
+        Self::check_submit_window(&env, &game)?;
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let rounds_key = DataKey::MultiRound(session_id);
+        let mut multi_round: MultiRound = env
+            .storage()
+            .temporary()
+            .get(&rounds_key)
+            .ok_or(Error::NotMultiRound)?;
+
+        // Committed strategies take no proof (see `reveal_round`), and team
+        // moves need every member's signature (see `submit_team_move`)
+        if multi_round.strategy_committed
+            || env.storage().temporary().has(&DataKey::Teams(session_id))
+        {
+            return Err(Error::WrongProofSystem);
+        }
+
+        let submitted = if is_player1 {
+            multi_round.player1_tactic_hashes.is_some()
+        } else {
+            multi_round.player2_tactic_hashes.is_some()
+        };
+        if submitted {
+            return Err(Error::AlreadySubmitted);
+        }
+        if inputs.tactic_hashes.len() != multi_round.rounds {
+            return Err(Error::InvalidRoundCount);
+        }
+        let proof_hash = Self::distinct_proof_hash(&env, &game, is_player1, &proof)?;
+
+        let nullifier_key = DataKey::Nullifier(inputs.nullifier.clone());
+        if env.storage().persistent().has(&nullifier_key) {
+            return Err(Error::NullifierUsed);
+        }
+
+        Self::verify_rounds_proof(&env, &proof, &inputs)?;
+
+        // The game itself records one hash per player, over all rounds
+        let mut hashes = Bytes::new(&env);
+        for hash in inputs.tactic_hashes.iter() {
+            hashes.append(&hash.into());
+        }
+        let digest: BytesN<32> = env.crypto().sha256(&hashes).into();
+
+        if is_player1 {
+            game.player1_commitment = Some(inputs.commitment.clone());
+            game.player1_proof_hash = Some(proof_hash);
+            game.player1_tactic_hash = Some(digest);
+            multi_round.player1_tactic_hashes = Some(inputs.tactic_hashes);
+        } else {
+            game.player2_commitment = Some(inputs.commitment.clone());
+            game.player2_proof_hash = Some(proof_hash);
+            game.player2_tactic_hash = Some(digest);
+            multi_round.player2_tactic_hashes = Some(inputs.tactic_hashes);
+        }
+        game.advance_submission();
+        TacticSubmitted {
+            session_id,
+            player,
+            phase: game.phase,
+        }
+        .publish(&env);
+
+        env.storage().persistent().set(&nullifier_key, &session_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&nullifier_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::save_game(&env, session_id, &mut game);
+        env.storage().temporary().set(&rounds_key, &multi_round);
+        Ok(())
+    }
+
+    /// Check a recursive rounds proof (see `submit_rounds`)
+    fn verify_rounds_proof(env: &Env, proof: &Bytes, inputs: &RoundInputs) -> Result<(), Error> {
+        if !groth16::is_field_element(&inputs.commitment) {
+            return Err(Error::InvalidCommitment);
+        }
+        let outputs_valid = groth16::is_field_element(&inputs.nullifier)
+            && inputs
+                .tactic_hashes
+                .iter()
+                .all(|hash| groth16::is_field_element(&hash));
+        if !outputs_valid {
+            return Err(Error::InvalidProof);
+        }
+        Self::check_proof_bytes(proof)?;
+
+        let verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::RoundsVerifier)
+            .ok_or(Error::VerifierNotConfigured)?;
+        let verifier = VerifierClient::new(env, &verifier_addr);
+        if !verifier.verify(proof, &inputs.to_fields(env)) {
+            return Err(Error::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Submit a team's move in a 2v2 match.
+    ///
+    /// Carries one tactic hash per member, in member order, and a single
+    /// BLS12-381 signature aggregating each member's signature of their
+    /// `team_move_payload`. The signature is its own authorization, so
+    /// anyone holding it may submit the move.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `captain` - Captain of the moving team
+    /// * `tactic_hashes` - `tactic_hash(tactic, secret, session_id)` of each
+    ///   member, for a random 32-byte secret the member keeps until the reveal
+    /// * `signature` - Aggregate of the members' signatures (G2, uncompressed)
+    ///
+    /// Fails with `InvalidTeamSignature` unless every member signed their
+    /// own hash, and `InvalidCommitment` for a hash that is not a field
+    /// element or copies one of the opposing team's.
+    pub fn submit_team_move(
+        env: Env,
+        session_id: u32,
+        captain: Address,
+        tactic_hashes: Vec<BytesN<32>>,
+        signature: BytesN<192>,
+    ) -> Result<(), Error> {
+        Self::require_unpaused(&env)?;
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_submissions_open()?;
+        Self::check_submit_window(&env, &game)?;
+
+        let is_player1 = if captain == game.player1 {
+            true
+        } else if captain == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let teams: Teams = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Teams(session_id))
+            .ok_or(Error::NotTeamGame)?;
+        let rounds_key = DataKey::MultiRound(session_id);
+        let mut multi_round: MultiRound = env
+            .storage()
+            .temporary()
+            .get(&rounds_key)
+            .ok_or(Error::NotMultiRound)?;
+
+        let (team, submitted, opponent_hashes) = if is_player1 {
+            (
+                teams.team1,
+                multi_round.player1_tactic_hashes.is_some(),
+                &multi_round.player2_tactic_hashes,
+            )
+        } else {
+            (
+                teams.team2,
+                multi_round.player2_tactic_hashes.is_some(),
+                &multi_round.player1_tactic_hashes,
+            )
+        };
+        if submitted {
+            return Err(Error::AlreadySubmitted);
+        }
+        if tactic_hashes.len() != TEAM_SIZE {
+            return Err(Error::InvalidRoundCount);
+        }
+
+        let mut keys = Vec::new(&env);
+        let mut messages = Vec::new(&env);
+        for (member, hash) in team.iter().zip(tactic_hashes.iter()) {
+            let copied = opponent_hashes
+                .as_ref()
+                .is_some_and(|hashes| hashes.contains(&hash));
+            if !groth16::is_field_element(&hash) || copied {
+                return Err(Error::InvalidCommitment);
+            }
+            messages.push_back(Self::team_move_payload(
+                env.clone(),
+                session_id,
+                member.player,
+                hash,
+            ));
+            keys.push_back(member.bls_key);
+        }
+        if !bls::verify_aggregate(&env, &keys, &messages, &signature) {
+            return Err(Error::InvalidTeamSignature);
+        }
+
+        // As for `submit_rounds`, the game records one hash over all rounds
+        let mut hashes = Bytes::new(&env);
+        for hash in tactic_hashes.iter() {
+            hashes.append(&hash.into());
+        }
+        let digest: BytesN<32> = env.crypto().sha256(&hashes).into();
+
+        if is_player1 {
+            game.player1_tactic_hash = Some(digest);
+            multi_round.player1_tactic_hashes = Some(tactic_hashes);
+        } else {
+            game.player2_tactic_hash = Some(digest);
+            multi_round.player2_tactic_hashes = Some(tactic_hashes);
+        }
+        game.advance_submission();
+        TacticSubmitted {
+            session_id,
+            player: captain,
+            phase: game.phase,
+        }
+        .publish(&env);
+
+        Self::save_game(&env, session_id, &mut game);
+        env.storage().temporary().set(&rounds_key, &multi_round);
+        Ok(())
+    }
+
+    /// Bytes a team member signs for `submit_team_move`
+    ///
+    /// Names the contract, the session and the member, so a signature cannot
+    /// be replayed elsewhere or credited to a teammate.
+    pub fn team_move_payload(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        tactic_hash: BytesN<32>,
+    ) -> Bytes {
+        TeamMove {
+            contract: env.current_contract_address(),
+            session_id,
+            player,
+            tactic_hash,
+        }
+        .to_xdr(&env)
+    }
+
+    /// Get both teams of a 2v2 match, or `None` for any other game
+    pub fn get_teams(env: Env, session_id: u32) -> Option<Teams> {
+        env.storage().temporary().get(&DataKey::Teams(session_id))
+    }
+
+    /// Reveal every round of a multi-round match.
+    ///
+    /// Only accepted once both players have submitted (`submit_rounds`).
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `tactics` - The tactic of each round, in order
+    /// * `secrets` - The player secret of each round's inner proof
+    ///
+    /// Signed by `player`, or by their session key (as for `submit_tactic`).
+    /// Fails with `InvalidReveal` unless `tactic_hash(tactics[i],
+    /// secrets[i], session_id)` equals the i-th submitted round hash for
+    /// every round.
+    pub fn reveal_rounds(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        tactics: Vec<u32>,
+        secrets: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_phase(GamePhase::BothSubmitted)?;
+        Self::check_reveal_window(&env, &game)?;
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let rounds_key = DataKey::MultiRound(session_id);
+        let mut multi_round: MultiRound = env
+            .storage()
+            .temporary()
+            .get(&rounds_key)
+            .ok_or(Error::NotMultiRound)?;
+
+        // Committed strategies are opened round by round (`reveal_round`)
+        if multi_round.strategy_committed {
+            return Err(Error::WrongProofSystem);
+        }
+
+        let (Some(hashes1), Some(hashes2)) = (
+            &multi_round.player1_tactic_hashes,
+            &multi_round.player2_tactic_hashes,
+        ) else {
+            return Err(Error::InvalidPhase);
+        };
+        let (committed, revealed) = if is_player1 {
+            (hashes1, &multi_round.player1_tactics)
+        } else {
+            (hashes2, &multi_round.player2_tactics)
+        };
+
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if tactics.len() != multi_round.rounds || secrets.len() != multi_round.rounds {
+            return Err(Error::InvalidRoundCount);
+        }
+        for ((tactic, secret), hash) in tactics.iter().zip(secrets.iter()).zip(committed.iter()) {
+            if tactic > 3 {
+                return Err(Error::InvalidTactic);
+            }
+            if tactic_hash(&env, tactic, &secret, session_id) != hash {
+                return Err(Error::InvalidReveal);
+            }
+        }
+
+        if game.tiebreak == TiebreakPolicy::Random {
+            for secret in secrets.iter() {
+                Self::mix_tiebreak_entropy(&env, session_id, &secret);
+            }
+        }
+
+        if is_player1 {
+            multi_round.player1_tactics = Some(tactics);
+        } else {
+            multi_round.player2_tactics = Some(tactics);
+        }
+        env.storage().temporary().set(&rounds_key, &multi_round);
+        Self::save_game(&env, session_id, &mut game);
+        Self::auto_resolve(&env, session_id)
+    }
+
+    /// Reveal one round of a committed strategy.
+    ///
+    /// Rounds are opened in order; the leaf `tactic_hash(tactic, salt,
+    /// session_id)` must sit at index `round` of the tree whose root the
+    /// player committed to (see `strategy_proof`). The inclusion proof is its
+    /// own authorization, so anyone holding it may submit the reveal.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player whose round is revealed
+    /// * `round` - Round index, equal to the number already revealed
+    /// * `tactic` - The tactic committed for the round
+    /// * `salt` - The salt of the round's leaf
+    /// * `proof` - Sibling hashes from the leaf up to the root
+    ///
+    /// Fails with `InvalidReveal` unless the proof opens the player's root.
+    pub fn reveal_round(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        round: u32,
+        tactic: u32,
+        salt: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_phase(GamePhase::BothSubmitted)?;
+        Self::check_reveal_window(&env, &game)?;
+
+        let (root, is_player1) = if player == game.player1 {
+            (&game.player1_tactic_hash, true)
+        } else if player == game.player2 {
+            (&game.player2_tactic_hash, false)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let rounds_key = DataKey::MultiRound(session_id);
+        let mut multi_round: MultiRound = env
+            .storage()
+            .temporary()
+            .get(&rounds_key)
+            .ok_or(Error::NotMultiRound)?;
+
+        if !multi_round.strategy_committed {
+            return Err(Error::WrongProofSystem);
+        }
+
+        let mut revealed = if is_player1 {
+            multi_round.player1_tactics.clone()
+        } else {
+            multi_round.player2_tactics.clone()
+        }
+        .unwrap_or_else(|| Vec::new(&env));
+        if round < revealed.len() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if round > revealed.len() || round >= multi_round.rounds {
+            return Err(Error::InvalidRoundCount);
+        }
+        if tactic > 3 {
+            return Err(Error::InvalidTactic);
+        }
+
+        let leaf = tactic_hash(&env, tactic, &salt, session_id);
+        let root = root.as_ref().ok_or(Error::InvalidPhase)?;
+        if !merkle::verify(&env, root, multi_round.rounds, round, &leaf, &proof) {
+            return Err(Error::InvalidReveal);
+        }
+
+        if game.tiebreak == TiebreakPolicy::Random {
+            Self::mix_tiebreak_entropy(&env, session_id, &salt);
+        }
+
+        revealed.push_back(tactic);
+        if is_player1 {
+            multi_round.player1_tactics = Some(revealed);
+        } else {
+            multi_round.player2_tactics = Some(revealed);
+        }
+        env.storage().temporary().set(&rounds_key, &multi_round);
+        Self::save_game(&env, session_id, &mut game);
+        Self::auto_resolve(&env, session_id)
+    }
+
+    /// Round hashes and revealed tactics of a multi-round game, if
+    /// `session_id` is one
+    pub fn get_multi_round(env: Env, session_id: u32) -> Option<MultiRound> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::MultiRound(session_id))
+    }
+
+    /// Reveal a hidden stake.
+    ///
+    /// Only accepted once both players have submitted their tactics, so a
+    /// stake cannot influence the opponent's choice. `resolve_match` fails
+    /// with `StakeNotRevealed` until both stakes are revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Player address
+    /// * `stake` - The stake committed to at `start_hidden_stake_game`
+    /// * `salt` - The salt it was hashed with
+    ///
+    /// Signed by `player`, or by their session key (as for `submit_tactic`).
+    /// Fails with `InvalidReveal` unless `stake_hash(stake, salt,
+    /// session_id)` equals the player's committed hash.
+    pub fn reveal_stake(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        stake: i128,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::require_submit_auth(&env, session_id, &player);
+
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        game.require_phase(GamePhase::BothSubmitted)?;
+        Self::check_reveal_window(&env, &game)?;
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let stakes_key = DataKey::HiddenStakes(session_id);
+        let mut stakes: HiddenStakes = env
+            .storage()
+            .temporary()
+            .get(&stakes_key)
+            .ok_or(Error::StakesNotHidden)?;
+
+        let (committed, revealed) = if is_player1 {
+            (&stakes.player1_stake_hash, stakes.player1_stake)
+        } else {
+            (&stakes.player2_stake_hash, stakes.player2_stake)
+        };
+
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if stake_hash(&env, stake, &salt, session_id) != *committed {
+            return Err(Error::InvalidReveal);
+        }
+        // Already proven by the range proof; checked again so a verifier
+        // that accepts too much cannot move stakes out of bounds
+        if stake < stakes.min_stake || stake > stakes.max_stake {
+            return Err(Error::InvalidStakeRange);
+        }
+
+        if is_player1 {
+            stakes.player1_stake = Some(stake);
+        } else {
+            stakes.player2_stake = Some(stake);
+        }
+
+        env.storage().temporary().set(&stakes_key, &stakes);
+        Self::save_game(&env, session_id, &mut game);
+        Self::auto_resolve(&env, session_id)
+    }
+
+    /// Bounds and committed stakes of a hidden-stake game, if `session_id`
+    /// is one
+    pub fn get_hidden_stakes(env: Env, session_id: u32) -> Option<HiddenStakes> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::HiddenStakes(session_id))
+    }
+
+    /// Odds of a `start_game_with_odds` match, if `session_id` is one
+    pub fn get_odds(env: Env, session_id: u32) -> Option<Odds> {
+        env.storage().temporary().get(&DataKey::Odds(session_id))
+    }
+
+    /// Rating range of a `start_bracket_game` match, if `session_id` is one
+    pub fn get_rating_bracket(env: Env, session_id: u32) -> Option<RatingBracket> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::RatingBracket(session_id))
+    }
+
+    // ========================================================================
+    // Session Keys
+    // ========================================================================
+
+    /// Authorize a session key to submit on the player's behalf.
+    ///
+    /// Lets smart-wallet (e.g. passkey) players approve once per match: the
+    /// wallet signs this grant, and the session key signs `submit_tactic`.
+    /// The grant only applies to `session_id`, only while the player's stake
+    /// is at most `max_stake`, and only until `expiration_ledger`.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session the key is scoped to
+    /// * `player` - Player granting the key
+    /// * `key` - Address that may sign submissions
+    /// * `max_stake` - Maximum player stake the key may act for
+    /// * `expiration_ledger` - Last ledger sequence the grant is valid for
+    pub fn authorize_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        key: Address,
+        max_stake: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let current = env.ledger().sequence();
+        if key == player || max_stake < 0 || expiration_ledger <= current {
+            return Err(Error::InvalidSessionKey);
+        }
+
+        let grant = SessionKeyGrant {
+            key,
+            max_stake,
+            expiration_ledger,
+        };
+        let grant_key = DataKey::SessionKey(session_id, player);
+        let ttl = (expiration_ledger - current).min(GAME_TTL_LEDGERS);
+        env.storage().temporary().set(&grant_key, &grant);
+        env.storage().temporary().extend_ttl(&grant_key, ttl, ttl);
+
+        Ok(())
+    }
+
+    /// Revoke the player's session key for a session
+    ///
+    /// # Arguments
+    /// * `session_id` - Session the key is scoped to
+    /// * `player` - Player that granted the key
+    pub fn revoke_session_key(env: Env, session_id: u32, player: Address) {
+        player.require_auth();
+        env.storage()
+            .temporary()
+            .remove(&DataKey::SessionKey(session_id, player));
+    }
+
+    /// Get the player's session key grant for a session, if any
+    ///
+    /// # Arguments
+    /// * `session_id` - Session the key is scoped to
+    /// * `player` - Player that granted the key
+    pub fn get_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Option<SessionKeyGrant> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::SessionKey(session_id, player))
+    }
+
+    /// Require auth for a submission: the session key if the player's grant
+    /// covers this session and stake, otherwise the player.
+    fn require_submit_auth(env: &Env, session_id: u32, player: &Address) {
+        let grant: Option<SessionKeyGrant> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::SessionKey(session_id, player.clone()));
+
+        if let Some(grant) = grant {
+            let game: Option<Game> = env.storage().temporary().get(&DataKey::Game(session_id));
+            let stake = game.and_then(|game| {
+                if *player == game.player1 {
+                    Some(game.player1_points)
+                } else if *player == game.player2 {
+                    Some(game.player2_points)
+                } else {
+                    None
+                }
+            });
+            // A hidden stake is only known to be at most the game's bound
+            let hidden: Option<HiddenStakes> = env
+                .storage()
+                .temporary()
+                .get(&DataKey::HiddenStakes(session_id));
+            let stake = stake.map(|stake| hidden.map_or(stake, |hidden| hidden.max_stake));
+
+            let in_scope = env.ledger().sequence() <= grant.expiration_ledger
+                && stake.is_some_and(|stake| stake <= grant.max_stake);
+            if in_scope {
+                grant.key.require_auth();
+                return;
+            }
+        }
+
+        player.require_auth();
+    }
+
+    /// Settle an auto-resolving game (see `set_auto_resolve`) if the reveal
+    /// just stored was the last one it was waiting for. Games scored by a
+    /// receipt are left to `resolve_with_receipt`
+    fn auto_resolve(env: &Env, session_id: u32) -> Result<(), Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if !game.auto_resolve || game.simulation_image_id.is_some() {
+            return Ok(());
+        }
+
+        let multi_round: Option<MultiRound> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::MultiRound(session_id));
+        let revealed = match multi_round {
+            Some(multi_round) => multi_round.rounds_won().is_some(),
+            None => game.player1_tactic.is_some() && game.player2_tactic.is_some(),
+        };
+        let hidden: Option<HiddenStakes> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::HiddenStakes(session_id));
+        let staked = hidden
+            .is_none_or(|hidden| hidden.player1_stake.is_some() && hidden.player2_stake.is_some());
+
+        if revealed && staked {
+            Self::resolve(env.clone(), session_id, None)?;
+        }
+        Ok(())
+    }
+
+    /// Resolve match after both players submit and reveal tactics.
+    /// Computes scores using strategic matrix and determines the outcome; a
+    /// draw stands or is decided per the game's `TiebreakPolicy`.
+    ///
+    /// Multi-round games are won by the player who wins more rounds (see
+    /// `start_multi_round_game`).
+    ///
+    /// Hidden-stake games also need both stakes revealed (`reveal_stake`);
+    /// they are recorded on the game and reported to the hub with the result.
+    ///
+    /// For batch-verified games both proofs are checked here first. A player
+    /// whose proof is invalid forfeits (the game is settled without scores),
+    /// even before they reveal; if both are invalid the call fails with
+    /// `InvalidProof`. Only proofs that verify have their nullifiers
+    /// consumed. A proof point off the curve makes the host trap instead (see
+    /// `groth16`).
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    ///
+    /// # Returns
+    /// * `Outcome` - Who won, or `Draw`
+    pub fn resolve_match(env: Env, session_id: u32) -> Result<Outcome, Error> {
+        Self::resolve(env, session_id, None)
+    }
+
+    /// Resolve a match scored by an off-chain simulation.
+    ///
+    /// For games started while a simulation image ID was set (see
+    /// `set_simulation_image_id`), which `resolve_match` refuses with
+    /// `ReceiptRequired`. The receipt must be for this session and the
+    /// revealed tactics, and its seal is checked against the game's image ID
+    /// by the verifier set with `set_receipt_verifier` (without one,
+    /// `VerifierNotConfigured`). Its scores then decide the outcome as usual.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `journal` - Journal committed by the simulation (see `zkvm.rs`)
+    /// * `seal` - The receipt's proof
+    ///
+    /// # Returns
+    /// * `Outcome` - Who won, or `Draw`
+    pub fn resolve_with_receipt(
+        env: Env,
+        session_id: u32,
+        journal: Bytes,
+        seal: Bytes,
+    ) -> Result<Outcome, Error> {
+        Self::resolve(env, session_id, Some((journal, seal)))
+    }
+
+    /// Resolve several finished matches in one transaction
+    ///
+    /// Each session is resolved as `resolve_match` would, and one that
+    /// fails (not yet revealed, not found, ...) is reported with its error
+    /// code rather than failing the rest. Games scored by a simulation
+    /// still need `resolve_with_receipt`.
+    ///
+    /// # Arguments
+    /// * `session_ids` - From 1 to `MAX_BATCH_SIZE` sessions (else
+    ///   `InvalidBatch`)
+    ///
+    /// # Returns
+    /// * A `BatchResult` per session, in order: `Resolved` or `Failed`
+    pub fn resolve_batch(env: Env, session_ids: Vec<u32>) -> Result<Vec<BatchResult>, Error> {
+        Self::run_batch(&env, &session_ids, |session_id| {
+            Self::resolve(env.clone(), session_id, None)
+                .map(|outcome| BatchResult::Resolved(session_id, outcome))
+        })
+    }
+
+    /// Pay out what several settled games' escrows still hold
+    ///
+    /// Each session is claimed as `claim_winnings` (for a won pot) or
+    /// `claim_refund` (otherwise) would, and one that fails is reported
+    /// with its error code rather than failing the rest. Anyone may call
+    /// it.
+    ///
+    /// # Arguments
+    /// * `session_ids` - From 1 to `MAX_BATCH_SIZE` sessions (else
+    ///   `InvalidBatch`)
+    ///
+    /// # Returns
+    /// * A `BatchResult` per session, in order: `Claimed` or `Failed`
+    pub fn claim_batch(env: Env, session_ids: Vec<u32>) -> Result<Vec<BatchResult>, Error> {
+        Self::run_batch(&env, &session_ids, |session_id| {
+            let won = escrow::get(&env, session_id)
+                .is_some_and(|escrow| escrow.settlement == Settlement::Winnings);
+            let paid = if won {
+                Self::claim_winnings(env.clone(), session_id)
+            } else {
+                Self::claim_refund(env.clone(), session_id)
+            };
+            paid.map(|amount| BatchResult::Claimed(session_id, amount))
+        })
+    }
+
+    /// Run `item` for each of a batch's sessions, collecting its result or
+    /// error. Every path `item` takes checks before it writes, so a failed
+    /// session leaves no partial state behind
+    fn run_batch(
+        env: &Env,
+        session_ids: &Vec<u32>,
+        mut item: impl FnMut(u32) -> Result<BatchResult, Error>,
+    ) -> Result<Vec<BatchResult>, Error> {
+        if session_ids.is_empty() || session_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatch);
+        }
+        let mut results = Vec::new(env);
+        for session_id in session_ids.iter() {
+            results.push_back(
+                item(session_id).unwrap_or_else(|err| BatchResult::Failed(session_id, err as u32)),
+            );
+        }
+        Ok(results)
+    }
+
+    /// Scores of a simulated match from its zkVM receipt
+    fn receipt_scores(
+        env: &Env,
+        session_id: u32,
+        image_id: &BytesN<32>,
+        tactics: (u32, u32),
+        journal: &Bytes,
+        seal: &Bytes,
+    ) -> Result<(u32, u32), Error> {
+        let decoded = zkvm::Journal::decode(journal).ok_or(Error::InvalidReceipt)?;
+        if decoded.session_id != session_id || (decoded.tactic1, decoded.tactic2) != tactics {
+            return Err(Error::InvalidReceipt);
+        }
+
+        let verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReceiptVerifier)
+            .ok_or(Error::VerifierNotConfigured)?;
+        if !zkvm::verify(env, &verifier_addr, seal, image_id, journal) {
+            return Err(Error::InvalidReceipt);
+        }
+        Ok((decoded.score1, decoded.score2))
+    }
+
+    /// Outcome of a drawn match under the game's `TiebreakPolicy`
+    fn break_tie(env: &Env, session_id: u32, game: &Game) -> Result<Outcome, Error> {
+        // The house's edge: it wins every tie
+        if game.player2 == env.current_contract_address() {
+            return Ok(Outcome::Player2Win);
+        }
+        match game.tiebreak {
+            TiebreakPolicy::Draw => return Ok(Outcome::Draw),
+            TiebreakPolicy::Player1 => return Ok(Outcome::Player1Win),
+            TiebreakPolicy::Random => {}
+        }
+
+        // Mixed in by the reveals, so only missing if nothing was revealed
+        let entropy: BytesN<32> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::TiebreakEntropy(session_id))
+            .ok_or(Error::TacticNotRevealed)?;
+        let hash = |hash: &Option<BytesN<32>>| -> Result<Bytes, Error> {
+            Ok(hash.clone().ok_or(Error::BothPlayersNotSubmitted)?.into())
+        };
+
+        let mut seed_bytes = hash(&game.player1_tactic_hash)?;
+        seed_bytes.append(&hash(&game.player2_tactic_hash)?);
+        seed_bytes.append(&entropy.into());
+
+        let seed = env.crypto().sha256(&seed_bytes);
+        env.prng().seed(seed.into());
+        if env.prng().gen_range::<u64>(0..=1) == 0 {
+            Ok(Outcome::Player1Win)
+        } else {
+            Ok(Outcome::Player2Win)
+        }
+    }
+
+    /// Shared body of `resolve_match` and `resolve_with_receipt`
+    fn resolve(
+        env: Env,
+        session_id: u32,
+        receipt: Option<(Bytes, Bytes)>,
+    ) -> Result<Outcome, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Already settled: never report the result to the hub twice
+        if let Some(outcome) = game.outcome() {
+            return Ok(outcome);
+        }
+        game.require_phase(GamePhase::BothSubmitted)?;
+
+        // Deferred proofs are checked before anything else, so a player whose
+        // opponent's proof fails wins without waiting for the opponent's
+        // reveal
+        let (valid1, valid2) = if game.batch_verification {
+            Self::verify_pending(&env, session_id, &game)?
+        } else {
+            (true, true)
+        };
+        if !valid1 && !valid2 {
+            return Err(Error::InvalidProof);
+        }
+        let forfeit = valid1 != valid2;
+        Self::draw_house_tactic(&env, session_id, &mut game);
+
+        // Multi-round games keep their tactics in `MultiRound` instead
+        let multi_round: Option<MultiRound> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::MultiRound(session_id));
+        let tactics = match &multi_round {
+            _ if forfeit => None,
+            Some(multi_round) if multi_round.rounds_won().is_none() => {
+                return Err(Error::TacticNotRevealed)
+            }
+            Some(_) => None,
+            None => Some((
+                game.player1_tactic.ok_or(Error::TacticNotRevealed)?,
+                game.player2_tactic.ok_or(Error::TacticNotRevealed)?,
+            )),
+        };
+
+        // A simulated game can only be scored by its simulation, and only a
+        // simulated game has one
+        match (&game.simulation_image_id, &receipt) {
+            (Some(_), None) if !forfeit => return Err(Error::ReceiptRequired),
+            (None, Some(_)) => return Err(Error::InvalidReceipt),
+            _ => {}
+        }
+
+        let hidden: Option<HiddenStakes> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::HiddenStakes(session_id));
+        if let Some(hidden) = &hidden {
+            game.player1_points = hidden.player1_stake.ok_or(Error::StakeNotRevealed)?;
+            game.player2_points = hidden.player2_stake.ok_or(Error::StakeNotRevealed)?;
+        }
+
+        let outcome = match (valid1, valid2) {
+            (true, true) => {
+                // Compute scores using strategic matrix, or the simulation;
+                // multi-round games count rounds won
+                let (score1, score2) = match (tactics, &game.simulation_image_id, &receipt) {
+                    (Some(tactics), Some(image_id), Some((journal, seal))) => {
+                        Self::receipt_scores(&env, session_id, image_id, tactics, journal, seal)?
+                    }
+                    (Some((tactic1, tactic2)), _, _) => get_score(tactic1, tactic2),
+                    (None, _, _) => multi_round
+                        .as_ref()
+                        .and_then(MultiRound::rounds_won)
+                        .unwrap_or_default(),
+                };
+                game.player1_score = Some(score1);
+                game.player2_score = Some(score2);
+
+                // Determine outcome (higher score wins, ties per the policy)
+                if score1 > score2 {
+                    Outcome::Player1Win
+                } else if score1 < score2 {
+                    Outcome::Player2Win
+                } else {
+                    Self::break_tie(&env, session_id, &game)?
+                }
+            }
+            // Forfeit: the unproven tactic is discarded
+            (true, false) => {
+                game.player2_tactic = None;
+                Outcome::Player1Win
+            }
+            (false, true) => {
+                game.player1_tactic = None;
+                Outcome::Player2Win
+            }
+            (false, false) => return Err(Error::InvalidProof),
+        };
+
+        if game.batch_verification {
+            Self::settle_pending_proof(&env, session_id, &game.player1, valid1);
+            Self::settle_pending_proof(&env, session_id, &game.player2, valid2);
+        }
+
+        // Finalize the result before calling out to the hub, so a hostile or
+        // re-entering hub can never observe (or settle) an unresolved game
+        game.settle(outcome, GamePhase::Resolved);
+        Self::save_game(&env, session_id, &mut game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::archive(&env, session_id, &game, outcome);
+        Self::untrack_open_game(&env, session_id, [&game.player1, &game.player2]);
+        Self::settle_escrow(&env, session_id, Some(&game));
+        Self::settle_side_bets(&env, session_id, Some(&game));
+        MatchResolved {
+            session_id,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            outcome,
+            player1_score: game.player1_score,
+            player2_score: game.player2_score,
+        }
+        .publish(&env);
+        Self::notify_hub(&env, session_id, &mut game)?;
+
+        Ok(outcome)
+    }
+
+    /// Settle a game an opponent abandoned.
+    ///
+    /// Once the game's `submit_deadline_ledger` has passed, the player who
+    /// submitted wins by forfeit if the opponent never did, and the result is
+    /// reported to the Game Hub as for `resolve_match`. Likewise, once the
+    /// `resolve_deadline_ledger` has passed, a player who revealed all their
+    /// game needs (every round, and any hidden stake) wins if the opponent
+    /// did not. Other games return `NoForfeit`; they are resolved as usual.
+    /// In batch-verified games the winner's deferred proof is checked first,
+    /// and an invalid one returns `InvalidProof`. Anyone may call it.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    ///
+    /// # Returns
+    /// * `Address` - Winner address
+    pub fn resolve_timeout(env: Env, session_id: u32) -> Result<Address, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.is_over() {
+            return Err(Error::InvalidPhase);
+        }
+        let now = env.ledger().sequence();
+        if now <= game.submit_deadline_ledger {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let (winner, outcome) = match game.phase {
+            GamePhase::AwaitingOpponent if game.player1_tactic_hash.is_some() => {
+                (game.player1.clone(), Outcome::Player1Win)
+            }
+            GamePhase::AwaitingOpponent => (game.player2.clone(), Outcome::Player2Win),
+            GamePhase::Created => return Err(Error::NoForfeit),
+            // Both submitted: only a missed reveal forfeits
+            GamePhase::BothSubmitted => {
+                if now <= game.resolve_deadline_ledger {
+                    return Err(Error::DeadlineNotReached);
+                }
+                Self::draw_house_tactic(&env, session_id, &mut game);
+                match Self::reveals_complete(&env, session_id, &game) {
+                    (true, false) => (game.player1.clone(), Outcome::Player1Win),
+                    (false, true) => (game.player2.clone(), Outcome::Player2Win),
+                    // A player who never revealed loses to the house even if
+                    // its beacon round was never revealed either
+                    (false, false) if game.player2 == env.current_contract_address() => {
+                        (game.player2.clone(), Outcome::Player2Win)
+                    }
+                    _ => return Err(Error::NoForfeit),
+                }
+            }
+            _ => return Err(Error::InvalidPhase),
+        };
+
+        if game.batch_verification {
+            let pending_key = DataKey::PendingProof(session_id, winner.clone());
+            let pending: PendingProof = env
+                .storage()
+                .temporary()
+                .get(&pending_key)
+                .ok_or(Error::BothPlayersNotSubmitted)?;
+            match Self::verify_zk_proof(&env, ProofSystem::Groth16, &pending.proof, &pending.inputs)
+            {
+                Ok(()) => {}
+                Err(Error::VerifierNotConfigured) => return Err(Error::VerifierNotConfigured),
+                Err(_) => return Err(Error::InvalidProof),
+            }
+            Self::settle_pending_proof(&env, session_id, &game.player1, winner == game.player1);
+            Self::settle_pending_proof(&env, session_id, &game.player2, winner == game.player2);
+        }
+
+        // Finalize before calling out to the hub, as in `resolve`
+        game.settle(outcome, GamePhase::TimedOut);
+        Self::save_game(&env, session_id, &mut game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::archive(&env, session_id, &game, outcome);
+        Self::untrack_open_game(&env, session_id, [&game.player1, &game.player2]);
+        Self::settle_escrow(&env, session_id, Some(&game));
+        Self::settle_side_bets(&env, session_id, Some(&game));
+        TimedOut {
+            session_id,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            winner: winner.clone(),
+            outcome,
+        }
+        .publish(&env);
+        Self::notify_hub(&env, session_id, &mut game)?;
+
+        Ok(winner)
+    }
+
+    /// Call off a game before either player has submitted.
+    ///
+    /// Signed by both players (just the player, against the house). The
+    /// game ends `Cancelled` and the hub is
+    /// told it ended drawn, which hands both players their points back; a
+    /// `GameCancelled` event is published. Nothing is added to match
+    /// history. Returns `InvalidPhase` once anyone has submitted.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    pub fn cancel_game(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        game.player1.require_auth();
+        if game.player2 != env.current_contract_address() {
+            game.player2.require_auth();
+        }
+        game.require_phase(GamePhase::Created)?;
+
+        // Finalize before calling out to the hub, as in `resolve`
+        game.settle(Outcome::Draw, GamePhase::Cancelled);
+        Self::save_game(&env, session_id, &mut game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::untrack_open_game(&env, session_id, [&game.player1, &game.player2]);
+        Self::update_global_stats(&env, |stats| stats.games_cancelled += 1);
+        Self::settle_escrow(&env, session_id, Some(&game));
+        Self::settle_side_bets(&env, session_id, Some(&game));
+        GameCancelled {
+            session_id,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+        }
+        .publish(&env);
+        Self::notify_hub(&env, session_id, &mut game)
+    }
+
+    /// Whether each player has revealed everything `resolve` needs from
+    /// them: every round of a multi-round, committed-strategy or team game,
+    /// otherwise their tactic, and in hidden-stake games their stake too
+    fn reveals_complete(env: &Env, session_id: u32, game: &Game) -> (bool, bool) {
+        let multi_round: Option<MultiRound> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::MultiRound(session_id));
+        let (mut done1, mut done2) = match multi_round {
+            Some(multi_round) => {
+                let complete = |tactics: &Option<Vec<u32>>| {
+                    tactics
+                        .as_ref()
+                        .is_some_and(|tactics| tactics.len() == multi_round.rounds)
+                };
+                (
+                    complete(&multi_round.player1_tactics),
+                    complete(&multi_round.player2_tactics),
+                )
+            }
+            None => (game.player1_tactic.is_some(), game.player2_tactic.is_some()),
+        };
+
+        let hidden: Option<HiddenStakes> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::HiddenStakes(session_id));
+        if let Some(hidden) = hidden {
+            done1 &= hidden.player1_stake.is_some();
+            done2 &= hidden.player2_stake.is_some();
+        }
+        (done1, done2)
+    }
+
+    /// Report a settled game's result to a hub that missed it.
+    ///
+    /// Settlement does not wait on the hub: if the hub trapped when
+    /// `resolve_match`, `resolve_with_receipt` or `resolve_timeout` called
+    /// it, the result is kept here with `hub_notified` false. This reports
+    /// it again, say once the hub is back or `set_hub` has pointed the
+    /// contract at a working one. Returns `HubNotNotified` if the hub still
+    /// rejects it, `GameNotSettled` for a game still in play, and nothing
+    /// for a game the hub already has. Anyone may call it.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    pub fn retry_hub_notify(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.outcome().is_none() {
+            return Err(Error::GameNotSettled);
+        }
+        if game.hub_notified {
+            return Ok(());
+        }
+
+        Self::notify_hub(&env, session_id, &mut game)?;
+        if !game.hub_notified {
+            return Err(Error::HubNotNotified);
+        }
+        Ok(())
+    }
+
+    /// Close a session whose game entry expired before it was resolved.
+    ///
+    /// The hub was told the session started but will never hear how it
+    /// ended; this drops the contract's open record of it and publishes a
+    /// `SessionAbandoned` event with the players and points the hub holds,
+    /// so they can be returned. Returns `SessionNotExpired` while the game
+    /// still exists (resolve it, or use `resolve_timeout`), and
+    /// `GameNotFound` for sessions with no open record. Anyone may call it.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    ///
+    /// # Returns
+    /// * `OpenSession` - The record that was closed
+    pub fn abandon_expired(env: Env, session_id: u32) -> Result<OpenSession, Error> {
+        let key = DataKey::OpenSession(session_id);
+        let record: OpenSession = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionNotExpired);
+        }
+
+        env.storage().persistent().remove(&key);
+        Self::untrack_open_game(&env, session_id, [&record.player1, &record.player2]);
+        Self::update_global_stats(&env, |stats| stats.games_abandoned += 1);
+        Self::settle_escrow(&env, session_id, None);
+        Self::settle_side_bets(&env, session_id, None);
+        SessionAbandoned {
+            session_id,
+            player1: record.player1.clone(),
+            player2: record.player2.clone(),
+            player1_points: record.player1_points,
+            player2_points: record.player2_points,
+        }
+        .publish(&env);
+        Ok(record)
+    }
+
+    /// Delete a settled game's storage once it has been archived.
+    ///
+    /// Settlement copies the result into both players' match history (see
+    /// `get_match_record`); after that the game entry and its per-session
+    /// entries (rounds, hidden stakes, teams, rating bracket, tiebreak
+    /// entropy, deferred proofs and session key grants) only serve
+    /// `get_game`, `get_attestation` and repeated `resolve_match` calls.
+    /// Deleting them stops their rent now instead of at expiry. Consumed
+    /// nullifiers are kept: they are what stops the game's proofs being
+    /// replayed into a later game under the same session ID, and they
+    /// expire on their own.
+    ///
+    /// Signed by `player`. Returns `GameNotSettled` for a game still in
+    /// play, and `HubNotNotified` while the hub is missing its result (see
+    /// `retry_hub_notify`).
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `player` - Either player of the game
+    pub fn purge_game(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if !game.is_over() {
+            return Err(Error::GameNotSettled);
+        }
+        if game.outcome().is_some() && !game.hub_notified {
+            return Err(Error::HubNotNotified);
+        }
+
+        let storage = env.storage().temporary();
+        for key in Self::game_entries(session_id, &game) {
+            storage.remove(&key);
+        }
+        for player in [game.player1, game.player2] {
+            storage.remove(&DataKey::SessionKey(session_id, player));
+        }
+        Ok(())
+    }
+
+    /// Keep a game's storage alive for at least `ledgers` more ledgers.
+    ///
+    /// Game entries live `GAME_TTL_LEDGERS` from their last write, which a
+    /// slow-moving game can outlast. Anyone (say, a frontend keeper) may
+    /// bump the game entry and its per-session entries; the caller pays the
+    /// rent and no signature is needed. The hub's open session record is
+    /// bumped to stay `GAME_TTL_LEDGERS` ahead, so `abandon_expired` can
+    /// still find it. Session key grants keep the expiry they were granted
+    /// with, and deadlines are unchanged.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    /// * `ledgers` - Ledgers to keep the game for, up to the network's
+    ///   maximum TTL (else `InvalidTtl`)
+    pub fn extend_game_ttl(env: Env, session_id: u32, ledgers: u32) -> Result<(), Error> {
+        let max_ttl = env.storage().max_ttl();
+        if ledgers == 0 || ledgers > max_ttl {
+            return Err(Error::InvalidTtl);
+        }
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        let storage = env.storage().temporary();
+        for key in Self::game_entries(session_id, &game) {
+            if storage.has(&key) {
+                storage.extend_ttl(&key, ledgers, ledgers);
+            }
+        }
+
+        let session_key = DataKey::OpenSession(session_id);
+        if env.storage().persistent().has(&session_key) {
+            let ttl = ledgers.saturating_add(GAME_TTL_LEDGERS).min(max_ttl);
+            env.storage().persistent().extend_ttl(&session_key, ttl, ttl);
+        }
+        Ok(())
+    }
+
+    /// Get the contract-wide game totals, e.g. for a health dashboard
+    pub fn get_global_stats(env: Env) -> GlobalStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GlobalStats)
+            .unwrap_or_default()
+    }
+
+    /// Get `player`'s lifetime record, all zero before their first settled
+    /// game
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerStats(player))
+            .unwrap_or_else(|| PlayerStats::new(&env))
+    }
+
+    /// Get how many settled games `player` has in their match history
+    pub fn get_match_count(env: Env, player: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MatchCount(player))
+            .unwrap_or(0)
+    }
+
+    /// Get a game from `player`'s match history.
+    ///
+    /// Results are archived in persistent storage when a game is settled
+    /// (`resolve_match`, `resolve_with_receipt` or `resolve_timeout`), so
+    /// they outlive the game entry `get_game` reads.
+    ///
+    /// # Arguments
+    /// * `player` - Either player of the game
+    /// * `index` - 0 for their first settled game, up to `get_match_count`
+    pub fn get_match_record(env: Env, player: Address, index: u32) -> Option<MatchRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MatchRecord(player, index))
+    }
+
+    /// Page through `player`'s match history, newest first, without an
+    /// external indexer
+    ///
+    /// Games whose record has been archived by the network are skipped
+    /// until restored.
+    ///
+    /// # Arguments
+    /// * `player` - Whose history to list
+    /// * `cursor` - `None` for the newest games, else the `index` of the
+    ///   last game of the previous page
+    /// * `limit` - Games per page, capped at `MAX_HISTORY_PAGE`
+    pub fn get_history(
+        env: Env,
+        player: Address,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Vec<GameSummary> {
+        let end = cursor.unwrap_or_else(|| Self::get_match_count(env.clone(), player.clone()));
+        let start = end.saturating_sub(limit.min(MAX_HISTORY_PAGE));
+        let mut page = vec![&env];
+        for index in (start..end).rev() {
+            let record: Option<MatchRecord> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::MatchRecord(player.clone(), index));
+            if let Some(record) = record {
+                page.push_back(record.summary(index, &player));
+            }
+        }
+        page
+    }
+
+    /// Get a game's submit and reveal deadlines, e.g. to show countdowns.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    pub fn get_deadlines(env: Env, session_id: u32) -> Result<Deadlines, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        Ok(Deadlines {
+            submit_deadline_ledger: game.submit_deadline_ledger,
+            resolve_deadline_ledger: game.resolve_deadline_ledger,
+        })
+    }
+
+    /// Get the ledger a game last changed in: started, a move submitted or
+    /// revealed, settled, or its result reported to the hub. Keepers can
+    /// compare it against `get_deadlines` to find games to `resolve_timeout`
+    /// from contract state alone. Games migrated from schema 1 read 0 until
+    /// their next change.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    pub fn get_last_activity(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        Ok(game.last_activity_ledger)
+    }
+
+    /// Session IDs of `player`'s games still in play (unsettled and not
+    /// expired), oldest first, so a wallet can list its active matches
+    /// without an indexer. Games that expire stay indexed until
+    /// `abandon_expired` closes them, but are not listed
+    pub fn get_open_games(env: Env, player: Address) -> Vec<u32> {
+        let sessions: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PlayerGames(player.clone()))
+            .unwrap_or(vec![&env]);
+        let mut open = vec![&env];
+        for session_id in sessions.iter() {
+            let game: Option<Game> = env.storage().temporary().get(&DataKey::Game(session_id));
+            if game.is_some_and(|game| {
+                !game.is_over() && (game.player1 == player || game.player2 == player)
+            }) {
+                open.push_back(session_id);
+            }
+        }
+        open
+    }
+
+    /// The open record of a session the hub has not yet heard the end of
+    pub fn get_open_session(env: Env, session_id: u32) -> Option<OpenSession> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenSession(session_id))
+    }
+
+    /// Score `tactic1` against `tactic2` with the matrix games are settled
+    /// by, so clients need not keep their own copy. Returns `InvalidTactic`
+    /// for anything but 0-3
+    pub fn simulate_score(_env: Env, tactic1: u32, tactic2: u32) -> Result<(u32, u32), Error> {
+        if tactic1 > 3 || tactic2 > 3 {
+            return Err(Error::InvalidTactic);
+        }
+        Ok(get_score(tactic1, tactic2))
+    }
+
+    /// Get the whole score matrix, ordered by `tactic1` then `tactic2`
+    pub fn get_score_matrix(env: Env) -> Vec<ScoreEntry> {
+        let mut matrix = vec![&env];
+        for tactic1 in 0..=3 {
+            for tactic2 in 0..=3 {
+                let (score1, score2) = get_score(tactic1, tactic2);
+                matrix.push_back(ScoreEntry {
+                    tactic1,
+                    tactic2,
+                    score1,
+                    score2,
+                });
+            }
+        }
+        matrix
+    }
+
+    /// Get a settled game's full state, including scores and tactics.
+    ///
+    /// Returns `GameNotSettled` while the game is in play; use
+    /// `get_game_public` to follow it until then.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    ///
+    /// # Returns
+    /// * `Game` - Complete game state
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.outcome().is_none() {
+            return Err(Error::GameNotSettled);
+        }
+        Ok(game)
+    }
+
+    /// Get a game's state with revealed tactics and proof hashes withheld
+    /// until it is settled, so clients polling an in-progress match do not
+    /// show either player the other's move.
+    ///
+    /// This only shapes what the view returns: contract storage itself is
+    /// public on the ledger.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    pub fn get_game_public(env: Env, session_id: u32) -> Result<PublicGame, Error> {
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        let (player1_revealed, player2_revealed) = Self::reveals_complete(&env, session_id, &game);
+        if game.outcome().is_none() {
+            game.player1_tactic = None;
+            game.player2_tactic = None;
+            game.player1_proof_hash = None;
+            game.player2_proof_hash = None;
+        }
+        Ok(PublicGame {
+            game,
+            player1_revealed,
+            player2_revealed,
+        })
+    }
+
+    /// Canonical, Merkle-committed attestation of a settled session.
+    ///
+    /// Bridges and partner games on other chains verify `root` (or a single
+    /// leaf against it) instead of trusting a relayer's summary. See
+    /// `attestation.rs` for the byte layout.
+    ///
+    /// # Arguments
+    /// * `session_id` - Game session ID
+    ///
+    /// # Returns
+    /// * `Attestation` - Leaves and keccak256 Merkle root
+    pub fn get_attestation(env: Env, session_id: u32) -> Result<Attestation, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        attestation::attest(&env, session_id, &game)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Get the address proposed as the next admin, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Propose handing the admin role to `new_admin`
+    ///
+    /// Nothing changes until `new_admin` signs `accept_admin`, so a mistyped
+    /// address cannot take the admin role (and with it, upgrades) out of
+    /// reach. Proposing again replaces the pending proposal. Under an
+    /// admin council, both proposing and accepting take its approval.
+    ///
+    /// # Arguments
+    /// * `new_admin` - The proposed admin address
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::ProposeAdmin(new_admin.clone()))?;
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        AdminProposed { admin, new_admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Take over as admin after being proposed with `propose_admin`
+    ///
+    /// Signed by the pending admin, and approved by the admin council if
+    /// one is set. Returns `NoPendingAdmin` if nobody has been proposed.
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        new_admin.require_auth();
+        if Self::get_admin_council(env.clone()).is_some() {
+            Self::authorize(&env, CouncilAction::AcceptAdmin(new_admin.clone()))?;
+        }
+        let previous_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        AdminTransferred {
+            previous_admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Whether `account` holds `role`, directly or through the `Admin` role.
+    /// The admin address itself is not listed but holds every role.
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        let storage = env.storage().instance();
+        storage.has(&DataKey::Role(account.clone(), role))
+            || storage.has(&DataKey::Role(account, Role::Admin))
+    }
+
+    /// Grant `role` to `account`, e.g. `Pauser` to an ops bot
+    ///
+    /// # Arguments
+    /// * `account` - The account to delegate to
+    /// * `role` - The role to grant
+    pub fn grant_role(env: Env, account: Address, role: Role) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::GrantRole(account.clone(), role))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Role(account, role), &true);
+        Ok(())
+    }
+
+    /// Take `role` back from `account`. Roles held through `Admin` stay
+    /// until `Admin` itself is revoked.
+    ///
+    /// # Arguments
+    /// * `account` - The account to revoke from
+    /// * `role` - The role to revoke
+    pub fn revoke_role(env: Env, account: Address, role: Role) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::RevokeRole(account.clone(), role))?;
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::Role(account, role));
+        Ok(())
+    }
+
+    /// Get the admin council, if one is set
+    pub fn get_admin_council(env: Env) -> Option<AdminCouncil> {
+        env.storage().instance().get(&DataKey::AdminCouncil)
+    }
+
+    /// Put the calls a leaked admin key could do most harm with under an
+    /// M-of-N admin council: the hub, pausing, upgrades, the admin
+    /// transfer, verifiers, roles, disputes, fees and the oracle
+    ///
+    /// Once a council is set, each `CouncilAction` call goes through only
+    /// after `threshold` members have approved that exact call with
+    /// `approve_action`, and the admin's signature no longer does. Holders
+    /// of the role a setter takes can still call it. Other admin setters
+    /// keep needing the admin. Setting the first council takes the admin;
+    /// changing or removing it (`None`) takes the current council's
+    /// approval.
+    ///
+    /// # Arguments
+    /// * `council` - Up to `MAX_COUNCIL_SIZE` distinct members and a
+    ///   threshold from 1 to their number (else `InvalidCouncil`), or
+    ///   `None` to go back to the admin alone
+    pub fn set_admin_council(env: Env, council: Option<AdminCouncil>) -> Result<(), Error> {
+        let action = match &council {
+            Some(council) => CouncilAction::SetCouncil(council.clone()),
+            None => CouncilAction::RemoveCouncil,
+        };
+        Self::authorize(&env, action)?;
+
+        match council {
+            Some(council) => {
+                let size = council.members.len();
+                if size > MAX_COUNCIL_SIZE
+                    || council.threshold == 0
+                    || council.threshold > size
+                {
+                    return Err(Error::InvalidCouncil);
+                }
+                for (i, member) in council.members.iter().enumerate() {
+                    if council.members.first_index_of(&member) != Some(i as u32) {
+                        return Err(Error::InvalidCouncil);
+                    }
+                }
+                env.storage()
+                    .instance()
+                    .set(&DataKey::AdminCouncil, &council);
+            }
+            None => env.storage().instance().remove(&DataKey::AdminCouncil),
+        }
+        Ok(())
+    }
+
+    /// Members of the admin council who have approved `action` and not yet
+    /// seen it carried out
+    pub fn get_approvals(env: Env, action: CouncilAction) -> Vec<Address> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Approvals(action))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Approve `action` as a member of the admin council
+    ///
+    /// Signed by `member`. The action can be carried out once `threshold`
+    /// members have approved it, which uses the approvals up. Approvals
+    /// lapse if the action is not carried out within `GAME_TTL_LEDGERS`.
+    ///
+    /// # Arguments
+    /// * `member` - Approving council member (else `NotCouncilMember`)
+    /// * `action` - The exact call being approved, arguments included
+    pub fn approve_action(env: Env, member: Address, action: CouncilAction) -> Result<(), Error> {
+        member.require_auth();
+        let council = Self::get_admin_council(env.clone()).ok_or(Error::NotCouncilMember)?;
+        if !council.members.contains(&member) {
+            return Err(Error::NotCouncilMember);
+        }
+
+        let mut approvals = Self::get_approvals(env.clone(), action.clone());
+        if !approvals.contains(&member) {
+            approvals.push_back(member);
+        }
+        let key = DataKey::Approvals(action);
+        env.storage().temporary().set(&key, &approvals);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::HubNotSet)
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// The new hub must answer `version()` with at least
+    /// `MIN_HUB_VERSION`, else `InvalidHub`, so a mistyped address is
+    /// caught here rather than by every later `start_game`. Needs the admin
+    /// council's approval once one is set (see `set_admin_council`).
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetHub(new_hub.clone()))?;
+        Self::check_hub(&env, &new_hub)?;
+
+        let previous_hub = env.storage().instance().get(&DataKey::GameHubAddress);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+        HubChanged {
+            previous_hub,
+            new_hub,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the external ZK verifier contract address, if configured
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The verifier contract address
+    pub fn get_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Verifier)
+    }
+
+    /// Delegate proof verification to an external verifier contract
+    ///
+    /// # Arguments
+    /// * `verifier` - Contract implementing `verify(proof, public_inputs) -> bool`
+    pub fn set_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetVerifier(verifier.clone()))?;
+
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+        Ok(())
+    }
+
+    /// Get the Groth16 verification key, if one is set
+    pub fn get_verification_key(env: Env) -> Option<Bytes> {
+        env.storage().instance().get(&DataKey::VerificationKey)
+    }
+
+    /// Set the Groth16 verification key used by `submit_tactic`
+    ///
+    /// Lets the circuit be upgraded after a new trusted setup without
+    /// redeploying. Only the length is checked here (six public inputs).
+    /// A key source set with `set_vk_source` takes precedence. An admin
+    /// council approves the key by its sha256.
+    ///
+    /// # Arguments
+    /// * `vk` - Serialized key, see `src/groth16.rs` for the layout
+    pub fn set_verification_key(env: Env, vk: Bytes) -> Result<(), Error> {
+        let vk_hash = env.crypto().sha256(&vk).into();
+        Self::authorize(&env, CouncilAction::SetVerificationKey(vk_hash))?;
+
+        if vk.len() != groth16::vk_len(6) {
+            return Err(Error::InvalidVerificationKey);
+        }
+
+        env.storage().instance().set(&DataKey::VerificationKey, &vk);
+        Ok(())
+    }
+
+    /// Get the registry entry the verification key is resolved from, if set
+    pub fn get_vk_source(env: Env) -> Option<VkSource> {
+        env.storage().instance().get(&DataKey::VkSource)
+    }
+
+    /// Resolve the Groth16 verification key from a `vk-registry` entry
+    ///
+    /// Replaces the key stored with `set_verification_key` while set, so
+    /// every deployment pinned to the same entry verifies against one
+    /// registered key. The entry is fetched and length-checked here; moving
+    /// to a new setup means pointing at its version.
+    ///
+    /// # Arguments
+    /// * `source` - Registry, circuit ID and version, or `None` to go back
+    ///   to the stored key
+    pub fn set_vk_source(env: Env, source: Option<VkSource>) -> Result<(), Error> {
+        let action = match &source {
+            Some(source) => CouncilAction::SetVkSource(source.clone()),
+            None => CouncilAction::RemoveVkSource,
+        };
+        Self::authorize(&env, action)?;
+
+        let Some(source) = source else {
+            env.storage().instance().remove(&DataKey::VkSource);
+            return Ok(());
+        };
+
+        let registry = VkRegistryClient::new(&env, &source.registry);
+        let vk = registry.get_vk(&source.circuit_id, &source.version);
+        if vk.len() != groth16::vk_len(6) {
+            return Err(Error::InvalidVerificationKey);
+        }
+
+        env.storage().instance().set(&DataKey::VkSource, &source);
+        Ok(())
+    }
+
+    /// Get the proof system new games are started with
+    pub fn get_proof_system(env: Env) -> ProofSystem {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProofSystem)
+            .unwrap_or(ProofSystem::Groth16)
+    }
+
+    /// Set the proof system new games are started with
+    ///
+    /// Games already started keep the backend they were created with.
+    ///
+    /// # Arguments
+    /// * `proof_system` - Backend for subsequent `start_game` calls
+    pub fn set_proof_system(env: Env, proof_system: ProofSystem) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetProofSystem(proof_system))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ProofSystem, &proof_system);
+        Ok(())
+    }
+
+    /// Get the largest proof, in bytes, a submission may carry
+    pub fn get_max_proof_size(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxProofSize)
+            .unwrap_or(DEFAULT_MAX_PROOF_SIZE)
+    }
+
+    /// Set the largest proof, in bytes, a submission may carry
+    ///
+    /// Larger proofs fail with `ProofTooLarge` before they are hashed or
+    /// stored, so they cannot inflate a transaction's cost. Raise it before
+    /// moving to a backend with bigger proofs.
+    ///
+    /// # Arguments
+    /// * `size` - Limit in bytes (`DEFAULT_MAX_PROOF_SIZE` until set)
+    pub fn set_max_proof_size(env: Env, size: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::MaxProofSize, &size);
+        Ok(())
+    }
+
+    /// Get the ledgers players have to submit after a game starts
+    /// (`DEFAULT_TIMEOUT_LEDGERS` until set)
+    pub fn get_timeout_ledgers(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimeoutLedgers)
+            .unwrap_or(DEFAULT_TIMEOUT_LEDGERS)
+    }
+
+    /// Set the ledgers players have to submit after a game starts, after
+    /// which `resolve_timeout` settles abandoned games. Players then have as
+    /// long again to reveal.
+    ///
+    /// Games keep the deadlines they started with. Both windows must end
+    /// before the game's storage would expire, else `InvalidDeadlines`.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+    /// * `ledgers` - Timeout in ledgers, below half the game storage TTL
+    pub fn set_timeout_ledgers(env: Env, caller: Address, ledgers: u32) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::Operator)?;
+
+        if ledgers.saturating_mul(2) >= GAME_TTL_LEDGERS {
+            return Err(Error::InvalidDeadlines);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::TimeoutLedgers, &ledgers);
+        Ok(())
+    }
+
+    /// Get the oldest proof version `submit_tactic_envelope` accepts
+    /// (`BARE_PROOF_VERSION` until set)
+    pub fn get_min_proof_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinProofVersion)
+            .unwrap_or(BARE_PROOF_VERSION)
+    }
+
+    /// Set the oldest proof version accepted
+    ///
+    /// Raise it once provers have moved to a new circuit to stop accepting
+    /// proofs from the old one, including bare `submit_tactic` proofs.
+    ///
+    /// # Arguments
+    /// * `version` - At most `PROOF_VERSION`, or `UnsupportedProofVersion`
+    pub fn set_min_proof_version(env: Env, version: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if version > PROOF_VERSION {
+            return Err(Error::UnsupportedProofVersion);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinProofVersion, &version);
+        Ok(())
+    }
+
+    /// Get the UltraHonk verifier contract address, if configured
+    pub fn get_ultrahonk_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::UltraHonkVerifier)
+    }
+
+    /// Set the verifier contract for games using `ProofSystem::UltraHonk`
+    ///
+    /// # Arguments
+    /// * `verifier` - Contract implementing `verify_proof(public_inputs, proof_bytes)`
+    pub fn set_ultrahonk_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetUltraHonkVerifier(verifier.clone()))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::UltraHonkVerifier, &verifier);
+        Ok(())
+    }
+
+    /// Get the hidden-stake range proof verifier, if configured
+    pub fn get_stake_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::StakeVerifier)
+    }
+
+    /// Set the verifier for `start_hidden_stake_game` range proofs
+    ///
+    /// # Arguments
+    /// * `verifier` - Contract implementing `verify(proof, public_inputs) -> bool`
+    pub fn set_stake_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetStakeVerifier(verifier.clone()))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::StakeVerifier, &verifier);
+        Ok(())
+    }
+
+    /// Get the rating proof verifier for bracket games, if configured
+    pub fn get_rating_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::RatingVerifier)
+    }
+
+    /// Set the verifier for `start_bracket_game` rating proofs
+    ///
+    /// # Arguments
+    /// * `verifier` - Contract implementing `verify(proof, public_inputs) -> bool`
+    pub fn set_rating_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetRatingVerifier(verifier.clone()))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RatingVerifier, &verifier);
+        Ok(())
+    }
+
+    /// Get the anti-sybil gate for ranked games, if set
+    pub fn get_sybil_gate(env: Env) -> Option<SybilGate> {
+        env.storage().instance().get(&DataKey::SybilGate)
+    }
+
+    /// Set the anti-sybil gate `start_ranked_game` checks players against
+    ///
+    /// Publish a new identity root as identities register, and a new
+    /// bracket when a ranking period starts. Nullifiers of earlier brackets
+    /// are kept but no longer checked.
+    ///
+    /// # Arguments
+    /// * `gate` - Verifier, identity root and bracket, or `None` to disable
+    ///   ranked games
+    pub fn set_sybil_gate(env: Env, gate: Option<SybilGate>) -> Result<(), Error> {
+        let action = match &gate {
+            Some(gate) => {
+                let gate_hash = env.crypto().sha256(&gate.clone().to_xdr(&env));
+                CouncilAction::SetSybilGate(gate_hash.into())
+            }
+            None => CouncilAction::RemoveSybilGate,
+        };
+        Self::authorize(&env, action)?;
+
+        match gate {
+            Some(gate) => env.storage().instance().set(&DataKey::SybilGate, &gate),
+            None => env.storage().instance().remove(&DataKey::SybilGate),
+        }
+        Ok(())
+    }
+
+    /// Get the image ID new games are scored by, if one is set
+    pub fn get_simulation_image_id(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::SimulationImageId)
+    }
+
+    /// Score new games with an off-chain simulation instead of the matrix
+    ///
+    /// Games started while an image ID is set must be resolved with
+    /// `resolve_with_receipt`, using a receipt of that zkVM program. Games
+    /// already started keep the scoring they were created with.
+    ///
+    /// # Arguments
+    /// * `image_id` - zkVM image ID of the simulation, or `None` for the matrix
+    pub fn set_simulation_image_id(env: Env, image_id: Option<BytesN<32>>) -> Result<(), Error> {
+        let action = match &image_id {
+            Some(image_id) => CouncilAction::SetSimulationImageId(image_id.clone()),
+            None => CouncilAction::RemoveSimulationImageId,
+        };
+        Self::authorize(&env, action)?;
+
+        match image_id {
+            Some(image_id) => env
+                .storage()
+                .instance()
+                .set(&DataKey::SimulationImageId, &image_id),
+            None => env.storage().instance().remove(&DataKey::SimulationImageId),
+        }
+        Ok(())
+    }
+
+    /// Get the zkVM receipt verifier contract address, if configured
+    pub fn get_receipt_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::ReceiptVerifier)
+    }
+
+    /// Set the verifier for `resolve_with_receipt` seals
+    ///
+    /// # Arguments
+    /// * `verifier` - Contract implementing
+    ///   `verify_receipt(seal, image_id, journal_digest)`
+    pub fn set_receipt_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetReceiptVerifier(verifier.clone()))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReceiptVerifier, &verifier);
+        Ok(())
+    }
+
+    /// Get the verifier contract for multi-round recursive proofs, if one is set
+    pub fn get_rounds_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::RoundsVerifier)
+    }
+
+    /// Set the verifier contract for multi-round recursive proofs
+    ///
+    /// # Arguments
+    /// * `verifier` - Contract exposing `verify(proof, public_inputs)` for
+    ///   the recursive rounds circuit
+    pub fn set_rounds_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetRoundsVerifier(verifier.clone()))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RoundsVerifier, &verifier);
+        Ok(())
+    }
+
+    /// Get the tiebreak policy new games are started with
+    pub fn get_tiebreak_policy(env: Env) -> TiebreakPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::TiebreakPolicy)
+            .unwrap_or(TiebreakPolicy::Draw)
+    }
+
+    /// Set how drawn matches are decided for new games
+    ///
+    /// Games already started keep the policy they were created with.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+    /// * `policy` - Policy for subsequent `start_game` calls
+    pub fn set_tiebreak_policy(
+        env: Env,
+        caller: Address,
+        policy: TiebreakPolicy,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::Operator)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TiebreakPolicy, &policy);
+        Ok(())
+    }
+
+    /// Get the proof freshness window, if one is set
+    pub fn get_proof_freshness_window(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::ProofFreshnessWindow)
+    }
+
+    /// Set how far ahead a proof's `valid_until_ledger` may be
+    ///
+    /// While a window is set, every proof must carry a non-zero
+    /// `valid_until_ledger` no more than `window` ledgers after the ledger it
+    /// is submitted in. `None` lifts the limit (expiries are still enforced).
+    ///
+    /// # Arguments
+    /// * `window` - Maximum validity in ledgers, or `None` for no limit
+    pub fn set_proof_freshness_window(env: Env, window: Option<u32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        match window {
+            Some(window) => env
+                .storage()
+                .instance()
+                .set(&DataKey::ProofFreshnessWindow, &window),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::ProofFreshnessWindow),
+        }
+        Ok(())
+    }
+
+    /// Whether new Groth16 games defer proof verification to `resolve_match`
+    pub fn get_batch_verification(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::BatchVerification)
+            .unwrap_or(false)
+    }
+
+    /// Defer proof verification of new Groth16 games to `resolve_match`
+    ///
+    /// Both proofs of a match are then checked with one batched pairing
+    /// check instead of one each at submission, at the cost of catching an
+    /// invalid proof only at resolution (its player forfeits). Games already
+    /// started keep the mode they were created with.
+    ///
+    /// # Arguments
+    /// * `enabled` - Mode for subsequent `start_game` calls
+    pub fn set_batch_verification(env: Env, enabled: bool) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetBatchVerification(enabled))?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BatchVerification, &enabled);
+        Ok(())
+    }
+
+    /// Whether new games settle themselves on their last reveal
+    pub fn get_auto_resolve(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AutoResolve)
+            .unwrap_or(false)
+    }
+
+    /// Settle new games as soon as their last reveal lands
+    ///
+    /// Tactics stay hidden until both players have submitted and revealed,
+    /// so the reveal completing a game (a tactic, the last round or, in
+    /// hidden-stake games, the last stake) scores it and calls the hub in
+    /// the same transaction, with no separate `resolve_match`. That reveal
+    /// then fails if settling does, though not when only the hub call fails
+    /// (see `retry_hub_notify`). Games scored by
+    /// a zkVM receipt still need `resolve_with_receipt`. Games already
+    /// started keep the mode they were created with.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+    /// * `enabled` - Mode for subsequent `start_game` calls
+    pub fn set_auto_resolve(env: Env, caller: Address, enabled: bool) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::Operator)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AutoResolve, &enabled);
+        Ok(())
+    }
+
+    /// Get the points a player may stake on a game (0 to `i128::MAX` until
+    /// set)
+    pub fn get_wager_limits(env: Env) -> WagerLimits {
+        env.storage()
+            .instance()
+            .get(&DataKey::WagerLimits)
+            .unwrap_or(WagerLimits {
+                min_wager: 0,
+                max_wager: i128::MAX,
+            })
+    }
+
+    /// Set the points a player may stake on games started from now on
+    ///
+    /// Every `start_*` call checks both players' points, returning
+    /// `WagerTooSmall` or `WagerTooLarge` for points outside the limits.
+    /// Hidden-stake games check their `[min_stake, max_stake]` range
+    /// instead, since the stakes themselves are not yet known. Negative
+    /// points are always rejected; a `min_wager` of 1 also rules out
+    /// games played for nothing.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `min_wager` - Fewest points a player may stake, at least 0
+    /// * `max_wager` - Most points a player may stake, at least `min_wager`
+    ///   (else `InvalidWagerLimits`)
+    pub fn set_wager_limits(
+        env: Env,
+        caller: Address,
+        min_wager: i128,
+        max_wager: i128,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if min_wager < 0 || min_wager > max_wager {
+            return Err(Error::InvalidWagerLimits);
+        }
+        let previous = Self::get_wager_limits(env.clone());
+        let limits = WagerLimits {
+            min_wager,
+            max_wager,
+        };
+        env.storage().instance().set(&DataKey::WagerLimits, &limits);
+        WagerLimitsChanged {
+            caller,
+            previous,
+            limits,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the token new games are wagered in (None = points only, nothing
+    /// is escrowed)
+    pub fn get_wager_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::WagerToken)
+    }
+
+    /// Get the native XLM Stellar Asset Contract on this network, for
+    /// `start_native_game` or `set_wager_token`
+    pub fn get_native_token(env: Env) -> Address {
+        escrow::native_token(&env)
+    }
+
+    /// Set the token games started from now on are wagered in
+    ///
+    /// While set, every `start_*` call moves each player's points, as units
+    /// of `token`, from the player into the contract, where they stay until
+    /// the game settles (see `get_escrow`). Players authorize that transfer
+    /// along with the start call. Hidden-stake games lock `max_stake` from
+    /// both. Games already started keep the token they escrowed.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `token` - A Stellar Asset Contract or other SEP-41 token, or `None`
+    ///   to stop escrowing
+    pub fn set_wager_token(env: Env, caller: Address, token: Option<Address>) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        let previous = Self::get_wager_token(env.clone());
+        match &token {
+            Some(token) => env.storage().instance().set(&DataKey::WagerToken, token),
+            None => env.storage().instance().remove(&DataKey::WagerToken),
+        }
+        WagerTokenChanged {
+            caller,
+            previous,
+            token,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the tokens held in escrow for a game, if it was wagered in a token
+    pub fn get_escrow(env: Env, session_id: u32) -> Option<Escrow> {
+        escrow::get(&env, session_id)
+    }
+
+    /// Pay out winnings held when a game settled
+    ///
+    /// `resolve_match` pushes each player's share of the pot to them; if the
+    /// token refuses (for example a smart wallet or account not yet able to
+    /// hold it), the share stays in escrow. Once the player can receive the
+    /// token, anyone may call this to send it on. Shares the token still
+    /// refuses stay held.
+    ///
+    /// # Returns
+    /// * The total amount paid out
+    pub fn claim_winnings(env: Env, session_id: u32) -> Result<i128, Error> {
+        let mut escrow = escrow::get(&env, session_id)
+            .filter(|escrow| escrow.settlement == Settlement::Winnings)
+            .ok_or(Error::NothingToClaim)?;
+
+        let paid = Self::pay_dues(&env, session_id, &mut escrow, true);
+        if paid == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        escrow::save(&env, session_id, &escrow);
+        Ok(paid)
+    }
+
+    /// Pay out refunds a game's escrow still owes
+    ///
+    /// Draws, cancellations and timeouts refund their escrow as they
+    /// settle (see `get_escrow`), holding any payout the token refuses.
+    /// This sends held refunds on, and refunds a game whose entry expired
+    /// before it was resolved, returning each player what they locked.
+    /// It is idempotent: with nothing left to refund (or once a refused
+    /// payout still cannot be sent) it pays nothing and returns 0. Returns
+    /// `GameNotSettled` for a game still in play. Anyone may call it.
+    ///
+    /// # Returns
+    /// * The total amount refunded by this call
+    pub fn claim_refund(env: Env, session_id: u32) -> Result<i128, Error> {
+        let Some(mut escrow) = escrow::get(&env, session_id) else {
+            return Ok(0);
+        };
+        match escrow.settlement {
+            Settlement::Pending => {
+                let game: Option<Game> = env.storage().temporary().get(&DataKey::Game(session_id));
+                if game.as_ref().is_some_and(|game| !game.is_over()) {
+                    return Err(Error::GameNotSettled);
+                }
+                Ok(Self::settle_escrow(&env, session_id, game.as_ref()))
+            }
+            Settlement::Refund => {
+                let paid = Self::pay_dues(&env, session_id, &mut escrow, true);
+                escrow::save(&env, session_id, &escrow);
+                Ok(paid)
+            }
+            Settlement::Winnings => Ok(0),
+        }
+    }
+
+    /// Prepay a token balance to start wagered games against
+    ///
+    /// `amount` of `token` moves from `player` into the contract once.
+    /// From then on, a game wagered in `token` takes the player's amount
+    /// out of this balance, if it covers it, instead of a transfer the
+    /// player has to authorize. Payouts are still sent to the player.
+    ///
+    /// # Arguments
+    /// * `player` - Who deposits, and signs the transfer
+    /// * `token` - The token to deposit
+    /// * `amount` - How much to deposit (else `InvalidBalanceAmount`)
+    ///
+    /// # Returns
+    /// * The player's balance in `token`
+    pub fn deposit_balance(
+        env: Env,
+        player: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+        Self::require_unpaused(&env)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidBalanceAmount);
+        }
+        escrow::take(&env, &token, &player, amount);
+        let balance = balance::get(&env, &player, &token) + amount;
+        balance::set(&env, &player, &token, balance);
+        BalanceDeposited {
+            player,
+            token,
+            amount,
+            balance,
+        }
+        .publish(&env);
+        Ok(balance)
+    }
+
+    /// Take tokens back out of a prepaid balance
+    ///
+    /// Amounts already staked on games have left the balance, and come
+    /// back as payouts. Allowed while paused.
+    ///
+    /// # Arguments
+    /// * `player` - Whose balance, and signs
+    /// * `token` - The balance's token
+    /// * `amount` - How much to withdraw (else `InvalidBalanceAmount`), up
+    ///   to the balance (else `InsufficientBalance`)
+    ///
+    /// # Returns
+    /// * The player's remaining balance in `token`
+    pub fn withdraw_balance(
+        env: Env,
+        player: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidBalanceAmount);
+        }
+        let held = balance::get(&env, &player, &token);
+        if amount > held {
+            return Err(Error::InsufficientBalance);
+        }
+        balance::set(&env, &player, &token, held - amount);
+        escrow::send(&env, &token, &player, amount);
+        BalanceWithdrawn {
+            player,
+            token,
+            amount,
+            balance: held - amount,
+        }
+        .publish(&env);
+        Ok(held - amount)
+    }
+
+    /// Get a player's prepaid balance in a token
+    pub fn get_balance(env: Env, player: Address, token: Address) -> i128 {
+        balance::get(&env, &player, &token)
+    }
+
+    /// Get who rules on disputed settlements, and for how long after
+    /// settling a pot can be disputed (None = no disputes)
+    pub fn get_dispute_config(env: Env) -> Option<DisputeConfig> {
+        env.storage().instance().get(&DataKey::DisputeConfig)
+    }
+
+    /// Set who rules on disputed settlements
+    ///
+    /// While set, every won pot between two players opens a dispute window
+    /// of `window_ledgers`, locking as much of the winner's bond in the
+    /// token they won as they were paid (`post_bond`). The loser may
+    /// dispute within the window, and `module` rules with
+    /// `resolve_dispute`. Windows already open keep the module they opened
+    /// with.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `Admin` role
+    /// * `config` - The module and a nonzero `window_ledgers` (else
+    ///   `InvalidDisputeConfig`), or `None` for no disputes
+    pub fn set_dispute_config(
+        env: Env,
+        caller: Address,
+        config: Option<DisputeConfig>,
+    ) -> Result<(), Error> {
+        let action = match &config {
+            Some(config) => CouncilAction::SetDisputeConfig(config.clone()),
+            None => CouncilAction::RemoveDisputeConfig,
+        };
+        Self::require_role_or_council(&env, &caller, Role::Admin, action)?;
+
+        if config.as_ref().is_some_and(|config| config.window_ledgers == 0) {
+            return Err(Error::InvalidDisputeConfig);
+        }
+        match &config {
+            Some(config) => env.storage().instance().set(&DataKey::DisputeConfig, config),
+            None => env.storage().instance().remove(&DataKey::DisputeConfig),
+        }
+        DisputeConfigChanged {
+            caller,
+            module: config.as_ref().map(|config| config.module.clone()),
+            window_ledgers: config.as_ref().map_or(0, |config| config.window_ledgers),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Post a bond that backs a player's wins while they can be disputed
+    ///
+    /// # Arguments
+    /// * `player` - Who posts, and signs the transfer
+    /// * `token` - The bond's token, the one the player's pots are won in
+    /// * `amount` - How much to add (else `InvalidBondAmount`)
+    ///
+    /// # Returns
+    /// * The player's bond in `token`
+    pub fn post_bond(
+        env: Env,
+        player: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+        Self::require_unpaused(&env)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidBondAmount);
+        }
+        escrow::take(&env, &token, &player, amount);
+        let mut bond = disputes::bond(&env, &player, &token);
+        bond.amount += amount;
+        disputes::save_bond(&env, &player, &token, &bond);
+        BondPosted {
+            player,
+            token,
+            amount,
+            bond: bond.amount,
+        }
+        .publish(&env);
+        Ok(bond.amount)
+    }
+
+    /// Take back the part of a bond not locked against a pot in its
+    /// dispute window. Allowed while paused.
+    ///
+    /// # Arguments
+    /// * `player` - Whose bond, and signs
+    /// * `token` - The bond's token
+    /// * `amount` - How much to withdraw, up to the free part (else
+    ///   `InvalidBondAmount`)
+    ///
+    /// # Returns
+    /// * The player's remaining bond in `token`
+    pub fn withdraw_bond(
+        env: Env,
+        player: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+
+        let mut bond = disputes::bond(&env, &player, &token);
+        if amount <= 0 || amount > bond.free() {
+            return Err(Error::InvalidBondAmount);
+        }
+        bond.amount -= amount;
+        disputes::save_bond(&env, &player, &token, &bond);
+        escrow::send(&env, &token, &player, amount);
+        BondWithdrawn {
+            player,
+            token,
+            amount,
+            bond: bond.amount,
+        }
+        .publish(&env);
+        Ok(bond.amount)
+    }
+
+    /// Get a player's bond in a token
+    pub fn get_bond(env: Env, player: Address, token: Address) -> Bond {
+        disputes::bond(&env, &player, &token)
+    }
+
+    /// Get a won pot's dispute window, while it is open or disputed
+    pub fn get_dispute(env: Env, session_id: u32) -> Option<Dispute> {
+        disputes::get(&env, session_id)
+    }
+
+    /// Dispute a settlement as its loser, for the dispute module to rule on
+    ///
+    /// # Arguments
+    /// * `session_id` - A won pot in its dispute window (else
+    ///   `DisputeNotFound`), up to its `deadline_ledger` and not yet
+    ///   disputed (else `DisputeWindowClosed`). The session ID stays in
+    ///   use until the dispute is resolved
+    pub fn dispute_settlement(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut dispute = disputes::get(&env, session_id).ok_or(Error::DisputeNotFound)?;
+        dispute.loser.require_auth();
+
+        if dispute.status != DisputeStatus::Open
+            || env.ledger().sequence() > dispute.deadline_ledger
+        {
+            return Err(Error::DisputeWindowClosed);
+        }
+        dispute.status = DisputeStatus::Filed;
+        disputes::save(&env, session_id, Some(&dispute));
+        SettlementDisputed {
+            session_id,
+            loser: dispute.loser,
+            module: dispute.module,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Rule on a disputed settlement
+    ///
+    /// Upholding it claws the locked part of the winner's bond back and
+    /// pays it to the loser; rejecting it releases the lock. The match
+    /// result itself, as recorded and reported to the hub, stands.
+    ///
+    /// # Arguments
+    /// * `session_id` - A disputed settlement (else `DisputeNotFound`)
+    /// * `upheld` - Whether the winner cheated
+    ///
+    /// # Returns
+    /// * The amount clawed back
+    pub fn resolve_dispute(env: Env, session_id: u32, upheld: bool) -> Result<i128, Error> {
+        let dispute = disputes::get(&env, session_id)
+            .filter(|dispute| dispute.status == DisputeStatus::Filed)
+            .ok_or(Error::DisputeNotFound)?;
+        dispute.module.require_auth();
+
+        disputes::unlock(&env, &dispute.winner, &dispute.token, dispute.locked, upheld);
+        disputes::save(&env, session_id, None);
+        let clawed_back = if upheld { dispute.locked } else { 0 };
+        if clawed_back > 0 {
+            escrow::send(&env, &dispute.token, &dispute.loser, clawed_back);
+        }
+        DisputeResolved {
+            session_id,
+            upheld,
+            winner: dispute.winner,
+            loser: dispute.loser,
+            token: dispute.token,
+            clawed_back,
+        }
+        .publish(&env);
+        Ok(clawed_back)
+    }
+
+    /// Close a dispute window that passed undisputed, releasing the lock
+    /// on the winner's bond. Anyone may call it
+    ///
+    /// # Arguments
+    /// * `session_id` - A won pot in its dispute window (else
+    ///   `DisputeNotFound`), past its `deadline_ledger` and not disputed
+    ///   (else `DisputeWindowOpen`)
+    pub fn finalize_settlement(env: Env, session_id: u32) -> Result<(), Error> {
+        let dispute = disputes::get(&env, session_id).ok_or(Error::DisputeNotFound)?;
+        if dispute.status != DisputeStatus::Open
+            || env.ledger().sequence() <= dispute.deadline_ledger
+        {
+            return Err(Error::DisputeWindowOpen);
+        }
+        disputes::unlock(&env, &dispute.winner, &dispute.token, dispute.locked, false);
+        disputes::save(&env, session_id, None);
+        SettlementFinalized {
+            session_id,
+            winner: dispute.winner,
+            released: dispute.locked,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the staking token, tiers and waiting periods (None = no staking)
+    pub fn get_staking_config(env: Env) -> Option<StakingConfig> {
+        env.storage().instance().get(&DataKey::StakingConfig)
+    }
+
+    /// Set how staking the studio token discounts the protocol fee
+    ///
+    /// A player whose active stake in `token` reaches a tier's `min_stake`
+    /// has the fee on the pots they win cut by the highest such tier's
+    /// `discount_bps`, from the next settlement on. Stakes are locked for
+    /// `lockup_ledgers` from their last top-up, and wait `cooldown_ledgers`
+    /// between `unstake` and `withdraw_stake`. Removing staking, or moving
+    /// it to another token, ends every discount; existing stakes can still
+    /// be unstaked and withdrawn.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `config` - The staking rules, with 1 to `MAX_STAKE_TIERS` tiers
+    ///   rising in `min_stake` from above 0 and each `discount_bps` at most
+    ///   10,000 (else `InvalidStakingConfig`), or `None` for no staking
+    pub fn set_staking_config(
+        env: Env,
+        caller: Address,
+        config: Option<StakingConfig>,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if let Some(config) = &config {
+            let tiers = config.tiers.len();
+            if tiers == 0 || tiers > MAX_STAKE_TIERS {
+                return Err(Error::InvalidStakingConfig);
+            }
+            let mut floor = 0;
+            for tier in config.tiers.iter() {
+                if tier.min_stake <= floor || tier.discount_bps > 10_000 {
+                    return Err(Error::InvalidStakingConfig);
+                }
+                floor = tier.min_stake;
+            }
+        }
+        match &config {
+            Some(config) => env.storage().instance().set(&DataKey::StakingConfig, config),
+            None => env.storage().instance().remove(&DataKey::StakingConfig),
+        }
+        StakingConfigChanged {
+            caller,
+            token: config.as_ref().map(|config| config.token.clone()),
+            tiers: config.as_ref().map_or(0, |config| config.tiers.len()),
+            lockup_ledgers: config.as_ref().map_or(0, |config| config.lockup_ledgers),
+            cooldown_ledgers: config.as_ref().map_or(0, |config| config.cooldown_ledgers),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Stake the staking token toward a fee discount tier
+    ///
+    /// The whole active stake, old and new, is then locked for the
+    /// configured `lockup_ledgers`.
+    ///
+    /// # Arguments
+    /// * `player` - Who stakes, and signs the transfer
+    /// * `amount` - How much to add (else `InvalidStakeAmount`, as when the
+    ///   player still has a stake in a token no longer configured)
+    ///
+    /// Returns `StakingNotConfigured` without a staking configuration.
+    pub fn stake(env: Env, player: Address, amount: i128) -> Result<Stake, Error> {
+        player.require_auth();
+        Self::require_unpaused(&env)?;
+
+        let config = Self::get_staking_config(env.clone()).ok_or(Error::StakingNotConfigured)?;
+        let mut stake = staking::get(&env, &player).unwrap_or(Stake {
+            token: config.token.clone(),
+            amount: 0,
+            locked_until_ledger: 0,
+            unstaking: 0,
+            cooldown_end_ledger: 0,
+        });
+        if amount <= 0 || stake.token != config.token {
+            return Err(Error::InvalidStakeAmount);
+        }
+        escrow::take(&env, &config.token, &player, amount);
+        stake.amount += amount;
+        stake.locked_until_ledger = env.ledger().sequence().saturating_add(config.lockup_ledgers);
+        staking::save(&env, &player, &stake);
+        Staked {
+            player,
+            amount,
+            stake: stake.amount,
+            locked_until_ledger: stake.locked_until_ledger,
+        }
+        .publish(&env);
+        Ok(stake)
+    }
+
+    /// Start withdrawing part of an active stake once its lockup is over
+    ///
+    /// `amount` stops counting toward the tier at once, and joins anything
+    /// already unstaking in a cooldown of the configured `cooldown_ledgers`
+    /// (none without staking), restarted from now.
+    ///
+    /// # Arguments
+    /// * `player` - Whose stake, and signs
+    /// * `amount` - Up to the active stake (else `InvalidStakeAmount`),
+    ///   unstaked from its `locked_until_ledger` on (else `StakeLocked`)
+    pub fn unstake(env: Env, player: Address, amount: i128) -> Result<Stake, Error> {
+        player.require_auth();
+
+        let mut stake = staking::get(&env, &player).ok_or(Error::InvalidStakeAmount)?;
+        if amount <= 0 || amount > stake.amount {
+            return Err(Error::InvalidStakeAmount);
+        }
+        let now = env.ledger().sequence();
+        if now < stake.locked_until_ledger {
+            return Err(Error::StakeLocked);
+        }
+        let cooldown = Self::get_staking_config(env.clone())
+            .map_or(0, |config| config.cooldown_ledgers);
+        stake.amount -= amount;
+        stake.unstaking += amount;
+        stake.cooldown_end_ledger = now.saturating_add(cooldown);
+        staking::save(&env, &player, &stake);
+        Unstaked {
+            player,
+            amount,
+            unstaking: stake.unstaking,
+            cooldown_end_ledger: stake.cooldown_end_ledger,
+        }
+        .publish(&env);
+        Ok(stake)
+    }
+
+    /// Withdraw everything unstaked once its cooldown is over. Allowed
+    /// while paused.
+    ///
+    /// # Arguments
+    /// * `player` - Whose stake, and signs; with something unstaking (else
+    ///   `InvalidStakeAmount`) from its `cooldown_end_ledger` on (else
+    ///   `StakeCoolingDown`)
+    ///
+    /// # Returns
+    /// * The amount withdrawn, in the stake's token
+    pub fn withdraw_stake(env: Env, player: Address) -> Result<i128, Error> {
+        player.require_auth();
+
+        let mut stake = staking::get(&env, &player).ok_or(Error::InvalidStakeAmount)?;
+        if stake.unstaking == 0 {
+            return Err(Error::InvalidStakeAmount);
+        }
+        if env.ledger().sequence() < stake.cooldown_end_ledger {
+            return Err(Error::StakeCoolingDown);
+        }
+        let amount = stake.unstaking;
+        stake.unstaking = 0;
+        staking::save(&env, &player, &stake);
+        escrow::send(&env, &stake.token, &player, amount);
+        StakeWithdrawn {
+            player,
+            token: stake.token,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Get a player's stake, if they have one
+    pub fn get_stake(env: Env, player: Address) -> Option<Stake> {
+        staking::get(&env, &player)
+    }
+
+    /// Get the fee discount tier a player's active stake earns now, if any
+    pub fn get_stake_tier(env: Env, player: Address) -> Option<StakeTier> {
+        let config = Self::get_staking_config(env.clone())?;
+        let stake = staking::get(&env, &player)?;
+        if stake.token != config.token {
+            return None;
+        }
+        config.tier_for(stake.amount)
+    }
+
+    /// Add liquidity to a token's house bankroll
+    ///
+    /// `amount` of `token` moves from `provider` into the bankroll, which
+    /// backs the house's stakes in `start_house_game`. The provider gets
+    /// shares in proportion to the bankroll's current value, so they share
+    /// its later wins and losses pro rata (see `withdraw_bankroll`).
+    ///
+    /// # Arguments
+    /// * `provider` - Who deposits, and signs the transfer
+    /// * `token` - The bankroll's token
+    /// * `amount` - How much to deposit (else `InvalidBankrollAmount`)
+    ///
+    /// # Returns
+    /// * The shares issued
+    pub fn deposit_bankroll(
+        env: Env,
+        provider: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        provider.require_auth();
+        Self::require_unpaused(&env)?;
+
+        let mut bankroll = bankroll::get(&env, &token);
+        let shares = bankroll.shares_for(amount);
+        if amount <= 0 || shares <= 0 {
+            return Err(Error::InvalidBankrollAmount);
+        }
+        escrow::take(&env, &token, &provider, amount);
+        bankroll.assets += amount;
+        bankroll.shares += shares;
+        bankroll::save(&env, &token, &bankroll);
+        let held = bankroll::shares(&env, &token, &provider);
+        bankroll::set_shares(&env, &token, &provider, held + shares);
+        BankrollDeposited {
+            token,
+            provider,
+            amount,
+            shares,
+        }
+        .publish(&env);
+        Ok(shares)
+    }
+
+    /// Redeem bankroll shares for their share of the bankroll
+    ///
+    /// Stakes locked on house games in play cannot be withdrawn until the
+    /// games settle: a withdrawal larger than the unlocked part returns
+    /// `InsufficientBankroll`. Allowed while paused.
+    ///
+    /// # Arguments
+    /// * `provider` - Who holds the shares, and signs
+    /// * `token` - The bankroll's token
+    /// * `shares` - How many to redeem, up to all the provider holds (else
+    ///   `InvalidBankrollAmount`)
+    ///
+    /// # Returns
+    /// * The amount of `token` sent to `provider`
+    pub fn withdraw_bankroll(
+        env: Env,
+        provider: Address,
+        token: Address,
+        shares: i128,
+    ) -> Result<i128, Error> {
+        provider.require_auth();
+
+        let held = bankroll::shares(&env, &token, &provider);
+        if shares <= 0 || shares > held {
+            return Err(Error::InvalidBankrollAmount);
+        }
+        let mut bankroll = bankroll::get(&env, &token);
+        let amount = bankroll.value_of(shares);
+        if amount > bankroll.free() {
+            return Err(Error::InsufficientBankroll);
+        }
+        bankroll.assets -= amount;
+        bankroll.shares -= shares;
+        bankroll::save(&env, &token, &bankroll);
+        bankroll::set_shares(&env, &token, &provider, held - shares);
+        escrow::send(&env, &token, &provider, amount);
+        BankrollWithdrawn {
+            token,
+            provider,
+            amount,
+            shares,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Set the randomness beacon house games draw the house's tactics from
+    ///
+    /// Each house game binds to the beacon's pending round when the player
+    /// commits (see `start_house_game`), and keeps that beacon if it is
+    /// changed later. Council-approved once a council is set.
+    ///
+    /// # Arguments
+    /// * `beacon` - A `contracts/randomness-beacon` deployment
+    pub fn set_house_beacon(env: Env, beacon: Address) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetHouseBeacon(beacon.clone()))?;
+
+        env.storage().instance().set(&DataKey::HouseBeacon, &beacon);
+        Ok(())
+    }
+
+    /// Get the house's randomness beacon, if one is set
+    pub fn get_house_beacon(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::HouseBeacon)
+    }
+
+    /// Get the beacon and round a house game's tactic is drawn from, once
+    /// the player has committed
+    pub fn get_house_round(env: Env, session_id: u32) -> Option<(Address, u64)> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::HouseRound(session_id))
+    }
+
+    /// Get a token's house bankroll (empty if nobody has deposited)
+    pub fn get_bankroll(env: Env, token: Address) -> Bankroll {
+        bankroll::get(&env, &token)
+    }
+
+    /// Get `provider`'s shares of a token's house bankroll
+    pub fn get_bankroll_shares(env: Env, token: Address, provider: Address) -> i128 {
+        bankroll::shares(&env, &token, &provider)
+    }
+
+    /// Stake on a player of an open game
+    ///
+    /// Anyone but the game's players may back either of them, until both
+    /// tactics are submitted (else `SideBetsClosed`). Bets are in the
+    /// game's `wager_token`; a points-only or mixed-asset game takes none
+    /// (`SideBetsUnavailable`). Repeat bets add to the bettor's stake, but
+    /// must back the same player. The stakes form a pari-mutuel pool: when
+    /// `resolve_match` settles a win, the winner's backers share the losing
+    /// side's stakes, less a rake (`get_side_bet_rake_bps`) paid to the
+    /// winner. Any other ending refunds every bet. See `claim_side_bet`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game to bet on
+    /// * `bettor` - Who stakes, and signs the transfer
+    /// * `player` - The player backed to win
+    /// * `amount` - How much of the game's token to stake
+    pub fn place_side_bet(
+        env: Env,
+        session_id: u32,
+        bettor: Address,
+        player: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        bettor.require_auth();
+        Self::require_unpaused(&env)?;
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if !matches!(game.phase, GamePhase::Created | GamePhase::AwaitingOpponent) {
+            return Err(Error::SideBetsClosed);
+        }
+        let token = game.wager_token.clone().ok_or(Error::SideBetsUnavailable)?;
+        if escrow::get(&env, session_id).is_some_and(|escrow| escrow.player2_token != token) {
+            return Err(Error::SideBetsUnavailable);
+        }
+        if amount <= 0
+            || bettor == game.player1
+            || bettor == game.player2
+            || (player != game.player1 && player != game.player2)
+        {
+            return Err(Error::InvalidSideBet);
+        }
+
+        let mut bet = side_bets::bet(&env, session_id, &bettor).unwrap_or(SideBet {
+            player: player.clone(),
+            amount: 0,
+        });
+        if bet.player != player {
+            return Err(Error::InvalidSideBet);
+        }
+        bet.amount += amount;
+        let mut pool = side_bets::pool(&env, session_id).unwrap_or(SideBetPool {
+            token: token.clone(),
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            player1_total: 0,
+            player2_total: 0,
+            rake_bps: Self::get_side_bet_rake_bps(env.clone()),
+            result: SideBetResult::Open,
+            rake: 0,
+            open_stake: 0,
+            unclaimed: 0,
+        });
+        if player == game.player1 {
+            pool.player1_total += amount;
+        } else {
+            pool.player2_total += amount;
+        }
+
+        escrow::take(&env, &token, &bettor, amount);
+        side_bets::save_bet(&env, session_id, &bettor, Some(&bet));
+        side_bets::save_pool(&env, session_id, &pool);
+        SideBetPlaced {
+            session_id,
+            bettor,
+            player,
+            amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Pay out a side bet once its game has settled
+    ///
+    /// Sends `bettor` their share of the pool if they backed the winner, or
+    /// their stake back if the pool was refunded, settling the pool first
+    /// if its game expired unresolved. Called with the match winner, it
+    /// sends them a rake the token refused at settlement. Returns
+    /// `GameNotSettled` while the game is in play, and `NothingToClaim`
+    /// for a losing, already paid or unknown bet. Anyone may call it.
+    ///
+    /// # Returns
+    /// * The amount paid
+    pub fn claim_side_bet(env: Env, session_id: u32, bettor: Address) -> Result<i128, Error> {
+        let mut pool = side_bets::pool(&env, session_id).ok_or(Error::NothingToClaim)?;
+        if pool.result == SideBetResult::Open {
+            let game: Option<Game> = env.storage().temporary().get(&DataKey::Game(session_id));
+            if game.as_ref().is_some_and(|game| !game.is_over()) {
+                return Err(Error::GameNotSettled);
+            }
+            Self::settle_side_bets(&env, session_id, game.as_ref());
+            pool = side_bets::pool(&env, session_id).ok_or(Error::NothingToClaim)?;
+        }
+
+        let winner = match pool.result {
+            SideBetResult::Player1Won => Some(&pool.player1),
+            SideBetResult::Player2Won => Some(&pool.player2),
+            _ => None,
+        };
+        let amount = if winner == Some(&bettor) {
+            core::mem::take(&mut pool.rake)
+        } else {
+            let bet = side_bets::bet(&env, session_id, &bettor).ok_or(Error::NothingToClaim)?;
+            let payout = pool.payout(&bet);
+            if payout > 0 {
+                pool.open_stake -= bet.amount;
+                pool.unclaimed -= payout;
+                side_bets::save_bet(&env, session_id, &bettor, None);
+            }
+            payout
+        };
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        escrow::send(&env, &pool.token, &bettor, amount);
+        side_bets::save_pool(&env, session_id, &pool);
+        SideBetPaid {
+            session_id,
+            recipient: bettor,
+            token: pool.token.clone(),
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Get the side bets placed on a game, while any are unpaid
+    pub fn get_side_bet_pool(env: Env, session_id: u32) -> Option<SideBetPool> {
+        side_bets::pool(&env, session_id)
+    }
+
+    /// Get `bettor`'s stake on a game, until it is paid out
+    pub fn get_side_bet(env: Env, session_id: u32, bettor: Address) -> Option<SideBet> {
+        side_bets::bet(&env, session_id, &bettor)
+    }
+
+    /// Get the rake on new side-bet pools, in basis points of the losing
+    /// side (`DEFAULT_SIDE_BET_RAKE_BPS` by default)
+    pub fn get_side_bet_rake_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SideBetRakeBps)
+            .unwrap_or(DEFAULT_SIDE_BET_RAKE_BPS)
+    }
+
+    /// Set the rake on side-bet pools opened from now on
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `rake_bps` - At most `MAX_FEE_BPS` (else `InvalidFee`)
+    pub fn set_side_bet_rake_bps(env: Env, caller: Address, rake_bps: u32) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if rake_bps > MAX_FEE_BPS {
+            return Err(Error::InvalidFee);
+        }
+        let previous_bps = Self::get_side_bet_rake_bps(env.clone());
+        env.storage().instance().set(&DataKey::SideBetRakeBps, &rake_bps);
+        SideBetRakeChanged {
+            caller,
+            previous_bps,
+            rake_bps,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Name the address that referred `player`
+    ///
+    /// From then on `referrer` is credited `get_referral_share_bps` of the
+    /// protocol fee taken from `player`'s stake in each won pot, claimable
+    /// with `claim_referral_rewards`. A player names a referrer once (else
+    /// `ReferrerAlreadySet`), and cannot name themselves (`InvalidReferrer`).
+    ///
+    /// # Arguments
+    /// * `player` - The referred player, who signs
+    /// * `referrer` - Who referred them
+    pub fn register_referrer(env: Env, player: Address, referrer: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        if player == referrer {
+            return Err(Error::InvalidReferrer);
+        }
+        if referral::referrer(&env, &player).is_some() {
+            return Err(Error::ReferrerAlreadySet);
+        }
+        referral::set_referrer(&env, &player, &referrer);
+        referral::update_stats(&env, &referrer, |stats| stats.referees += 1);
+        ReferrerRegistered { player, referrer }.publish(&env);
+        Ok(())
+    }
+
+    /// Send a referrer their unclaimed rewards in `token`
+    ///
+    /// Returns `NothingToClaim` if there are none. Anyone may call it.
+    ///
+    /// # Returns
+    /// * The amount sent
+    pub fn claim_referral_rewards(
+        env: Env,
+        referrer: Address,
+        token: Address,
+    ) -> Result<i128, Error> {
+        let mut rewards = referral::rewards(&env, &referrer, &token);
+        let amount = rewards.claimable;
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        rewards.claimable = 0;
+        referral::save_rewards(&env, &referrer, &token, &rewards);
+        escrow::send(&env, &token, &referrer, amount);
+        ReferralRewardsClaimed {
+            referrer,
+            token,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Get who referred `player`, if anyone
+    pub fn get_referrer(env: Env, player: Address) -> Option<Address> {
+        referral::referrer(&env, &player)
+    }
+
+    /// Get how many players a referrer has brought in, and how many of
+    /// their stakes have earned the referrer a share
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        referral::stats(&env, &referrer)
+    }
+
+    /// Get a referrer's rewards in `token`, earned and still claimable
+    pub fn get_referral_rewards(env: Env, referrer: Address, token: Address) -> ReferralRewards {
+        referral::rewards(&env, &referrer, &token)
+    }
+
+    /// Get the referrers' share of the fee on their referees' stakes, in
+    /// basis points (`DEFAULT_REFERRAL_SHARE_BPS` by default)
+    pub fn get_referral_share_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReferralShareBps)
+            .unwrap_or(DEFAULT_REFERRAL_SHARE_BPS)
+    }
+
+    /// Set the referrers' share of the fee on their referees' stakes
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `share_bps` - At most 10,000 (else `InvalidFee`)
+    pub fn set_referral_share_bps(env: Env, caller: Address, share_bps: u32) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if share_bps > 10_000 {
+            return Err(Error::InvalidFee);
+        }
+        let previous_bps = Self::get_referral_share_bps(env.clone());
+        env.storage().instance().set(&DataKey::ReferralShareBps, &share_bps);
+        ReferralShareChanged {
+            caller,
+            previous_bps,
+            share_bps,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the hub's share of the fee on won pots, in basis points (0 by
+    /// default)
+    pub fn get_hub_share_bps(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::HubShareBps).unwrap_or(0)
+    }
+
+    /// Set the hub's share of the fee on won pots
+    ///
+    /// As a won pot settles, `share_bps` of each stake's fee, after any
+    /// referral share, is sent to the treasury the hub names with its
+    /// `treasury()`, and the fee collector keeps the rest. A hub that names
+    /// none, or a treasury the token refuses, leaves the fee collector the
+    /// whole fee.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `share_bps` - At most 10,000 (else `InvalidFee`)
+    pub fn set_hub_share_bps(env: Env, caller: Address, share_bps: u32) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if share_bps > 10_000 {
+            return Err(Error::InvalidFee);
+        }
+        let previous_bps = Self::get_hub_share_bps(env.clone());
+        env.storage().instance().set(&DataKey::HubShareBps, &share_bps);
+        HubShareChanged {
+            caller,
+            previous_bps,
+            share_bps,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get a player's current and best run of consecutive wins
+    pub fn get_streak(env: Env, player: Address) -> Streak {
+        streak::get(&env, &player)
+    }
+
+    /// Get the bonus paid for winning on a streak (None = no bonus)
+    pub fn get_streak_bonus(env: Env) -> Option<StreakBonus> {
+        env.storage().instance().get(&DataKey::StreakBonus)
+    }
+
+    /// Set the bonus paid for winning on a streak
+    ///
+    /// A player who wins a pot with a streak (`get_streak`) of at least
+    /// `min_streak` is owed `step_bps` of what they won for each win from
+    /// `min_streak` on, up to `max_bps`, alongside their winnings. The
+    /// bonus comes out of the protocol fees accrued in that token, and is
+    /// cut to what has accrued.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `bonus` - The schedule, with `min_streak` at least 2 and `max_bps`
+    ///   at most 10,000 (else `InvalidStreakBonus`), or `None` for no bonus
+    pub fn set_streak_bonus(
+        env: Env,
+        caller: Address,
+        bonus: Option<StreakBonus>,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if bonus
+            .as_ref()
+            .is_some_and(|bonus| bonus.min_streak < 2 || bonus.max_bps > 10_000)
+        {
+            return Err(Error::InvalidStreakBonus);
+        }
+        match &bonus {
+            Some(bonus) => env.storage().instance().set(&DataKey::StreakBonus, bonus),
+            None => env.storage().instance().remove(&DataKey::StreakBonus),
+        }
+        StreakBonusChanged {
+            caller,
+            min_streak: bonus.as_ref().map_or(0, |bonus| bonus.min_streak),
+            step_bps: bonus.as_ref().map_or(0, |bonus| bonus.step_bps),
+            max_bps: bonus.as_ref().map_or(0, |bonus| bonus.max_bps),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the epoch rules (None = no epochs)
+    pub fn get_epoch_config(env: Env) -> Option<EpochConfig> {
+        env.storage().instance().get(&DataKey::EpochConfig)
+    }
+
+    /// Set how reward epochs run and fill their pools
+    ///
+    /// Epochs last `length_ledgers`, and `share_bps` of every protocol fee
+    /// taken in `token`, after any referral and hub share, goes to the
+    /// running epoch's pool. Wins in games wagered in `token` rank players
+    /// in the epoch they settle in; once it is over, its top `top_n`
+    /// share the pool pro rata to their wins (`claim_epoch_reward`).
+    /// Changing the rules, or removing them, ends the running epoch: the
+    /// next one starts now.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `config` - The rules, with a nonzero `length_ledgers`, `share_bps`
+    ///   at most 10,000 and `top_n` from 1 to `MAX_EPOCH_LEADERS` (else
+    ///   `InvalidEpochConfig`), or `None` for no epochs
+    pub fn set_epoch_config(
+        env: Env,
+        caller: Address,
+        config: Option<EpochConfig>,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if config.as_ref().is_some_and(|config| {
+            config.length_ledgers == 0
+                || config.share_bps > 10_000
+                || !(1..=MAX_EPOCH_LEADERS).contains(&config.top_n)
+        }) {
+            return Err(Error::InvalidEpochConfig);
+        }
+        let schedule: Option<EpochSchedule> =
+            env.storage().instance().get(&DataKey::EpochSchedule);
+        let first_epoch = match (Self::current_epoch(&env), schedule) {
+            (Some((_, epoch)), _) => epoch + 1,
+            (None, Some(schedule)) => schedule.first_epoch,
+            (None, None) => 0,
+        };
+        let schedule = EpochSchedule {
+            first_epoch,
+            start_ledger: env.ledger().sequence(),
+        };
+        env.storage().instance().set(&DataKey::EpochSchedule, &schedule);
+        match &config {
+            Some(config) => env.storage().instance().set(&DataKey::EpochConfig, config),
+            None => env.storage().instance().remove(&DataKey::EpochConfig),
+        }
+        EpochConfigChanged {
+            caller,
+            token: config.as_ref().map(|config| config.token.clone()),
+            length_ledgers: config.as_ref().map_or(0, |config| config.length_ledgers),
+            share_bps: config.as_ref().map_or(0, |config| config.share_bps),
+            top_n: config.as_ref().map_or(0, |config| config.top_n),
+            first_epoch,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the running epoch, if epochs are configured
+    pub fn get_current_epoch(env: Env) -> Option<u32> {
+        Self::current_epoch(&env).map(|(_, epoch)| epoch)
+    }
+
+    /// Get an epoch's pool and leaderboard, if anything has accrued to it
+    pub fn get_epoch(env: Env, epoch: u32) -> Option<EpochRewards> {
+        epochs::rewards(&env, epoch)
+    }
+
+    /// Get a player's wins in an epoch
+    pub fn get_epoch_wins(env: Env, epoch: u32, player: Address) -> u32 {
+        epochs::wins(&env, epoch, &player)
+    }
+
+    /// Claim a leader's share of an epoch's pool
+    ///
+    /// Once the epoch is over, each of its leaders (`get_epoch`) may claim
+    /// `pool * wins / total wins of the leaders` once; the last claim takes
+    /// the rounding dust. Epochs paid by a reward tree (`set_epoch_root`)
+    /// are claimed with `claim_epoch_reward_with_proof` instead.
+    ///
+    /// # Arguments
+    /// * `player` - The leader, who signs
+    /// * `epoch` - An epoch that is over (else `EpochNotOver`)
+    ///
+    /// # Returns
+    /// * The amount sent, or `NothingToClaim` for a player with no share
+    ///   left to claim
+    pub fn claim_epoch_reward(env: Env, player: Address, epoch: u32) -> Result<i128, Error> {
+        player.require_auth();
+
+        Self::require_epoch_over(&env, epoch)?;
+        let mut rewards = epochs::rewards(&env, epoch).ok_or(Error::NothingToClaim)?;
+        if rewards.root.is_some() {
+            return Err(Error::InvalidEpochProof);
+        }
+        let amount = rewards.share_of(&player);
+        if amount == 0 || epochs::claimed(&env, epoch, &player) {
+            return Err(Error::NothingToClaim);
+        }
+        rewards.claims += 1;
+        Self::pay_epoch_reward(&env, epoch, &player, &mut rewards, amount);
+        Ok(amount)
+    }
+
+    /// Claim an epoch reward from its reward tree
+    ///
+    /// # Arguments
+    /// * `player` - Who the reward is for, and signs
+    /// * `epoch` - An epoch with a reward tree (`set_epoch_root`)
+    /// * `index` - The leaf's position in the tree
+    /// * `amount` - The reward the leaf commits to (`epoch_leaf`)
+    /// * `proof` - The leaf's inclusion proof, as `strategy_proof` builds
+    ///   it (a proof that does not check out, or a reward beyond what is
+    ///   left of the pool, is `InvalidEpochProof`)
+    ///
+    /// # Returns
+    /// * The amount sent, or `NothingToClaim` if already claimed
+    pub fn claim_epoch_reward_with_proof(
+        env: Env,
+        player: Address,
+        epoch: u32,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+
+        let mut rewards = epochs::rewards(&env, epoch).ok_or(Error::InvalidEpochProof)?;
+        let Some(root) = &rewards.root else {
+            return Err(Error::InvalidEpochProof);
+        };
+        if epochs::claimed(&env, epoch, &player) {
+            return Err(Error::NothingToClaim);
+        }
+        let leaf = epoch_leaf(&env, epoch, &player, amount);
+        if amount <= 0
+            || amount > rewards.pool - rewards.claimed
+            || !merkle::verify(&env, root, rewards.leaves, index, &leaf, &proof)
+        {
+            return Err(Error::InvalidEpochProof);
+        }
+        Self::pay_epoch_reward(&env, epoch, &player, &mut rewards, amount);
+        Ok(amount)
+    }
+
+    /// Publish a reward tree for an epoch, to pay it by proof rather than
+    /// by its on-chain leaderboard
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `epoch` - An epoch that is over (else `EpochNotOver`), with a pool
+    ///   and no claims paid yet (else `InvalidEpochRoot`)
+    /// * `root` - Root of the tree of `epoch_leaf` leaves
+    /// * `leaves` - How many leaves the tree has, at least 1
+    pub fn set_epoch_root(
+        env: Env,
+        caller: Address,
+        epoch: u32,
+        root: BytesN<32>,
+        leaves: u32,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        Self::require_epoch_over(&env, epoch)?;
+        let mut rewards = epochs::rewards(&env, epoch)
+            .filter(|rewards| rewards.claimed == 0 && leaves > 0)
+            .ok_or(Error::InvalidEpochRoot)?;
+        rewards.root = Some(root.clone());
+        rewards.leaves = leaves;
+        epochs::save_rewards(&env, epoch, &rewards);
+        EpochRootSet {
+            epoch,
+            caller,
+            root,
+            leaves,
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        let game_hub_addr: Address = env
+    /// Reject an epoch still running, or not yet begun
+    fn require_epoch_over(env: &Env, epoch: u32) -> Result<(), Error> {
+        let schedule: EpochSchedule = env
             .storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+            .get(&DataKey::EpochSchedule)
+            .ok_or(Error::EpochNotOver)?;
+        let next = Self::current_epoch(env).map_or(schedule.first_epoch, |(_, epoch)| epoch);
+        if epoch >= next {
+            return Err(Error::EpochNotOver);
+        }
+        Ok(())
+    }
 
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+    /// Send `player` `amount` of an epoch's pool and record the claim
+    fn pay_epoch_reward(
+        env: &Env,
+        epoch: u32,
+        player: &Address,
+        rewards: &mut EpochRewards,
+        amount: i128,
+    ) {
+        rewards.claimed += amount;
+        epochs::save_rewards(env, epoch, rewards);
+        epochs::set_claimed(env, epoch, player);
+        escrow::send(env, &rewards.token, player, amount);
+        EpochRewardClaimed {
+            epoch,
+            player: player.clone(),
+            token: rewards.token.clone(),
+            amount,
+        }
+        .publish(env);
+    }
 
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
-            player1_points,
-            player2_points,
-            player1_proof_hash: None,
-            player2_proof_hash: None,
-            player1_tactic: None,
-            player2_tactic: None,
-            player1_score: None,
-            player2_score: None,
-            winner: None,
-        };
+    /// Get the jackpot in a token, paid out on a run set by
+    /// `set_jackpot_config`
+    pub fn get_jackpot(env: Env, token: Address) -> i128 {
+        jackpot::get(&env, &token)
+    }
 
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    /// Get a player's current run toward the jackpot
+    pub fn get_jackpot_run(env: Env, player: Address) -> u32 {
+        jackpot::run(&env, &player)
+    }
 
-        Ok(())
+    /// Get the jackpot rules (None = no jackpot)
+    pub fn get_jackpot_config(env: Env) -> Option<JackpotConfig> {
+        env.storage().instance().get(&DataKey::JackpotConfig)
     }
 
-    /// Verify a ZK proof for tactical choice submission
+    /// Set how the jackpot fills and what wins it
     ///
-    /// **Verification steps:**
-    /// 1. Basic proof validation (length, format)
-    /// 2. Tactic range check (must be 0-3)
-    /// 3. Protocol 25 verification (when available)
+    /// `share_bps` of every protocol fee, after any referral and hub share,
+    /// goes to the jackpot in the fee's token (`get_jackpot`). A player who wins
+    /// `wins_in_a_row` games wagered in a token in a row on a `Random`
+    /// tiebreak, with both sides playing `tactic` and staking at least
+    /// `min_stake`, is owed `payout_bps` of the jackpot in the token of the
+    /// pot that completes the run, alongside their winnings. Any other
+    /// result ends a run (`get_jackpot_run`).
+    /// Removing the rules leaves the jackpot in place for when they return.
     ///
     /// # Arguments
-    /// * `env` - Environment
-    /// * `proof` - ZK proof bytes from Noir circuit
-    /// * `tactic` - The claimed tactic (public input)
-    /// * `session_id` - The session ID (public input)
-    ///
-    /// # Returns
-    /// * `Ok(())` if proof is valid
-    /// * `Err(Error::InvalidProof)` if proof is invalid
-    fn verify_zk_proof(
-        _env: &Env,
-        proof: &Bytes,
-        tactic: u32,
-        _session_id: u32,
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `config` - The rules, with `share_bps` at most 10,000, a valid
+    ///   `tactic`, `wins_in_a_row` at least 1, `payout_bps` from 1 to
+    ///   10,000 and `min_stake` at least 1 (else `InvalidJackpotConfig`), or
+    ///   `None` for no jackpot
+    pub fn set_jackpot_config(
+        env: Env,
+        caller: Address,
+        config: Option<JackpotConfig>,
     ) -> Result<(), Error> {
-        // Basic validation: proof should not be empty
-        if proof.len() == 0 {
-            return Err(Error::InvalidProof);
-        }
+        Self::require_role(&env, &caller, Role::FeeManager)?;
 
-        // Tactic range check (redundant with submit_tactic, but good for defense in depth)
-        if tactic > 3 {
-            return Err(Error::InvalidTactic);
+        if config.as_ref().is_some_and(|config| {
+            config.share_bps > 10_000
+                || config.tactic > 3
+                || config.wins_in_a_row == 0
+                || !(1..=10_000).contains(&config.payout_bps)
+                || config.min_stake < 1
+        }) {
+            return Err(Error::InvalidJackpotConfig);
         }
+        match &config {
+            Some(config) => env.storage().instance().set(&DataKey::JackpotConfig, config),
+            None => env.storage().instance().remove(&DataKey::JackpotConfig),
+        }
+        JackpotConfigChanged {
+            caller,
+            enabled: config.is_some(),
+            share_bps: config.as_ref().map_or(0, |config| config.share_bps),
+            tactic: config.as_ref().map_or(0, |config| config.tactic),
+            wins_in_a_row: config.as_ref().map_or(0, |config| config.wins_in_a_row),
+            payout_bps: config.as_ref().map_or(0, |config| config.payout_bps),
+            min_stake: config.as_ref().map_or(0, |config| config.min_stake),
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        // TODO: Full ZK verification using Stellar Protocol 25 (X-Ray) primitives
-        // Once Protocol 25 is available on testnet, use:
-        // - env.crypto().verify_noir_proof(proof, public_inputs)
-        // - Or env.crypto().verify_bn254_proof(proof, vk, public_inputs)
-        //
-        // For now, we validate proof format and rely on client-side generation
-        // This is acceptable for hackathon/demo, but NOT for production
+    /// Get the protocol fee, in basis points of a won pot (0 by default)
+    pub fn get_fee_bps(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+    }
 
-        // Validate proof minimum length (Barretenberg proofs are typically > 100 bytes)
-        if proof.len() < 32 {
-            return Err(Error::InvalidProof);
-        }
+    /// Set the protocol fee taken from the pot of games started from now on
+    ///
+    /// When a game wagered in a token is won, `fee_bps` of the pot (both
+    /// players' stakes) is kept back from the winner and accrues to the
+    /// contract (see `get_accrued_fees`). Draws, cancellations, timeouts and
+    /// expired games are refunded without a fee. Each game keeps the fee it
+    /// started with.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `fee_bps` - At most `MAX_FEE_BPS` (else `InvalidFee`)
+    pub fn set_fee_bps(env: Env, caller: Address, fee_bps: u32) -> Result<(), Error> {
+        let action = CouncilAction::SetFeeBps(fee_bps);
+        Self::require_role_or_council(&env, &caller, Role::FeeManager, action)?;
 
-        // Additional validation: check that proof is not all zeros
-        let mut all_zeros = true;
-        for i in 0..proof.len() {
-            if proof.get(i).unwrap_or(0) != 0 {
-                all_zeros = false;
-                break;
-            }
+        if fee_bps > MAX_FEE_BPS {
+            return Err(Error::InvalidFee);
+        }
+        let previous_bps = Self::get_fee_bps(env.clone());
+        env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+        FeeChanged {
+            caller,
+            previous_bps,
+            fee_bps,
         }
+        .publish(&env);
+        Ok(())
+    }
 
-        if all_zeros {
-            return Err(Error::InvalidProof);
+    /// Get the address `withdraw_fees` pays (the admin unless set)
+    pub fn get_fee_collector(env: Env) -> Result<Address, Error> {
+        match env.storage().instance().get(&DataKey::FeeCollector) {
+            Some(collector) => Ok(collector),
+            None => Self::get_admin(env),
         }
+    }
+
+    /// Set the address `withdraw_fees` pays
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `collector` - The new fee collector
+    pub fn set_fee_collector(env: Env, caller: Address, collector: Address) -> Result<(), Error> {
+        let action = CouncilAction::SetFeeCollector(collector.clone());
+        Self::require_role_or_council(&env, &caller, Role::FeeManager, action)?;
 
-        // Proof passed basic validation
-        // In production, this would include full cryptographic verification
+        let previous = Self::get_fee_collector(env.clone())?;
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeCollector, &collector);
+        FeeCollectorChanged {
+            caller,
+            previous,
+            collector,
+        }
+        .publish(&env);
         Ok(())
     }
 
-    /// Submit tactical choice with ZK proof.
+    /// Get the fees accrued in `token` and not yet withdrawn
+    pub fn get_accrued_fees(env: Env, token: Address) -> i128 {
+        escrow::accrued_fees(&env, &token)
+    }
+
+    /// Send every fee accrued in `token` to the fee collector
     ///
-    /// **ZK Proof validates:**
-    /// - tactic ∈ [0-3]
-    /// - player identity
-    /// - hasn't already submitted
+    /// Returns `NothingToClaim` if none has accrued.
     ///
     /// # Arguments
-    /// * `session_id` - Game session ID
-    /// * `player` - Player address
-    /// * `tactic` - Tactical choice (0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut)
-    /// * `proof` - ZK proof bytes (Noir-generated proof for on-chain verification)
-    pub fn submit_tactic(
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `token` - The token to withdraw
+    ///
+    /// # Returns
+    /// * The amount withdrawn
+    pub fn withdraw_fees(env: Env, caller: Address, token: Address) -> Result<i128, Error> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        let amount = escrow::accrued_fees(&env, &token);
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        let collector = Self::get_fee_collector(env.clone())?;
+        escrow::add_fees(&env, &token, -amount);
+        escrow::send(&env, &token, &collector, amount);
+        FeesWithdrawn {
+            token,
+            caller,
+            collector,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Get the price oracle `start_mixed_game` values stakes with
+    pub fn get_oracle_config(env: Env) -> Option<OracleConfig> {
+        env.storage().instance().get(&DataKey::OracleConfig)
+    }
+
+    /// Set the price oracle for mixed-asset games
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+    /// * `config` - A SEP-40 oracle (such as Reflector) pricing every token
+    ///   players may mix, with a tolerance of at most 10,000 bps (else
+    ///   `InvalidOracleConfig`), or `None` to stop mixed-asset games
+    pub fn set_oracle_config(
         env: Env,
-        session_id: u32,
-        player: Address,
-        tactic: u32,
-        proof: Bytes,
+        caller: Address,
+        config: Option<OracleConfig>,
     ) -> Result<(), Error> {
-        player.require_auth();
+        let action = match &config {
+            Some(config) => CouncilAction::SetOracleConfig(config.clone()),
+            None => CouncilAction::RemoveOracleConfig,
+        };
+        Self::require_role_or_council(&env, &caller, Role::FeeManager, action)?;
 
-        if tactic > 3 {
-            return Err(Error::InvalidTactic);
+        if config
+            .as_ref()
+            .is_some_and(|config| config.tolerance_bps > 10_000)
+        {
+            return Err(Error::InvalidOracleConfig);
         }
+        let previous = Self::get_oracle_config(env.clone()).map(|previous| previous.oracle);
+        match &config {
+            Some(config) => env.storage().instance().set(&DataKey::OracleConfig, config),
+            None => env.storage().instance().remove(&DataKey::OracleConfig),
+        }
+        OracleConfigChanged {
+            caller,
+            previous,
+            oracle: config.as_ref().map(|config| config.oracle.clone()),
+            tolerance_bps: config.as_ref().map_or(0, |config| config.tolerance_bps),
+            max_age_seconds: config.as_ref().map_or(0, |config| config.max_age_seconds),
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        // Verify ZK proof
-        Self::verify_zk_proof(&env, &proof, tactic, session_id)?;
-
-        // Store proof hash for commitment tracking
-        let proof_hash = env.crypto().keccak256(&proof).into();
+    /// Get the most games a player may have in play at once (None = no limit)
+    pub fn get_max_open_games(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::MaxOpenGames)
+    }
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+    /// Set the most games a player may have in play at once
+    ///
+    /// A `start_*` call that would put either player over the limit returns
+    /// `TooManyOpenGames`, which bounds the storage and hub sessions one
+    /// address can hold open. Games count from their start until they
+    /// settle or expire; games already in play above a lowered limit are
+    /// left alone. `None` lifts the limit.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+    /// * `limit` - Maximum open games per player, from 1 to
+    ///   `MAX_OPEN_GAMES_LIMIT` (else `InvalidGameLimit`), or `None` for no
+    ///   limit
+    pub fn set_max_open_games(
+        env: Env,
+        caller: Address,
+        limit: Option<u32>,
+    ) -> Result<(), Error> {
+        Self::require_role(&env, &caller, Role::Operator)?;
 
-        if game.winner.is_some() {
-            return Err(Error::GameAlreadyEnded);
+        if limit.is_some_and(|limit| limit == 0 || limit > MAX_OPEN_GAMES_LIMIT) {
+            return Err(Error::InvalidGameLimit);
         }
 
-        if player == game.player1 {
-            if game.player1_proof_hash.is_some() {
-                return Err(Error::AlreadySubmitted);
-            }
-            game.player1_proof_hash = Some(proof_hash);
-            game.player1_tactic = Some(tactic);
-        } else if player == game.player2 {
-            if game.player2_proof_hash.is_some() {
-                return Err(Error::AlreadySubmitted);
-            }
-            game.player2_proof_hash = Some(proof_hash);
-            game.player2_tactic = Some(tactic);
-        } else {
-            return Err(Error::NotPlayer);
+        match limit {
+            Some(limit) => env
+                .storage()
+                .instance()
+                .set(&DataKey::MaxOpenGames, &limit),
+            None => env.storage().instance().remove(&DataKey::MaxOpenGames),
         }
-
-        env.storage().temporary().set(&key, &game);
         Ok(())
     }
 
-    /// Resolve match after both players submit tactics.
-    /// Computes scores using strategic matrix and determines winner.
+    /// Whether new games and submissions are paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    /// Stop new games and submissions during an incident, such as a broken
+    /// circuit or verifier
     ///
-    /// # Arguments
-    /// * `session_id` - Game session ID
+    /// Every `start_*` entrypoint and every submission (`submit_tactic` and
+    /// its variants, `commit_tactic`, `submit_rounds`, `submit_team_move`)
+    /// returns `Paused` until `unpause`. Games in play can still be
+    /// revealed and settled (`resolve_match`, `resolve_timeout`) so stakes
+    /// are never locked. Deadlines keep running while paused.
     ///
-    /// # Returns
-    /// * `Address` - Winner address
-    pub fn resolve_match(env: Env, session_id: u32) -> Result<Address, Error> {
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
-
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+    /// A holder of the `Pauser` (or `Admin`) role can pause on their own
+    /// signature, even under an admin council, since pausing cannot move
+    /// funds. Anyone else needs the admin, or the council's approval once
+    /// one is set.
+    ///
+    /// # Arguments
+    /// * `caller` - The account pausing
+    pub fn pause(env: Env, caller: Address) -> Result<(), Error> {
+        if Self::has_role(env.clone(), caller.clone(), Role::Pauser) {
+            caller.require_auth();
+        } else {
+            Self::authorize(&env, CouncilAction::Pause)?;
         }
 
-        let tactic1 = game.player1_tactic.ok_or(Error::BothPlayersNotSubmitted)?;
-        let tactic2 = game.player2_tactic.ok_or(Error::BothPlayersNotSubmitted)?;
+        Self::set_paused(&env, true);
+        Ok(())
+    }
+
+    /// Accept new games and submissions again after `pause`. Needs the
+    /// admin council's approval once one is set.
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::Unpause)?;
 
-        // Compute scores using strategic matrix
-        let (score1, score2) = get_score(tactic1, tactic2);
-        game.player1_score = Some(score1);
-        game.player2_score = Some(score2);
+        Self::set_paused(&env, false);
+        Ok(())
+    }
 
-        // Determine winner (higher score wins, ties go to player1)
-        let winner = if score1 >= score2 {
-            game.player1.clone()
+    /// Store the pause flag and announce it
+    fn set_paused(env: &Env, paused: bool) {
+        let was_paused = Self::is_paused(env.clone());
+        if paused {
+            env.storage().instance().set(&DataKey::Paused, &true);
         } else {
-            game.player2.clone()
-        };
+            env.storage().instance().remove(&DataKey::Paused);
+        }
+        PauseChanged { was_paused, paused }.publish(env);
+    }
+
+    /// Get the contract's version, `(major, minor, patch)`. The commit it
+    /// was built from and its circuit version are in the WASM's
+    /// `contractmeta` entries
+    pub fn get_version(_env: Env) -> (u32, u32, u32) {
+        CONTRACT_VERSION
+    }
 
-        game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
+    /// Get the admin, hub, verifier, pause state, limits and TTL policy in
+    /// one call, for operations dashboards
+    pub fn get_config(env: Env) -> Result<ContractConfig, Error> {
+        Ok(ContractConfig {
+            admin: Self::get_admin(env.clone())?,
+            hub: Self::get_hub(env.clone())?,
+            verifier: Self::get_verifier(env.clone()),
+            proof_system: Self::get_proof_system(env.clone()),
+            paused: Self::is_paused(env.clone()),
+            wager_limits: Self::get_wager_limits(env.clone()),
+            wager_token: Self::get_wager_token(env.clone()),
+            fee_bps: Self::get_fee_bps(env.clone()),
+            fee_collector: Self::get_fee_collector(env.clone())?,
+            side_bet_rake_bps: Self::get_side_bet_rake_bps(env.clone()),
+            referral_share_bps: Self::get_referral_share_bps(env.clone()),
+            hub_share_bps: Self::get_hub_share_bps(env.clone()),
+            oracle: Self::get_oracle_config(env.clone()).map(|config| config.oracle),
+            max_open_games: Self::get_max_open_games(env.clone()),
+            timeout_ledgers: Self::get_timeout_ledgers(env.clone()),
+            game_ttl_ledgers: GAME_TTL_LEDGERS,
+            history_ttl_ledgers: HISTORY_TTL_LEDGERS,
+            upgrade_delay_ledgers: Self::get_upgrade_delay(env),
+        })
+    }
 
-        let game_hub_addr: Address = env
-            .storage()
+    /// Get the storage schema version, 0 for deployments from before
+    /// versioning that have not run `migrate`
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(0)
+    }
 
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-        let player1_won = winner == game.player1;
-        game_hub.end_game(&session_id, &player1_won);
+    /// Bring storage up to `SCHEMA_VERSION` after an upgrade
+    ///
+    /// Runs the steps between the stored schema and this WASM's, then
+    /// records the new version; a no-op once up to date. Returns
+    /// `SchemaTooNew` if storage was written by a newer WASM. Games in play
+    /// are temporary entries the contract cannot enumerate, so each one
+    /// stored in an old layout is rewritten by `migrate_games` instead.
+    pub fn migrate(env: Env) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::Migrate)?;
 
-        Ok(winner)
+        let version = Self::get_schema_version(env.clone());
+        if version > SCHEMA_VERSION {
+            return Err(Error::SchemaTooNew);
+        }
+        // 0 -> 1, 1 -> 2 and 2 -> 3 changed only the `Game` layout, see
+        // `migrate_games`.
+        // Deployments from before the flag were initialized by their
+        // constructor
+        env.storage().instance().set(&DataKey::Initialized, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        Ok(())
     }
 
-    /// Get game state including scores and tactics (after resolution).
+    /// Rewrite games stored in an older layout (see `migration.rs`) so they
+    /// can be played on after an upgrade
+    ///
+    /// Until then every entrypoint reading such a game traps. Anyone may
+    /// call it, e.g. a keeper walking `get_open_games` or the hub's open
+    /// sessions; games already current, or not found, are skipped. Keep
+    /// batches small enough for one transaction's footprint.
     ///
     /// # Arguments
-    /// * `session_id` - Game session ID
+    /// * `session_ids` - Sessions to migrate
     ///
     /// # Returns
-    /// * `Game` - Complete game state
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
+    /// * `u32` - How many games were rewritten
+    pub fn migrate_games(env: Env, session_ids: Vec<u32>) -> u32 {
+        let mut migrated = 0;
+        for session_id in session_ids.iter() {
+            if migration::migrate_game(&env, session_id) {
+                migrated += 1;
+            }
+        }
+        migrated
+    }
+
+    /// Get the ledgers an upgrade waits between proposal and execution
+    /// (`DEFAULT_UPGRADE_DELAY_LEDGERS` until set)
+    pub fn get_upgrade_delay(env: Env) -> u32 {
         env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+            .instance()
+            .get(&DataKey::UpgradeDelay)
+            .unwrap_or(DEFAULT_UPGRADE_DELAY_LEDGERS)
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+    /// Set the ledgers an upgrade waits between proposal and execution
+    ///
+    /// Upgrades already proposed keep the ledger they became executable
+    /// at.
+    ///
+    /// # Arguments
+    /// * `ledgers` - Delay in ledgers, at least `MIN_UPGRADE_DELAY_LEDGERS`
+    ///   (else `InvalidUpgradeDelay`)
+    pub fn set_upgrade_delay(env: Env, ledgers: u32) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::SetUpgradeDelay(ledgers))?;
 
-    /// Get the current admin address
+        if ledgers < MIN_UPGRADE_DELAY_LEDGERS {
+            return Err(Error::InvalidUpgradeDelay);
+        }
+        env.storage().instance().set(&DataKey::UpgradeDelay, &ledgers);
+        Ok(())
+    }
+
+    /// Get the upgrade waiting out its delay, if any
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        env.storage().instance().get(&DataKey::PendingUpgrade)
+    }
+
+    /// Propose upgrading the contract to `wasm_hash`
     ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
+    /// The upgrade can only be executed with `execute_upgrade` once
+    /// `get_upgrade_delay` ledgers have passed, giving players a window to
+    /// settle their games before the logic under their stakes changes. An
+    /// `UpgradeProposed` event announces it. Proposing again replaces the
+    /// pending upgrade and restarts the delay. Needs the admin council's
+    /// approval once one is set; executing or cancelling an approved
+    /// upgrade is left to the admin.
+    ///
+    /// # Arguments
+    /// * `wasm_hash` - The hash of the new WASM binary, already uploaded
+    pub fn propose_upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::authorize(&env, CouncilAction::ProposeUpgrade(wasm_hash.clone()))?;
+
+        let executable_ledger = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::get_upgrade_delay(env.clone()));
+        let pending = PendingUpgrade {
+            wasm_hash: wasm_hash.clone(),
+            executable_ledger,
+        };
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+            .set(&DataKey::PendingUpgrade, &pending);
+        UpgradeProposed {
+            wasm_hash,
+            executable_ledger,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    /// Set a new admin address
+    /// Upgrade the contract to the pending WASM hash once its delay has
+    /// passed
     ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
+    /// Returns `NoPendingUpgrade` without a proposal and `UpgradeNotReady`
+    /// before its `executable_ledger`.
+    pub fn execute_upgrade(env: Env) -> Result<(), Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        let pending = Self::get_pending_upgrade(env.clone()).ok_or(Error::NoPendingUpgrade)?;
+        if env.ledger().sequence() < pending.executable_ledger {
+            return Err(Error::UpgradeNotReady);
+        }
+        env.storage().instance().remove(&DataKey::PendingUpgrade);
+        env.deployer()
+            .update_current_contract_wasm(pending.wasm_hash.clone());
+        UpgradeExecuted {
+            wasm_hash: pending.wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    /// Get the current GameHub contract address
+    /// Withdraw the pending upgrade
+    pub fn cancel_upgrade(env: Env) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let pending = Self::get_pending_upgrade(env.clone()).ok_or(Error::NoPendingUpgrade)?;
+        env.storage().instance().remove(&DataKey::PendingUpgrade);
+        UpgradeCancelled {
+            wasm_hash: pending.wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Whether the constructor has run (or `migrate` has, for deployments
+    /// from before the flag)
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Initialized)
+    }
+
+    /// Get the reinitialization waiting out its delay, if any
+    pub fn get_pending_reinit(env: Env) -> Option<PendingReinit> {
+        env.storage().instance().get(&DataKey::PendingReinit)
+    }
+
+    /// Propose replacing the admin and hub set at initialization, for when
+    /// a deployment went out with the wrong ones
     ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
+    /// Follows the upgrade timelock: `reinitialize` applies it once
+    /// `get_upgrade_delay` ledgers have passed, and a `ReinitProposed`
+    /// event announces it. Proposing again replaces the pending one and
+    /// restarts the delay. Needs the admin council's approval once one is
+    /// set.
+    ///
+    /// # Arguments
+    /// * `admin` - The new admin, who also signs `reinitialize`
+    /// * `game_hub` - The new GameHub contract address, checked as by
+    ///   `set_hub`
+    pub fn propose_reinitialize(env: Env, admin: Address, game_hub: Address) -> Result<(), Error> {
+        Self::authorize(
+            &env,
+            CouncilAction::Reinitialize(admin.clone(), game_hub.clone()),
+        )?;
+        Self::check_hub(&env, &game_hub)?;
+
+        let executable_ledger = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::get_upgrade_delay(env.clone()));
+        let pending = PendingReinit {
+            admin: admin.clone(),
+            game_hub: game_hub.clone(),
+            executable_ledger,
+        };
         env.storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+            .set(&DataKey::PendingReinit, &pending);
+        ReinitProposed {
+            admin,
+            game_hub,
+            executable_ledger,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    /// Set a new GameHub contract address
+    /// Apply the pending reinitialization once its delay has passed
     ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
+    /// Signed by the current admin and, if it changes, the new one. Any
+    /// pending admin transfer is dropped. Returns `NoPendingReinit` without
+    /// a proposal and `ReinitNotReady` before its `executable_ledger`.
+    pub fn reinitialize(env: Env) -> Result<(), Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
+        let pending = Self::get_pending_reinit(env.clone()).ok_or(Error::NoPendingReinit)?;
+        if env.ledger().sequence() < pending.executable_ledger {
+            return Err(Error::ReinitNotReady);
+        }
+        if pending.admin != admin {
+            pending.admin.require_auth();
+        }
+
+        let previous_hub = env.storage().instance().get(&DataKey::GameHubAddress);
+        env.storage().instance().remove(&DataKey::PendingReinit);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage().instance().set(&DataKey::Admin, &pending.admin);
         env.storage()
             .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .set(&DataKey::GameHubAddress, &pending.game_hub);
+        env.storage().instance().set(&DataKey::Initialized, &true);
+        Reinitialized {
+            previous_admin: admin,
+            previous_hub,
+            admin: pending.admin,
+            game_hub: pending.game_hub,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+    /// Withdraw the pending reinitialization
+    pub fn cancel_reinitialize(env: Env) -> Result<(), Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        if !env.storage().instance().has(&DataKey::PendingReinit) {
+            return Err(Error::NoPendingReinit);
+        }
+        env.storage().instance().remove(&DataKey::PendingReinit);
+        Ok(())
     }
 }
 
@@ -489,3 +9127,9 @@ impl ZkTacticalMatchContract {
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod test_auth;
+#[cfg(test)]
+mod test_snapshot;
+#[cfg(test)]
+mod test_stress;
@@ -0,0 +1,90 @@
+//! Merkle-committed round strategies.
+//!
+//! A player commits to every round of a multi-round match at once with the
+//! root of a sha256 Merkle tree over their round leaves, then opens one round
+//! at a time with an inclusion proof (`reveal_round`).
+//!
+//! ```text
+//! leaf i  = tactic_hash(tactic_i, salt_i, session_id)
+//! width   = rounds rounded up to a power of two; missing leaves are 32 zero bytes
+//! node    = sha256(left | right)
+//! proof   = the sibling at every level, from the leaf up
+//! ```
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut pair = Bytes::from(left.clone());
+    pair.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&pair).into()
+}
+
+/// The leaves, padded with zero leaves to a power of two
+fn padded(env: &Env, leaves: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut level = leaves.clone();
+    while level.len() < leaves.len().next_power_of_two() {
+        level.push_back(BytesN::from_array(env, &[0; 32]));
+    }
+    level
+}
+
+fn parent_level(env: &Env, level: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut parents = Vec::new(env);
+    for i in (0..level.len()).step_by(2) {
+        parents.push_back(hash_pair(
+            env,
+            &level.get_unchecked(i),
+            &level.get_unchecked(i + 1),
+        ));
+    }
+    parents
+}
+
+/// Root of the strategy tree over `leaves`, one per round in order
+pub fn strategy_root(env: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut level = padded(env, leaves);
+    while level.len() > 1 {
+        level = parent_level(env, &level);
+    }
+    level.get_unchecked(0)
+}
+
+/// Inclusion proof for the leaf at `index`, as `reveal_round` takes it
+pub fn strategy_proof(env: &Env, leaves: &Vec<BytesN<32>>, index: u32) -> Vec<BytesN<32>> {
+    let mut level = padded(env, leaves);
+    let mut index = index;
+    let mut proof = Vec::new(env);
+    while level.len() > 1 {
+        proof.push_back(level.get_unchecked(index ^ 1));
+        level = parent_level(env, &level);
+        index /= 2;
+    }
+    proof
+}
+
+/// Whether `proof` places `leaf` at `index` of a `rounds`-leaf tree with
+/// `root`. The proof length must match the tree's depth
+pub fn verify(
+    env: &Env,
+    root: &BytesN<32>,
+    rounds: u32,
+    index: u32,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    if index >= rounds || proof.len() != rounds.next_power_of_two().trailing_zeros() {
+        return false;
+    }
+
+    let mut node = leaf.clone();
+    let mut index = index;
+    for sibling in proof.iter() {
+        node = if index.is_multiple_of(2) {
+            hash_pair(env, &node, &sibling)
+        } else {
+            hash_pair(env, &sibling, &node)
+        };
+        index /= 2;
+    }
+    node == *root
+}
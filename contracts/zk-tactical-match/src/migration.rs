@@ -0,0 +1,260 @@
+//! Storage schema versions and the steps between them.
+//!
+//! Contract types are stored as maps keyed by field name, so a `Game`
+//! written by an older WASM no longer decodes once fields are added and
+//! every entrypoint touching it traps. Each schema version keeps the layout
+//! it replaced here, with the conversion to the next one.
+//!
+//! ```text
+//! 0  no SchemaVersion stored; Game without `phase` and `hub_notified`
+//! 1  Game tracks its `GamePhase` and whether the hub has its result
+//! 2  Game records the ledger it last changed in, `last_activity_ledger`
+//! 3  Game records the token it is wagered in, `wager_token`
+//! ```
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, Symbol, Val};
+
+use crate::{escrow, DataKey, Game, GamePhase, GameResult, ProofSystem, TiebreakPolicy};
+
+/// Schema this WASM reads and writes
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// `Game` as stored at schema 0
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameV0 {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub proof_system: ProofSystem,
+    pub batch_verification: bool,
+    pub simulation_image_id: Option<BytesN<32>>,
+    pub tiebreak: TiebreakPolicy,
+    pub auto_resolve: bool,
+    pub submit_deadline_ledger: u32,
+    pub resolve_deadline_ledger: u32,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    pub player1_proof_hash: Option<BytesN<32>>,
+    pub player2_proof_hash: Option<BytesN<32>>,
+    pub player1_tactic_hash: Option<BytesN<32>>,
+    pub player2_tactic_hash: Option<BytesN<32>>,
+    pub player1_tactic: Option<u32>,
+    pub player2_tactic: Option<u32>,
+    pub player1_score: Option<u32>,
+    pub player2_score: Option<u32>,
+    pub result: GameResult,
+}
+
+/// `Game` as stored at schema 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameV1 {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub proof_system: ProofSystem,
+    pub batch_verification: bool,
+    pub simulation_image_id: Option<BytesN<32>>,
+    pub tiebreak: TiebreakPolicy,
+    pub auto_resolve: bool,
+    pub submit_deadline_ledger: u32,
+    pub resolve_deadline_ledger: u32,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    pub player1_proof_hash: Option<BytesN<32>>,
+    pub player2_proof_hash: Option<BytesN<32>>,
+    pub player1_tactic_hash: Option<BytesN<32>>,
+    pub player2_tactic_hash: Option<BytesN<32>>,
+    pub player1_tactic: Option<u32>,
+    pub player2_tactic: Option<u32>,
+    pub player1_score: Option<u32>,
+    pub player2_score: Option<u32>,
+    pub hub_notified: bool,
+    pub phase: GamePhase,
+    pub result: GameResult,
+}
+
+/// `Game` as stored at schema 2
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameV2 {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub proof_system: ProofSystem,
+    pub batch_verification: bool,
+    pub simulation_image_id: Option<BytesN<32>>,
+    pub tiebreak: TiebreakPolicy,
+    pub auto_resolve: bool,
+    pub submit_deadline_ledger: u32,
+    pub resolve_deadline_ledger: u32,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    pub player1_proof_hash: Option<BytesN<32>>,
+    pub player2_proof_hash: Option<BytesN<32>>,
+    pub player1_tactic_hash: Option<BytesN<32>>,
+    pub player2_tactic_hash: Option<BytesN<32>>,
+    pub player1_tactic: Option<u32>,
+    pub player2_tactic: Option<u32>,
+    pub player1_score: Option<u32>,
+    pub player2_score: Option<u32>,
+    pub hub_notified: bool,
+    pub phase: GamePhase,
+    pub result: GameResult,
+    pub last_activity_ledger: u32,
+}
+
+impl From<GameV0> for GameV1 {
+    /// The phase follows from what was stored: settled games are `Resolved`
+    /// (a forfeit cannot be told apart), otherwise each tactic hash marks a
+    /// submission. Schema 0 settled and reported to the hub in one
+    /// transaction, so settled games were reported
+    fn from(old: GameV0) -> Self {
+        let submitted = [&old.player1_tactic_hash, &old.player2_tactic_hash]
+            .iter()
+            .filter(|hash| hash.is_some())
+            .count();
+        let settled = matches!(old.result, GameResult::Settled(_));
+        let phase = match (settled, submitted) {
+            (true, _) => GamePhase::Resolved,
+            (false, 0) => GamePhase::Created,
+            (false, 1) => GamePhase::AwaitingOpponent,
+            (false, _) => GamePhase::BothSubmitted,
+        };
+        GameV1 {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            proof_system: old.proof_system,
+            batch_verification: old.batch_verification,
+            simulation_image_id: old.simulation_image_id,
+            tiebreak: old.tiebreak,
+            auto_resolve: old.auto_resolve,
+            submit_deadline_ledger: old.submit_deadline_ledger,
+            resolve_deadline_ledger: old.resolve_deadline_ledger,
+            player1_commitment: old.player1_commitment,
+            player2_commitment: old.player2_commitment,
+            player1_proof_hash: old.player1_proof_hash,
+            player2_proof_hash: old.player2_proof_hash,
+            player1_tactic_hash: old.player1_tactic_hash,
+            player2_tactic_hash: old.player2_tactic_hash,
+            player1_tactic: old.player1_tactic,
+            player2_tactic: old.player2_tactic,
+            player1_score: old.player1_score,
+            player2_score: old.player2_score,
+            hub_notified: settled,
+            phase,
+            result: old.result,
+        }
+    }
+}
+
+impl From<GameV1> for GameV2 {
+    /// When a schema 1 game last changed was not stored; it reads as 0
+    /// until its next move, so keepers treat it as long idle and check its
+    /// deadlines
+    fn from(old: GameV1) -> Self {
+        GameV2 {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            proof_system: old.proof_system,
+            batch_verification: old.batch_verification,
+            simulation_image_id: old.simulation_image_id,
+            tiebreak: old.tiebreak,
+            auto_resolve: old.auto_resolve,
+            submit_deadline_ledger: old.submit_deadline_ledger,
+            resolve_deadline_ledger: old.resolve_deadline_ledger,
+            player1_commitment: old.player1_commitment,
+            player2_commitment: old.player2_commitment,
+            player1_proof_hash: old.player1_proof_hash,
+            player2_proof_hash: old.player2_proof_hash,
+            player1_tactic_hash: old.player1_tactic_hash,
+            player2_tactic_hash: old.player2_tactic_hash,
+            player1_tactic: old.player1_tactic,
+            player2_tactic: old.player2_tactic,
+            player1_score: old.player1_score,
+            player2_score: old.player2_score,
+            hub_notified: old.hub_notified,
+            phase: old.phase,
+            result: old.result,
+            last_activity_ledger: 0,
+        }
+    }
+}
+
+impl From<GameV2> for Game {
+    /// Schema 2 games were wagered in the contract-wide token, which only
+    /// their escrow recorded; `migrate_game` fills it in from there
+    fn from(old: GameV2) -> Self {
+        Game {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            proof_system: old.proof_system,
+            batch_verification: old.batch_verification,
+            simulation_image_id: old.simulation_image_id,
+            tiebreak: old.tiebreak,
+            auto_resolve: old.auto_resolve,
+            submit_deadline_ledger: old.submit_deadline_ledger,
+            resolve_deadline_ledger: old.resolve_deadline_ledger,
+            player1_commitment: old.player1_commitment,
+            player2_commitment: old.player2_commitment,
+            player1_proof_hash: old.player1_proof_hash,
+            player2_proof_hash: old.player2_proof_hash,
+            player1_tactic_hash: old.player1_tactic_hash,
+            player2_tactic_hash: old.player2_tactic_hash,
+            player1_tactic: old.player1_tactic,
+            player2_tactic: old.player2_tactic,
+            player1_score: old.player1_score,
+            player2_score: old.player2_score,
+            hub_notified: old.hub_notified,
+            phase: old.phase,
+            result: old.result,
+            last_activity_ledger: old.last_activity_ledger,
+            wager_token: None,
+        }
+    }
+}
+
+/// Rewrite `session_id`'s game in the current layout if it is stored in an
+/// older one, returning whether it was. The entry keeps its TTL.
+///
+/// Decoding a map into the wrong struct traps rather than failing, so the
+/// layout is told by its field names first.
+pub fn migrate_game(env: &Env, session_id: u32) -> bool {
+    let key = DataKey::Game(session_id);
+    let Some(fields) = env.storage().temporary().get::<_, Map<Symbol, Val>>(&key) else {
+        return false;
+    };
+    if fields.contains_key(Symbol::new(env, "wager_token")) {
+        return false;
+    }
+    let old = if fields.contains_key(Symbol::new(env, "last_activity_ledger")) {
+        env.storage().temporary().get::<_, GameV2>(&key)
+    } else if fields.contains_key(Symbol::new(env, "phase")) {
+        env.storage()
+            .temporary()
+            .get::<_, GameV1>(&key)
+            .map(GameV2::from)
+    } else {
+        env.storage()
+            .temporary()
+            .get::<_, GameV0>(&key)
+            .map(|old| GameV2::from(GameV1::from(old)))
+    };
+    let Some(old) = old else {
+        return false;
+    };
+    let mut game = Game::from(old);
+    game.wager_token = escrow::get(env, session_id).map(|escrow| escrow.player1_token);
+    env.storage().temporary().set(&key, &game);
+    true
+}
@@ -0,0 +1,89 @@
+//! Price checks for mixed-asset wagers.
+//!
+//! When the players stake different tokens (`start_mixed_game`), a SEP-40
+//! price oracle such as Reflector values both stakes in its base asset at
+//! start, and they must match within the configured tolerance:
+//!
+//! ```text
+//! lastprice(asset: Asset) -> Option<PriceData>
+//! value = amount * price
+//! |value1 - value2| * 10_000 <= tolerance_bps * max(value1, value2)
+//! ```
+//!
+//! Both values share the oracle's decimals, so they compare as they are.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+use crate::Error;
+
+/// Asset as SEP-40 oracles name it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// A SEP-40 price quote, `timestamp` in seconds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+// Only the generated client is used
+#[allow(dead_code)]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+/// Oracle consulted by `start_mixed_game` (`set_oracle_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    pub tolerance_bps: u32,   // how far apart the stakes' values may be
+    pub max_age_seconds: u64, // older quotes count as unavailable
+}
+
+/// Value `amount` of `token` in the oracle's base asset
+fn value(env: &Env, config: &OracleConfig, token: &Address, amount: i128) -> Result<i128, Error> {
+    let client = PriceOracleClient::new(env, &config.oracle);
+    let quote = match client.try_lastprice(&Asset::Stellar(token.clone())) {
+        Ok(Ok(Some(quote))) => quote,
+        _ => return Err(Error::PriceUnavailable),
+    };
+    let age = env.ledger().timestamp().saturating_sub(quote.timestamp);
+    if quote.price <= 0 || age > config.max_age_seconds {
+        return Err(Error::PriceUnavailable);
+    }
+    amount
+        .checked_mul(quote.price)
+        .ok_or(Error::StakesNotEquivalent)
+}
+
+/// Check two stakes in different tokens are worth the same, within
+/// `config.tolerance_bps`
+pub fn check_equivalent(
+    env: &Env,
+    config: &OracleConfig,
+    (token1, amount1): (&Address, i128),
+    (token2, amount2): (&Address, i128),
+) -> Result<(), Error> {
+    let value1 = value(env, config, token1, amount1)?;
+    let value2 = value(env, config, token2, amount2)?;
+    let gap = (value1 - value2)
+        .checked_abs()
+        .and_then(|gap| gap.checked_mul(10_000))
+        .ok_or(Error::StakesNotEquivalent)?;
+    let allowed = value1
+        .max(value2)
+        .checked_mul(config.tolerance_bps as i128)
+        .ok_or(Error::StakesNotEquivalent)?;
+    if gap > allowed {
+        return Err(Error::StakesNotEquivalent);
+    }
+    Ok(())
+}
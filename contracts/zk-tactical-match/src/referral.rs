@@ -0,0 +1,81 @@
+//! Referral rewards.
+//!
+//! A player may name, once, the address that referred them
+//! (`register_referrer`). From then on, whenever a won pot's protocol fee is
+//! taken from that player's stake, the referrer is credited
+//! `get_referral_share_bps` of it instead of the fee collector. Credits
+//! accrue per token until the referrer pulls them with
+//! `claim_referral_rewards`.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{DataKey, HISTORY_TTL_LEDGERS};
+
+/// What a referrer has brought in (`get_referral_stats`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub referees: u32,    // players who named them
+    pub raked_games: u32, // referee stakes they were credited a share of
+}
+
+/// A referrer's rewards in one token (`get_referral_rewards`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralRewards {
+    pub earned: i128,    // credited over all time
+    pub claimable: i128, // not yet claimed
+}
+
+/// Keep a referral entry alive as long as match history
+fn extend(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+}
+
+/// Who referred `player`, if anyone
+pub fn referrer(env: &Env, player: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(player.clone()))
+}
+
+/// Record that `referrer` referred `player`
+pub fn set_referrer(env: &Env, player: &Address, referrer: &Address) {
+    let key = DataKey::Referrer(player.clone());
+    env.storage().persistent().set(&key, referrer);
+    extend(env, &key);
+}
+
+/// `referrer`'s referral statistics
+pub fn stats(env: &Env, referrer: &Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralStats(referrer.clone()))
+        .unwrap_or_default()
+}
+
+/// Apply `update` to `referrer`'s statistics
+pub fn update_stats(env: &Env, referrer: &Address, update: impl FnOnce(&mut ReferralStats)) {
+    let key = DataKey::ReferralStats(referrer.clone());
+    let mut stats = stats(env, referrer);
+    update(&mut stats);
+    env.storage().persistent().set(&key, &stats);
+    extend(env, &key);
+}
+
+/// `referrer`'s rewards in `token`
+pub fn rewards(env: &Env, referrer: &Address, token: &Address) -> ReferralRewards {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralRewards(referrer.clone(), token.clone()))
+        .unwrap_or_default()
+}
+
+/// Store `referrer`'s rewards in `token`
+pub fn save_rewards(env: &Env, referrer: &Address, token: &Address, rewards: &ReferralRewards) {
+    let key = DataKey::ReferralRewards(referrer.clone(), token.clone());
+    env.storage().persistent().set(&key, rewards);
+    extend(env, &key);
+}
@@ -0,0 +1,149 @@
+//! Spectator side bets.
+//!
+//! Anyone but the two players can stake on either player of a game wagered
+//! in a token, in that token, until both tactics are submitted
+//! (`place_side_bet`). The stakes form a pari-mutuel pool per game:
+//!
+//! ```text
+//! rake   = losing_total * rake_bps / 10_000
+//! payout = stake + (losing_total - rake) * stake / winning_total
+//! ```
+//!
+//! The rake goes to the match winner, on top of their pot. Bets are settled
+//! with the game: a `resolve_match` win pays the winning side, and anything
+//! else (a draw, cancellation, timeout or expiry, or a pool with no bets on
+//! the winner) refunds every stake without a rake. Bettors pull what they
+//! are owed with `claim_side_bet`; the last winning claim takes the rounding
+//! dust, so the pool empties exactly.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{DataKey, OPEN_SESSION_TTL_LEDGERS};
+
+/// The side bets on one game (`get_side_bet_pool`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SideBetPool {
+    pub token: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_total: i128, // staked on player1
+    pub player2_total: i128, // staked on player2
+    pub rake_bps: u32,       // fixed by the first bet
+    pub result: SideBetResult,
+    pub rake: i128,       // owed to the winner, once settled
+    pub open_stake: i128, // stakes not yet claimed on the paying side
+    pub unclaimed: i128,  // owed to bettors, rake aside
+}
+
+/// How a side-bet pool was settled
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SideBetResult {
+    Open,       // the game is still in play
+    Player1Won, // bets on player1 share the pool
+    Player2Won, // bets on player2 share the pool
+    Refund,     // every stake is returned
+}
+
+/// One bettor's stake on a game (`get_side_bet`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SideBet {
+    pub player: Address, // the player backed
+    pub amount: i128,
+}
+
+impl SideBetPool {
+    /// Settle the pool as won by player1 (`Some(true)`), player2
+    /// (`Some(false)`) or neither (`None`)
+    pub fn settle(&mut self, player1_won: Option<bool>) {
+        let (winning, losing) = match player1_won {
+            Some(true) => (self.player1_total, self.player2_total),
+            Some(false) => (self.player2_total, self.player1_total),
+            None => (0, 0),
+        };
+        let total = self.player1_total + self.player2_total;
+        if winning == 0 {
+            self.result = SideBetResult::Refund;
+            self.open_stake = total;
+            self.unclaimed = total;
+            return;
+        }
+        self.result = if player1_won == Some(true) {
+            SideBetResult::Player1Won
+        } else {
+            SideBetResult::Player2Won
+        };
+        self.rake = losing * self.rake_bps as i128 / 10_000;
+        self.open_stake = winning;
+        self.unclaimed = total - self.rake;
+    }
+
+    /// What `bet` is paid from a settled pool, or 0 if it lost
+    pub fn payout(&self, bet: &SideBet) -> i128 {
+        let (winning, losing, side) = match self.result {
+            SideBetResult::Open => return 0,
+            SideBetResult::Refund => return bet.amount,
+            SideBetResult::Player1Won => (self.player1_total, self.player2_total, &self.player1),
+            SideBetResult::Player2Won => (self.player2_total, self.player1_total, &self.player2),
+        };
+        if bet.player != *side {
+            return 0;
+        }
+        if bet.amount == self.open_stake {
+            return self.unclaimed;
+        }
+        let rake = losing * self.rake_bps as i128 / 10_000;
+        bet.amount + (losing - rake) * bet.amount / winning
+    }
+
+    /// Nothing left to pay out
+    pub fn is_paid(&self) -> bool {
+        self.result != SideBetResult::Open && self.unclaimed == 0 && self.rake == 0
+    }
+}
+
+/// The side-bet pool for `session_id`, if any bets were placed
+pub fn pool(env: &Env, session_id: u32) -> Option<SideBetPool> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SideBetPool(session_id))
+}
+
+/// Store `pool` under `session_id`, or remove it once fully paid out
+pub fn save_pool(env: &Env, session_id: u32, pool: &SideBetPool) {
+    let key = DataKey::SideBetPool(session_id);
+    if pool.is_paid() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, pool);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
+
+/// `bettor`'s stake on `session_id`, if any
+pub fn bet(env: &Env, session_id: u32, bettor: &Address) -> Option<SideBet> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SideBet(session_id, bettor.clone()))
+}
+
+/// Store `bettor`'s stake on `session_id` (None: remove it)
+pub fn save_bet(env: &Env, session_id: u32, bettor: &Address, bet: Option<&SideBet>) {
+    let key = DataKey::SideBet(session_id, bettor.clone());
+    let Some(bet) = bet else {
+        env.storage().persistent().remove(&key);
+        return;
+    };
+    env.storage().persistent().set(&key, bet);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
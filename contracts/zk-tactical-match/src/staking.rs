@@ -0,0 +1,78 @@
+//! Token staking for fee discounts.
+//!
+//! With staking configured (`set_staking_config`), players lock the studio
+//! token (`stake`) to earn a discount on the protocol fee of the pots they
+//! win. Their tier is the highest whose `min_stake` their active stake
+//! reaches, and at settlement its `discount_bps` comes off the game's fee:
+//!
+//! ```text
+//! fee_bps = game fee_bps * (10_000 - discount_bps) / 10_000
+//! ```
+//!
+//! Every stake relocks the whole of it for `lockup_ledgers`. Once the lock
+//! is over, `unstake` moves an amount into a cooldown of `cooldown_ledgers`,
+//! where it no longer counts toward the tier, and `withdraw_stake` returns
+//! it after the cooldown. Unstaking more restarts the cooldown.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{DataKey, OPEN_SESSION_TTL_LEDGERS};
+
+/// The staking token, its tiers and its waiting periods
+/// (`set_staking_config`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakingConfig {
+    pub token: Address,
+    pub tiers: Vec<StakeTier>, // by `min_stake`, lowest first
+    pub lockup_ledgers: u32,   // a stake is locked for, from its last top-up
+    pub cooldown_ledgers: u32, // from `unstake` to `withdraw_stake`
+}
+
+/// A discount and the stake that earns it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeTier {
+    pub min_stake: i128,
+    pub discount_bps: u32, // off the protocol fee of a won pot
+}
+
+/// A player's stake (`get_stake`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stake {
+    pub token: Address,
+    pub amount: i128,             // active, counted toward the tier
+    pub locked_until_ledger: u32, // first ledger `amount` can be unstaked in
+    pub unstaking: i128,          // in its cooldown
+    pub cooldown_end_ledger: u32, // first ledger `unstaking` can be withdrawn in
+}
+
+impl StakingConfig {
+    /// The tier a stake of `amount` in the staking token earns, if any
+    pub fn tier_for(&self, amount: i128) -> Option<StakeTier> {
+        self.tiers.iter().rev().find(|tier| amount >= tier.min_stake)
+    }
+}
+
+/// `player`'s stake, if they have one
+pub fn get(env: &Env, player: &Address) -> Option<Stake> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Stake(player.clone()))
+}
+
+/// Store `player`'s stake, removing it once nothing is left in it
+pub fn save(env: &Env, player: &Address, stake: &Stake) {
+    let key = DataKey::Stake(player.clone());
+    if stake.amount == 0 && stake.unstaking == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, stake);
+    env.storage().persistent().extend_ttl(
+        &key,
+        OPEN_SESSION_TTL_LEDGERS,
+        OPEN_SESSION_TTL_LEDGERS,
+    );
+}
@@ -0,0 +1,69 @@
+//! Win streaks and their bonus.
+//!
+//! Every settled game extends the winner's run of consecutive wins and
+//! ends the loser's (a draw ends both). With a bonus configured
+//! (`set_streak_bonus`), a player who wins a pot on a streak of at least
+//! `min_streak` is paid an escalating share of what they won, out of the
+//! protocol fees accrued in that token:
+//!
+//! ```text
+//! bonus_bps = min(step_bps * (streak - min_streak + 1), max_bps)
+//! bonus     = min(opponent_stake * bonus_bps / 10_000, accrued fees)
+//! ```
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{DataKey, MatchResult, HISTORY_TTL_LEDGERS};
+
+/// A player's consecutive wins (`get_streak`)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Streak {
+    pub current: u32,
+    pub best: u32,
+}
+
+/// The bonus for winning on a streak (`set_streak_bonus`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreakBonus {
+    pub min_streak: u32, // the first streak length paid, at least 2
+    pub step_bps: u32,   // added per win from `min_streak` on
+    pub max_bps: u32,    // at most 10,000
+}
+
+impl StreakBonus {
+    /// The bonus for a win that brings a streak to `streak`, in basis
+    /// points of the pot won (0 below `min_streak`)
+    pub fn bonus_bps(&self, streak: u32) -> u32 {
+        if streak < self.min_streak {
+            return 0;
+        }
+        let steps = streak - self.min_streak + 1;
+        self.step_bps.saturating_mul(steps).min(self.max_bps)
+    }
+}
+
+/// `player`'s streak
+pub fn get(env: &Env, player: &Address) -> Streak {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Streak(player.clone()))
+        .unwrap_or_default()
+}
+
+/// Count a settled game for `player`
+pub fn record(env: &Env, player: &Address, result: MatchResult) {
+    let key = DataKey::Streak(player.clone());
+    let mut streak = get(env, player);
+    if result == MatchResult::Won {
+        streak.current += 1;
+        streak.best = streak.best.max(streak.current);
+    } else {
+        streak.current = 0;
+    }
+    env.storage().persistent().set(&key, &streak);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, HISTORY_TTL_LEDGERS, HISTORY_TTL_LEDGERS);
+}
@@ -1,37 +1,51 @@
 #![cfg(test)]
 
-use crate::{Error, ZkTacticalMatchContract, ZkTacticalMatchContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+extern crate std;
 
-// ============================================================================
-// Mock GameHub for Unit Testing
-// ============================================================================
-
-#[contract]
-pub struct MockGameHub;
-
-#[contractimpl]
-impl MockGameHub {
-    pub fn start_game(
-        _env: Env,
-        _game_id: Address,
-        _session_id: u32,
-        _player1: Address,
-        _player2: Address,
-        _player1_points: i128,
-        _player2_points: i128,
-    ) {
-    }
-
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-    }
-}
+use crate::{
+    player_binding, rating_key, stake_hash, strategy_proof, strategy_root, tactic_hash, DataKey,
+    AdminCouncil, CouncilAction, Deadlines, Error, Game, GameCancelled, GamePhase, GameStarted, HubChanged, GameV0, GameV1, GameV2, Due, Escrow, OracleConfig, MatchRecord, OpenSession, Outcome, PendingReinit, PendingUpgrade, ProofEnvelope,
+    ProofSystem, PublicInputs, RatingBracket, Role, RoundInputs, StakeCommitment, SybilGate, TeamMember,
+    Teams, TiebreakPolicy, UniquenessProof, VkSource, WagerLimits, ZkTacticalMatchContract,
+    ZkTacticalMatchContractClient, ATTESTATION_VERSION, DEFAULT_MAX_PROOF_SIZE,
+    DEFAULT_TIMEOUT_LEDGERS, DEFAULT_UPGRADE_DELAY_LEDGERS, MAX_OPEN_GAMES_LIMIT, MAX_ROUNDS,
+    MIN_UPGRADE_DELAY_LEDGERS, PROOF_VERSION, SCHEMA_VERSION, CONTRACT_VERSION, GameSummary,
+    MatchResult, PlayerStats, GlobalStats, ScoreEntry, MAX_HISTORY_PAGE, MatchResolved, PauseChanged, TacticSubmitted,
+    TimedOut, WagerLimitsChanged, WinningsClaimed, WinningsHeld, WinningsPaid, RefundClaimed, RefundHeld,
+    RefundPaid, Settlement, FORFEIT_SHARE_BPS, MAX_FEE_BPS, FeesWithdrawn, SideBet, SideBetResult,
+    BatchResult, Odds, Stake, StakeTier, StakingConfig, Bond, DisputeConfig, DisputeStatus, epoch_leaf, EpochConfig, EpochLeader, DEFAULT_SIDE_BET_RAKE_BPS, Streak, StreakBonus, JackpotConfig, Bankroll, ReferralRewards, ReferralStats, DEFAULT_REFERRAL_SHARE_BPS,
+};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Events as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Event as _, IntoVal, Symbol, Vec, vec};
+use soroban_sdk::xdr::ToXdr;
+use stellar_game_testutils::{
+    bls_aggregate, bls_key, bls_sign, empty_proof, groth16_contract, groth16_vk, mock_commitment,
+    mock_nullifier, mock_player_proof, mock_proof, mock_secret, setup_ledger, short_proof,
+    zero_proof, FailingGameHub, Groth16ProofVector, HubAttack, HubFailure, HubOutcome,
+    LegacyGameHub, LegacyGameHubClient, MaliciousGameHub, MaliciousGameHubClient, MockGameHub,
+    MockGameHubClient, MockVerifier, MockVerifierClient, TestLedger, GROTH16_PROOFS, balance,
+    freeze, fund, fund_native, native_token, wager_token, MockPriceOracle, MockPriceOracleClient,
+};
+use stellar_game_testutils::vectors::PROOF_HASH_VECTORS;
+use vk_registry::{VkRegistry, VkRegistryClient};
+use randomness_beacon::{RandomnessBeacon, RandomnessBeaconClient};
 
 // ============================================================================
 // Test Helpers
 // ============================================================================
 
+/// Point every verifier slot at one verifier that accepts all proofs, as
+/// the contract rejects proofs it has no verifier for
+fn accept_all_proofs(client: &ZkTacticalMatchContractClient) {
+    let verifier = client.env.register(MockVerifier, (true,));
+    client.set_verifier(&verifier);
+    client.set_ultrahonk_verifier(&verifier);
+    client.set_stake_verifier(&verifier);
+    client.set_rating_verifier(&verifier);
+    client.set_rounds_verifier(&verifier);
+    client.set_receipt_verifier(&verifier);
+}
+
 fn setup_test() -> (
     Env,
     ZkTacticalMatchContractClient<'static>,
@@ -40,22 +54,13 @@ fn setup_test() -> (
 ) {
     let env = Env::default();
     env.mock_all_auths();
-
-    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
-        timestamp: 1441065600,
-        protocol_version: 25,
-        sequence_number: 100,
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: u32::MAX / 2,
-        min_persistent_entry_ttl: u32::MAX / 2,
-        max_entry_ttl: u32::MAX / 2,
-    });
+    setup_ledger(&env);
 
     let hub_addr = env.register(MockGameHub, ());
     let admin = Address::generate(&env);
     let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
     let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    accept_all_proofs(&client);
 
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
@@ -63,11 +68,38 @@ fn setup_test() -> (
     (env, client, player1, player2)
 }
 
-fn mock_proof(env: &Env, tactic: u32, session_id: u32) -> Bytes {
-    let mut data = Bytes::new(env);
-    data.append(&Bytes::from_array(env, &tactic.to_be_bytes()));
-    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-    data
+/// Public inputs matching `mock_proof(tactic, session_id)` for `player`
+fn mock_inputs(
+    client: &ZkTacticalMatchContractClient,
+    player: &Address,
+    session_id: u32,
+    tactic: u32,
+) -> PublicInputs {
+    let env = &client.env;
+    PublicInputs {
+        session_id,
+        player_binding: player_binding(env, &client.address, player),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(env, tactic, session_id),
+        nullifier: mock_nullifier(env, player, session_id),
+        tactic_hash: tactic_hash(
+            env,
+            tactic,
+            &mock_secret(env, player, session_id),
+            session_id,
+        ),
+    }
+}
+
+/// Reveal the tactic `player` submitted with `mock_inputs`
+fn mock_reveal(
+    client: &ZkTacticalMatchContractClient,
+    player: &Address,
+    session_id: u32,
+    tactic: u32,
+) {
+    let secret = mock_secret(&client.env, player, session_id);
+    client.reveal_tactic(&session_id, player, &tactic, &secret);
 }
 
 // ============================================================================
@@ -83,8 +115,8 @@ fn test_complete_game() {
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    let game = client.get_game(&session_id);
-    assert!(game.winner.is_none());
+    let game = client.get_game_public(&session_id).game;
+    assert!(game.outcome().is_none());
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
 
@@ -92,15 +124,17 @@ fn test_complete_game() {
     let proof1 = mock_proof(&_env, 0, session_id); // Defensive
     let proof2 = mock_proof(&_env, 2, session_id); // Aggressive
     
-    client.submit_tactic(&session_id, &player1, &0, &proof1);
-    client.submit_tactic(&session_id, &player2, &2, &proof2);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &proof1);
+    client.submit_tactic(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 2), &proof2);
+    mock_reveal(&client, &player1, session_id, 0);
+    mock_reveal(&client, &player2, session_id, 2);
 
     // Resolve match
-    let winner = client.resolve_match(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // Defensive (0) draws with Aggressive (2)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Draw);
 
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
+    assert!(final_game.outcome().is_some());
     assert!(final_game.player1_score.is_some());
     assert!(final_game.player2_score.is_some());
 }
@@ -115,8 +149,10 @@ fn test_score_matrix_defensive_vs_aggressive() {
     let proof1 = mock_proof(&_env, 0, session_id); // Defensive
     let proof2 = mock_proof(&_env, 2, session_id); // Aggressive
     
-    client.submit_tactic(&session_id, &player1, &0, &proof1);
-    client.submit_tactic(&session_id, &player2, &2, &proof2);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &proof1);
+    client.submit_tactic(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 2), &proof2);
+    mock_reveal(&client, &player1, session_id, 0);
+    mock_reveal(&client, &player2, session_id, 2);
 
     client.resolve_match(&session_id);
 
@@ -136,8 +172,10 @@ fn test_score_matrix_balanced_vs_defensive() {
     let proof1 = mock_proof(&_env, 1, session_id); // Balanced
     let proof2 = mock_proof(&_env, 0, session_id); // Defensive
     
-    client.submit_tactic(&session_id, &player1, &1, &proof1);
-    client.submit_tactic(&session_id, &player2, &0, &proof2);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &proof1);
+    client.submit_tactic(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 0), &proof2);
+    mock_reveal(&client, &player1, session_id, 1);
+    mock_reveal(&client, &player2, session_id, 0);
 
     client.resolve_match(&session_id);
 
@@ -145,7 +183,7 @@ fn test_score_matrix_balanced_vs_defensive() {
     // Balanced vs Defensive = 1-0 (Balanced wins)
     assert_eq!(game.player1_score.unwrap(), 1);
     assert_eq!(game.player2_score.unwrap(), 0);
-    assert_eq!(game.winner.unwrap(), player1);
+    assert_eq!(game.outcome(), Some(Outcome::Player1Win));
 }
 
 #[test]
@@ -155,19 +193,21 @@ fn test_score_matrix_allout_vs_allout() {
     let session_id = 4u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    let proof1 = mock_proof(&_env, 3, session_id); // AllOut
-    let proof2 = mock_proof(&_env, 3, session_id); // AllOut
+    let proof1 = mock_player_proof(&_env, &player1, 3, session_id); // AllOut
+    let proof2 = mock_player_proof(&_env, &player2, 3, session_id); // AllOut
     
-    client.submit_tactic(&session_id, &player1, &3, &proof1);
-    client.submit_tactic(&session_id, &player2, &3, &proof2);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 3), &proof1);
+    client.submit_tactic(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 3), &proof2);
+    mock_reveal(&client, &player1, session_id, 3);
+    mock_reveal(&client, &player2, session_id, 3);
 
     client.resolve_match(&session_id);
 
     let game = client.get_game(&session_id);
-    // AllOut vs AllOut = 4-4 (maximum chaos draw, player1 wins tie)
+    // AllOut vs AllOut = 4-4 (maximum chaos draw)
     assert_eq!(game.player1_score.unwrap(), 4);
     assert_eq!(game.player2_score.unwrap(), 4);
-    assert_eq!(game.winner.unwrap(), player1); // Tie goes to player1
+    assert_eq!(game.outcome(), Some(Outcome::Draw));
 }
 
 // ============================================================================
@@ -182,11 +222,11 @@ fn test_cannot_submit_twice() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let proof1 = mock_proof(&_env, 0, session_id);
-    client.submit_tactic(&session_id, &player1, &0, &proof1);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &proof1);
 
     // Try to submit again
     let proof2 = mock_proof(&_env, 1, session_id);
-    let result = client.try_submit_tactic(&session_id, &player1, &1, &proof2);
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &proof2);
     
     match result {
         Err(Ok(err)) => assert_eq!(err, Error::AlreadySubmitted),
@@ -202,13 +242,13 @@ fn test_cannot_resolve_before_both_submit() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let proof1 = mock_proof(&_env, 0, session_id);
-    client.submit_tactic(&session_id, &player1, &0, &proof1);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &proof1);
 
     let result = client.try_resolve_match(&session_id);
     
     match result {
-        Err(Ok(err)) => assert_eq!(err, Error::BothPlayersNotSubmitted),
-        _ => panic!("Expected BothPlayersNotSubmitted error"),
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
     }
 }
 
@@ -219,9 +259,14 @@ fn test_invalid_tactic() {
     let session_id = 7u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    let proof = mock_proof(&_env, 4, session_id); // Invalid: must be 0-3
-    let result = client.try_submit_tactic(&session_id, &player1, &4, &proof);
-    
+    // The tactic is hidden at submission, so an invalid one is caught at reveal
+    let proof1 = mock_proof(&_env, 4, session_id); // Invalid: must be 0-3
+    let proof2 = mock_proof(&_env, 0, session_id);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 4), &proof1);
+    client.submit_tactic(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 0), &proof2);
+
+    let secret = mock_secret(&_env, &player1, session_id);
+    let result = client.try_reveal_tactic(&session_id, &player1, &4, &secret);
     match result {
         Err(Ok(err)) => assert_eq!(err, Error::InvalidTactic),
         _ => panic!("Expected InvalidTactic error"),
@@ -237,7 +282,7 @@ fn test_non_player_cannot_submit() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let proof = mock_proof(&env, 0, session_id);
-    let result = client.try_submit_tactic(&session_id, &non_player, &0, &proof);
+    let result = client.try_submit_tactic(&session_id, &non_player, &mock_inputs(&client, &non_player, session_id, 0), &proof);
     
     match result {
         Err(Ok(err)) => assert_eq!(err, Error::NotPlayer),
@@ -245,6 +290,121 @@ fn test_non_player_cannot_submit() {
     }
 }
 
+#[test]
+fn test_malformed_proofs_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 12u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    for proof in [empty_proof(&env), short_proof(&env), zero_proof(&env, 64)] {
+        let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &proof);
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+            _ => panic!("Expected InvalidProof error"),
+        }
+    }
+}
+
+#[test]
+fn test_hub_failure_on_start_reverts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    let hub_addr = env.register(FailingGameHub, (HubFailure::StartGame,));
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    let result = client.try_start_game(&13u32, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(result.is_err());
+    assert!(client.try_get_game(&13u32).is_err());
+}
+
+#[test]
+fn test_set_hub_rejects_non_hubs() {
+    let (env, client, _player1, _player2) = setup_test();
+    let hub = client.get_hub();
+
+    // A mistyped account address, and a contract with another interface
+    let not_hubs = [Address::generate(&env), env.register(MockVerifier, (true,))];
+    for not_hub in not_hubs {
+        match client.try_set_hub(&not_hub) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidHub),
+            _ => panic!("Expected InvalidHub error"),
+        }
+    }
+    assert_eq!(client.get_hub(), hub);
+
+    // Hubs from before draws still qualify
+    let legacy_hub = env.register(LegacyGameHub, ());
+    client.set_hub(&legacy_hub);
+    assert_eq!(client.get_hub(), legacy_hub);
+}
+
+#[test]
+fn test_session_ids_allocated_by_the_hub() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = client.allocate_session();
+    assert_eq!((session_id, client.allocate_session()), (1, 2));
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Hubs from before allocation hand out none
+    client.set_hub(&env.register(LegacyGameHub, ()));
+    match client.try_allocate_session() {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidHub),
+        _ => panic!("Expected InvalidHub error"),
+    }
+}
+
+#[test]
+fn test_cannot_play_against_yourself() {
+    let (env, client, player1, _player2) = setup_test();
+
+    let results = [
+        client.try_start_game(&14u32, &player1, &player1, &100_0000000, &100_0000000),
+        client.try_start_game_with_deadlines(&14u32, &player1, &player1, &100_0000000, &100_0000000, &30, &20),
+        client.try_start_multi_round_game(&14u32, &player1, &player1, &100_0000000, &100_0000000, &3),
+        client.try_start_hidden_stake_game(&14u32, &player1, &player1, &10, &1_000, &hidden_stake(&env, 100, 0xa1, 14), &hidden_stake(&env, 100, 0xb2, 14)),
+    ];
+    for result in results {
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::SelfPlayNotAllowed),
+            _ => panic!("Expected SelfPlayNotAllowed error"),
+        }
+    }
+    assert!(client.try_get_game(&14u32).is_err());
+}
+
+#[test]
+fn test_missing_config_is_a_typed_error() {
+    let (env, client, player1, player2) = setup_test();
+
+    env.as_contract(&client.address, || env.storage().instance().remove(&DataKey::GameHubAddress));
+    match client.try_get_hub() {
+        Err(Ok(err)) => assert_eq!(err, Error::HubNotSet),
+        _ => panic!("Expected HubNotSet error"),
+    }
+    match client.try_start_game(&15u32, &player1, &player2, &100_0000000, &100_0000000) {
+        Err(Ok(err)) => assert_eq!(err, Error::HubNotSet),
+        _ => panic!("Expected HubNotSet error"),
+    }
+
+    env.as_contract(&client.address, || env.storage().instance().remove(&DataKey::Admin));
+    match client.try_get_admin() {
+        Err(Ok(err)) => assert_eq!(err, Error::NotInitialized),
+        _ => panic!("Expected NotInitialized error"),
+    }
+    match client.try_set_hub(&Address::generate(&env)) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotInitialized),
+        _ => panic!("Expected NotInitialized error"),
+    }
+}
+
 #[test]
 fn test_resolve_is_idempotent() {
     let (_env, client, player1, player2) = setup_test();
@@ -255,13 +415,15 @@ fn test_resolve_is_idempotent() {
     let proof1 = mock_proof(&_env, 1, session_id);
     let proof2 = mock_proof(&_env, 2, session_id);
     
-    client.submit_tactic(&session_id, &player1, &1, &proof1);
-    client.submit_tactic(&session_id, &player2, &2, &proof2);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &proof1);
+    client.submit_tactic(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 2), &proof2);
+    mock_reveal(&client, &player1, session_id, 1);
+    mock_reveal(&client, &player2, session_id, 2);
 
-    let winner1 = client.resolve_match(&session_id);
-    let winner2 = client.resolve_match(&session_id);
+    let outcome1 = client.resolve_match(&session_id);
+    let outcome2 = client.resolve_match(&session_id);
     
-    assert_eq!(winner1, winner2);
+    assert_eq!(outcome1, outcome2);
 }
 
 // ============================================================================
@@ -285,14 +447,5943 @@ fn test_multiple_games_independent() {
     let proof2a = mock_proof(&env, 2, session2);
     let proof2b = mock_proof(&env, 3, session2);
 
-    client.submit_tactic(&session1, &player1, &0, &proof1a);
-    client.submit_tactic(&session1, &player2, &1, &proof1b);
-    client.submit_tactic(&session2, &player3, &2, &proof2a);
-    client.submit_tactic(&session2, &player4, &3, &proof2b);
+    client.submit_tactic(&session1, &player1, &mock_inputs(&client, &player1, session1, 0), &proof1a);
+    client.submit_tactic(&session1, &player2, &mock_inputs(&client, &player2, session1, 1), &proof1b);
+    client.submit_tactic(&session2, &player3, &mock_inputs(&client, &player3, session2, 2), &proof2a);
+    client.submit_tactic(&session2, &player4, &mock_inputs(&client, &player4, session2, 3), &proof2b);
+    mock_reveal(&client, &player1, session1, 0);
+    mock_reveal(&client, &player2, session1, 1);
+    mock_reveal(&client, &player3, session2, 2);
+    mock_reveal(&client, &player4, session2, 3);
+
+    // Defensive loses to Balanced; Aggressive draws with AllOut
+    assert_eq!(client.resolve_match(&session1), Outcome::Player2Win);
+    assert_eq!(client.resolve_match(&session2), Outcome::Draw);
+}
+
+#[test]
+fn test_session_id_collision_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let player4 = Address::generate(&env);
+
+    // Two matches race for the same session ID; whichever lands second fails,
+    // however it was started, and the first game is left as it was
+    let session_id = 16u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, session_id, &player1, 2);
+    let game = client.get_game_public(&session_id).game;
+
+    let results = [
+        client.try_start_game(&session_id, &player3, &player4, &50_0000000, &50_0000000),
+        client.try_start_game_with_deadlines(&session_id, &player3, &player4, &50_0000000, &50_0000000, &30, &20),
+        client.try_start_multi_round_game(&session_id, &player3, &player4, &50_0000000, &50_0000000, &3),
+    ];
+    for result in results {
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::SessionAlreadyExists),
+            _ => panic!("Expected SessionAlreadyExists error"),
+        }
+    }
+    assert_eq!(client.get_game_public(&session_id).game, game);
+    assert!(client.get_multi_round(&session_id).is_none());
+
+    // Once settled, the ID is free again
+    submit_one(&client, session_id, &player2, 1);
+    mock_reveal(&client, &player1, session_id, 2);
+    mock_reveal(&client, &player2, session_id, 1);
+    client.resolve_match(&session_id);
+    client.start_game(&session_id, &player3, &player4, &50_0000000, &50_0000000);
+    assert_eq!(client.get_game_public(&session_id).game.player1, player3);
+}
+
+#[test]
+fn test_unreported_session_id_cannot_be_reused() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    client.start_game(&17, &player1, &player2, &100_0000000, &100_0000000);
+
+    // The game expired, but the hub never heard how it ended
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    assert!(client.try_get_game(&17).is_err());
+    match client.try_start_game(&17, &player1, &player2, &100_0000000, &100_0000000) {
+        Err(Ok(err)) => assert_eq!(err, Error::SessionAlreadyExists),
+        _ => panic!("Expected SessionAlreadyExists error"),
+    }
+
+    let (stake1, stake2) = (hidden_stake(&env, 100, 0xa1, 17), hidden_stake(&env, 100, 0xb2, 17));
+    match client.try_start_hidden_stake_game(&17, &player1, &player2, &10, &1_000, &stake1, &stake2) {
+        Err(Ok(err)) => assert_eq!(err, Error::SessionAlreadyExists),
+        _ => panic!("Expected SessionAlreadyExists error"),
+    }
+
+    client.abandon_expired(&17);
+    client.start_game(&17, &player1, &player2, &100_0000000, &100_0000000);
+}
+
+// ============================================================================
+// Reveal Tests
+// ============================================================================
+
+/// Start a session and submit Defensive (0) for player1, Aggressive (2) for
+/// player2
+fn submit_hidden(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address) {
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    let env = &client.env;
+    client.submit_tactic(&session_id, player1, &mock_inputs(client, player1, session_id, 0), &mock_proof(env, 0, session_id));
+    client.submit_tactic(&session_id, player2, &mock_inputs(client, player2, session_id, 2), &mock_proof(env, 2, session_id));
+}
+
+#[test]
+fn test_tactics_hidden_until_revealed() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 8u32;
+    submit_hidden(&client, session_id, &player1, &player2);
+
+    // Only the hashes are on-chain until the players reveal
+    let game = client.get_game_public(&session_id).game;
+    assert_eq!(game.player1_tactic, None);
+    assert_eq!(game.player2_tactic, None);
+    let expected = tactic_hash(&env, 0, &mock_secret(&env, &player1, session_id), session_id);
+    assert_eq!(game.player1_tactic_hash, Some(expected));
+
+    mock_reveal(&client, &player1, session_id, 0);
+    match client.try_resolve_match(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::TacticNotRevealed),
+        _ => panic!("Expected TacticNotRevealed error"),
+    }
+
+    mock_reveal(&client, &player2, session_id, 2);
+    let view = client.get_game_public(&session_id);
+    assert!(view.player1_revealed && view.player2_revealed);
+    assert_eq!(view.game.player1_tactic, None);
+
+    // Defensive (0) draws with Aggressive (2)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Draw);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_tactic, Some(0));
+    assert_eq!(game.player2_tactic, Some(2));
+}
+
+#[test]
+fn test_full_view_waits_for_settlement() {
+    let (_env, client, player1, player2) = setup_test();
+
+    let session_id = 9u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, session_id, &player1, 3);
+    match client.try_get_game(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotSettled),
+        _ => panic!("Expected GameNotSettled error"),
+    }
+
+    submit_one(&client, session_id, &player2, 1);
+    mock_reveal(&client, &player1, session_id, 3);
+    let view = client.get_game_public(&session_id);
+    assert_eq!((view.player1_revealed, view.player2_revealed), (true, false));
+    assert_eq!(view.game.player1_tactic, None);
+    assert_eq!(view.game.player1_proof_hash, None);
+    assert!(view.game.player1_tactic_hash.is_some());
+
+    mock_reveal(&client, &player2, session_id, 1);
+    client.resolve_match(&session_id);
+    let settled = client.get_game(&session_id);
+    assert_eq!(client.get_game_public(&session_id).game, settled);
+    assert_eq!(settled.player1_tactic, Some(3));
+    assert!(settled.player1_proof_hash.is_some());
+    match client.try_get_game(&10) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+}
+
+#[test]
+fn test_reveal_waits_for_both_submissions() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 9u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &mock_proof(&env, 1, session_id));
+
+    // Revealing now would show player2 what they are up against
+    let secret = mock_secret(&env, &player1, session_id);
+    match client.try_reveal_tactic(&session_id, &player1, &1, &secret) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+}
+
+#[test]
+fn test_reveal_must_open_tactic_hash() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 10u32;
+    submit_hidden(&client, session_id, &player1, &player2);
+
+    // Switching tactic after seeing the opponent commit is rejected
+    let secret = mock_secret(&env, &player1, session_id);
+    match client.try_reveal_tactic(&session_id, &player1, &3, &secret) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+        _ => panic!("Expected InvalidReveal error"),
+    }
+
+    // So is the right tactic with the wrong secret
+    let wrong_secret = mock_secret(&env, &player2, session_id);
+    match client.try_reveal_tactic(&session_id, &player1, &0, &wrong_secret) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+        _ => panic!("Expected InvalidReveal error"),
+    }
+    assert_eq!(client.get_game_public(&session_id).game.player1_tactic, None);
+}
+
+#[test]
+fn test_cannot_reveal_twice() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 11u32;
+    submit_hidden(&client, session_id, &player1, &player2);
+    mock_reveal(&client, &player1, session_id, 0);
+
+    let secret = mock_secret(&env, &player1, session_id);
+    match client.try_reveal_tactic(&session_id, &player1, &0, &secret) {
+        Err(Ok(err)) => assert_eq!(err, Error::AlreadyRevealed),
+        _ => panic!("Expected AlreadyRevealed error"),
+    }
+}
+
+#[test]
+fn test_non_player_cannot_reveal() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 12u32;
+    submit_hidden(&client, session_id, &player1, &player2);
+
+    let non_player = Address::generate(&env);
+    let secret = mock_secret(&env, &player1, session_id);
+    match client.try_reveal_tactic(&session_id, &non_player, &0, &secret) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotPlayer),
+        _ => panic!("Expected NotPlayer error"),
+    }
+}
+
+// ============================================================================
+// Commit-Reveal Mode Tests
+// ============================================================================
+
+/// Start a `CommitReveal` game (the default is restored afterwards)
+fn start_commit_reveal(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address) {
+    client.set_proof_system(&ProofSystem::CommitReveal);
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    client.set_proof_system(&ProofSystem::Groth16);
+}
+
+/// Salted hash a player commits to in a `CommitReveal` game
+fn salted_hash(env: &Env, tactic: u32, salt: u8, session_id: u32) -> BytesN<32> {
+    tactic_hash(env, tactic, &BytesN::from_array(env, &[salt; 32]), session_id)
+}
+
+#[test]
+fn test_commit_reveal_game_resolves() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 70u32;
+    start_commit_reveal(&client, session_id, &player1, &player2);
+    assert_eq!(client.get_game_public(&session_id).game.proof_system, ProofSystem::CommitReveal);
+
+    client.commit_tactic(&session_id, &player1, &salted_hash(&env, 1, 0xa1, session_id));
+    client.commit_tactic(&session_id, &player2, &salted_hash(&env, 0, 0xb2, session_id));
+
+    let game = client.get_game_public(&session_id).game;
+    assert_eq!(game.player1_tactic, None);
+    assert_eq!(game.player1_commitment, None);
+    assert_eq!(game.player1_tactic_hash, Some(salted_hash(&env, 1, 0xa1, session_id)));
+
+    client.reveal_tactic(&session_id, &player1, &1, &BytesN::from_array(&env, &[0xa1; 32]));
+    client.reveal_tactic(&session_id, &player2, &0, &BytesN::from_array(&env, &[0xb2; 32]));
+
+    // Balanced (1) beats Defensive (0)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_score, Some(1));
+    assert_eq!(game.player2_score, Some(0));
+}
+
+#[test]
+fn test_commit_reveal_rejects_wrong_salt() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 71u32;
+    start_commit_reveal(&client, session_id, &player1, &player2);
+    client.commit_tactic(&session_id, &player1, &salted_hash(&env, 2, 0xa1, session_id));
+    client.commit_tactic(&session_id, &player2, &salted_hash(&env, 3, 0xb2, session_id));
+
+    let wrong_salt = BytesN::from_array(&env, &[0xa2; 32]);
+    match client.try_reveal_tactic(&session_id, &player1, &2, &wrong_salt) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+        _ => panic!("Expected InvalidReveal error"),
+    }
+
+    // A hash committed for another session does not open here either
+    let salt = BytesN::from_array(&env, &[0xb2; 32]);
+    let session_id = 72u32;
+    start_commit_reveal(&client, session_id, &player1, &player2);
+    client.commit_tactic(&session_id, &player1, &salted_hash(&env, 2, 0xa1, session_id));
+    client.commit_tactic(&session_id, &player2, &salted_hash(&env, 3, 0xb2, 71));
+    match client.try_reveal_tactic(&session_id, &player2, &3, &salt) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+        _ => panic!("Expected InvalidReveal error"),
+    }
+}
+
+#[test]
+fn test_commit_tactic_rejects_bad_commitments() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 73u32;
+    start_commit_reveal(&client, session_id, &player1, &player2);
+
+    // `tactic_hash` always clears the top byte, so this could never be opened
+    let unopenable = BytesN::from_array(&env, &[0xff; 32]);
+    match client.try_commit_tactic(&session_id, &player1, &unopenable) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidCommitment),
+        _ => panic!("Expected InvalidCommitment error"),
+    }
+
+    let hash = salted_hash(&env, 3, 0xa1, session_id);
+    client.commit_tactic(&session_id, &player1, &hash);
+    match client.try_commit_tactic(&session_id, &player1, &salted_hash(&env, 0, 0xa1, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::AlreadySubmitted),
+        _ => panic!("Expected AlreadySubmitted error"),
+    }
+
+    // Copying the opponent's hash would let player2 mirror their reveal
+    match client.try_commit_tactic(&session_id, &player2, &hash) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidCommitment),
+        _ => panic!("Expected InvalidCommitment error"),
+    }
+}
+
+#[test]
+fn test_submission_mode_must_match_game() {
+    let (env, client, player1, player2) = setup_test();
+
+    // No proofs in a commit-reveal game...
+    let session_id = 74u32;
+    start_commit_reveal(&client, session_id, &player1, &player2);
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &mock_proof(&env, 1, session_id));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+
+    // ...and no bare commitments in a ZK game
+    let session_id = 75u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    match client.try_commit_tactic(&session_id, &player1, &salted_hash(&env, 1, 0xa1, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+}
+
+// ============================================================================
+// Hidden Stake Tests
+// ============================================================================
+
+/// Stake hash with a placeholder range proof (only a stake verifier checks it)
+fn hidden_stake(env: &Env, stake: i128, salt: u8, session_id: u32) -> StakeCommitment {
+    StakeCommitment {
+        stake_hash: stake_hash(env, stake, &BytesN::from_array(env, &[salt; 32]), session_id),
+        proof: mock_proof(env, salt as u32, session_id),
+    }
+}
+
+/// Start a hidden-stake game with bounds [10, 1000]; player1 salts with
+/// 0xa1 and player2 with 0xb2
+fn start_hidden(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address, stake1: i128, stake2: i128) {
+    let env = &client.env;
+    client.start_hidden_stake_game(&session_id, player1, player2, &10, &1_000, &hidden_stake(env, stake1, 0xa1, session_id), &hidden_stake(env, stake2, 0xb2, session_id));
+}
+
+/// Submit and reveal tactics for both players (player1 plays `tactic1`)
+fn play_tactics(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address, tactic1: u32, tactic2: u32) {
+    let env = &client.env;
+    client.submit_tactic(&session_id, player1, &mock_inputs(client, player1, session_id, tactic1), &mock_player_proof(env, player1, tactic1, session_id));
+    client.submit_tactic(&session_id, player2, &mock_inputs(client, player2, session_id, tactic2), &mock_player_proof(env, player2, tactic2, session_id));
+    mock_reveal(client, player1, session_id, tactic1);
+    mock_reveal(client, player2, session_id, tactic2);
+}
+
+#[test]
+fn test_hidden_stakes_revealed_at_settlement() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 80u32;
+    start_hidden(&client, session_id, &player1, &player2, 250, 700);
+
+    let game = client.get_game_public(&session_id).game;
+    assert_eq!(game.player1_points, 0);
+    assert_eq!(game.player2_points, 0);
+    let stakes = client.get_hidden_stakes(&session_id).unwrap();
+    assert_eq!((stakes.min_stake, stakes.max_stake), (10, 1_000));
+    assert_eq!(stakes.player1_stake, None);
+
+    play_tactics(&client, session_id, &player1, &player2, 2, 1);
+    client.reveal_stake(&session_id, &player1, &250, &BytesN::from_array(&env, &[0xa1; 32]));
+    client.reveal_stake(&session_id, &player2, &700, &BytesN::from_array(&env, &[0xb2; 32]));
+
+    // Aggressive (2) beats Balanced (1)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_points, 250);
+    assert_eq!(game.player2_points, 700);
+    assert_eq!(client.get_hidden_stakes(&session_id).unwrap().player2_stake, Some(700));
+
+    // Ordinary games have no hidden stakes
+    client.start_game(&81, &player1, &player2, &100, &100);
+    assert_eq!(client.get_hidden_stakes(&81), None);
+}
+
+#[test]
+fn test_hidden_stake_game_reserves_max_stake_at_the_hub() {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    // A version 1 hub, which cannot lower stakes
+    let hub_addr = env.register(LegacyGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    accept_all_proofs(&client);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    // The hub session holds the most either player could have staked...
+    let session_id = 82u32;
+    start_hidden(&client, session_id, &player1, &player2, 250, 700);
+    let open = client.get_open_session(&session_id).unwrap();
+    assert_eq!((open.player1_points, open.player2_points), (1_000, 1_000));
+    play_tactics(&client, session_id, &player1, &player2, 2, 1);
+    client.reveal_stake(&session_id, &player1, &250, &BytesN::from_array(&env, &[0xa1; 32]));
+    client.reveal_stake(&session_id, &player2, &700, &BytesN::from_array(&env, &[0xb2; 32]));
+
+    // ...until settlement lowers it to the stakes revealed. This hub cannot,
+    // which does not hold up settlement, so the result waits for one that can
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    assert!(!client.get_game(&session_id).hub_notified);
+    assert_eq!(client.get_open_session(&session_id).unwrap().player1_points, 1_000);
+
+    let hub_addr = env.register(MockGameHub, ());
+    client.set_hub(&hub_addr);
+    client.retry_hub_notify(&session_id);
+    assert!(client.get_game(&session_id).hub_notified);
+    let hub = MockGameHubClient::new(&env, &hub_addr);
+    assert_eq!(hub.stakes(&session_id), Some((250, 700)));
+    assert_eq!(hub.outcome(&session_id), Some(HubOutcome::Player1Win));
+    assert_eq!(client.get_open_session(&session_id), None);
+}
+
+#[test]
+fn test_resolve_waits_for_stake_reveals() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 83u32;
+    start_hidden(&client, session_id, &player1, &player2, 100, 200);
+
+    // Stakes stay hidden until both tactics are in
+    let salt1 = BytesN::from_array(&env, &[0xa1; 32]);
+    match client.try_reveal_stake(&session_id, &player1, &100, &salt1) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+
+    play_tactics(&client, session_id, &player1, &player2, 1, 1);
+    client.reveal_stake(&session_id, &player1, &100, &salt1);
+    match client.try_resolve_match(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::StakeNotRevealed),
+        _ => panic!("Expected StakeNotRevealed error"),
+    }
+}
+
+#[test]
+fn test_reveal_stake_must_open_hash() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 84u32;
+    // Player 2's stake is out of bounds, which only a verifier would catch
+    start_hidden(&client, session_id, &player1, &player2, 100, 5_000);
+    play_tactics(&client, session_id, &player1, &player2, 1, 2);
+
+    let salt1 = BytesN::from_array(&env, &[0xa1; 32]);
+    for (stake, salt) in [(101, salt1.clone()), (100, BytesN::from_array(&env, &[0xa2; 32]))] {
+        match client.try_reveal_stake(&session_id, &player1, &stake, &salt) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+            _ => panic!("Expected InvalidReveal error"),
+        }
+    }
+
+    client.reveal_stake(&session_id, &player1, &100, &salt1);
+    match client.try_reveal_stake(&session_id, &player1, &100, &salt1) {
+        Err(Ok(err)) => assert_eq!(err, Error::AlreadyRevealed),
+        _ => panic!("Expected AlreadyRevealed error"),
+    }
+
+    match client.try_reveal_stake(&session_id, &player2, &5_000, &BytesN::from_array(&env, &[0xb2; 32])) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidStakeRange),
+        _ => panic!("Expected InvalidStakeRange error"),
+    }
+
+    // Nothing to reveal in an ordinary game
+    client.start_game(&85, &player1, &player2, &100, &100);
+    play_tactics(&client, 85, &player1, &player2, 1, 2);
+    match client.try_reveal_stake(&85, &player1, &100, &salt1) {
+        Err(Ok(err)) => assert_eq!(err, Error::StakesNotHidden),
+        _ => panic!("Expected StakesNotHidden error"),
+    }
+}
+
+#[test]
+fn test_hidden_stake_game_rejects_bad_setup() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 86u32;
+    let stake1 = hidden_stake(&env, 100, 0xa1, session_id);
+    let stake2 = hidden_stake(&env, 100, 0xb2, session_id);
+
+    for (min, max) in [(-1, 100), (200, 100)] {
+        match client.try_start_hidden_stake_game(&session_id, &player1, &player2, &min, &max, &stake1, &stake2) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidStakeRange),
+            _ => panic!("Expected InvalidStakeRange error"),
+        }
+    }
+
+    let unopenable = StakeCommitment {
+        stake_hash: BytesN::from_array(&env, &[0xff; 32]),
+        proof: stake2.proof.clone(),
+    };
+    match client.try_start_hidden_stake_game(&session_id, &player1, &player2, &10, &1_000, &stake1, &unopenable) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidCommitment),
+        _ => panic!("Expected InvalidCommitment error"),
+    }
+
+    let unproven = StakeCommitment {
+        stake_hash: stake2.stake_hash.clone(),
+        proof: short_proof(&env),
+    };
+    match client.try_start_hidden_stake_game(&session_id, &player1, &player2, &10, &1_000, &stake1, &unproven) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(client.try_get_game(&session_id).is_err());
+
+    // Reuse is caught here, both ways, before the hub is asked
+    client.start_hidden_stake_game(&session_id, &player1, &player2, &10, &1_000, &stake1, &stake2);
+    match client.try_start_hidden_stake_game(&session_id, &player1, &player2, &10, &1_000, &stake1, &stake2) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameAlreadyExists),
+        _ => panic!("Expected GameAlreadyExists error"),
+    }
+    match client.try_start_game(&session_id, &player1, &player2, &100, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameAlreadyExists),
+        _ => panic!("Expected GameAlreadyExists error"),
+    }
+
+    client.start_game(&87, &player1, &player2, &100, &100);
+    match client.try_start_hidden_stake_game(&87, &player1, &player2, &10, &1_000, &stake1, &stake2) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameAlreadyExists),
+        _ => panic!("Expected GameAlreadyExists error"),
+    }
+}
+
+#[test]
+fn test_stake_verifier_checks_range_proofs() {
+    let (env, client, player1, player2) = setup_test();
+
+    let verifier_addr = env.register(MockVerifier, (true,));
+    client.set_stake_verifier(&verifier_addr);
+    assert_eq!(client.get_stake_verifier(), Some(verifier_addr.clone()));
+    let verifier = MockVerifierClient::new(&env, &verifier_addr);
+
+    let session_id = 88u32;
+    let stake1 = hidden_stake(&env, 100, 0xa1, session_id);
+    let stake2 = hidden_stake(&env, 5_000, 0xb2, session_id);
+    verifier.set_proof(&stake2.proof, &false);
+
+    match client.try_start_hidden_stake_game(&session_id, &player1, &player2, &10, &1_000, &stake1, &stake2) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+
+    let stake2 = hidden_stake(&env, 900, 0xb3, session_id);
+    client.start_hidden_stake_game(&session_id, &player1, &player2, &10, &1_000, &stake1, &stake2);
+    // One check per player (the rejected attempt was rolled back)
+    assert_eq!(verifier.calls(), 2);
+}
+
+// ============================================================================
+// Simulated Match Tests
+// ============================================================================
+
+/// Start a game scored by the simulation with image ID `[7; 32]` (new games
+/// use the matrix again afterwards)
+fn start_simulated(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address) {
+    client.set_simulation_image_id(&Some(BytesN::from_array(&client.env, &[7; 32])));
+    client.start_game(&session_id, player1, player2, &100_0000000, &100_0000000);
+    client.set_simulation_image_id(&None);
+}
+
+/// Journal of a simulation run: `session_id | tactic1 | tactic2 | score1 | score2`
+fn journal(env: &Env, session_id: u32, tactics: (u32, u32), scores: (u32, u32)) -> Bytes {
+    let mut journal = Bytes::new(env);
+    for word in [session_id, tactics.0, tactics.1, scores.0, scores.1] {
+        journal.append(&Bytes::from_array(env, &word.to_be_bytes()));
+    }
+    journal
+}
+
+#[test]
+fn test_simulated_game_scored_by_receipt() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 90u32;
+    start_simulated(&client, session_id, &player1, &player2);
+    assert_eq!(client.get_game_public(&session_id).game.simulation_image_id, Some(BytesN::from_array(&env, &[7; 32])));
+    assert_eq!(client.get_simulation_image_id(), None);
+
+    // The matrix would have Balanced (1) beat Defensive (0)...
+    play_tactics(&client, session_id, &player1, &player2, 1, 0);
+    match client.try_resolve_match(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::ReceiptRequired),
+        _ => panic!("Expected ReceiptRequired error"),
+    }
+
+    // ...but the simulation decides
+    let seal = mock_proof(&env, 0, session_id);
+    let outcome = client.resolve_with_receipt(&session_id, &journal(&env, session_id, (1, 0), (1, 5)), &seal);
+    assert_eq!(outcome, Outcome::Player2Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_score, Some(1));
+    assert_eq!(game.player2_score, Some(5));
+}
+
+#[test]
+fn test_receipt_must_match_game() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 91u32;
+    start_simulated(&client, session_id, &player1, &player2);
+    play_tactics(&client, session_id, &player1, &player2, 2, 3);
+
+    let seal = mock_proof(&env, 0, session_id);
+    let mut truncated = journal(&env, session_id, (2, 3), (4, 1));
+    truncated.pop_back();
+    for bad in [
+        journal(&env, 92, (2, 3), (4, 1)), // Another session
+        journal(&env, session_id, (3, 2), (4, 1)), // Other tactics
+        truncated,
+    ] {
+        match client.try_resolve_with_receipt(&session_id, &bad, &seal) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidReceipt),
+            _ => panic!("Expected InvalidReceipt error"),
+        }
+    }
+    assert_eq!(client.resolve_with_receipt(&session_id, &journal(&env, session_id, (2, 3), (4, 1)), &seal), Outcome::Player1Win);
+
+    // Matrix-scored games take no receipt
+    let session_id = 92u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, session_id, &player1, &player2, 2, 3);
+    match client.try_resolve_with_receipt(&session_id, &journal(&env, session_id, (2, 3), (4, 1)), &seal) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReceipt),
+        _ => panic!("Expected InvalidReceipt error"),
+    }
+}
+
+#[test]
+fn test_receipt_verifier_checks_seal() {
+    let (env, client, player1, player2) = setup_test();
+
+    let verifier_addr = env.register(MockVerifier, (true,));
+    client.set_receipt_verifier(&verifier_addr);
+    assert_eq!(client.get_receipt_verifier(), Some(verifier_addr.clone()));
+    let verifier = MockVerifierClient::new(&env, &verifier_addr);
+
+    let session_id = 93u32;
+    start_simulated(&client, session_id, &player1, &player2);
+    play_tactics(&client, session_id, &player1, &player2, 0, 0);
+
+    let journal = journal(&env, session_id, (0, 0), (0, 2));
+    let bad_seal = mock_proof(&env, 1, session_id);
+    verifier.set_proof(&bad_seal, &false);
+    match client.try_resolve_with_receipt(&session_id, &journal, &bad_seal) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReceipt),
+        _ => panic!("Expected InvalidReceipt error"),
+    }
+
+    assert_eq!(client.resolve_with_receipt(&session_id, &journal, &mock_proof(&env, 0, session_id)), Outcome::Player2Win);
+    let digest: BytesN<32> = env.crypto().sha256(&journal).into();
+    assert_eq!(verifier.last_journal_digest(), Some(digest));
+}
+
+// ============================================================================
+// Committed Strategy Tests
+// ============================================================================
+
+/// Strategy tree leaves for `tactics`, salted like `round_secret`
+fn strategy_leaves(env: &Env, tactics: &[u32], salt: u8, session_id: u32) -> Vec<BytesN<32>> {
+    let mut leaves = Vec::new(env);
+    for (round, tactic) in tactics.iter().enumerate() {
+        leaves.push_back(tactic_hash(env, *tactic, &round_secret(env, salt, round as u32), session_id));
+    }
+    leaves
+}
+
+fn start_committed(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address, tactics1: &[u32], tactics2: &[u32]) {
+    let env = &client.env;
+    let root1 = strategy_root(env, &strategy_leaves(env, tactics1, 0xa1, session_id));
+    let root2 = strategy_root(env, &strategy_leaves(env, tactics2, 0xb2, session_id));
+    let rounds = tactics1.len() as u32;
+    client.start_committed_multi_round_game(&session_id, player1, player2, &100_0000000, &100_0000000, &rounds, &root1, &root2);
+}
+
+/// Open `round` of `player`'s strategy
+fn reveal_committed(client: &ZkTacticalMatchContractClient, session_id: u32, player: &Address, tactics: &[u32], salt: u8, round: u32) {
+    let env = &client.env;
+    let proof = strategy_proof(env, &strategy_leaves(env, tactics, salt, session_id), round);
+    client.reveal_round(&session_id, player, &round, &tactics[round as usize], &round_secret(env, salt, round), &proof);
+}
+
+#[test]
+fn test_committed_strategy_resolves_round_by_round() {
+    let (env, client, player1, player2) = setup_test();
+
+    // Five rounds, so the tree is padded to eight leaves
+    let session_id = 150u32;
+    let tactics1 = [1, 2, 0, 3, 0];
+    let tactics2 = [0, 1, 1, 3, 2];
+    start_committed(&client, session_id, &player1, &player2, &tactics1, &tactics2);
+    let multi_round = client.get_multi_round(&session_id).unwrap();
+    assert!(multi_round.strategy_committed);
+    assert_eq!(client.get_game_public(&session_id).game.player1_tactic_hash, Some(strategy_root(&env, &strategy_leaves(&env, &tactics1, 0xa1, session_id))));
+
+    for round in 0..5 {
+        match client.try_resolve_match(&session_id) {
+            Err(Ok(err)) => assert_eq!(err, Error::TacticNotRevealed),
+            _ => panic!("Expected TacticNotRevealed error"),
+        }
+        reveal_committed(&client, session_id, &player1, &tactics1, 0xa1, round);
+        reveal_committed(&client, session_id, &player2, &tactics2, 0xb2, round);
+    }
+
+    // Rounds: player1, player1, player2, draw (4-4), draw (1-1)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_score, Some(2));
+    assert_eq!(game.player2_score, Some(1));
+    let multi_round = client.get_multi_round(&session_id).unwrap();
+    assert_eq!(multi_round.player2_tactics, Some(Vec::from_slice(&env, &tactics2)));
+}
+
+#[test]
+fn test_committed_round_must_open_root() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 151u32;
+    let tactics1 = [2, 2, 2];
+    start_committed(&client, session_id, &player1, &player2, &tactics1, &[0, 0, 0]);
+    let leaves = strategy_leaves(&env, &tactics1, 0xa1, session_id);
+    let secret = round_secret(&env, 0xa1, 0);
+
+    // Another tactic, another round's path, or the opponent's tree
+    let bad_reveals = [
+        (3, strategy_proof(&env, &leaves, 0)),
+        (2, strategy_proof(&env, &leaves, 1)),
+        (2, strategy_proof(&env, &strategy_leaves(&env, &[0, 0, 0], 0xb2, session_id), 0)),
+    ];
+    match client.try_reveal_round(&session_id, &player1, &0, &4, &secret, &strategy_proof(&env, &leaves, 0)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidTactic),
+        _ => panic!("Expected InvalidTactic error"),
+    }
+    for (tactic, proof) in bad_reveals {
+        match client.try_reveal_round(&session_id, &player1, &0, &tactic, &secret, &proof) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+            _ => panic!("Expected InvalidReveal error"),
+        }
+    }
+
+    // Rounds open in order, once each
+    match client.try_reveal_round(&session_id, &player1, &1, &2, &round_secret(&env, 0xa1, 1), &strategy_proof(&env, &leaves, 1)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidRoundCount),
+        _ => panic!("Expected InvalidRoundCount error"),
+    }
+    reveal_committed(&client, session_id, &player1, &tactics1, 0xa1, 0);
+    match client.try_reveal_round(&session_id, &player1, &0, &2, &secret, &strategy_proof(&env, &leaves, 0)) {
+        Err(Ok(err)) => assert_eq!(err, Error::AlreadyRevealed),
+        _ => panic!("Expected AlreadyRevealed error"),
+    }
+}
+
+#[test]
+fn test_committed_strategy_mode_must_match_game() {
+    let (env, client, player1, player2) = setup_test();
+
+    // Identical roots would let one player echo the other's reveals
+    let root = strategy_root(&env, &strategy_leaves(&env, &[1, 1], 0xa1, 152));
+    match client.try_start_committed_multi_round_game(&152, &player1, &player2, &100_0000000, &100_0000000, &2, &root, &root) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidCommitment),
+        _ => panic!("Expected InvalidCommitment error"),
+    }
+
+    // A committed game takes no round proofs (its roots were the
+    // submissions)...
+    let session_id = 153u32;
+    start_committed(&client, session_id, &player1, &player2, &[1, 1], &[2, 2]);
+    let inputs = round_inputs(&client, &player1, session_id, &[1, 1], 0xa1);
+    match client.try_submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+    match client.try_reveal_rounds(&session_id, &player1, &Vec::from_slice(&env, &[1, 1]), &round_secrets(&env, 0xa1, 2)) {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+
+    // ...and a proven one cannot be opened round by round
+    let session_id = 154u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &2);
+    for (player, salt) in [(&player1, 0xa1), (&player2, 0xb2)] {
+        let inputs = round_inputs(&client, player, session_id, &[1, 1], salt);
+        client.submit_rounds(&session_id, player, &inputs, &mock_player_proof(&env, player, 0, session_id));
+    }
+    let leaves = strategy_leaves(&env, &[1, 1], 0xa1, session_id);
+    match client.try_reveal_round(&session_id, &player1, &0, &1, &round_secret(&env, 0xa1, 0), &strategy_proof(&env, &leaves, 0)) {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+}
+
+// ============================================================================
+// Tiebreak Tests
+// ============================================================================
+
+#[test]
+fn test_random_tiebreak_is_fixed_per_game() {
+    let (_env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_tiebreak_policy(), TiebreakPolicy::Draw);
+
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Random);
+    client.start_game(&100, &player1, &player2, &100_0000000, &100_0000000);
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Player1);
+    client.start_game(&101, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert_eq!(client.get_game_public(&100).game.tiebreak, TiebreakPolicy::Random);
+    assert_eq!(client.get_game_public(&101).game.tiebreak, TiebreakPolicy::Player1);
+
+    // Only draws are affected: Balanced (1) still beats Defensive (0)
+    play_tactics(&client, 100, &player1, &player2, 0, 1);
+    assert_eq!(client.resolve_match(&100), Outcome::Player2Win);
+}
+
+#[test]
+fn test_draw_is_reported_to_hub() {
+    let (env, client, player1, player2) = setup_test();
+    let hub_addr = env.register(MockGameHub, ());
+    let hub = MockGameHubClient::new(&env, &hub_addr);
+    client.set_hub(&hub_addr);
+
+    // Balanced vs Balanced (1-1) under the default policy
+    client.start_game(&102, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 102, &player1, &player2, 1, 1);
+    assert_eq!(client.resolve_match(&102), Outcome::Draw);
+    assert_eq!(client.get_game(&102).outcome(), Some(Outcome::Draw));
+    assert_eq!(hub.outcome(&102), Some(HubOutcome::Draw));
+
+    // The original rule is still available
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Player1);
+    client.start_game(&103, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 103, &player1, &player2, 1, 1);
+    assert_eq!(client.resolve_match(&103), Outcome::Player1Win);
+    assert_eq!(hub.outcome(&103), Some(HubOutcome::Player1Win));
+}
+
+#[test]
+fn test_wins_reach_hubs_without_draw_support() {
+    let (env, client, player1, player2) = setup_test();
+    let hub_addr = env.register(LegacyGameHub, ());
+    let hub = LegacyGameHubClient::new(&env, &hub_addr);
+    client.set_hub(&hub_addr);
+
+    // Balanced (1) beats Defensive (0)
+    client.start_game(&104, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 104, &player1, &player2, 0, 1);
+    assert_eq!(client.resolve_match(&104), Outcome::Player2Win);
+    assert_eq!(hub.player1_won(&104), Some(false));
+
+    // Only a draw needs `end_game_with_outcome`; the game still settles
+    // but the hub never hears of it
+    client.start_game(&105, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 105, &player1, &player2, 1, 1);
+    assert_eq!(client.resolve_match(&105), Outcome::Draw);
+    assert!(!client.get_game(&105).hub_notified);
+    assert!(client.try_retry_hub_notify(&105).is_err());
+}
+
+#[test]
+fn test_random_tiebreak_can_go_either_way() {
+    let (_env, client, player1, player2) = setup_test();
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Random);
+
+    // Both draw Balanced vs Balanced; the secrets differ per session
+    let mut player1_wins = 0;
+    for session_id in 110..126u32 {
+        client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+        play_tactics(&client, session_id, &player1, &player2, 1, 1);
+        if client.resolve_match(&session_id) == Outcome::Player1Win {
+            player1_wins += 1;
+        }
+        let game = client.get_game(&session_id);
+        assert_eq!(game.player1_score, game.player2_score);
+    }
+    assert!(player1_wins > 0 && player1_wins < 16, "player1 won {} of 16 draws", player1_wins);
+}
+
+#[test]
+fn test_random_tiebreak_ignores_reveal_order() {
+    let (env, client, player1, player2) = setup_test();
+    let hub_addr = env.register(MockGameHub, ());
+    let other_id = env.register(ZkTacticalMatchContract, (&Address::generate(&env), &hub_addr));
+    let other = ZkTacticalMatchContractClient::new(&env, &other_id);
+
+    // The same draw in two deployments, revealed in opposite orders
+    let session_id = 130u32;
+    let winners = [(&client, true), (&other, false)].map(|(client, player1_first)| {
+        client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Random);
+        start_commit_reveal(client, session_id, &player1, &player2);
+        client.commit_tactic(&session_id, &player1, &salted_hash(&env, 3, 0xa1, session_id));
+        client.commit_tactic(&session_id, &player2, &salted_hash(&env, 3, 0xb2, session_id));
+
+        let reveal_p1 = || client.reveal_tactic(&session_id, &player1, &3, &BytesN::from_array(&env, &[0xa1; 32]));
+        let reveal_p2 = || client.reveal_tactic(&session_id, &player2, &3, &BytesN::from_array(&env, &[0xb2; 32]));
+        if player1_first {
+            reveal_p1();
+            reveal_p2();
+        } else {
+            reveal_p2();
+            reveal_p1();
+        }
+        client.resolve_match(&session_id)
+    });
+    assert_eq!(winners[0], winners[1]);
+}
+
+// ============================================================================
+// Multi-Round Tests
+// ============================================================================
+
+/// Secret of the inner proof for `round`; `salt` tells players apart
+fn round_secret(env: &Env, salt: u8, round: u32) -> BytesN<32> {
+    let mut bytes = [salt; 32];
+    bytes[0] = 0;
+    bytes[31] = round as u8;
+    BytesN::from_array(env, &bytes)
+}
+
+fn round_secrets(env: &Env, salt: u8, rounds: u32) -> Vec<BytesN<32>> {
+    let mut secrets = Vec::new(env);
+    for round in 0..rounds {
+        secrets.push_back(round_secret(env, salt, round));
+    }
+    secrets
+}
+
+/// Public inputs of a recursive proof over `tactics`, one per round
+fn round_inputs(client: &ZkTacticalMatchContractClient, player: &Address, session_id: u32, tactics: &[u32], salt: u8) -> RoundInputs {
+    let env = &client.env;
+    let mut tactic_hashes = Vec::new(env);
+    for (round, tactic) in tactics.iter().enumerate() {
+        tactic_hashes.push_back(tactic_hash(env, *tactic, &round_secret(env, salt, round as u32), session_id));
+    }
+    RoundInputs {
+        session_id,
+        player_binding: player_binding(env, &client.address, player),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(env, 0, session_id),
+        nullifier: mock_nullifier(env, player, session_id),
+        tactic_hashes,
+    }
+}
+
+/// Submit and reveal every round for both players
+fn play_rounds(client: &ZkTacticalMatchContractClient, session_id: u32, player1: &Address, player2: &Address, tactics1: &[u32], tactics2: &[u32]) {
+    let env = &client.env;
+    client.submit_rounds(&session_id, player1, &round_inputs(client, player1, session_id, tactics1, 0xa1), &mock_player_proof(env, player1, 0, session_id));
+    client.submit_rounds(&session_id, player2, &round_inputs(client, player2, session_id, tactics2, 0xb2), &mock_player_proof(env, player2, 0, session_id));
+    let rounds = tactics1.len() as u32;
+    client.reveal_rounds(&session_id, player1, &Vec::from_slice(env, tactics1), &round_secrets(env, 0xa1, rounds));
+    client.reveal_rounds(&session_id, player2, &Vec::from_slice(env, tactics2), &round_secrets(env, 0xb2, rounds));
+}
+
+#[test]
+fn test_best_of_three_resolves_by_rounds_won() {
+    let (env, client, player1, player2) = setup_test();
+
+    let verifier_addr = env.register(MockVerifier, (true,));
+    client.set_rounds_verifier(&verifier_addr);
+    assert_eq!(client.get_rounds_verifier(), Some(verifier_addr.clone()));
+    let verifier = MockVerifierClient::new(&env, &verifier_addr);
+
+    let session_id = 140u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &3);
+
+    // One proof per player, unpacked into one tactic hash per round
+    let inputs = round_inputs(&client, &player1, session_id, &[1, 2, 0], 0xa1);
+    client.submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id));
+    let fields = verifier.last_fields().unwrap();
+    assert_eq!(fields.len(), 5 + 3);
+    assert_eq!(fields.slice(5..), inputs.tactic_hashes);
+    assert_eq!(verifier.calls(), 1);
+
+    let inputs = round_inputs(&client, &player2, session_id, &[0, 1, 1], 0xb2);
+    client.submit_rounds(&session_id, &player2, &inputs, &mock_player_proof(&env, &player2, 0, session_id));
+    client.reveal_rounds(&session_id, &player1, &Vec::from_slice(&env, &[1, 2, 0]), &round_secrets(&env, 0xa1, 3));
+    client.reveal_rounds(&session_id, &player2, &Vec::from_slice(&env, &[0, 1, 1]), &round_secrets(&env, 0xb2, 3));
+
+    // Balanced beats Defensive, Aggressive beats Balanced, then Defensive
+    // loses to Balanced: player1 takes two rounds of three
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_score, Some(2));
+    assert_eq!(game.player2_score, Some(1));
+    assert_eq!(game.player1_tactic, None);
+    let multi_round = client.get_multi_round(&session_id).unwrap();
+    assert_eq!(multi_round.rounds, 3);
+    assert_eq!(multi_round.player2_tactics, Some(Vec::from_slice(&env, &[0, 1, 1])));
+    assert_eq!(client.get_multi_round(&(session_id + 1)), None);
+}
+
+#[test]
+fn test_drawn_rounds_count_for_neither() {
+    let (_env, client, player1, player2) = setup_test();
+
+    let session_id = 141u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &3);
+
+    // One round each, then Aggressive vs Aggressive (2-2)
+    play_rounds(&client, session_id, &player1, &player2, &[1, 0, 2], &[0, 1, 2]);
+    assert_eq!(client.resolve_match(&session_id), Outcome::Draw);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_score, Some(1));
+    assert_eq!(game.player2_score, Some(1));
+}
+
+#[test]
+fn test_round_count_is_checked() {
+    let (env, client, player1, player2) = setup_test();
+
+    for rounds in [0, MAX_ROUNDS + 1] {
+        match client.try_start_multi_round_game(&142, &player1, &player2, &100_0000000, &100_0000000, &rounds) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidRoundCount),
+            _ => panic!("Expected InvalidRoundCount error"),
+        }
+    }
+
+    let session_id = 143u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &3);
+    let inputs = round_inputs(&client, &player1, session_id, &[1, 2], 0xa1);
+    match client.try_submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidRoundCount),
+        _ => panic!("Expected InvalidRoundCount error"),
+    }
+
+    let inputs = round_inputs(&client, &player1, session_id, &[1, 2, 3], 0xa1);
+    client.submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id));
+    let inputs = round_inputs(&client, &player2, session_id, &[3, 2, 1], 0xb2);
+    client.submit_rounds(&session_id, &player2, &inputs, &mock_player_proof(&env, &player2, 0, session_id));
+    match client.try_reveal_rounds(&session_id, &player1, &Vec::from_slice(&env, &[1, 2]), &round_secrets(&env, 0xa1, 2)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidRoundCount),
+        _ => panic!("Expected InvalidRoundCount error"),
+    }
+}
+
+#[test]
+fn test_round_reveals_must_open_hashes() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 144u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &2);
+    let inputs = round_inputs(&client, &player1, session_id, &[3, 0], 0xa1);
+    client.submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id));
+
+    let tactics = Vec::from_slice(&env, &[3, 0]);
+    match client.try_reveal_rounds(&session_id, &player1, &tactics, &round_secrets(&env, 0xa1, 2)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+
+    let inputs = round_inputs(&client, &player2, session_id, &[1, 1], 0xb2);
+    client.submit_rounds(&session_id, &player2, &inputs, &mock_player_proof(&env, &player2, 0, session_id));
+    match client.try_resolve_match(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::TacticNotRevealed),
+        _ => panic!("Expected TacticNotRevealed error"),
+    }
+
+    // Swapping two rounds does not open the hashes
+    match client.try_reveal_rounds(&session_id, &player1, &Vec::from_slice(&env, &[0, 3]), &round_secrets(&env, 0xa1, 2)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReveal),
+        _ => panic!("Expected InvalidReveal error"),
+    }
+
+    client.reveal_rounds(&session_id, &player1, &tactics, &round_secrets(&env, 0xa1, 2));
+    match client.try_reveal_rounds(&session_id, &player1, &tactics, &round_secrets(&env, 0xa1, 2)) {
+        Err(Ok(err)) => assert_eq!(err, Error::AlreadyRevealed),
+        _ => panic!("Expected AlreadyRevealed error"),
+    }
+}
+
+#[test]
+fn test_multi_round_submission_mode_must_match_game() {
+    let (env, client, player1, player2) = setup_test();
+
+    // Single-round entrypoints refuse a multi-round game...
+    let session_id = 145u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &3);
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &mock_proof(&env, 1, session_id));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+    for (player, salt) in [(&player1, 0xa1), (&player2, 0xb2)] {
+        let inputs = round_inputs(&client, player, session_id, &[1, 2, 0], salt);
+        client.submit_rounds(&session_id, player, &inputs, &mock_player_proof(&env, player, 0, session_id));
+    }
+    match client.try_reveal_tactic(&session_id, &player1, &1, &mock_secret(&env, &player1, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+
+    // ...and round proofs need a multi-round game
+    let session_id = 146u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let inputs = round_inputs(&client, &player1, session_id, &[1], 0xa1);
+    match client.try_submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotMultiRound),
+        _ => panic!("Expected NotMultiRound error"),
+    }
+}
+
+#[test]
+fn test_rejected_round_proof_stores_nothing() {
+    let (env, client, player1, player2) = setup_test();
+
+    let verifier_addr = env.register(MockVerifier, (false,));
+    client.set_rounds_verifier(&verifier_addr);
+
+    let session_id = 147u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &3);
+    let inputs = round_inputs(&client, &player1, session_id, &[0, 1, 2], 0xa1);
+    match client.try_submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(!client.is_nullifier_used(&inputs.nullifier));
+    assert_eq!(client.get_game_public(&session_id).game.player1_tactic_hash, None);
+}
+
+// ============================================================================
+// Test Vector Tests
+// ============================================================================
+
+#[test]
+fn test_stored_commitment_matches_submission() {
+    let (env, client, player1, player2) = setup_test();
+
+    for (i, vector) in PROOF_HASH_VECTORS.iter().enumerate() {
+        let session_id = 100 + i as u32;
+        client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+        let inputs = mock_inputs(&client, &player1, session_id, vector.tactic);
+        let proof = mock_proof(&env, vector.tactic, vector.session_id);
+        client.submit_tactic(&session_id, &player1, &inputs, &proof);
+
+        let game = client.get_game_public(&session_id).game;
+        assert_eq!(game.player1_commitment, Some(inputs.commitment));
+        assert_eq!(game.player2_commitment, None);
+    }
+}
+
+#[test]
+fn test_commitment_must_be_field_element() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 13u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // 0xff.. is above the BN254 scalar modulus, so no circuit can output it
+    let inputs = PublicInputs {
+        commitment: BytesN::from_array(&env, &[0xff; 32]),
+        ..mock_inputs(&client, &player1, session_id, 1)
+    };
+    let proof = mock_proof(&env, 1, session_id);
+    let result = client.try_submit_tactic(&session_id, &player1, &inputs, &proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidCommitment),
+        _ => panic!("Expected InvalidCommitment error"),
+    }
+}
+
+#[test]
+fn test_tactic_hash_must_be_field_element() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 14u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = PublicInputs {
+        tactic_hash: BytesN::from_array(&env, &[0xff; 32]),
+        ..mock_inputs(&client, &player1, session_id, 1)
+    };
+    let proof = mock_proof(&env, 1, session_id);
+    let result = client.try_submit_tactic(&session_id, &player1, &inputs, &proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+}
+
+#[test]
+fn test_score_views_match_settlement() {
+    let (_env, client, player1, player2) = setup_test();
+
+    let matrix = client.get_score_matrix();
+    assert_eq!(matrix.len(), 16);
+    assert_eq!(
+        matrix.get(7).unwrap(),
+        ScoreEntry { tactic1: 1, tactic2: 3, score1: 2, score2: 3 }
+    );
+    for entry in matrix.iter() {
+        assert_eq!(
+            client.simulate_score(&entry.tactic1, &entry.tactic2),
+            (entry.score1, entry.score2)
+        );
+    }
+
+    // What a real game settles with
+    client.start_game(&15, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 15, &player1, &player2, 3, 1);
+    client.resolve_match(&15);
+    let game = client.get_game(&15);
+    assert_eq!((game.player1_score.unwrap(), game.player2_score.unwrap()), client.simulate_score(&3, &1));
+
+    for (tactic1, tactic2) in [(4, 0), (0, 4)] {
+        match client.try_simulate_score(&tactic1, &tactic2) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidTactic),
+            _ => panic!("Expected InvalidTactic error"),
+        }
+    }
+}
+
+// ============================================================================
+// Nullifier Tests
+// ============================================================================
+
+#[test]
+fn test_nullifier_is_consumed() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let nullifier = mock_nullifier(&env, &player1, session_id);
+    assert!(!client.is_nullifier_used(&nullifier));
+
+    let proof = mock_proof(&env, 2, session_id);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 2), &proof);
+    assert!(client.is_nullifier_used(&nullifier));
+}
+
+#[test]
+fn test_nullifier_cannot_be_reused_across_sessions() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&5, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&6, &player1, &player2, &100_0000000, &100_0000000);
+
+    let first = mock_inputs(&client, &player1, 5, 2);
+    client.submit_tactic(&5, &player1, &first, &mock_proof(&env, 2, 5));
+
+    let replay = PublicInputs {
+        nullifier: first.nullifier,
+        ..mock_inputs(&client, &player1, 6, 2)
+    };
+    let result = client.try_submit_tactic(&6, &player1, &replay, &mock_proof(&env, 2, 6));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::NullifierUsed),
+        _ => panic!("Expected NullifierUsed error"),
+    }
+    assert!(client.get_game_public(&6).game.player1_commitment.is_none());
+}
+
+#[test]
+fn test_nullifier_must_be_field_element() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let nullifier = BytesN::from_array(&env, &[0xff; 32]);
+    let inputs = PublicInputs {
+        nullifier: nullifier.clone(),
+        ..mock_inputs(&client, &player1, session_id, 1)
+    };
+    let proof = mock_proof(&env, 1, session_id);
+    let result = client.try_submit_tactic(&session_id, &player1, &inputs, &proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(!client.is_nullifier_used(&nullifier));
+}
+
+// ============================================================================
+// Proof Binding Tests
+// ============================================================================
+
+#[test]
+fn test_inputs_for_other_session_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&40, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&41, &player1, &player2, &100_0000000, &100_0000000);
+
+    // A proof made for session 40, submitted to session 41
+    let inputs = mock_inputs(&client, &player1, 40, 2);
+    let result = client.try_submit_tactic(&41, &player1, &inputs, &mock_proof(&env, 2, 40));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofSessionMismatch),
+        _ => panic!("Expected ProofSessionMismatch error"),
+    }
+    assert!(client.get_game_public(&41).game.player1_commitment.is_none());
+}
+
+#[test]
+fn test_inputs_for_other_player_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = mock_inputs(&client, &player1, session_id, 2);
+    let result = client.try_submit_tactic(&session_id, &player2, &inputs, &mock_proof(&env, 2, session_id));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofPlayerMismatch),
+        _ => panic!("Expected ProofPlayerMismatch error"),
+    }
+    assert!(!client.is_nullifier_used(&inputs.nullifier));
+}
+
+#[test]
+fn test_inputs_for_other_contract_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Right player, but bound to another deployment
+    let other_contract = Address::generate(&env);
+    let inputs = PublicInputs {
+        player_binding: player_binding(&env, &other_contract, &player1),
+        ..mock_inputs(&client, &player1, session_id, 2)
+    };
+    let result = client.try_submit_tactic(&session_id, &player1, &inputs, &mock_proof(&env, 2, session_id));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofPlayerMismatch),
+        _ => panic!("Expected ProofPlayerMismatch error"),
+    }
+}
+
+#[test]
+fn test_player_binding_is_per_player_and_contract() {
+    let (env, client, player1, player2) = setup_test();
+
+    let binding = client.get_player_binding(&player1);
+    assert_eq!(binding, player_binding(&env, &client.address, &player1));
+    assert_ne!(binding, client.get_player_binding(&player2));
+    assert_ne!(binding, player_binding(&env, &Address::generate(&env), &player1));
+
+    // Always a BN254 field element
+    assert_eq!(binding.to_array()[0], 0);
+}
+
+// ============================================================================
+// Proof Freshness Tests
+// ============================================================================
+
+#[test]
+fn test_expired_proof_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 44u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let now = env.ledger().sequence();
+    let inputs1 = PublicInputs { valid_until_ledger: now + 10, ..mock_inputs(&client, &player1, session_id, 2) };
+    let inputs2 = PublicInputs { valid_until_ledger: now + 10, ..mock_inputs(&client, &player2, session_id, 1) };
+
+    // Still valid in its last ledger
+    env.ledger().set_sequence_number(now + 10);
+    client.submit_tactic(&session_id, &player1, &inputs1, &mock_proof(&env, 2, session_id));
+
+    env.ledger().set_sequence_number(now + 11);
+    let result = client.try_submit_tactic(&session_id, &player2, &inputs2, &mock_proof(&env, 1, session_id));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofExpired),
+        _ => panic!("Expected ProofExpired error"),
+    }
+    assert!(!client.is_nullifier_used(&inputs2.nullifier));
+}
+
+#[test]
+fn test_freshness_window_limits_validity() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_proof_freshness_window(&Some(100));
+
+    let session_id = 45u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let now = env.ledger().sequence();
+    let proof = mock_proof(&env, 2, session_id);
+    let inputs = mock_inputs(&client, &player1, session_id, 2);
+
+    // No expiry, or one beyond the window, is rejected
+    for valid_until_ledger in [0, now + 101] {
+        let stale = PublicInputs { valid_until_ledger, ..inputs.clone() };
+        match client.try_submit_tactic(&session_id, &player1, &stale, &proof) {
+            Err(Ok(err)) => assert_eq!(err, Error::ProofValidityTooLong),
+            _ => panic!("Expected ProofValidityTooLong error"),
+        }
+    }
+
+    let fresh = PublicInputs { valid_until_ledger: now + 100, ..inputs };
+    client.submit_tactic(&session_id, &player1, &fresh, &proof);
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_some());
+}
+
+#[test]
+fn test_freshness_window_round_trip() {
+    let (_env, client, _player1, _player2) = setup_test();
+    assert_eq!(client.get_proof_freshness_window(), None);
+
+    client.set_proof_freshness_window(&Some(720));
+    assert_eq!(client.get_proof_freshness_window(), Some(720));
+
+    client.set_proof_freshness_window(&None);
+    assert_eq!(client.get_proof_freshness_window(), None);
+}
+
+// ============================================================================
+// External Verifier Tests
+// ============================================================================
+
+fn setup_with_verifier(
+    default_accept: bool,
+) -> (
+    Env,
+    ZkTacticalMatchContractClient<'static>,
+    MockVerifierClient<'static>,
+    Address,
+    Address,
+) {
+    let (env, client, player1, player2) = setup_test();
+
+    let verifier_addr = env.register(MockVerifier, (default_accept,));
+    client.set_verifier(&verifier_addr);
+    let verifier = MockVerifierClient::new(&env, &verifier_addr);
+
+    (env, client, verifier, player1, player2)
+}
+
+#[test]
+fn test_verifier_accepts_proof() {
+    let (env, client, verifier, player1, player2) = setup_with_verifier(true);
+    assert_eq!(client.get_verifier(), Some(verifier.address.clone()));
+
+    let session_id = 20u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let proof = mock_proof(&env, 1, session_id);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &proof);
+
+    assert_eq!(verifier.calls(), 1);
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_some());
+}
+
+#[test]
+fn test_verifier_rejects_proof() {
+    let (env, client, verifier, player1, player2) = setup_with_verifier(true);
+
+    let session_id = 21u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let bad_proof = mock_proof(&env, 2, session_id);
+    verifier.set_proof(&bad_proof, &false);
+
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 2), &bad_proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_none());
+
+    // A different proof still gets the default verdict
+    let good_proof = mock_proof(&env, 1, session_id);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &good_proof);
+}
+
+#[test]
+fn test_format_checks_run_before_verifier() {
+    let (env, client, verifier, player1, player2) = setup_with_verifier(true);
+
+    let session_id = 22u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &empty_proof(&env));
+    assert!(result.is_err());
+    assert_eq!(verifier.calls(), 0);
+}
+
+// ============================================================================
+// Malicious Hub Tests
+// ============================================================================
+
+/// Assert a `try_` call failed with `VerifierNotConfigured`
+fn expect_unconfigured<T, E>(result: Result<T, Result<Error, E>>) {
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::VerifierNotConfigured),
+        _ => panic!("Expected VerifierNotConfigured error"),
+    }
+}
+
+#[test]
+fn test_proofs_need_a_configured_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+    let hub_addr = env.register(MockGameHub, ());
+    let contract_id = env.register(ZkTacticalMatchContract, (&Address::generate(&env), &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    let (player1, player2) = (Address::generate(&env), Address::generate(&env));
+    assert_eq!(client.get_verifier(), None);
+    assert_eq!(client.get_ultrahonk_verifier(), None);
+
+    // Neither a Groth16 key nor a verifier: well-formed proofs are refused
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+    expect_unconfigured(client.try_submit_tactic(&1, &player1, &mock_inputs(&client, &player1, 1, 0), &mock_player_proof(&env, &player1, 0, 1)));
+
+    client.set_proof_system(&ProofSystem::UltraHonk);
+    client.start_game(&2, &player1, &player2, &100_0000000, &100_0000000);
+    client.set_proof_system(&ProofSystem::Groth16);
+    expect_unconfigured(client.try_submit_tactic(&2, &player1, &mock_inputs(&client, &player1, 2, 3), &mock_proof(&env, 3, 2)));
+
+    client.start_multi_round_game(&3, &player1, &player2, &100_0000000, &100_0000000, &3);
+    expect_unconfigured(client.try_submit_rounds(&3, &player1, &round_inputs(&client, &player1, 3, &[1, 2, 0], 0xa1), &mock_player_proof(&env, &player1, 0, 3)));
+
+    expect_unconfigured(client.try_start_hidden_stake_game(&4, &player1, &player2, &10, &1_000, &hidden_stake(&env, 100, 0xa1, 4), &hidden_stake(&env, 100, 0xb2, 4)));
+
+    let mut root = [0x2a; 32];
+    root[0] = 0;
+    MockGameHubClient::new(&env, &hub_addr).set_ratings_root(&BytesN::from_array(&env, &root));
+    let bracket = RatingBracket { min_rating: 1200, max_rating: 1400 };
+    let (proof1, proof2) = (mock_player_proof(&env, &player1, 0, 5), mock_player_proof(&env, &player2, 0, 5));
+    expect_unconfigured(client.try_start_bracket_game(&5, &player1, &player2, &100_0000000, &100_0000000, &bracket, &proof1, &proof2));
+
+    // Batch-verified proofs are only checked at resolution
+    client.set_batch_verification(&true);
+    client.start_game(&6, &player1, &player2, &100_0000000, &100_0000000);
+    client.set_batch_verification(&false);
+    play_tactics(&client, 6, &player1, &player2, 2, 1);
+    expect_unconfigured(client.try_resolve_match(&6));
+
+    // With a proof verifier but no receipt verifier, receipts are refused
+    client.set_verifier(&env.register(MockVerifier, (true,)));
+    start_simulated(&client, 7, &player1, &player2);
+    play_tactics(&client, 7, &player1, &player2, 1, 0);
+    let seal = mock_proof(&env, 0, 7);
+    expect_unconfigured(client.try_resolve_with_receipt(&7, &journal(&env, 7, (1, 0), (1, 5)), &seal));
+}
+
+/// Submit and reveal Balanced (1) for player1 and Aggressive (2) for player2
+fn submit_both(
+    env: &Env,
+    client: &ZkTacticalMatchContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    let proof1 = mock_proof(env, 1, session_id);
+    let proof2 = mock_proof(env, 2, session_id);
+    client.submit_tactic(&session_id, player1, &mock_inputs(client, player1, session_id, 1), &proof1);
+    client.submit_tactic(&session_id, player2, &mock_inputs(client, player2, session_id, 2), &proof2);
+    mock_reveal(client, player1, session_id, 1);
+    mock_reveal(client, player2, session_id, 2);
+}
+
+#[test]
+fn test_honest_hub_settles_exactly_once() {
+    let (env, client, player1, player2) = setup_test();
+    let hub_addr = env.register(MaliciousGameHub, (HubAttack::Honest,));
+    let hub = MaliciousGameHubClient::new(&env, &hub_addr);
+    client.set_hub(&hub_addr);
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_both(&env, &client, session_id, &player1, &player2);
+
+    let outcome = client.resolve_match(&session_id);
+    assert_eq!(client.resolve_match(&session_id), outcome);
+    assert_eq!(hub.end_game_calls(), 1);
+}
+
+#[test]
+fn test_reentrant_end_game_cannot_double_settle() {
+    let (env, client, player1, player2) = setup_test();
+    let attack = HubAttack::ReenterOnEnd(Symbol::new(&env, "resolve_match"));
+    let hub_addr = env.register(MaliciousGameHub, (attack,));
+    let hub = MaliciousGameHubClient::new(&env, &hub_addr);
+    client.set_hub(&hub_addr);
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_both(&env, &client, session_id, &player1, &player2);
+
+    // Re-entry is rejected by the host, so the hub's call reverts; the
+    // match settles without it and resolving again does not call the hub
+    let outcome = client.resolve_match(&session_id);
+    assert_eq!(client.resolve_match(&session_id), outcome);
+    assert!(!client.get_game(&session_id).hub_notified);
+    assert_eq!(hub.end_game_calls(), 0);
+
+    // Once the hub stops attacking, it hears the result exactly once
+    hub.set_attack(&HubAttack::Honest);
+    client.retry_hub_notify(&session_id);
+    client.retry_hub_notify(&session_id);
+    assert!(client.get_game(&session_id).hub_notified);
+    assert_eq!(hub.end_game_calls(), 1);
+}
+
+#[test]
+fn test_reentrant_start_game_leaves_no_state() {
+    let (env, client, player1, player2) = setup_test();
+    let attack = HubAttack::ReenterOnStart(Symbol::new(&env, "resolve_match"));
+    let hub_addr = env.register(MaliciousGameHub, (attack,));
+    client.set_hub(&hub_addr);
+
+    let session_id = 32u32;
+    let result = client.try_start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(result.is_err());
+    assert!(client.try_get_game(&session_id).is_err());
+}
+
+#[test]
+fn test_trapping_hub_cannot_wedge_settlement() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 33u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_both(&env, &client, session_id, &player1, &player2);
+
+    // Hub goes down between start and settlement; the game settles anyway
+    let failing_hub = env.register(FailingGameHub, (HubFailure::EndGame,));
+    client.set_hub(&failing_hub);
+    let outcome = client.resolve_match(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.outcome(), Some(outcome));
+    assert!(!game.hub_notified);
+    assert!(client.get_open_session(&session_id).is_some());
+    assert_eq!(client.get_match_record(&player1, &0).unwrap().outcome, outcome);
+
+    // The session stays taken until the hub has the result
+    match client.try_retry_hub_notify(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::HubNotNotified),
+        _ => panic!("Expected HubNotNotified error"),
+    }
+    match client.try_purge_game(&session_id, &player1) {
+        Err(Ok(err)) => assert_eq!(err, Error::HubNotNotified),
+        _ => panic!("Expected HubNotNotified error"),
+    }
+    match client.try_start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000) {
+        Err(Ok(err)) => assert_eq!(err, Error::SessionAlreadyExists),
+        _ => panic!("Expected SessionAlreadyExists error"),
+    }
+
+    // Admin points the game at a working hub and the result goes through
+    let hub_addr = env.register(MockGameHub, ());
+    client.set_hub(&hub_addr);
+    client.retry_hub_notify(&session_id);
+    assert!(client.get_game(&session_id).hub_notified);
+    assert_eq!(client.get_open_session(&session_id), None);
+    assert!(MockGameHubClient::new(&env, &hub_addr).outcome(&session_id).is_some());
+    client.purge_game(&session_id, &player1);
+}
+
+#[test]
+fn test_retry_hub_notify_needs_settled_game() {
+    let (env, client, player1, player2) = setup_test();
+    match client.try_retry_hub_notify(&34) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+
+    client.start_game(&34, &player1, &player2, &100_0000000, &100_0000000);
+    submit_both(&env, &client, 34, &player1, &player2);
+    match client.try_retry_hub_notify(&34) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotSettled),
+        _ => panic!("Expected GameNotSettled error"),
+    }
+
+    // A game the hub already has is left alone
+    client.resolve_match(&34);
+    assert!(client.get_game(&34).hub_notified);
+    client.retry_hub_notify(&34);
+}
+
+// ============================================================================
+// Attestation Tests
+// ============================================================================
+
+#[test]
+fn test_attestation_requires_settlement() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    match client.try_get_attestation(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_both(&env, &client, session_id, &player1, &player2);
+    match client.try_get_attestation(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotSettled),
+        _ => panic!("Expected GameNotSettled error"),
+    }
+}
+
+#[test]
+fn test_attestation_layout_and_root() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &50_0000000);
+    submit_both(&env, &client, session_id, &player1, &player2);
+    client.resolve_match(&session_id);
+
+    let attestation = client.get_attestation(&session_id);
+    assert_eq!(attestation.leaves.len(), 4);
+
+    let header = attestation.leaves.get(0).unwrap();
+    assert_eq!(header.len(), 70);
+    assert_eq!(header.get(0), Some(ATTESTATION_VERSION));
+    assert_eq!(header.slice(66..), Bytes::from_array(&env, &session_id.to_be_bytes()));
+
+    let player2_leaf = attestation.leaves.get(2).unwrap();
+    assert_eq!(player2_leaf.len(), 49);
+    assert_eq!(
+        player2_leaf.slice(33..),
+        Bytes::from_array(&env, &50_0000000i128.to_be_bytes())
+    );
+
+    // Balanced (1) vs Aggressive (2) scores 1-2: player2 wins
+    let outcome = attestation.leaves.get(3).unwrap();
+    let mut expected_outcome = Bytes::from_array(&env, &[2u8]);
+    expected_outcome.append(&Bytes::from_array(&env, &1u32.to_be_bytes()));
+    expected_outcome.append(&Bytes::from_array(&env, &2u32.to_be_bytes()));
+    assert_eq!(outcome, expected_outcome);
+
+    let node = |i: u32| -> Bytes {
+        let hash: BytesN<32> = env.crypto().keccak256(&attestation.leaves.get(i).unwrap()).into();
+        hash.into()
+    };
+    let pair = |mut left: Bytes, right: Bytes| -> Bytes {
+        left.append(&right);
+        let hash: BytesN<32> = env.crypto().keccak256(&left).into();
+        hash.into()
+    };
+    let root = pair(pair(node(0), node(1)), pair(node(2), node(3)));
+    assert_eq!(Bytes::from(attestation.root.clone()), root);
+
+    // Deterministic: the same session always attests to the same root
+    assert_eq!(client.get_attestation(&session_id), attestation);
+}
+
+#[test]
+fn test_attestation_records_draw() {
+    let (_env, client, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, session_id, &player1, &player2, 2, 2);
+    assert_eq!(client.resolve_match(&session_id), Outcome::Draw);
+
+    let outcome = client.get_attestation(&session_id).leaves.get(3).unwrap();
+    assert_eq!(outcome.get(0), Some(0));
+}
+
+// ============================================================================
+// Groth16 Verification Tests
+// ============================================================================
+
+/// Set the fixture verification key (see `stellar_game_testutils::groth16`)
+fn store_groth16_vk(env: &Env, client: &ZkTacticalMatchContractClient) {
+    client.set_verification_key(&groth16_vk(env));
+}
+
+/// Contract at the address the fixture proofs are bound to, with the
+/// fixture key stored and the fixture players returned
+fn setup_groth16() -> (
+    Env,
+    ZkTacticalMatchContractClient<'static>,
+    Address,
+    Address,
+) {
+    let (env, client, player1, player2) = deploy_groth16();
+    store_groth16_vk(&env, &client);
+    (env, client, player1, player2)
+}
+
+/// As `setup_groth16`, without a verification key
+fn deploy_groth16() -> (
+    Env,
+    ZkTacticalMatchContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register_at(
+        &groth16_contract(&env),
+        ZkTacticalMatchContract,
+        (&admin, &hub_addr),
+    );
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let player1 = p1.player(&env);
+    let player2 = p2.player(&env);
+
+    (env, client, player1, player2)
+}
+
+/// Public inputs the fixture proof `vector` attests to
+fn groth16_inputs(env: &Env, vector: &Groth16ProofVector) -> PublicInputs {
+    PublicInputs {
+        session_id: vector.session_id,
+        player_binding: vector.player_binding(env),
+        valid_until_ledger: vector.valid_until_ledger,
+        commitment: vector.commitment(env),
+        nullifier: vector.nullifier(env),
+        tactic_hash: vector.tactic_hash(env),
+    }
+}
+
+/// Inputs claiming `tactic` instead of the one the fixture proof attests to
+fn groth16_inputs_for_tactic(env: &Env, vector: &Groth16ProofVector, tactic: u32) -> PublicInputs {
+    let hash = tactic_hash(env, tactic, &vector.secret(env), vector.session_id);
+    PublicInputs { tactic_hash: hash, ..groth16_inputs(env, vector) }
+}
+
+#[test]
+fn test_groth16_fixture_binding_matches_contract() {
+    let (env, client, _player1, _player2) = setup_groth16();
+
+    for vector in GROTH16_PROOFS {
+        let player = vector.player(&env);
+        assert_eq!(client.get_player_binding(&player), vector.player_binding(&env));
+    }
+}
+
+#[test]
+fn test_groth16_fixture_tactic_hash_matches_contract() {
+    let env = Env::default();
+
+    for vector in GROTH16_PROOFS {
+        let expected = tactic_hash(&env, vector.tactic, &vector.secret(&env), vector.session_id);
+        assert_eq!(vector.tactic_hash(&env), expected);
+    }
+}
+
+#[test]
+fn test_groth16_valid_proofs_settle_match() {
+    let (env, client, player1, player2) = setup_groth16();
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    client.submit_tactic(&session_id, &player1, &groth16_inputs(&env, &p1), &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &groth16_inputs(&env, &p2), &p2.proof(&env));
+    assert_eq!(client.get_game_public(&session_id).game.player1_commitment, Some(p1.commitment(&env)));
+    client.reveal_tactic(&session_id, &player1, &p1.tactic, &p1.secret(&env));
+    client.reveal_tactic(&session_id, &player2, &p2.tactic, &p2.secret(&env));
+
+    // Aggressive (2) beats Balanced (1)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+}
+
+#[test]
+fn test_groth16_rejects_proof_for_other_tactic() {
+    let (env, client, player1, player2) = setup_groth16();
+
+    let vector = GROTH16_PROOFS[0];
+    client.start_game(&vector.session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = groth16_inputs_for_tactic(&env, &vector, 3);
+    let result = client.try_submit_tactic(&vector.session_id, &player1, &inputs, &vector.proof(&env));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+}
+
+#[test]
+fn test_groth16_rejects_proof_for_other_session() {
+    let (env, client, player1, player2) = setup_groth16();
+
+    // Claiming the new session in the public inputs passes the binding
+    // check, but the proof was made for the old one
+    let vector = GROTH16_PROOFS[0];
+    let session_id = vector.session_id + 1;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = PublicInputs { session_id, ..groth16_inputs(&env, &vector) };
+    let result = client.try_submit_tactic(&session_id, &player1, &inputs, &vector.proof(&env));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+}
+
+#[test]
+fn test_groth16_rejects_expired_proof() {
+    let (env, client, player1, player2) = setup_groth16();
+
+    let vector = GROTH16_PROOFS[0];
+    client.start_game(&vector.session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = groth16_inputs(&env, &vector);
+    env.ledger().set_sequence_number(vector.valid_until_ledger + 1);
+    match client.try_submit_tactic(&vector.session_id, &player1, &inputs, &vector.proof(&env)) {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofExpired),
+        _ => panic!("Expected ProofExpired error"),
+    }
+
+    // Extending the expiry is caught by the proof itself
+    let extended = PublicInputs { valid_until_ledger: vector.valid_until_ledger + 1_000, ..inputs };
+    match client.try_submit_tactic(&vector.session_id, &player1, &extended, &vector.proof(&env)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+}
+
+#[test]
+fn test_groth16_rejects_commitment_not_proven() {
+    let (env, client, player1, player2) = setup_groth16();
+
+    // Valid proof, but the claimed commitment is not the circuit output it proves
+    let [p1, p2] = GROTH16_PROOFS;
+    client.start_game(&p1.session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = PublicInputs {
+        commitment: p2.commitment(&env),
+        ..groth16_inputs(&env, &p1)
+    };
+    let result = client.try_submit_tactic(&p1.session_id, &player1, &inputs, &p1.proof(&env));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+}
+
+#[test]
+fn test_groth16_proof_cannot_be_copied_by_opponent() {
+    let (env, client, player1, player2) = setup_groth16();
+
+    let vector = GROTH16_PROOFS[0];
+    let session_id = vector.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Copied verbatim, the inputs name player1
+    let copied = groth16_inputs(&env, &vector);
+    let result = client.try_submit_tactic(&session_id, &player2, &copied, &vector.proof(&env));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofPlayerMismatch),
+        _ => panic!("Expected ProofPlayerMismatch error"),
+    }
+
+    // Rebound to player2, the proof no longer verifies
+    let rebound = PublicInputs {
+        player_binding: client.get_player_binding(&player2),
+        ..copied
+    };
+    let result = client.try_submit_tactic(&session_id, &player2, &rebound, &vector.proof(&env));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(client.get_game_public(&session_id).game.player2_commitment.is_none());
+}
+
+#[test]
+fn test_groth16_rejects_mock_proof() {
+    let (env, client, player1, player2) = setup_test();
+    store_groth16_vk(&env, &client);
+
+    let session_id = 7u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Well-formed but not a Groth16 proof
+    let proof = mock_proof(&env, 2, session_id);
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 2), &proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_none());
+}
+
+#[test]
+fn test_verification_key_round_trip() {
+    let (env, client, _player1, _player2) = setup_test();
+    assert_eq!(client.get_verification_key(), None);
+
+    store_groth16_vk(&env, &client);
+    assert_eq!(client.get_verification_key(), Some(groth16_vk(&env)));
+}
+
+#[test]
+fn test_verification_key_length_is_checked() {
+    let (env, client, _player1, _player2) = setup_test();
+
+    let mut vk = groth16_vk(&env);
+    vk.pop_back();
+    match client.try_set_verification_key(&vk) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidVerificationKey),
+        _ => panic!("Expected InvalidVerificationKey error"),
+    }
+    assert_eq!(client.get_verification_key(), None);
+}
+
+/// Registry holding the fixture key as `tactic` version 1
+fn setup_vk_registry(env: &Env) -> VkRegistryClient<'static> {
+    let admin = Address::generate(env);
+    let registry = VkRegistryClient::new(env, &env.register(VkRegistry, (&admin,)));
+    registry.register(&Symbol::new(env, "tactic"), &1, &groth16_vk(env));
+    registry
+}
+
+#[test]
+fn test_groth16_vk_resolved_from_registry() {
+    let (env, client, player1, player2) = deploy_groth16();
+    let registry = setup_vk_registry(&env);
+
+    let source = VkSource { registry: registry.address.clone(), circuit_id: Symbol::new(&env, "tactic"), version: 1 };
+    client.set_vk_source(&Some(source.clone()));
+    assert_eq!(client.get_vk_source(), Some(source));
+    assert_eq!(client.get_verification_key(), None);
+
+    // The registry's key rejects a proof for another tactic...
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let result = client.try_submit_tactic(&session_id, &player1, &groth16_inputs_for_tactic(&env, &p1, 3), &p1.proof(&env));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+
+    // ...and settles the match with the real ones
+    client.submit_tactic(&session_id, &player1, &groth16_inputs(&env, &p1), &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &groth16_inputs(&env, &p2), &p2.proof(&env));
+    client.reveal_tactic(&session_id, &player1, &p1.tactic, &p1.secret(&env));
+    client.reveal_tactic(&session_id, &player2, &p2.tactic, &p2.secret(&env));
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+}
+
+#[test]
+fn test_vk_source_must_resolve_to_a_key() {
+    let (env, client, _player1, _player2) = setup_test();
+    let registry = setup_vk_registry(&env);
+    let tactic = Symbol::new(&env, "tactic");
+
+    // A registered key of the wrong length
+    registry.register(&tactic, &2, &Bytes::from_array(&env, &[1u8; 64]));
+    let source = VkSource { registry: registry.address.clone(), circuit_id: tactic.clone(), version: 2 };
+    match client.try_set_vk_source(&Some(source)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidVerificationKey),
+        _ => panic!("Expected InvalidVerificationKey error"),
+    }
+
+    // An unregistered version traps in the registry
+    let source = VkSource { registry: registry.address.clone(), circuit_id: tactic.clone(), version: 3 };
+    assert!(client.try_set_vk_source(&Some(source)).is_err());
+    assert_eq!(client.get_vk_source(), None);
+
+    let source = VkSource { registry: registry.address.clone(), circuit_id: tactic, version: 1 };
+    client.set_vk_source(&Some(source));
+    client.set_vk_source(&None);
+    assert_eq!(client.get_vk_source(), None);
+}
+
+// ============================================================================
+// Proof Version Tests
+// ============================================================================
+
+fn envelope(env: &Env, version: u32, system: ProofSystem, tactic: u32, session_id: u32) -> ProofEnvelope {
+    ProofEnvelope { version, system, payload: mock_proof(env, tactic, session_id) }
+}
+
+#[test]
+fn test_enveloped_proofs_play_a_match() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 160u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.submit_tactic_envelope(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &envelope(&env, PROOF_VERSION, ProofSystem::Groth16, 1, session_id));
+    client.submit_tactic_envelope(&session_id, &player2, &mock_inputs(&client, &player2, session_id, 0), &envelope(&env, PROOF_VERSION, ProofSystem::Groth16, 0, session_id));
+    mock_reveal(&client, &player1, session_id, 1);
+    mock_reveal(&client, &player2, session_id, 0);
+
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win); // Balanced beats Defensive
+}
+
+#[test]
+fn test_unsupported_proof_version_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 161u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let inputs = mock_inputs(&client, &player1, session_id, 2);
+
+    for version in [0, PROOF_VERSION + 1] {
+        let result = client.try_submit_tactic_envelope(&session_id, &player1, &inputs, &envelope(&env, version, ProofSystem::Groth16, 2, session_id));
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::UnsupportedProofVersion),
+            _ => panic!("Expected UnsupportedProofVersion error"),
+        }
+    }
+    assert!(!client.is_nullifier_used(&inputs.nullifier));
+
+    // The envelope's backend must be the game's
+    let result = client.try_submit_tactic_envelope(&session_id, &player1, &inputs, &envelope(&env, PROOF_VERSION, ProofSystem::UltraHonk, 2, session_id));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_none());
+}
+
+#[test]
+fn test_min_proof_version_round_trip() {
+    let (_env, client, _player1, _player2) = setup_test();
+    assert_eq!(client.get_min_proof_version(), 1);
+
+    // A contract cannot require proofs newer than it can verify
+    match client.try_set_min_proof_version(&(PROOF_VERSION + 1)) {
+        Err(Ok(err)) => assert_eq!(err, Error::UnsupportedProofVersion),
+        _ => panic!("Expected UnsupportedProofVersion error"),
+    }
+    client.set_min_proof_version(&PROOF_VERSION);
+    assert_eq!(client.get_min_proof_version(), PROOF_VERSION);
+}
+
+// ============================================================================
+// Proof Size Tests
+// ============================================================================
+
+/// A proof of `len` non-zero bytes, which passes the format checks
+fn sized_proof(env: &Env, len: u32) -> Bytes {
+    Bytes::from_slice(env, &std::vec![0xab; len as usize])
+}
+
+#[test]
+fn test_oversized_proof_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_max_proof_size(), DEFAULT_MAX_PROOF_SIZE);
+
+    let session_id = 170u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let inputs = mock_inputs(&client, &player1, session_id, 2);
+
+    let result = client.try_submit_tactic(&session_id, &player1, &inputs, &sized_proof(&env, DEFAULT_MAX_PROOF_SIZE + 1));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofTooLarge),
+        _ => panic!("Expected ProofTooLarge error"),
+    }
+    assert!(!client.is_nullifier_used(&inputs.nullifier));
+
+    // The limit itself is allowed
+    client.submit_tactic(&session_id, &player1, &inputs, &sized_proof(&env, DEFAULT_MAX_PROOF_SIZE));
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_some());
+}
+
+#[test]
+fn test_max_proof_size_applies_to_every_submission() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_max_proof_size(&32);
+    assert_eq!(client.get_max_proof_size(), 32);
+
+    // mock_proof is 64 bytes
+    let session_id = 171u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let inputs = mock_inputs(&client, &player1, session_id, 1);
+    let proof = mock_proof(&env, 1, session_id);
+    let enveloped = ProofEnvelope { version: PROOF_VERSION, system: ProofSystem::Groth16, payload: proof.clone() };
+    let results = [
+        client.try_submit_tactic(&session_id, &player1, &inputs, &proof),
+        client.try_submit_tactic_envelope(&session_id, &player1, &inputs, &enveloped),
+    ];
+    for result in results {
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::ProofTooLarge),
+            _ => panic!("Expected ProofTooLarge error"),
+        }
+    }
+
+    let session_id = 172u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &2);
+    let inputs = round_inputs(&client, &player1, session_id, &[1, 2], 0xa1);
+    match client.try_submit_rounds(&session_id, &player1, &inputs, &mock_proof(&env, 0, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::ProofTooLarge),
+        _ => panic!("Expected ProofTooLarge error"),
+    }
+}
+
+// ============================================================================
+// Duplicate Proof Tests
+// ============================================================================
+
+#[test]
+fn test_opponent_proof_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 180u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let proof = mock_proof(&env, 1, session_id);
+    client.submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &proof);
+    // Proof hashes are withheld from the public view until settlement
+    let stored_game = || {
+        env.as_contract(&client.address, || {
+            env.storage().temporary().get::<_, Game>(&DataKey::Game(session_id)).unwrap()
+        })
+    };
+    let proof_hash: BytesN<32> = env.crypto().keccak256(&proof).into();
+    assert_eq!(stored_game().player1_proof_hash, Some(proof_hash));
+
+    // Same tactic, own inputs, but player1's proof bytes
+    let inputs = mock_inputs(&client, &player2, session_id, 1);
+    match client.try_submit_tactic(&session_id, &player2, &inputs, &proof) {
+        Err(Ok(err)) => assert_eq!(err, Error::DuplicateProof),
+        _ => panic!("Expected DuplicateProof error"),
+    }
+    assert!(!client.is_nullifier_used(&inputs.nullifier));
+    assert!(client.get_game_public(&session_id).game.player2_commitment.is_none());
+
+    client.submit_tactic(&session_id, &player2, &inputs, &mock_player_proof(&env, &player2, 1, session_id));
+    assert!(stored_game().player2_proof_hash.is_some());
+}
+
+#[test]
+fn test_opponent_round_proof_rejected() {
+    let (env, client, player1, player2) = setup_test();
+
+    let session_id = 181u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &2);
+    let proof = mock_proof(&env, 0, session_id);
+    client.submit_rounds(&session_id, &player1, &round_inputs(&client, &player1, session_id, &[1, 2], 0xa1), &proof);
+
+    let inputs = round_inputs(&client, &player2, session_id, &[0, 3], 0xb2);
+    match client.try_submit_rounds(&session_id, &player2, &inputs, &proof) {
+        Err(Ok(err)) => assert_eq!(err, Error::DuplicateProof),
+        _ => panic!("Expected DuplicateProof error"),
+    }
+    assert!(!client.is_nullifier_used(&inputs.nullifier));
+}
+
+// ============================================================================
+// Anti-Sybil Tests
+// ============================================================================
+
+/// Gate ranked games on a mock uniqueness verifier, in bracket 1
+fn setup_sybil_gate(env: &Env, client: &ZkTacticalMatchContractClient) -> (MockVerifierClient<'static>, SybilGate) {
+    let verifier_addr = env.register(MockVerifier, (true,));
+    let gate = SybilGate { verifier: verifier_addr.clone(), identity_root: BytesN::from_array(env, &[0x1d; 32]), bracket: 1 };
+    client.set_sybil_gate(&Some(gate.clone()));
+    (MockVerifierClient::new(env, &verifier_addr), gate)
+}
+
+/// Uniqueness proof of identity `id`, with the same nullifier in every
+/// bracket (a real one changes with the bracket)
+fn identity(env: &Env, id: u8) -> UniquenessProof {
+    let mut nullifier = [id; 32];
+    nullifier[0] = 0;
+    UniquenessProof { nullifier: BytesN::from_array(env, &nullifier), proof: mock_proof(env, id as u32, 0) }
+}
+
+#[test]
+fn test_ranked_games_require_sybil_gate() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_sybil_gate(), None);
+
+    match client.try_start_ranked_game(&190, &player1, &player2, &100_0000000, &100_0000000, &identity(&env, 1), &identity(&env, 2)) {
+        Err(Ok(err)) => assert_eq!(err, Error::SybilGateDisabled),
+        _ => panic!("Expected SybilGateDisabled error"),
+    }
+}
+
+#[test]
+fn test_ranked_game_checks_uniqueness_proofs() {
+    let (env, client, player1, player2) = setup_test();
+    let (verifier, gate) = setup_sybil_gate(&env, &client);
+    assert_eq!(client.get_sybil_gate(), Some(gate.clone()));
+
+    let session_id = 191u32;
+    let (identity1, identity2) = (identity(&env, 1), identity(&env, 2));
+    client.start_ranked_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &identity1, &identity2);
+    assert_eq!(verifier.calls(), 2);
+    assert!(client.is_identity_used(&1, &identity1.nullifier));
+    assert!(client.is_identity_used(&1, &identity2.nullifier));
+
+    // Root, bracket, player binding and nullifier: Semaphore's root, scope,
+    // message and nullifier
+    let mut bracket = [0u8; 32];
+    bracket[31] = 1;
+    let expected = Vec::from_slice(&env, &[gate.identity_root, BytesN::from_array(&env, &bracket), player_binding(&env, &client.address, &player2), identity2.nullifier]);
+    assert_eq!(verifier.last_fields(), Some(expected));
+
+    // From here it plays as any other game
+    play_tactics(&client, session_id, &player1, &player2, 2, 1);
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+}
+
+#[test]
+fn test_identity_plays_once_per_bracket() {
+    let (env, client, player1, player2) = setup_test();
+    let (_verifier, mut gate) = setup_sybil_gate(&env, &client);
+    client.start_ranked_game(&192, &player1, &player2, &100_0000000, &100_0000000, &identity(&env, 1), &identity(&env, 2));
+
+    // The same person from fresh addresses, on one side or on both
+    let player3 = Address::generate(&env);
+    let player4 = Address::generate(&env);
+    for (identity3, identity4) in [(identity(&env, 1), identity(&env, 3)), (identity(&env, 3), identity(&env, 3))] {
+        match client.try_start_ranked_game(&193, &player3, &player4, &100_0000000, &100_0000000, &identity3, &identity4) {
+            Err(Ok(err)) => assert_eq!(err, Error::IdentityAlreadyPlayed),
+            _ => panic!("Expected IdentityAlreadyPlayed error"),
+        }
+    }
+    assert!(!client.is_identity_used(&1, &identity(&env, 3).nullifier));
+
+    // A new bracket lets every identity play again
+    gate.bracket = 2;
+    client.set_sybil_gate(&Some(gate));
+    client.start_ranked_game(&193, &player3, &player4, &100_0000000, &100_0000000, &identity(&env, 1), &identity(&env, 3));
+    assert!(client.is_identity_used(&2, &identity(&env, 1).nullifier));
+}
+
+#[test]
+fn test_invalid_uniqueness_proof_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    let (verifier, _gate) = setup_sybil_gate(&env, &client);
+
+    let session_id = 194u32;
+    let (identity1, identity2) = (identity(&env, 1), identity(&env, 2));
+    verifier.set_proof(&identity2.proof, &false);
+    match client.try_start_ranked_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &identity1, &identity2) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(!client.is_identity_used(&1, &identity1.nullifier));
+    assert!(client.try_get_game(&session_id).is_err());
+
+    client.set_sybil_gate(&None);
+    assert_eq!(client.get_sybil_gate(), None);
+}
+
+// ============================================================================
+// Team Match Tests
+// ============================================================================
+
+/// Four players, signing with BLS secrets 1 to 4; the first two are team 1
+/// (captain first), the last two team 2
+fn setup_teams(env: &Env) -> ([Address; 4], Vec<TeamMember>, Vec<TeamMember>) {
+    let players = [Address::generate(env), Address::generate(env), Address::generate(env), Address::generate(env)];
+    let member = |i: usize| TeamMember { player: players[i].clone(), bls_key: bls_key(env, i as u8 + 1) };
+    let team1 = Vec::from_array(env, [member(0), member(1)]);
+    let team2 = Vec::from_array(env, [member(2), member(3)]);
+    (players, team1, team2)
+}
+
+/// One tactic hash per member, with the same secrets as `round_secrets`
+fn team_hashes(env: &Env, session_id: u32, tactics: &[u32], salt: u8) -> Vec<BytesN<32>> {
+    let mut hashes = Vec::new(env);
+    for (round, tactic) in tactics.iter().enumerate() {
+        hashes.push_back(tactic_hash(env, *tactic, &round_secret(env, salt, round as u32), session_id));
+    }
+    hashes
+}
+
+/// Aggregate of each `(member, seed)` signing their hash in `hashes`
+fn sign_team_move(client: &ZkTacticalMatchContractClient, session_id: u32, members: &[(&Address, u8)], hashes: &Vec<BytesN<32>>) -> BytesN<192> {
+    let env = &client.env;
+    let signatures: std::vec::Vec<BytesN<192>> = members
+        .iter()
+        .zip(hashes.iter())
+        .map(|((player, seed), hash)| bls_sign(env, *seed, &client.team_move_payload(&session_id, player, &hash)))
+        .collect();
+    bls_aggregate(env, &signatures)
+}
+
+#[test]
+fn test_team_match_moves_in_one_transaction() {
+    let (env, client, _player1, _player2) = setup_test();
+    let (players, team1, team2) = setup_teams(&env);
+
+    let session_id = 200u32;
+    client.start_team_game(&session_id, &team1, &team2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_teams(&session_id), Some(Teams { team1, team2 }));
+    let game = client.get_game_public(&session_id).game;
+    assert_eq!((game.player1, game.player2), (players[0].clone(), players[2].clone()));
+
+    let hashes1 = team_hashes(&env, session_id, &[2, 0], 0xa1);
+    let signature1 = sign_team_move(&client, session_id, &[(&players[0], 1), (&players[1], 2)], &hashes1);
+    client.submit_team_move(&session_id, &players[0], &hashes1, &signature1);
+    let hashes2 = team_hashes(&env, session_id, &[1, 2], 0xb2);
+    let signature2 = sign_team_move(&client, session_id, &[(&players[2], 3), (&players[3], 4)], &hashes2);
+    client.submit_team_move(&session_id, &players[2], &hashes2, &signature2);
+
+    // Captains reveal for their team
+    client.reveal_rounds(&session_id, &players[0], &Vec::from_slice(&env, &[2, 0]), &round_secrets(&env, 0xa1, 2));
+    client.reveal_rounds(&session_id, &players[2], &Vec::from_slice(&env, &[1, 2]), &round_secrets(&env, 0xb2, 2));
+
+    // Aggressive beats Balanced, then Defensive draws with Aggressive
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_score, Some(1));
+    assert_eq!(game.player2_score, Some(0));
+}
+
+#[test]
+fn test_team_move_needs_every_members_signature() {
+    let (env, client, _player1, _player2) = setup_test();
+    let (players, team1, team2) = setup_teams(&env);
+
+    let session_id = 201u32;
+    client.start_team_game(&session_id, &team1, &team2, &100_0000000, &100_0000000);
+    let hashes = team_hashes(&env, session_id, &[3, 1], 0xa1);
+    let mut swapped = Vec::new(&env);
+    swapped.push_back(hashes.get_unchecked(1));
+    swapped.push_back(hashes.get_unchecked(0));
+
+    let rejected = [
+        // The captain alone
+        (hashes.clone(), sign_team_move(&client, session_id, &[(&players[0], 1)], &hashes)),
+        // Each member signing the other's hash
+        (hashes.clone(), sign_team_move(&client, session_id, &[(&players[0], 1), (&players[1], 2)], &swapped)),
+        // Someone else's key for the second member
+        (hashes.clone(), sign_team_move(&client, session_id, &[(&players[0], 1), (&players[1], 3)], &hashes)),
+    ];
+    for (hashes, signature) in rejected {
+        match client.try_submit_team_move(&session_id, &players[0], &hashes, &signature) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidTeamSignature),
+            _ => panic!("Expected InvalidTeamSignature error"),
+        }
+    }
+
+    let signature = sign_team_move(&client, session_id, &[(&players[0], 1), (&players[1], 2)], &hashes);
+    client.submit_team_move(&session_id, &players[0], &hashes, &signature);
+    match client.try_submit_team_move(&session_id, &players[0], &hashes, &signature) {
+        Err(Ok(err)) => assert_eq!(err, Error::AlreadySubmitted),
+        _ => panic!("Expected AlreadySubmitted error"),
+    }
+
+    // Team 2 cannot mirror a hash of team 1's
+    let copied = Vec::from_array(&env, [hashes.get_unchecked(0), team_hashes(&env, session_id, &[0], 0xb2).get_unchecked(0)]);
+    let signature = sign_team_move(&client, session_id, &[(&players[2], 3), (&players[3], 4)], &copied);
+    match client.try_submit_team_move(&session_id, &players[2], &copied, &signature) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidCommitment),
+        _ => panic!("Expected InvalidCommitment error"),
+    }
+}
+
+#[test]
+fn test_team_game_rejects_invalid_teams() {
+    let (env, client, _player1, _player2) = setup_test();
+    let (players, team1, team2) = setup_teams(&env);
+
+    let short = Vec::from_array(&env, [team1.get_unchecked(0)]);
+    let mut shared = team2.clone();
+    shared.set(1, team1.get_unchecked(1));
+    let mut identity_key = [0u8; 96];
+    identity_key[0] = 0x40;
+    let mut keyless = team2.clone();
+    keyless.set(1, TeamMember { player: players[3].clone(), bls_key: BytesN::from_array(&env, &identity_key) });
+
+    for (team1, team2) in [(short, team2.clone()), (team1.clone(), shared), (team1.clone(), keyless)] {
+        match client.try_start_team_game(&202, &team1, &team2, &100_0000000, &100_0000000) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidTeam),
+            _ => panic!("Expected InvalidTeam error"),
+        }
+    }
+    assert_eq!(client.get_teams(&202), None);
+}
+
+#[test]
+fn test_team_game_takes_only_team_moves() {
+    let (env, client, player1, player2) = setup_test();
+    let (players, team1, team2) = setup_teams(&env);
+
+    let session_id = 203u32;
+    client.start_team_game(&session_id, &team1, &team2, &100_0000000, &100_0000000);
+    let inputs = round_inputs(&client, &players[0], session_id, &[1, 2], 0xa1);
+    match client.try_submit_rounds(&session_id, &players[0], &inputs, &mock_proof(&env, 0, session_id)) {
+        Err(Ok(err)) => assert_eq!(err, Error::WrongProofSystem),
+        _ => panic!("Expected WrongProofSystem error"),
+    }
+
+    let session_id = 204u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &2);
+    let hashes = team_hashes(&env, session_id, &[1, 2], 0xa1);
+    match client.try_submit_team_move(&session_id, &player1, &hashes, &BytesN::from_array(&env, &[0; 192])) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotTeamGame),
+        _ => panic!("Expected NotTeamGame error"),
+    }
+}
+
+// ============================================================================
+// Rating Bracket Tests
+// ============================================================================
+
+/// Publish a ratings root on the hub and check rating proofs with a mock
+/// verifier
+fn setup_ratings(env: &Env, client: &ZkTacticalMatchContractClient) -> (MockVerifierClient<'static>, BytesN<32>) {
+    let verifier_addr = env.register(MockVerifier, (true,));
+    client.set_rating_verifier(&verifier_addr);
+    let mut root = [0x2a; 32];
+    root[0] = 0;
+    let root = BytesN::from_array(env, &root);
+    MockGameHubClient::new(env, &client.get_hub()).set_ratings_root(&root);
+    (MockVerifierClient::new(env, &verifier_addr), root)
+}
+
+fn u32_field(env: &Env, value: u32) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[28..].copy_from_slice(&value.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+#[test]
+fn test_bracket_game_checks_rating_proofs() {
+    let (env, client, player1, player2) = setup_test();
+    let (verifier, root) = setup_ratings(&env, &client);
+
+    let session_id = 210u32;
+    let bracket = RatingBracket { min_rating: 1200, max_rating: 1400 };
+    let (proof1, proof2) = (mock_player_proof(&env, &player1, 0, session_id), mock_player_proof(&env, &player2, 0, session_id));
+    client.start_bracket_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &bracket, &proof1, &proof2);
+    assert_eq!(verifier.calls(), 2);
+    assert_eq!(client.get_rating_bracket(&session_id), Some(bracket));
+
+    // The hub's root, the bracket and the player's key in the ratings tree
+    let expected = Vec::from_array(&env, [root, u32_field(&env, 1200), u32_field(&env, 1400), rating_key(&env, &player2)]);
+    assert_eq!(verifier.last_fields(), Some(expected));
+    assert_eq!(client.get_rating_key(&player2), rating_key(&env, &player2));
+
+    // From here it plays as any other game
+    play_tactics(&client, session_id, &player1, &player2, 2, 1);
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+}
+
+#[test]
+fn test_bracket_game_rejects_player_outside_bracket() {
+    let (env, client, player1, player2) = setup_test();
+    let (verifier, _root) = setup_ratings(&env, &client);
+
+    let session_id = 211u32;
+    let bracket = RatingBracket { min_rating: 1200, max_rating: 1400 };
+    let (proof1, proof2) = (mock_player_proof(&env, &player1, 0, session_id), mock_player_proof(&env, &player2, 0, session_id));
+    verifier.set_proof(&proof2, &false);
+
+    match client.try_start_bracket_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &bracket, &proof1, &proof2) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert_eq!(client.get_rating_bracket(&session_id), None);
+    assert!(client.try_get_game(&session_id).is_err());
+}
+
+#[test]
+fn test_bracket_game_needs_published_ratings_and_valid_bracket() {
+    let (env, client, player1, player2) = setup_test();
+    let bracket = RatingBracket { min_rating: 1200, max_rating: 1400 };
+    let (proof1, proof2) = (mock_player_proof(&env, &player1, 0, 212), mock_player_proof(&env, &player2, 0, 212));
+
+    match client.try_start_bracket_game(&212, &player1, &player2, &100_0000000, &100_0000000, &bracket, &proof1, &proof2) {
+        Err(Ok(err)) => assert_eq!(err, Error::RatingsUnavailable),
+        _ => panic!("Expected RatingsUnavailable error"),
+    }
+
+    setup_ratings(&env, &client);
+    let inverted = RatingBracket { min_rating: 1400, max_rating: 1200 };
+    match client.try_start_bracket_game(&212, &player1, &player2, &100_0000000, &100_0000000, &inverted, &proof1, &proof2) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidRatingBracket),
+        _ => panic!("Expected InvalidRatingBracket error"),
+    }
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+/// Submit `tactic` for `player` alone
+fn submit_one(client: &ZkTacticalMatchContractClient, session_id: u32, player: &Address, tactic: u32) {
+    let env = &client.env;
+    client.submit_tactic(&session_id, player, &mock_inputs(client, player, session_id, tactic), &mock_player_proof(env, player, tactic, session_id));
+}
+
+#[test]
+fn test_timeout_awards_the_player_who_submitted() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 220u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let deadline = client.get_game_public(&session_id).game.submit_deadline_ledger;
+    assert_eq!(deadline, env.ledger().sequence() + DEFAULT_TIMEOUT_LEDGERS);
+
+    submit_one(&client, session_id, &player2, 1);
+
+    // The deadline ledger itself is still in time
+    for sequence in [env.ledger().sequence(), deadline] {
+        env.ledger().set_sequence_number(sequence);
+        match client.try_resolve_timeout(&session_id) {
+            Err(Ok(err)) => assert_eq!(err, Error::DeadlineNotReached),
+            _ => panic!("Expected DeadlineNotReached error"),
+        }
+    }
+
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.resolve_timeout(&session_id), player2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.outcome(), Some(Outcome::Player2Win));
+    assert_eq!((game.player1_score, game.player2_score), (None, None));
+
+    match client.try_resolve_timeout(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player2Win);
+}
+
+#[test]
+fn test_timeout_needs_exactly_one_submission() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&221, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&222, &player1, &player2, &100_0000000, &100_0000000);
+    client.submit_tactic(&222, &player1, &mock_inputs(&client, &player1, 222, 0), &mock_player_proof(&env, &player1, 0, 222));
+    client.submit_tactic(&222, &player2, &mock_inputs(&client, &player2, 222, 3), &mock_player_proof(&env, &player2, 3, 222));
+
+    // Neither submitted; both did, and the reveal window is still open
+    env.ledger().set_sequence_number(client.get_game_public(&221).game.submit_deadline_ledger + 1);
+    match client.try_resolve_timeout(&221) {
+        Err(Ok(err)) => assert_eq!(err, Error::NoForfeit),
+        _ => panic!("Expected NoForfeit error"),
+    }
+    match client.try_resolve_timeout(&222) {
+        Err(Ok(err)) => assert_eq!(err, Error::DeadlineNotReached),
+        _ => panic!("Expected DeadlineNotReached error"),
+    }
+
+    // Neither revealed either
+    env.ledger().set_sequence_number(client.get_game_public(&222).game.resolve_deadline_ledger + 1);
+    match client.try_resolve_timeout(&222) {
+        Err(Ok(err)) => assert_eq!(err, Error::NoForfeit),
+        _ => panic!("Expected NoForfeit error"),
+    }
+    match client.try_resolve_timeout(&223) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+}
+
+#[test]
+fn test_timeout_is_fixed_when_the_game_starts() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_timeout_ledgers(), DEFAULT_TIMEOUT_LEDGERS);
+    client.start_game(&224, &player1, &player2, &100_0000000, &100_0000000);
+
+    client.set_timeout_ledgers(&client.get_admin(), &10);
+    assert_eq!(client.get_timeout_ledgers(), 10);
+    client.start_game(&225, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 224, &player1, 2);
+    submit_one(&client, 225, &player1, 2);
+
+    let now = env.ledger().sequence();
+    assert_eq!(client.get_game_public(&224).game.submit_deadline_ledger, now + DEFAULT_TIMEOUT_LEDGERS);
+    assert_eq!(client.get_game_public(&225).game.submit_deadline_ledger, now + 10);
+
+    env.ledger().set_sequence_number(now + 11);
+    assert_eq!(client.resolve_timeout(&225), player1);
+    assert!(client.try_resolve_timeout(&224).is_err());
+}
+
+#[test]
+fn test_timeout_cannot_outlive_game_storage() {
+    let (_env, client, _player1, _player2) = setup_test();
+    match client.try_set_timeout_ledgers(&client.get_admin(), &518_400) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidDeadlines),
+        _ => panic!("Expected InvalidDeadlines error"),
+    }
+}
+
+#[test]
+fn test_timeout_reports_forfeit_to_hub() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 226u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, session_id, &player1, 2);
+    env.ledger().set_sequence_number(client.get_game_public(&session_id).game.submit_deadline_ledger + 1);
+
+    // A hub that rejects the result does not stop the forfeit
+    client.set_hub(&env.register(FailingGameHub, (HubFailure::EndGame,)));
+    assert_eq!(client.resolve_timeout(&session_id), player1);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.outcome(), game.hub_notified), (Some(Outcome::Player1Win), false));
+
+    let hub_addr = env.register(MockGameHub, ());
+    client.set_hub(&hub_addr);
+    client.retry_hub_notify(&session_id);
+    assert_eq!(MockGameHubClient::new(&env, &hub_addr).outcome(&session_id), Some(HubOutcome::Player1Win));
+}
+
+// ============================================================================
+// Deadline Tests
+// ============================================================================
+
+#[test]
+fn test_default_deadlines() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&250, &player1, &player2, &100_0000000, &100_0000000);
+
+    let now = env.ledger().sequence();
+    let deadlines = client.get_deadlines(&250);
+    assert_eq!(deadlines, Deadlines { submit_deadline_ledger: now + DEFAULT_TIMEOUT_LEDGERS, resolve_deadline_ledger: now + 2 * DEFAULT_TIMEOUT_LEDGERS });
+    let game = client.get_game_public(&250).game;
+    assert_eq!((game.submit_deadline_ledger, game.resolve_deadline_ledger), (deadlines.submit_deadline_ledger, deadlines.resolve_deadline_ledger));
+
+    match client.try_get_deadlines(&251) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+}
+
+#[test]
+fn test_last_activity_follows_each_move() {
+    let (env, client, player1, player2) = setup_test();
+    let start = env.ledger().sequence();
+    client.start_game(&258, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_last_activity(&258), start);
+
+    env.ledger().set_sequence_number(start + 10);
+    submit_one(&client, 258, &player1, 2);
+    assert_eq!(client.get_last_activity(&258), start + 10);
+
+    // Reads are not activity
+    env.ledger().set_sequence_number(start + 20);
+    client.get_game_public(&258);
+    assert_eq!(client.get_last_activity(&258), start + 10);
+
+    submit_one(&client, 258, &player2, 1);
+    mock_reveal(&client, &player1, 258, 2);
+    env.ledger().set_sequence_number(start + 30);
+    mock_reveal(&client, &player2, 258, 1);
+    assert_eq!(client.get_last_activity(&258), start + 30);
+    env.ledger().set_sequence_number(start + 40);
+    client.resolve_match(&258);
+    assert_eq!(client.get_last_activity(&258), start + 40);
+    assert_eq!(client.get_game(&258).last_activity_ledger, start + 40);
+
+    match client.try_get_last_activity(&259) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+}
+
+#[test]
+fn test_start_game_with_deadlines() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game_with_deadlines(&252, &player1, &player2, &100_0000000, &50_0000000, &30, &20);
+
+    let now = env.ledger().sequence();
+    assert_eq!(client.get_deadlines(&252), Deadlines { submit_deadline_ledger: now + 30, resolve_deadline_ledger: now + 50 });
+    assert_eq!(client.get_open_session(&252), Some(OpenSession { player1: player1.clone(), player2: player2.clone(), player1_points: 100_0000000, player2_points: 50_0000000 }));
+
+    play_tactics(&client, 252, &player1, &player2, 2, 1);
+    assert_eq!(client.resolve_match(&252), Outcome::Player1Win);
+
+    for (submit, resolve) in [(0u32, 20u32), (30, 0), (300_000, 218_400), (u32::MAX, 1)] {
+        match client.try_start_game_with_deadlines(&253, &player1, &player2, &100_0000000, &50_0000000, &submit, &resolve) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidDeadlines),
+            _ => panic!("Expected InvalidDeadlines error"),
+        }
+    }
+}
+
+#[test]
+fn test_timeout_awards_the_player_who_revealed() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 254u32;
+    client.start_game_with_deadlines(&session_id, &player1, &player2, &100_0000000, &100_0000000, &30, &20);
+    submit_one(&client, session_id, &player1, 0);
+    submit_one(&client, session_id, &player2, 3);
+    mock_reveal(&client, &player2, session_id, 3);
+
+    // Past the submit deadline the reveal window is still open
+    let deadlines = client.get_deadlines(&session_id);
+    for sequence in [deadlines.submit_deadline_ledger + 1, deadlines.resolve_deadline_ledger] {
+        env.ledger().set_sequence_number(sequence);
+        match client.try_resolve_timeout(&session_id) {
+            Err(Ok(err)) => assert_eq!(err, Error::DeadlineNotReached),
+            _ => panic!("Expected DeadlineNotReached error"),
+        }
+    }
+
+    env.ledger().set_sequence_number(deadlines.resolve_deadline_ledger + 1);
+    assert_eq!(client.resolve_timeout(&session_id), player2);
+    assert_eq!(client.get_game(&session_id).outcome(), Some(Outcome::Player2Win));
+    assert_eq!(client.get_open_session(&session_id), None);
+}
+
+/// Move past `session_id`'s reveal window
+fn pass_resolve_deadline(client: &ZkTacticalMatchContractClient, session_id: u32) {
+    let deadlines = client.get_deadlines(&session_id);
+    client.env.ledger().set_sequence_number(deadlines.resolve_deadline_ledger + 1);
+}
+
+#[test]
+fn test_timeout_awards_the_player_who_revealed_every_round() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 270u32;
+    client.start_multi_round_game(&session_id, &player1, &player2, &100_0000000, &100_0000000, &3);
+    client.submit_rounds(&session_id, &player1, &round_inputs(&client, &player1, session_id, &[1, 2, 0], 0xa1), &mock_player_proof(&env, &player1, 0, session_id));
+    client.submit_rounds(&session_id, &player2, &round_inputs(&client, &player2, session_id, &[0, 1, 1], 0xb2), &mock_player_proof(&env, &player2, 0, session_id));
+    client.reveal_rounds(&session_id, &player1, &Vec::from_slice(&env, &[1, 2, 0]), &round_secrets(&env, 0xa1, 3));
+
+    pass_resolve_deadline(&client, session_id);
+    assert_eq!(client.resolve_timeout(&session_id), player1);
+    assert_eq!(client.get_game(&session_id).outcome(), Some(Outcome::Player1Win));
+}
+
+#[test]
+fn test_timeout_awards_the_player_who_opened_their_whole_strategy() {
+    let (_env, client, player1, player2) = setup_test();
+    let session_id = 271u32;
+    let tactics1 = [1, 2, 0];
+    let tactics2 = [0, 1, 1];
+    start_committed(&client, session_id, &player1, &player2, &tactics1, &tactics2);
+    for round in 0..3 {
+        reveal_committed(&client, session_id, &player2, &tactics2, 0xb2, round);
+    }
+    // Opening some rounds is not enough
+    for round in 0..2 {
+        reveal_committed(&client, session_id, &player1, &tactics1, 0xa1, round);
+    }
+
+    pass_resolve_deadline(&client, session_id);
+    assert_eq!(client.resolve_timeout(&session_id), player2);
+    assert_eq!(client.get_game(&session_id).outcome(), Some(Outcome::Player2Win));
+}
+
+#[test]
+fn test_timeout_awards_the_team_that_revealed() {
+    let (env, client, _player1, _player2) = setup_test();
+    let (players, team1, team2) = setup_teams(&env);
+    let session_id = 272u32;
+    client.start_team_game(&session_id, &team1, &team2, &100_0000000, &100_0000000);
+    let hashes1 = team_hashes(&env, session_id, &[2, 0], 0xa1);
+    let signature1 = sign_team_move(&client, session_id, &[(&players[0], 1), (&players[1], 2)], &hashes1);
+    client.submit_team_move(&session_id, &players[0], &hashes1, &signature1);
+    let hashes2 = team_hashes(&env, session_id, &[1, 2], 0xb2);
+    let signature2 = sign_team_move(&client, session_id, &[(&players[2], 3), (&players[3], 4)], &hashes2);
+    client.submit_team_move(&session_id, &players[2], &hashes2, &signature2);
+    client.reveal_rounds(&session_id, &players[2], &Vec::from_slice(&env, &[1, 2]), &round_secrets(&env, 0xb2, 2));
+
+    pass_resolve_deadline(&client, session_id);
+    assert_eq!(client.resolve_timeout(&session_id), players[2]);
+}
+
+#[test]
+fn test_timeout_awards_the_player_who_revealed_their_stake() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 273u32;
+    start_hidden(&client, session_id, &player1, &player2, 250, 700);
+    play_tactics(&client, session_id, &player1, &player2, 2, 1);
+    client.reveal_stake(&session_id, &player2, &700, &BytesN::from_array(&env, &[0xb2; 32]));
+
+    // Both tactics are open, but player1 never revealed their stake
+    pass_resolve_deadline(&client, session_id);
+    assert_eq!(client.resolve_timeout(&session_id), player2);
+    assert_eq!(client.get_game(&session_id).outcome(), Some(Outcome::Player2Win));
+}
+
+#[test]
+fn test_timeout_awards_the_player_who_revealed_in_simulated_game() {
+    let (_env, client, player1, player2) = setup_test();
+    let session_id = 274u32;
+    start_simulated(&client, session_id, &player1, &player2);
+    submit_one(&client, session_id, &player1, 1);
+    submit_one(&client, session_id, &player2, 0);
+    mock_reveal(&client, &player1, session_id, 1);
+
+    pass_resolve_deadline(&client, session_id);
+    assert_eq!(client.resolve_timeout(&session_id), player1);
+}
+
+#[test]
+fn test_submissions_close_at_submit_deadline() {
+    let (env, client, player1, player2) = setup_test();
+    let (players, team1, team2) = setup_teams(&env);
+    client.start_game(&280, &player1, &player2, &100_0000000, &100_0000000);
+    start_commit_reveal(&client, 281, &player1, &player2);
+    client.start_multi_round_game(&282, &player1, &player2, &100_0000000, &100_0000000, &3);
+    client.start_team_game(&283, &team1, &team2, &100_0000000, &100_0000000);
+
+    // The deadline is the last ledger to submit in
+    let deadline = client.get_deadlines(&280).submit_deadline_ledger;
+    env.ledger().set_sequence_number(deadline);
+    submit_one(&client, 280, &player1, 1);
+    client.commit_tactic(&281, &player1, &salted_hash(&env, 1, 0xa1, 281));
+    client.submit_rounds(&282, &player1, &round_inputs(&client, &player1, 282, &[1, 2, 0], 0xa1), &mock_player_proof(&env, &player1, 0, 282));
+    let hashes1 = team_hashes(&env, 283, &[2, 0], 0xa1);
+    let signature1 = sign_team_move(&client, 283, &[(&players[0], 1), (&players[1], 2)], &hashes1);
+    client.submit_team_move(&283, &players[0], &hashes1, &signature1);
+
+    env.ledger().set_sequence_number(deadline + 1);
+    let hashes2 = team_hashes(&env, 283, &[1, 2], 0xb2);
+    let signature2 = sign_team_move(&client, 283, &[(&players[2], 3), (&players[3], 4)], &hashes2);
+    let results = [
+        client.try_submit_tactic(&280, &player2, &mock_inputs(&client, &player2, 280, 0), &mock_player_proof(&env, &player2, 0, 280)),
+        client.try_commit_tactic(&281, &player2, &salted_hash(&env, 0, 0xb2, 281)),
+        client.try_submit_rounds(&282, &player2, &round_inputs(&client, &player2, 282, &[0, 1, 1], 0xb2), &mock_player_proof(&env, &player2, 0, 282)),
+        client.try_submit_team_move(&283, &players[2], &hashes2, &signature2),
+    ];
+    for result in results {
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::SubmitDeadlinePassed),
+            _ => panic!("Expected SubmitDeadlinePassed error"),
+        }
+    }
+}
+
+#[test]
+fn test_reveals_close_at_resolve_deadline() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&284, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 284, &player1, 1);
+    submit_one(&client, 284, &player2, 0);
+    client.start_multi_round_game(&285, &player1, &player2, &100_0000000, &100_0000000, &3);
+    client.submit_rounds(&285, &player1, &round_inputs(&client, &player1, 285, &[1, 2, 0], 0xa1), &mock_player_proof(&env, &player1, 0, 285));
+    client.submit_rounds(&285, &player2, &round_inputs(&client, &player2, 285, &[0, 1, 1], 0xb2), &mock_player_proof(&env, &player2, 0, 285));
+    start_committed(&client, 286, &player1, &player2, &[1, 2, 0], &[0, 1, 1]);
+    start_hidden(&client, 287, &player1, &player2, 250, 700);
+    play_tactics(&client, 287, &player1, &player2, 2, 1);
+
+    // The deadline is the last ledger to reveal in
+    let deadline = client.get_deadlines(&284).resolve_deadline_ledger;
+    env.ledger().set_sequence_number(deadline);
+    mock_reveal(&client, &player1, 284, 1);
+    client.reveal_rounds(&285, &player1, &Vec::from_slice(&env, &[1, 2, 0]), &round_secrets(&env, 0xa1, 3));
+    reveal_committed(&client, 286, &player1, &[1, 2, 0], 0xa1, 0);
+    client.reveal_stake(&287, &player1, &250, &BytesN::from_array(&env, &[0xa1; 32]));
+
+    env.ledger().set_sequence_number(deadline + 1);
+    let proof = strategy_proof(&env, &strategy_leaves(&env, &[0, 1, 1], 0xb2, 286), 0);
+    let results = [
+        client.try_reveal_tactic(&284, &player2, &0, &mock_secret(&env, &player2, 284)),
+        client.try_reveal_rounds(&285, &player2, &Vec::from_slice(&env, &[0, 1, 1]), &round_secrets(&env, 0xb2, 3)),
+        client.try_reveal_round(&286, &player2, &0, &0, &round_secret(&env, 0xb2, 0), &proof),
+        client.try_reveal_stake(&287, &player2, &700, &BytesN::from_array(&env, &[0xb2; 32])),
+    ];
+    for result in results {
+        match result {
+            Err(Ok(err)) => assert_eq!(err, Error::RevealDeadlinePassed),
+            _ => panic!("Expected RevealDeadlinePassed error"),
+        }
+    }
+}
+
+#[test]
+fn test_timeout_cannot_outlive_half_game_storage() {
+    let (_env, client, _player1, _player2) = setup_test();
+    client.set_timeout_ledgers(&client.get_admin(), &259_199);
+    assert_eq!(client.get_timeout_ledgers(), 259_199);
+    match client.try_set_timeout_ledgers(&client.get_admin(), &259_200) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidDeadlines),
+        _ => panic!("Expected InvalidDeadlines error"),
+    }
+}
+
+// ============================================================================
+// Expired Session Tests
+// ============================================================================
+
+#[test]
+fn test_open_session_recorded_until_result_reported() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&230, &player1, &player2, &100_0000000, &50_0000000);
+    let record = OpenSession { player1: player1.clone(), player2: player2.clone(), player1_points: 100_0000000, player2_points: 50_0000000 };
+    assert_eq!(client.get_open_session(&230), Some(record));
+
+    play_tactics(&client, 230, &player1, &player2, 2, 1);
+    client.resolve_match(&230);
+    assert_eq!(client.get_open_session(&230), None);
+
+    // Forfeits report a result too
+    client.start_game(&231, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 231, &player1, 2);
+    env.ledger().set_sequence_number(client.get_game_public(&231).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&231);
+    assert_eq!(client.get_open_session(&231), None);
+
+    // Hidden-stake games hold the most either player could stake
+    start_hidden(&client, 232, &player1, &player2, 100, 100);
+    let record = OpenSession { player1, player2, player1_points: 1_000, player2_points: 1_000 };
+    assert_eq!(client.get_open_session(&232), Some(record));
+}
+
+#[test]
+fn test_abandon_expired_session() {
+    let (env, client, player1, player2) = setup_test();
+    // Short minimum TTLs, so game entries live exactly as long as the
+    // contract extends them
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    client.start_game(&233, &player1, &player2, &100_0000000, &50_0000000);
+    submit_one(&client, 233, &player1, 2);
+
+    match client.try_abandon_expired(&233) {
+        Err(Ok(err)) => assert_eq!(err, Error::SessionNotExpired),
+        _ => panic!("Expected SessionNotExpired error"),
+    }
+
+    // Nobody resolved it before the game entry expired
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    assert!(client.try_get_game(&233).is_err());
+    let record = client.abandon_expired(&233);
+    assert_eq!(record, OpenSession { player1, player2, player1_points: 100_0000000, player2_points: 50_0000000 });
+    assert_eq!(client.get_open_session(&233), None);
+
+    for session_id in [233u32, 234] {
+        match client.try_abandon_expired(&session_id) {
+            Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+            _ => panic!("Expected GameNotFound error"),
+        }
+    }
+}
+
+#[test]
+fn test_global_stats_count_every_ending() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_global_stats(), GlobalStats::default());
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+
+    client.start_game(&270, &player1, &player2, &100, &50);
+    play_tactics(&client, 270, &player1, &player2, 1, 2);
+    client.resolve_match(&270);
+    client.start_game(&271, &player1, &player2, &100, &50);
+    client.cancel_game(&271);
+    client.start_game(&272, &player1, &player2, &100, &50);
+    submit_one(&client, 272, &player1, 2);
+    env.ledger().set_sequence_number(client.get_game_public(&272).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&272);
+
+    // Nobody resolves 273 before it expires
+    client.start_game(&273, &player1, &player2, &100, &50);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    client.abandon_expired(&273);
+    client.start_game(&274, &player1, &player2, &100, &50);
+
+    assert_eq!(
+        client.get_global_stats(),
+        GlobalStats {
+            games_started: 5,
+            games_resolved: 1,
+            games_timed_out: 1,
+            games_cancelled: 1,
+            games_abandoned: 1,
+            total_volume: 750,
+            active_sessions: 1,
+            resolution_rate_bps: 5_000,
+        }
+    );
+}
+
+// ============================================================================
+// Match History Tests
+// ============================================================================
+
+#[test]
+fn test_settled_games_are_archived_for_both_players() {
+    let (env, client, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    assert_eq!(client.get_match_count(&player1), 0);
+    assert_eq!(client.get_match_record(&player1, &0), None);
+
+    client.start_game(&260, &player1, &player2, &100_0000000, &50_0000000);
+    play_tactics(&client, 260, &player1, &player2, 2, 1);
+    client.resolve_match(&260);
+
+    client.start_game(&261, &player3, &player1, &10_0000000, &10_0000000);
+    submit_one(&client, 261, &player3, 0);
+    env.ledger().set_sequence_number(client.get_game_public(&261).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&261);
+
+    let first = MatchRecord {
+        session_id: 260,
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100_0000000,
+        player2_points: 50_0000000,
+        player1_score: Some(2),
+        player2_score: Some(1),
+        outcome: Outcome::Player1Win,
+        ledger: 100,
+    };
+    assert_eq!(client.get_match_count(&player1), 2);
+    assert_eq!(client.get_match_record(&player1, &0), Some(first.clone()));
+    assert_eq!(client.get_match_record(&player2, &0), Some(first));
+
+    // Forfeits are archived without scores
+    let forfeit = client.get_match_record(&player1, &1).unwrap();
+    assert_eq!((forfeit.session_id, forfeit.outcome), (261, Outcome::Player1Win));
+    assert_eq!(forfeit.player1, player3);
+    assert_eq!((forfeit.player1_score, forfeit.player2_score), (None, None));
+    assert_eq!(client.get_match_record(&player3, &0), Some(forfeit));
+    assert_eq!(client.get_match_count(&player2), 1);
+    assert_eq!(client.get_match_record(&player1, &2), None);
+}
+
+#[test]
+fn test_match_history_outlives_the_game() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    client.start_game(&262, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 262, &player1, &player2, 3, 3);
+    assert_eq!(client.resolve_match(&262), Outcome::Draw);
+
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    assert!(client.try_get_game(&262).is_err());
+    let record = client.get_match_record(&player2, &0).unwrap();
+    assert_eq!((record.session_id, record.outcome), (262, Outcome::Draw));
+    assert_eq!((record.player1_score, record.player2_score), (Some(4), Some(4)));
+}
+
+#[test]
+fn test_history_pages_newest_first() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_history(&player1, &None, &10), vec![&env]);
+
+    // Aggressive beats Balanced, whichever side plays it
+    for (session_id, tactic1, tactic2) in [(263, 2, 1), (264, 1, 2), (265, 1, 1)] {
+        client.start_game(&session_id, &player1, &player2, &100, &40);
+        play_tactics(&client, session_id, &player1, &player2, tactic1, tactic2);
+        client.resolve_match(&session_id);
+    }
+
+    let page = client.get_history(&player2, &None, &2);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            GameSummary {
+                index: 2,
+                session_id: 265,
+                opponent: player1.clone(),
+                points: 40,
+                opponent_points: 100,
+                score: Some(1),
+                opponent_score: Some(1),
+                result: MatchResult::Drawn,
+                ledger: 100,
+            },
+            GameSummary {
+                index: 1,
+                session_id: 264,
+                opponent: player1.clone(),
+                points: 40,
+                opponent_points: 100,
+                score: Some(2),
+                opponent_score: Some(1),
+                result: MatchResult::Won,
+                ledger: 100,
+            },
+        ]
+    );
+
+    let cursor = page.last().unwrap().index;
+    let rest = client.get_history(&player2, &Some(cursor), &2);
+    assert_eq!(rest.len(), 1);
+    let oldest = rest.get(0).unwrap();
+    assert_eq!((oldest.session_id, oldest.result), (263, MatchResult::Lost));
+    assert_eq!(client.get_history(&player2, &Some(0), &2), vec![&env]);
+
+    let mine = client.get_history(&player1, &None, &u32::MAX);
+    assert_eq!(mine.len(), 3);
+    assert_eq!(mine.get(2).unwrap().result, MatchResult::Won);
+    assert_eq!(mine.get(2).unwrap().opponent, player2);
+}
+
+#[test]
+fn test_history_page_is_capped() {
+    let (env, client, player1, player2) = setup_test();
+    for session_id in 0..MAX_HISTORY_PAGE + 2 {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        submit_one(&client, session_id, &player1, 1);
+    }
+    env.ledger().set_sequence_number(client.get_game_public(&0).game.submit_deadline_ledger + 1);
+    for session_id in 0..MAX_HISTORY_PAGE + 2 {
+        client.resolve_timeout(&session_id);
+    }
+
+    let page = client.get_history(&player1, &None, &100);
+    assert_eq!(page.len(), MAX_HISTORY_PAGE);
+    assert_eq!(page.get(0).unwrap().session_id, MAX_HISTORY_PAGE + 1);
+    let rest = client.get_history(&player1, &Some(page.last().unwrap().index), &100);
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest.last().unwrap().session_id, 0);
+}
+
+#[test]
+fn test_player_stats_count_every_settlement() {
+    let (env, client, player1, player2) = setup_test();
+    let empty = client.get_player_stats(&player1);
+    assert_eq!((empty.games, empty.favorite_tactic), (0, None));
+
+    for (session_id, tactic1, tactic2) in [(266, 2, 1), (267, 1, 2), (268, 1, 1)] {
+        client.start_game(&session_id, &player1, &player2, &100, &40);
+        play_tactics(&client, session_id, &player1, &player2, tactic1, tactic2);
+        client.resolve_match(&session_id);
+    }
+    // player2 never submits and forfeits
+    client.start_game(&269, &player1, &player2, &100, &40);
+    submit_one(&client, 269, &player1, 3);
+    env.ledger().set_sequence_number(client.get_game_public(&269).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&269);
+
+    assert_eq!(
+        client.get_player_stats(&player1),
+        PlayerStats {
+            games: 4,
+            wins: 2,
+            losses: 1,
+            draws: 1,
+            forfeits: 0,
+            total_wagered: 400,
+            tactic_counts: vec![&env, 0, 2, 1, 0],
+            favorite_tactic: Some(1),
+        }
+    );
+    assert_eq!(
+        client.get_player_stats(&player2),
+        PlayerStats {
+            games: 4,
+            wins: 1,
+            losses: 2,
+            draws: 1,
+            forfeits: 1,
+            total_wagered: 160,
+            tactic_counts: vec![&env, 0, 2, 1, 0],
+            favorite_tactic: Some(1),
+        }
+    );
+}
+
+// ============================================================================
+// Storage Lifetime Tests
+// ============================================================================
+
+#[test]
+fn test_purge_settled_game() {
+    let (_env, client, player1, player2) = setup_test();
+    client.start_game(&270, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 270, &player1, &player2, 2, 1);
+    client.resolve_match(&270);
+
+    client.purge_game(&270, &player2);
+    assert!(client.try_get_game(&270).is_err());
+    match client.try_resolve_match(&270) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+
+    // The result lives on in match history
+    let record = client.get_match_record(&player1, &0).unwrap();
+    assert_eq!((record.session_id, record.outcome), (270, Outcome::Player1Win));
+
+    // The session ID is free again
+    client.start_game(&270, &player1, &player2, &10_0000000, &10_0000000);
+    assert_eq!(client.get_game_public(&270).game.player1_points, 10_0000000);
+}
+
+#[test]
+fn test_purge_needs_settled_game() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&271, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 271, &player1, 1);
+    match client.try_purge_game(&271, &player1) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotSettled),
+        _ => panic!("Expected GameNotSettled error"),
+    }
+
+    let outsider = Address::generate(&env);
+    client.start_game(&273, &player1, &player2, &100_0000000, &100_0000000);
+    play_tactics(&client, 273, &player1, &player2, 0, 0);
+    client.resolve_match(&273);
+    match client.try_purge_game(&273, &outsider) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotPlayer),
+        _ => panic!("Expected NotPlayer error"),
+    }
+    assert!(client.try_get_game_public(&271).is_ok());
+    assert!(client.try_get_game(&273).is_ok());
+}
+
+#[test]
+fn test_purge_drops_per_session_entries() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_rounds_verifier(&env.register(MockVerifier, (true,)));
+    client.start_multi_round_game(&274, &player1, &player2, &100_0000000, &100_0000000, &3);
+    play_rounds(&client, 274, &player1, &player2, &[1, 2, 0], &[0, 1, 1]);
+    client.resolve_match(&274);
+    env.as_contract(&client.address, || assert!(env.storage().temporary().has(&DataKey::MultiRound(274))));
+    client.purge_game(&274, &player1);
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        assert!(!storage.has(&DataKey::Game(274)));
+        assert!(!storage.has(&DataKey::MultiRound(274)));
+    });
+}
+
+#[test]
+fn test_extend_game_ttl_outlives_default() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    client.start_game(&275, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 275, &player1, 2);
+    let start = env.ledger().sequence();
+
+    // Anyone can bump a game; it survives past both default lifetimes
+    client.extend_game_ttl(&275, &2_000_000);
+    env.ledger().set_sequence_number(start + 2 * 518_400 + 1);
+    assert_eq!(client.get_game_public(&275).game.player1, player1);
+    assert!(client.get_open_session(&275).is_some());
+
+    // The open session record still outlives the game
+    env.ledger().set_sequence_number(start + 2_000_000 + 1);
+    assert!(client.try_get_game(&275).is_err());
+    assert_eq!(client.abandon_expired(&275).player2, player2);
+}
+
+#[test]
+fn test_extend_game_ttl_bounds() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_max_ttl(1_000_000).apply(&env);
+    match client.try_extend_game_ttl(&276, &518_400) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotFound),
+        _ => panic!("Expected GameNotFound error"),
+    }
+
+    client.start_game(&276, &player1, &player2, &100_0000000, &100_0000000);
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    for ledgers in [0, max_ttl + 1] {
+        match client.try_extend_game_ttl(&276, &ledgers) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidTtl),
+            _ => panic!("Expected InvalidTtl error"),
+        }
+    }
+    client.extend_game_ttl(&276, &max_ttl);
+}
+
+// ============================================================================
+// Auto-Resolve Tests
+// ============================================================================
+
+#[test]
+fn test_auto_resolve_on_last_reveal() {
+    let (env, client, player1, player2) = setup_test();
+    assert!(!client.get_auto_resolve());
+
+    // Games keep the mode they were started with
+    client.start_game(&240, &player1, &player2, &100_0000000, &100_0000000);
+    client.set_auto_resolve(&client.get_admin(), &true);
+    assert!(client.get_auto_resolve());
+    client.start_game(&241, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(!client.get_game_public(&240).game.auto_resolve);
+    assert!(client.get_game_public(&241).game.auto_resolve);
+
+    play_tactics(&client, 240, &player1, &player2, 2, 1);
+    assert_eq!(client.get_game_public(&240).game.outcome(), None);
+
+    client.submit_tactic(&241, &player1, &mock_inputs(&client, &player1, 241, 2), &mock_player_proof(&env, &player1, 2, 241));
+    client.submit_tactic(&241, &player2, &mock_inputs(&client, &player2, 241, 1), &mock_player_proof(&env, &player2, 1, 241));
+    mock_reveal(&client, &player1, 241, 2);
+    assert_eq!(client.get_game_public(&241).game.outcome(), None);
+
+    // Aggressive (2) beats Balanced (1), settled by the second reveal
+    mock_reveal(&client, &player2, 241, 1);
+    let game = client.get_game(&241);
+    assert_eq!(game.outcome(), Some(Outcome::Player1Win));
+    assert_eq!((game.player1_score, game.player2_score), (Some(2), Some(1)));
+    assert_eq!(client.get_open_session(&241), None);
+    assert_eq!(client.resolve_match(&241), Outcome::Player1Win);
+}
+
+#[test]
+fn test_auto_resolve_waits_for_stakes_and_rounds() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_auto_resolve(&client.get_admin(), &true);
+
+    // Hidden stakes are revealed after the tactics
+    start_hidden(&client, 242, &player1, &player2, 250, 700);
+    play_tactics(&client, 242, &player1, &player2, 2, 1);
+    client.reveal_stake(&242, &player1, &250, &BytesN::from_array(&env, &[0xa1; 32]));
+    assert_eq!(client.get_game_public(&242).game.outcome(), None);
+    client.reveal_stake(&242, &player2, &700, &BytesN::from_array(&env, &[0xb2; 32]));
+    let game = client.get_game(&242);
+    assert_eq!(game.outcome(), Some(Outcome::Player1Win));
+    assert_eq!((game.player1_points, game.player2_points), (250, 700));
+
+    // Multi-round games settle once every round is open
+    client.start_multi_round_game(&243, &player1, &player2, &100_0000000, &100_0000000, &3);
+    play_rounds(&client, 243, &player1, &player2, &[1, 2, 0], &[0, 1, 1]);
+    assert_eq!(client.get_game(&243).outcome(), Some(Outcome::Player1Win));
+
+    // Simulated games still wait for their receipt
+    start_simulated(&client, 244, &player1, &player2);
+    play_tactics(&client, 244, &player1, &player2, 2, 3);
+    assert_eq!(client.get_game_public(&244).game.outcome(), None);
+}
+
+// ============================================================================
+// Proof System Tests
+// ============================================================================
+
+/// Route new games to a mock UltraHonk verifier
+fn setup_ultrahonk(
+    env: &Env,
+    client: &ZkTacticalMatchContractClient,
+) -> MockVerifierClient<'static> {
+    let verifier_addr = env.register(MockVerifier, (true,));
+    client.set_ultrahonk_verifier(&verifier_addr);
+    client.set_proof_system(&ProofSystem::UltraHonk);
+    MockVerifierClient::new(env, &verifier_addr)
+}
+
+#[test]
+fn test_proof_system_defaults_to_groth16() {
+    let (_env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_proof_system(), ProofSystem::Groth16);
+
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game_public(&1).game.proof_system, ProofSystem::Groth16);
+}
+
+#[test]
+fn test_ultrahonk_game_uses_ultrahonk_verifier() {
+    let (env, client, player1, player2) = setup_test();
+    let verifier = setup_ultrahonk(&env, &client);
+
+    let session_id = 50u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game_public(&session_id).game.proof_system, ProofSystem::UltraHonk);
+
+    let inputs = mock_inputs(&client, &player1, session_id, 3);
+    client.submit_tactic(&session_id, &player1, &inputs, &mock_proof(&env, 3, session_id));
+    assert_eq!(verifier.calls(), 1);
+
+    // Public inputs arrive as concatenated 32-byte fields
+    assert_eq!(verifier.last_public_inputs(), Some(inputs.to_bytes(&env)));
+    assert_eq!(inputs.to_bytes(&env).len(), 192);
+}
+
+#[test]
+fn test_ultrahonk_rejects_proof() {
+    let (env, client, player1, player2) = setup_test();
+    let verifier = setup_ultrahonk(&env, &client);
+
+    let session_id = 51u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let bad_proof = mock_proof(&env, 1, session_id);
+    verifier.set_proof(&bad_proof, &false);
+
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 1), &bad_proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(client.get_game_public(&session_id).game.player1_commitment.is_none());
+}
+
+#[test]
+fn test_ultrahonk_rejects_partial_field_element() {
+    let (env, client, player1, player2) = setup_test();
+    let verifier = setup_ultrahonk(&env, &client);
+
+    let session_id = 52u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let mut proof = mock_proof(&env, 0, session_id);
+    proof.push_back(0xab);
+    let result = client.try_submit_tactic(&session_id, &player1, &mock_inputs(&client, &player1, session_id, 0), &proof);
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert_eq!(verifier.calls(), 0);
+}
+
+#[test]
+fn test_proof_system_is_fixed_at_start() {
+    let (env, client, player1, player2) = setup_test();
+    store_groth16_vk(&env, &client);
+
+    // Started under Groth16, so switching the default does not apply
+    client.start_game(&60, &player1, &player2, &100_0000000, &100_0000000);
+    let verifier = setup_ultrahonk(&env, &client);
+    client.start_game(&61, &player1, &player2, &100_0000000, &100_0000000);
+
+    let result = client.try_submit_tactic(&60, &player1, &mock_inputs(&client, &player1, 60, 2), &mock_proof(&env, 2, 60));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert_eq!(verifier.calls(), 0);
+
+    client.submit_tactic(&61, &player1, &mock_inputs(&client, &player1, 61, 2), &mock_proof(&env, 2, 61));
+    assert_eq!(verifier.calls(), 1);
+}
+
+// ============================================================================
+// Batch Verification Tests
+// ============================================================================
+
+#[test]
+fn test_batch_verification_defaults_off() {
+    let (_env, client, player1, player2) = setup_test();
+    assert!(!client.get_batch_verification());
+
+    client.start_game(&1, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(!client.get_game_public(&1).game.batch_verification);
+}
+
+#[test]
+fn test_batch_verified_proofs_settle_match() {
+    let (env, client, player1, player2) = setup_groth16();
+    client.set_batch_verification(&true);
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(client.get_game_public(&session_id).game.batch_verification);
+
+    client.submit_tactic(&session_id, &player1, &groth16_inputs(&env, &p1), &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &groth16_inputs(&env, &p2), &p2.proof(&env));
+    client.reveal_tactic(&session_id, &player1, &p1.tactic, &p1.secret(&env));
+    client.reveal_tactic(&session_id, &player2, &p2.tactic, &p2.secret(&env));
+
+    // Aggressive (2) beats Balanced (1)
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+    let game = client.get_game(&session_id);
+    assert!(game.player1_score.is_some() && game.player2_score.is_some());
+}
+
+#[test]
+fn test_batch_verified_invalid_proof_forfeits() {
+    let (env, client, player1, player2) = setup_groth16();
+    client.set_batch_verification(&true);
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Claims a tactic the proof does not attest to; only caught at resolution
+    let inputs = groth16_inputs_for_tactic(&env, &p1, 3);
+    client.submit_tactic(&session_id, &player1, &inputs, &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &groth16_inputs(&env, &p2), &p2.proof(&env));
+    client.reveal_tactic(&session_id, &player1, &3, &p1.secret(&env));
+    client.reveal_tactic(&session_id, &player2, &p2.tactic, &p2.secret(&env));
+
+    // The failed batch and both single checks overrun the default budget
+    env.cost_estimate().budget().reset_unlimited();
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player2Win);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_tactic, None);
+    assert_eq!(game.player2_tactic, Some(p2.tactic));
+    assert_eq!(game.player1_score, None);
+    assert_eq!(game.player2_score, None);
+}
+
+#[test]
+fn test_batch_verified_both_invalid_fails() {
+    let (env, client, player1, player2) = setup_groth16();
+    client.set_batch_verification(&true);
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs1 = groth16_inputs_for_tactic(&env, &p1, 3);
+    let inputs2 = groth16_inputs_for_tactic(&env, &p2, 0);
+    client.submit_tactic(&session_id, &player1, &inputs1, &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &inputs2, &p2.proof(&env));
+    client.reveal_tactic(&session_id, &player1, &3, &p1.secret(&env));
+    client.reveal_tactic(&session_id, &player2, &0, &p2.secret(&env));
+
+    env.cost_estimate().budget().reset_unlimited();
+    match client.try_resolve_match(&session_id) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+    assert!(client.get_game_public(&session_id).game.outcome().is_none());
+    assert!(!client.is_nullifier_used(&inputs1.nullifier));
+    assert!(!client.is_nullifier_used(&inputs2.nullifier));
+}
+
+#[test]
+fn test_batch_verified_nullifiers_consumed_at_settlement() {
+    let (env, client, player1, player2) = setup_groth16();
+    client.set_batch_verification(&true);
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let (inputs1, inputs2) = (groth16_inputs(&env, &p1), groth16_inputs(&env, &p2));
+    client.submit_tactic(&session_id, &player1, &inputs1, &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &inputs2, &p2.proof(&env));
+
+    // Unverified proofs reserve nothing
+    assert!(!client.is_nullifier_used(&inputs1.nullifier));
+    assert!(!client.is_nullifier_used(&inputs2.nullifier));
+
+    client.reveal_tactic(&session_id, &player1, &p1.tactic, &p1.secret(&env));
+    client.reveal_tactic(&session_id, &player2, &p2.tactic, &p2.secret(&env));
+    client.resolve_match(&session_id);
+    assert!(client.is_nullifier_used(&inputs1.nullifier));
+    assert!(client.is_nullifier_used(&inputs2.nullifier));
+}
+
+#[test]
+fn test_batch_verified_forfeit_needs_no_opponent_reveal() {
+    let (env, client, player1, player2) = setup_groth16();
+    client.set_batch_verification(&true);
+
+    let [p1, p2] = GROTH16_PROOFS;
+    let session_id = p1.session_id;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let (inputs1, inputs2) = (groth16_inputs_for_tactic(&env, &p1, 3), groth16_inputs(&env, &p2));
+    client.submit_tactic(&session_id, &player1, &inputs1, &p1.proof(&env));
+    client.submit_tactic(&session_id, &player2, &inputs2, &p2.proof(&env));
+
+    // The cheater never reveals; the honest player need not either
+    env.cost_estimate().budget().reset_unlimited();
+    assert_eq!(client.resolve_match(&session_id), Outcome::Player2Win);
+    assert_eq!(client.get_game(&session_id).player1_score, None);
+    assert!(!client.is_nullifier_used(&inputs1.nullifier));
+    assert!(client.is_nullifier_used(&inputs2.nullifier));
+}
+
+#[test]
+fn test_batch_verified_submit_still_checks_format() {
+    let (env, client, player1, player2) = setup_groth16();
+    client.set_batch_verification(&true);
+
+    let vector = GROTH16_PROOFS[0];
+    client.start_game(&vector.session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let inputs = groth16_inputs(&env, &vector);
+    let result = client.try_submit_tactic(&vector.session_id, &player1, &inputs, &Bytes::from_array(&env, &[0u8; 256]));
+    match result {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidProof),
+        _ => panic!("Expected InvalidProof error"),
+    }
+}
+
+#[test]
+fn test_batch_verification_is_fixed_at_start() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&70, &player1, &player2, &100_0000000, &100_0000000);
+    client.set_batch_verification(&true);
+    assert!(client.get_batch_verification());
+    client.start_game(&71, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert!(!client.get_game_public(&70).game.batch_verification);
+    assert!(client.get_game_public(&71).game.batch_verification);
+
+    // UltraHonk proofs cannot share a pairing check
+    setup_ultrahonk(&env, &client);
+    client.start_game(&72, &player1, &player2, &100_0000000, &100_0000000);
+    assert!(!client.get_game_public(&72).game.batch_verification);
+}
+
+// ============================================================================
+// Game Phase Tests
+// ============================================================================
+
+#[test]
+fn test_phase_follows_game_lifecycle() {
+    let (_env, client, player1, player2) = setup_test();
+    client.start_game(&280, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game_public(&280).game.phase, GamePhase::Created);
+
+    submit_one(&client, 280, &player2, 1);
+    assert_eq!(client.get_game_public(&280).game.phase, GamePhase::AwaitingOpponent);
+    submit_one(&client, 280, &player1, 2);
+    assert_eq!(client.get_game_public(&280).game.phase, GamePhase::BothSubmitted);
+
+    mock_reveal(&client, &player1, 280, 2);
+    mock_reveal(&client, &player2, 280, 1);
+    assert_eq!(client.get_game_public(&280).game.phase, GamePhase::BothSubmitted);
+    assert_eq!(client.resolve_match(&280), Outcome::Player1Win);
+    let game = client.get_game(&280);
+    assert_eq!(game.phase, GamePhase::Resolved);
+    assert!(game.is_over());
+
+    // Resolving again just returns the result
+    assert_eq!(client.resolve_match(&280), Outcome::Player1Win);
+}
+
+#[test]
+fn test_entrypoints_reject_wrong_phase() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&281, &player1, &player2, &100_0000000, &100_0000000);
+    match client.try_resolve_match(&281) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+
+    play_tactics(&client, 281, &player1, &player2, 2, 1);
+    client.resolve_match(&281);
+    let proof = mock_player_proof(&env, &player1, 3, 281);
+    match client.try_submit_tactic(&281, &player1, &mock_inputs(&client, &player1, 281, 3), &proof) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+    match client.try_reveal_tactic(&281, &player1, &2, &mock_secret(&env, &player1, 281)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+        _ => panic!("Expected InvalidPhase error"),
+    }
+}
+
+#[test]
+fn test_timeout_ends_game_timed_out() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&282, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 282, &player1, 0);
+    env.ledger().set_sequence_number(client.get_game_public(&282).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&282);
+
+    let game = client.get_game(&282);
+    assert_eq!(game.phase, GamePhase::TimedOut);
+    assert_eq!(game.outcome(), Some(Outcome::Player1Win));
+    assert_eq!(client.resolve_match(&282), Outcome::Player1Win);
+}
+
+#[test]
+fn test_committed_strategy_starts_both_submitted() {
+    let (_env, client, player1, player2) = setup_test();
+    start_committed(&client, 283, &player1, &player2, &[1, 2], &[0, 3]);
+    assert_eq!(client.get_game_public(&283).game.phase, GamePhase::BothSubmitted);
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_new_games_and_submissions() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&290, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 290, &player1, 2);
+
+    assert!(!client.is_paused());
+    client.pause(&client.get_admin());
+    assert!(client.is_paused());
+
+    match client.try_start_game(&291, &player1, &player2, &100_0000000, &100_0000000) {
+        Err(Ok(err)) => assert_eq!(err, Error::Paused),
+        _ => panic!("Expected Paused error"),
+    }
+    match client.try_start_multi_round_game(&292, &player1, &player2, &100_0000000, &100_0000000, &3) {
+        Err(Ok(err)) => assert_eq!(err, Error::Paused),
+        _ => panic!("Expected Paused error"),
+    }
+    let proof = mock_player_proof(&env, &player2, 1, 290);
+    match client.try_submit_tactic(&290, &player2, &mock_inputs(&client, &player2, 290, 1), &proof) {
+        Err(Ok(err)) => assert_eq!(err, Error::Paused),
+        _ => panic!("Expected Paused error"),
+    }
+
+    client.unpause();
+    assert!(!client.is_paused());
+    submit_one(&client, 290, &player2, 1);
+    client.start_game(&291, &player1, &player2, &100_0000000, &100_0000000);
+}
+
+#[test]
+fn test_pause_lets_games_settle() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&293, &player1, &player2, &100_0000000, &100_0000000);
+    client.start_game(&294, &player1, &player2, &100_0000000, &100_0000000);
+    submit_one(&client, 293, &player1, 2);
+    submit_one(&client, 293, &player2, 1);
+    submit_one(&client, 294, &player2, 0);
+    client.pause(&client.get_admin());
+
+    mock_reveal(&client, &player1, 293, 2);
+    mock_reveal(&client, &player2, 293, 1);
+    assert_eq!(client.resolve_match(&293), Outcome::Player1Win);
+
+    env.ledger().set_sequence_number(client.get_game_public(&294).game.submit_deadline_ledger + 1);
+    assert_eq!(client.resolve_timeout(&294), player2);
+}
+
+// ============================================================================
+// Open Game Limit Tests
+// ============================================================================
+
+#[test]
+fn test_open_game_limit_applies_to_both_players() {
+    let (env, client, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    assert_eq!(client.get_max_open_games(), None);
+    client.set_max_open_games(&client.get_admin(), &Some(2));
+    assert_eq!(client.get_max_open_games(), Some(2));
+
+    client.start_game(&300, &player1, &player2, &100, &100);
+    client.start_multi_round_game(&301, &player1, &player3, &100, &100, &3);
+    assert_eq!(client.get_open_games(&player1), vec![&env, 300, 301]);
+
+    // Player 1 is at the limit, whichever side they play
+    match client.try_start_game(&302, &player3, &player1, &100, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::TooManyOpenGames),
+        _ => panic!("Expected TooManyOpenGames error"),
+    }
+    match client.try_start_hidden_stake_game(
+        &302,
+        &player1,
+        &player2,
+        &0,
+        &100,
+        &hidden_stake(&env, 50, 0xa1, 302),
+        &hidden_stake(&env, 50, 0xb2, 302),
+    ) {
+        Err(Ok(err)) => assert_eq!(err, Error::TooManyOpenGames),
+        _ => panic!("Expected TooManyOpenGames error"),
+    }
+    assert_eq!(client.get_open_session(&302), None);
+
+    // Player 2 still has room
+    client.start_game(&303, &player2, &player3, &100, &100);
+
+    // Lifting the limit lets the game start
+    client.set_max_open_games(&client.get_admin(), &None);
+    client.start_game(&302, &player3, &player1, &100, &100);
+    assert_eq!(client.get_open_games(&player1), vec![&env, 300, 301, 302]);
+
+    for limit in [0, MAX_OPEN_GAMES_LIMIT + 1] {
+        match client.try_set_max_open_games(&client.get_admin(), &Some(limit)) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidGameLimit),
+            _ => panic!("Expected InvalidGameLimit error"),
+        }
+    }
+    client.set_max_open_games(&client.get_admin(), &Some(MAX_OPEN_GAMES_LIMIT));
+}
+
+#[test]
+fn test_settled_and_expired_games_stop_counting() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    client.set_max_open_games(&client.get_admin(), &Some(1));
+
+    client.start_game(&304, &player1, &player2, &100, &100);
+    play_tactics(&client, 304, &player1, &player2, 2, 1);
+    client.resolve_match(&304);
+    assert_eq!(client.get_open_games(&player1), vec![&env]);
+
+    // Forfeits settle too
+    client.start_game(&305, &player1, &player2, &100, &100);
+    submit_one(&client, 305, &player1, 2);
+    env.ledger().set_sequence_number(client.get_game_public(&305).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&305);
+
+    // A game left to expire frees its slot with it
+    client.start_game(&306, &player1, &player2, &100, &100);
+    match client.try_start_game(&307, &player1, &player2, &100, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::TooManyOpenGames),
+        _ => panic!("Expected TooManyOpenGames error"),
+    }
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    assert_eq!(client.get_open_games(&player1), vec![&env]);
+    client.start_game(&307, &player1, &player2, &100, &100);
+    assert_eq!(client.get_open_games(&player2), vec![&env, 307]);
+}
+
+#[test]
+fn test_open_games_are_indexed_until_they_end() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    let indexed = |player: &Address| -> Option<Vec<u32>> {
+        env.as_contract(&client.address, || {
+            env.storage().persistent().get(&DataKey::PlayerGames(player.clone()))
+        })
+    };
+
+    for session_id in [310, 311, 312] {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+    assert_eq!(client.get_open_games(&player1), vec![&env, 310, 311, 312]);
+    assert_eq!(client.get_open_games(&player2), vec![&env, 310, 311, 312]);
+
+    play_tactics(&client, 310, &player1, &player2, 2, 1);
+    client.resolve_match(&310);
+    client.cancel_game(&311);
+    assert_eq!(indexed(&player1), Some(vec![&env, 312]));
+
+    // An expired game drops out of the listing at once, and out of the
+    // index once abandoned
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    assert_eq!(client.get_open_games(&player2), vec![&env]);
+    assert_eq!(indexed(&player2), Some(vec![&env, 312]));
+    client.abandon_expired(&312);
+    assert_eq!((indexed(&player1), indexed(&player2)), (None, None));
+}
+
+// ============================================================================
+// Wager Limit Tests
+// ============================================================================
+
+#[test]
+fn test_negative_wagers_rejected_by_default() {
+    let (_env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_wager_limits(), WagerLimits { min_wager: 0, max_wager: i128::MAX });
+
+    match client.try_start_game(&310, &player1, &player2, &-1, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::WagerTooSmall),
+        _ => panic!("Expected WagerTooSmall error"),
+    }
+    match client.try_start_multi_round_game(&310, &player1, &player2, &100, &-1, &3) {
+        Err(Ok(err)) => assert_eq!(err, Error::WagerTooSmall),
+        _ => panic!("Expected WagerTooSmall error"),
+    }
+    assert_eq!(client.get_open_session(&310), None);
+
+    // Free games are allowed until a minimum is set
+    client.start_game(&310, &player1, &player2, &0, &0);
+}
+
+#[test]
+fn test_wager_limits_apply_to_new_games() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_wager_limits(&client.get_admin(), &10, &1_000);
+
+    match client.try_start_game(&311, &player1, &player2, &0, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::WagerTooSmall),
+        _ => panic!("Expected WagerTooSmall error"),
+    }
+    match client.try_start_game_with_deadlines(&311, &player1, &player2, &100, &1_001, &100, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::WagerTooLarge),
+        _ => panic!("Expected WagerTooLarge error"),
+    }
+    client.start_game(&311, &player1, &player2, &10, &1_000);
+
+    // Hidden stakes are checked by their range
+    match client.try_start_hidden_stake_game(
+        &312,
+        &player1,
+        &player2,
+        &10,
+        &2_000,
+        &hidden_stake(&env, 50, 0xa1, 312),
+        &hidden_stake(&env, 50, 0xb2, 312),
+    ) {
+        Err(Ok(err)) => assert_eq!(err, Error::WagerTooLarge),
+        _ => panic!("Expected WagerTooLarge error"),
+    }
+    start_hidden(&client, 312, &player1, &player2, 50, 50);
+
+    for (min, max) in [(-1, 100), (100, 99)] {
+        match client.try_set_wager_limits(&client.get_admin(), &min, &max) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidWagerLimits),
+            _ => panic!("Expected InvalidWagerLimits error"),
+        }
+    }
+    assert_eq!(client.get_wager_limits(), WagerLimits { min_wager: 10, max_wager: 1_000 });
+}
+
+// ============================================================================
+// Escrow Tests
+// ============================================================================
+
+/// Wager games in a fresh token and give both players 1,000 of it
+fn setup_escrow(client: &ZkTacticalMatchContractClient, player1: &Address, player2: &Address) -> Address {
+    let env = &client.env;
+    let token = wager_token(env);
+    client.set_wager_token(&client.get_admin(), &Some(token.clone()));
+    fund(env, &token, &[player1, player2], 1_000);
+    token
+}
+
+#[test]
+fn test_start_game_escrows_wagers() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_wager_token(), None);
+    client.start_game(&400, &player1, &player2, &100, &50);
+    assert_eq!(client.get_escrow(&400), None);
+
+    let token = setup_escrow(&client, &player1, &player2);
+    client.start_game(&401, &player1, &player2, &100, &50);
+    assert_eq!(
+        client.get_escrow(&401),
+        Some(Escrow {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_token: token.clone(),
+            player2_token: token.clone(),
+            player1_amount: 100,
+            player2_amount: 50,
+            fee_bps: 0,
+            settlement: Settlement::Pending,
+            dues: vec![&env],
+        })
+    );
+    assert_eq!(balance(&env, &token, &player1), 900);
+    assert_eq!(balance(&env, &token, &player2), 950);
+    assert_eq!(balance(&env, &token, &client.address), 150);
+
+    // Other start paths escrow too; hidden stakes lock the top of the range
+    fund(&env, &token, &[&player1, &player2], 1_000);
+    start_hidden(&client, 402, &player1, &player2, 250, 700);
+    let escrow = client.get_escrow(&402).unwrap();
+    assert_eq!((escrow.player1_amount, escrow.player2_amount), (1_000, 1_000));
+}
+
+#[test]
+fn test_escrow_needs_funded_players() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+
+    assert!(client.try_start_game(&403, &player1, &player2, &100, &1_001).is_err());
+    assert!(client.try_get_game_public(&403).is_err());
+    assert_eq!(client.get_escrow(&403), None);
+    assert_eq!(balance(&env, &token, &player1), 1_000);
+
+    // Turning escrow off leaves games already started with their token
+    client.start_game(&404, &player1, &player2, &100, &100);
+    client.set_wager_token(&client.get_admin(), &None);
+    client.start_game(&405, &player1, &player2, &100, &100);
+    assert_eq!(client.get_escrow(&404).unwrap().player1_token, token);
+    assert_eq!(client.get_escrow(&405), None);
+}
+
+#[test]
+fn test_winner_is_paid_the_pot() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.start_game(&406, &player1, &player2, &100, &100);
+    play_tactics(&client, 406, &player1, &player2, 2, 1);
+    assert_eq!(client.resolve_match(&406), Outcome::Player1Win);
+
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [
+            WinningsPaid { session_id: 406, player: player1.clone(), token: token.clone(), amount: 200 }
+                .to_xdr(&env, &client.address),
+            MatchResolved {
+                session_id: 406,
+                player1: player1.clone(),
+                player2: player2.clone(),
+                outcome: Outcome::Player1Win,
+                player1_score: Some(2),
+                player2_score: Some(1),
+            }
+            .to_xdr(&env, &client.address),
+        ]
+    );
+    assert_eq!(balance(&env, &token, &player1), 1_100);
+    assert_eq!(balance(&env, &token, &player2), 900);
+    assert_eq!(balance(&env, &token, &client.address), 0);
+
+    // Fully paid out, so the session ID is free again
+    assert_eq!(client.get_escrow(&406), None);
+    client.start_game(&406, &player1, &player2, &100, &100);
+
+    // A hidden-stake loser gets back what they locked beyond their stake
+    fund(&env, &token, &[&player1, &player2], 1_000);
+    start_hidden(&client, 407, &player1, &player2, 250, 700);
+    play_tactics(&client, 407, &player1, &player2, 1, 2);
+    client.reveal_stake(&407, &player1, &250, &BytesN::from_array(&env, &[0xa1; 32]));
+    client.reveal_stake(&407, &player2, &700, &BytesN::from_array(&env, &[0xb2; 32]));
+    assert_eq!(client.resolve_match(&407), Outcome::Player2Win);
+    assert_eq!(balance(&env, &token, &client.address), 200);
+    assert_eq!(balance(&env, &token, &player1), 1_000 + 750);
+    assert_eq!(balance(&env, &token, &player2), 800 + 1_250);
+}
+
+#[test]
+fn test_held_winnings_are_claimed() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.start_game(&408, &player1, &player2, &100, &100);
+    play_tactics(&client, 408, &player1, &player2, 2, 1);
+
+    match client.try_claim_winnings(&408) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+
+    // The winner cannot receive the token, so the pot is held for them
+    freeze(&env, &token, &player1, true);
+    client.resolve_match(&408);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [
+            WinningsHeld { session_id: 408, player: player1.clone(), token: token.clone(), amount: 200 }
+                .to_xdr(&env, &client.address),
+            MatchResolved {
+                session_id: 408,
+                player1: player1.clone(),
+                player2: player2.clone(),
+                outcome: Outcome::Player1Win,
+                player1_score: Some(2),
+                player2_score: Some(1),
+            }
+            .to_xdr(&env, &client.address),
+        ]
+    );
+    let escrow = client.get_escrow(&408).unwrap();
+    assert_eq!(escrow.settlement, Settlement::Winnings);
+    assert_eq!(escrow.dues, vec![&env, Due { player: player1.clone(), token: token.clone(), amount: 200 }]);
+    assert_eq!(balance(&env, &token, &client.address), 200);
+
+    // Held winnings keep the session ID in use, and stay held while the
+    // token still refuses them
+    match client.try_start_game(&408, &player1, &player2, &100, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::SessionAlreadyExists),
+        _ => panic!("Expected SessionAlreadyExists error"),
+    }
+    match client.try_claim_winnings(&408) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+
+    freeze(&env, &token, &player1, false);
+    assert_eq!(client.claim_winnings(&408), 200);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [WinningsClaimed { session_id: 408, player: player1.clone(), token: token.clone(), amount: 200 }
+            .to_xdr(&env, &client.address)]
+    );
+    assert_eq!(balance(&env, &token, &player1), 1_100);
+    assert_eq!(client.get_escrow(&408), None);
+    match client.try_claim_winnings(&408) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+}
+
+#[test]
+fn test_draws_cancellations_and_timeouts_refund() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+
+    // Balanced vs Balanced = 1-1
+    client.start_game(&409, &player1, &player2, &100, &50);
+    play_tactics(&client, 409, &player1, &player2, 1, 1);
+    assert_eq!(client.resolve_match(&409), Outcome::Draw);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [
+            RefundPaid { session_id: 409, player: player1.clone(), token: token.clone(), amount: 100 }
+                .to_xdr(&env, &client.address),
+            RefundPaid { session_id: 409, player: player2.clone(), token: token.clone(), amount: 50 }
+                .to_xdr(&env, &client.address),
+            MatchResolved {
+                session_id: 409,
+                player1: player1.clone(),
+                player2: player2.clone(),
+                outcome: Outcome::Draw,
+                player1_score: Some(1),
+                player2_score: Some(1),
+            }
+            .to_xdr(&env, &client.address),
+        ]
+    );
+    assert_eq!(balance(&env, &token, &player1), 1_000);
+    assert_eq!(balance(&env, &token, &player2), 1_000);
+    assert_eq!(client.get_escrow(&409), None);
+
+    client.start_game(&410, &player1, &player2, &100, &50);
+    client.cancel_game(&410);
+    assert_eq!(balance(&env, &token, &player1), 1_000);
+    assert_eq!(balance(&env, &token, &player2), 1_000);
+    assert_eq!(client.get_escrow(&410), None);
+
+    // The player who never submitted forfeits a share of their stake
+    client.start_game(&411, &player1, &player2, &100, &100);
+    submit_one(&client, 411, &player1, 2);
+    let deadline = client.get_game_public(&411).game.submit_deadline_ledger;
+    env.ledger().set_sequence_number(deadline + 1);
+    client.resolve_timeout(&411);
+    let forfeit = 100 * FORFEIT_SHARE_BPS as i128 / 10_000;
+    assert_eq!(balance(&env, &token, &player1), 1_000 + forfeit);
+    assert_eq!(balance(&env, &token, &player2), 1_000 - forfeit);
+    assert_eq!(balance(&env, &token, &client.address), 0);
+}
+
+#[test]
+fn test_claim_refund_is_idempotent() {
+    let (env, client, player1, player2) = setup_test();
+    TestLedger::default().with_temp_ttl(16).apply(&env);
+    let token = setup_escrow(&client, &player1, &player2);
+    assert_eq!(client.claim_refund(&412), 0);
+
+    client.start_game(&412, &player1, &player2, &100, &100);
+    match client.try_claim_refund(&412) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotSettled),
+        _ => panic!("Expected GameNotSettled error"),
+    }
+
+    // A refused refund is held, and claiming it pays nothing until the
+    // player can receive the token
+    freeze(&env, &token, &player2, true);
+    client.cancel_game(&412);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [
+            RefundPaid { session_id: 412, player: player1.clone(), token: token.clone(), amount: 100 }
+                .to_xdr(&env, &client.address),
+            RefundHeld { session_id: 412, player: player2.clone(), token: token.clone(), amount: 100 }
+                .to_xdr(&env, &client.address),
+            GameCancelled { session_id: 412, player1: player1.clone(), player2: player2.clone() }
+                .to_xdr(&env, &client.address),
+        ]
+    );
+    assert_eq!(
+        client.get_escrow(&412).unwrap().dues,
+        vec![&env, Due { player: player2.clone(), token: token.clone(), amount: 100 }]
+    );
+    assert_eq!(client.claim_refund(&412), 0);
+    freeze(&env, &token, &player2, false);
+    assert_eq!(client.claim_refund(&412), 100);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [RefundClaimed { session_id: 412, player: player2.clone(), token: token.clone(), amount: 100 }
+            .to_xdr(&env, &client.address)]
+    );
+    assert_eq!(client.claim_refund(&412), 0);
+    assert_eq!(balance(&env, &token, &player2), 1_000);
+
+    // A game left to expire refunds both players in full
+    client.start_game(&413, &player1, &player2, &100, &50);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 518_400 + 1);
+    assert_eq!(client.claim_refund(&413), 150);
+    assert_eq!(client.claim_refund(&413), 0);
+    assert_eq!(balance(&env, &token, &player1), 1_000);
+    assert_eq!(balance(&env, &token, &player2), 1_000);
+}
+
+#[test]
+fn test_fee_is_taken_from_won_pots() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    let admin = client.get_admin();
+    match client.try_set_fee_bps(&admin, &(MAX_FEE_BPS + 1)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidFee),
+        _ => panic!("Expected InvalidFee error"),
+    }
+
+    // A game keeps the fee it started with: 5% of a 200 pot
+    client.set_fee_bps(&admin, &MAX_FEE_BPS);
+    client.start_game(&414, &player1, &player2, &100, &100);
+    client.set_fee_bps(&admin, &0);
+    play_tactics(&client, 414, &player1, &player2, 2, 1);
+    client.resolve_match(&414);
+    assert_eq!(balance(&env, &token, &player1), 1_090);
+    assert_eq!(client.get_accrued_fees(&token), 10);
+
+    // Refunds pay no fee
+    client.set_fee_bps(&admin, &MAX_FEE_BPS);
+    client.start_game(&415, &player1, &player2, &100, &100);
+    client.cancel_game(&415);
+    assert_eq!(client.get_accrued_fees(&token), 10);
+
+    let outsider = Address::generate(&env);
+    match client.try_withdraw_fees(&outsider, &token) {
+        Err(Ok(err)) => assert_eq!(err, Error::MissingRole),
+        _ => panic!("Expected MissingRole error"),
+    }
+    let collector = Address::generate(&env);
+    client.set_fee_collector(&admin, &collector);
+    assert_eq!(client.withdraw_fees(&admin, &token), 10);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [FeesWithdrawn { token: token.clone(), caller: admin.clone(), collector: collector.clone(), amount: 10 }
+            .to_xdr(&env, &client.address)]
+    );
+    assert_eq!(balance(&env, &token, &collector), 10);
+    assert_eq!(balance(&env, &token, &client.address), 0);
+    match client.try_withdraw_fees(&admin, &token) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+}
+
+#[test]
+fn test_start_game_with_token() {
+    let (env, client, player1, player2) = setup_test();
+    let default_token = setup_escrow(&client, &player1, &player2);
+    let token = wager_token(&env);
+    fund(&env, &token, &[&player1, &player2], 500);
+
+    client.start_game_with_token(&416, &player1, &player2, &100, &100, &token);
+    assert_eq!(client.get_game_public(&416).game.wager_token, Some(token.clone()));
+    assert_eq!(client.get_escrow(&416).unwrap().player2_token, token);
+    assert_eq!(balance(&env, &token, &player1), 400);
+    assert_eq!(balance(&env, &default_token, &player1), 1_000);
+
+    // Paid out in the game's own token
+    play_tactics(&client, 416, &player1, &player2, 1, 2);
+    client.resolve_match(&416);
+    assert_eq!(balance(&env, &token, &player2), 600);
+    assert_eq!(balance(&env, &default_token, &player2), 1_000);
+
+    // Other games still use the contract-wide token
+    client.start_game(&417, &player1, &player2, &100, &100);
+    assert_eq!(client.get_game_public(&417).game.wager_token, Some(default_token));
+
+    // Players without the chosen token cannot start
+    let unfunded = wager_token(&env);
+    assert!(client.try_start_game_with_token(&418, &player1, &player2, &100, &100, &unfunded).is_err());
+    assert!(client.try_get_game_public(&418).is_err());
+}
+
+#[test]
+fn test_native_xlm_wagers() {
+    let (env, client, player1, player2) = setup_test();
+    let xlm = native_token(&env);
+    assert_eq!(client.get_native_token(), xlm);
+    fund_native(&env, &[&player1, &player2], 50_0000000);
+
+    // 5 XLM against 2 XLM, in stroops
+    client.start_native_game(&422, &player1, &player2, &5_0000000, &2_0000000);
+    assert_eq!(client.get_game_public(&422).game.wager_token, Some(xlm.clone()));
+    assert_eq!(balance(&env, &xlm, &client.address), 7_0000000);
+
+    play_tactics(&client, 422, &player1, &player2, 1, 2);
+    client.resolve_match(&422);
+    assert_eq!(balance(&env, &xlm, &player1), 45_0000000);
+    assert_eq!(balance(&env, &xlm, &player2), 55_0000000);
+
+    // XLM can also be the contract-wide wager token
+    client.set_wager_token(&client.get_admin(), &Some(xlm.clone()));
+    client.start_game(&423, &player1, &player2, &1_0000000, &1_0000000);
+    client.cancel_game(&423);
+    assert_eq!(balance(&env, &xlm, &player1), 45_0000000);
+    assert_eq!(balance(&env, &xlm, &client.address), 0);
+}
+
+/// Price two fresh tokens through a mock oracle (tolerance 1%) and give
+/// player1 1,000 of the first and player2 1,000 of the second
+fn setup_mixed(
+    client: &ZkTacticalMatchContractClient,
+    (player1, player2): (&Address, &Address),
+    (price1, price2): (i128, i128),
+) -> (Address, Address) {
+    let env = &client.env;
+    let oracle = env.register(MockPriceOracle, ());
+    let (token1, token2) = (wager_token(env), wager_token(env));
+    let prices = MockPriceOracleClient::new(env, &oracle);
+    prices.set_price(&token1, &price1);
+    prices.set_price(&token2, &price2);
+    let config = OracleConfig { oracle, tolerance_bps: 100, max_age_seconds: 300 };
+    client.set_oracle_config(&client.get_admin(), &Some(config));
+    fund(env, &token1, &[player1], 1_000);
+    fund(env, &token2, &[player2], 1_000);
+    (token1, token2)
+}
+
+#[test]
+fn test_mixed_game_pays_each_deposit_in_its_token() {
+    let (env, client, player1, player2) = setup_test();
+    let (token1, token2) = setup_mixed(&client, (&player1, &player2), (3_0000000, 1_0000000));
+
+    // 100 of token1 is worth 300 of token2
+    client.start_mixed_game(&419, &player1, &player2, &100, &300, &token1, &token2);
+    let escrow = client.get_escrow(&419).unwrap();
+    assert_eq!((escrow.player1_token, escrow.player2_token), (token1.clone(), token2.clone()));
+    assert_eq!(balance(&env, &token1, &player1), 900);
+    assert_eq!(balance(&env, &token2, &player2), 700);
+
+    // The winner takes both deposits
+    play_tactics(&client, 419, &player1, &player2, 1, 2);
+    assert_eq!(client.resolve_match(&419), Outcome::Player2Win);
+    assert_eq!(balance(&env, &token1, &player2), 100);
+    assert_eq!(balance(&env, &token2, &player2), 1_000);
+    assert_eq!(client.get_escrow(&419), None);
+
+    // A refund returns each player's own token
+    client.start_mixed_game(&420, &player1, &player2, &100, &298, &token1, &token2);
+    client.cancel_game(&420);
+    assert_eq!(balance(&env, &token1, &player1), 900);
+    assert_eq!(balance(&env, &token2, &player2), 1_000);
+}
+
+#[test]
+fn test_mixed_stakes_must_match_in_value() {
+    let (env, client, player1, player2) = setup_test();
+    let unpriced = wager_token(&env);
+    match client.try_start_mixed_game(&421, &player1, &player2, &100, &300, &unpriced, &unpriced) {
+        Err(Ok(err)) => assert_eq!(err, Error::OracleNotConfigured),
+        _ => panic!("Expected OracleNotConfigured error"),
+    }
+    let (token1, token2) = setup_mixed(&client, (&player1, &player2), (3_0000000, 1_0000000));
+
+    // Outside the 1% tolerance
+    match client.try_start_mixed_game(&421, &player1, &player2, &100, &290, &token1, &token2) {
+        Err(Ok(err)) => assert_eq!(err, Error::StakesNotEquivalent),
+        _ => panic!("Expected StakesNotEquivalent error"),
+    }
+    match client.try_start_mixed_game(&421, &player1, &player2, &100, &300, &token1, &unpriced) {
+        Err(Ok(err)) => assert_eq!(err, Error::PriceUnavailable),
+        _ => panic!("Expected PriceUnavailable error"),
+    }
+
+    // Quotes older than the oracle's max age are not used
+    env.ledger().with_mut(|li| li.timestamp += 301);
+    match client.try_start_mixed_game(&421, &player1, &player2, &100, &300, &token1, &token2) {
+        Err(Ok(err)) => assert_eq!(err, Error::PriceUnavailable),
+        _ => panic!("Expected PriceUnavailable error"),
+    }
+    assert!(client.try_get_game_public(&421).is_err());
+
+    let loose = OracleConfig {
+        oracle: client.address.clone(),
+        tolerance_bps: 10_001,
+        max_age_seconds: 0,
+    };
+    match client.try_set_oracle_config(&client.get_admin(), &Some(loose)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidOracleConfig),
+        _ => panic!("Expected InvalidOracleConfig error"),
+    }
+}
+
+#[test]
+fn test_side_bets_pay_the_winners_backers() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    let bettors = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    fund(&env, &token, &[&bettors[0], &bettors[1], &bettors[2]], 1_000);
+
+    client.start_game(&424, &player1, &player2, &100, &100);
+    client.place_side_bet(&424, &bettors[0], &player2, &100);
+    client.place_side_bet(&424, &bettors[1], &player2, &100);
+    client.place_side_bet(&424, &bettors[1], &player2, &200);
+    client.place_side_bet(&424, &bettors[2], &player1, &200);
+    assert_eq!(client.get_side_bet(&424, &bettors[1]), Some(SideBet { player: player2.clone(), amount: 300 }));
+    let pool = client.get_side_bet_pool(&424).unwrap();
+    assert_eq!((pool.player1_total, pool.player2_total), (200, 400));
+    assert_eq!(pool.rake_bps, DEFAULT_SIDE_BET_RAKE_BPS);
+    match client.try_claim_side_bet(&424, &bettors[0]) {
+        Err(Ok(err)) => assert_eq!(err, Error::GameNotSettled),
+        _ => panic!("Expected GameNotSettled error"),
+    }
+
+    // Player2 wins: 1% of the losing 200 is raked to them, and their
+    // backers share the other 198 by stake
+    play_tactics(&client, 424, &player1, &player2, 1, 2);
+    client.resolve_match(&424);
+    let pool = client.get_side_bet_pool(&424).unwrap();
+    assert_eq!((pool.result, pool.rake), (SideBetResult::Player2Won, 0));
+    assert_eq!(balance(&env, &token, &player2), 1_100 + 2);
+
+    assert_eq!(client.claim_side_bet(&424, &bettors[0]), 149);
+    match client.try_claim_side_bet(&424, &bettors[0]) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+    match client.try_claim_side_bet(&424, &bettors[2]) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+
+    // The last winning claim takes the rounding dust and empties the pool
+    assert_eq!(client.claim_side_bet(&424, &bettors[1]), 449);
+    assert_eq!(client.get_side_bet_pool(&424), None);
+    assert_eq!(balance(&env, &token, &bettors[0]), 1_049);
+    assert_eq!(balance(&env, &token, &bettors[1]), 1_149);
+    assert_eq!(balance(&env, &token, &bettors[2]), 800);
+    assert_eq!(balance(&env, &token, &client.address), 0);
+}
+
+#[test]
+fn test_side_bets_refund_and_close() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&425, &player1, &player2, &100, &100);
+    let bettor = Address::generate(&env);
+    match client.try_place_side_bet(&425, &bettor, &player1, &10) {
+        Err(Ok(err)) => assert_eq!(err, Error::SideBetsUnavailable),
+        _ => panic!("Expected SideBetsUnavailable error"),
+    }
+
+    let token = setup_escrow(&client, &player1, &player2);
+    fund(&env, &token, &[&bettor], 1_000);
+    client.start_game(&426, &player1, &player2, &100, &100);
+    for (backer, backed, amount) in [
+        (&player1, &player2, 10),
+        (&bettor, &bettor, 10),
+        (&bettor, &player1, 0),
+    ] {
+        match client.try_place_side_bet(&426, backer, backed, &amount) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidSideBet),
+            _ => panic!("Expected InvalidSideBet error"),
+        }
+    }
+    client.place_side_bet(&426, &bettor, &player1, &50);
+    match client.try_place_side_bet(&426, &bettor, &player2, &50) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidSideBet),
+        _ => panic!("Expected InvalidSideBet error"),
+    }
+
+    // Betting closes once both tactics are in
+    let late = Address::generate(&env);
+    fund(&env, &token, &[&late], 1_000);
+    play_tactics(&client, 426, &player1, &player2, 2, 2);
+    match client.try_place_side_bet(&426, &late, &player2, &50) {
+        Err(Ok(err)) => assert_eq!(err, Error::SideBetsClosed),
+        _ => panic!("Expected SideBetsClosed error"),
+    }
+
+    // A draw refunds every bet, with no rake
+    assert_eq!(client.resolve_match(&426), Outcome::Draw);
+    assert_eq!(client.get_side_bet_pool(&426).unwrap().result, SideBetResult::Refund);
+    assert_eq!(client.claim_side_bet(&426, &bettor), 50);
+    assert_eq!(balance(&env, &token, &bettor), 1_000);
+    assert_eq!(client.get_side_bet_pool(&426), None);
+
+    // So does a cancellation
+    client.start_game(&427, &player1, &player2, &100, &100);
+    client.place_side_bet(&427, &bettor, &player2, &70);
+    client.cancel_game(&427);
+    assert_eq!(client.claim_side_bet(&427, &bettor), 70);
+    assert_eq!(balance(&env, &token, &bettor), 1_000);
+}
+
+/// Set a randomness beacon for house games, with round 1 committed
+fn house_beacon(env: &Env, client: &ZkTacticalMatchContractClient) -> RandomnessBeaconClient<'static> {
+    let beacon = RandomnessBeaconClient::new(env, &env.register(RandomnessBeacon, (client.get_admin(),)));
+    client.set_house_beacon(&beacon.address);
+    commit_round(env, &beacon, 1);
+    beacon
+}
+
+fn beacon_seed(env: &Env, round: u64) -> BytesN<32> {
+    BytesN::from_array(env, &[round as u8; 32])
+}
+
+fn commit_round(env: &Env, beacon: &RandomnessBeaconClient, round: u64) {
+    let seed: Bytes = beacon_seed(env, round).into();
+    beacon.commit(&round, &env.crypto().sha256(&seed).into());
+}
+
+#[test]
+fn test_house_game_settles_against_the_bankroll() {
+    let (env, client, player, provider) = setup_test();
+    let token = wager_token(&env);
+    fund(&env, &token, &[&player, &provider], 1_000);
+    assert_eq!(client.deposit_bankroll(&provider, &token, &1_000), 1_000);
+
+    // The house needs a beacon to draw its tactics from
+    match client.try_start_house_game(&430, &player, &100, &token) {
+        Err(Ok(err)) => assert_eq!(err, Error::HouseBeaconNotSet),
+        _ => panic!("Expected HouseBeaconNotSet error"),
+    }
+    let beacon = house_beacon(&env, &client);
+    assert_eq!(client.get_house_beacon(), Some(beacon.address.clone()));
+
+    client.start_house_game(&430, &player, &100, &token);
+    let game = client.get_game_public(&430).game;
+    assert_eq!(game.player2, client.address);
+    assert_eq!(client.get_bankroll(&token), Bankroll { assets: 1_000, locked: 100, shares: 1_000 });
+
+    // The house is in as soon as the player submits, bound to the beacon's
+    // pending round, whose seed neither the player nor the contract knows
+    client.submit_tactic(&430, &player, &mock_inputs(&client, &player, 430, 2), &mock_player_proof(&env, &player, 2, 430));
+    assert_eq!(client.get_game_public(&430).game.phase, GamePhase::BothSubmitted);
+    assert_eq!(client.get_house_round(&430), Some((beacon.address.clone(), 1)));
+    let stored: Game = env.as_contract(&client.address, || env.storage().temporary().get(&DataKey::Game(430)).unwrap());
+    assert_eq!(stored.player2_tactic, None);
+
+    // Only the unlocked part can be withdrawn while the game is in play
+    match client.try_withdraw_bankroll(&provider, &token, &1_000) {
+        Err(Ok(err)) => assert_eq!(err, Error::InsufficientBankroll),
+        _ => panic!("Expected InsufficientBankroll error"),
+    }
+
+    // Until the round is revealed the house has no tactic to score
+    mock_reveal(&client, &player, 430, 2);
+    match client.try_resolve_match(&430) {
+        Err(Ok(err)) => assert_eq!(err, Error::TacticNotRevealed),
+        _ => panic!("Expected TacticNotRevealed error"),
+    }
+    beacon.reveal(&1, &beacon_seed(&env, 1));
+    let outcome = client.resolve_match(&430);
+    let assets = match outcome {
+        Outcome::Player1Win => 900,
+        Outcome::Player2Win => 1_100,
+        Outcome::Draw => panic!("The house wins ties"),
+    };
+    assert_eq!(client.get_bankroll(&token), Bankroll { assets, locked: 0, shares: 1_000 });
+    assert_eq!(balance(&env, &token, &player), 2_000 - assets);
+
+    // The provider redeems their shares at the bankroll's new value
+    assert_eq!(client.withdraw_bankroll(&provider, &token, &1_000), assets);
+    assert_eq!(client.get_bankroll_shares(&token, &provider), 0);
+    assert_eq!(balance(&env, &token, &client.address), 0);
+
+    // A player cannot commit while no round is pending
+    fund(&env, &token, &[&provider], 1_000);
+    client.deposit_bankroll(&provider, &token, &1_000);
+    client.start_house_game(&433, &player, &100, &token);
+    match client.try_submit_tactic(&433, &player, &mock_inputs(&client, &player, 433, 2), &mock_player_proof(&env, &player, 2, 433)) {
+        Err(Ok(err)) => assert_eq!(err, Error::RandomnessNotReady),
+        _ => panic!("Expected RandomnessNotReady error"),
+    }
+}
+
+#[test]
+fn test_all_out_cannot_beat_the_house() {
+    let (env, client, player, provider) = setup_test();
+    let token = wager_token(&env);
+    fund(&env, &token, &[&player, &provider], 10_000);
+    client.deposit_bankroll(&provider, &token, &10_000);
+    let beacon = house_beacon(&env, &client);
+
+    // AllOut draws with everything but Balanced, which it beats. The house
+    // takes the draws, even under a tiebreak that would hand them to player1
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Player1);
+    let rounds = 40;
+    let mut wins = 0;
+    for round in 1..=rounds {
+        let session_id = 440 + round as u32;
+        if round > 1 {
+            commit_round(&env, &beacon, round);
+        }
+        client.start_house_game(&session_id, &player, &100, &token);
+        client.submit_tactic(&session_id, &player, &mock_inputs(&client, &player, session_id, 3), &mock_player_proof(&env, &player, 3, session_id));
+        beacon.reveal(&round, &beacon_seed(&env, round));
+        mock_reveal(&client, &player, session_id, 3);
+
+        let outcome = client.resolve_match(&session_id);
+        let house_tactic = client.get_game(&session_id).player2_tactic.unwrap();
+        if house_tactic == 1 {
+            assert_eq!(outcome, Outcome::Player1Win);
+            wins += 1;
+        } else {
+            assert_eq!(outcome, Outcome::Player2Win);
+        }
+    }
+
+    assert!(wins * 2 < rounds);
+    assert_eq!(balance(&env, &token, &player), 10_000 + 100 * (2 * wins as i128 - rounds as i128));
+    assert!(balance(&env, &token, &player) < 10_000);
+}
+
+#[test]
+fn test_bankroll_shares_and_limits() {
+    let (env, client, player, provider) = setup_test();
+    let token = wager_token(&env);
+    let late = Address::generate(&env);
+    fund(&env, &token, &[&player, &provider, &late], 1_000);
+    house_beacon(&env, &client);
+    match client.try_start_house_game(&431, &player, &100, &token) {
+        Err(Ok(err)) => assert_eq!(err, Error::InsufficientBankroll),
+        _ => panic!("Expected InsufficientBankroll error"),
+    }
+    match client.try_deposit_bankroll(&provider, &token, &0) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidBankrollAmount),
+        _ => panic!("Expected InvalidBankrollAmount error"),
+    }
+    client.deposit_bankroll(&provider, &token, &200);
+
+    // A player who never reveals forfeits their whole stake to the bankroll,
+    // even while the house's round is still unrevealed
+    client.start_house_game(&431, &player, &100, &token);
+    client.submit_tactic(&431, &player, &mock_inputs(&client, &player, 431, 1), &mock_player_proof(&env, &player, 1, 431));
+    let deadline = client.get_game_public(&431).game.resolve_deadline_ledger;
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.resolve_timeout(&431), client.address);
+    assert_eq!(client.get_bankroll(&token).assets, 300);
+
+    // Later deposits buy shares at the bankroll's value
+    assert_eq!(client.deposit_bankroll(&late, &token, &150), 100);
+    match client.try_withdraw_bankroll(&late, &token, &101) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidBankrollAmount),
+        _ => panic!("Expected InvalidBankrollAmount error"),
+    }
+
+    // A house game is called off by the player alone
+    client.start_house_game(&432, &player, &300, &token);
+    assert_eq!(client.get_bankroll(&token).locked, 300);
+    client.cancel_game(&432);
+    assert_eq!(client.get_bankroll(&token), Bankroll { assets: 450, locked: 0, shares: 300 });
+    assert_eq!(client.withdraw_bankroll(&late, &token, &100), 150);
+    assert_eq!(client.withdraw_bankroll(&provider, &token, &200), 300);
+    assert_eq!(balance(&env, &token, &player), 900);
+}
+
+#[test]
+fn test_referrers_earn_a_share_of_the_fee() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    let referrer = Address::generate(&env);
+    client.set_fee_bps(&client.get_admin(), &500);
+    client.set_referral_share_bps(&client.get_admin(), &2_500);
+
+    client.register_referrer(&player1, &referrer);
+    assert_eq!(client.get_referrer(&player1), Some(referrer.clone()));
+    assert_eq!(client.get_referrer(&player2), None);
+    match client.try_register_referrer(&player1, &player2) {
+        Err(Ok(err)) => assert_eq!(err, Error::ReferrerAlreadySet),
+        _ => panic!("Expected ReferrerAlreadySet error"),
+    }
+    match client.try_register_referrer(&player2, &player2) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidReferrer),
+        _ => panic!("Expected InvalidReferrer error"),
+    }
+
+    // The 5% fee on each 400 stake is 20; a quarter of player1's goes to
+    // their referrer, whether or not player1 won
+    client.start_game(&433, &player1, &player2, &400, &400);
+    play_tactics(&client, 433, &player1, &player2, 1, 2);
+    client.resolve_match(&433);
+    assert_eq!(client.get_accrued_fees(&token), 35);
+    assert_eq!(client.get_referral_rewards(&referrer, &token), ReferralRewards { earned: 5, claimable: 5 });
+    assert_eq!(client.get_referral_stats(&referrer), ReferralStats { referees: 1, raked_games: 1 });
+
+    assert_eq!(client.claim_referral_rewards(&referrer, &token), 5);
+    assert_eq!(balance(&env, &token, &referrer), 5);
+    assert_eq!(client.get_referral_rewards(&referrer, &token), ReferralRewards { earned: 5, claimable: 0 });
+    match client.try_claim_referral_rewards(&referrer, &token) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+
+    // Refunds take no fee, so earn no share
+    client.start_game(&434, &player1, &player2, &100, &100);
+    client.cancel_game(&434);
+    assert_eq!(client.get_referral_stats(&referrer).raked_games, 1);
+}
+
+#[test]
+fn test_streak_bonus_is_paid_from_accrued_fees() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.set_fee_bps(&client.get_admin(), &500);
+    let bonus = StreakBonus { min_streak: 2, step_bps: 1_000, max_bps: 2_500 };
+    client.set_streak_bonus(&client.get_admin(), &Some(bonus.clone()));
+    assert_eq!(client.get_streak_bonus(), Some(bonus));
+
+    // Bonuses of 0%, 10%, 20% and 25% of the 100 won, the last cut to the
+    // 10 of fees left
+    for (session_id, fees_left, balance2) in [
+        (435, 10, 1_090),
+        (436, 10, 1_190),
+        (437, 0, 1_300),
+        (438, 0, 1_400),
+    ] {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        play_tactics(&client, session_id, &player1, &player2, 1, 2);
+        client.resolve_match(&session_id);
+        assert_eq!(client.get_accrued_fees(&token), fees_left);
+        assert_eq!(balance(&env, &token, &player2), balance2);
+    }
+    assert_eq!(client.get_streak(&player2), Streak { current: 4, best: 4 });
+    assert_eq!(client.get_streak(&player1), Streak { current: 0, best: 0 });
+
+    // A draw ends the streak
+    client.start_game(&439, &player1, &player2, &100, &100);
+    play_tactics(&client, 439, &player1, &player2, 2, 2);
+    client.resolve_match(&439);
+    assert_eq!(client.get_streak(&player2), Streak { current: 0, best: 4 });
+
+    let bad = StreakBonus { min_streak: 1, step_bps: 1_000, max_bps: 2_500 };
+    match client.try_set_streak_bonus(&client.get_admin(), &Some(bad)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidStreakBonus),
+        _ => panic!("Expected InvalidStreakBonus error"),
+    }
+    client.set_streak_bonus(&client.get_admin(), &None);
+    assert_eq!(client.get_streak_bonus(), None);
+}
+
+#[test]
+fn test_jackpot_pays_out_on_a_run_of_tiebreak_wins() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.set_fee_bps(&client.get_admin(), &500);
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Random);
+    let config = JackpotConfig { share_bps: 4_000, tactic: 3, wins_in_a_row: 2, payout_bps: 10_000, min_stake: 50 };
+    client.set_jackpot_config(&client.get_admin(), &Some(config.clone()));
+    assert_eq!(client.get_jackpot_config(), Some(config));
+
+    // 2 of each stake's 5 fee feeds the jackpot. AllOut ties go either
+    // way; the first to win two in a row takes all of it
+    let mut runs = (0, 0);
+    let mut jackpot = 0;
+    for session_id in 440..480u32 {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        play_tactics(&client, session_id, &player1, &player2, 3, 3);
+        let before = (balance(&env, &token, &player1), balance(&env, &token, &player2));
+        let (winner, before, run, other) = match client.resolve_match(&session_id) {
+            Outcome::Player1Win => (&player1, before.0, &mut runs.0, &mut runs.1),
+            _ => (&player2, before.1, &mut runs.1, &mut runs.0),
+        };
+        jackpot += 4;
+        *run += 1;
+        *other = 0;
+        if *run == 2 {
+            assert_eq!(client.get_jackpot_run(winner), 0);
+            assert_eq!(client.get_jackpot(&token), 0);
+            assert_eq!(balance(&env, &token, winner), before + 190 + jackpot);
+            jackpot = 0;
+            break;
+        }
+        assert_eq!(client.get_jackpot_run(&player1), runs.0);
+        assert_eq!(client.get_jackpot_run(&player2), runs.1);
+        assert_eq!(client.get_jackpot(&token), jackpot);
+    }
+    assert_eq!(jackpot, 0, "no run completed in 40 ties");
+
+    // A win on the scores alone ends a run
+    client.start_game(&480, &player1, &player2, &100, &100);
+    play_tactics(&client, 480, &player1, &player2, 3, 3);
+    client.resolve_match(&480);
+    assert_eq!(client.get_jackpot_run(&player1) + client.get_jackpot_run(&player2), 1);
+    client.start_game(&481, &player1, &player2, &100, &100);
+    play_tactics(&client, 481, &player1, &player2, 2, 1);
+    client.resolve_match(&481);
+    assert_eq!(client.get_jackpot_run(&player1), 0);
+    assert_eq!(client.get_jackpot_run(&player2), 0);
+    assert_eq!(client.get_jackpot(&token), 8);
+
+    let bad = JackpotConfig { share_bps: 5_000, tactic: 4, wins_in_a_row: 3, payout_bps: 10_000, min_stake: 50 };
+    match client.try_set_jackpot_config(&client.get_admin(), &Some(bad)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidJackpotConfig),
+        _ => panic!("Expected InvalidJackpotConfig error"),
+    }
+    let free = JackpotConfig { share_bps: 5_000, tactic: 3, wins_in_a_row: 3, payout_bps: 10_000, min_stake: 0 };
+    match client.try_set_jackpot_config(&client.get_admin(), &Some(free)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidJackpotConfig),
+        _ => panic!("Expected InvalidJackpotConfig error"),
+    }
+    client.set_jackpot_config(&client.get_admin(), &None);
+    assert_eq!(client.get_jackpot_config(), None);
+    assert_eq!(client.get_jackpot(&token), 8);
+}
+
+#[test]
+fn test_jackpot_runs_need_random_ties_and_real_stakes() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.set_fee_bps(&client.get_admin(), &500);
+    let config = JackpotConfig { share_bps: 4_000, tactic: 3, wins_in_a_row: 2, payout_bps: 10_000, min_stake: 50 };
+    client.set_jackpot_config(&client.get_admin(), &Some(config));
+
+    // Colluding accounts handing each other Player1 ties get nowhere
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Player1);
+    for session_id in 490..494u32 {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        play_tactics(&client, session_id, &player1, &player2, 3, 3);
+        assert_eq!(client.resolve_match(&session_id), Outcome::Player1Win);
+        assert_eq!(client.get_jackpot_run(&player1), 0);
+    }
+    let jackpot = client.get_jackpot(&token);
+    assert_eq!(jackpot, 16);
+
+    // Nor do random ties staked below the minimum, or not at all
+    client.set_tiebreak_policy(&client.get_admin(), &TiebreakPolicy::Random);
+    for (session_id, stake) in [(494, 0), (495, 0), (496, 49), (497, 49), (498, 10), (499, 0)] {
+        client.start_game(&session_id, &player1, &player2, &stake, &100);
+        play_tactics(&client, session_id, &player1, &player2, 3, 3);
+        assert_ne!(client.resolve_match(&session_id), Outcome::Draw);
+        assert_eq!(client.get_jackpot_run(&player1), 0);
+        assert_eq!(client.get_jackpot_run(&player2), 0);
+    }
+    assert!(client.get_jackpot(&token) >= jackpot);
+    assert_eq!(balance(&env, &token, &player1) + balance(&env, &token, &player2) + client.get_accrued_fees(&token) + client.get_jackpot(&token), 2_000);
+}
+
+#[test]
+fn test_games_draw_on_a_prepaid_balance() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    assert_eq!(client.deposit_balance(&player1, &token, &300), 300);
+    assert_eq!(balance(&env, &token, &player1), 700);
+
+    // player1 signs only the game; player2 still authorizes a transfer
+    client.start_game(&445, &player1, &player2, &100, &100);
+    assert_eq!(env.auths().len(), 2);
+    for (signer, invocation) in env.auths() {
+        let transfers = invocation.sub_invocations.len();
+        assert_eq!(transfers, if signer == player1 { 0 } else { 1 });
+    }
+    assert_eq!(client.get_balance(&player1, &token), 200);
+    assert_eq!(balance(&env, &token, &player1), 700);
+    assert_eq!(balance(&env, &token, &player2), 900);
+
+    // Winnings are sent to the winner, not to their balance
+    play_tactics(&client, 445, &player1, &player2, 2, 1);
+    client.resolve_match(&445);
+    assert_eq!(balance(&env, &token, &player1), 900);
+    assert_eq!(client.get_balance(&player1, &token), 200);
+
+    // A balance short of the stake is left alone
+    client.start_game(&446, &player1, &player2, &250, &100);
+    assert_eq!(client.get_balance(&player1, &token), 200);
+    assert_eq!(balance(&env, &token, &player1), 650);
+    client.cancel_game(&446);
+
+    match client.try_withdraw_balance(&player1, &token, &201) {
+        Err(Ok(err)) => assert_eq!(err, Error::InsufficientBalance),
+        _ => panic!("Expected InsufficientBalance error"),
+    }
+    match client.try_deposit_balance(&player1, &token, &0) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidBalanceAmount),
+        _ => panic!("Expected InvalidBalanceAmount error"),
+    }
+    assert_eq!(client.withdraw_balance(&player1, &token, &200), 0);
+    assert_eq!(balance(&env, &token, &player1), 1_100);
+}
+
+#[test]
+fn test_batches_report_each_session() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    for session_id in [447, 448, 449] {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+    freeze(&env, &token, &player1, true);
+    play_tactics(&client, 447, &player1, &player2, 2, 1);
+    play_tactics(&client, 448, &player1, &player2, 1, 2);
+
+    // Sessions that cannot settle are reported without holding up the rest
+    assert_eq!(
+        client.resolve_batch(&vec![&env, 447, 449, 448, 999]),
+        vec![
+            &env,
+            BatchResult::Resolved(447, Outcome::Player1Win),
+            BatchResult::Failed(449, Error::InvalidPhase as u32),
+            BatchResult::Resolved(448, Outcome::Player2Win),
+            BatchResult::Failed(999, Error::GameNotFound as u32),
+        ]
+    );
+    assert_eq!(client.get_game_public(&448).game.phase, GamePhase::Resolved);
+
+    // player1's refused winnings are held until claimed
+    freeze(&env, &token, &player1, false);
+    assert_eq!(
+        client.claim_batch(&vec![&env, 447, 449, 448]),
+        vec![
+            &env,
+            BatchResult::Claimed(447, 200),
+            BatchResult::Failed(449, Error::GameNotSettled as u32),
+            BatchResult::Claimed(448, 0),
+        ]
+    );
+    assert_eq!(balance(&env, &token, &player1), 900);
+
+    match client.try_claim_batch(&Vec::new(&env)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidBatch),
+        _ => panic!("Expected InvalidBatch error"),
+    }
+}
+
+#[test]
+fn test_hub_treasury_takes_its_share_of_the_fee() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.set_fee_bps(&client.get_admin(), &500);
+    client.set_hub_share_bps(&client.get_admin(), &4_000);
+    assert_eq!(client.get_hub_share_bps(), 4_000);
+
+    // A hub without a treasury leaves the whole fee to the collector
+    client.start_game(&450, &player1, &player2, &100, &100);
+    play_tactics(&client, 450, &player1, &player2, 2, 1);
+    client.resolve_match(&450);
+    assert_eq!(client.get_accrued_fees(&token), 10);
+
+    // 2 of each stake's 5 fee goes to the treasury the hub names
+    let treasury = Address::generate(&env);
+    MockGameHubClient::new(&env, &client.get_hub()).set_treasury(&treasury);
+    client.start_game(&451, &player1, &player2, &100, &100);
+    play_tactics(&client, 451, &player1, &player2, 2, 1);
+    client.resolve_match(&451);
+    assert_eq!(balance(&env, &token, &treasury), 4);
+    assert_eq!(client.get_accrued_fees(&token), 16);
+    assert_eq!(balance(&env, &token, &player1), 1_180);
+
+    match client.try_set_hub_share_bps(&client.get_admin(), &10_001) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidFee),
+        _ => panic!("Expected InvalidFee error"),
+    }
+}
+
+#[test]
+fn test_epoch_pools_pay_the_leaders() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    client.set_fee_bps(&client.get_admin(), &500);
+    let config = EpochConfig { token: token.clone(), length_ledgers: 100, share_bps: 10_000, top_n: 2 };
+    client.set_epoch_config(&client.get_admin(), &Some(config.clone()));
+    assert_eq!(client.get_epoch_config(), Some(config));
+    assert_eq!(client.get_current_epoch(), Some(0));
+
+    // Every game's 10 in fees goes to the pool
+    for (session_id, tactic1, tactic2) in [(452, 2, 1), (453, 2, 1), (454, 1, 2)] {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        play_tactics(&client, session_id, &player1, &player2, tactic1, tactic2);
+        client.resolve_match(&session_id);
+    }
+    let epoch = client.get_epoch(&0).unwrap();
+    assert_eq!(epoch.pool, 30);
+    assert_eq!(
+        epoch.leaders,
+        vec![
+            &env,
+            EpochLeader { player: player1.clone(), wins: 2 },
+            EpochLeader { player: player2.clone(), wins: 1 },
+        ]
+    );
+    assert_eq!(client.get_accrued_fees(&token), 0);
+    match client.try_claim_epoch_reward(&player1, &0) {
+        Err(Ok(err)) => assert_eq!(err, Error::EpochNotOver),
+        _ => panic!("Expected EpochNotOver error"),
+    }
+
+    env.ledger().set_sequence_number(env.ledger().sequence() + 100);
+    assert_eq!(client.get_current_epoch(), Some(1));
+    let (balance1, balance2) = (balance(&env, &token, &player1), balance(&env, &token, &player2));
+    assert_eq!(client.claim_epoch_reward(&player1, &0), 20);
+    assert_eq!(client.claim_epoch_reward(&player2, &0), 10);
+    assert_eq!(balance(&env, &token, &player1), balance1 + 20);
+    assert_eq!(balance(&env, &token, &player2), balance2 + 10);
+    match client.try_claim_epoch_reward(&player1, &0) {
+        Err(Ok(err)) => assert_eq!(err, Error::NothingToClaim),
+        _ => panic!("Expected NothingToClaim error"),
+    }
+
+    // An epoch can be paid by a reward tree instead
+    client.start_game(&455, &player1, &player2, &100, &100);
+    play_tactics(&client, 455, &player1, &player2, 2, 1);
+    client.resolve_match(&455);
+    let leaves = vec![&env, epoch_leaf(&env, 1, &player1, 6), epoch_leaf(&env, 1, &player2, 4)];
+    let root = strategy_root(&env, &leaves);
+    match client.try_set_epoch_root(&client.get_admin(), &1, &root, &2) {
+        Err(Ok(err)) => assert_eq!(err, Error::EpochNotOver),
+        _ => panic!("Expected EpochNotOver error"),
+    }
+    env.ledger().set_sequence_number(env.ledger().sequence() + 100);
+    client.set_epoch_root(&client.get_admin(), &1, &root, &2);
+    match client.try_claim_epoch_reward(&player1, &1) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidEpochProof),
+        _ => panic!("Expected InvalidEpochProof error"),
+    }
+    let proof = strategy_proof(&env, &leaves, 1);
+    match client.try_claim_epoch_reward_with_proof(&player2, &1, &1, &5, &proof) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidEpochProof),
+        _ => panic!("Expected InvalidEpochProof error"),
+    }
+    assert_eq!(client.claim_epoch_reward_with_proof(&player2, &1, &1, &4, &proof), 4);
+    assert_eq!(client.get_epoch(&1).unwrap().claimed, 4);
+
+    let bad = EpochConfig { token, length_ledgers: 100, share_bps: 10_000, top_n: 0 };
+    match client.try_set_epoch_config(&client.get_admin(), &Some(bad)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidEpochConfig),
+        _ => panic!("Expected InvalidEpochConfig error"),
+    }
+    client.set_epoch_config(&client.get_admin(), &None);
+    assert_eq!(client.get_current_epoch(), None);
+}
+
+#[test]
+fn test_upheld_dispute_claws_back_the_winners_bond() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    let module = Address::generate(&env);
+    let config = DisputeConfig { module: module.clone(), window_ledgers: 50 };
+    client.set_dispute_config(&client.get_admin(), &Some(config.clone()));
+    assert_eq!(client.get_dispute_config(), Some(config));
+    assert_eq!(client.post_bond(&player1, &token, &300), 300);
+
+    // The 200 player1 was paid is locked in their bond
+    client.start_game(&456, &player1, &player2, &100, &100);
+    play_tactics(&client, 456, &player1, &player2, 2, 1);
+    client.resolve_match(&456);
+    assert_eq!(balance(&env, &token, &player1), 800);
+    let dispute = client.get_dispute(&456).unwrap();
+    assert_eq!((dispute.locked, dispute.status), (200, DisputeStatus::Open));
+    assert_eq!(client.get_bond(&player1, &token), Bond { amount: 300, locked: 200 });
+    match client.try_withdraw_bond(&player1, &token, &150) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidBondAmount),
+        _ => panic!("Expected InvalidBondAmount error"),
+    }
+    match client.try_finalize_settlement(&456) {
+        Err(Ok(err)) => assert_eq!(err, Error::DisputeWindowOpen),
+        _ => panic!("Expected DisputeWindowOpen error"),
+    }
+
+    client.dispute_settlement(&456);
+    match client.try_dispute_settlement(&456) {
+        Err(Ok(err)) => assert_eq!(err, Error::DisputeWindowClosed),
+        _ => panic!("Expected DisputeWindowClosed error"),
+    }
+    assert_eq!(client.resolve_dispute(&456, &true), 200);
+    assert_eq!(balance(&env, &token, &player2), 1_100);
+    assert_eq!(client.get_bond(&player1, &token), Bond { amount: 100, locked: 0 });
+    assert_eq!(client.get_dispute(&456), None);
+
+    // Only the free bond is locked, and an undisputed window releases it
+    client.start_game(&457, &player1, &player2, &100, &100);
+    play_tactics(&client, 457, &player1, &player2, 2, 1);
+    client.resolve_match(&457);
+    assert_eq!(client.get_dispute(&457).unwrap().locked, 100);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 51);
+    match client.try_dispute_settlement(&457) {
+        Err(Ok(err)) => assert_eq!(err, Error::DisputeWindowClosed),
+        _ => panic!("Expected DisputeWindowClosed error"),
+    }
+    client.finalize_settlement(&457);
+    assert_eq!(client.get_bond(&player1, &token), Bond { amount: 100, locked: 0 });
+    assert_eq!(client.withdraw_bond(&player1, &token, &100), 0);
+    match client.try_resolve_dispute(&457, &false) {
+        Err(Ok(err)) => assert_eq!(err, Error::DisputeNotFound),
+        _ => panic!("Expected DisputeNotFound error"),
+    }
+}
+
+#[test]
+fn test_handicap_odds_set_the_stakes_and_payout() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    let odds = Odds { player1: 2, player2: 1 };
+
+    // At 2:1 player1 risks 250 to win 125; player2's other 175 stays put
+    client.start_game_with_odds(&458, &player1, &player2, &250, &300, &token, &odds);
+    let name = Symbol::new(&env, "start_game_with_odds");
+    let args = (458_u32, player1.clone(), player2.clone(), 250_i128, 300_i128, token.clone(), odds.clone());
+    let signed = AuthorizedFunction::Contract((client.address.clone(), name, args.into_val(&env)));
+    for player in [&player1, &player2] {
+        assert!(env
+            .auths()
+            .iter()
+            .any(|(signer, invocation)| signer == player && invocation.function == signed));
+    }
+    assert_eq!(client.get_odds(&458), Some(odds.clone()));
+    let escrow = client.get_escrow(&458).unwrap();
+    assert_eq!((escrow.player1_amount, escrow.player2_amount), (250, 125));
+
+    // The underdog's win pays twice what they staked
+    play_tactics(&client, 458, &player1, &player2, 1, 2);
+    client.resolve_match(&458);
+    assert_eq!(balance(&env, &token, &player1), 750);
+    assert_eq!(balance(&env, &token, &player2), 1_250);
+
+    for (points, odds) in [(100, Odds { player1: 0, player2: 1 }), (1, odds)] {
+        match client.try_start_game_with_odds(&459, &player1, &player2, &points, &100, &token, &odds) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidOdds),
+            _ => panic!("Expected InvalidOdds error"),
+        }
+    }
+    assert_eq!(client.get_odds(&459), None);
+}
+
+#[test]
+fn test_stakers_win_pots_at_a_discounted_fee() {
+    let (env, client, player1, player2) = setup_test();
+    let token = setup_escrow(&client, &player1, &player2);
+    let studio = wager_token(&env);
+    fund(&env, &studio, &[&player1], 1_000);
+    client.set_fee_bps(&client.get_admin(), &500);
+
+    let tier = |min_stake, discount_bps| StakeTier { min_stake, discount_bps };
+    let config = StakingConfig {
+        token: studio.clone(),
+        tiers: vec![&env, tier(100, 2_500), tier(500, 5_000)],
+        lockup_ledgers: 50,
+        cooldown_ledgers: 20,
+    };
+    let mut invalid = config.clone();
+    invalid.tiers = vec![&env, tier(500, 5_000), tier(100, 2_500)];
+    match client.try_set_staking_config(&client.get_admin(), &Some(invalid)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidStakingConfig),
+        _ => panic!("Expected InvalidStakingConfig error"),
+    }
+    match client.try_stake(&player1, &500) {
+        Err(Ok(err)) => assert_eq!(err, Error::StakingNotConfigured),
+        _ => panic!("Expected StakingNotConfigured error"),
+    }
+    client.set_staking_config(&client.get_admin(), &Some(config));
+
+    // Half of the 5% fee is waived: 2 on each stake instead of 5
+    let stake = client.stake(&player1, &500);
+    assert_eq!(stake.locked_until_ledger, env.ledger().sequence() + 50);
+    assert_eq!(client.get_stake_tier(&player1), Some(tier(500, 5_000)));
+    assert_eq!(client.get_stake_tier(&player2), None);
+    client.start_game(&460, &player1, &player2, &100, &100);
+    play_tactics(&client, 460, &player1, &player2, 2, 1);
+    client.resolve_match(&460);
+    assert_eq!(balance(&env, &token, &player1), 1_096);
+    assert_eq!(client.get_accrued_fees(&token), 4);
+
+    match client.try_unstake(&player1, &300) {
+        Err(Ok(err)) => assert_eq!(err, Error::StakeLocked),
+        _ => panic!("Expected StakeLocked error"),
+    }
+    env.ledger().set_sequence_number(stake.locked_until_ledger);
+    let stake = client.unstake(&player1, &300);
+    assert_eq!(
+        stake,
+        Stake {
+            token: studio.clone(),
+            amount: 200,
+            locked_until_ledger: stake.locked_until_ledger,
+            unstaking: 300,
+            cooldown_end_ledger: env.ledger().sequence() + 20,
+        }
+    );
+
+    // What is left earns the lower tier: a 3.75% fee, rounded down per stake
+    assert_eq!(client.get_stake_tier(&player1), Some(tier(100, 2_500)));
+    client.start_game(&461, &player1, &player2, &100, &100);
+    play_tactics(&client, 461, &player1, &player2, 2, 1);
+    client.resolve_match(&461);
+    assert_eq!(balance(&env, &token, &player1), 1_190);
+    assert_eq!(client.get_accrued_fees(&token), 10);
+
+    match client.try_withdraw_stake(&player1) {
+        Err(Ok(err)) => assert_eq!(err, Error::StakeCoolingDown),
+        _ => panic!("Expected StakeCoolingDown error"),
+    }
+    env.ledger().set_sequence_number(stake.cooldown_end_ledger);
+    assert_eq!(client.withdraw_stake(&player1), 300);
+    assert_eq!(balance(&env, &studio, &player1), 800);
+    match client.try_withdraw_stake(&player1) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidStakeAmount),
+        _ => panic!("Expected InvalidStakeAmount error"),
+    }
+}
+
+// ============================================================================
+// Upgrade Timelock Tests
+// ============================================================================
+
+#[test]
+fn test_upgrade_waits_out_its_delay() {
+    let (env, client, _player1, _player2) = setup_test();
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(client.get_upgrade_delay(), DEFAULT_UPGRADE_DELAY_LEDGERS);
+
+    match client.try_execute_upgrade() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingUpgrade),
+        _ => panic!("Expected NoPendingUpgrade error"),
+    }
+
+    let proposed_at = env.ledger().sequence();
+    client.propose_upgrade(&wasm_hash);
+    let executable_ledger = proposed_at + DEFAULT_UPGRADE_DELAY_LEDGERS;
+    assert_eq!(client.get_pending_upgrade(), Some(PendingUpgrade { wasm_hash, executable_ledger }));
+
+    env.ledger().set_sequence_number(executable_ledger - 1);
+    match client.try_execute_upgrade() {
+        Err(Ok(err)) => assert_eq!(err, Error::UpgradeNotReady),
+        _ => panic!("Expected UpgradeNotReady error"),
+    }
+
+    // Past the delay the upgrade goes ahead; this hash was never uploaded,
+    // so the host rejects it rather than the timelock
+    env.ledger().set_sequence_number(executable_ledger);
+    assert!(matches!(client.try_execute_upgrade(), Err(Err(_))));
+    assert!(client.get_pending_upgrade().is_some());
+}
+
+#[test]
+fn test_cancelled_upgrade_cannot_run() {
+    let (env, client, _player1, _player2) = setup_test();
+    match client.try_set_upgrade_delay(&(MIN_UPGRADE_DELAY_LEDGERS - 1)) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidUpgradeDelay),
+        _ => panic!("Expected InvalidUpgradeDelay error"),
+    }
+    client.set_upgrade_delay(&MIN_UPGRADE_DELAY_LEDGERS);
+
+    client.propose_upgrade(&BytesN::from_array(&env, &[7u8; 32]));
+    client.cancel_upgrade();
+    assert_eq!(client.get_pending_upgrade(), None);
+    match client.try_cancel_upgrade() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingUpgrade),
+        _ => panic!("Expected NoPendingUpgrade error"),
+    }
+
+    env.ledger().set_sequence_number(env.ledger().sequence() + MIN_UPGRADE_DELAY_LEDGERS);
+    match client.try_execute_upgrade() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingUpgrade),
+        _ => panic!("Expected NoPendingUpgrade error"),
+    }
+}
+
+// ============================================================================
+// Initialization Tests
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+fn test_constructor_cannot_run_twice() {
+    let (env, client, _player1, _player2) = setup_test();
+    assert!(client.is_initialized());
+
+    let other_hub = env.register(MockGameHub, ());
+    env.as_contract(&client.address, || {
+        ZkTacticalMatchContract::__constructor(env.clone(), Address::generate(&env), other_hub);
+    });
+}
+
+#[test]
+fn test_reinitialize_waits_out_upgrade_delay() {
+    let (env, client, _player1, _player2) = setup_test();
+    let new_admin = Address::generate(&env);
+    let new_hub = env.register(MockGameHub, ());
+
+    match client.try_reinitialize() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingReinit),
+        _ => panic!("Expected NoPendingReinit error"),
+    }
+
+    let executable_ledger = env.ledger().sequence() + DEFAULT_UPGRADE_DELAY_LEDGERS;
+    client.propose_reinitialize(&new_admin, &new_hub);
+    assert_eq!(
+        client.get_pending_reinit(),
+        Some(PendingReinit {
+            admin: new_admin.clone(),
+            game_hub: new_hub.clone(),
+            executable_ledger,
+        })
+    );
+
+    env.ledger().set_sequence_number(executable_ledger - 1);
+    match client.try_reinitialize() {
+        Err(Ok(err)) => assert_eq!(err, Error::ReinitNotReady),
+        _ => panic!("Expected ReinitNotReady error"),
+    }
+
+    env.ledger().set_sequence_number(executable_ledger);
+    client.reinitialize();
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_hub(), new_hub);
+    assert_eq!(client.get_pending_reinit(), None);
+}
+
+#[test]
+fn test_cancelled_reinitialize_cannot_run() {
+    let (env, client, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+    let hub = client.get_hub();
+
+    client.propose_reinitialize(&Address::generate(&env), &env.register(MockGameHub, ()));
+    client.cancel_reinitialize();
+    match client.try_cancel_reinitialize() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingReinit),
+        _ => panic!("Expected NoPendingReinit error"),
+    }
+
+    env.ledger().set_sequence_number(env.ledger().sequence() + DEFAULT_UPGRADE_DELAY_LEDGERS);
+    match client.try_reinitialize() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingReinit),
+        _ => panic!("Expected NoPendingReinit error"),
+    }
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), hub);
+}
+
+// ============================================================================
+// Admin Council Tests
+// ============================================================================
+
+fn council_of(env: &Env, size: u32, threshold: u32) -> AdminCouncil {
+    let mut members = Vec::new(env);
+    for _ in 0..size {
+        members.push_back(Address::generate(env));
+    }
+    AdminCouncil { members, threshold }
+}
+
+#[test]
+fn test_council_actions_need_threshold_approvals() {
+    let (env, client, _player1, _player2) = setup_test();
+    let council = council_of(&env, 3, 2);
+    client.set_admin_council(&Some(council.clone()));
+    assert_eq!(client.get_admin_council(), Some(council.clone()));
+
+    let new_hub = env.register(MockGameHub, ());
+    let action = CouncilAction::SetHub(new_hub.clone());
+    client.approve_action(&council.members.get(0).unwrap(), &action);
+    client.approve_action(&council.members.get(0).unwrap(), &action);
+    match client.try_set_hub(&new_hub) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotApproved),
+        _ => panic!("Expected NotApproved error"),
+    }
+
+    // Approvals are for the exact call, arguments included
+    client.approve_action(&council.members.get(1).unwrap(), &CouncilAction::SetHub(Address::generate(&env)));
+    assert!(client.try_set_hub(&new_hub).is_err());
+
+    client.approve_action(&council.members.get(2).unwrap(), &action);
+    assert_eq!(client.get_approvals(&action).len(), 2);
+    client.set_hub(&new_hub);
+    assert_eq!(client.get_hub(), new_hub);
+
+    // Carrying the action out uses the approvals up
+    assert_eq!(client.get_approvals(&action), vec![&env]);
+    match client.try_set_hub(&new_hub) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotApproved),
+        _ => panic!("Expected NotApproved error"),
+    }
+
+    assert!(client.try_pause(&client.get_admin()).is_err());
+    for action in [CouncilAction::Pause, CouncilAction::Unpause] {
+        for i in 0..2 {
+            client.approve_action(&council.members.get(i).unwrap(), &action);
+        }
+    }
+    client.pause(&client.get_admin());
+    client.unpause();
+
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert!(client.try_propose_upgrade(&wasm_hash).is_err());
+    for i in 1..3 {
+        client.approve_action(&council.members.get(i).unwrap(), &CouncilAction::ProposeUpgrade(wasm_hash.clone()));
+    }
+    client.propose_upgrade(&wasm_hash);
+    assert!(client.get_pending_upgrade().is_some());
+}
+
+#[test]
+fn test_council_gates_admin_setters() {
+    let (env, client, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+    let council = council_of(&env, 2, 2);
+    client.set_admin_council(&Some(council.clone()));
+    let approve = |action: CouncilAction| {
+        for member in council.members.iter() {
+            client.approve_action(&member, &action);
+        }
+    };
+    let assert_not_approved = |result: Result<_, Result<Error, _>>| match result {
+        Err(Ok(err)) => assert_eq!(err, Error::NotApproved),
+        _ => panic!("Expected NotApproved error"),
+    };
+
+    // The admin's signature alone no longer does
+    let other = Address::generate(&env);
+    let vk = Bytes::from_array(&env, &[1u8; 8]);
+    let dispute_config = DisputeConfig { module: other.clone(), window_ledgers: 50 };
+    assert_not_approved(client.try_propose_admin(&other));
+    assert_not_approved(client.try_set_verifier(&other));
+    assert_not_approved(client.try_set_verification_key(&vk));
+    assert_not_approved(client.try_grant_role(&other, &Role::FeeManager));
+    assert_not_approved(client.try_revoke_role(&other, &Role::FeeManager));
+    assert_not_approved(client.try_set_dispute_config(&admin, &Some(dispute_config.clone())));
+    assert_not_approved(client.try_set_fee_bps(&admin, &100));
+    assert_not_approved(client.try_set_fee_collector(&admin, &other));
+    assert_not_approved(client.try_set_oracle_config(&admin, &None));
+    assert_eq!(client.get_fee_bps(), 0);
+
+    // The council approves exact calls: a key by its hash
+    approve(CouncilAction::SetVerificationKey(env.crypto().sha256(&vk).into()));
+    match client.try_set_verification_key(&vk) {
+        Err(Ok(err)) => assert_eq!(err, Error::InvalidVerificationKey),
+        _ => panic!("Expected InvalidVerificationKey error"),
+    }
+    approve(CouncilAction::SetFeeBps(100));
+    assert_not_approved(client.try_set_fee_bps(&admin, &200));
+    client.set_fee_bps(&admin, &100);
+    assert_eq!(client.get_fee_bps(), 100);
+    approve(CouncilAction::SetDisputeConfig(dispute_config.clone()));
+    client.set_dispute_config(&admin, &Some(dispute_config.clone()));
+    assert_eq!(client.get_dispute_config(), Some(dispute_config));
+
+    // A role the council granted works on its holder's signature
+    let fee_manager = Address::generate(&env);
+    approve(CouncilAction::GrantRole(fee_manager.clone(), Role::FeeManager));
+    client.grant_role(&fee_manager, &Role::FeeManager);
+    client.set_fee_collector(&fee_manager, &other);
+    assert_eq!(client.get_fee_collector(), other);
+
+    // Both halves of an admin transfer take the council
+    approve(CouncilAction::ProposeAdmin(other.clone()));
+    client.propose_admin(&other);
+    assert_not_approved(client.try_accept_admin());
+    approve(CouncilAction::AcceptAdmin(other.clone()));
+    client.accept_admin();
+    assert_eq!(client.get_admin(), other);
+}
+
+#[test]
+fn test_council_gates_security_setters() {
+    let (env, client, _player1, _player2) = setup_test();
+    let council = council_of(&env, 2, 2);
+    client.set_admin_council(&Some(council.clone()));
+    let approve = |action: CouncilAction| {
+        for member in council.members.iter() {
+            client.approve_action(&member, &action);
+        }
+    };
+    let assert_not_approved = |result: Result<_, Result<Error, _>>| match result {
+        Err(Ok(err)) => assert_eq!(err, Error::NotApproved),
+        _ => panic!("Expected NotApproved error"),
+    };
+
+    // Every verifier, key source and proof setting, and the upgrade path,
+    // needs the council once there is one; the admin's signature is refused
+    let other = Address::generate(&env);
+    let image_id = BytesN::from_array(&env, &[9u8; 32]);
+    let gate = SybilGate { verifier: other.clone(), identity_root: image_id.clone(), bracket: 1 };
+    assert_not_approved(client.try_set_vk_source(&None));
+    assert_not_approved(client.try_set_ultrahonk_verifier(&other));
+    assert_not_approved(client.try_set_proof_system(&ProofSystem::CommitReveal));
+    assert_not_approved(client.try_set_stake_verifier(&other));
+    assert_not_approved(client.try_set_rating_verifier(&other));
+    assert_not_approved(client.try_set_sybil_gate(&Some(gate.clone())));
+    assert_not_approved(client.try_set_simulation_image_id(&Some(image_id.clone())));
+    assert_not_approved(client.try_set_receipt_verifier(&other));
+    assert_not_approved(client.try_set_rounds_verifier(&other));
+    assert_not_approved(client.try_set_batch_verification(&true));
+    assert_not_approved(client.try_migrate());
+    assert_not_approved(client.try_set_upgrade_delay(&(DEFAULT_UPGRADE_DELAY_LEDGERS * 2)));
+    assert_eq!(client.get_proof_system(), ProofSystem::Groth16);
+    assert_ne!(client.get_rounds_verifier(), Some(other.clone()));
+
+    // Approved calls go through, for exactly the arguments approved
+    approve(CouncilAction::SetRoundsVerifier(other.clone()));
+    assert_not_approved(client.try_set_receipt_verifier(&other));
+    client.set_rounds_verifier(&other);
+    assert_eq!(client.get_rounds_verifier(), Some(other.clone()));
+    approve(CouncilAction::SetProofSystem(ProofSystem::CommitReveal));
+    client.set_proof_system(&ProofSystem::CommitReveal);
+    assert_eq!(client.get_proof_system(), ProofSystem::CommitReveal);
+    approve(CouncilAction::SetSybilGate(env.crypto().sha256(&gate.clone().to_xdr(&env)).into()));
+    client.set_sybil_gate(&Some(gate.clone()));
+    assert_eq!(client.get_sybil_gate(), Some(gate));
+    assert_not_approved(client.try_set_sybil_gate(&None));
+    approve(CouncilAction::RemoveSybilGate);
+    client.set_sybil_gate(&None);
+    assert_eq!(client.get_sybil_gate(), None);
+    approve(CouncilAction::Migrate);
+    client.migrate();
+}
+
+#[test]
+fn test_council_membership_and_changes() {
+    let (env, client, _player1, _player2) = setup_test();
+    let outsider = Address::generate(&env);
+    match client.try_approve_action(&outsider, &CouncilAction::Pause) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotCouncilMember),
+        _ => panic!("Expected NotCouncilMember error"),
+    }
+
+    let mut duplicated = council_of(&env, 2, 1);
+    duplicated.members.push_back(duplicated.members.get(0).unwrap());
+    for council in [council_of(&env, 2, 0), council_of(&env, 2, 3), council_of(&env, 11, 1), duplicated] {
+        match client.try_set_admin_council(&Some(council)) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidCouncil),
+            _ => panic!("Expected InvalidCouncil error"),
+        }
+    }
+
+    let council = council_of(&env, 2, 2);
+    client.set_admin_council(&Some(council.clone()));
+    match client.try_approve_action(&outsider, &CouncilAction::Pause) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotCouncilMember),
+        _ => panic!("Expected NotCouncilMember error"),
+    }
+
+    // The council now decides on itself too
+    let replacement = council_of(&env, 1, 1);
+    assert!(client.try_set_admin_council(&Some(replacement.clone())).is_err());
+    client.approve_action(&council.members.get(0).unwrap(), &CouncilAction::Pause);
+    for member in council.members.iter() {
+        client.approve_action(&member, &CouncilAction::SetCouncil(replacement.clone()));
+    }
+    client.set_admin_council(&Some(replacement.clone()));
+
+    // Approvals from members who left no longer count
+    assert_eq!(client.get_approvals(&CouncilAction::Pause).len(), 1);
+    match client.try_pause(&client.get_admin()) {
+        Err(Ok(err)) => assert_eq!(err, Error::NotApproved),
+        _ => panic!("Expected NotApproved error"),
+    }
+    client.approve_action(&replacement.members.get(0).unwrap(), &CouncilAction::RemoveCouncil);
+    client.set_admin_council(&None);
+    assert_eq!(client.get_admin_council(), None);
+    client.pause(&client.get_admin());
+}
+
+// ============================================================================
+// Schema Migration Tests
+// ============================================================================
+
+#[test]
+fn test_version_matches_crate() {
+    let (_env, client, _player1, _player2) = setup_test();
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+    assert_eq!(
+        std::format!("{}.{}.{}", CONTRACT_VERSION.0, CONTRACT_VERSION.1, CONTRACT_VERSION.2),
+        env!("CARGO_PKG_VERSION")
+    );
+}
+
+#[test]
+fn test_config_matches_individual_getters() {
+    let (_env, client, _player1, _player2) = setup_test();
+    client.set_wager_limits(&client.get_admin(), &10, &1_000);
+    client.set_max_open_games(&client.get_admin(), &Some(4));
+    client.set_fee_bps(&client.get_admin(), &250);
+    client.pause(&client.get_admin());
+
+    let config = client.get_config();
+    assert_eq!(config.admin, client.get_admin());
+    assert_eq!(config.hub, client.get_hub());
+    assert_eq!(config.verifier, client.get_verifier());
+    assert_eq!(config.proof_system, client.get_proof_system());
+    assert!(config.paused);
+    assert_eq!(config.wager_limits, WagerLimits { min_wager: 10, max_wager: 1_000 });
+    assert_eq!((config.fee_bps, config.fee_collector), (250, client.get_admin()));
+    assert_eq!(config.oracle, None);
+    assert_eq!(config.side_bet_rake_bps, DEFAULT_SIDE_BET_RAKE_BPS);
+    assert_eq!(config.referral_share_bps, DEFAULT_REFERRAL_SHARE_BPS);
+    assert_eq!(config.hub_share_bps, 0);
+    assert_eq!(config.max_open_games, Some(4));
+    assert_eq!(config.timeout_ledgers, DEFAULT_TIMEOUT_LEDGERS);
+    assert_eq!((config.game_ttl_ledgers, config.history_ttl_ledgers), (518_400, 1_036_800));
+    assert_eq!(config.upgrade_delay_ledgers, DEFAULT_UPGRADE_DELAY_LEDGERS);
+}
+
+/// Store `session_id`'s game as a schema 2 deployment would have
+fn downgrade_game_to_v2(env: &Env, client: &ZkTacticalMatchContractClient, session_id: u32) {
+    let game = client.get_game_public(&session_id).game;
+    let old = GameV2 {
+        player1: game.player1,
+        player2: game.player2,
+        player1_points: game.player1_points,
+        player2_points: game.player2_points,
+        proof_system: game.proof_system,
+        batch_verification: game.batch_verification,
+        simulation_image_id: game.simulation_image_id,
+        tiebreak: game.tiebreak,
+        auto_resolve: game.auto_resolve,
+        submit_deadline_ledger: game.submit_deadline_ledger,
+        resolve_deadline_ledger: game.resolve_deadline_ledger,
+        player1_commitment: game.player1_commitment,
+        player2_commitment: game.player2_commitment,
+        player1_proof_hash: game.player1_proof_hash,
+        player2_proof_hash: game.player2_proof_hash,
+        player1_tactic_hash: game.player1_tactic_hash,
+        player2_tactic_hash: game.player2_tactic_hash,
+        player1_tactic: game.player1_tactic,
+        player2_tactic: game.player2_tactic,
+        player1_score: game.player1_score,
+        player2_score: game.player2_score,
+        hub_notified: game.hub_notified,
+        phase: game.phase,
+        result: game.result,
+        last_activity_ledger: game.last_activity_ledger,
+    };
+    env.as_contract(&client.address, || {
+        env.storage().temporary().set(&DataKey::Game(session_id), &old);
+    });
+}
+
+/// Store `session_id`'s game as a schema 1 deployment would have
+fn downgrade_game_to_v1(env: &Env, client: &ZkTacticalMatchContractClient, session_id: u32) {
+    let game = client.get_game_public(&session_id).game;
+    let old = GameV1 {
+        player1: game.player1,
+        player2: game.player2,
+        player1_points: game.player1_points,
+        player2_points: game.player2_points,
+        proof_system: game.proof_system,
+        batch_verification: game.batch_verification,
+        simulation_image_id: game.simulation_image_id,
+        tiebreak: game.tiebreak,
+        auto_resolve: game.auto_resolve,
+        submit_deadline_ledger: game.submit_deadline_ledger,
+        resolve_deadline_ledger: game.resolve_deadline_ledger,
+        player1_commitment: game.player1_commitment,
+        player2_commitment: game.player2_commitment,
+        player1_proof_hash: game.player1_proof_hash,
+        player2_proof_hash: game.player2_proof_hash,
+        player1_tactic_hash: game.player1_tactic_hash,
+        player2_tactic_hash: game.player2_tactic_hash,
+        player1_tactic: game.player1_tactic,
+        player2_tactic: game.player2_tactic,
+        player1_score: game.player1_score,
+        player2_score: game.player2_score,
+        hub_notified: game.hub_notified,
+        phase: game.phase,
+        result: game.result,
+    };
+    env.as_contract(&client.address, || {
+        env.storage().temporary().set(&DataKey::Game(session_id), &old);
+    });
+}
+
+/// Store `session_id`'s game as a schema 0 deployment would have
+fn downgrade_game(env: &Env, client: &ZkTacticalMatchContractClient, session_id: u32) {
+    let game = client.get_game_public(&session_id).game;
+    let old = GameV0 {
+        player1: game.player1,
+        player2: game.player2,
+        player1_points: game.player1_points,
+        player2_points: game.player2_points,
+        proof_system: game.proof_system,
+        batch_verification: game.batch_verification,
+        simulation_image_id: game.simulation_image_id,
+        tiebreak: game.tiebreak,
+        auto_resolve: game.auto_resolve,
+        submit_deadline_ledger: game.submit_deadline_ledger,
+        resolve_deadline_ledger: game.resolve_deadline_ledger,
+        player1_commitment: game.player1_commitment,
+        player2_commitment: game.player2_commitment,
+        player1_proof_hash: game.player1_proof_hash,
+        player2_proof_hash: game.player2_proof_hash,
+        player1_tactic_hash: game.player1_tactic_hash,
+        player2_tactic_hash: game.player2_tactic_hash,
+        player1_tactic: game.player1_tactic,
+        player2_tactic: game.player2_tactic,
+        player1_score: game.player1_score,
+        player2_score: game.player2_score,
+        result: game.result,
+    };
+    env.as_contract(&client.address, || {
+        env.storage().temporary().set(&DataKey::Game(session_id), &old);
+    });
+}
+
+#[test]
+fn test_migrate_records_schema_version() {
+    let (env, client, _player1, _player2) = setup_test();
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    client.migrate();
+
+    // Deployments from before versioning read as schema 0
+    env.as_contract(&client.address, || env.storage().instance().remove(&DataKey::SchemaVersion));
+    assert_eq!(client.get_schema_version(), 0);
+    client.migrate();
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::SchemaVersion, &(SCHEMA_VERSION + 1))
+    });
+    match client.try_migrate() {
+        Err(Ok(err)) => assert_eq!(err, Error::SchemaTooNew),
+        _ => panic!("Expected SchemaTooNew error"),
+    }
+}
+
+#[test]
+fn test_old_games_play_on_after_migration() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&320, &player1, &player2, &100, &100);
+    submit_one(&client, 320, &player1, 2);
+    client.start_game(&321, &player1, &player2, &100, &100);
+    play_tactics(&client, 321, &player1, &player2, 2, 1);
+    client.resolve_match(&321);
+    client.start_game(&322, &player1, &player2, &100, &100);
+    let token = setup_escrow(&client, &player1, &player2);
+    client.start_game(&324, &player1, &player2, &100, &100);
+
+    for session_id in [320, 321] {
+        downgrade_game(&env, &client, session_id);
+    }
+    downgrade_game_to_v1(&env, &client, 322);
+    downgrade_game_to_v2(&env, &client, 324);
+    // An old layout does not decode...
+    assert!(client.try_get_game(&320).is_err());
+
+    // ...until it is rewritten; current and missing games are skipped
+    assert_eq!(client.migrate_games(&vec![&env, 320, 321, 322, 323, 324]), 4);
+    assert_eq!(client.migrate_games(&vec![&env, 320, 321, 322, 324]), 0);
+
+    let game = client.get_game_public(&320).game;
+    assert_eq!((game.phase, game.hub_notified), (GamePhase::AwaitingOpponent, false));
+    let game = client.get_game(&321);
+    assert_eq!((game.phase, game.hub_notified), (GamePhase::Resolved, true));
+    let game = client.get_game_public(&322).game;
+    assert_eq!((game.phase, game.last_activity_ledger), (GamePhase::Created, 0));
+    assert_eq!(game.wager_token, None);
+    // The token a schema 2 game was wagered in is taken from its escrow
+    assert_eq!(client.get_game_public(&324).game.wager_token, Some(token));
+
+    submit_one(&client, 320, &player2, 1);
+    mock_reveal(&client, &player1, 320, 2);
+    mock_reveal(&client, &player2, 320, 1);
+    assert_eq!(client.resolve_match(&320), Outcome::Player1Win);
+}
+
+// ============================================================================
+// Lifecycle Event Tests
+// ============================================================================
+
+#[test]
+fn test_match_lifecycle_events() {
+    let (env, client, player1, player2) = setup_test();
+    let events = || env.events().all().filter_by_contract(&client.address);
+
+    client.start_game(&330, &player1, &player2, &100, &50);
+    assert_eq!(
+        events(),
+        [GameStarted {
+            session_id: 330,
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points: 100,
+            player2_points: 50,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+
+    submit_one(&client, 330, &player2, 1);
+    assert_eq!(
+        events(),
+        [TacticSubmitted { session_id: 330, player: player2.clone(), phase: GamePhase::AwaitingOpponent }
+            .to_xdr(&env, &client.address)]
+    );
+    submit_one(&client, 330, &player1, 2);
+    assert_eq!(
+        events(),
+        [TacticSubmitted { session_id: 330, player: player1.clone(), phase: GamePhase::BothSubmitted }
+            .to_xdr(&env, &client.address)]
+    );
+
+    // Reveals stay quiet; Aggressive vs Balanced = 2-1
+    mock_reveal(&client, &player1, 330, 2);
+    mock_reveal(&client, &player2, 330, 1);
+    assert_eq!(events(), []);
+    client.resolve_match(&330);
+    assert_eq!(
+        events(),
+        [MatchResolved {
+            session_id: 330,
+            player1: player1.clone(),
+            player2: player2.clone(),
+            outcome: Outcome::Player1Win,
+            player1_score: Some(2),
+            player2_score: Some(1),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_forfeit_publishes_timed_out() {
+    let (env, client, player1, player2) = setup_test();
+    client.start_game(&331, &player1, &player2, &100, &100);
+    submit_one(&client, 331, &player1, 1);
+
+    env.ledger().set_sequence_number(client.get_game_public(&331).game.submit_deadline_ledger + 1);
+    client.resolve_timeout(&331);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [TimedOut {
+            session_id: 331,
+            player1: player1.clone(),
+            player2,
+            winner: player1,
+            outcome: Outcome::Player1Win,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_cancel_game_before_play() {
+    let (env, client, player1, player2) = setup_test();
+    let hub = MockGameHubClient::new(&env, &client.get_hub());
+    client.start_game(&332, &player1, &player2, &100, &100);
+
+    client.cancel_game(&332);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [GameCancelled { session_id: 332, player1: player1.clone(), player2: player2.clone() }
+            .to_xdr(&env, &client.address)]
+    );
+    let game = client.get_game(&332);
+    assert_eq!((game.phase, game.hub_notified), (GamePhase::Cancelled, true));
+    assert_eq!(hub.outcome(&332), Some(HubOutcome::Draw));
+    assert_eq!(client.get_match_count(&player1), 0);
+
+    // Once anyone has submitted the game has to be played out
+    client.start_game(&333, &player1, &player2, &100, &100);
+    submit_one(&client, 333, &player1, 1);
+    for session_id in [332, 333] {
+        match client.try_cancel_game(&session_id) {
+            Err(Ok(err)) => assert_eq!(err, Error::InvalidPhase),
+            _ => panic!("Expected InvalidPhase error"),
+        }
+    }
+}
+
+#[test]
+fn test_admin_actions_publish_old_and_new_values() {
+    let (env, client, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+    let events = || env.events().all().filter_by_contract(&client.address);
+
+    let previous_hub = client.get_hub();
+    let new_hub = env.register(MockGameHub, ());
+    client.set_hub(&new_hub);
+    assert_eq!(
+        events(),
+        [HubChanged { previous_hub: Some(previous_hub), new_hub }.to_xdr(&env, &client.address)]
+    );
 
-    let winner1 = client.resolve_match(&session1);
-    let winner2 = client.resolve_match(&session2);
+    client.pause(&admin);
+    assert_eq!(
+        events(),
+        [PauseChanged { was_paused: false, paused: true }.to_xdr(&env, &client.address)]
+    );
+    client.unpause();
+    assert_eq!(
+        events(),
+        [PauseChanged { was_paused: true, paused: false }.to_xdr(&env, &client.address)]
+    );
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+    client.set_wager_limits(&admin, &10, &1_000);
+    assert_eq!(
+        events(),
+        [WagerLimitsChanged {
+            caller: admin,
+            previous: WagerLimits { min_wager: 0, max_wager: i128::MAX },
+            limits: WagerLimits { min_wager: 10, max_wager: 1_000 },
+        }
+        .to_xdr(&env, &client.address)]
+    );
 }
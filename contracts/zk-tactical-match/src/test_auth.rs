@@ -0,0 +1,2086 @@
+#![cfg(test)]
+
+// Authorization matrix tests.
+//
+// These deliberately avoid `mock_all_auths()`: every call mocks exactly the
+// signatures it is expected to need, and negative cases check that a
+// missing or wrong signer is rejected.
+
+extern crate std;
+
+use crate::{
+    player_binding, stake_hash, strategy_proof, strategy_root, tactic_hash, AdminCouncil,
+    CouncilAction, Error, OracleConfig, ProofSystem, StreakBonus, JackpotConfig, EpochConfig, DisputeConfig, StakingConfig, StakeTier, PublicInputs, Role, RoundInputs, StakeCommitment,
+    SybilGate, TeamMember, TiebreakPolicy, VkSource, ZkTacticalMatchContract, ZkTacticalMatchContractClient,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::address_payload::AddressPayload;
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger as _, MockAuth,
+    MockAuthInvoke,
+};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, Vec, vec};
+use stellar_game_testutils::{
+    bls_aggregate, bls_key, bls_sign, groth16_vk, mock_commitment, mock_nullifier, mock_proof,
+    mock_secret, setup_ledger, MockGameHub, MockVerifier,
+};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    client: ZkTacticalMatchContractClient<'static>,
+    admin: Address,
+    player1: Address,
+    player2: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    setup_ledger(&env);
+
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+
+    // Proofs need a verifier; configuring it is checked in its own tests
+    env.mock_all_auths();
+    let verifier = env.register(MockVerifier, (true,));
+    client.set_verifier(&verifier);
+    client.set_rounds_verifier(&verifier);
+    client.set_stake_verifier(&verifier);
+    env.set_auths(&[]);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    Setup {
+        env,
+        client,
+        admin,
+        player1,
+        player2,
+    }
+}
+
+/// Public inputs matching `mock_proof(tactic, session_id)` for `player`
+fn mock_inputs(s: &Setup, player: &Address, session_id: u32, tactic: u32) -> PublicInputs {
+    PublicInputs {
+        session_id,
+        player_binding: player_binding(&s.env, &s.client.address, player),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(&s.env, tactic, session_id),
+        nullifier: mock_nullifier(&s.env, player, session_id),
+        tactic_hash: tactic_hash(&s.env, tactic, &mock_secret(&s.env, player, session_id), session_id),
+    }
+}
+
+/// Submit `tactic` for `player` with their own signature
+fn submit_with_auth(s: &Setup, player: &Address, session_id: u32, tactic: u32) {
+    let inputs = mock_inputs(s, player, session_id, tactic);
+    let proof = mock_proof(&s.env, tactic, session_id);
+    let args: Vec<Val> = (session_id, player.clone(), inputs.clone(), proof.clone()).into_val(&s.env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: player,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "submit_tactic",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .submit_tactic(&session_id, player, &inputs, &proof);
+}
+
+/// `reveal_tactic` arguments opening the tactic `submit_with_auth` submitted
+fn reveal_args(s: &Setup, player: &Address, session_id: u32, tactic: u32) -> Vec<Val> {
+    let secret = mock_secret(&s.env, player, session_id);
+    (session_id, player.clone(), tactic, secret).into_val(&s.env)
+}
+
+fn invocation(
+    env: &Env,
+    contract: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> AuthorizedInvocation {
+    AuthorizedInvocation {
+        function: AuthorizedFunction::Contract((
+            contract.clone(),
+            Symbol::new(env, fn_name),
+            args,
+        )),
+        sub_invocations: std::vec![],
+    }
+}
+
+/// What both players sign to start session `session_id` between player1
+/// and `opponent`: the match terms, then what the entrypoint adds to them
+fn start_args(
+    s: &Setup,
+    session_id: u32,
+    opponent: &Address,
+    points: (i128, i128),
+    terms: Vec<Val>,
+) -> Vec<Val> {
+    let mut args: Vec<Val> =
+        (session_id, s.player1.clone(), opponent.clone(), points.0, points.1).into_val(&s.env);
+    args.append(&terms);
+    args
+}
+
+/// `start_game` arguments both players sign, with no wager token set
+fn start_game_args(s: &Setup, session_id: u32, opponent: &Address, points: (i128, i128)) -> Vec<Val> {
+    start_args(s, session_id, opponent, points, (None::<Address>,).into_val(&s.env))
+}
+
+/// Start session `session_id` with exactly the two player signatures
+fn start_with_auth(s: &Setup, session_id: u32, points: i128) {
+    let args = start_game_args(s, session_id, &s.player2, (points, points));
+    s.client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_game",
+                    args: args.clone(),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_game",
+                    args,
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .start_game(&session_id, &s.player1, &s.player2, &points, &points);
+}
+
+// ============================================================================
+// start_game
+// ============================================================================
+
+#[test]
+fn test_start_game_requires_both_players() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+
+    let args = start_game_args(&s, 1, &s.player2, (100, 100));
+    assert_eq!(
+        s.env.auths(),
+        std::vec![
+            (
+                s.player1.clone(),
+                invocation(&s.env, &s.client.address, "start_game", args.clone())
+            ),
+            (
+                s.player2.clone(),
+                invocation(&s.env, &s.client.address, "start_game", args)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_start_game_rejects_single_signer() {
+    let s = setup_test();
+    let args = start_game_args(&s, 1, &s.player2, (100, 100));
+
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "start_game",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .try_start_game(&1, &s.player1, &s.player2, &100, &100);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_start_game_rejects_wrong_points() {
+    let s = setup_test();
+
+    // Both players signed for 100 points, but the call commits 500
+    let args = start_game_args(&s, 1, &s.player2, (100, 100));
+    let result = s
+        .client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_game",
+                    args: args.clone(),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_game",
+                    args,
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .try_start_game(&1, &s.player1, &s.player2, &500, &500);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_start_game_rejects_other_terms() {
+    let s = setup_test();
+    let other = Address::generate(&s.env);
+    let start = |opponent: &Address, args2: Vec<Val>, points2: i128| {
+        s.client
+            .mock_auths(&[
+                MockAuth {
+                    address: &s.player1,
+                    invoke: &MockAuthInvoke {
+                        contract: &s.client.address,
+                        fn_name: "start_game",
+                        args: start_game_args(&s, 1, &s.player2, (100, 100)),
+                        sub_invokes: &[],
+                    },
+                },
+                MockAuth {
+                    address: opponent,
+                    invoke: &MockAuthInvoke {
+                        contract: &s.client.address,
+                        fn_name: "start_game",
+                        args: args2,
+                        sub_invokes: &[],
+                    },
+                },
+            ])
+            .try_start_game(&1, &s.player1, opponent, &100, &points2)
+    };
+
+    // Player 1 agreed to play player 2, not whoever else signs
+    let args = start_game_args(&s, 1, &other, (100, 100));
+    assert!(start(&other, args, 100).is_err());
+
+    // Nor can player 2 lower their own stake against player 1's 100
+    let args = start_game_args(&s, 1, &s.player2, (100, 10));
+    assert!(start(&s.player2, args, 10).is_err());
+
+    let args = start_game_args(&s, 1, &s.player2, (100, 100));
+    assert!(start(&s.player2, args, 100).is_ok());
+}
+
+#[test]
+fn test_cancel_game_requires_both_players() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    let invoke = MockAuthInvoke {
+        contract: &s.client.address,
+        fn_name: "cancel_game",
+        args: (1u32,).into_val(&s.env),
+        sub_invokes: &[],
+    };
+
+    for player in [&s.player1, &s.player2] {
+        let result = s
+            .client
+            .mock_auths(&[MockAuth { address: player, invoke: &invoke }])
+            .try_cancel_game(&1);
+        assert!(result.is_err());
+    }
+
+    s.client
+        .mock_auths(&[
+            MockAuth { address: &s.player1, invoke: &invoke },
+            MockAuth { address: &s.player2, invoke: &invoke },
+        ])
+        .cancel_game(&1);
+    assert_eq!(
+        s.env.auths(),
+        std::vec![
+            (
+                s.player1.clone(),
+                invocation(&s.env, &s.client.address, "cancel_game", (1u32,).into_val(&s.env))
+            ),
+            (
+                s.player2.clone(),
+                invocation(&s.env, &s.client.address, "cancel_game", (1u32,).into_val(&s.env))
+            ),
+        ]
+    );
+}
+
+// ============================================================================
+// submit_tactic
+// ============================================================================
+
+#[test]
+fn test_submit_tactic_requires_player() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+
+    let inputs = mock_inputs(&s, &s.player1, 1, 2);
+    let proof = mock_proof(&s.env, 2, 1);
+    let args: Vec<Val> = (1u32, s.player1.clone(), inputs.clone(), proof.clone()).into_val(&s.env);
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "submit_tactic",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .submit_tactic(&1, &s.player1, &inputs, &proof);
+
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.player1.clone(),
+            invocation(&s.env, &s.client.address, "submit_tactic", args)
+        )]
+    );
+}
+
+#[test]
+fn test_submit_tactic_rejects_opponent_signature() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+
+    // player2 signs a submission on behalf of player1
+    let inputs = mock_inputs(&s, &s.player1, 1, 2);
+    let proof = mock_proof(&s.env, 2, 1);
+    let args: Vec<Val> = (1u32, s.player1.clone(), inputs.clone(), proof.clone()).into_val(&s.env);
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "submit_tactic",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .try_submit_tactic(&1, &s.player1, &inputs, &proof);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_resolve_match_is_permissionless() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+
+    let players = [(&s.player1, 1u32), (&s.player2, 2u32)];
+    for (player, tactic) in players {
+        submit_with_auth(&s, player, 1, tactic);
+    }
+    for (player, tactic) in players {
+        let secret = mock_secret(&s.env, player, 1);
+        s.client
+            .mock_auths(&[MockAuth {
+                address: player,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "reveal_tactic",
+                    args: reveal_args(&s, player, 1, tactic),
+                    sub_invokes: &[],
+                },
+            }])
+            .reveal_tactic(&1, player, &tactic, &secret);
+    }
+
+    s.client.mock_auths(&[]).resolve_match(&1);
+    assert_eq!(s.env.auths(), std::vec![]);
+}
+
+#[test]
+fn test_resolve_timeout_is_permissionless() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    submit_with_auth(&s, &s.player1, 1, 2);
+
+    let deadline = s.client.get_game_public(&1).game.submit_deadline_ledger;
+    s.env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    s.client.mock_auths(&[]).resolve_timeout(&1);
+    assert_eq!(s.env.auths(), std::vec![]);
+}
+
+// ============================================================================
+// reveal_tactic
+// ============================================================================
+
+#[test]
+fn test_reveal_tactic_requires_player() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    submit_with_auth(&s, &s.player1, 1, 0);
+    submit_with_auth(&s, &s.player2, 1, 3);
+
+    let args = reveal_args(&s, &s.player1, 1, 0);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "reveal_tactic",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .reveal_tactic(&1, &s.player1, &0, &mock_secret(&s.env, &s.player1, 1));
+
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.player1.clone(),
+            invocation(&s.env, &s.client.address, "reveal_tactic", args)
+        )]
+    );
+}
+
+#[test]
+fn test_reveal_tactic_rejects_opponent_signature() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    submit_with_auth(&s, &s.player1, 1, 0);
+    submit_with_auth(&s, &s.player2, 1, 3);
+
+    // player2 cannot reveal player1's tactic for them
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "reveal_tactic",
+                args: reveal_args(&s, &s.player1, 1, 0),
+                sub_invokes: &[],
+            },
+        }])
+        .try_reveal_tactic(&1, &s.player1, &0, &mock_secret(&s.env, &s.player1, 1));
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// commit_tactic
+// ============================================================================
+
+#[test]
+fn test_commit_tactic_requires_player() {
+    let s = setup_test();
+    let system: Vec<Val> = (ProofSystem::CommitReveal,).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "set_proof_system", system));
+    start_with_auth(&s, 1, 100);
+
+    let hash = tactic_hash(&s.env, 2, &BytesN::from_array(&s.env, &[9; 32]), 1);
+    let args: Vec<Val> = (1u32, s.player1.clone(), hash.clone()).into_val(&s.env);
+
+    // The opponent cannot commit on player1's behalf
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "commit_tactic",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .try_commit_tactic(&1, &s.player1, &hash);
+    assert!(result.is_err());
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "commit_tactic",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .commit_tactic(&1, &s.player1, &hash);
+
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.player1.clone(),
+            invocation(&s.env, &s.client.address, "commit_tactic", args)
+        )]
+    );
+}
+
+// ============================================================================
+// start_hidden_stake_game / reveal_stake
+// ============================================================================
+
+/// Stake commitment for `stake`, salted with `[salt; 32]`
+fn hidden_stake(s: &Setup, stake: i128, salt: u8, session_id: u32) -> StakeCommitment {
+    StakeCommitment {
+        stake_hash: stake_hash(&s.env, stake, &BytesN::from_array(&s.env, &[salt; 32]), session_id),
+        proof: mock_proof(&s.env, salt as u32, session_id),
+    }
+}
+
+/// Start hidden-stake session `session_id` (bounds [10, 1000]), with
+/// player1 signing for `signed1` and player2 for `signed2`
+fn start_hidden_with_auth(
+    s: &Setup,
+    session_id: u32,
+    stake1: &StakeCommitment,
+    stake2: &StakeCommitment,
+    signed1: &StakeCommitment,
+    signed2: &StakeCommitment,
+) -> bool {
+    let args1: Vec<Val> = (session_id, 10i128, 1_000i128, signed1.stake_hash.clone()).into_val(&s.env);
+    let args2: Vec<Val> = (session_id, 10i128, 1_000i128, signed2.stake_hash.clone()).into_val(&s.env);
+    s.client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_hidden_stake_game",
+                    args: args1,
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_hidden_stake_game",
+                    args: args2,
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .try_start_hidden_stake_game(&session_id, &s.player1, &s.player2, &10, &1_000, stake1, stake2)
+        .is_ok()
+}
+
+#[test]
+fn test_hidden_stake_game_requires_both_players() {
+    let s = setup_test();
+    let stake1 = hidden_stake(&s, 100, 0xa1, 1);
+    let stake2 = hidden_stake(&s, 200, 0xb2, 1);
+
+    // Player 2 signed for a different stake than the one committed
+    let other = hidden_stake(&s, 900, 0xb2, 1);
+    assert!(!start_hidden_with_auth(&s, 1, &stake1, &stake2, &stake1, &other));
+
+    assert!(start_hidden_with_auth(&s, 1, &stake1, &stake2, &stake1, &stake2));
+    assert_eq!(
+        s.env.auths(),
+        std::vec![
+            (
+                s.player1.clone(),
+                invocation(
+                    &s.env,
+                    &s.client.address,
+                    "start_hidden_stake_game",
+                    (1u32, 10i128, 1_000i128, stake1.stake_hash).into_val(&s.env)
+                )
+            ),
+            (
+                s.player2.clone(),
+                invocation(
+                    &s.env,
+                    &s.client.address,
+                    "start_hidden_stake_game",
+                    (1u32, 10i128, 1_000i128, stake2.stake_hash).into_val(&s.env)
+                )
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_reveal_stake_requires_player() {
+    let s = setup_test();
+    let stake1 = hidden_stake(&s, 100, 0xa1, 1);
+    let stake2 = hidden_stake(&s, 200, 0xb2, 1);
+    assert!(start_hidden_with_auth(&s, 1, &stake1, &stake2, &stake1, &stake2));
+    submit_with_auth(&s, &s.player1, 1, 0);
+    submit_with_auth(&s, &s.player2, 1, 3);
+
+    let salt = BytesN::from_array(&s.env, &[0xa1; 32]);
+    let args: Vec<Val> = (1u32, s.player1.clone(), 100i128, salt.clone()).into_val(&s.env);
+
+    // The opponent cannot reveal player1's stake for them
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "reveal_stake",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .try_reveal_stake(&1, &s.player1, &100, &salt);
+    assert!(result.is_err());
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "reveal_stake",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .reveal_stake(&1, &s.player1, &100, &salt);
+
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.player1.clone(),
+            invocation(&s.env, &s.client.address, "reveal_stake", args)
+        )]
+    );
+}
+
+// ============================================================================
+// start_game_with_deadlines
+// ============================================================================
+
+/// Start session `session_id` with 30 + 20 ledger windows, with player2
+/// signing for a `signed_submit` ledger submit window
+fn start_deadlines_with_auth(s: &Setup, session_id: u32, signed_submit: u32) -> bool {
+    let terms = |submit: u32| (None::<Address>, submit, 20u32).into_val(&s.env);
+    let args1 = start_args(s, session_id, &s.player2, (100, 100), terms(30));
+    let args2 = start_args(s, session_id, &s.player2, (100, 100), terms(signed_submit));
+    s.client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_game_with_deadlines",
+                    args: args1,
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_game_with_deadlines",
+                    args: args2,
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .try_start_game_with_deadlines(&session_id, &s.player1, &s.player2, &100, &100, &30, &20)
+        .is_ok()
+}
+
+#[test]
+fn test_start_game_with_deadlines_requires_both_players() {
+    let s = setup_test();
+
+    // Player 2 agreed to a shorter submit window
+    assert!(!start_deadlines_with_auth(&s, 1, 10));
+
+    assert!(start_deadlines_with_auth(&s, 1, 30));
+    let terms = (None::<Address>, 30u32, 20u32).into_val(&s.env);
+    let args = start_args(&s, 1, &s.player2, (100, 100), terms);
+    assert_eq!(
+        s.env.auths(),
+        std::vec![
+            (
+                s.player1.clone(),
+                invocation(
+                    &s.env,
+                    &s.client.address,
+                    "start_game_with_deadlines",
+                    args.clone()
+                )
+            ),
+            (
+                s.player2.clone(),
+                invocation(&s.env, &s.client.address, "start_game_with_deadlines", args)
+            ),
+        ]
+    );
+}
+
+// ============================================================================
+// start_multi_round_game / submit_rounds
+// ============================================================================
+
+/// Start best-of-3 session `session_id`, with player2 signing for
+/// `signed_rounds`
+fn start_rounds_with_auth(s: &Setup, session_id: u32, signed_rounds: u32) -> bool {
+    let terms = |rounds: u32| (None::<Address>, rounds).into_val(&s.env);
+    let args1 = start_args(s, session_id, &s.player2, (100, 100), terms(3));
+    let args2 = start_args(s, session_id, &s.player2, (100, 100), terms(signed_rounds));
+    s.client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_multi_round_game",
+                    args: args1,
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_multi_round_game",
+                    args: args2,
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .try_start_multi_round_game(&session_id, &s.player1, &s.player2, &100, &100, &3)
+        .is_ok()
+}
+
+#[test]
+fn test_multi_round_game_requires_both_players() {
+    let s = setup_test();
+
+    // Player 2 agreed to a longer match
+    assert!(!start_rounds_with_auth(&s, 1, 5));
+
+    assert!(start_rounds_with_auth(&s, 1, 3));
+    let terms = (None::<Address>, 3u32).into_val(&s.env);
+    let args = start_args(&s, 1, &s.player2, (100, 100), terms);
+    assert_eq!(
+        s.env.auths(),
+        std::vec![
+            (
+                s.player1.clone(),
+                invocation(&s.env, &s.client.address, "start_multi_round_game", args.clone())
+            ),
+            (
+                s.player2.clone(),
+                invocation(&s.env, &s.client.address, "start_multi_round_game", args)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_submit_rounds_requires_player() {
+    let s = setup_test();
+    assert!(start_rounds_with_auth(&s, 1, 3));
+
+    let mut tactic_hashes = Vec::new(&s.env);
+    for tactic in 0..3 {
+        let secret = BytesN::from_array(&s.env, &[tactic as u8 + 1; 32]);
+        tactic_hashes.push_back(tactic_hash(&s.env, tactic, &secret, 1));
+    }
+    let inputs = RoundInputs {
+        session_id: 1,
+        player_binding: player_binding(&s.env, &s.client.address, &s.player1),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(&s.env, 0, 1),
+        nullifier: mock_nullifier(&s.env, &s.player1, 1),
+        tactic_hashes,
+    };
+    let proof = mock_proof(&s.env, 0, 1);
+    let args: Vec<Val> = (1u32, s.player1.clone(), inputs.clone(), proof.clone()).into_val(&s.env);
+
+    // The opponent cannot submit player1's rounds for them
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "submit_rounds",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .try_submit_rounds(&1, &s.player1, &inputs, &proof);
+    assert!(result.is_err());
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "submit_rounds",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .submit_rounds(&1, &s.player1, &inputs, &proof);
+
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.player1.clone(),
+            invocation(&s.env, &s.client.address, "submit_rounds", args)
+        )]
+    );
+}
+
+// ============================================================================
+// start_committed_multi_round_game / reveal_round
+// ============================================================================
+
+/// Strategy tree leaves for `tactics` in session 1, one secret per round
+fn strategy_leaves(env: &Env, tactics: &[u32], salt: u8) -> Vec<BytesN<32>> {
+    let mut leaves = Vec::new(env);
+    for (round, tactic) in tactics.iter().enumerate() {
+        let secret = BytesN::from_array(env, &[salt + round as u8; 32]);
+        leaves.push_back(tactic_hash(env, *tactic, &secret, 1));
+    }
+    leaves
+}
+
+#[test]
+fn test_committed_strategy_requires_both_roots_signed() {
+    let s = setup_test();
+    let root1 = strategy_root(&s.env, &strategy_leaves(&s.env, &[1, 2, 0], 0x10));
+    let root2 = strategy_root(&s.env, &strategy_leaves(&s.env, &[0, 1, 1], 0x20));
+    let terms = |root2: &BytesN<32>| (None::<Address>, 3u32, root1.clone(), root2.clone());
+    let args = start_args(&s, 1, &s.player2, (100, 100), terms(&root2).into_val(&s.env));
+    let other_root = start_args(&s, 1, &s.player2, (100, 100), terms(&root1).into_val(&s.env));
+
+    // player2 cannot be held to a root they never signed
+    let result = s
+        .client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_committed_multi_round_game",
+                    args: args.clone(),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_committed_multi_round_game",
+                    args: other_root,
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .try_start_committed_multi_round_game(
+            &1, &s.player1, &s.player2, &100, &100, &3, &root1, &root2,
+        );
+    assert!(result.is_err());
+
+    s.client
+        .mock_auths(&[
+            MockAuth {
+                address: &s.player1,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_committed_multi_round_game",
+                    args: args.clone(),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &s.player2,
+                invoke: &MockAuthInvoke {
+                    contract: &s.client.address,
+                    fn_name: "start_committed_multi_round_game",
+                    args: args.clone(),
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .start_committed_multi_round_game(
+            &1, &s.player1, &s.player2, &100, &100, &3, &root1, &root2,
+        );
+    assert_eq!(
+        s.env.auths(),
+        std::vec![
+            (
+                s.player1.clone(),
+                invocation(
+                    &s.env,
+                    &s.client.address,
+                    "start_committed_multi_round_game",
+                    args.clone()
+                )
+            ),
+            (
+                s.player2.clone(),
+                invocation(
+                    &s.env,
+                    &s.client.address,
+                    "start_committed_multi_round_game",
+                    args
+                )
+            ),
+        ]
+    );
+
+    // Opening a round needs no signature: the Merkle path is the authority
+    let leaves = strategy_leaves(&s.env, &[1, 2, 0], 0x10);
+    let secret = BytesN::from_array(&s.env, &[0x10; 32]);
+    let proof = strategy_proof(&s.env, &leaves, 0);
+    s.client
+        .mock_auths(&[])
+        .reveal_round(&1, &s.player1, &0, &1, &secret, &proof);
+    assert_eq!(s.env.auths(), std::vec![]);
+}
+
+// ============================================================================
+// Session keys
+// ============================================================================
+
+/// Grant `key` for `session_id` with the player's own signature
+fn grant_session_key(s: &Setup, session_id: u32, key: &Address, max_stake: i128, expiration: u32) {
+    let args: Vec<Val> =
+        (session_id, s.player1.clone(), key.clone(), max_stake, expiration).into_val(&s.env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "authorize_session_key",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .authorize_session_key(&session_id, &s.player1, key, &max_stake, &expiration);
+}
+
+/// Submit for player1 signed only by `signer`
+fn submit_signed_by(s: &Setup, signer: &Address, session_id: u32) -> bool {
+    let inputs = mock_inputs(s, &s.player1, session_id, 1);
+    let proof = mock_proof(&s.env, 1, session_id);
+    let args: Vec<Val> =
+        (session_id, s.player1.clone(), inputs.clone(), proof.clone()).into_val(&s.env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: signer,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "submit_tactic",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .try_submit_tactic(&session_id, &s.player1, &inputs, &proof)
+        .is_ok()
+}
+
+#[test]
+fn test_session_key_signs_submission() {
+    let s = setup_test();
+    let key = Address::generate(&s.env);
+    start_with_auth(&s, 1, 100);
+    grant_session_key(&s, 1, &key, 100, 1_000);
+
+    assert!(submit_signed_by(&s, &key, 1));
+
+    // Only the session key signed; the player's wallet was not involved
+    assert_eq!(s.env.auths().len(), 1);
+    assert_eq!(s.env.auths()[0].0, key);
+}
+
+#[test]
+fn test_session_key_scope_is_enforced() {
+    let s = setup_test();
+    let key = Address::generate(&s.env);
+    start_with_auth(&s, 1, 100);
+    start_with_auth(&s, 2, 100);
+    start_with_auth(&s, 3, 500);
+
+    // Grant for session 1 does not carry over to session 2
+    grant_session_key(&s, 1, &key, 1_000, 1_000);
+    assert!(!submit_signed_by(&s, &key, 2));
+
+    // Stake above max_stake requires the player
+    grant_session_key(&s, 3, &key, 100, 1_000);
+    assert!(!submit_signed_by(&s, &key, 3));
+    assert!(submit_signed_by(&s, &s.player1, 3));
+
+    // Expired grant requires the player
+    s.env.ledger().with_mut(|li| li.sequence_number = 1_001);
+    assert!(!submit_signed_by(&s, &key, 1));
+    assert!(submit_signed_by(&s, &s.player1, 1));
+}
+
+#[test]
+fn test_session_key_grant_requires_player_and_can_be_revoked() {
+    let s = setup_test();
+    let key = Address::generate(&s.env);
+    start_with_auth(&s, 1, 100);
+
+    // The opponent cannot grant a key for player1
+    let args: Vec<Val> = (1u32, s.player1.clone(), key.clone(), 100i128, 1_000u32).into_val(&s.env);
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "authorize_session_key",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .try_authorize_session_key(&1, &s.player1, &key, &100, &1_000);
+    assert!(result.is_err());
+
+    grant_session_key(&s, 1, &key, 100, 1_000);
+    assert_eq!(s.client.get_session_key(&1, &s.player1).unwrap().key, key);
+
+    let args: Vec<Val> = (1u32, s.player1.clone()).into_val(&s.env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player1,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "revoke_session_key",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .revoke_session_key(&1, &s.player1);
+
+    assert!(s.client.get_session_key(&1, &s.player1).is_none());
+    assert!(!submit_signed_by(&s, &key, 1));
+}
+
+// ============================================================================
+// Signed (relayed) submissions
+// ============================================================================
+
+/// Replace player1 with a `G...` account controlled by a known ed25519 key
+fn setup_with_signing_player() -> (Setup, SigningKey) {
+    let mut s = setup_test();
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&s.env, &signing_key.verifying_key().to_bytes());
+    s.player1 = Address::from_payload(
+        &s.env,
+        AddressPayload::AccountIdPublicKeyEd25519(public_key),
+    );
+    (s, signing_key)
+}
+
+/// Start a game for the signing player. `mock_auths` registers a contract at
+/// every address it mocks, which an account can't have, so this start is the
+/// one call mocked wholesale; mocking is switched off again afterwards
+fn start_with_signing_player(s: &Setup, session_id: u32, points: i128) {
+    s.env.mock_all_auths();
+    s.client
+        .start_game(&session_id, &s.player1, &s.player2, &points, &points);
+    s.env.set_auths(&[]);
+}
+
+fn sign(env: &Env, signing_key: &SigningKey, message: &Bytes) -> BytesN<64> {
+    let mut buf = std::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    BytesN::from_array(env, &signing_key.sign(&buf).to_bytes())
+}
+
+/// Player1's signature over a submission of tactic 1 for `session_id`
+fn signed_submission(
+    s: &Setup,
+    signing_key: &SigningKey,
+    session_id: u32,
+    nonce: u64,
+    expiration: u32,
+) -> (PublicInputs, Bytes, BytesN<64>) {
+    let inputs = mock_inputs(s, &s.player1, session_id, 1);
+    let proof = mock_proof(&s.env, 1, session_id);
+    let payload = s.client.submission_payload(
+        &session_id,
+        &s.player1,
+        &inputs,
+        &proof,
+        &nonce,
+        &expiration,
+    );
+    (inputs, proof, sign(&s.env, signing_key, &payload))
+}
+
+#[test]
+fn test_relayer_submits_signed_tactic_without_player_auth() {
+    let (s, signing_key) = setup_with_signing_player();
+    start_with_signing_player(&s, 1, 100);
+
+    let (inputs, proof, signature) = signed_submission(&s, &signing_key, 1, 0, 1_000);
+    s.client
+        .submit_tactic_signed(&1, &s.player1, &inputs, &proof, &0, &1_000, &signature);
+
+    assert!(s.env.auths().is_empty());
+    assert_eq!(s.client.get_nonce(&s.player1), 1);
+    assert_eq!(s.client.get_game_public(&1).game.player1_tactic_hash, Some(inputs.tactic_hash));
+}
+
+#[test]
+fn test_signed_submission_cannot_be_replayed() {
+    let (s, signing_key) = setup_with_signing_player();
+    start_with_signing_player(&s, 1, 100);
+    start_with_signing_player(&s, 2, 100);
+
+    let (inputs, proof, signature) = signed_submission(&s, &signing_key, 1, 0, 1_000);
+    s.client
+        .submit_tactic_signed(&1, &s.player1, &inputs, &proof, &0, &1_000, &signature);
+
+    let result = s
+        .client
+        .try_submit_tactic_signed(&1, &s.player1, &inputs, &proof, &0, &1_000, &signature);
+    assert_eq!(result, Err(Ok(Error::InvalidNonce)));
+
+    // A stale nonce is rejected for other sessions too
+    let (inputs, proof, signature) = signed_submission(&s, &signing_key, 2, 0, 1_000);
+    let result = s
+        .client
+        .try_submit_tactic_signed(&2, &s.player1, &inputs, &proof, &0, &1_000, &signature);
+    assert_eq!(result, Err(Ok(Error::InvalidNonce)));
+}
+
+#[test]
+fn test_signed_submission_rejects_oversized_proof() {
+    let (s, signing_key) = setup_with_signing_player();
+    start_with_signing_player(&s, 1, 100);
+    let size: Vec<Val> = (32u32,).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "set_max_proof_size", size));
+
+    // Rejected before the proof is hashed, so the nonce is not consumed
+    let (inputs, proof, signature) = signed_submission(&s, &signing_key, 1, 0, 1_000);
+    let result = s
+        .client
+        .try_submit_tactic_signed(&1, &s.player1, &inputs, &proof, &0, &1_000, &signature);
+    assert_eq!(result, Err(Ok(Error::ProofTooLarge)));
+    assert_eq!(s.client.get_nonce(&s.player1), 0);
+}
+
+#[test]
+fn test_signed_submission_expires() {
+    let (s, signing_key) = setup_with_signing_player();
+    start_with_signing_player(&s, 1, 100);
+
+    let expiration = s.env.ledger().sequence() + 10;
+    let (inputs, proof, signature) = signed_submission(&s, &signing_key, 1, 0, expiration);
+    s.env.ledger().with_mut(|li| li.sequence_number = expiration + 1);
+
+    let result = s.client.try_submit_tactic_signed(
+        &1,
+        &s.player1,
+        &inputs,
+        &proof,
+        &0,
+        &expiration,
+        &signature,
+    );
+    assert_eq!(result, Err(Ok(Error::SignatureExpired)));
+}
+
+#[test]
+fn test_signed_submission_rejects_tampered_tactic() {
+    let (s, signing_key) = setup_with_signing_player();
+    start_with_signing_player(&s, 1, 100);
+
+    // Signed for tactic 1, relayed as tactic 3
+    let (inputs, proof, signature) = signed_submission(&s, &signing_key, 1, 0, 1_000);
+    let secret = mock_secret(&s.env, &s.player1, 1);
+    let result = s.client.try_submit_tactic_signed(
+        &1,
+        &s.player1,
+        &PublicInputs {
+            tactic_hash: tactic_hash(&s.env, 3, &secret, 1),
+            ..inputs
+        },
+        &proof,
+        &0,
+        &1_000,
+        &signature,
+    );
+    assert!(result.is_err());
+    assert_eq!(s.client.get_nonce(&s.player1), 0);
+}
+
+#[test]
+fn test_signed_submission_rejects_contract_player() {
+    let s = setup_test();
+    let contract_player = s.env.register(MockGameHub, ());
+    let inputs = mock_inputs(&s, &contract_player, 1, 1);
+    let proof = mock_proof(&s.env, 1, 1);
+    let signature = BytesN::from_array(&s.env, &[0u8; 64]);
+
+    let result = s.client.try_submit_tactic_signed(
+        &1,
+        &contract_player,
+        &inputs,
+        &proof,
+        &0,
+        &1_000,
+        &signature,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidSigner)));
+}
+
+// ============================================================================
+// start_team_game / submit_team_move
+// ============================================================================
+
+/// Four members with BLS secrets 1 to 4, two per team
+fn team_members(s: &Setup) -> (Vec<TeamMember>, Vec<TeamMember>) {
+    let member = |player: &Address, seed: u8| TeamMember {
+        player: player.clone(),
+        bls_key: bls_key(&s.env, seed),
+    };
+    let team1 = Vec::from_array(
+        &s.env,
+        [member(&s.player1, 1), member(&Address::generate(&s.env), 2)],
+    );
+    let team2 = Vec::from_array(
+        &s.env,
+        [member(&s.player2, 3), member(&Address::generate(&s.env), 4)],
+    );
+    (team1, team2)
+}
+
+/// `start_team_game` arguments each member signs
+fn team_start_args(s: &Setup, session_id: u32, points: i128, member: &TeamMember) -> Vec<Val> {
+    (session_id, points, member.bls_key.clone()).into_val(&s.env)
+}
+
+/// Start a team game with the signatures of `signers` (indices into team1
+/// followed by team2)
+fn try_start_team_with_auth(
+    s: &Setup,
+    session_id: u32,
+    team1: &Vec<TeamMember>,
+    team2: &Vec<TeamMember>,
+    signers: &[usize],
+) -> bool {
+    let members: std::vec::Vec<TeamMember> = team1.iter().chain(team2.iter()).collect();
+    let args: std::vec::Vec<Vec<Val>> = members
+        .iter()
+        .map(|member| team_start_args(s, session_id, 100, member))
+        .collect();
+    let invokes: std::vec::Vec<MockAuthInvoke> = args
+        .iter()
+        .map(|args| MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "start_team_game",
+            args: args.clone(),
+            sub_invokes: &[],
+        })
+        .collect();
+    let auths: std::vec::Vec<MockAuth> = signers
+        .iter()
+        .map(|&i| MockAuth {
+            address: &members[i].player,
+            invoke: &invokes[i],
+        })
+        .collect();
+    s.client
+        .mock_auths(&auths)
+        .try_start_team_game(&session_id, team1, team2, &100, &100)
+        .is_ok()
+}
+
+#[test]
+fn test_start_team_game_requires_every_member() {
+    let s = setup_test();
+    let (team1, team2) = team_members(&s);
+
+    for missing in 0..4 {
+        let signers: std::vec::Vec<usize> = (0..4).filter(|&i| i != missing).collect();
+        assert!(!try_start_team_with_auth(&s, 1, &team1, &team2, &signers));
+    }
+    assert!(try_start_team_with_auth(&s, 1, &team1, &team2, &[0, 1, 2, 3]));
+
+    let expected: std::vec::Vec<(Address, AuthorizedInvocation)> = team1
+        .iter()
+        .chain(team2.iter())
+        .map(|member| {
+            let args = team_start_args(&s, 1, 100, &member);
+            (
+                member.player.clone(),
+                invocation(&s.env, &s.client.address, "start_team_game", args),
+            )
+        })
+        .collect();
+    assert_eq!(s.env.auths(), expected);
+}
+
+#[test]
+fn test_start_team_game_rejects_signature_for_another_key() {
+    let s = setup_test();
+    let (team1, team2) = team_members(&s);
+    let signed: std::vec::Vec<TeamMember> = team1.iter().chain(team2.iter()).collect();
+
+    // The last member signed for key 4; the call registers key 5
+    let mut registered = team2.clone();
+    let mut member = registered.get_unchecked(1);
+    member.bls_key = bls_key(&s.env, 5);
+    registered.set(1, member);
+
+    let args: std::vec::Vec<Vec<Val>> = signed
+        .iter()
+        .map(|member| team_start_args(&s, 1, 100, member))
+        .collect();
+    let invokes: std::vec::Vec<MockAuthInvoke> = args
+        .iter()
+        .map(|args| MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "start_team_game",
+            args: args.clone(),
+            sub_invokes: &[],
+        })
+        .collect();
+    let auths: std::vec::Vec<MockAuth> = signed
+        .iter()
+        .zip(invokes.iter())
+        .map(|(member, invoke)| MockAuth {
+            address: &member.player,
+            invoke,
+        })
+        .collect();
+    let result = s
+        .client
+        .mock_auths(&auths)
+        .try_start_team_game(&1, &team1, &registered, &100, &100);
+
+    assert!(result.is_err());
+}
+#[test]
+fn test_submit_team_move_needs_no_auth() {
+    let s = setup_test();
+    let (team1, team2) = team_members(&s);
+    assert!(try_start_team_with_auth(&s, 1, &team1, &team2, &[0, 1, 2, 3]));
+
+    // A relayer submits with nothing but the members' BLS signatures
+    let hashes: Vec<BytesN<32>> = Vec::from_array(
+        &s.env,
+        [
+            tactic_hash(&s.env, 2, &mock_secret(&s.env, &s.player1, 1), 1),
+            tactic_hash(&s.env, 0, &mock_secret(&s.env, &s.player1, 2), 1),
+        ],
+    );
+    let signatures: std::vec::Vec<BytesN<192>> = team1
+        .iter()
+        .zip(hashes.iter())
+        .zip([1u8, 2])
+        .map(|((member, hash), seed)| {
+            let payload = s.client.team_move_payload(&1, &member.player, &hash);
+            bls_sign(&s.env, seed, &payload)
+        })
+        .collect();
+    let signature = bls_aggregate(&s.env, &signatures);
+
+    s.client
+        .mock_auths(&[])
+        .submit_team_move(&1, &s.player1, &hashes, &signature);
+    assert_eq!(s.env.auths(), std::vec![]);
+}
+
+// ============================================================================
+// purge_game / extend_game_ttl
+// ============================================================================
+
+#[test]
+fn test_purge_game_requires_player() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    submit_with_auth(&s, &s.player1, 1, 2);
+    let deadline = s.client.get_game_public(&1).game.submit_deadline_ledger;
+    s.env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    s.client.mock_auths(&[]).resolve_timeout(&1);
+
+    let args: Vec<Val> = (1u32, s.player2.clone()).into_val(&s.env);
+    assert!(s.client.mock_auths(&[]).try_purge_game(&1, &s.player2).is_err());
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.player2,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "purge_game",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .purge_game(&1, &s.player2);
+
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.player2.clone(),
+            invocation(&s.env, &s.client.address, "purge_game", args)
+        )]
+    );
+}
+
+#[test]
+fn test_migrate_games_needs_no_auth() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    assert_eq!(s.client.mock_auths(&[]).migrate_games(&vec![&s.env, 1]), 0);
+    assert_eq!(s.env.auths(), std::vec![]);
+}
+
+#[test]
+fn test_extend_game_ttl_needs_no_auth() {
+    let s = setup_test();
+    start_with_auth(&s, 1, 100);
+    s.client.mock_auths(&[]).extend_game_ttl(&1, &1_000_000);
+    assert_eq!(s.env.auths(), std::vec![]);
+}
+
+// ============================================================================
+// Admin operations
+// ============================================================================
+
+/// Entrypoints gated by a role, whose first argument is the caller
+const ROLE_GATED: [&str; 19] = [
+    "set_tiebreak_policy",
+    "set_timeout_ledgers",
+    "set_auto_resolve",
+    "set_wager_limits",
+    "set_wager_token",
+    "set_fee_bps",
+    "set_fee_collector",
+    "set_oracle_config",
+    "set_side_bet_rake_bps",
+    "set_referral_share_bps",
+    "set_hub_share_bps",
+    "set_streak_bonus",
+    "set_jackpot_config",
+    "set_epoch_config",
+    "set_epoch_root",
+    "set_dispute_config",
+    "set_staking_config",
+    "set_max_open_games",
+    "pause",
+];
+
+/// Call an admin entrypoint with `signer`'s auth for `args`. Role-gated
+/// entrypoints get `signer` as their caller ahead of `args`
+fn admin_call_succeeds(s: &Setup, signer: &Address, fn_name: &str, args: Vec<Val>) -> bool {
+    let args = if ROLE_GATED.contains(&fn_name) {
+        let mut with_caller: Vec<Val> = (signer.clone(),).into_val(&s.env);
+        with_caller.append(&args);
+        with_caller
+    } else {
+        args
+    };
+    let auths = [MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name,
+            args: args.clone(),
+            sub_invokes: &[],
+        },
+    }];
+    let client = s.client.mock_auths(&auths);
+
+    match fn_name {
+        "propose_admin" => client
+            .try_propose_admin(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "accept_admin" => client.try_accept_admin().is_ok(),
+        "set_hub" => client
+            .try_set_hub(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_verifier" => client
+            .try_set_verifier(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_ultrahonk_verifier" => client
+            .try_set_ultrahonk_verifier(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_stake_verifier" => client
+            .try_set_stake_verifier(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_receipt_verifier" => client
+            .try_set_receipt_verifier(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_rounds_verifier" => client
+            .try_set_rounds_verifier(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_rating_verifier" => client
+            .try_set_rating_verifier(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_simulation_image_id" => client
+            .try_set_simulation_image_id(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_proof_system" => client
+            .try_set_proof_system(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_tiebreak_policy" => client
+            .try_set_tiebreak_policy(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_verification_key" => client
+            .try_set_verification_key(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_min_proof_version" => client
+            .try_set_min_proof_version(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_vk_source" => client
+            .try_set_vk_source(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_max_proof_size" => client
+            .try_set_max_proof_size(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_sybil_gate" => client
+            .try_set_sybil_gate(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_timeout_ledgers" => client
+            .try_set_timeout_ledgers(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_auto_resolve" => client
+            .try_set_auto_resolve(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_wager_limits" => client
+            .try_set_wager_limits(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+                &args.get(2).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_wager_token" => client
+            .try_set_wager_token(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_fee_bps" => client
+            .try_set_fee_bps(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_fee_collector" => client
+            .try_set_fee_collector(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_side_bet_rake_bps" => client
+            .try_set_side_bet_rake_bps(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_referral_share_bps" => client
+            .try_set_referral_share_bps(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_hub_share_bps" => client
+            .try_set_hub_share_bps(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_streak_bonus" => client
+            .try_set_streak_bonus(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_epoch_config" => client
+            .try_set_epoch_config(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_epoch_root" => client
+            .try_set_epoch_root(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+                &args.get(2).unwrap().into_val(&s.env),
+                &args.get(3).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_staking_config" => client
+            .try_set_staking_config(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_dispute_config" => client
+            .try_set_dispute_config(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_jackpot_config" => client
+            .try_set_jackpot_config(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_oracle_config" => client
+            .try_set_oracle_config(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "grant_role" => client
+            .try_grant_role(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "revoke_role" => client
+            .try_revoke_role(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "set_admin_council" => client
+            .try_set_admin_council(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "set_max_open_games" => client
+            .try_set_max_open_games(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "pause" => client
+            .try_pause(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "unpause" => client.try_unpause().is_ok(),
+        "propose_upgrade" => client
+            .try_propose_upgrade(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        "execute_upgrade" => client.try_execute_upgrade().is_ok(),
+        "migrate" => client.try_migrate().is_ok(),
+        "cancel_upgrade" => client.try_cancel_upgrade().is_ok(),
+        "propose_reinitialize" => client
+            .try_propose_reinitialize(
+                &args.get(0).unwrap().into_val(&s.env),
+                &args.get(1).unwrap().into_val(&s.env),
+            )
+            .is_ok(),
+        "reinitialize" => client.try_reinitialize().is_ok(),
+        "cancel_reinitialize" => client.try_cancel_reinitialize().is_ok(),
+        "set_upgrade_delay" => client
+            .try_set_upgrade_delay(&args.get(0).unwrap().into_val(&s.env))
+            .is_ok(),
+        _ => panic!("unknown admin entrypoint {}", fn_name),
+    }
+}
+
+#[test]
+fn test_admin_ops_require_admin() {
+    let s = setup_test();
+    let outsider = Address::generate(&s.env);
+    let target = Address::generate(&s.env);
+
+    for fn_name in [
+        "set_hub",
+        "set_verifier",
+        "set_ultrahonk_verifier",
+        "set_stake_verifier",
+        "set_receipt_verifier",
+        "set_rounds_verifier",
+        "set_rating_verifier",
+        "propose_admin",
+    ] {
+        let args: Vec<Val> = (target.clone(),).into_val(&s.env);
+        assert!(
+            !admin_call_succeeds(&s, &outsider, fn_name, args.clone()),
+            "{} accepted a non-admin signature",
+            fn_name
+        );
+        assert!(
+            !admin_call_succeeds(&s, &s.player1, fn_name, args),
+            "{} accepted a player signature",
+            fn_name
+        );
+    }
+
+    let wasm_hash: Vec<Val> = (BytesN::from_array(&s.env, &[7u8; 32]),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "propose_upgrade", wasm_hash.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "propose_upgrade", wasm_hash));
+    for fn_name in ["execute_upgrade", "cancel_upgrade"] {
+        assert!(!admin_call_succeeds(&s, &outsider, fn_name, Vec::new(&s.env)));
+    }
+    assert!(admin_call_succeeds(&s, &s.admin, "cancel_upgrade", Vec::new(&s.env)));
+
+    assert!(!admin_call_succeeds(&s, &outsider, "migrate", Vec::new(&s.env)));
+    assert!(admin_call_succeeds(&s, &s.admin, "migrate", Vec::new(&s.env)));
+
+    let delay: Vec<Val> = (100_000u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_upgrade_delay", delay.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_upgrade_delay", delay));
+
+    let vk: Vec<Val> = (groth16_vk(&s.env),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_verification_key", vk.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_verification_key", vk));
+
+    let system: Vec<Val> = (ProofSystem::UltraHonk,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_proof_system", system.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_proof_system", system));
+
+    let image_id: Vec<Val> = (Some(BytesN::from_array(&s.env, &[7u8; 32])),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_simulation_image_id", image_id.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_simulation_image_id", image_id));
+
+    let policy: Vec<Val> = (TiebreakPolicy::Random,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_tiebreak_policy", policy.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_tiebreak_policy", policy));
+
+    let version: Vec<Val> = (1u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_min_proof_version", version.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_min_proof_version", version));
+
+    let source: Vec<Val> = (Option::<VkSource>::None,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_vk_source", source.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_vk_source", source));
+
+    let size: Vec<Val> = (1_024u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_max_proof_size", size.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_max_proof_size", size));
+
+    let gate: Vec<Val> = (Option::<SybilGate>::None,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_sybil_gate", gate.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_sybil_gate", gate));
+
+    let timeout: Vec<Val> = (100u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_timeout_ledgers", timeout.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_timeout_ledgers", timeout));
+
+    let enabled: Vec<Val> = (true,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_auto_resolve", enabled.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_auto_resolve", enabled));
+
+    let council: Vec<Val> = (Option::<AdminCouncil>::None,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_admin_council", council.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_admin_council", council));
+
+    let wagers: Vec<Val> = (1i128, 1_000i128).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_wager_limits", wagers.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_wager_limits", wagers));
+
+    let token: Vec<Val> = (Some(Address::generate(&s.env)),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_wager_token", token.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_wager_token", token));
+
+    let fee: Vec<Val> = (100u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_fee_bps", fee.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_fee_bps", fee));
+
+    let collector: Vec<Val> = (Address::generate(&s.env),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_fee_collector", collector.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_fee_collector", collector));
+
+    let oracle = OracleConfig {
+        oracle: Address::generate(&s.env),
+        tolerance_bps: 100,
+        max_age_seconds: 300,
+    };
+    let oracle: Vec<Val> = (Some(oracle),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_oracle_config", oracle.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_oracle_config", oracle));
+
+    let rake: Vec<Val> = (200u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_side_bet_rake_bps", rake.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_side_bet_rake_bps", rake));
+
+    let share: Vec<Val> = (2_000u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_referral_share_bps", share.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_referral_share_bps", share));
+
+    let share: Vec<Val> = (2_000u32,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_hub_share_bps", share.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_hub_share_bps", share));
+
+    let bonus = StreakBonus {
+        min_streak: 3,
+        step_bps: 500,
+        max_bps: 2_000,
+    };
+    let bonus: Vec<Val> = (Some(bonus),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_streak_bonus", bonus.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_streak_bonus", bonus));
+
+    let jackpot = JackpotConfig {
+        share_bps: 1_000,
+        tactic: 3,
+        wins_in_a_row: 3,
+        payout_bps: 5_000,
+        min_stake: 10,
+    };
+    let jackpot: Vec<Val> = (Some(jackpot),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_jackpot_config", jackpot.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_jackpot_config", jackpot));
+
+    let epochs = EpochConfig {
+        token: Address::generate(&s.env),
+        length_ledgers: 100,
+        share_bps: 1_000,
+        top_n: 10,
+    };
+    let epochs: Vec<Val> = (Some(epochs),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_epoch_config", epochs.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_epoch_config", epochs));
+    // The admin's call needs an epoch that is over with a pool (see test.rs)
+    let root: Vec<Val> = (0u32, BytesN::from_array(&s.env, &[1; 32]), 1u32).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_epoch_root", root));
+
+    let disputes = DisputeConfig {
+        module: Address::generate(&s.env),
+        window_ledgers: 100,
+    };
+    let disputes: Vec<Val> = (Some(disputes),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_dispute_config", disputes.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_dispute_config", disputes));
+
+    let staking = StakingConfig {
+        token: Address::generate(&s.env),
+        tiers: vec![&s.env, StakeTier { min_stake: 100, discount_bps: 2_500 }],
+        lockup_ledgers: 100,
+        cooldown_ledgers: 100,
+    };
+    let staking: Vec<Val> = (Some(staking),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_staking_config", staking.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_staking_config", staking));
+
+    let limit: Vec<Val> = (Some(3u32),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "set_max_open_games", limit.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "set_max_open_games", limit));
+
+    for fn_name in ["pause", "unpause"] {
+        assert!(!admin_call_succeeds(&s, &outsider, fn_name, Vec::new(&s.env)));
+        assert!(!admin_call_succeeds(&s, &s.player1, fn_name, Vec::new(&s.env)));
+        assert!(admin_call_succeeds(&s, &s.admin, fn_name, Vec::new(&s.env)));
+    }
+}
+
+#[test]
+fn test_admin_ops_accept_admin() {
+    let s = setup_test();
+    let new_hub = s.env.register(MockGameHub, ());
+
+    let args: Vec<Val> = (new_hub.clone(),).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "set_hub", args.clone()));
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(
+            s.admin.clone(),
+            invocation(&s.env, &s.client.address, "set_hub", args)
+        )]
+    );
+    assert_eq!(s.client.get_hub(), new_hub);
+
+    // After handing over admin, the old admin can no longer act
+    let new_admin = Address::generate(&s.env);
+    let args: Vec<Val> = (new_admin.clone(),).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "propose_admin", args));
+    assert!(admin_call_succeeds(&s, &new_admin, "accept_admin", Vec::new(&s.env)));
+    assert_eq!(s.client.get_admin(), new_admin);
+
+    let args: Vec<Val> = (new_hub,).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &s.admin, "set_hub", args.clone()));
+    assert!(admin_call_succeeds(&s, &new_admin, "set_hub", args));
+}
+
+#[test]
+fn test_admin_transfer_needs_new_admin_to_accept() {
+    let s = setup_test();
+    let new_admin = Address::generate(&s.env);
+    let outsider = Address::generate(&s.env);
+
+    match s.client.try_accept_admin() {
+        Err(Ok(err)) => assert_eq!(err, Error::NoPendingAdmin),
+        _ => panic!("Expected NoPendingAdmin error"),
+    }
+
+    // A proposal alone changes nothing
+    let args: Vec<Val> = (new_admin.clone(),).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "propose_admin", args));
+    assert_eq!(s.client.get_admin(), s.admin);
+    assert_eq!(s.client.get_pending_admin(), Some(new_admin.clone()));
+
+    // Only the proposed address can accept, not the current admin
+    for signer in [&outsider, &s.admin] {
+        assert!(!admin_call_succeeds(&s, signer, "accept_admin", Vec::new(&s.env)));
+    }
+    assert_eq!(s.client.get_admin(), s.admin);
+
+    // A mistyped proposal can be replaced before it is accepted
+    let args: Vec<Val> = (outsider.clone(),).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "propose_admin", args));
+    assert!(!admin_call_succeeds(&s, &new_admin, "accept_admin", Vec::new(&s.env)));
+    assert!(admin_call_succeeds(&s, &outsider, "accept_admin", Vec::new(&s.env)));
+    assert_eq!(s.client.get_admin(), outsider);
+    assert_eq!(s.client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_reinitialize_needs_old_and_new_admin() {
+    let s = setup_test();
+    let new_admin = Address::generate(&s.env);
+    let outsider = Address::generate(&s.env);
+    let new_hub = s.env.register(MockGameHub, ());
+
+    let args: Vec<Val> = (new_admin.clone(), new_hub.clone()).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &outsider, "propose_reinitialize", args.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "propose_reinitialize", args));
+    s.env
+        .ledger()
+        .set_sequence_number(s.client.get_pending_reinit().unwrap().executable_ledger);
+
+    // Neither admin alone can apply it
+    for signer in [&outsider, &s.admin, &new_admin] {
+        assert!(!admin_call_succeeds(&s, signer, "reinitialize", Vec::new(&s.env)));
+    }
+    assert!(!admin_call_succeeds(&s, &outsider, "cancel_reinitialize", Vec::new(&s.env)));
+
+    let invoke = MockAuthInvoke {
+        contract: &s.client.address,
+        fn_name: "reinitialize",
+        args: Vec::new(&s.env),
+        sub_invokes: &[],
+    };
+    s.client
+        .mock_auths(&[
+            MockAuth { address: &s.admin, invoke: &invoke },
+            MockAuth { address: &new_admin, invoke: &invoke },
+        ])
+        .reinitialize();
+    assert_eq!(s.client.get_admin(), new_admin);
+    assert_eq!(s.client.get_hub(), new_hub);
+}
+
+#[test]
+fn test_approve_action_requires_member_auth() {
+    let s = setup_test();
+    let member = Address::generate(&s.env);
+    let council = AdminCouncil { members: vec![&s.env, member.clone()], threshold: 1 };
+    let args: Vec<Val> = (Some(council),).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "set_admin_council", args));
+
+    let args: Vec<Val> = (member.clone(), CouncilAction::Pause).into_val(&s.env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &member,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "approve_action",
+                args: args.clone(),
+                sub_invokes: &[],
+            },
+        }])
+        .approve_action(&member, &CouncilAction::Pause);
+    assert_eq!(
+        s.env.auths(),
+        std::vec![(member.clone(), invocation(&s.env, &s.client.address, "approve_action", args))]
+    );
+
+    // Once approved, anyone may carry the action out
+    s.client.mock_auths(&[]).pause(&member);
+    assert!(s.client.is_paused());
+
+    // The admin alone no longer can
+    assert!(!admin_call_succeeds(&s, &s.admin, "unpause", Vec::new(&s.env)));
+}
+
+#[test]
+fn test_roles_delegate_day_to_day_operations() {
+    let s = setup_test();
+    let bot = Address::generate(&s.env);
+    let timeout: Vec<Val> = (100u32,).into_val(&s.env);
+    let wagers: Vec<Val> = (1i128, 1_000i128).into_val(&s.env);
+
+    // Without a role the bot can do nothing
+    assert!(!admin_call_succeeds(&s, &bot, "pause", Vec::new(&s.env)));
+    assert!(!admin_call_succeeds(&s, &bot, "set_timeout_ledgers", timeout.clone()));
+    match s.client.mock_all_auths().try_set_timeout_ledgers(&bot, &100) {
+        Err(Ok(err)) => assert_eq!(err, Error::MissingRole),
+        _ => panic!("Expected MissingRole error"),
+    }
+
+    // Only the admin hands out roles
+    let grant: Vec<Val> = (bot.clone(), Role::Pauser).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &bot, "grant_role", grant.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "grant_role", grant.clone()));
+    assert!(s.client.has_role(&bot, &Role::Pauser));
+
+    // Each role covers its own duties only
+    assert!(admin_call_succeeds(&s, &bot, "pause", Vec::new(&s.env)));
+    assert!(!admin_call_succeeds(&s, &bot, "unpause", Vec::new(&s.env)));
+    assert!(!admin_call_succeeds(&s, &bot, "set_wager_limits", wagers.clone()));
+
+    let fee_manager: Vec<Val> = (bot.clone(), Role::FeeManager).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "grant_role", fee_manager));
+    assert!(admin_call_succeeds(&s, &bot, "set_wager_limits", wagers));
+    assert!(!admin_call_succeeds(&s, &bot, "set_timeout_ledgers", timeout.clone()));
+
+    // Admin holders can do what every other role can, but not upgrade
+    let admin_role: Vec<Val> = (bot.clone(), Role::Admin).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "grant_role", admin_role.clone()));
+    assert!(s.client.has_role(&bot, &Role::Operator));
+    assert!(admin_call_succeeds(&s, &bot, "set_timeout_ledgers", timeout.clone()));
+    let enabled: Vec<Val> = (true,).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &bot, "set_auto_resolve", enabled));
+    let wasm_hash: Vec<Val> = (BytesN::from_array(&s.env, &[7u8; 32]),).into_val(&s.env);
+    assert!(!admin_call_succeeds(&s, &bot, "propose_upgrade", wasm_hash));
+    assert!(!admin_call_succeeds(&s, &bot, "grant_role", grant));
+
+    // Revoking Admin takes the roles held through it
+    assert!(!admin_call_succeeds(&s, &bot, "revoke_role", admin_role.clone()));
+    assert!(admin_call_succeeds(&s, &s.admin, "revoke_role", admin_role));
+    assert!(!admin_call_succeeds(&s, &bot, "set_timeout_ledgers", timeout));
+    assert!(!s.client.has_role(&bot, &Role::Operator));
+    assert!(s.client.has_role(&bot, &Role::Pauser));
+}
+
+#[test]
+fn test_pauser_acts_alone_under_council() {
+    let s = setup_test();
+    let bot = Address::generate(&s.env);
+    let member = Address::generate(&s.env);
+    let grant: Vec<Val> = (bot.clone(), Role::Pauser).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "grant_role", grant));
+    let council = AdminCouncil { members: vec![&s.env, member], threshold: 1 };
+    let args: Vec<Val> = (Some(council),).into_val(&s.env);
+    assert!(admin_call_succeeds(&s, &s.admin, "set_admin_council", args));
+
+    // The admin now needs the council to pause, but the pauser does not
+    assert!(!admin_call_succeeds(&s, &s.admin, "pause", Vec::new(&s.env)));
+    assert!(admin_call_succeeds(&s, &bot, "pause", Vec::new(&s.env)));
+    assert!(s.client.is_paused());
+}
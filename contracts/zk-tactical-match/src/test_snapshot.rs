@@ -0,0 +1,283 @@
+#![cfg(test)]
+
+// Golden-state regression tests.
+//
+// Each test runs a canonical flow and compares a snapshot of the contract's
+// storage (entry counts, stored values, TTLs) plus write footprint against
+// fixed expected values. A failure here means a change altered the storage
+// layout or rent behaviour; update the golden values only if that was
+// intended.
+
+use crate::{
+    player_binding, tactic_hash, DataKey, Game, GamePhase, GameResult, Outcome, ProofSystem, PublicInputs, TiebreakPolicy,
+    ZkTacticalMatchContract, ZkTacticalMatchContractClient,
+};
+use soroban_sdk::testutils::storage::{Instance as _, Temporary as _};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env};
+use stellar_game_testutils::{
+    mock_commitment, mock_nullifier, mock_proof, mock_secret, MockGameHub, MockVerifier,
+    TestLedger,
+};
+
+// ============================================================================
+// Snapshot Helpers
+// ============================================================================
+
+#[derive(Debug, Eq, PartialEq)]
+struct StorageSnapshot {
+    instance_entries: u32,
+    temporary_entries: u32,
+    game: Option<Game>,
+    game_ttl: Option<u32>,
+}
+
+fn snapshot(env: &Env, contract_id: &Address, session_id: u32) -> StorageSnapshot {
+    env.as_contract(contract_id, || {
+        let key = DataKey::Game(session_id);
+        let game: Option<Game> = env.storage().temporary().get(&key);
+        let game_ttl = game
+            .as_ref()
+            .map(|_| env.storage().temporary().get_ttl(&key));
+
+        StorageSnapshot {
+            instance_entries: env.storage().instance().all().len(),
+            temporary_entries: env.storage().temporary().all().len(),
+            game,
+            game_ttl,
+        }
+    })
+}
+
+/// Ledger where new temporary entries start with a short TTL, so the
+/// contract's own `extend_ttl` calls are what the snapshot observes
+fn setup_test() -> (
+    Env,
+    ZkTacticalMatchContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    TestLedger::default()
+        .with_temp_ttl(16)
+        .with_persistent_ttl(4096)
+        .with_max_ttl(1_000_000)
+        .apply(&env);
+
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    let verifier = env.register(MockVerifier, (true,));
+    client.set_verifier(&verifier);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, player1, player2)
+}
+
+fn new_game(player1: &Address, player2: &Address) -> Game {
+    Game {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100,
+        player2_points: 50,
+        proof_system: ProofSystem::Groth16,
+        batch_verification: false,
+        simulation_image_id: None,
+        tiebreak: TiebreakPolicy::Draw,
+        auto_resolve: false,
+        submit_deadline_ledger: 17_380, // Started at ledger 100
+        resolve_deadline_ledger: 34_660,
+        player1_commitment: None,
+        player2_commitment: None,
+        player1_proof_hash: None,
+        player2_proof_hash: None,
+        player1_tactic_hash: None,
+        player2_tactic_hash: None,
+        player1_tactic: None,
+        player2_tactic: None,
+        player1_score: None,
+        player2_score: None,
+        hub_notified: false,
+        phase: GamePhase::Created,
+        result: GameResult::Pending,
+        last_activity_ledger: 100,
+        wager_token: None,
+    }
+}
+
+/// Public inputs matching `mock_proof(tactic, session_id)` for `player`
+fn mock_inputs(
+    client: &ZkTacticalMatchContractClient,
+    player: &Address,
+    session_id: u32,
+    tactic: u32,
+) -> PublicInputs {
+    let env = &client.env;
+    PublicInputs {
+        session_id,
+        player_binding: player_binding(env, &client.address, player),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(env, tactic, session_id),
+        nullifier: mock_nullifier(env, player, session_id),
+        tactic_hash: mock_tactic_hash(env, player, session_id, tactic),
+    }
+}
+
+/// Tactic hash `mock_inputs` commits to, opened with `mock_secret`
+fn mock_tactic_hash(env: &Env, player: &Address, session_id: u32, tactic: u32) -> BytesN<32> {
+    let secret = mock_secret(env, player, session_id);
+    tactic_hash(env, tactic, &secret, session_id)
+}
+
+/// Game after player1 submitted Balanced (1) and player2 Aggressive (2) in
+/// session 1
+fn submitted_game(env: &Env, player1: &Address, player2: &Address) -> Game {
+    let mut game = new_game(player1, player2);
+    game.player1_commitment = Some(mock_commitment(env, 1, 1));
+    game.player2_commitment = Some(mock_commitment(env, 2, 1));
+    game.player1_proof_hash = Some(env.crypto().keccak256(&mock_proof(env, 1, 1)).into());
+    game.player2_proof_hash = Some(env.crypto().keccak256(&mock_proof(env, 2, 1)).into());
+    game.player1_tactic_hash = Some(mock_tactic_hash(env, player1, 1, 1));
+    game.player2_tactic_hash = Some(mock_tactic_hash(env, player2, 1, 2));
+    game.phase = GamePhase::BothSubmitted;
+    game
+}
+
+/// Reveal `tactic` as submitted by `mock_inputs`
+fn reveal(client: &ZkTacticalMatchContractClient, player: &Address, session_id: u32, tactic: u32) {
+    let secret = mock_secret(&client.env, player, session_id);
+    client.reveal_tactic(&session_id, player, &tactic, &secret);
+}
+
+// ============================================================================
+// Golden Flows
+// ============================================================================
+
+#[test]
+fn test_snapshot_after_constructor() {
+    let (env, client, _player1, _player2) = setup_test();
+
+    assert_eq!(
+        snapshot(&env, &client.address, 1),
+        StorageSnapshot {
+            instance_entries: 5,
+            temporary_entries: 0,
+            game: None,
+            game_ttl: None,
+        }
+    );
+}
+
+#[test]
+fn test_snapshot_after_start() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &50);
+
+    assert_eq!(
+        snapshot(&env, &client.address, 1),
+        StorageSnapshot {
+            instance_entries: 5,
+            temporary_entries: 1,
+            game: Some(new_game(&player1, &player2)),
+            game_ttl: Some(518_400),
+        }
+    );
+}
+
+#[test]
+fn test_snapshot_after_submissions() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &50);
+
+    let proof1 = mock_proof(&env, 1, 1);
+    let proof2 = mock_proof(&env, 2, 1);
+    client.submit_tactic(&1, &player1, &mock_inputs(&client, &player1, 1, 1), &proof1);
+    client.submit_tactic(&1, &player2, &mock_inputs(&client, &player2, 1, 2), &proof2);
+
+    // A submission writes the game entry, its consumed nullifier and the
+    // player's auth nonce, plus the mock verifier's call log
+    assert_eq!(env.cost_estimate().resources().write_entries, 4);
+
+    // Tactics stay hidden until revealed
+    assert_eq!(
+        snapshot(&env, &client.address, 1),
+        StorageSnapshot {
+            instance_entries: 5,
+            temporary_entries: 1,
+            game: Some(submitted_game(&env, &player1, &player2)),
+            game_ttl: Some(518_400),
+        }
+    );
+}
+
+#[test]
+fn test_snapshot_after_reveals() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &50);
+
+    let proof1 = mock_proof(&env, 1, 1);
+    let proof2 = mock_proof(&env, 2, 1);
+    client.submit_tactic(&1, &player1, &mock_inputs(&client, &player1, 1, 1), &proof1);
+    client.submit_tactic(&1, &player2, &mock_inputs(&client, &player2, 1, 2), &proof2);
+    reveal(&client, &player1, 1, 1);
+    reveal(&client, &player2, 1, 2);
+
+    // A reveal only writes the game entry and the player's auth nonce
+    assert_eq!(env.cost_estimate().resources().write_entries, 2);
+
+    let mut expected = submitted_game(&env, &player1, &player2);
+    expected.player1_tactic = Some(1);
+    expected.player2_tactic = Some(2);
+
+    assert_eq!(
+        snapshot(&env, &client.address, 1),
+        StorageSnapshot {
+            instance_entries: 5,
+            temporary_entries: 1,
+            game: Some(expected),
+            game_ttl: Some(518_400),
+        }
+    );
+}
+
+#[test]
+fn test_snapshot_after_resolution() {
+    let (env, client, player1, player2) = setup_test();
+
+    client.start_game(&1, &player1, &player2, &100, &50);
+
+    let proof1 = mock_proof(&env, 1, 1);
+    let proof2 = mock_proof(&env, 2, 1);
+    client.submit_tactic(&1, &player1, &mock_inputs(&client, &player1, 1, 1), &proof1);
+    client.submit_tactic(&1, &player2, &mock_inputs(&client, &player2, 1, 2), &proof2);
+    reveal(&client, &player1, 1, 1);
+    reveal(&client, &player2, 1, 2);
+    client.resolve_match(&1);
+
+    // Balanced vs Aggressive = 1-2
+    let mut expected = submitted_game(&env, &player1, &player2);
+    expected.player1_tactic = Some(1);
+    expected.player2_tactic = Some(2);
+    expected.player1_score = Some(1);
+    expected.player2_score = Some(2);
+    expected.hub_notified = true;
+    expected.phase = GamePhase::Resolved;
+    expected.result = GameResult::Settled(Outcome::Player2Win);
+
+    assert_eq!(
+        snapshot(&env, &client.address, 1),
+        StorageSnapshot {
+            instance_entries: 5,
+            temporary_entries: 1,
+            game: Some(expected),
+            game_ttl: Some(518_400),
+        }
+    );
+}
@@ -0,0 +1,408 @@
+#![cfg(test)]
+
+// Stress tests for session storage.
+//
+// These run hundreds of wagered sessions through the contract with submissions
+// and reveals interleaved out of order, some timing out and some expiring
+// mid-flight, checking that every session stays isolated and consistent and
+// that every stake is paid out.
+//
+// Thousands of sessions run in batches, each in a fresh `Env` under the
+// network's resource limits: the test host counts every ledger entry towards
+// each call's memory, so one `Env` holding them all would outgrow the limits
+// (and slow down quadratically) even though no single transaction does.
+
+extern crate std;
+
+use crate::{
+    get_score, player_binding, tactic_hash, Error, Outcome, PublicInputs, ZkTacticalMatchContract,
+    ZkTacticalMatchContractClient, DEFAULT_TIMEOUT_LEDGERS, FORFEIT_SHARE_BPS, GAME_TTL_LEDGERS,
+};
+use core::cmp::Ordering;
+use core::ops::Range;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+use std::vec::Vec;
+use stellar_game_testutils::{
+    balance, fund, mock_commitment, mock_nullifier, mock_player_proof, mock_proof, mock_secret,
+    wager_token, MockGameHub, MockVerifier, TestLedger,
+};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test(ledger: TestLedger) -> (Env, ZkTacticalMatchContractClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    ledger.apply(&env);
+
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    let verifier = env.register(MockVerifier, (true,));
+    client.set_verifier(&verifier);
+
+    (env, client)
+}
+
+/// Public inputs matching `mock_proof(tactic, session_id)` for `player`
+fn mock_inputs(
+    client: &ZkTacticalMatchContractClient,
+    player: &Address,
+    session_id: u32,
+    tactic: u32,
+) -> PublicInputs {
+    let env = &client.env;
+    PublicInputs {
+        session_id,
+        player_binding: player_binding(env, &client.address, player),
+        valid_until_ledger: 0,
+        commitment: mock_commitment(env, tactic, session_id),
+        nullifier: mock_nullifier(env, player, session_id),
+        tactic_hash: tactic_hash(
+            env,
+            tactic,
+            &mock_secret(env, player, session_id),
+            session_id,
+        ),
+    }
+}
+
+/// Open the tactic committed to by `mock_inputs`
+fn mock_reveal(
+    client: &ZkTacticalMatchContractClient,
+    player: &Address,
+    session_id: u32,
+    tactic: u32,
+) {
+    let secret = mock_secret(&client.env, player, session_id);
+    client.reveal_tactic(&session_id, player, &tactic, &secret);
+}
+
+/// Deterministic Fisher-Yates shuffle (LCG), so failures are reproducible
+fn shuffle<T>(items: &mut [T], mut seed: u64) {
+    for i in (1..items.len()).rev() {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let j = (seed >> 33) as usize % (i + 1);
+        items.swap(i, j);
+    }
+}
+
+/// Tactic a player uses in a given session (deterministic, covers all pairs)
+fn tactic_for(session_id: u32, is_player1: bool) -> u32 {
+    if is_player1 {
+        session_id % 4
+    } else {
+        (session_id / 4) % 4
+    }
+}
+
+fn assert_error<T, E>(
+    result: Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected: Error,
+) {
+    match result {
+        Err(Ok(err)) => assert_eq!(err, expected),
+        _ => panic!("Expected {:?}", expected),
+    }
+}
+
+// ============================================================================
+// Stress Tests
+// ============================================================================
+
+/// Run the wagered `sessions` between 64 players with submissions and
+/// reveals shuffled, then check every session, the escrow, the open-game
+/// index and the global stats. Every tenth session times out on a missed
+/// reveal; the rest resolve.
+fn run_interleaved_sessions(env: Env, client: ZkTacticalMatchContractClient, sessions: Range<u32>) {
+    let count = sessions.len() as u32;
+    let token = wager_token(&env);
+    client.set_wager_token(&client.get_admin(), &Some(token.clone()));
+
+    const STAKE1: i128 = 100;
+    const STAKE2: i128 = 60;
+    let funds = STAKE1 * count as i128;
+    let players: Vec<Address> = (0..64).map(|_| Address::generate(&env)).collect();
+    for player in &players {
+        fund(&env, &token, &[player], funds);
+    }
+    let pair = |session_id: u32| {
+        let p1 = (session_id as usize * 7) % players.len();
+        let p2 = (p1 + 1 + session_id as usize % (players.len() - 1)) % players.len();
+        (p1, p2)
+    };
+    let times_out = |session_id: u32| session_id % 10 == 3;
+
+    for session_id in sessions.clone() {
+        let (p1, p2) = pair(session_id);
+        client.start_game(&session_id, &players[p1], &players[p2], &STAKE1, &STAKE2);
+    }
+    assert_eq!(
+        balance(&env, &token, &client.address),
+        (STAKE1 + STAKE2) * count as i128
+    );
+
+    // Every submission from every session, in shuffled order
+    let mut submissions: Vec<(u32, bool)> = sessions
+        .clone()
+        .flat_map(|session_id| [(session_id, true), (session_id, false)])
+        .collect();
+    shuffle(&mut submissions, 0x5eed ^ sessions.start as u64);
+    // Every reveal but the timed-out sessions' player 2, in another order
+    let mut reveals: Vec<(u32, bool)> = submissions
+        .iter()
+        .copied()
+        .filter(|(session_id, is_player1)| *is_player1 || !times_out(*session_id))
+        .collect();
+    shuffle(&mut reveals, 0x7e7e ^ sessions.start as u64);
+
+    for (i, (session_id, is_player1)) in submissions.iter().enumerate() {
+        let (p1, p2) = pair(*session_id);
+        let player = &players[if *is_player1 { p1 } else { p2 }];
+        let tactic = tactic_for(*session_id, *is_player1);
+        let proof = mock_player_proof(&env, player, tactic, *session_id);
+        let inputs = mock_inputs(&client, player, *session_id, tactic);
+        client.submit_tactic(session_id, player, &inputs, &proof);
+
+        // Nothing is revealed yet, so no session can resolve
+        if i % 97 == 0 {
+            let game = client.get_game_public(session_id).game;
+            let expected =
+                if game.player1_tactic_hash.is_some() && game.player2_tactic_hash.is_some() {
+                    Error::TacticNotRevealed
+                } else {
+                    Error::InvalidPhase
+                };
+            assert_error(client.try_resolve_match(session_id), expected);
+        }
+    }
+
+    // The reveals, in a different shuffled order
+    for (i, (session_id, is_player1)) in reveals.iter().enumerate() {
+        let (p1, p2) = pair(*session_id);
+        let player = &players[if *is_player1 { p1 } else { p2 }];
+        mock_reveal(
+            &client,
+            player,
+            *session_id,
+            tactic_for(*session_id, *is_player1),
+        );
+
+        // Resolve a sample of sessions while others are still mid-flight
+        if i % 97 == 0 {
+            let view = client.get_game_public(session_id);
+            if view.player1_revealed && view.player2_revealed {
+                client.resolve_match(session_id);
+            } else {
+                assert_error(
+                    client.try_resolve_match(session_id),
+                    Error::TacticNotRevealed,
+                );
+            }
+        }
+    }
+
+    // What each player's token balance should have moved by
+    let mut deltas = [0i128; 64];
+
+    // Resolve everything in reverse order and check each session in isolation
+    for session_id in sessions.clone().rev().filter(|id| !times_out(*id)) {
+        let (p1, p2) = pair(session_id);
+        let outcome = client.resolve_match(&session_id);
+
+        let game = client.get_game(&session_id);
+        let tactic1 = tactic_for(session_id, true);
+        let tactic2 = tactic_for(session_id, false);
+        let (score1, score2) = get_score(tactic1, tactic2);
+
+        assert_eq!(game.player1, players[p1]);
+        assert_eq!(game.player2, players[p2]);
+        assert_eq!(game.player1_tactic, Some(tactic1));
+        assert_eq!(game.player2_tactic, Some(tactic2));
+        assert_eq!(game.player1_score, Some(score1));
+        assert_eq!(game.player2_score, Some(score2));
+        let expected = match score1.cmp(&score2) {
+            Ordering::Greater => {
+                deltas[p1] += STAKE2;
+                deltas[p2] -= STAKE2;
+                Outcome::Player1Win
+            }
+            Ordering::Less => {
+                deltas[p1] -= STAKE1;
+                deltas[p2] += STAKE1;
+                Outcome::Player2Win
+            }
+            Ordering::Equal => Outcome::Draw,
+        };
+        assert_eq!(outcome, expected);
+
+        let late = mock_proof(&env, 0, session_id);
+        assert_error(
+            client.try_submit_tactic(
+                &session_id,
+                &players[p1],
+                &mock_inputs(&client, &players[p1], session_id, 0),
+                &late,
+            ),
+            Error::InvalidPhase,
+        );
+    }
+
+    // Only the sessions waiting on a reveal are still open
+    let timed_out = sessions.clone().filter(|id| times_out(*id)).count() as u32;
+    assert_eq!(client.get_global_stats().active_sessions, timed_out);
+
+    // Player 2 forfeits their share of the stake in every one of them
+    env.ledger()
+        .with_mut(|li| li.sequence_number += 2 * DEFAULT_TIMEOUT_LEDGERS + 1);
+    let forfeit = STAKE2 * FORFEIT_SHARE_BPS as i128 / 10_000;
+    for session_id in sessions.clone().filter(|id| times_out(*id)) {
+        let (p1, p2) = pair(session_id);
+        assert_eq!(client.resolve_timeout(&session_id), players[p1]);
+        deltas[p1] += forfeit;
+        deltas[p2] -= forfeit;
+    }
+
+    // Every escrow paid out in full, and to the right players
+    for session_id in sessions {
+        assert_eq!(client.get_escrow(&session_id), None);
+    }
+    assert_eq!(balance(&env, &token, &client.address), 0);
+    assert_eq!(client.get_accrued_fees(&token), 0);
+    for (player, delta) in players.iter().zip(deltas) {
+        assert_eq!(balance(&env, &token, player), funds + delta);
+        assert!(client.get_open_games(player).is_empty());
+    }
+
+    let stats = client.get_global_stats();
+    assert_eq!(stats.games_started, count);
+    assert_eq!(stats.games_resolved, count - timed_out);
+    assert_eq!(stats.games_timed_out, timed_out);
+    assert_eq!(stats.games_cancelled + stats.games_abandoned, 0);
+    assert_eq!(stats.total_volume, (STAKE1 + STAKE2) * count as i128);
+    assert_eq!(stats.active_sessions, 0);
+}
+
+/// Sessions per batch of `run_session_batches`
+const BATCH_SESSIONS: u32 = 50;
+
+/// Run `batches` of `BATCH_SESSIONS` interleaved sessions, each batch in a
+/// fresh `Env` with its own session IDs (and so its own pairings, tactics
+/// and shuffles)
+fn run_session_batches(batches: Range<u32>) {
+    for batch in batches {
+        let (env, client) = setup_test(TestLedger::default());
+        let first = batch * BATCH_SESSIONS;
+        run_interleaved_sessions(env, client, first..first + BATCH_SESSIONS);
+    }
+}
+
+#[test]
+fn test_hundreds_of_interleaved_sessions() {
+    let (env, client) = setup_test(TestLedger::default());
+    run_interleaved_sessions(env, client, 0..300);
+}
+
+// 2,000 sessions in all, split across tests so they can run in parallel
+
+#[test]
+fn test_thousands_of_sessions_batches_0_to_9() {
+    run_session_batches(0..10);
+}
+
+#[test]
+fn test_thousands_of_sessions_batches_10_to_19() {
+    run_session_batches(10..20);
+}
+
+#[test]
+fn test_thousands_of_sessions_batches_20_to_29() {
+    run_session_batches(20..30);
+}
+
+#[test]
+fn test_thousands_of_sessions_batches_30_to_39() {
+    run_session_batches(30..40);
+}
+
+#[test]
+fn test_expired_sessions_do_not_affect_live_ones() {
+    let (env, client) = setup_test(
+        TestLedger::default()
+            .with_temp_ttl(16)
+            .with_max_ttl(4 * GAME_TTL_LEDGERS),
+    );
+
+    const SESSIONS: u32 = 200;
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let token = wager_token(&env);
+    client.set_wager_token(&client.get_admin(), &Some(token.clone()));
+    let funds = 100 * 2 * SESSIONS as i128;
+    fund(&env, &token, &[&player1, &player2], funds);
+
+    // First wave, started at the initial ledger
+    for session_id in 0..SESSIONS {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        let proof = mock_proof(&env, 1, session_id);
+        let inputs = mock_inputs(&client, &player1, session_id, 1);
+        client.submit_tactic(&session_id, &player1, &inputs, &proof);
+    }
+
+    // Second wave, started shortly before the first wave expires
+    env.ledger()
+        .with_mut(|li| li.sequence_number += GAME_TTL_LEDGERS - 10);
+    for session_id in SESSIONS..2 * SESSIONS {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+
+    // Move past the first wave's TTL
+    env.ledger().with_mut(|li| li.sequence_number += 20);
+
+    for session_id in 0..SESSIONS {
+        assert!(client.try_get_game(&session_id).is_err());
+        assert!(client.try_resolve_match(&session_id).is_err());
+    }
+
+    for session_id in SESSIONS..2 * SESSIONS {
+        let proof1 = mock_proof(&env, 3, session_id);
+        let proof2 = mock_proof(&env, 0, session_id);
+        let inputs1 = mock_inputs(&client, &player1, session_id, 3);
+        let inputs2 = mock_inputs(&client, &player2, session_id, 0);
+        client.submit_tactic(&session_id, &player2, &inputs2, &proof2);
+        client.submit_tactic(&session_id, &player1, &inputs1, &proof1);
+        mock_reveal(&client, &player1, session_id, 3);
+        mock_reveal(&client, &player2, session_id, 0);
+
+        // AllOut vs Defensive = 2-2
+        assert_eq!(client.resolve_match(&session_id), Outcome::Draw);
+    }
+
+    // The expired wave is still escrowed until abandoned
+    assert_eq!(
+        balance(&env, &token, &client.address),
+        200 * SESSIONS as i128
+    );
+    for session_id in 0..SESSIONS {
+        client.abandon_expired(&session_id);
+        assert_eq!(client.get_escrow(&session_id), None);
+    }
+
+    // Every stake refunded, nothing left open
+    assert_eq!(balance(&env, &token, &client.address), 0);
+    for player in [&player1, &player2] {
+        assert_eq!(balance(&env, &token, player), funds);
+        assert!(client.get_open_games(player).is_empty());
+    }
+    let stats = client.get_global_stats();
+    assert_eq!(stats.games_started, 2 * SESSIONS);
+    assert_eq!(stats.games_resolved, SESSIONS);
+    assert_eq!(stats.games_abandoned, SESSIONS);
+    assert_eq!(stats.active_sessions, 0);
+}
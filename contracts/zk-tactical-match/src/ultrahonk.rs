@@ -0,0 +1,36 @@
+//! UltraHonk verification for proofs from Noir's barretenberg backend.
+//!
+//! UltraHonk has no trusted setup per circuit but its verifier is far too
+//! large to inline here, so proofs are checked by a separate verifier
+//! contract generated for the circuit (the verification key is baked in).
+//! That contract follows the barretenberg Soroban verifier interface:
+//!
+//! ```text
+//! verify_proof(public_inputs: Bytes, proof_bytes: Bytes)
+//! ```
+//!
+//! Both arguments are sequences of 32-byte big-endian field elements, and a
+//! rejected proof makes the call fail rather than return a verdict.
+
+use soroban_sdk::{contractclient, Address, Bytes, Env};
+
+/// Size of one serialized field element
+pub const FIELD_LEN: u32 = 32;
+
+// Only the generated client is used
+#[allow(dead_code)]
+#[contractclient(name = "UltraHonkVerifierClient")]
+pub trait UltraHonkVerifier {
+    fn verify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes);
+}
+
+/// Whether `proof` can be an UltraHonk proof (whole field elements only)
+pub fn is_well_formed(proof: &Bytes) -> bool {
+    !proof.is_empty() && proof.len().is_multiple_of(FIELD_LEN)
+}
+
+/// Check `proof` against `public_inputs` with the verifier at `verifier`
+pub fn verify(env: &Env, verifier: &Address, proof: &Bytes, public_inputs: &Bytes) -> bool {
+    let client = UltraHonkVerifierClient::new(env, verifier);
+    matches!(client.try_verify_proof(public_inputs, proof), Ok(Ok(())))
+}
@@ -0,0 +1,86 @@
+//! zkVM receipt adapter (RISC Zero, SP1).
+//!
+//! Games started while a simulation image ID is set are scored by an
+//! off-chain simulation of the match instead of the built-in matrix. The
+//! simulation runs as a zkVM guest program, and its execution receipt (the
+//! journal the guest committed plus the seal proving it) is handed to
+//! `resolve_with_receipt`. Seals are checked by a separate verifier contract
+//! for the zkVM, which follows the interface:
+//!
+//! ```text
+//! verify_receipt(seal: Bytes, image_id: BytesN<32>, journal_digest: BytesN<32>)
+//! ```
+//!
+//! `journal_digest` is the sha256 of the journal, and a rejected receipt
+//! makes the call fail rather than return a verdict. The journal is five
+//! u32 big-endian words:
+//!
+//! ```text
+//! session_id | tactic1 | tactic2 | score1 | score2
+//! ```
+//!
+//! The tactics are the simulation's inputs, so the contract can check that
+//! the receipt is for the tactics the players revealed.
+
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env};
+
+/// Length of a serialized journal
+pub const JOURNAL_LEN: u32 = 20;
+
+// Only the generated client is used
+#[allow(dead_code)]
+#[contractclient(name = "ReceiptVerifierClient")]
+pub trait ReceiptVerifier {
+    fn verify_receipt(env: Env, seal: Bytes, image_id: BytesN<32>, journal_digest: BytesN<32>);
+}
+
+/// Output a simulation guest commits to its journal
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Journal {
+    pub session_id: u32,
+    pub tactic1: u32,
+    pub tactic2: u32,
+    pub score1: u32,
+    pub score2: u32,
+}
+
+impl Journal {
+    /// Decode a journal, or `None` if it is not `JOURNAL_LEN` bytes long
+    pub fn decode(journal: &Bytes) -> Option<Journal> {
+        if journal.len() != JOURNAL_LEN {
+            return None;
+        }
+
+        let word = |index: u32| {
+            let mut bytes = [0u8; 4];
+            journal
+                .slice(index * 4..index * 4 + 4)
+                .copy_into_slice(&mut bytes);
+            u32::from_be_bytes(bytes)
+        };
+        Some(Journal {
+            session_id: word(0),
+            tactic1: word(1),
+            tactic2: word(2),
+            score1: word(3),
+            score2: word(4),
+        })
+    }
+}
+
+/// Check `seal` proves `image_id` produced `journal`, with the verifier at
+/// `verifier`
+pub fn verify(
+    env: &Env,
+    verifier: &Address,
+    seal: &Bytes,
+    image_id: &BytesN<32>,
+    journal: &Bytes,
+) -> bool {
+    let journal_digest: BytesN<32> = env.crypto().sha256(journal).into();
+    let client = ReceiptVerifierClient::new(env, verifier);
+    matches!(
+        client.try_verify_receipt(seal, image_id, &journal_digest),
+        Ok(Ok(()))
+    )
+}
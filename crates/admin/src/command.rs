@@ -0,0 +1,278 @@
+use std::env;
+use std::fs;
+
+pub const USAGE: &str = "\
+Usage: sgs-admin [--network <name>] [--source <identity>] [--dry-run] <command>
+
+Options:
+  --network <name>      Stellar CLI network (default: testnet)
+  --source <identity>   Signing identity or secret (default: $SGS_ADMIN_SOURCE)
+  --dry-run             Print the stellar CLI invocation instead of running it
+
+Commands:
+  deploy <wasm> <admin> <hub>          Deploy a game contract and run its constructor
+  register-game <hub> <game> <name> <version>
+                                       Allow a game contract to open sessions on the hub
+  set-hub <contract> <hub>             Point a game contract at a new Game Hub
+  set-vk <contract> <vk-file>          Upload a circuit verification key (binary file)
+  register-vk <registry> <circuit> <version> <vk-file>
+                                       Register a key version in the vk-registry
+  pause <contract>                     Pause new games and submissions (as --source)
+  unpause <contract>                   Resume a paused contract
+  inspect <contract> <session_id>      Read a session's public game state (simulation only)
+  help                                 Show this message";
+
+/// Global flags shared by every command
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    pub network: String,
+    pub source: Option<String>,
+    pub dry_run: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            network: String::from("testnet"),
+            source: env::var("SGS_ADMIN_SOURCE").ok(),
+            dry_run: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    Deploy {
+        wasm: String,
+        admin: String,
+        hub: String,
+    },
+    RegisterGame {
+        hub: String,
+        game: String,
+        name: String,
+        version: String,
+    },
+    SetHub {
+        contract: String,
+        hub: String,
+    },
+    SetVk {
+        contract: String,
+        vk_file: String,
+    },
+    RegisterVk {
+        registry: String,
+        circuit_id: String,
+        version: u32,
+        vk_file: String,
+    },
+    Pause {
+        contract: String,
+    },
+    Unpause {
+        contract: String,
+    },
+    Inspect {
+        contract: String,
+        session_id: u32,
+    },
+}
+
+/// Parse CLI arguments. Returns `Ok(None)` when help was requested.
+pub fn parse_args(args: &[String]) -> Result<Option<(Options, Command)>, String> {
+    let mut options = Options::default();
+    let mut positional: Vec<&str> = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--network" => {
+                options.network = iter.next().ok_or("--network requires a value")?.clone();
+            }
+            "--source" => {
+                options.source = Some(iter.next().ok_or("--source requires a value")?.clone());
+            }
+            "--dry-run" => options.dry_run = true,
+            "-h" | "--help" => return Ok(None),
+            flag if flag.starts_with("--") => return Err(format!("unknown option {flag}")),
+            value => positional.push(value),
+        }
+    }
+
+    let Some((name, rest)) = positional.split_first() else {
+        return Ok(None);
+    };
+
+    let command = match (*name, rest) {
+        ("help", _) => return Ok(None),
+        ("deploy", [wasm, admin, hub]) => Command::Deploy {
+            wasm: wasm.to_string(),
+            admin: admin.to_string(),
+            hub: hub.to_string(),
+        },
+        ("register-game", [hub, game, name, version]) => Command::RegisterGame {
+            hub: hub.to_string(),
+            game: game.to_string(),
+            name: name.to_string(),
+            version: version.to_string(),
+        },
+        ("set-hub", [contract, hub]) => Command::SetHub {
+            contract: contract.to_string(),
+            hub: hub.to_string(),
+        },
+        ("set-vk", [contract, vk_file]) => Command::SetVk {
+            contract: contract.to_string(),
+            vk_file: vk_file.to_string(),
+        },
+        ("register-vk", [registry, circuit_id, version, vk_file]) => Command::RegisterVk {
+            registry: registry.to_string(),
+            circuit_id: circuit_id.to_string(),
+            version: version
+                .parse()
+                .map_err(|_| format!("invalid version: {version}"))?,
+            vk_file: vk_file.to_string(),
+        },
+        ("pause", [contract]) => Command::Pause {
+            contract: contract.to_string(),
+        },
+        ("unpause", [contract]) => Command::Unpause {
+            contract: contract.to_string(),
+        },
+        ("inspect", [contract, session_id]) => Command::Inspect {
+            contract: contract.to_string(),
+            session_id: session_id
+                .parse()
+                .map_err(|_| format!("invalid session_id: {session_id}"))?,
+        },
+        (name, _) => return Err(format!("unknown command or wrong arguments: {name}")),
+    };
+
+    Ok(Some((options, command)))
+}
+
+impl Command {
+    /// Build the `stellar` CLI arguments for this command
+    pub fn to_stellar_args(&self, options: &Options) -> Result<Vec<String>, String> {
+        let source = options
+            .source
+            .clone()
+            .ok_or("no signing identity: pass --source or set SGS_ADMIN_SOURCE")?;
+
+        let args = match self {
+            Command::Deploy { wasm, admin, hub } => {
+                let mut args = base(&["contract", "deploy", "--wasm", wasm], &source, options);
+                args.extend(strings(&["--", "--admin", admin, "--game-hub", hub]));
+                args
+            }
+            Command::RegisterGame {
+                hub,
+                game,
+                name,
+                version,
+            } => {
+                let metadata = format!(
+                    r#"{{"name":"{}","version":"{}"}}"#,
+                    json_escape(name),
+                    json_escape(version)
+                );
+                invoke(
+                    hub,
+                    &source,
+                    options,
+                    &["register_game", "--game-id", game, "--metadata", &metadata],
+                )
+            }
+            Command::SetHub { contract, hub } => {
+                invoke(contract, &source, options, &["set_hub", "--new-hub", hub])
+            }
+            Command::SetVk { contract, vk_file } => {
+                let vk =
+                    fs::read(vk_file).map_err(|err| format!("failed to read {vk_file}: {err}"))?;
+                invoke(
+                    contract,
+                    &source,
+                    options,
+                    &["set_verification_key", "--vk", &hex(&vk)],
+                )
+            }
+            Command::RegisterVk {
+                registry,
+                circuit_id,
+                version,
+                vk_file,
+            } => {
+                let vk =
+                    fs::read(vk_file).map_err(|err| format!("failed to read {vk_file}: {err}"))?;
+                invoke(
+                    registry,
+                    &source,
+                    options,
+                    &[
+                        "register",
+                        "--circuit-id",
+                        circuit_id,
+                        "--version",
+                        &version.to_string(),
+                        "--vk",
+                        &hex(&vk),
+                    ],
+                )
+            }
+            // The source signs as the caller: the admin, or a Pauser
+            Command::Pause { contract } => {
+                invoke(contract, &source, options, &["pause", "--caller", &source])
+            }
+            Command::Unpause { contract } => invoke(contract, &source, options, &["unpause"]),
+            Command::Inspect {
+                contract,
+                session_id,
+            } => {
+                let mut args = base(
+                    &["contract", "invoke", "--id", contract, "--send", "no"],
+                    &source,
+                    options,
+                );
+                args.extend(strings(&[
+                    "--",
+                    "get_game_public",
+                    "--session-id",
+                    &session_id.to_string(),
+                ]));
+                args
+            }
+        };
+
+        Ok(args)
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn base(head: &[&str], source: &str, options: &Options) -> Vec<String> {
+    let mut args = strings(head);
+    args.extend(strings(&[
+        "--source-account",
+        source,
+        "--network",
+        &options.network,
+    ]));
+    args
+}
+
+fn invoke(contract: &str, source: &str, options: &Options, call: &[&str]) -> Vec<String> {
+    let mut args = base(&["contract", "invoke", "--id", contract], source, options);
+    args.push(String::from("--"));
+    args.extend(strings(call));
+    args
+}
+
+fn json_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
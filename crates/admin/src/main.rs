@@ -0,0 +1,68 @@
+//! # sgs-admin
+//!
+//! Operational runbook for Stellar Game Studio deployments, as a CLI.
+//! Every command is translated into a `stellar` CLI invocation, so the
+//! same identities and network config used by `bun run deploy` apply.
+//!
+//! ```text
+//! sgs-admin [--network <name>] [--source <identity>] [--dry-run] <command>
+//! ```
+//!
+//! Run `sgs-admin help` for the command list.
+
+mod command;
+mod stellar;
+
+use std::env;
+use std::process::ExitCode;
+
+use command::{parse_args, Options, USAGE};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let (options, command) = match parse_args(&args) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("❌ {err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let invocation = match command.to_stellar_args(&options) {
+        Ok(invocation) => invocation,
+        Err(err) => {
+            eprintln!("❌ {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    run(&options, &invocation)
+}
+
+fn run(options: &Options, invocation: &[String]) -> ExitCode {
+    if options.dry_run {
+        println!("{}", stellar::render(invocation));
+        return ExitCode::SUCCESS;
+    }
+
+    match stellar::execute(invocation) {
+        Ok(output) => {
+            if !output.is_empty() {
+                println!("{output}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("❌ {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
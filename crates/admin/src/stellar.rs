@@ -0,0 +1,37 @@
+use std::process::Command;
+
+/// Binary used for all on-chain operations
+pub const STELLAR_BIN: &str = "stellar";
+
+/// Shell-style rendering of an invocation, for `--dry-run`
+pub fn render(args: &[String]) -> String {
+    let mut out = String::from(STELLAR_BIN);
+    for arg in args {
+        out.push(' ');
+        if arg.is_empty() || arg.contains(char::is_whitespace) {
+            out.push('\'');
+            out.push_str(arg);
+            out.push('\'');
+        } else {
+            out.push_str(arg);
+        }
+    }
+    out
+}
+
+/// Run `stellar <args>` and return trimmed stdout
+pub fn execute(args: &[String]) -> Result<String, String> {
+    let output = Command::new(STELLAR_BIN)
+        .args(args)
+        .output()
+        .map_err(|err| {
+            format!("failed to run `{STELLAR_BIN}`: {err} (is the Stellar CLI installed?)")
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("`{}` failed:\n{}", render(args), stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
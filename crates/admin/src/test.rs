@@ -0,0 +1,188 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, Val};
+use stellar_game_testutils::{setup_ledger, MockGameHub};
+use zk_tactical_match::{ZkTacticalMatchContract, ZkTacticalMatchContractClient};
+
+use crate::command::{parse_args, Command, Options};
+use crate::stellar::render;
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn options() -> Options {
+    Options {
+        network: String::from("testnet"),
+        source: Some(String::from("admin")),
+        dry_run: false,
+    }
+}
+
+#[test]
+fn test_parse_global_options() {
+    let (options, command) = parse_args(&args(&[
+        "--network",
+        "futurenet",
+        "--source",
+        "ops",
+        "--dry-run",
+        "set-hub",
+        "CGAME",
+        "CHUB",
+    ]))
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(options.network, "futurenet");
+    assert_eq!(options.source.as_deref(), Some("ops"));
+    assert!(options.dry_run);
+    assert_eq!(
+        command,
+        Command::SetHub {
+            contract: String::from("CGAME"),
+            hub: String::from("CHUB"),
+        }
+    );
+}
+
+#[test]
+fn test_parse_errors_and_help() {
+    assert!(parse_args(&args(&[])).unwrap().is_none());
+    assert!(parse_args(&args(&["help"])).unwrap().is_none());
+    assert!(parse_args(&args(&["inspect", "CGAME", "abc"])).is_err());
+    assert!(parse_args(&args(&["set-hub", "CGAME"])).is_err());
+    assert!(parse_args(&args(&["--bogus", "set-hub", "CGAME", "CHUB"])).is_err());
+    assert!(parse_args(&args(&["pause"])).is_err());
+    assert!(parse_args(&args(&["register-game", "CHUB", "CGAME", "Tactics"])).is_err());
+}
+
+#[test]
+fn test_pause_and_unpause_invoke_the_contract() {
+    let (_, pause) = parse_args(&args(&["pause", "CGAME"])).unwrap().unwrap();
+    assert_eq!(
+        render(&pause.to_stellar_args(&options()).unwrap()),
+        "stellar contract invoke --id CGAME --source-account admin --network testnet \
+         -- pause --caller admin"
+    );
+
+    let (_, unpause) = parse_args(&args(&["unpause", "CGAME"])).unwrap().unwrap();
+    assert_eq!(
+        render(&unpause.to_stellar_args(&options()).unwrap()),
+        "stellar contract invoke --id CGAME --source-account admin --network testnet \
+         -- unpause"
+    );
+}
+
+#[test]
+fn test_register_game_passes_metadata_as_json() {
+    let (_, command) = parse_args(&args(&[
+        "register-game",
+        "CHUB",
+        "CGAME",
+        "Tactics",
+        "1.2.0",
+    ]))
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        command,
+        Command::RegisterGame {
+            hub: String::from("CHUB"),
+            game: String::from("CGAME"),
+            name: String::from("Tactics"),
+            version: String::from("1.2.0"),
+        }
+    );
+    assert_eq!(
+        render(&command.to_stellar_args(&options()).unwrap()),
+        "stellar contract invoke --id CHUB --source-account admin --network testnet \
+         -- register_game --game-id CGAME --metadata {\"name\":\"Tactics\",\"version\":\"1.2.0\"}"
+    );
+}
+
+#[test]
+fn test_deploy_passes_constructor_args() {
+    let command = Command::Deploy {
+        wasm: String::from("game.wasm"),
+        admin: String::from("GADMIN"),
+        hub: String::from("CHUB"),
+    };
+
+    assert_eq!(
+        render(&command.to_stellar_args(&options()).unwrap()),
+        "stellar contract deploy --wasm game.wasm --source-account admin --network testnet \
+         -- --admin GADMIN --game-hub CHUB"
+    );
+}
+
+#[test]
+fn test_inspect_is_simulation_only() {
+    let command = Command::Inspect {
+        contract: String::from("CGAME"),
+        session_id: 42,
+    };
+
+    assert_eq!(
+        render(&command.to_stellar_args(&options()).unwrap()),
+        "stellar contract invoke --id CGAME --send no --source-account admin --network testnet \
+         -- get_game_public --session-id 42"
+    );
+}
+
+#[test]
+fn test_inspect_reads_an_in_progress_session() {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+    let hub = env.register(MockGameHub, ());
+    let contract_id = env.register(ZkTacticalMatchContract, (&Address::generate(&env), &hub));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    let (player1, player2) = (Address::generate(&env), Address::generate(&env));
+    client.start_game(&7, &player1, &player2, &100, &100);
+
+    // Call the function the inspect invocation names, as the CLI would
+    let command = Command::Inspect {
+        contract: String::from("CGAME"),
+        session_id: 7,
+    };
+    let invocation = command.to_stellar_args(&options()).unwrap();
+    let separator = invocation.iter().position(|arg| arg == "--").unwrap();
+    let function = Symbol::new(&env, &invocation[separator + 1]);
+    let args = vec![&env, 7u32.into_val(&env)];
+
+    let result = env.try_invoke_contract::<Val, soroban_sdk::Error>(&contract_id, &function, args);
+    assert!(matches!(result, Ok(Ok(_))));
+    // The full view is held back until the game settles
+    assert!(client.try_get_game(&7).is_err());
+}
+
+#[test]
+fn test_register_vk_uploads_key_as_hex() {
+    let vk_file = std::env::temp_dir().join("sgs-admin-test-vk.bin");
+    std::fs::write(&vk_file, [0xab, 0x01]).unwrap();
+    let vk_path = vk_file.to_str().unwrap();
+
+    let (_, command) = parse_args(&args(&["register-vk", "CREG", "tactic", "2", vk_path]))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        render(&command.to_stellar_args(&options()).unwrap()),
+        "stellar contract invoke --id CREG --source-account admin --network testnet \
+         -- register --circuit-id tactic --version 2 --vk ab01"
+    );
+    assert!(parse_args(&args(&["register-vk", "CREG", "tactic", "v2", vk_path])).is_err());
+}
+
+#[test]
+fn test_missing_source_is_rejected() {
+    let command = Command::SetHub {
+        contract: String::from("CGAME"),
+        hub: String::from("CHUB"),
+    };
+    let options = Options {
+        source: None,
+        ..options()
+    };
+
+    assert!(command.to_stellar_args(&options).is_err());
+}
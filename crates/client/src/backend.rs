@@ -0,0 +1,251 @@
+use soroban_sdk::{Address, Bytes, BytesN, InvokeError};
+use zk_tactical_match::{
+    player_binding, tactic_hash, Error, Outcome, PublicGame, PublicInputs,
+    ZkTacticalMatchContractClient,
+};
+
+/// Snapshot of a match as seen by the client flows
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchState<A> {
+    pub player1: A,
+    pub player2: A,
+    pub player1_submitted: bool,
+    pub player2_submitted: bool,
+    pub player1_revealed: bool,
+    pub player2_revealed: bool,
+    pub outcome: Option<Outcome>,
+}
+
+impl<A: PartialEq> MatchState<A> {
+    pub fn both_submitted(&self) -> bool {
+        self.player1_submitted && self.player2_submitted
+    }
+
+    pub fn both_revealed(&self) -> bool {
+        self.player1_revealed && self.player2_revealed
+    }
+
+    /// Whether the opponent of `player` has submitted
+    pub fn opponent_submitted(&self, player: &A) -> bool {
+        if *player == self.player1 {
+            self.player2_submitted
+        } else {
+            self.player1_submitted
+        }
+    }
+}
+
+/// Transport used by [`crate::TacticalMatchFlow`]
+///
+/// `get_game` returns `Ok(None)` when the session does not exist (yet), so
+/// flows can poll across the gap between submission and inclusion.
+pub trait TacticalMatchBackend {
+    type Address: Clone + PartialEq;
+    type Error;
+
+    /// A session ID the game's hub allocated it, for `start_game`: hubs
+    /// open sessions only under IDs allocated to the game
+    fn allocate_session(&self) -> Result<u32, Self::Error>;
+
+    fn start_game(
+        &self,
+        session_id: u32,
+        player1: &Self::Address,
+        player2: &Self::Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Self::Error>;
+
+    /// The `player_binding` public input a proof for `player` must carry
+    fn player_binding(&self, player: &Self::Address) -> Result<[u8; 32], Self::Error>;
+
+    /// The `valid_until_ledger` public input to prove with now: the latest
+    /// expiry the contract's freshness window allows, or 0 (no expiry) when
+    /// no window is set
+    fn proof_valid_until(&self) -> Result<u32, Self::Error>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn submit_tactic(
+        &self,
+        session_id: u32,
+        player: &Self::Address,
+        valid_until_ledger: u32,
+        commitment: &[u8; 32],
+        nullifier: &[u8; 32],
+        tactic_hash: &[u8; 32],
+        proof: &[u8],
+    ) -> Result<(), Self::Error>;
+
+    /// The contract's `tactic_hash(tactic, salt, session_id)`, to commit to
+    /// without a proof
+    fn tactic_hash(
+        &self,
+        tactic: u32,
+        salt: &[u8; 32],
+        session_id: u32,
+    ) -> Result<[u8; 32], Self::Error>;
+
+    /// Commit to a tactic hash without a proof (`CommitReveal` games)
+    fn commit_tactic(
+        &self,
+        session_id: u32,
+        player: &Self::Address,
+        hash: &[u8; 32],
+    ) -> Result<(), Self::Error>;
+
+    /// Open the tactic committed to by an earlier `submit_tactic` or
+    /// `commit_tactic`
+    fn reveal_tactic(
+        &self,
+        session_id: u32,
+        player: &Self::Address,
+        tactic: u32,
+        secret: &[u8; 32],
+    ) -> Result<(), Self::Error>;
+
+    fn resolve_match(&self, session_id: u32) -> Result<Outcome, Self::Error>;
+
+    fn get_game(&self, session_id: u32) -> Result<Option<MatchState<Self::Address>>, Self::Error>;
+}
+
+// ============================================================================
+// In-process backend (generated contract client)
+// ============================================================================
+
+/// Failure returned by the in-process backend
+#[derive(Debug, Eq, PartialEq)]
+pub enum BackendError {
+    /// The contract returned one of its `Error` variants
+    Contract(Error),
+    /// The invocation trapped without a contract error
+    Invoke(InvokeError),
+    /// The return value could not be decoded
+    Conversion,
+}
+
+type TryResult<T, E> = Result<Result<T, E>, Result<Error, InvokeError>>;
+
+fn map_try<T, E>(result: TryResult<T, E>) -> Result<T, BackendError> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(_)) => Err(BackendError::Conversion),
+        Err(Ok(err)) => Err(BackendError::Contract(err)),
+        Err(Err(err)) => Err(BackendError::Invoke(err)),
+    }
+}
+
+impl From<PublicGame> for MatchState<Address> {
+    fn from(view: PublicGame) -> Self {
+        let game = view.game;
+        let outcome = game.outcome();
+        Self {
+            player1: game.player1,
+            player2: game.player2,
+            player1_submitted: game.player1_tactic_hash.is_some(),
+            player2_submitted: game.player2_tactic_hash.is_some(),
+            player1_revealed: view.player1_revealed,
+            player2_revealed: view.player2_revealed,
+            outcome,
+        }
+    }
+}
+
+impl TacticalMatchBackend for ZkTacticalMatchContractClient<'_> {
+    type Address = Address;
+    type Error = BackendError;
+
+    fn allocate_session(&self) -> Result<u32, BackendError> {
+        map_try(self.try_allocate_session())
+    }
+
+    fn start_game(
+        &self,
+        session_id: u32,
+        player1: &Address,
+        player2: &Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), BackendError> {
+        map_try(self.try_start_game(
+            &session_id,
+            player1,
+            player2,
+            &player1_points,
+            &player2_points,
+        ))
+    }
+
+    fn player_binding(&self, player: &Address) -> Result<[u8; 32], BackendError> {
+        Ok(player_binding(&self.env, &self.address, player).to_array())
+    }
+
+    fn proof_valid_until(&self) -> Result<u32, BackendError> {
+        let window = self.get_proof_freshness_window();
+        Ok(window.map_or(0, |window| self.env.ledger().sequence() + window))
+    }
+
+    fn submit_tactic(
+        &self,
+        session_id: u32,
+        player: &Address,
+        valid_until_ledger: u32,
+        commitment: &[u8; 32],
+        nullifier: &[u8; 32],
+        tactic_hash: &[u8; 32],
+        proof: &[u8],
+    ) -> Result<(), BackendError> {
+        let inputs = PublicInputs {
+            session_id,
+            player_binding: player_binding(&self.env, &self.address, player),
+            valid_until_ledger,
+            commitment: BytesN::from_array(&self.env, commitment),
+            nullifier: BytesN::from_array(&self.env, nullifier),
+            tactic_hash: BytesN::from_array(&self.env, tactic_hash),
+        };
+        let proof = Bytes::from_slice(&self.env, proof);
+        map_try(self.try_submit_tactic(&session_id, player, &inputs, &proof))
+    }
+
+    fn tactic_hash(
+        &self,
+        tactic: u32,
+        salt: &[u8; 32],
+        session_id: u32,
+    ) -> Result<[u8; 32], BackendError> {
+        let salt = BytesN::from_array(&self.env, salt);
+        Ok(tactic_hash(&self.env, tactic, &salt, session_id).to_array())
+    }
+
+    fn commit_tactic(
+        &self,
+        session_id: u32,
+        player: &Address,
+        hash: &[u8; 32],
+    ) -> Result<(), BackendError> {
+        let hash = BytesN::from_array(&self.env, hash);
+        map_try(self.try_commit_tactic(&session_id, player, &hash))
+    }
+
+    fn reveal_tactic(
+        &self,
+        session_id: u32,
+        player: &Address,
+        tactic: u32,
+        secret: &[u8; 32],
+    ) -> Result<(), BackendError> {
+        let secret = BytesN::from_array(&self.env, secret);
+        map_try(self.try_reveal_tactic(&session_id, player, &tactic, &secret))
+    }
+
+    fn resolve_match(&self, session_id: u32) -> Result<Outcome, BackendError> {
+        map_try(self.try_resolve_match(&session_id))
+    }
+
+    fn get_game(&self, session_id: u32) -> Result<Option<MatchState<Address>>, BackendError> {
+        match map_try(self.try_get_game_public(&session_id)) {
+            Ok(game) => Ok(Some(game.into())),
+            Err(BackendError::Contract(Error::GameNotFound)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
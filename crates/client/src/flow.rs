@@ -0,0 +1,290 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::backend::{MatchState, TacticalMatchBackend};
+use zk_tactical_match::Outcome;
+
+/// Polling policy for flows that wait on the other player or on resolution
+#[derive(Clone, Copy, Debug)]
+pub struct PollConfig {
+    pub interval: Duration,
+    pub max_attempts: u32,
+}
+
+impl Default for PollConfig {
+    /// Roughly one ledger between polls, for up to ten minutes
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            max_attempts: 120,
+        }
+    }
+}
+
+/// Errors surfaced by [`TacticalMatchFlow`]
+#[derive(Debug, Eq, PartialEq)]
+pub enum ClientError<E> {
+    /// The backend call failed
+    Backend(E),
+    /// The session does not exist
+    GameNotFound,
+    /// The awaited condition did not occur within `PollConfig::max_attempts`
+    Timeout { attempts: u32 },
+    /// Tactic outside 0-3
+    InvalidTactic,
+}
+
+/// A newly created session and its state once the opponent has joined
+pub type CreatedMatch<A> = (u32, MatchState<A>);
+
+/// Output of a [`Prover`]: the commitment, nullifier and tactic hash the
+/// proof attests to, plus the serialized proof submitted on-chain
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofArtifacts {
+    pub commitment: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub tactic_hash: [u8; 32],
+    pub proof: Vec<u8>,
+}
+
+/// Generates the commitment and proof for a tactic (e.g. by shelling out to
+/// `nargo`/`bb` or calling a proving service)
+///
+/// `player_binding` and `valid_until_ledger` are public inputs of the proof,
+/// see [`TacticalMatchBackend::player_binding`] and
+/// [`TacticalMatchBackend::proof_valid_until`].
+pub trait Prover {
+    type Error;
+
+    fn prove(
+        &self,
+        tactic: u32,
+        secret: &[u8; 32],
+        session_id: u32,
+        player_binding: &[u8; 32],
+        valid_until_ledger: u32,
+    ) -> Result<ProofArtifacts, Self::Error>;
+}
+
+/// Typed multi-step flows over a [`TacticalMatchBackend`]
+pub struct TacticalMatchFlow<B> {
+    backend: B,
+    poll: PollConfig,
+}
+
+impl<B: TacticalMatchBackend> TacticalMatchFlow<B> {
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            poll: PollConfig::default(),
+        }
+    }
+
+    pub fn with_poll_config(mut self, poll: PollConfig) -> Self {
+        self.poll = poll;
+        self
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Allocate a session ID at the game's hub and start the match under
+    /// it. Returns the session ID.
+    ///
+    /// Both players' authorizations for `start_game` with that ID must be
+    /// available to the backend (multi-sig start).
+    pub fn create_match(
+        &self,
+        player1: &B::Address,
+        player2: &B::Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, ClientError<B::Error>> {
+        let session_id = self
+            .backend
+            .allocate_session()
+            .map_err(ClientError::Backend)?;
+        self.backend
+            .start_game(session_id, player1, player2, player1_points, player2_points)
+            .map_err(ClientError::Backend)?;
+        Ok(session_id)
+    }
+
+    /// Block until the opponent of `player` has submitted a tactic
+    pub fn wait_for_opponent(
+        &self,
+        session_id: u32,
+        player: &B::Address,
+    ) -> Result<MatchState<B::Address>, ClientError<B::Error>> {
+        self.poll_until(session_id, |state| state.opponent_submitted(player))
+    }
+
+    /// [`Self::create_match`], then block until `player2` has submitted a
+    /// tactic. Returns the session ID with the match state. To keep the
+    /// session ID when the wait times out, call the two steps separately.
+    pub fn create_and_wait_for_opponent(
+        &self,
+        player1: &B::Address,
+        player2: &B::Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<CreatedMatch<B::Address>, ClientError<B::Error>> {
+        let session_id = self.create_match(player1, player2, player1_points, player2_points)?;
+        let state = self.wait_for_opponent(session_id, player1)?;
+        Ok((session_id, state))
+    }
+
+    /// Generate the commitment and proof for `tactic` with `prover`, then
+    /// submit it. Returns the artifacts so the caller can keep the
+    /// commitment alongside `secret`; both `tactic` and `secret` are needed
+    /// again for [`Self::reveal_when_opponent_submitted`].
+    pub fn submit_with_generated_commitment<P: Prover>(
+        &self,
+        prover: &P,
+        session_id: u32,
+        player: &B::Address,
+        tactic: u32,
+        secret: &[u8; 32],
+    ) -> Result<ProofArtifacts, ClientError<B::Error>>
+    where
+        P::Error: Into<B::Error>,
+    {
+        if tactic > 3 {
+            return Err(ClientError::InvalidTactic);
+        }
+
+        let player_binding = self
+            .backend
+            .player_binding(player)
+            .map_err(ClientError::Backend)?;
+        let valid_until_ledger = self
+            .backend
+            .proof_valid_until()
+            .map_err(ClientError::Backend)?;
+        let artifacts = prover
+            .prove(
+                tactic,
+                secret,
+                session_id,
+                &player_binding,
+                valid_until_ledger,
+            )
+            .map_err(|err| ClientError::Backend(err.into()))?;
+
+        self.backend
+            .submit_tactic(
+                session_id,
+                player,
+                valid_until_ledger,
+                &artifacts.commitment,
+                &artifacts.nullifier,
+                &artifacts.tactic_hash,
+                &artifacts.proof,
+            )
+            .map_err(ClientError::Backend)?;
+
+        Ok(artifacts)
+    }
+
+    /// Commit to `tactic` without a proof, in a `CommitReveal` game.
+    /// Returns the committed hash; keep `salt` for
+    /// [`Self::reveal_when_opponent_submitted`].
+    pub fn commit_with_salt(
+        &self,
+        session_id: u32,
+        player: &B::Address,
+        tactic: u32,
+        salt: &[u8; 32],
+    ) -> Result<[u8; 32], ClientError<B::Error>> {
+        if tactic > 3 {
+            return Err(ClientError::InvalidTactic);
+        }
+
+        let hash = self
+            .backend
+            .tactic_hash(tactic, salt, session_id)
+            .map_err(ClientError::Backend)?;
+
+        self.backend
+            .commit_tactic(session_id, player, &hash)
+            .map_err(ClientError::Backend)?;
+
+        Ok(hash)
+    }
+
+    /// Block until both players have submitted, then reveal `player`'s
+    /// tactic (`secret` is the proof's secret, or the salt passed to
+    /// [`Self::commit_with_salt`]).
+    pub fn reveal_when_opponent_submitted(
+        &self,
+        session_id: u32,
+        player: &B::Address,
+        tactic: u32,
+        secret: &[u8; 32],
+    ) -> Result<MatchState<B::Address>, ClientError<B::Error>> {
+        let state = self.poll_until(session_id, |state| state.both_submitted())?;
+
+        self.backend
+            .reveal_tactic(session_id, player, tactic, secret)
+            .map_err(ClientError::Backend)?;
+
+        Ok(state)
+    }
+
+    /// Poll until the session is settled.
+    ///
+    /// Once both players have revealed, this also calls `resolve_match`
+    /// (which is permissionless), so a single bot can drive a match to
+    /// settlement.
+    pub fn poll_until_resolved(
+        &self,
+        session_id: u32,
+    ) -> Result<Outcome, ClientError<B::Error>> {
+        for _ in 0..self.poll.max_attempts {
+            let state = self.fetch(session_id)?;
+
+            if let Some(outcome) = state.outcome {
+                return Ok(outcome);
+            }
+
+            if state.both_revealed() {
+                return self
+                    .backend
+                    .resolve_match(session_id)
+                    .map_err(ClientError::Backend);
+            }
+
+            thread::sleep(self.poll.interval);
+        }
+
+        Err(ClientError::Timeout {
+            attempts: self.poll.max_attempts,
+        })
+    }
+
+    fn fetch(&self, session_id: u32) -> Result<MatchState<B::Address>, ClientError<B::Error>> {
+        self.backend
+            .get_game(session_id)
+            .map_err(ClientError::Backend)?
+            .ok_or(ClientError::GameNotFound)
+    }
+
+    fn poll_until(
+        &self,
+        session_id: u32,
+        done: impl Fn(&MatchState<B::Address>) -> bool,
+    ) -> Result<MatchState<B::Address>, ClientError<B::Error>> {
+        for _ in 0..self.poll.max_attempts {
+            let state = self.fetch(session_id)?;
+            if done(&state) {
+                return Ok(state);
+            }
+            thread::sleep(self.poll.interval);
+        }
+
+        Err(ClientError::Timeout {
+            attempts: self.poll.max_attempts,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+//! # Stellar Game Client
+//!
+//! Higher-level typed flows over the generated game contract clients, for
+//! matchmaking servers and bots written in Rust:
+//!
+//! - [`TacticalMatchFlow::create_match`] (allocates the session ID at the
+//!   game's hub)
+//! - [`TacticalMatchFlow::create_and_wait_for_opponent`]
+//! - [`TacticalMatchFlow::submit_with_generated_commitment`]
+//! - [`TacticalMatchFlow::commit_with_salt`] (`CommitReveal` games, no prover)
+//! - [`TacticalMatchFlow::reveal_when_opponent_submitted`]
+//! - [`TacticalMatchFlow::poll_until_resolved`]
+//!
+//! Flows are written against the [`TacticalMatchBackend`] trait so the same
+//! code drives an in-process `Env` (simulations, tests) or an RPC transport.
+//! The generated `ZkTacticalMatchContractClient` implements the trait out of
+//! the box, see [`backend`].
+
+pub mod backend;
+pub mod flow;
+
+pub use backend::{BackendError, MatchState, TacticalMatchBackend};
+pub use flow::{ClientError, CreatedMatch, PollConfig, ProofArtifacts, Prover, TacticalMatchFlow};
+
+#[cfg(test)]
+mod test;
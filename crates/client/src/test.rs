@@ -0,0 +1,272 @@
+use std::time::Duration;
+
+use game_hub::{GameHub, GameHubClient, GameMetadata, SessionStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, BytesN, Env, String};
+use stellar_game_testutils::{mock_proof, setup_ledger, MockGameHub, MockVerifier};
+use zk_tactical_match::{
+    tactic_hash, Error, Outcome, ProofSystem, ZkTacticalMatchContract,
+    ZkTacticalMatchContractClient,
+};
+
+use crate::{
+    BackendError, ClientError, PollConfig, ProofArtifacts, Prover, TacticalMatchBackend,
+    TacticalMatchFlow,
+};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+/// Prover that emits the shared mock proof fixture
+struct FixtureProver {
+    env: Env,
+}
+
+impl Prover for FixtureProver {
+    type Error = BackendError;
+
+    fn prove(
+        &self,
+        tactic: u32,
+        secret: &[u8; 32],
+        session_id: u32,
+        _player_binding: &[u8; 32],
+        _valid_until_ledger: u32,
+    ) -> Result<ProofArtifacts, BackendError> {
+        let proof = mock_proof(&self.env, tactic, session_id);
+        let mut bytes = std::vec![0u8; proof.len() as usize];
+        proof.copy_into_slice(&mut bytes);
+        // Stand-in for Poseidon2(secret, session_id): unique per pair and
+        // below the field modulus
+        let mut nullifier = *secret;
+        nullifier[0] = 0;
+        nullifier[28..].copy_from_slice(&session_id.to_be_bytes());
+        let secret = BytesN::from_array(&self.env, secret);
+        Ok(ProofArtifacts {
+            commitment: secret.to_array(),
+            nullifier,
+            tactic_hash: tactic_hash(&self.env, tactic, &secret, session_id).to_array(),
+            proof: bytes,
+        })
+    }
+}
+
+fn setup_test() -> (
+    Env,
+    ZkTacticalMatchContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    setup_ledger(&env);
+
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ZkTacticalMatchContract, (&admin, &hub_addr));
+    let client = ZkTacticalMatchContractClient::new(&env, &contract_id);
+    // The fixture proof is not a real Groth16 proof, so accept everything
+    client.set_verifier(&env.register(MockVerifier, (true,)));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, player1, player2)
+}
+
+fn no_wait(max_attempts: u32) -> PollConfig {
+    PollConfig {
+        interval: Duration::ZERO,
+        max_attempts,
+    }
+}
+
+// ============================================================================
+// Flow Tests
+// ============================================================================
+
+#[test]
+fn test_full_flow_resolves() {
+    let (env, client, player1, player2) = setup_test();
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(3));
+    let prover = FixtureProver { env: env.clone() };
+
+    // The match is started under a session ID the hub allocated, and
+    // nobody has submitted yet, so waiting for the opponent times out
+    let session_id = flow.create_match(&player1, &player2, 100, 100).unwrap();
+    let result = flow.wait_for_opponent(session_id, &player1);
+    assert_eq!(result, Err(ClientError::Timeout { attempts: 3 }));
+
+    flow.submit_with_generated_commitment(&prover, session_id, &player2, 2, &[7; 32])
+        .unwrap();
+    assert!(flow.wait_for_opponent(session_id, &player1).is_ok());
+
+    // Revealing waits for player1's submission
+    let result = flow.reveal_when_opponent_submitted(session_id, &player2, 2, &[7; 32]);
+    assert_eq!(result, Err(ClientError::Timeout { attempts: 3 }));
+
+    flow.submit_with_generated_commitment(&prover, session_id, &player1, 0, &[9; 32])
+        .unwrap();
+
+    // Both submitted, but nothing is revealed yet
+    let result = flow.poll_until_resolved(session_id);
+    assert_eq!(result, Err(ClientError::Timeout { attempts: 3 }));
+
+    flow.reveal_when_opponent_submitted(session_id, &player2, 2, &[7; 32])
+        .unwrap();
+    flow.reveal_when_opponent_submitted(session_id, &player1, 0, &[9; 32])
+        .unwrap();
+
+    let outcome = flow.poll_until_resolved(session_id).unwrap();
+    assert_eq!(flow.backend().get_game(&session_id).outcome(), Some(outcome));
+
+    // Each match gets a fresh session ID
+    let result = flow.create_and_wait_for_opponent(&player1, &player2, 100, 100);
+    assert_eq!(result, Err(ClientError::Timeout { attempts: 3 }));
+    assert!(flow.backend().get_game_public(&(session_id + 1)).game.outcome().is_none());
+}
+
+#[test]
+fn test_flow_against_the_game_hub() {
+    let (env, client, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+    let hub = GameHubClient::new(&env, &env.register(GameHub, (&admin,)));
+    let metadata = GameMetadata {
+        name: String::from_str(&env, "ZK Tactical Match"),
+        version: String::from_str(&env, "1.0.0"),
+    };
+    hub.register_game(&client.address, &metadata);
+    let points = env.register_stellar_asset_contract_v2(admin).address();
+    hub.set_points_token(&points);
+    for player in [&player1, &player2] {
+        StellarAssetClient::new(&env, &points).mint(player, &1_000);
+        hub.deposit(player, &1_000);
+    }
+    client.set_hub(&hub.address);
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(1));
+    let prover = FixtureProver { env: env.clone() };
+
+    // The hub refuses a session ID it did not allocate the game
+    let result = flow.backend().try_start_game(&7, &player1, &player2, &100, &100);
+    assert!(result.is_err());
+
+    let session_id = flow.create_match(&player1, &player2, 100, 100).unwrap();
+    assert_eq!(hub.balance(&player1), 900);
+    flow.submit_with_generated_commitment(&prover, session_id, &player1, 2, &[3; 32])
+        .unwrap();
+    flow.submit_with_generated_commitment(&prover, session_id, &player2, 1, &[4; 32])
+        .unwrap();
+    flow.reveal_when_opponent_submitted(session_id, &player1, 2, &[3; 32])
+        .unwrap();
+    flow.reveal_when_opponent_submitted(session_id, &player2, 1, &[4; 32])
+        .unwrap();
+
+    // Aggressive (2) beats Balanced (1), and the hub pays player1 the pot
+    assert_eq!(flow.poll_until_resolved(session_id), Ok(Outcome::Player1Win));
+    assert_eq!(hub.get_session(&session_id).status, SessionStatus::Player1Won);
+    assert_eq!(hub.balance(&player1), 1_100);
+    assert_eq!(hub.balance(&player2), 900);
+}
+
+#[test]
+fn test_invalid_tactic_rejected_before_submission() {
+    let (env, client, player1, player2) = setup_test();
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(1));
+    let prover = FixtureProver { env };
+
+    flow.backend()
+        .start_game(&2, &player1, &player2, &100, &100);
+
+    let result = flow.submit_with_generated_commitment(&prover, 2, &player1, 4, &[1; 32]);
+    assert_eq!(result, Err(ClientError::InvalidTactic));
+}
+
+#[test]
+fn test_missing_session_and_contract_errors() {
+    let (env, client, player1, player2) = setup_test();
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(1));
+    let prover = FixtureProver { env };
+
+    assert_eq!(flow.poll_until_resolved(3), Err(ClientError::GameNotFound));
+
+    flow.backend()
+        .start_game(&3, &player1, &player2, &100, &100);
+    flow.submit_with_generated_commitment(&prover, 3, &player1, 1, &[1; 32])
+        .unwrap();
+
+    let result = flow.submit_with_generated_commitment(&prover, 3, &player1, 1, &[1; 32]);
+    assert_eq!(
+        result,
+        Err(ClientError::Backend(BackendError::Contract(
+            Error::AlreadySubmitted
+        )))
+    );
+}
+
+#[test]
+fn test_reveal_must_match_submitted_tactic() {
+    let (env, client, player1, player2) = setup_test();
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(1));
+    let prover = FixtureProver { env };
+
+    flow.backend()
+        .start_game(&5, &player1, &player2, &100, &100);
+    flow.submit_with_generated_commitment(&prover, 5, &player1, 1, &[2; 32])
+        .unwrap();
+    flow.submit_with_generated_commitment(&prover, 5, &player2, 2, &[3; 32])
+        .unwrap();
+
+    let result = flow.reveal_when_opponent_submitted(5, &player1, 3, &[2; 32]);
+    assert_eq!(
+        result,
+        Err(ClientError::Backend(BackendError::Contract(
+            Error::InvalidReveal
+        )))
+    );
+}
+
+#[test]
+fn test_commit_reveal_flow_needs_no_prover() {
+    let (_env, client, player1, player2) = setup_test();
+    client.set_proof_system(&ProofSystem::CommitReveal);
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(1));
+
+    flow.backend()
+        .start_game(&6, &player1, &player2, &100, &100);
+    let hash = flow.commit_with_salt(6, &player1, 2, &[6; 32]).unwrap();
+    assert_eq!(hash, flow.backend().tactic_hash(2, &[6; 32], 6).unwrap());
+    flow.commit_with_salt(6, &player2, 1, &[7; 32]).unwrap();
+
+    flow.reveal_when_opponent_submitted(6, &player1, 2, &[6; 32])
+        .unwrap();
+    flow.reveal_when_opponent_submitted(6, &player2, 1, &[7; 32])
+        .unwrap();
+
+    // Aggressive (2) beats Balanced (1)
+    assert_eq!(flow.poll_until_resolved(6), Ok(Outcome::Player1Win));
+}
+
+#[test]
+fn test_flow_proves_within_freshness_window() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_proof_freshness_window(&Some(50));
+    assert_eq!(client.proof_valid_until(), Ok(env.ledger().sequence() + 50));
+
+    let flow = TacticalMatchFlow::new(client).with_poll_config(no_wait(1));
+    let prover = FixtureProver { env };
+
+    flow.backend()
+        .start_game(&4, &player1, &player2, &100, &100);
+    flow.submit_with_generated_commitment(&prover, 4, &player1, 3, &[4; 32])
+        .unwrap();
+    flow.submit_with_generated_commitment(&prover, 4, &player2, 0, &[5; 32])
+        .unwrap();
+    flow.reveal_when_opponent_submitted(4, &player1, 3, &[4; 32])
+        .unwrap();
+    flow.reveal_when_opponent_submitted(4, &player2, 0, &[5; 32])
+        .unwrap();
+
+    assert!(flow.poll_until_resolved(4).is_ok());
+}
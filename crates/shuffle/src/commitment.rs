@@ -0,0 +1,55 @@
+//! Shuffle commitments.
+//!
+//! A player commits to `shuffle_commitment` of their permutation before
+//! seeing anyone else's, and later opens it with a [`ShuffleReveal`].
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{is_permutation, ShuffleError, MAX_DECK_SIZE};
+
+/// Opening of a shuffle commitment: the permutation and the salt that hid it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShuffleReveal {
+    pub permutation: Vec<u32>,
+    pub salt: BytesN<32>,
+}
+
+/// Commitment to `reveal` by `player` in one session
+pub fn shuffle_commitment(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    reveal: &ShuffleReveal,
+) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &session_id.to_be_bytes());
+    data.append(&player.clone().to_xdr(env));
+    data.append(&reveal.salt.clone().into());
+    data.extend_from_array(&reveal.permutation.len().to_be_bytes());
+    for position in reveal.permutation.iter() {
+        data.extend_from_array(&position.to_be_bytes());
+    }
+    env.crypto().sha256(&data).into()
+}
+
+/// Check that `reveal` opens `player`'s `commitment` to a shuffle of a
+/// `deck_size` deck
+pub fn verify_reveal(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    commitment: &BytesN<32>,
+    reveal: &ShuffleReveal,
+    deck_size: u32,
+) -> Result<(), ShuffleError> {
+    if deck_size == 0 || deck_size > MAX_DECK_SIZE {
+        return Err(ShuffleError::InvalidDeckSize);
+    }
+    if !is_permutation(&reveal.permutation, deck_size) {
+        return Err(ShuffleError::NotAPermutation);
+    }
+    if shuffle_commitment(env, session_id, player, reveal) != *commitment {
+        return Err(ShuffleError::CommitmentMismatch);
+    }
+    Ok(())
+}
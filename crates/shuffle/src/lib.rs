@@ -0,0 +1,46 @@
+#![no_std]
+
+//! # Stellar Game Shuffle
+//!
+//! Verifiable two-party deck shuffles for card games. Each player commits to
+//! a secret permutation of the deck, then reveals it; the dealt deck is the
+//! first permutation followed by the second. As long as one player picked
+//! theirs at random, neither can steer the result:
+//!
+//! ```text
+//! commitment = sha256(session_id (u32 BE) || player address XDR || salt
+//!                     || deck_size (u32 BE) || permutation[i] (u32 BE)...)
+//! deck[i]    = first[second[i]]
+//! ```
+//!
+//! Commitments name the player, so an opponent who copies one cannot open
+//! it, and therefore cannot mirror the other's shuffle once revealed. A
+//! player who never reveals stalls the deal; games should pair this with a
+//! reveal deadline.
+//!
+//! - [`permutation`] - permutation checks and composition
+//! - [`commitment`] - shuffle commitments and their reveals
+
+pub mod commitment;
+pub mod permutation;
+
+pub use commitment::{shuffle_commitment, verify_reveal, ShuffleReveal};
+pub use permutation::{apply, is_permutation, shuffled_deck};
+
+/// Largest deck a shuffle may cover (two standard decks plus jokers fit)
+pub const MAX_DECK_SIZE: u32 = 128;
+
+/// Why a revealed shuffle was rejected. Contracts map these onto their own
+/// `Error` enums
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShuffleError {
+    /// The deck is empty or larger than `MAX_DECK_SIZE`
+    InvalidDeckSize,
+    /// The reveal does not list every position of the deck exactly once
+    NotAPermutation,
+    /// The reveal does not open the player's commitment
+    CommitmentMismatch,
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,47 @@
+//! Deck permutations.
+//!
+//! A permutation of a deck of `n` cards lists, for every position `i`, the
+//! position in the incoming deck of the card that moves to `i`.
+
+use soroban_sdk::{Env, Vec};
+
+use crate::MAX_DECK_SIZE;
+
+/// Whether `permutation` lists every position of a `deck_size` deck exactly
+/// once. Decks larger than `MAX_DECK_SIZE` never qualify
+pub fn is_permutation(permutation: &Vec<u32>, deck_size: u32) -> bool {
+    if deck_size > MAX_DECK_SIZE || permutation.len() != deck_size {
+        return false;
+    }
+
+    let mut seen = [false; MAX_DECK_SIZE as usize];
+    for position in permutation.iter() {
+        if position >= deck_size || seen[position as usize] {
+            return false;
+        }
+        seen[position as usize] = true;
+    }
+    true
+}
+
+/// `deck` rearranged by `permutation`: position `i` holds
+/// `deck[permutation[i]]`. Both must have the same length
+pub fn apply(env: &Env, deck: &Vec<u32>, permutation: &Vec<u32>) -> Vec<u32> {
+    let mut shuffled = Vec::new(env);
+    for position in permutation.iter() {
+        shuffled.push_back(deck.get_unchecked(position));
+    }
+    shuffled
+}
+
+/// The deck `0..deck_size` after each of `permutations` in turn
+pub fn shuffled_deck(env: &Env, deck_size: u32, permutations: &[Vec<u32>]) -> Vec<u32> {
+    let mut deck = Vec::new(env);
+    for card in 0..deck_size {
+        deck.push_back(card);
+    }
+    for permutation in permutations {
+        deck = apply(env, &deck, permutation);
+    }
+    deck
+}
@@ -0,0 +1,125 @@
+extern crate std;
+
+use crate::{
+    apply, is_permutation, shuffle_commitment, shuffled_deck, verify_reveal, ShuffleError,
+    ShuffleReveal, MAX_DECK_SIZE,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn reveal(env: &Env, permutation: &[u32], salt: u8) -> ShuffleReveal {
+    ShuffleReveal {
+        permutation: Vec::from_slice(env, permutation),
+        salt: BytesN::from_array(env, &[salt; 32]),
+    }
+}
+
+#[test]
+fn test_is_permutation() {
+    let env = Env::default();
+    assert!(is_permutation(&Vec::from_slice(&env, &[0, 1, 2, 3]), 4));
+    assert!(is_permutation(&Vec::from_slice(&env, &[3, 0, 2, 1]), 4));
+
+    // Repeated, missing or out-of-range positions, and the wrong length
+    assert!(!is_permutation(&Vec::from_slice(&env, &[0, 1, 1, 3]), 4));
+    assert!(!is_permutation(&Vec::from_slice(&env, &[0, 1, 2, 4]), 4));
+    assert!(!is_permutation(&Vec::from_slice(&env, &[0, 1, 2]), 4));
+
+    let oversized = shuffled_deck(&env, MAX_DECK_SIZE + 1, &[]);
+    assert!(!is_permutation(&oversized, MAX_DECK_SIZE + 1));
+    let largest = shuffled_deck(&env, MAX_DECK_SIZE, &[]);
+    assert!(is_permutation(&largest, MAX_DECK_SIZE));
+}
+
+#[test]
+fn test_shuffled_deck_applies_permutations_in_order() {
+    let env = Env::default();
+    let first = Vec::from_slice(&env, &[1, 2, 3, 0]);
+    let second = Vec::from_slice(&env, &[3, 2, 1, 0]);
+
+    assert_eq!(
+        shuffled_deck(&env, 4, &[]),
+        Vec::from_slice(&env, &[0, 1, 2, 3])
+    );
+    assert_eq!(shuffled_deck(&env, 4, core::slice::from_ref(&first)), first);
+    assert_eq!(
+        shuffled_deck(&env, 4, &[first.clone(), second.clone()]),
+        Vec::from_slice(&env, &[0, 3, 2, 1])
+    );
+    assert_eq!(
+        apply(&env, &first, &second),
+        shuffled_deck(&env, 4, &[first, second])
+    );
+}
+
+#[test]
+fn test_either_player_alone_cannot_fix_the_deck() {
+    let env = Env::default();
+    let first = Vec::from_slice(&env, &[2, 0, 3, 1]);
+
+    // Whatever the first player picked, every second shuffle deals a
+    // different deck
+    let seconds = [[0, 1, 2, 3], [1, 0, 3, 2], [3, 2, 1, 0], [2, 3, 0, 1]];
+    let decks: std::vec::Vec<Vec<u32>> = seconds
+        .iter()
+        .map(|second| shuffled_deck(&env, 4, &[first.clone(), Vec::from_slice(&env, second)]))
+        .collect();
+    for (i, deck) in decks.iter().enumerate() {
+        assert!(is_permutation(deck, 4));
+        assert!(decks[i + 1..].iter().all(|other| other != deck));
+    }
+}
+
+#[test]
+fn test_verify_reveal() {
+    let env = Env::default();
+    let player = Address::generate(&env);
+    let opened = reveal(&env, &[2, 0, 3, 1], 7);
+    let commitment = shuffle_commitment(&env, 1, &player, &opened);
+
+    assert_eq!(
+        verify_reveal(&env, 1, &player, &commitment, &opened, 4),
+        Ok(())
+    );
+
+    // Another salt, permutation, session or player
+    let mismatched = [
+        (1, player.clone(), reveal(&env, &[2, 0, 3, 1], 8)),
+        (1, player.clone(), reveal(&env, &[2, 0, 1, 3], 7)),
+        (2, player.clone(), opened.clone()),
+        (1, Address::generate(&env), opened.clone()),
+    ];
+    for (session_id, player, reveal) in mismatched {
+        assert_eq!(
+            verify_reveal(&env, session_id, &player, &commitment, &reveal, 4),
+            Err(ShuffleError::CommitmentMismatch)
+        );
+    }
+}
+
+#[test]
+fn test_verify_reveal_checks_the_deck() {
+    let env = Env::default();
+    let player = Address::generate(&env);
+
+    // A committed non-permutation still cannot be opened
+    let stacked = reveal(&env, &[0, 0, 0, 0], 7);
+    let commitment = shuffle_commitment(&env, 1, &player, &stacked);
+    assert_eq!(
+        verify_reveal(&env, 1, &player, &commitment, &stacked, 4),
+        Err(ShuffleError::NotAPermutation)
+    );
+
+    let opened = reveal(&env, &[2, 0, 3, 1], 7);
+    let commitment = shuffle_commitment(&env, 1, &player, &opened);
+    assert_eq!(
+        verify_reveal(&env, 1, &player, &commitment, &opened, 5),
+        Err(ShuffleError::NotAPermutation)
+    );
+    for deck_size in [0, MAX_DECK_SIZE + 1] {
+        assert_eq!(
+            verify_reveal(&env, 1, &player, &commitment, &opened, deck_size),
+            Err(ShuffleError::InvalidDeckSize)
+        );
+    }
+}
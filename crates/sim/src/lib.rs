@@ -0,0 +1,24 @@
+//! # Stellar Game Sim
+//!
+//! Off-chain Monte-Carlo balance simulation for the ZK Tactical Match score
+//! matrix. Plays large numbers of matches between two tactic distributions
+//! and reports per-tactic expected values, so matrix changes can be checked
+//! for dominant strategies before they are deployed.
+//!
+//! - [`ScoreMatrix`] - the 4x4 matrix, defaulting to the on-chain one
+//! - [`Strategy`] - a probability distribution over tactics
+//! - [`simulate`] - Monte-Carlo run producing a [`Report`]
+//! - [`expected_values`] - exact per-tactic EVs for cross-checking
+
+pub mod matrix;
+pub mod rng;
+pub mod simulate;
+pub mod strategy;
+
+pub use matrix::{ScoreMatrix, TACTIC_COUNT, TACTIC_NAMES};
+pub use rng::SplitMix64;
+pub use simulate::{expected_values, simulate, Report, TacticStats};
+pub use strategy::Strategy;
+
+#[cfg(test)]
+mod test;
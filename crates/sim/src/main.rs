@@ -0,0 +1,96 @@
+//! # sgs-sim
+//!
+//! ```text
+//! sgs-sim [--matches N] [--seed S] [--matrix CELLS] [--p1 STRATEGY] [--p2 STRATEGY]
+//! ```
+//!
+//! Strategies are preset names (`uniform`, `cautious`, `reckless`, or a
+//! tactic name) or four weights such as `1,2,3,4`. `--matrix` takes 16
+//! `p1-p2` cells in row-major order and defaults to the on-chain matrix.
+
+use std::env;
+use std::process::ExitCode;
+
+use stellar_game_sim::{expected_values, simulate, ScoreMatrix, Strategy, TACTIC_NAMES};
+
+struct Args {
+    matches: u64,
+    seed: u64,
+    matrix: ScoreMatrix,
+    player1: Strategy,
+    player2: Strategy,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        matches: 1_000_000,
+        seed: 0x5eed,
+        matrix: ScoreMatrix::default(),
+        player1: Strategy::uniform(),
+        player2: Strategy::uniform(),
+    };
+
+    let raw: Vec<String> = env::args().skip(1).collect();
+    let mut iter = raw.iter();
+    while let Some(flag) = iter.next() {
+        let mut value = || iter.next().ok_or(format!("{flag} requires a value"));
+        match flag.as_str() {
+            "--matches" => {
+                args.matches = value()?.parse().map_err(|_| "invalid --matches")?;
+            }
+            "--seed" => args.seed = value()?.parse().map_err(|_| "invalid --seed")?,
+            "--matrix" => args.matrix = ScoreMatrix::parse(value()?)?,
+            "--p1" => args.player1 = Strategy::parse(value()?)?,
+            "--p2" => args.player2 = Strategy::parse(value()?)?,
+            other => return Err(format!("unknown argument: {other}")),
+        }
+    }
+
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("❌ {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = simulate(
+        &args.matrix,
+        &args.player1,
+        &args.player2,
+        args.matches,
+        args.seed,
+    );
+    let exact = expected_values(&args.matrix, &args.player2);
+
+    println!("{}", args.matrix);
+    println!(
+        "{} matches: P1 wins {} | P2 wins {} | ties {} | P1 win rate with tiebreak {:.4}\n",
+        report.matches,
+        report.player1_wins,
+        report.player2_wins,
+        report.ties,
+        report.player1_win_rate_with_tiebreak()
+    );
+
+    println!(
+        "{:<12}{:>12}{:>12}{:>12}{:>12}",
+        "P1 tactic", "played", "EV points", "EV goals", "exact goals"
+    );
+    for (tactic, stats) in report.per_tactic.iter().enumerate() {
+        println!(
+            "{:<12}{:>12}{:>12.4}{:>12.4}{:>12.4}",
+            TACTIC_NAMES[tactic],
+            stats.played,
+            stats.expected_points(),
+            stats.expected_goal_diff(),
+            exact[tactic]
+        );
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+pub const TACTIC_COUNT: usize = 4;
+
+pub const TACTIC_NAMES: [&str; TACTIC_COUNT] = ["Defensive", "Balanced", "Aggressive", "AllOut"];
+
+/// `scores[t1][t2] = (player1 score, player2 score)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScoreMatrix {
+    pub scores: [[(u32, u32); TACTIC_COUNT]; TACTIC_COUNT],
+}
+
+impl Default for ScoreMatrix {
+    /// The matrix currently enforced by the contract
+    fn default() -> Self {
+        let mut scores = [[(0, 0); TACTIC_COUNT]; TACTIC_COUNT];
+        for (t1, row) in scores.iter_mut().enumerate() {
+            for (t2, cell) in row.iter_mut().enumerate() {
+                *cell = zk_tactical_match::get_score(t1 as u32, t2 as u32);
+            }
+        }
+        Self { scores }
+    }
+}
+
+impl ScoreMatrix {
+    pub fn score(&self, tactic1: usize, tactic2: usize) -> (u32, u32) {
+        self.scores[tactic1][tactic2]
+    }
+
+    /// Parse 16 comma-separated `p1-p2` cells in row-major order, e.g.
+    /// `"0-0,0-1,1-1,2-2,1-0,..."`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let cells: Vec<&str> = input.split(',').map(str::trim).collect();
+        if cells.len() != TACTIC_COUNT * TACTIC_COUNT {
+            return Err(format!(
+                "expected {} cells, got {}",
+                TACTIC_COUNT * TACTIC_COUNT,
+                cells.len()
+            ));
+        }
+
+        let mut scores = [[(0, 0); TACTIC_COUNT]; TACTIC_COUNT];
+        for (i, cell) in cells.iter().enumerate() {
+            let (a, b) = cell
+                .split_once('-')
+                .ok_or_else(|| format!("cell {i} is not `p1-p2`: {cell}"))?;
+            let a = a.parse().map_err(|_| format!("cell {i}: bad score {a}"))?;
+            let b = b.parse().map_err(|_| format!("cell {i}: bad score {b}"))?;
+            scores[i / TACTIC_COUNT][i % TACTIC_COUNT] = (a, b);
+        }
+
+        Ok(Self { scores })
+    }
+}
+
+impl fmt::Display for ScoreMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<12}", "P1 \\ P2")?;
+        for name in TACTIC_NAMES {
+            write!(f, "{name:>12}")?;
+        }
+        writeln!(f)?;
+        for (t1, row) in self.scores.iter().enumerate() {
+            write!(f, "{:<12}", TACTIC_NAMES[t1])?;
+            for (a, b) in row {
+                write!(f, "{:>12}", format!("{a}-{b}"))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::matrix::{ScoreMatrix, TACTIC_COUNT};
+use crate::rng::SplitMix64;
+use crate::strategy::Strategy;
+
+/// Player 1 results when playing one particular tactic
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TacticStats {
+    pub played: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub ties: u64,
+    /// Sum of `player1 score - player2 score`
+    pub goal_diff: i64,
+}
+
+impl TacticStats {
+    /// Mean goal difference per match
+    pub fn expected_goal_diff(&self) -> f64 {
+        if self.played == 0 {
+            return 0.0;
+        }
+        self.goal_diff as f64 / self.played as f64
+    }
+
+    /// Expected match points: win = 1, tie = 0.5, loss = 0
+    pub fn expected_points(&self) -> f64 {
+        if self.played == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 + 0.5 * self.ties as f64) / self.played as f64
+    }
+}
+
+/// Aggregated outcome of a simulation run, from player 1's perspective
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    pub matches: u64,
+    pub player1_wins: u64,
+    pub player2_wins: u64,
+    pub ties: u64,
+    pub per_tactic: [TacticStats; TACTIC_COUNT],
+}
+
+impl Report {
+    /// Player 1 win rate under the contract's current tie rule (ties go to
+    /// player 1)
+    pub fn player1_win_rate_with_tiebreak(&self) -> f64 {
+        if self.matches == 0 {
+            return 0.0;
+        }
+        (self.player1_wins + self.ties) as f64 / self.matches as f64
+    }
+}
+
+/// Play `matches` games of `player1` vs `player2` over `matrix`
+pub fn simulate(
+    matrix: &ScoreMatrix,
+    player1: &Strategy,
+    player2: &Strategy,
+    matches: u64,
+    seed: u64,
+) -> Report {
+    let mut rng = SplitMix64::new(seed);
+    let mut report = Report {
+        matches,
+        ..Report::default()
+    };
+
+    for _ in 0..matches {
+        let tactic1 = player1.sample(&mut rng);
+        let tactic2 = player2.sample(&mut rng);
+        let (score1, score2) = matrix.score(tactic1, tactic2);
+
+        let stats = &mut report.per_tactic[tactic1];
+        stats.played += 1;
+        stats.goal_diff += i64::from(score1) - i64::from(score2);
+
+        if score1 > score2 {
+            report.player1_wins += 1;
+            stats.wins += 1;
+        } else if score2 > score1 {
+            report.player2_wins += 1;
+            stats.losses += 1;
+        } else {
+            report.ties += 1;
+            stats.ties += 1;
+        }
+    }
+
+    report
+}
+
+/// Exact expected goal difference for each player 1 tactic against
+/// `opponent`
+pub fn expected_values(matrix: &ScoreMatrix, opponent: &Strategy) -> [f64; TACTIC_COUNT] {
+    let weights = opponent.weights();
+    let mut values = [0.0; TACTIC_COUNT];
+    for (tactic1, value) in values.iter_mut().enumerate() {
+        for (tactic2, weight) in weights.iter().enumerate() {
+            let (score1, score2) = matrix.score(tactic1, tactic2);
+            *value += weight * (f64::from(score1) - f64::from(score2));
+        }
+    }
+    values
+}
@@ -0,0 +1,86 @@
+use crate::matrix::{TACTIC_COUNT, TACTIC_NAMES};
+use crate::rng::SplitMix64;
+
+/// Probability distribution over tactics
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Strategy {
+    weights: [f64; TACTIC_COUNT],
+}
+
+impl Strategy {
+    /// Build from non-negative weights; they are normalized to sum to 1
+    pub fn new(weights: [f64; TACTIC_COUNT]) -> Result<Self, String> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(String::from("weights must be finite and non-negative"));
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err(String::from("weights must not all be zero"));
+        }
+        Ok(Self {
+            weights: weights.map(|w| w / total),
+        })
+    }
+
+    pub fn uniform() -> Self {
+        Self {
+            weights: [0.25; TACTIC_COUNT],
+        }
+    }
+
+    /// Always play `tactic`
+    pub fn pure(tactic: usize) -> Self {
+        let mut weights = [0.0; TACTIC_COUNT];
+        weights[tactic] = 1.0;
+        Self { weights }
+    }
+
+    /// Parse a preset name (`uniform`, `defensive`, `balanced`,
+    /// `aggressive`, `allout`, `cautious`, `reckless`) or four
+    /// comma-separated weights such as `1,2,3,4`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        match input {
+            "uniform" => return Ok(Self::uniform()),
+            "cautious" => return Self::new([0.4, 0.3, 0.2, 0.1]),
+            "reckless" => return Self::new([0.1, 0.2, 0.3, 0.4]),
+            _ => {}
+        }
+
+        if let Some(tactic) = TACTIC_NAMES
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(input))
+        {
+            return Ok(Self::pure(tactic));
+        }
+
+        let parts: Vec<&str> = input.split(',').map(str::trim).collect();
+        if parts.len() != TACTIC_COUNT {
+            return Err(format!("unknown strategy: {input}"));
+        }
+        let mut weights = [0.0; TACTIC_COUNT];
+        for (weight, part) in weights.iter_mut().zip(parts) {
+            *weight = part.parse().map_err(|_| format!("bad weight: {part}"))?;
+        }
+        Self::new(weights)
+    }
+
+    pub fn weights(&self) -> [f64; TACTIC_COUNT] {
+        self.weights
+    }
+
+    pub fn sample(&self, rng: &mut SplitMix64) -> usize {
+        let roll = rng.next_f64();
+        let mut cumulative = 0.0;
+        for (tactic, weight) in self.weights.iter().enumerate() {
+            cumulative += weight;
+            if roll < cumulative {
+                return tactic;
+            }
+        }
+        // Floating point slack: fall back to the last tactic with weight
+        self.weights
+            .iter()
+            .rposition(|w| *w > 0.0)
+            .unwrap_or(TACTIC_COUNT - 1)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{expected_values, simulate, ScoreMatrix, SplitMix64, Strategy};
+
+#[test]
+fn test_default_matrix_matches_contract() {
+    let matrix = ScoreMatrix::default();
+    assert_eq!(matrix.score(0, 0), (0, 0));
+    assert_eq!(matrix.score(1, 0), (1, 0));
+    assert_eq!(matrix.score(2, 1), (2, 1));
+    assert_eq!(matrix.score(3, 3), (4, 4));
+}
+
+#[test]
+fn test_parse_matrix_round_trip() {
+    let cells = "0-0,0-1,1-1,2-2,1-0,1-1,1-2,2-3,1-1,2-1,2-2,3-3,2-2,3-2,3-3,4-4";
+    assert_eq!(ScoreMatrix::parse(cells).unwrap(), ScoreMatrix::default());
+    assert!(ScoreMatrix::parse("0-0,1-1").is_err());
+    assert!(ScoreMatrix::parse(&cells.replace("4-4", "4:4")).is_err());
+}
+
+#[test]
+fn test_parse_strategies() {
+    assert_eq!(Strategy::parse("uniform").unwrap(), Strategy::uniform());
+    assert_eq!(Strategy::parse("allout").unwrap(), Strategy::pure(3));
+    assert_eq!(
+        Strategy::parse("1,1,1,1").unwrap().weights(),
+        [0.25, 0.25, 0.25, 0.25]
+    );
+    assert!(Strategy::parse("0,0,0,0").is_err());
+    assert!(Strategy::parse("-1,1,1,1").is_err());
+    assert!(Strategy::parse("nonsense").is_err());
+}
+
+#[test]
+fn test_simulation_is_deterministic() {
+    let matrix = ScoreMatrix::default();
+    let a = simulate(
+        &matrix,
+        &Strategy::uniform(),
+        &Strategy::uniform(),
+        10_000,
+        7,
+    );
+    let b = simulate(
+        &matrix,
+        &Strategy::uniform(),
+        &Strategy::uniform(),
+        10_000,
+        7,
+    );
+    assert_eq!(a, b);
+    assert_eq!(a.player1_wins + a.player2_wins + a.ties, 10_000);
+
+    let mut rng = SplitMix64::new(1);
+    let first = rng.next_u64();
+    assert_eq!(SplitMix64::new(1).next_u64(), first);
+}
+
+#[test]
+fn test_pure_strategies_follow_matrix() {
+    let matrix = ScoreMatrix::default();
+    // Balanced always beats Defensive
+    let report = simulate(&matrix, &Strategy::pure(1), &Strategy::pure(0), 1_000, 1);
+    assert_eq!(report.player1_wins, 1_000);
+    assert_eq!(report.per_tactic[1].goal_diff, 1_000);
+}
+
+#[test]
+fn test_monte_carlo_converges_to_exact_values() {
+    let matrix = ScoreMatrix::default();
+    let opponent = Strategy::parse("reckless").unwrap();
+    let report = simulate(&matrix, &Strategy::uniform(), &opponent, 400_000, 42);
+    let exact = expected_values(&matrix, &opponent);
+
+    for (stats, expected) in report.per_tactic.iter().zip(exact) {
+        assert!((stats.expected_goal_diff() - expected).abs() < 0.02);
+    }
+}
@@ -0,0 +1,58 @@
+//! BLS12-381 signing fixtures for aggregated signatures.
+//!
+//! Follows the standard ciphersuite with keys in G1 and signatures in G2,
+//! both uncompressed, so fixtures verify against any contract that checks
+//! `e(-g1, sum(sig_i)) * prod e(pk_i, hash_to_g2(m_i)) == 1`. Secrets are
+//! derived from a one-byte seed and are for tests only.
+
+use soroban_sdk::{
+    crypto::bls12_381::{Fr, G1Affine, G2Affine},
+    Bytes, BytesN, Env,
+};
+
+/// Domain separation tag messages are hashed to G2 with
+pub const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// The G1 generator, uncompressed
+const G1_GENERATOR: [u8; 96] = [
+    0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c, 0x4f, 0xa9, 0xac, 0x0f,
+    0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05, 0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58,
+    0x6c, 0x55, 0xe8, 0x3f, 0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+    0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed, 0x74, 0x1d, 0x8a, 0xe4,
+    0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6, 0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed,
+    0xd0, 0x3c, 0xc7, 0x44, 0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
+];
+
+/// Secret key of `seed`: the scalar `seed`
+fn secret(env: &Env, seed: u8) -> Fr {
+    let mut bytes = [0u8; 32];
+    bytes[31] = seed;
+    Fr::from_bytes(BytesN::from_array(env, &bytes))
+}
+
+/// Public key (G1) of the test secret `seed`
+pub fn bls_key(env: &Env, seed: u8) -> BytesN<96> {
+    let generator = G1Affine::from_array(env, &G1_GENERATOR);
+    env.crypto()
+        .bls12_381()
+        .g1_mul(&generator, &secret(env, seed))
+        .to_bytes()
+}
+
+/// Signature (G2) of `message` by the test secret `seed`
+pub fn bls_sign(env: &Env, seed: u8, message: &Bytes) -> BytesN<192> {
+    let bls = env.crypto().bls12_381();
+    let point = bls.hash_to_g2(message, &Bytes::from_slice(env, BLS_DST));
+    bls.g2_mul(&point, &secret(env, seed)).to_bytes()
+}
+
+/// Aggregate of `signatures`: their sum in G2
+pub fn bls_aggregate(env: &Env, signatures: &[BytesN<192>]) -> BytesN<192> {
+    let bls = env.crypto().bls12_381();
+    let (first, rest) = signatures.split_first().expect("no signatures");
+    rest.iter()
+        .fold(G2Affine::from_bytes(first.clone()), |sum, signature| {
+            bls.g2_add(&sum, &G2Affine::from_bytes(signature.clone()))
+        })
+        .to_bytes()
+}
@@ -0,0 +1,165 @@
+//! Groth16 (BN254) verification fixture.
+//!
+//! Generated by `circuit/scripts/groth16_fixture.py`: every point is a known
+//! multiple of the curve generators, so the proofs satisfy the Groth16
+//! pairing equation for this key without a real circuit. Public inputs are
+//! `[session_id, player_binding, valid_until_ledger, commitment, nullifier,
+//! tactic_hash]`, matching the contract's verifier interface; the commitments
+//! and nullifiers are arbitrary field elements standing in for the circuit's
+//! Poseidon2 outputs, while each tactic hash opens to the vector's tactic and
+//! secret.
+//!
+//! The player bindings are computed for [`GROTH16_CONTRACT`] and each
+//! vector's player, so the proofs only verify for a contract registered at
+//! that address.
+//!
+//! Test-only: the "toxic waste" is in the script, so anyone can forge proofs
+//! against [`groth16_vk`].
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+/// Verification key: `alpha (G1) || beta, gamma, delta (G2) || IC[0..7] (G1)`
+const VK_HEX: [&str; 14] = [
+    "2a14705537b009189da8808651eecdb82482477fe92ac12ca8b71f80fc3d49ef2df7ee7f243ea8b38e1ddf14029258877a618c779fd4717db6177e19ea67ec38",
+    "009edaf0698a8c56f51139588acc094cee3c37d427bb6d2eab830aae529097d123ad66f3a7cca9dc75049635faebd124316244b91de5fb2764cd151572a905f7",
+    "2700e8a29b7bb45f3022a18a07bdc66d0254559e17cce64e3b4ad21578fcf4101ad4f87d3b4375a39988ac099b042b1e7c0c715678e4c2bea8905f607cf950f8",
+    "227071bba5ff3b47ed8b504bb5b215bc701d7a3259b933bff1a4164eae499c2c0c51a367b61d3119677b29739ddccbb78002b5558d8f49ff16e299c1b41f8098",
+    "08bb188b2a6187bb1e87834c85a6a917763d65b98febf2c45ea339dd77fac41518fd2fd13be8494c39e8a91325d1ef3ba7d1a205d10788e38bc9e09d9be87769",
+    "25407be35f18c6594174374841311466c0e66ff003762448c06bca4fa5e9c54e15cbba9ab73bc73d0ba4ad132a15cb0c73107a9c19b040c4c73d89f6bf75404d",
+    "1edef86c1a42fa85ab6ae8d268a7e9b46890b2130dd83b91c86c504cf1f93fbf2c750c045112e4ab07f18b12475309cebdcb726bda1ca9948bacd498a28cf411",
+    "1e28260f0ee971dec1e84cf81ff2776ad314d2cfb9ef81d4c970620c29b811f128fc8a72d4ff12654c3c39dab54eaef9638d28de738959779fcd3e7ac918b396",
+    "1605ffc1ea2e1aef15d774d3207176420c5cc454b19b55558562b0c7ddf00a7d0cf605873faa8028df38ec2d0800d5ddc67f1776338d675491fe87f6bb7354b3",
+    "14b4fa251277a6f4cbbfe379a152a976641f58a4a2bffd3b677ea093bdad853c28ce094a6d16280abcf8d84efa062c85511819dd87d8da255885ce0580ebee36",
+    "24f253a56d4badbe5f105ae102f14cf23ecb3a3892640ed1edb49c9d9e45d0631392ab50e020ade3c6069f16bf09d1ac4ebe686a3063ce392a0ea2b7ec03f6b1",
+    "235658752a7ef475c544c746269813ac4192b73534cc667df0cfa5b4a76589b30106c4ad7d200e59f40aa8d0ae719339319fd3dd3bad23e3d396b46fdc166d18",
+    "234747f9e4dc9fce767bceda070fe9806ce76ebf5f4c01642e77ec9477f7fbfa0073fc5dc2c193dccf5ad9592a351981cfaa6a3ad9cea2027fb2c21d6f361eeb",
+    "2773a72ef8bbc77d34767e20341f9bd662f934073d090558413db21ed1ce73d91ad951adde13e289d45db95283a606cd80c9a0fb2c712196b5463f6c8094a6be",
+];
+
+/// A valid proof (`A (G1) || B (G2) || C (G1)`) for
+/// `(session_id, player_binding, valid_until_ledger, commitment, nullifier,
+/// tactic_hash)`, where `tactic_hash` opens to `(tactic, secret)`
+#[derive(Clone, Copy, Debug)]
+pub struct Groth16ProofVector {
+    pub session_id: u32,
+    pub valid_until_ledger: u32,
+    pub tactic: u32,
+    player: &'static str,
+    player_binding: &'static str,
+    commitment: &'static str,
+    nullifier: &'static str,
+    secret: &'static str,
+    tactic_hash: &'static str,
+    proof: [&'static str; 4],
+}
+
+impl Groth16ProofVector {
+    /// The account the proof was made for
+    pub fn player(&self, env: &Env) -> Address {
+        Address::from_str(env, self.player)
+    }
+
+    pub fn player_binding(&self, env: &Env) -> BytesN<32> {
+        hex_field(env, self.player_binding)
+    }
+
+    pub fn commitment(&self, env: &Env) -> BytesN<32> {
+        hex_field(env, self.commitment)
+    }
+
+    pub fn nullifier(&self, env: &Env) -> BytesN<32> {
+        hex_field(env, self.nullifier)
+    }
+
+    pub fn secret(&self, env: &Env) -> BytesN<32> {
+        hex_field(env, self.secret)
+    }
+
+    pub fn tactic_hash(&self, env: &Env) -> BytesN<32> {
+        hex_field(env, self.tactic_hash)
+    }
+
+    pub fn proof(&self, env: &Env) -> Bytes {
+        hex_bytes(env, &self.proof)
+    }
+}
+
+/// Contract address the fixture proofs are bound to
+pub const GROTH16_CONTRACT: &str = "CAIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRCEIRDB3V";
+
+pub const GROTH16_PROOFS: [Groth16ProofVector; 2] = [
+    Groth16ProofVector {
+        session_id: 7,
+        valid_until_ledger: 1_000,
+        tactic: 2,
+        player: "GCQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DINBUGQ2DJX7",
+        player_binding: "008e2a12c32e868b1fbeef3fc61f892f4da452b83303b4bdbdb1d2803df87093",
+        commitment: "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+        nullifier: "1111111111111111111111111111111111111111111111111111111111111111",
+        secret: "00a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2",
+        tactic_hash: "00ef7a94a56ecab0c193f1f587b9ddbf5b32e5430d2c13e8458f6397e4c9f130",
+        proof: [
+            "003994af9546cdff40006d2c4f32dbb004d348f9a97dfeb88d6cf1671c2e3d932e0191fc912a1eb50c10abd503e81b1a53bfb2605c6689117d9a561bf85fed3c",
+            "1e93c5d5e3826db6f134be26370670773e655682af85257716e506c08afa9f4d026f2a51664abd5df20806b106ae16658935d0809aa0e0ef1dd70bc2c58b5c5c",
+            "23a8a7e489423bcd1b057d39505d314ab3519d6bfcf56496c5d40a74236b36f900edec27fdfdfb0691bd86dfae9a6d2421109ae91e433761f258beb6fac2bac5",
+            "036d5274ef407d6272ef9f822464d334c6a3d7272d309d0c011cf8a9f8e9578a0a83b470b33ed6729aedda5b824dbbf86a56c777eaed67c575fb9617835f158f",
+        ],
+    },
+    Groth16ProofVector {
+        session_id: 7,
+        valid_until_ledger: 1_000,
+        tactic: 1,
+        player: "GCZLFMVSWKZLFMVSWKZLFMVSWKZLFMVSWKZLFMVSWKZLFMVSWKZLFF6T",
+        player_binding: "003e0bb5959b1d6e9ded12fd77518c94f9b06db9095166a2528faa2c81aade96",
+        commitment: "0fedcba9876543210fedcba9876543210fedcba9876543210fedcba987654321",
+        nullifier: "2222222222222222222222222222222222222222222222222222222222222222",
+        secret: "00b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1",
+        tactic_hash: "00d15ec46a404a4591d88c8c1164221ae37b449c7e9ee7b675e7fdba03d2e9d8",
+        proof: [
+            "30201b31ecdc2e09f012293c4c7d5e72a58b53a17162c2d1448f39beeb6c6e2b2219b38b2fc75a43fd4ea18e1ae4718511d5689589173b51cfdb732fb6a07700",
+            "0b0ec4ecf3451ebf9795f27e75ed71c3d0fc719caec858b2470f42753a86945e1ac1a743eec625ca449f794850f495a5c8e16ce8020d43dd642746bfed24d9d1",
+            "12cd88c1188bc7db945b628dfe103c7ca9e02f3f7d9a1ea07d112336771b5df82d8e91e4bb772a550dddb739ebca17e4c27ba5be55a3d18faeddab6645ccfe09",
+            "27c6177951012eacd5af9d71d0199554bc25ba17319ecc4e762ac5a733d852b50632a0c87ccccf2afe2d48890c319afa78386182520b33ba17c0241a0d2bfa5c",
+        ],
+    },
+];
+
+/// Serialized fixture verification key (896 bytes)
+pub fn groth16_vk(env: &Env) -> Bytes {
+    hex_bytes(env, &VK_HEX)
+}
+
+/// [`GROTH16_CONTRACT`] as an address
+pub fn groth16_contract(env: &Env) -> Address {
+    Address::from_str(env, GROTH16_CONTRACT)
+}
+
+fn hex_field(env: &Env, hex: &str) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    hex_bytes(env, &[hex]).copy_into_slice(&mut bytes);
+    BytesN::from_array(env, &bytes)
+}
+
+fn hex_bytes(env: &Env, parts: &[&str]) -> Bytes {
+    let mut out = Bytes::new(env);
+    for part in parts {
+        for i in (0..part.len()).step_by(2) {
+            out.push_back(u8::from_str_radix(&part[i..i + 2], 16).expect("invalid hex"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fixture_sizes() {
+        let env = Env::default();
+        assert_eq!(groth16_vk(&env).len(), 64 + 3 * 128 + 7 * 64);
+        for vector in GROTH16_PROOFS {
+            assert_eq!(vector.proof(&env).len(), 64 + 128 + 64);
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use soroban_sdk::{
+    contract, contractimpl, contracttype, vec, Address, BytesN, Env, IntoVal, Symbol, Val,
+};
+
+// ============================================================================
+// Mock GameHub
+// ============================================================================
+
+/// No-op Game Hub exposing the interface every game contract consumes.
+///
+/// Use this for unit tests that only care about game logic. It publishes no
+/// ratings until a root is set with `set_ratings_root`, and remembers the
+/// outcome of each session ended with `end_game` or `end_game_with_outcome`,
+/// and the stakes of each lowered with `reduce_stakes`. It names no treasury
+/// (`treasury` traps) until one is set with `set_treasury`. It hands out
+/// session IDs from 1 but opens sessions under any ID.
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        // Mock implementation - does nothing
+    }
+
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        let outcome = if player1_won {
+            HubOutcome::Player1Win
+        } else {
+            HubOutcome::Player2Win
+        };
+        Self::end_game_with_outcome(env, session_id, outcome);
+    }
+
+    pub fn end_game_with_outcome(env: Env, session_id: u32, outcome: HubOutcome) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Outcome(session_id), &outcome);
+    }
+
+    /// Outcome reported for `session_id`, if it has ended
+    pub fn outcome(env: Env, session_id: u32) -> Option<HubOutcome> {
+        env.storage().instance().get(&DataKey::Outcome(session_id))
+    }
+
+    /// Lower a session's stakes, remembering them for `stakes`
+    pub fn reduce_stakes(env: Env, session_id: u32, player1_points: i128, player2_points: i128) {
+        env.storage().instance().set(
+            &DataKey::Stakes(session_id),
+            &(player1_points, player2_points),
+        );
+    }
+
+    /// Stakes `session_id` was lowered to, if any
+    pub fn stakes(env: Env, session_id: u32) -> Option<(i128, i128)> {
+        env.storage().instance().get(&DataKey::Stakes(session_id))
+    }
+
+    /// Hand out the next session ID, counting up from 1
+    pub fn allocate_session(env: Env, _game_id: Address) -> u32 {
+        let session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSession)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSession, &(session_id + 1));
+        session_id
+    }
+
+    /// Hub interface version: 4, with draws (`end_game_with_outcome`),
+    /// `reduce_stakes` and `allocate_session`
+    pub fn version(_env: Env) -> u32 {
+        4
+    }
+
+    pub fn add_game(_env: Env, _game_address: Address) {
+        // Mock implementation - does nothing
+    }
+
+    /// Publish `root` as the ratings tree root
+    pub fn set_ratings_root(env: Env, root: BytesN<32>) {
+        env.storage().instance().set(&DataKey::RatingsRoot, &root);
+    }
+
+    pub fn ratings_root(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::RatingsRoot)
+    }
+
+    /// Name `treasury` as where the hub collects its revenue share
+    pub fn set_treasury(env: Env, treasury: Address) {
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    pub fn treasury(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .expect("no treasury set")
+    }
+}
+
+/// Three-way result of a session, as recorded by [`MockGameHub`]
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HubOutcome {
+    Player1Win = 0,
+    Player2Win = 1,
+    Draw = 2,
+}
+
+// ============================================================================
+// Legacy GameHub
+// ============================================================================
+
+/// Game Hub with only the original two-way interface (`start_game` and
+/// `end_game`), as deployed before draws existed.
+///
+/// Remembers who won each ended session; a game that calls
+/// `end_game_with_outcome` on it fails.
+#[contract]
+pub struct LegacyGameHub;
+
+#[contractimpl]
+impl LegacyGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+    }
+
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Player1Won(session_id), &player1_won);
+    }
+
+    /// Hub interface version: 1, the original two-way interface
+    pub fn version(_env: Env) -> u32 {
+        1
+    }
+
+    /// `player1_won` reported for `session_id`, if it has ended
+    pub fn player1_won(env: Env, session_id: u32) -> Option<bool> {
+        env.storage().instance().get(&DataKey::Player1Won(session_id))
+    }
+}
+
+// ============================================================================
+// Failing GameHub
+// ============================================================================
+
+/// Which hub entrypoint a [`FailingGameHub`] should trap on
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HubFailure {
+    StartGame = 0,
+    EndGame = 1,
+    Both = 2,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Failure,
+    RatingsRoot,
+    Outcome(u32),
+    Player1Won(u32),
+    Stakes(u32),
+    NextSession,
+    Treasury,
+}
+
+/// Game Hub that traps on the configured entrypoint(s).
+///
+/// Register with the failure mode as constructor argument:
+///
+/// ```ignore
+/// let hub = env.register(FailingGameHub, (HubFailure::EndGame,));
+/// ```
+#[contract]
+pub struct FailingGameHub;
+
+#[contractimpl]
+impl FailingGameHub {
+    pub fn __constructor(env: Env, failure: HubFailure) {
+        env.storage().instance().set(&DataKey::Failure, &failure);
+    }
+
+    pub fn start_game(
+        env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        if matches!(
+            Self::failure(&env),
+            HubFailure::StartGame | HubFailure::Both
+        ) {
+            panic!("GameHub unavailable: start_game");
+        }
+    }
+
+    pub fn end_game(env: Env, _session_id: u32, _player1_won: bool) {
+        if matches!(Self::failure(&env), HubFailure::EndGame | HubFailure::Both) {
+            panic!("GameHub unavailable: end_game");
+        }
+    }
+
+    pub fn end_game_with_outcome(env: Env, _session_id: u32, _outcome: HubOutcome) {
+        if matches!(Self::failure(&env), HubFailure::EndGame | HubFailure::Both) {
+            panic!("GameHub unavailable: end_game_with_outcome");
+        }
+    }
+
+    /// Answers even while failing, like a hub whose settlement is broken
+    pub fn version(_env: Env) -> u32 {
+        2
+    }
+
+    /// Change the failure mode after registration
+    pub fn set_failure(env: Env, failure: HubFailure) {
+        env.storage().instance().set(&DataKey::Failure, &failure);
+    }
+}
+
+impl FailingGameHub {
+    fn failure(env: &Env) -> HubFailure {
+        env.storage()
+            .instance()
+            .get(&DataKey::Failure)
+            .unwrap_or(HubFailure::Both)
+    }
+}
+
+// ============================================================================
+// Malicious GameHub
+// ============================================================================
+
+/// What a [`MaliciousGameHub`] does when the game calls it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HubAttack {
+    /// Behave like a normal hub (only count calls)
+    Honest,
+    /// From `start_game`, call `game.<fn>(session_id)`
+    ReenterOnStart(Symbol),
+    /// From `end_game`, call `game.<fn>(session_id)`
+    ReenterOnEnd(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum MaliciousKey {
+    Game,
+    Attack,
+    EndGameCalls,
+}
+
+/// Hostile Game Hub that calls back into the game contract.
+///
+/// The game is whatever contract passed itself as `game_id` to
+/// `start_game`. Counts `end_game` and `end_game_with_outcome` calls so tests
+/// can assert settlement happened exactly once.
+///
+/// ```ignore
+/// let attack = HubAttack::ReenterOnEnd(Symbol::new(&env, "resolve_match"));
+/// let hub = env.register(MaliciousGameHub, (attack,));
+/// ```
+#[contract]
+pub struct MaliciousGameHub;
+
+#[contractimpl]
+impl MaliciousGameHub {
+    pub fn __constructor(env: Env, attack: HubAttack) {
+        env.storage().instance().set(&MaliciousKey::Attack, &attack);
+    }
+
+    pub fn set_attack(env: Env, attack: HubAttack) {
+        env.storage().instance().set(&MaliciousKey::Attack, &attack);
+    }
+
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        env.storage().instance().set(&MaliciousKey::Game, &game_id);
+
+        if let HubAttack::ReenterOnStart(fn_name) = Self::attack(&env) {
+            Self::call_game(&env, &fn_name, session_id);
+        }
+    }
+
+    pub fn end_game(env: Env, session_id: u32, _player1_won: bool) {
+        Self::settle(&env, session_id);
+    }
+
+    pub fn end_game_with_outcome(env: Env, session_id: u32, _outcome: HubOutcome) {
+        Self::settle(&env, session_id);
+    }
+
+    pub fn version(_env: Env) -> u32 {
+        2
+    }
+
+    /// Number of completed `end_game` and `end_game_with_outcome` calls
+    pub fn end_game_calls(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MaliciousKey::EndGameCalls)
+            .unwrap_or(0)
+    }
+}
+
+impl MaliciousGameHub {
+    fn settle(env: &Env, session_id: u32) {
+        let calls = Self::end_game_calls(env.clone());
+        env.storage()
+            .instance()
+            .set(&MaliciousKey::EndGameCalls, &(calls + 1));
+
+        if let HubAttack::ReenterOnEnd(fn_name) = Self::attack(env) {
+            Self::call_game(env, &fn_name, session_id);
+        }
+    }
+
+    fn attack(env: &Env) -> HubAttack {
+        env.storage()
+            .instance()
+            .get(&MaliciousKey::Attack)
+            .unwrap_or(HubAttack::Honest)
+    }
+
+    fn call_game(env: &Env, fn_name: &Symbol, session_id: u32) {
+        let game: Address = env
+            .storage()
+            .instance()
+            .get(&MaliciousKey::Game)
+            .expect("start_game not called yet");
+        env.invoke_contract::<Val>(&game, fn_name, vec![env, session_id.into_val(env)]);
+    }
+}
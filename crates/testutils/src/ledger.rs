@@ -0,0 +1,89 @@
+use soroban_sdk::testutils::{Ledger as _, LedgerInfo};
+use soroban_sdk::Env;
+
+/// Builder for the ledger state used by game contract tests.
+///
+/// Defaults match the ledger every game test in this repo has used so far:
+/// protocol 25, sequence 100 and TTL limits large enough that nothing expires.
+///
+/// ```ignore
+/// TestLedger::default()
+///     .with_temp_ttl(16)
+///     .with_max_ttl(600_000)
+///     .apply(&env);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TestLedger {
+    pub timestamp: u64,
+    pub protocol_version: u32,
+    pub sequence_number: u32,
+    pub min_temp_entry_ttl: u32,
+    pub min_persistent_entry_ttl: u32,
+    pub max_entry_ttl: u32,
+}
+
+impl Default for TestLedger {
+    fn default() -> Self {
+        Self {
+            timestamp: 1441065600,
+            protocol_version: 25,
+            sequence_number: 100,
+            min_temp_entry_ttl: u32::MAX / 2,
+            min_persistent_entry_ttl: u32::MAX / 2,
+            max_entry_ttl: u32::MAX / 2,
+        }
+    }
+}
+
+impl TestLedger {
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn with_sequence(mut self, sequence_number: u32) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    pub fn with_protocol_version(mut self, protocol_version: u32) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    /// Minimum TTL given to new temporary entries (game state)
+    pub fn with_temp_ttl(mut self, ledgers: u32) -> Self {
+        self.min_temp_entry_ttl = ledgers;
+        self
+    }
+
+    /// Minimum TTL given to new persistent entries
+    pub fn with_persistent_ttl(mut self, ledgers: u32) -> Self {
+        self.min_persistent_entry_ttl = ledgers;
+        self
+    }
+
+    /// Upper bound for any `extend_ttl` call
+    pub fn with_max_ttl(mut self, ledgers: u32) -> Self {
+        self.max_entry_ttl = ledgers;
+        self
+    }
+
+    pub fn apply(&self, env: &Env) {
+        env.ledger().set(LedgerInfo {
+            timestamp: self.timestamp,
+            protocol_version: self.protocol_version,
+            sequence_number: self.sequence_number,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: self.min_temp_entry_ttl,
+            min_persistent_entry_ttl: self.min_persistent_entry_ttl,
+            max_entry_ttl: self.max_entry_ttl,
+        });
+    }
+}
+
+/// Apply the default test ledger to `env`
+pub fn setup_ledger(env: &Env) {
+    TestLedger::default().apply(env);
+}
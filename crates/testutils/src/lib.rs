@@ -0,0 +1,49 @@
+//! # Stellar Game Testutils
+//!
+//! Shared test fixtures for Stellar Game Studio contracts:
+//!
+//! - [`bls`] - BLS12-381 keys and (aggregated) signatures
+//! - [`groth16`] - Self-consistent Groth16 (BN254) key and proofs
+//! - [`hub`] - Mock Game Hub contracts (no-op, legacy, failing and malicious variants)
+//! - [`ledger`] - Ledger setup builder with configurable TTL policy
+//! - [`oracle`] - Mock SEP-40 price oracle for mixed-asset wagers
+//! - [`proofs`] - Proof and commitment fixtures for ZK-enabled games
+//! - [`token`] - Stellar Asset Contract fixtures (issued and native XLM) for
+//!   escrowed wagers
+//! - [`vectors`] - Deterministic commitment/proof-hash test vectors
+//! - [`verifier`] - Mock ZK verifier contract with per-proof verdicts
+//!
+//! Add as a dev-dependency of a game contract:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! stellar-game-testutils = { workspace = true }
+//! ```
+
+pub mod bls;
+pub mod groth16;
+pub mod hub;
+pub mod ledger;
+pub mod oracle;
+pub mod proofs;
+pub mod token;
+pub mod vectors;
+pub mod verifier;
+
+pub use bls::{bls_aggregate, bls_key, bls_sign, BLS_DST};
+pub use groth16::{
+    groth16_contract, groth16_vk, Groth16ProofVector, GROTH16_CONTRACT, GROTH16_PROOFS,
+};
+pub use hub::{
+    FailingGameHub, FailingGameHubClient, HubAttack, HubFailure, HubOutcome, LegacyGameHub,
+    LegacyGameHubClient, MaliciousGameHub, MaliciousGameHubClient, MockGameHub,
+    MockGameHubClient,
+};
+pub use ledger::{setup_ledger, TestLedger};
+pub use oracle::{MockPriceOracle, MockPriceOracleClient};
+pub use proofs::{
+    empty_proof, mock_commitment, mock_nullifier, mock_player_proof, mock_proof, mock_secret,
+    short_proof, zero_proof,
+};
+pub use token::{balance, freeze, fund, fund_native, native_token, wager_token};
+pub use verifier::{MockVerifier, MockVerifierClient};
@@ -0,0 +1,52 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+
+/// Asset as SEP-40 price feeds (e.g. Reflector) name it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// A SEP-40 price quote
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Price(Asset),
+}
+
+/// Stand-in for a SEP-40 price oracle such as Reflector. Assets have no
+/// price until one is set:
+///
+/// ```ignore
+/// let oracle = env.register(MockPriceOracle, ());
+/// MockPriceOracleClient::new(&env, &oracle).set_price(&token, &2_0000000);
+/// ```
+#[contract]
+pub struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    /// Quote `asset` at `price`, timestamped with the current ledger
+    pub fn set_price(env: Env, asset: Address, price: i128) {
+        let quote = PriceData {
+            price,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Price(Asset::Stellar(asset)), &quote);
+    }
+
+    /// SEP-40 interface consumed by game contracts
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        env.storage().instance().get(&DataKey::Price(asset))
+    }
+}
@@ -0,0 +1,91 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+/// Length of the fixtures produced by [`mock_proof`]
+pub const MOCK_PROOF_LEN: u32 = 64;
+
+/// Well-formed proof bytes for `(tactic, session_id)`.
+///
+/// Encodes both values big-endian and pads to [`MOCK_PROOF_LEN`] with a
+/// non-zero filler, so it passes format checks (non-empty, minimum length,
+/// not all zeros). Different inputs always give different bytes.
+pub fn mock_proof(env: &Env, tactic: u32, session_id: u32) -> Bytes {
+    let mut data = Bytes::new(env);
+    data.append(&Bytes::from_array(env, &tactic.to_be_bytes()));
+    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    while data.len() < MOCK_PROOF_LEN {
+        data.push_back(0xab);
+    }
+    data
+}
+
+/// [`mock_proof`] bound to `player`.
+///
+/// Keeps the `(tactic, session_id)` prefix but replaces the last 32 bytes
+/// with sha256 of the player's address, so two players submitting the same
+/// tactic in one session send different proofs, as they would with a real
+/// circuit that binds the player.
+pub fn mock_player_proof(env: &Env, player: &Address, tactic: u32, session_id: u32) -> Bytes {
+    let mut data = mock_proof(env, tactic, session_id).slice(0..MOCK_PROOF_LEN - 32);
+    let binding = env.crypto().sha256(&player.clone().to_xdr(env)).to_array();
+    data.extend_from_array(&binding);
+    data
+}
+
+/// Commitment fixture paired with [`mock_proof`].
+///
+/// A valid BN254 field element (top byte zero) encoding `tactic` and
+/// `session_id` big-endian, padded with 0xcd. Stands in for the circuit's
+/// Poseidon2 output wherever no real verifier is configured.
+pub fn mock_commitment(env: &Env, tactic: u32, session_id: u32) -> BytesN<32> {
+    let mut bytes = [0xcd; 32];
+    bytes[0] = 0;
+    bytes[1..5].copy_from_slice(&tactic.to_be_bytes());
+    bytes[5..9].copy_from_slice(&session_id.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
+
+/// Nullifier fixture paired with [`mock_proof`].
+///
+/// Stands in for the circuit's `Poseidon2(player_secret, session_id)`:
+/// keccak256 of the player's address and `session_id` with the top byte
+/// cleared, so it is a valid BN254 field element and distinct per
+/// `(player, session_id)`.
+pub fn mock_nullifier(env: &Env, player: &Address, session_id: u32) -> BytesN<32> {
+    let mut data = player.clone().to_xdr(env);
+    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    let mut bytes = env.crypto().keccak256(&data).to_array();
+    bytes[0] = 0;
+    BytesN::from_array(env, &bytes)
+}
+
+/// Player secret fixture paired with [`mock_proof`].
+///
+/// sha256 of the player's address and `session_id` with the top byte
+/// cleared, so like a real circuit secret it is a BN254 field element, and it
+/// is distinct per `(player, session_id)`. Opens the tactic hash at reveal.
+pub fn mock_secret(env: &Env, player: &Address, session_id: u32) -> BytesN<32> {
+    let mut data = player.clone().to_xdr(env);
+    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    let mut bytes = env.crypto().sha256(&data).to_array();
+    bytes[0] = 0;
+    BytesN::from_array(env, &bytes)
+}
+
+/// Zero-length proof
+pub fn empty_proof(env: &Env) -> Bytes {
+    Bytes::new(env)
+}
+
+/// Non-zero proof that is below any plausible minimum proof length
+pub fn short_proof(env: &Env) -> Bytes {
+    Bytes::from_array(env, &[0xab; 8])
+}
+
+/// `len` zero bytes
+pub fn zero_proof(env: &Env, len: u32) -> Bytes {
+    let mut data = Bytes::new(env);
+    for _ in 0..len {
+        data.push_back(0);
+    }
+    data
+}
@@ -0,0 +1,89 @@
+use soroban_sdk::testutils::{Address as _, IssuerFlags};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{
+    AccountEntry, AccountEntryExt, AccountId, LedgerEntry, LedgerEntryData, LedgerEntryExt,
+    LedgerKey, LedgerKeyAccount, PublicKey, ScAddress, SequenceNumber, Thresholds, Uint256,
+};
+use soroban_sdk::{Address, Bytes, Env, TryFromVal};
+use std::rc::Rc;
+
+/// XDR of `Asset::Native`
+const NATIVE_ASSET: [u8; 4] = [0; 4];
+
+/// Account the native token is funded from, as XLM cannot be minted
+const NATIVE_TREASURY: [u8; 32] = [0x58; 32];
+
+/// Register a Stellar Asset Contract with a fresh issuer, for games that
+/// escrow their wagers. The issuer can revoke holders (see [`freeze`])
+pub fn wager_token(env: &Env) -> Address {
+    let issuer = Address::generate(env);
+    let asset = env.register_stellar_asset_contract_v2(issuer);
+    asset.issuer().set_flag(IssuerFlags::RevocableFlag);
+    asset.address()
+}
+
+/// Mint `amount` of `token` to each holder, whatever auths the test mocks
+pub fn fund(env: &Env, token: &Address, holders: &[&Address], amount: i128) {
+    let client = StellarAssetClient::new(env, token);
+    for holder in holders {
+        client.mock_all_auths().mint(holder, &amount);
+    }
+}
+
+/// `holder`'s balance of `token`
+pub fn balance(env: &Env, token: &Address, holder: &Address) -> i128 {
+    TokenClient::new(env, token).balance(holder)
+}
+
+/// Deauthorize (`frozen`) or reauthorize `holder`'s balance of `token`; a
+/// frozen holder can neither send nor receive it
+pub fn freeze(env: &Env, token: &Address, holder: &Address, frozen: bool) {
+    StellarAssetClient::new(env, token)
+        .mock_all_auths()
+        .set_authorized(holder, &!frozen);
+}
+
+/// Deploy (once) and return the native XLM Stellar Asset Contract, at the
+/// address `native_token` in the contract resolves for the test network
+pub fn native_token(env: &Env) -> Address {
+    let deployer = env.deployer().with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET));
+    let address = deployer.deployed_address();
+    if TokenClient::new(env, &address).try_decimals().is_err() {
+        deployer.deploy();
+    }
+    address
+}
+
+/// Send `amount` stroops of native XLM to each holder, from a treasury
+/// account created on first use
+pub fn fund_native(env: &Env, holders: &[&Address], amount: i128) {
+    let account_id = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(NATIVE_TREASURY)));
+    let key = Rc::new(LedgerKey::Account(LedgerKeyAccount {
+        account_id: account_id.clone(),
+    }));
+    if env.host().get_ledger_entry(&key).unwrap().is_none() {
+        let entry = Rc::new(LedgerEntry {
+            data: LedgerEntryData::Account(AccountEntry {
+                account_id: account_id.clone(),
+                balance: i64::MAX,
+                flags: 0,
+                home_domain: Default::default(),
+                inflation_dest: None,
+                num_sub_entries: 0,
+                seq_num: SequenceNumber(0),
+                thresholds: Thresholds([1; 4]),
+                signers: Default::default(),
+                ext: AccountEntryExt::V0,
+            }),
+            last_modified_ledger_seq: 0,
+            ext: LedgerEntryExt::V0,
+        });
+        env.host().add_ledger_entry(&key, &entry, None).unwrap();
+    }
+
+    let treasury = Address::try_from_val(env, &ScAddress::Account(account_id)).unwrap();
+    let client = TokenClient::new(env, &native_token(env));
+    for holder in holders {
+        client.mock_all_auths().transfer(&treasury, *holder, &amount);
+    }
+}
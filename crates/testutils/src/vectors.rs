@@ -0,0 +1,155 @@
+//! Deterministic commitment and proof-hash test vectors.
+//!
+//! Mirrors `circuit/test_vectors.json`, which is the copy consumed by the
+//! Noir circuit tooling. If an encoding changes, update both files; the
+//! `vectors_match_circuit_json` test fails if they drift apart.
+
+use soroban_sdk::{Bytes, BytesN, Env};
+
+/// Fixed `(tactic, secret, session_id)` input and the keccak256 of its
+/// canonical commitment preimage (see [`commitment_preimage`])
+#[derive(Clone, Copy, Debug)]
+pub struct CommitmentVector {
+    pub name: &'static str,
+    pub tactic: u32,
+    /// 32-byte secret, hex encoded
+    pub secret: &'static str,
+    pub session_id: u32,
+    /// Expected keccak256 of the preimage, hex encoded
+    pub keccak256: &'static str,
+}
+
+/// keccak256 of [`crate::mock_proof`] for a fixed `(tactic, session_id)`
+#[derive(Clone, Copy, Debug)]
+pub struct ProofHashVector {
+    pub tactic: u32,
+    pub session_id: u32,
+    /// Expected keccak256 of the proof bytes, hex encoded
+    pub keccak256: &'static str,
+}
+
+pub const COMMITMENT_VECTORS: [CommitmentVector; 4] = [
+    CommitmentVector {
+        name: "defensive_constant_secret",
+        tactic: 0,
+        secret: "0101010101010101010101010101010101010101010101010101010101010101",
+        session_id: 1,
+        keccak256: "a48721b533c71cf303f0ab44f6aafb2175a9b9ef65b707765920e8c3b0de32bb",
+    },
+    CommitmentVector {
+        name: "aggressive_prover_toml_secret",
+        tactic: 2,
+        secret: "00000000000000000000000000000000000000018ee90ff6c373e0ee4e3f0ad2",
+        session_id: 1,
+        keccak256: "f2af8b4e6c4c248e38e622b6ccc516c807fe88e12b46b422fbf00863eac867ac",
+    },
+    CommitmentVector {
+        name: "allout_max_secret",
+        tactic: 3,
+        secret: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        session_id: 42,
+        keccak256: "eb937b74a7e94b62e6c9ec9261ead26c14dd556bda41047431522ad064245957",
+    },
+    CommitmentVector {
+        name: "balanced_max_session",
+        tactic: 1,
+        secret: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        session_id: 4294967295,
+        keccak256: "3a7086b3b42e38a3c5850e61549d3543f0c72df4a9934816822b82d5a05f8916",
+    },
+];
+
+pub const PROOF_HASH_VECTORS: [ProofHashVector; 3] = [
+    ProofHashVector {
+        tactic: 0,
+        session_id: 1,
+        keccak256: "a6de5e4eca1080d8ec3b40c1aaadbd8d3b13ead853a8d084e90dcedd3e9262ed",
+    },
+    ProofHashVector {
+        tactic: 2,
+        session_id: 1,
+        keccak256: "99208f41d8f2d8cf9a661cd36d6a52f8ba4f1dd52cf87afa244bc5db0efac068",
+    },
+    ProofHashVector {
+        tactic: 3,
+        session_id: 42,
+        keccak256: "719ccf1258df5d0f7b5e1133808d74e3af9602352af55f49cb7cd4e95afe97bc",
+    },
+];
+
+impl CommitmentVector {
+    pub fn secret(&self, env: &Env) -> BytesN<32> {
+        BytesN::from_array(env, &decode_hex(self.secret))
+    }
+
+    pub fn preimage(&self, env: &Env) -> Bytes {
+        commitment_preimage(env, self.tactic, &self.secret(env), self.session_id)
+    }
+
+    pub fn expected(&self, env: &Env) -> BytesN<32> {
+        BytesN::from_array(env, &decode_hex(self.keccak256))
+    }
+}
+
+impl ProofHashVector {
+    pub fn expected(&self, env: &Env) -> BytesN<32> {
+        BytesN::from_array(env, &decode_hex(self.keccak256))
+    }
+}
+
+/// Canonical commitment preimage:
+/// `tactic (u32 BE) || secret (32 bytes) || session_id (u32 BE)`
+pub fn commitment_preimage(env: &Env, tactic: u32, secret: &BytesN<32>, session_id: u32) -> Bytes {
+    let mut data = Bytes::from_array(env, &tactic.to_be_bytes());
+    data.append(&Bytes::from(secret.clone()));
+    data.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    data
+}
+
+/// Decode a 64-character hex string
+pub fn decode_hex(hex: &str) -> [u8; 32] {
+    assert_eq!(hex.len(), 64, "expected 32 bytes of hex");
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).expect("invalid hex");
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CIRCUIT_VECTORS: &str = include_str!("../../../circuit/test_vectors.json");
+
+    #[test]
+    fn vectors_match_circuit_json() {
+        for vector in COMMITMENT_VECTORS {
+            assert!(CIRCUIT_VECTORS.contains(vector.name));
+            assert!(CIRCUIT_VECTORS.contains(vector.secret));
+            assert!(CIRCUIT_VECTORS.contains(vector.keccak256));
+        }
+        for vector in PROOF_HASH_VECTORS {
+            assert!(CIRCUIT_VECTORS.contains(vector.keccak256));
+        }
+    }
+
+    #[test]
+    fn commitment_vectors_hash_to_expected() {
+        let env = Env::default();
+        for vector in COMMITMENT_VECTORS {
+            let hash: BytesN<32> = env.crypto().keccak256(&vector.preimage(&env)).into();
+            assert_eq!(hash, vector.expected(&env), "vector {}", vector.name);
+        }
+    }
+
+    #[test]
+    fn proof_hash_vectors_hash_to_expected() {
+        let env = Env::default();
+        for vector in PROOF_HASH_VECTORS {
+            let proof = crate::mock_proof(&env, vector.tactic, vector.session_id);
+            let hash: BytesN<32> = env.crypto().keccak256(&proof).into();
+            assert_eq!(hash, vector.expected(&env));
+        }
+    }
+}
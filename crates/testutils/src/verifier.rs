@@ -0,0 +1,113 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Bytes, BytesN, Env, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Default,
+    Proof(Bytes),
+    Calls,
+    LastPublicInputs,
+    LastFields,
+    LastJournalDigest,
+}
+
+/// Configurable stand-in for an external ZK verifier contract.
+///
+/// Serves the Groth16 `verify` interface, the UltraHonk `verify_proof`
+/// interface and the zkVM `verify_receipt` interface (seals count as proofs). Every proof gets the default verdict unless an
+/// explicit verdict was set for its exact bytes:
+///
+/// ```ignore
+/// let verifier = env.register(MockVerifier, (true,));
+/// let client = MockVerifierClient::new(&env, &verifier);
+/// client.set_proof(&bad_proof, &false);
+/// ```
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn __constructor(env: Env, default_accept: bool) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Default, &default_accept);
+    }
+
+    /// Verdict for any proof without an explicit entry
+    pub fn set_default(env: Env, accept: bool) {
+        env.storage().instance().set(&DataKey::Default, &accept);
+    }
+
+    /// Verdict for these exact proof bytes
+    pub fn set_proof(env: Env, proof: Bytes, accept: bool) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Proof(proof), &accept);
+    }
+
+    /// Verifier interface consumed by game contracts
+    pub fn verify(env: Env, proof: Bytes, public_inputs: Vec<BytesN<32>>) -> bool {
+        env.storage()
+            .instance()
+            .set(&DataKey::LastFields, &public_inputs);
+
+        let calls: u32 = env.storage().instance().get(&DataKey::Calls).unwrap_or(0);
+        env.storage().instance().set(&DataKey::Calls, &(calls + 1));
+
+        env.storage()
+            .instance()
+            .get(&DataKey::Proof(proof))
+            .or_else(|| env.storage().instance().get(&DataKey::Default))
+            .unwrap_or(false)
+    }
+
+    /// Public inputs passed to the last `verify` call (empty after a
+    /// `verify_proof` or `verify_receipt` call)
+    pub fn last_fields(env: Env) -> Option<Vec<BytesN<32>>> {
+        env.storage().instance().get(&DataKey::LastFields)
+    }
+
+    /// UltraHonk verifier interface: traps instead of returning `false`
+    pub fn verify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes) {
+        env.storage()
+            .instance()
+            .set(&DataKey::LastPublicInputs, &public_inputs);
+
+        let fields = Vec::new(&env);
+        if !Self::verify(env, proof_bytes, fields) {
+            panic!("proof rejected");
+        }
+    }
+
+    /// Public inputs passed to the last `verify_proof` call
+    pub fn last_public_inputs(env: Env) -> Option<Bytes> {
+        env.storage().instance().get(&DataKey::LastPublicInputs)
+    }
+
+    /// zkVM receipt verifier interface: traps instead of returning `false`
+    pub fn verify_receipt(
+        env: Env,
+        seal: Bytes,
+        _image_id: BytesN<32>,
+        journal_digest: BytesN<32>,
+    ) {
+        env.storage()
+            .instance()
+            .set(&DataKey::LastJournalDigest, &journal_digest);
+
+        let fields = Vec::new(&env);
+        if !Self::verify(env, seal, fields) {
+            panic!("receipt rejected");
+        }
+    }
+
+    /// Journal digest passed to the last `verify_receipt` call
+    pub fn last_journal_digest(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::LastJournalDigest)
+    }
+
+    /// Number of proofs checked so far (any interface)
+    pub fn calls(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Calls).unwrap_or(0)
+    }
+}
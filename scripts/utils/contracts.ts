@@ -9,6 +9,8 @@ export type ContractInfo = {
   envKey: string;
   bindingsOutDir: string;
   isMockHub: boolean;
+  /** Shared service contracts (not games) take only `--admin` at deploy time. */
+  isService: boolean;
 };
 
 export type ContractSelection = {
@@ -17,6 +19,16 @@ export type ContractSelection = {
   ambiguous: { target: string; matches: string[] }[];
 };
 
+// Workspace contracts that are Studio services rather than games. They are
+// deployed like games but are not wired to the Game Hub.
+const SERVICE_CONTRACTS = new Set([
+  "game-hub",
+  "randomness-beacon",
+  "reward-claims",
+  "studio-token",
+  "vk-registry",
+]);
+
 function toWasmName(packageName: string): string {
   return packageName.replaceAll("-", "_");
 }
@@ -65,6 +77,7 @@ export async function getWorkspaceContracts(): Promise<ContractInfo[]> {
       envKey,
       bindingsOutDir: `bindings/${wasmName}`,
       isMockHub: packageName === "mock-game-hub" || wasmName === "mock_game_hub",
+      isService: SERVICE_CONTRACTS.has(packageName),
     });
   }
 
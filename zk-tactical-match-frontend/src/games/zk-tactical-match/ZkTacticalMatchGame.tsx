@@ -3,8 +3,8 @@ import { ZkTacticalMatchService } from './zkTacticalMatchService';
 import { useWallet } from '@/hooks/useWallet';
 import { ZK_TACTICAL_MATCH_CONTRACT } from '@/utils/constants';
 import { matchmakingService, type Match } from './matchmakingService';
-import type { Game } from './bindings';
-import { generateTacticProof, generatePlayerSecret } from './zkProofService';
+import { GamePhase, ProofSystem, type Game } from './bindings';
+import { generateTacticProof, generatePlayerSecret, secretToBytes, computeTacticHash } from './zkProofService';
 
 const zkTacticalMatchService = new ZkTacticalMatchService(ZK_TACTICAL_MATCH_CONTRACT);
 
@@ -23,10 +23,14 @@ const TACTICS = [
   { id: 3, name: 'All-Out', icon: '🔥', gradient: 'from-purple-400 via-pink-500 to-rose-500', glow: 'shadow-purple-500/50' },
 ];
 
+// Polling while waiting for the opponent's reveal (about two minutes)
+const REVEAL_POLL_INTERVAL_MS = 2000;
+const REVEAL_POLL_ATTEMPTS = 60;
+
 export function ZkTacticalMatchGame({ userAddress, availablePoints, onStandingsRefresh, onGameComplete, currentEpoch }: Props) {
   const { getContractSigner } = useWallet();
   const [phase, setPhase] = useState<'lobby' | 'waiting' | 'join' | 'ready' | 'waitingForP2' | 'waitingForP1' | 'tactics' | 'waitingForOpponent' | 'results'>('lobby');
-  const [sessionId, setSessionId] = useState(() => Math.floor(Math.random() * 0xffffffff) || 1);
+  const [sessionId, setSessionId] = useState(0);
   const [matchId, setMatchId] = useState<string | null>(null);
   const [currentMatch, setCurrentMatch] = useState<Match | null>(null);
   const [joinCode, setJoinCode] = useState('');
@@ -40,7 +44,22 @@ export function ZkTacticalMatchGame({ userAddress, availablePoints, onStandingsR
   const activeSessionId = currentMatch?.sessionId ?? sessionId;
 
   const handleCreateMatch = async () => {
-    const match = matchmakingService.createMatch(userAddress, sessionId, BigInt(1000000));
+    // The Game Hub opens sessions only under IDs it allocated to this game
+    let allocatedSessionId: number;
+    try {
+      setLoading(true);
+      setError(null);
+      allocatedSessionId = await zkTacticalMatchService.allocateSession(userAddress, getContractSigner());
+    } catch (err) {
+      console.error('[Player 1] Error allocating session:', err);
+      setError(err instanceof Error ? err.message : 'Failed to allocate session');
+      return;
+    } finally {
+      setLoading(false);
+    }
+    setSessionId(allocatedSessionId);
+
+    const match = matchmakingService.createMatch(userAddress, allocatedSessionId, BigInt(1000000));
     setMatchId(match.id);
     setCurrentMatch(match);
     setPhase('waiting');
@@ -196,36 +215,74 @@ export function ZkTacticalMatchGame({ userAddress, availablePoints, onStandingsR
       setError(null);
       const signer = getContractSigner();
 
-      console.log(`[${userAddress === currentMatch?.player1 ? 'Player 1' : 'Player 2'}] Generating ZK proof for tactic:`, selectedTactic);
-
-      let proof: Uint8Array;
-
-      try {
-        // Generate player secret (should be stored securely in production)
-        const playerSecret = generatePlayerSecret();
-        console.log('[ZK] Player secret generated');
-
-        // Generate ZK proof
-        proof = await generateTacticProof(selectedTactic, playerSecret, activeSessionId);
-        console.log('[ZK] ✅ Proof generated successfully (', proof.length, 'bytes)');
-      } catch (zkError) {
-        console.warn('[ZK] Failed to generate ZK proof, using mock proof:', zkError);
-        console.warn('[ZK] This is acceptable for demo/testing but NOT for production');
-
-        // Fallback to mock proof (deterministic based on tactic and session)
-        const mockProofData = new Uint8Array(128);
-        // Fill with deterministic data based on tactic and sessionId
-        mockProofData[0] = selectedTactic;
-        for (let i = 1; i < 128; i++) {
-          mockProofData[i] = (selectedTactic * activeSessionId + i) % 256;
+      // Player secret (should be stored securely in production): needed
+      // again to reveal the tactic once both players have submitted. Also
+      // serves as the salt in commit-reveal games
+      const playerSecret = generatePlayerSecret();
+      const secret = secretToBytes(playerSecret);
+      const tactic = selectedTactic;
+      console.log('[ZK] Player secret generated');
+
+      const view = await zkTacticalMatchService.getGamePublic(activeSessionId);
+      if (view?.game.proof_system === ProofSystem.CommitReveal) {
+        // No prover needed: commit the salted hash and reveal it as usual
+        console.log(`[${userAddress === currentMatch?.player1 ? 'Player 1' : 'Player 2'}] Committing tactic without proof`);
+        const tacticHash = await computeTacticHash(tactic, secret, activeSessionId);
+        await zkTacticalMatchService.commitTactic(activeSessionId, userAddress, tacticHash, signer);
+      } else {
+        console.log(`[${userAddress === currentMatch?.player1 ? 'Player 1' : 'Player 2'}] Generating ZK proof for tactic:`, selectedTactic);
+
+        // The proof must name this player and contract, or the contract rejects it
+        const playerBinding = await zkTacticalMatchService.getPlayerBinding(userAddress);
+        // ...and expire within the contract's freshness window, if it sets one
+        const validUntilLedger = await zkTacticalMatchService.getProofValidUntil();
+
+        let proof: Uint8Array;
+        let commitment: Uint8Array;
+        let nullifier: Uint8Array;
+        let tacticHash: Uint8Array;
+
+        try {
+          // Generate ZK proof
+          ({ proof, commitment, nullifier, tacticHash } = await generateTacticProof(selectedTactic, playerSecret, activeSessionId, playerBinding, validUntilLedger));
+          console.log('[ZK] ✅ Proof generated successfully (', proof.length, 'bytes)');
+        } catch (zkError) {
+          console.warn('[ZK] Failed to generate ZK proof, using mock proof:', zkError);
+          console.warn('[ZK] This is acceptable for demo/testing but NOT for production');
+
+          // Fallback to mock proof (deterministic based on tactic and session)
+          const mockProofData = new Uint8Array(128);
+          // Fill with deterministic data based on tactic and sessionId
+          mockProofData[0] = selectedTactic;
+          for (let i = 1; i < 128; i++) {
+            mockProofData[i] = (selectedTactic * activeSessionId + i) % 256;
+          }
+          proof = mockProofData;
+
+          // Mock commitment: any BN254 field element (top byte zero) is accepted
+          // when no verifier is configured
+          commitment = new Uint8Array(32);
+          commitment[1] = selectedTactic;
+          new DataView(commitment.buffer).setUint32(2, activeSessionId);
+
+          // Mock nullifier: must be unique per player and session, since the
+          // contract rejects any nullifier it has already seen
+          const digest = await crypto.subtle.digest(
+            'SHA-256',
+            new TextEncoder().encode(`${userAddress}:${activeSessionId}`)
+          );
+          nullifier = new Uint8Array(digest);
+          nullifier[0] = 0;
+
+          // Mock tactic hash: must still open to the tactic for reveal_tactic
+          tacticHash = await computeTacticHash(selectedTactic, secret, activeSessionId);
+          console.log('[ZK] Using mock proof (', proof.length, 'bytes)');
         }
-        proof = mockProofData;
-        console.log('[ZK] Using mock proof (', proof.length, 'bytes)');
-      }
 
-      console.log(`[${userAddress === currentMatch?.player1 ? 'Player 1' : 'Player 2'}] Submitting tactic with proof`);
+        console.log(`[${userAddress === currentMatch?.player1 ? 'Player 1' : 'Player 2'}] Submitting tactic with proof`);
 
-      await zkTacticalMatchService.submitTactic(activeSessionId, userAddress, selectedTactic, proof, signer);
+        await zkTacticalMatchService.submitTactic(activeSessionId, userAddress, playerBinding, validUntilLedger, commitment, nullifier, tacticHash, proof, signer);
+      }
 
       // Update matchmaking service
       matchmakingService.submitTactic(matchId, userAddress, selectedTactic);
@@ -234,18 +291,33 @@ export function ZkTacticalMatchGame({ userAddress, availablePoints, onStandingsR
 
       console.log(`[${userAddress === currentMatch?.player1 ? 'Player 1' : 'Player 2'}] Tactic submitted, checking if both players are ready...`);
 
+      // Reveal once both have submitted, wait for the opponent's reveal, then
+      // resolve (whichever player gets there first settles the match)
+      const revealAndResolve = async () => {
+        await zkTacticalMatchService.revealTactic(activeSessionId, userAddress, tactic, secret, signer);
+        console.log('Tactic revealed, waiting for opponent reveal...');
+
+        for (let attempt = 0; attempt < REVEAL_POLL_ATTEMPTS; attempt++) {
+          const view = await zkTacticalMatchService.getGamePublic(activeSessionId);
+          if (view?.game.phase === GamePhase.Resolved || view?.game.phase === GamePhase.TimedOut) {
+            return view.game;
+          }
+          if (view?.player1_revealed && view?.player2_revealed) {
+            await zkTacticalMatchService.resolveMatch(activeSessionId, userAddress, signer);
+            return zkTacticalMatchService.getGame(activeSessionId);
+          }
+          await new Promise((resolve) => setTimeout(resolve, REVEAL_POLL_INTERVAL_MS));
+        }
+        throw new Error('Opponent did not reveal their tactic in time');
+      };
+
       // Check if both players have submitted tactics
       const updatedMatch = matchmakingService.getMatch(matchId);
       if (updatedMatch?.player1Tactic !== null && updatedMatch?.player2Tactic !== null) {
-        console.log('Both players have submitted tactics, resolving match...');
+        console.log('Both players have submitted tactics, revealing...');
 
-        // Both players submitted, resolve the match
-        await zkTacticalMatchService.resolveMatch(activeSessionId, userAddress, signer);
-
-        console.log('Match resolved, fetching game state...');
-
-        // Fetch final game state
-        const finalGameState = await zkTacticalMatchService.getGame(activeSessionId);
+        const finalGameState = await revealAndResolve();
+        console.log('Match resolved');
         setGameState(finalGameState);
         setPhase('results');
 
@@ -259,14 +331,10 @@ export function ZkTacticalMatchGame({ userAddress, availablePoints, onStandingsR
         // Poll for other player's tactic submission
         const unwatch = matchmakingService.watchMatch(matchId, async (updated) => {
           if (updated?.player1Tactic !== null && updated?.player2Tactic !== null) {
-            console.log('Other player submitted tactic, resolving match...');
+            console.log('Other player submitted tactic, revealing...');
 
             try {
-              // Both players submitted, resolve the match
-              await zkTacticalMatchService.resolveMatch(activeSessionId, userAddress, signer);
-
-              // Fetch final game state
-              const finalGameState = await zkTacticalMatchService.getGame(activeSessionId);
+              const finalGameState = await revealAndResolve();
               setGameState(finalGameState);
               setPhase('results');
 
@@ -39,142 +39,3445 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadySubmitted"},
+  4: {message:"BothPlayersNotSubmitted"},
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"InvalidTactic"},
+  7: {message:"InvalidProof"},
+  8: {message:"InvalidSessionKey"},
+  9: {message:"InvalidSigner"},
+  10: {message:"SignatureExpired"},
+  11: {message:"InvalidNonce"},
+  12: {message:"GameNotSettled"},
+  13: {message:"InvalidVerificationKey"},
+  14: {message:"InvalidCommitment"},
+  15: {message:"NullifierUsed"},
+  16: {message:"ProofSessionMismatch"},
+  17: {message:"ProofPlayerMismatch"},
+  18: {message:"ProofExpired"},
+  19: {message:"ProofValidityTooLong"},
+  20: {message:"InvalidReveal"},
+  21: {message:"AlreadyRevealed"},
+  22: {message:"TacticNotRevealed"},
+  23: {message:"WrongProofSystem"},
+  24: {message:"InvalidStakeRange"},
+  25: {message:"StakeNotRevealed"},
+  26: {message:"StakesNotHidden"},
+  27: {message:"GameAlreadyExists"},
+  28: {message:"InvalidReceipt"},
+  29: {message:"ReceiptRequired"},
+  30: {message:"InvalidRoundCount"},
+  31: {message:"NotMultiRound"},
+  32: {message:"UnsupportedProofVersion"},
+  33: {message:"ProofTooLarge"},
+  34: {message:"DuplicateProof"},
+  35: {message:"SybilGateDisabled"},
+  36: {message:"IdentityAlreadyPlayed"},
+  37: {message:"InvalidTeam"},
+  38: {message:"NotTeamGame"},
+  39: {message:"InvalidTeamSignature"},
+  40: {message:"RatingsUnavailable"},
+  41: {message:"InvalidRatingBracket"},
+  42: {message:"DeadlineNotReached"},
+  43: {message:"NoForfeit"},
+  44: {message:"SessionNotExpired"},
+  45: {message:"InvalidDeadlines"},
+  46: {message:"SelfPlayNotAllowed"},
+  47: {message:"NotInitialized"},
+  48: {message:"HubNotSet"},
+  49: {message:"SessionAlreadyExists"},
+  50: {message:"SubmitDeadlinePassed"},
+  51: {message:"RevealDeadlinePassed"},
+  52: {message:"VerifierNotConfigured"},
+  53: {message:"UnsupportedAddress"},
+  54: {message:"InvalidTtl"},
+  55: {message:"InvalidPhase"},
+  56: {message:"Paused"},
+  57: {message:"HubNotNotified"},
+  58: {message:"TooManyOpenGames"},
+  59: {message:"InvalidGameLimit"},
+  60: {message:"WagerTooSmall"},
+  61: {message:"WagerTooLarge"},
+  62: {message:"InvalidWagerLimits"},
+  63: {message:"NoPendingAdmin"},
+  64: {message:"NoPendingUpgrade"},
+  65: {message:"UpgradeNotReady"},
+  66: {message:"InvalidUpgradeDelay"},
+  67: {message:"InvalidCouncil"},
+  68: {message:"NotCouncilMember"},
+  69: {message:"NotApproved"},
+  70: {message:"MissingRole"},
+  71: {message:"SchemaTooNew"},
+  72: {message:"AlreadyInitialized"},
+  73: {message:"NoPendingReinit"},
+  74: {message:"ReinitNotReady"},
+  75: {message:"InvalidHub"},
+  76: {message:"NothingToClaim"},
+  77: {message:"InvalidFee"},
+  78: {message:"OracleNotConfigured"},
+  79: {message:"PriceUnavailable"},
+  80: {message:"StakesNotEquivalent"},
+  81: {message:"InvalidOracleConfig"},
+  82: {message:"SideBetsClosed"},
+  83: {message:"SideBetsUnavailable"},
+  84: {message:"InvalidSideBet"},
+  85: {message:"InsufficientBankroll"},
+  86: {message:"InvalidBankrollAmount"},
+  87: {message:"ReferrerAlreadySet"},
+  88: {message:"InvalidReferrer"},
+  89: {message:"InvalidStreakBonus"},
+  90: {message:"InvalidJackpotConfig"},
+  91: {message:"InvalidBalanceAmount"},
+  92: {message:"InsufficientBalance"},
+  93: {message:"InvalidBatch"},
+  94: {message:"InvalidEpochConfig"},
+  95: {message:"EpochNotOver"},
+  96: {message:"InvalidEpochRoot"},
+  97: {message:"InvalidEpochProof"},
+  98: {message:"InvalidDisputeConfig"},
+  99: {message:"DisputeNotFound"},
+  100: {message:"DisputeWindowClosed"},
+  101: {message:"DisputeWindowOpen"},
+  102: {message:"InvalidBondAmount"},
+  103: {message:"InvalidOdds"},
+  104: {message:"InvalidStakingConfig"},
+  105: {message:"StakingNotConfigured"},
+  106: {message:"InvalidStakeAmount"},
+  107: {message:"StakeLocked"},
+  108: {message:"StakeCoolingDown"},
+  109: {message:"HouseBeaconNotSet"},
+  110: {message:"RandomnessNotReady"}
+}
+
+/**
+ * Tactical formations: 0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut
+ */
+export enum Tactic {
+  Defensive = 0,
+  Balanced = 1,
+  Aggressive = 2,
+  AllOut = 3,
+}
+
+/**
+ * One cell of the score matrix (`get_score_matrix`)
+ */
+export interface ScoreEntry {
+  score1: u32;
+  score2: u32;
+  tactic1: u32;
+  tactic2: u32;
+}
+
+/**
+ * Proving backend a game's proofs are checked with
+ * 
+ * `CommitReveal` games take no proof at all: players `commit_tactic` a
+ * salted `tactic_hash` and open it with `reveal_tactic`, for clients that
+ * cannot generate ZK proofs.
+ */
+export enum ProofSystem {
+  Groth16 = 0,
+  UltraHonk = 1,
+  CommitReveal = 2,
+}
+
+/**
+ * How a drawn match is decided
+ * 
+ * `Draw` (the default) reports it to the hub as a draw. `Player1` is the
+ * original rule. `Random` seeds `env.prng()` with both tactic hashes and
+ * both revealed secrets, so neither player can steer it: each secret is
+ * committed before the other's is revealed. House games ignore the
+ * policy: the house wins their ties. Only `Random` ties count toward a
+ * jackpot run.
+ */
+export enum TiebreakPolicy {
+  Player1 = 0,
+  Random = 1,
+  Draw = 2,
+}
+
+/**
+ * Result of a settled game. Draws are reported to the hub's
+ * `end_game_with_outcome`, wins to its `end_game`
+ */
+export enum Outcome {
+  Player1Win = 0,
+  Player2Win = 1,
+  Draw = 2,
+}
+
+/**
+ * Where a game is in its lifecycle. Entrypoints only act on games in the
+ * phases they expect, failing with `InvalidPhase` otherwise
+ * 
+ * Games start `Created` and move to `AwaitingOpponent` and `BothSubmitted`
+ * as players submit (committed strategies start `BothSubmitted`). Reveals
+ * happen in `BothSubmitted`, and settling ends the game `Resolved`, or
+ * `TimedOut` for a forfeit by `resolve_timeout`, or `Cancelled` for games
+ * called off before play with `cancel_game`.
+ */
+export enum GamePhase {
+  Created = 0,
+  AwaitingOpponent = 1,
+  BothSubmitted = 2,
+  Resolved = 3,
+  Cancelled = 4,
+  TimedOut = 5,
+}
+
+/**
+ * Whether a game has been settled, and how. A contract type can't hold an
+ * `Option` of another contract type, so `Game` keeps this instead; read it
+ * through `Game::outcome`
+ */
+export type GameResult = {tag: "Pending", values: void} | {tag: "Settled", values: readonly [Outcome]};
+
+/**
+ * Public inputs of the tactic circuit, in the order the circuit declares
+ * them (public parameters, then its return values). The tactic itself is a
+ * private input; `tactic_hash` commits to it until `reveal_tactic`
+ */
+export interface PublicInputs {
+  commitment: Buffer;
+  nullifier: Buffer;
+  player_binding: Buffer;
+  session_id: u32;
+  tactic_hash: Buffer;
+  valid_until_ledger: u32;
+}
+
+/**
+ * Public inputs of the recursive rounds circuit, which verifies one tactic
+ * proof per round of a multi-round match. `commitment` and `nullifier`
+ * aggregate the inner proofs' outputs; `tactic_hashes` are the rounds' tactic
+ * hashes in order, opened by `reveal_rounds`
+ */
+export interface RoundInputs {
+  commitment: Buffer;
+  nullifier: Buffer;
+  player_binding: Buffer;
+  session_id: u32;
+  tactic_hashes: Array<Buffer>;
+  valid_until_ledger: u32;
+}
+
 export interface Game {
+  auto_resolve: boolean;
+  batch_verification: boolean;
+  hub_notified: boolean;
+  last_activity_ledger: u32;
+  phase: GamePhase;
   player1: string;
+  player1_commitment: Option<Buffer>;
   player1_points: i128;
   player1_proof_hash: Option<Buffer>;
   player1_score: Option<u32>;
   player1_tactic: Option<u32>;
+  player1_tactic_hash: Option<Buffer>;
   player2: string;
+  player2_commitment: Option<Buffer>;
   player2_points: i128;
   player2_proof_hash: Option<Buffer>;
   player2_score: Option<u32>;
   player2_tactic: Option<u32>;
-  winner: Option<string>;
+  player2_tactic_hash: Option<Buffer>;
+  proof_system: ProofSystem;
+  resolve_deadline_ledger: u32;
+  result: GameResult;
+  simulation_image_id: Option<Buffer>;
+  submit_deadline_ledger: u32;
+  tiebreak: TiebreakPolicy;
+  wager_token: Option<string>;
+}
+
+/**
+ * A game as anyone may see it (`get_game_public`)
+ * 
+ * Until the game is settled, `game` has its tactics and proof hashes
+ * masked to `None`; the reveal flags say who has revealed everything
+ * resolution needs from them.
+ */
+export interface PublicGame {
+  game: Game;
+  player1_revealed: boolean;
+  player2_revealed: boolean;
+}
+
+/**
+ * How one session of a batch went (`resolve_batch`, `claim_batch`)
+ */
+export type BatchResult = {tag: "Resolved", values: readonly [u32, Outcome]} | {tag: "Claimed", values: readonly [u32, i128]} | {tag: "Failed", values: readonly [u32, u32]};
+
+/**
+ * Rounds of a game started with `start_multi_round_game` or
+ * `start_committed_multi_round_game`. The game's own tactic hash fields hold
+ * the sha256 of each player's round hashes, or their strategy roots, and its
+ * tactics stay `None`
+ */
+export interface MultiRound {
+  player1_tactic_hashes: Option<Array<Buffer>>;
+  player1_tactics: Option<Array<u32>>;
+  player2_tactic_hashes: Option<Array<Buffer>>;
+  player2_tactics: Option<Array<u32>>;
+  rounds: u32;
+  strategy_committed: boolean;
+}
+
+/**
+ * A submission whose proof is checked at `resolve_match` (batch
+ * verification), kept until the game resolves
+ */
+export interface PendingProof {
+  inputs: PublicInputs;
+  proof: Buffer;
+}
+
+/**
+ * Where the Groth16 verification key is resolved from: the entry
+ * `(circuit_id, version)` of a `vk-registry` contract
+ */
+export interface VkSource {
+  circuit_id: string;
+  registry: string;
+  version: u32;
+}
+
+/**
+ * A tactic proof tagged with the circuit version and backend it was made
+ * for, as taken by `submit_tactic_envelope`. Versions below
+ * `get_min_proof_version()` or above `PROOF_VERSION` are rejected, so a
+ * circuit upgrade can retire proofs from the old one
+ */
+export interface ProofEnvelope {
+  payload: Buffer;
+  system: ProofSystem;
+  version: u32;
+}
+
+/**
+ * A player's stake in a hidden-stake game: the hash it is committed to
+ * (see `stake_hash`) and a proof that it lies within the game's bounds
+ */
+export interface StakeCommitment {
+  proof: Buffer;
+  stake_hash: Buffer;
+}
+
+/**
+ * What the hub was told when a session started. Kept in persistent storage
+ * until the result is reported, so a session whose game entry expired
+ * before it was resolved can still be reconciled (`abandon_expired`)
+ */
+export interface OpenSession {
+  player1: string;
+  player1_points: i128;
+  player2: string;
+  player2_points: i128;
+}
+
+/**
+ * A game's deadlines, for countdowns (`get_deadlines`)
+ */
+export interface Deadlines {
+  resolve_deadline_ledger: u32;
+  submit_deadline_ledger: u32;
+}
+
+/**
+ * Smallest and largest points a player may stake on a game
+ * (`set_wager_limits`)
+ */
+export interface WagerLimits {
+  max_wager: i128;
+  min_wager: i128;
+}
+
+/**
+ * The contract's operational settings in one read (`get_config`)
+ * 
+ * The TTLs are the ledgers each game entry, and each player's match
+ * history and stats, are kept alive for on every write.
+ */
+export interface ContractConfig {
+  admin: string;
+  fee_bps: u32;
+  fee_collector: string;
+  game_ttl_ledgers: u32;
+  history_ttl_ledgers: u32;
+  hub: string;
+  hub_share_bps: u32;
+  max_open_games: Option<u32>;
+  oracle: Option<string>;
+  paused: boolean;
+  proof_system: ProofSystem;
+  referral_share_bps: u32;
+  side_bet_rake_bps: u32;
+  timeout_ledgers: u32;
+  upgrade_delay_ledgers: u32;
+  verifier: Option<string>;
+  wager_limits: WagerLimits;
+  wager_token: Option<string>;
+}
+
+/**
+ * A settled game as kept in each player's match history, after the game
+ * entry itself has expired (`get_match_record`)
+ */
+export interface MatchRecord {
+  ledger: u32;
+  outcome: Outcome;
+  player1: string;
+  player1_points: i128;
+  player1_score: Option<u32>;
+  player2: string;
+  player2_points: i128;
+  player2_score: Option<u32>;
+  session_id: u32;
+}
+
+/**
+ * A player's lifetime record, for profile pages
+ * 
+ * `forfeits` are the `losses` that came from `resolve_timeout`.
+ * `tactic_counts` holds how often each revealed tactic was played, indexed
+ * by `Tactic`; multi-round games and unrevealed tactics are not counted.
+ */
+export interface PlayerStats {
+  draws: u32;
+  favorite_tactic: Option<u32>;
+  forfeits: u32;
+  games: u32;
+  losses: u32;
+  tactic_counts: Array<u32>;
+  total_wagered: i128;
+  wins: u32;
+}
+
+/**
+ * Contract-wide totals for dashboards (`get_global_stats`)
+ * 
+ * `total_volume` is the points committed across every game started.
+ * `resolution_rate_bps` is the share of ended games that were played out
+ * (resolved or timed out) rather than cancelled or abandoned, in basis
+ * points. Games that expire unresolved stay in `active_sessions` until
+ * `abandon_expired` closes them.
+ */
+export interface GlobalStats {
+  active_sessions: u32;
+  games_abandoned: u32;
+  games_cancelled: u32;
+  games_resolved: u32;
+  games_started: u32;
+  games_timed_out: u32;
+  resolution_rate_bps: u32;
+  total_volume: i128;
+}
+
+/**
+ * How a game ended for one player
+ */
+export enum MatchResult {
+  Won = 0,
+  Lost = 1,
+  Drawn = 2,
+}
+
+/**
+ * A `MatchRecord` from one player's side, as listed by `get_history`
+ */
+export interface GameSummary {
+  index: u32;
+  ledger: u32;
+  opponent: string;
+  opponent_points: i128;
+  opponent_score: Option<u32>;
+  points: i128;
+  result: MatchResult;
+  score: Option<u32>;
+  session_id: u32;
+}
+
+/**
+ * Admins who must approve sensitive actions, `threshold` of them at a
+ * time (`set_admin_council`)
+ */
+export interface AdminCouncil {
+  members: Array<string>;
+  threshold: u32;
+}
+
+/**
+ * Day-to-day duties the admin can delegate with `grant_role`, so they do
+ * not need the key that controls upgrades. The admin holds every role, and
+ * `Admin` holders may do whatever the other roles can
+ */
+export enum Role {
+  Admin = 0,
+  Operator = 1,
+  Pauser = 2,
+  FeeManager = 3,
+}
+
+/**
+ * An action that needs the admin council's approval once a council is set
+ */
+export type CouncilAction = {tag: "SetHub", values: readonly [string]} | {tag: "Pause", values: void} | {tag: "Unpause", values: void} | {tag: "ProposeUpgrade", values: readonly [Buffer]} | {tag: "Reinitialize", values: readonly [string, string]} | {tag: "SetCouncil", values: readonly [AdminCouncil]} | {tag: "RemoveCouncil", values: void} | {tag: "ProposeAdmin", values: readonly [string]} | {tag: "AcceptAdmin", values: readonly [string]} | {tag: "SetVerifier", values: readonly [string]} | {tag: "SetVerificationKey", values: readonly [Buffer]} | {tag: "GrantRole", values: readonly [string, Role]} | {tag: "RevokeRole", values: readonly [string, Role]} | {tag: "SetDisputeConfig", values: readonly [DisputeConfig]} | {tag: "RemoveDisputeConfig", values: void} | {tag: "SetFeeBps", values: readonly [u32]} | {tag: "SetFeeCollector", values: readonly [string]} | {tag: "SetOracleConfig", values: readonly [OracleConfig]} | {tag: "RemoveOracleConfig", values: void} | {tag: "SetHouseBeacon", values: readonly [string]} | {tag: "SetVkSource", values: readonly [VkSource]} | {tag: "RemoveVkSource", values: void} | {tag: "SetUltraHonkVerifier", values: readonly [string]} | {tag: "SetProofSystem", values: readonly [ProofSystem]} | {tag: "SetStakeVerifier", values: readonly [string]} | {tag: "SetRatingVerifier", values: readonly [string]} | {tag: "SetSybilGate", values: readonly [Buffer]} | {tag: "RemoveSybilGate", values: void} | {tag: "SetSimulationImageId", values: readonly [Buffer]} | {tag: "RemoveSimulationImageId", values: void} | {tag: "SetReceiptVerifier", values: readonly [string]} | {tag: "SetRoundsVerifier", values: readonly [string]} | {tag: "SetBatchVerification", values: readonly [boolean]} | {tag: "Migrate", values: void} | {tag: "SetUpgradeDelay", values: readonly [u32]};
+
+/**
+ * An upgrade waiting out its delay (`get_pending_upgrade`)
+ */
+export interface PendingUpgrade {
+  /**
+   * First ledger `execute_upgrade` may run in
+   */
+  executable_ledger: u32;
+  wasm_hash: Buffer;
+}
+
+/**
+ * A reinitialization waiting out the upgrade delay
+ * (`get_pending_reinit`)
+ */
+export interface PendingReinit {
+  admin: string;
+  /**
+   * First ledger `reinitialize` may run in
+   */
+  executable_ledger: u32;
+  game_hub: string;
+}
+
+/**
+ * Bounds and committed stakes of a game started with
+ * `start_hidden_stake_game`. Each stake stays `None` until its player opens
+ * the hash with `reveal_stake`
+ */
+export interface HiddenStakes {
+  max_stake: i128;
+  min_stake: i128;
+  player1_stake: Option<i128>;
+  player1_stake_hash: Buffer;
+  player2_stake: Option<i128>;
+  player2_stake_hash: Buffer;
+}
+
+/**
+ * The odds of a `start_game_with_odds` match: player1 risks `player1`
+ * units for every `player2` units player2 risks, so at 2:1 player1 is the
+ * favourite and wins half what they stake, and player2 twice theirs
+ */
+export interface Odds {
+  player1: u32;
+  player2: u32;
+}
+
+/**
+ * A member of a 2v2 team (`start_team_game`) and the BLS12-381 public key
+ * (G1, uncompressed) their part of each team move is signed with
+ */
+export interface TeamMember {
+  bls_key: Buffer;
+  player: string;
+}
+
+/**
+ * Both sides of a 2v2 match, in member order. Each team's first member is
+ * its captain and holds the game's `player1`/`player2` slot; member `i`
+ * plays round `i` against the opposing member `i`
+ */
+export interface Teams {
+  team1: Array<TeamMember>;
+  team2: Array<TeamMember>;
+}
+
+/**
+ * What each teammate signs for `submit_team_move`: their own tactic hash
+ * in one session. The signed message is the XDR encoding of this struct
+ */
+export interface TeamMove {
+  contract: string;
+  player: string;
+  session_id: u32;
+  tactic_hash: Buffer;
+}
+
+/**
+ * Anti-sybil configuration for `start_ranked_game`: the verifier of
+ * uniqueness proofs, the identity set they prove membership of and the
+ * bracket their nullifiers are scoped to. Moving to a new bracket lets every
+ * identity play again
+ */
+export interface SybilGate {
+  bracket: u32;
+  identity_root: Buffer;
+  verifier: string;
+}
+
+/**
+ * A player's uniqueness proof for `start_ranked_game`. The nullifier is
+ * derived from the player's identity and the gate's bracket, so an identity
+ * has exactly one per bracket whichever address it plays from
+ */
+export interface UniquenessProof {
+  nullifier: Buffer;
+  proof: Buffer;
+}
+
+/**
+ * Inclusive rating range a `start_bracket_game` match is restricted to
+ */
+export interface RatingBracket {
+  max_rating: u32;
+  min_rating: u32;
+}
+
+/**
+ * Session key grant: lets `key` sign `submit_tactic` on the player's behalf
+ * for one session, as long as the player's stake is within `max_stake` and
+ * the grant has not expired.
+ */
+export interface SessionKeyGrant {
+  expiration_ledger: u32;
+  key: string;
+  max_stake: i128;
+}
+
+/**
+ * Payload a player signs for `submit_tactic_signed`. The signed message is
+ * the XDR encoding of this struct (an `ScMap` keyed by field name).
+ */
+export interface SignedSubmission {
+  contract: string;
+  expiration_ledger: u32;
+  inputs: PublicInputs;
+  nonce: u64;
+  player: string;
+  proof_hash: Buffer;
+  session_id: u32;
+}
+
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "Verifier", values: void} | {tag: "SessionKey", values: readonly [u32, string]} | {tag: "Nonce", values: readonly [string]} | {tag: "VerificationKey", values: void} | {tag: "ProofSystem", values: void} | {tag: "UltraHonkVerifier", values: void} | {tag: "Nullifier", values: readonly [Buffer]} | {tag: "ProofFreshnessWindow", values: void} | {tag: "BatchVerification", values: void} | {tag: "PendingProof", values: readonly [u32, string]} | {tag: "HiddenStakes", values: readonly [u32]} | {tag: "StakeVerifier", values: void} | {tag: "SimulationImageId", values: void} | {tag: "ReceiptVerifier", values: void} | {tag: "TiebreakPolicy", values: void} | {tag: "TiebreakEntropy", values: readonly [u32]} | {tag: "MultiRound", values: readonly [u32]} | {tag: "RoundsVerifier", values: void} | {tag: "MinProofVersion", values: void} | {tag: "VkSource", values: void} | {tag: "MaxProofSize", values: void} | {tag: "SybilGate", values: void} | {tag: "IdentityNullifier", values: readonly [u32, Buffer]} | {tag: "Teams", values: readonly [u32]} | {tag: "RatingVerifier", values: void} | {tag: "RatingBracket", values: readonly [u32]} | {tag: "TimeoutLedgers", values: void} | {tag: "OpenSession", values: readonly [u32]} | {tag: "AutoResolve", values: void} | {tag: "MatchCount", values: readonly [string]} | {tag: "MatchRecord", values: readonly [string, u32]} | {tag: "Paused", values: void} | {tag: "MaxOpenGames", values: void} | {tag: "PlayerGames", values: readonly [string]} | {tag: "WagerLimits", values: void} | {tag: "PendingAdmin", values: void} | {tag: "UpgradeDelay", values: void} | {tag: "PendingUpgrade", values: void} | {tag: "AdminCouncil", values: void} | {tag: "Approvals", values: readonly [CouncilAction]} | {tag: "Role", values: readonly [string, Role]} | {tag: "SchemaVersion", values: void} | {tag: "Initialized", values: void} | {tag: "PendingReinit", values: void} | {tag: "PlayerStats", values: readonly [string]} | {tag: "GlobalStats", values: void} | {tag: "WagerToken", values: void} | {tag: "Escrow", values: readonly [u32]} | {tag: "FeeBps", values: void} | {tag: "FeeCollector", values: void} | {tag: "AccruedFees", values: readonly [string]} | {tag: "OracleConfig", values: void} | {tag: "SideBetRakeBps", values: void} | {tag: "SideBetPool", values: readonly [u32]} | {tag: "SideBet", values: readonly [u32, string]} | {tag: "Bankroll", values: readonly [string]} | {tag: "BankrollShares", values: readonly [string, string]} | {tag: "HouseBeacon", values: void} | {tag: "HouseRound", values: readonly [u32]} | {tag: "ReferralShareBps", values: void} | {tag: "Referrer", values: readonly [string]} | {tag: "ReferralStats", values: readonly [string]} | {tag: "ReferralRewards", values: readonly [string, string]} | {tag: "Streak", values: readonly [string]} | {tag: "StreakBonus", values: void} | {tag: "Jackpot", values: readonly [string]} | {tag: "JackpotConfig", values: void} | {tag: "JackpotRun", values: readonly [string]} | {tag: "Balance", values: readonly [string, string]} | {tag: "HubShareBps", values: void} | {tag: "EpochConfig", values: void} | {tag: "EpochSchedule", values: void} | {tag: "EpochRewards", values: readonly [u32]} | {tag: "EpochWins", values: readonly [u32, string]} | {tag: "EpochClaimed", values: readonly [u32, string]} | {tag: "DisputeConfig", values: void} | {tag: "Bond", values: readonly [string, string]} | {tag: "Dispute", values: readonly [u32]} | {tag: "Odds", values: readonly [u32]} | {tag: "StakingConfig", values: void} | {tag: "Stake", values: readonly [string]};
+
+/**
+ * Canonical, Merkle-committed record of a settled session
+ */
+export interface Attestation {
+  /**
+   * Leaves in order: header, player1, player2, outcome
+   */
+  leaves: Array<Buffer>;
+  /**
+   * keccak256 Merkle root over the leaves
+   */
+  root: Buffer;
+}
+
+/**
+ * A token's house bankroll (`get_bankroll`)
+ */
+export interface Bankroll {
+  assets: i128;
+  locked: i128;
+  shares: i128;
+}
+
+/**
+ * Who rules on disputes, and for how long a settlement can be disputed
+ * (`set_dispute_config`)
+ */
+export interface DisputeConfig {
+  module: string;
+  window_ledgers: u32;
+}
+
+/**
+ * A player's bond in one token (`get_bond`)
+ */
+export interface Bond {
+  amount: i128;
+  locked: i128;
+}
+
+/**
+ * A won pot in its dispute window (`get_dispute`)
+ */
+export interface Dispute {
+  deadline_ledger: u32;
+  locked: i128;
+  loser: string;
+  module: string;
+  status: DisputeStatus;
+  token: string;
+  winner: string;
+}
+
+/**
+ * Where a dispute window stands
+ */
+export type DisputeStatus = {tag: "Open", values: void} | {tag: "Filed", values: void};
+
+/**
+ * How epochs run and fill their pools (`set_epoch_config`)
+ */
+export interface EpochConfig {
+  length_ledgers: u32;
+  share_bps: u32;
+  token: string;
+  top_n: u32;
+}
+
+/**
+ * When the epochs of the current configuration began. Reconfiguring ends
+ * the running epoch, and the next one starts from then
+ */
+export interface EpochSchedule {
+  first_epoch: u32;
+  start_ledger: u32;
+}
+
+/**
+ * One epoch's pool and leaderboard (`get_epoch`)
+ */
+export interface EpochRewards {
+  claimed: i128;
+  claims: u32;
+  leaders: Array<EpochLeader>;
+  leaves: u32;
+  pool: i128;
+  root: Option<Buffer>;
+  token: string;
+}
+
+/**
+ * A ranked player and their wins in an epoch
+ */
+export interface EpochLeader {
+  player: string;
+  wins: u32;
+}
+
+/**
+ * Tokens a game's players have locked in the contract (`get_escrow`)
+ */
+export interface Escrow {
+  dues: Array<Due>;
+  fee_bps: u32;
+  player1: string;
+  player1_amount: i128;
+  player1_token: string;
+  player2: string;
+  player2_amount: i128;
+  player2_token: string;
+  settlement: Settlement;
+}
+
+/**
+ * An amount the escrow owes a player
+ */
+export interface Due {
+  amount: i128;
+  player: string;
+  token: string;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadySubmitted"},
-  4: {message:"BothPlayersNotSubmitted"},
-  5: {message:"GameAlreadyEnded"},
-  6: {message:"InvalidTactic"},
-  7: {message:"InvalidProof"}
-}
+/**
+ * How a game's escrow was paid out
+ */
+export type Settlement = {tag: "Pending", values: void} | {tag: "Winnings", values: void} | {tag: "Refund", values: void};
+
+/**
+ * How the jackpot fills and what wins it (`set_jackpot_config`)
+ */
+export interface JackpotConfig {
+  min_stake: i128;
+  payout_bps: u32;
+  share_bps: u32;
+  tactic: u32;
+  wins_in_a_row: u32;
+}
+
+/**
+ * `Game` as stored at schema 0
+ */
+export interface GameV0 {
+  auto_resolve: boolean;
+  batch_verification: boolean;
+  player1: string;
+  player1_commitment: Option<Buffer>;
+  player1_points: i128;
+  player1_proof_hash: Option<Buffer>;
+  player1_score: Option<u32>;
+  player1_tactic: Option<u32>;
+  player1_tactic_hash: Option<Buffer>;
+  player2: string;
+  player2_commitment: Option<Buffer>;
+  player2_points: i128;
+  player2_proof_hash: Option<Buffer>;
+  player2_score: Option<u32>;
+  player2_tactic: Option<u32>;
+  player2_tactic_hash: Option<Buffer>;
+  proof_system: ProofSystem;
+  resolve_deadline_ledger: u32;
+  result: GameResult;
+  simulation_image_id: Option<Buffer>;
+  submit_deadline_ledger: u32;
+  tiebreak: TiebreakPolicy;
+}
+
+/**
+ * `Game` as stored at schema 1
+ */
+export interface GameV1 {
+  auto_resolve: boolean;
+  batch_verification: boolean;
+  hub_notified: boolean;
+  phase: GamePhase;
+  player1: string;
+  player1_commitment: Option<Buffer>;
+  player1_points: i128;
+  player1_proof_hash: Option<Buffer>;
+  player1_score: Option<u32>;
+  player1_tactic: Option<u32>;
+  player1_tactic_hash: Option<Buffer>;
+  player2: string;
+  player2_commitment: Option<Buffer>;
+  player2_points: i128;
+  player2_proof_hash: Option<Buffer>;
+  player2_score: Option<u32>;
+  player2_tactic: Option<u32>;
+  player2_tactic_hash: Option<Buffer>;
+  proof_system: ProofSystem;
+  resolve_deadline_ledger: u32;
+  result: GameResult;
+  simulation_image_id: Option<Buffer>;
+  submit_deadline_ledger: u32;
+  tiebreak: TiebreakPolicy;
+}
+
+/**
+ * `Game` as stored at schema 2
+ */
+export interface GameV2 {
+  auto_resolve: boolean;
+  batch_verification: boolean;
+  hub_notified: boolean;
+  last_activity_ledger: u32;
+  phase: GamePhase;
+  player1: string;
+  player1_commitment: Option<Buffer>;
+  player1_points: i128;
+  player1_proof_hash: Option<Buffer>;
+  player1_score: Option<u32>;
+  player1_tactic: Option<u32>;
+  player1_tactic_hash: Option<Buffer>;
+  player2: string;
+  player2_commitment: Option<Buffer>;
+  player2_points: i128;
+  player2_proof_hash: Option<Buffer>;
+  player2_score: Option<u32>;
+  player2_tactic: Option<u32>;
+  player2_tactic_hash: Option<Buffer>;
+  proof_system: ProofSystem;
+  resolve_deadline_ledger: u32;
+  result: GameResult;
+  simulation_image_id: Option<Buffer>;
+  submit_deadline_ledger: u32;
+  tiebreak: TiebreakPolicy;
+}
+
+/**
+ * Asset as SEP-40 oracles name it
+ */
+export type Asset = {tag: "Stellar", values: readonly [string]} | {tag: "Other", values: readonly [string]};
+
+/**
+ * A SEP-40 price quote, `timestamp` in seconds
+ */
+export interface PriceData {
+  price: i128;
+  timestamp: u64;
+}
+
+/**
+ * Oracle consulted by `start_mixed_game` (`set_oracle_config`)
+ */
+export interface OracleConfig {
+  max_age_seconds: u64;
+  oracle: string;
+  tolerance_bps: u32;
+}
+
+/**
+ * What a referrer has brought in (`get_referral_stats`)
+ */
+export interface ReferralStats {
+  raked_games: u32;
+  referees: u32;
+}
+
+/**
+ * A referrer's rewards in one token (`get_referral_rewards`)
+ */
+export interface ReferralRewards {
+  claimable: i128;
+  earned: i128;
+}
+
+/**
+ * The side bets on one game (`get_side_bet_pool`)
+ */
+export interface SideBetPool {
+  open_stake: i128;
+  player1: string;
+  player1_total: i128;
+  player2: string;
+  player2_total: i128;
+  rake: i128;
+  rake_bps: u32;
+  result: SideBetResult;
+  token: string;
+  unclaimed: i128;
+}
+
+/**
+ * How a side-bet pool was settled
+ */
+export type SideBetResult = {tag: "Open", values: void} | {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Refund", values: void};
+
+/**
+ * One bettor's stake on a game (`get_side_bet`)
+ */
+export interface SideBet {
+  amount: i128;
+  player: string;
+}
+
+/**
+ * The staking token, its tiers and its waiting periods
+ * (`set_staking_config`)
+ */
+export interface StakingConfig {
+  cooldown_ledgers: u32;
+  lockup_ledgers: u32;
+  tiers: Array<StakeTier>;
+  token: string;
+}
+
+/**
+ * A discount and the stake that earns it
+ */
+export interface StakeTier {
+  discount_bps: u32;
+  min_stake: i128;
+}
+
+/**
+ * A player's stake (`get_stake`)
+ */
+export interface Stake {
+  amount: i128;
+  cooldown_end_ledger: u32;
+  locked_until_ledger: u32;
+  token: string;
+  unstaking: i128;
+}
+
+/**
+ * A player's consecutive wins (`get_streak`)
+ */
+export interface Streak {
+  best: u32;
+  current: u32;
+}
+
+/**
+ * The bonus for winning on a streak (`set_streak_bonus`)
+ */
+export interface StreakBonus {
+  max_bps: u32;
+  min_streak: u32;
+  step_bps: u32;
+}
+
+export interface Client {
+  /**
+   * Construct and simulate a allocate_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allocate this game a session ID at the Game Hub, unique across
+   * every game, for the players to sign into `start_game` or another
+   * `start_*` entrypoint. Version 4 hubs open sessions only under IDs
+   * allocated to the game; a hub that allocates none fails with
+   * `InvalidHub`. Anyone may call it.
+   */
+  allocate_session: (options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new ZK Tactical Match between two players.
+   * 
+   * Both players sign the full terms: the session, both players, both
+   * points and the wager token (`get_wager_token`), so neither signature
+   * can be paired with another opponent or stake. Returns
+   * `SelfPlayNotAllowed` if both players are the same address, and
+   * `InsufficientBalance` if the Game Hub holds less than a player's
+   * points for them, as do the other `start_*` entrypoints. A game
+   * wagered in a token stakes nothing at the hub: its escrow holds the
+   * stakes instead.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier, from `allocate_session`
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1
+   * * `player2_points` - Betting amount for player 2
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game_with_deadlines transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match with its own submit and reveal windows.
+   * 
+   * Plays as `start_game`, but instead of the admin's timeout players
+   * have `submit_ledgers` to submit and a further `resolve_ledgers` to
+   * reveal, after which `resolve_timeout` settles the game by forfeit.
+   * Both players sign the windows with the terms. Returns `InvalidDeadlines` if either
+   * is 0 or together they outlast the game's storage.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1
+   * * `player2_points` - Betting amount for player 2
+   * * `submit_ledgers` - Ledgers from now to submit in
+   * * `resolve_ledgers` - Ledgers after the submit deadline to reveal in
+   */
+  start_game_with_deadlines: ({session_id, player1, player2, player1_points, player2_points, submit_ledgers, resolve_ledgers}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, submit_ledgers: u32, resolve_ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game_with_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match wagered in a token of the players' choosing.
+   * 
+   * Plays as `start_game`, but the points are escrowed in `token`
+   * instead of the contract-wide `get_wager_token` (see `get_escrow`), so
+   * a match can be played for USDC, XLM or a community token. Both
+   * players sign this token in the terms. The token is kept in
+   * the game's `wager_token` and pays out the result.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1, in `token` units
+   * * `player2_points` - Betting amount for player 2, in `token` units
+   * * `token` - A Stellar Asset Contract or other SEP-41 token
+   */
+  start_game_with_token: ({session_id, player1, player2, player1_points, player2_points, token}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_native_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match wagered in native XLM.
+   * 
+   * As `start_game_with_token` with the native Stellar Asset Contract
+   * (`get_native_token`) as the token, which players sign along with
+   * their points. Amounts are stroops (1 XLM = 10,000,000).
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1, in stroops
+   * * `player2_points` - Betting amount for player 2, in stroops
+   */
+  start_native_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_house_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match against the house.
+   * 
+   * The contract itself is player2, staking `points` of `token` from the
+   * token's bankroll (see `deposit_bankroll`) against the player's
+   * `points`; a bankroll without that much unlocked returns
+   * `InsufficientBankroll`. The player submits or commits and reveals
+   * as usual. The house's tactic comes from the round of the house
+   * beacon (`set_house_beacon`, else `HouseBeaconNotSet`) that is pending
+   * when the player commits, so it is fixed before the player's tactic
+   * but unknown until the round is revealed; with no round pending the
+   * commit returns `RandomnessNotReady`. The house wins drawn scores
+   * whatever the game's tiebreak, which is its edge. A player who never
+   * reveals forfeits their whole stake to `resolve_timeout`. The
+   * bankroll takes the house's share of the result when the game
+   * settles. The player signs the token along with their points.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player` - The player, as player1
+   * * `points` - Each side's stake, in `token` units
+   * * `token` - 
+   */
+  start_house_game: ({session_id, player, points, token}: {session_id: u32, player: string, points: i128, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_mixed_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match where each player stakes a different token.
+   * 
+   * Plays as `start_game_with_token`, but player1 deposits
+   * `player1_points` of `player1_token` and player2 `player2_points` of
+   * `player2_token`. The price oracle (`get_oracle_config`) values both
+   * stakes when the game starts, and they must be worth the same within
+   * its tolerance, else `StakesNotEquivalent`. A missing or stale price
+   * returns `PriceUnavailable`, and no oracle `OracleNotConfigured`.
+   * Each deposit is paid out in its own token: the winner gets both,
+   * and a refund returns each player's own. Both players sign both
+   * tokens along with their points.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1, in `player1_token`
+   * * `player2_points` - Betting amount for player 2, in `player2_token`
+   * * `player1_token` - The token player 1 stakes
+   * * `player2_token` - The token player 2 stakes
+   */
+  start_mixed_game: ({session_id, player1, player2, player1_points, player2_points, player1_token, player2_token}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, player1_token: string, player2_token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game_with_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match at handicap odds.
+   * 
+   * Plays as `start_game_with_token`, but the stakes are not assumed to
+   * be even: each player's points are the most they will risk, and the
+   * game is played for the largest stakes within them that are exactly
+   * at `odds`:
+   * 
+   * ```text
+   * units          = min(player1_points / odds.player1, player2_points / odds.player2)
+   * player1 stakes = units * odds.player1
+   * player2 stakes = units * odds.player2
+   * ```
+   * 
+   * Only those stakes are escrowed, and the winner takes the pot, so a
+   * win pays exactly what the odds give. Odds with a zero side, or
+   * points too small to cover one unit, return `InvalidOdds`. Both
+   * players sign the token and the odds with the terms; the
+   * odds are kept for the game (`get_odds`).
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - The most player 1 risks, in `token` units
+   * * `player2_points` - The most player 2 risks, in `token` units
+   * * `token` - A Stellar Asset Contract or other SEP-41 token
+   * * `
+   */
+  start_game_with_odds: ({session_id, player1, player2, player1_points, player2_points, token, odds}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, token: string, odds: Odds}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_multi_round_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-N match.
+   * 
+   * Instead of one tactic per player, each player submits a single
+   * recursive proof attesting to all `rounds` tactics (`submit_rounds`)
+   * and opens them together with `reveal_rounds`. `resolve_match` scores
+   * every round with the matrix; the player who wins more rounds wins the
+   * match, and a tie in rounds won is decided by the `TiebreakPolicy`.
+   * 
+   * Round proofs are checked by the verifier set with
+   * `set_rounds_verifier`, whatever the game's `ProofSystem`; multi-round
+   * games are never batch-verified or scored by a simulation.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1
+   * * `player2_points` - Betting amount for player 2
+   * * `rounds` - Number of rounds, 1 to `MAX_ROUNDS`
+   */
+  start_multi_round_game: ({session_id, player1, player2, player1_points, player2_points, rounds}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, rounds: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_committed_multi_round_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-N match with every round committed up front.
+   * 
+   * Both players sign the start with the roots of Merkle trees over their
+   * round tactics (see `strategy_root`), so no submissions follow: rounds
+   * are opened one at a time, in order, with `reveal_round`. Otherwise it
+   * plays as `start_multi_round_game`.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1
+   * * `player2_points` - Betting amount for player 2
+   * * `rounds` - Number of rounds, 1 to `MAX_ROUNDS`
+   * * `player1_root` - Root of player 1's strategy tree
+   * * `player2_root` - Root of player 2's strategy tree
+   */
+  start_committed_multi_round_game: ({session_id, player1, player2, player1_points, player2_points, rounds, player1_root, player2_root}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, rounds: u32, player1_root: Buffer, player2_root: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a 2v2 match.
+   * 
+   * Played as a `TEAM_SIZE`-round match: member `i` of each team plays
+   * round `i`, the team winning more rounds wins, and a tie is decided by
+   * the `TiebreakPolicy`. The hub sees the captains (each team's first
+   * member) and the team stakes. Teams move with `submit_team_move` and
+   * captains reveal every member's tactic with `reveal_rounds`.
+   * 
+   * Every member signs `(session_id, team points, bls_key)`.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `team1` - Team 1's members, captain first
+   * * `team2` - Team 2's members, captain first
+   * * `team1_points` - Betting amount for team 1
+   * * `team2_points` - Betting amount for team 2
+   * 
+   * Fails with `InvalidTeam` unless both teams have `TEAM_SIZE` distinct
+   * members with valid keys.
+   */
+  start_team_game: ({session_id, team1, team2, team1_points, team2_points}: {session_id: u32, team1: Array<TeamMember>, team2: Array<TeamMember>, team1_points: i128, team2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_hidden_stake_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match whose stakes stay hidden until settlement.
+   * 
+   * Each player commits to their stake with `stake_hash` and proves it
+   * lies within `[min_stake, max_stake]`; the range proof's public inputs
+   * are `[session_id, player_binding, min_stake, max_stake, stake_hash]`
+   * as 32-byte field elements, checked by the verifier set with
+   * `set_stake_verifier` (without one, `VerifierNotConfigured`). The game
+   * then plays as usual, with stakes of 0 until each player opens their
+   * hash with `reveal_stake`. Unless the game is wagered in a token, the
+   * Game Hub session opens with `max_stake` from each player, so a player
+   * cannot withdraw their hub balance from under the result, and is lowered to the revealed stakes at
+   * settlement (`reduce_stakes`, which needs a version 3 hub).
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier, not yet in use
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `min_stake` - Lowest stake either player may have committed to
+   * * `max_stake` - Highest stake either player may have commi
+   */
+  start_hidden_stake_game: ({session_id, player1, player2, min_stake, max_stake, player1_stake, player2_stake}: {session_id: u32, player1: string, player2: string, min_stake: i128, max_stake: i128, player1_stake: StakeCommitment, player2_stake: StakeCommitment}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_ranked_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a ranked match, open only to players who prove they are unique.
+   * 
+   * Each player supplies a uniqueness proof against the current
+   * `SybilGate`: that they hold an identity in its identity set, and that
+   * `nullifier` is that identity's nullifier for its bracket. The
+   * nullifiers are consumed, so an identity plays one ranked game per
+   * bracket, and a second address of the same person returns
+   * `IdentityAlreadyPlayed`. The game itself then plays as one started
+   * with `start_game`.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1
+   * * `player2_points` - Betting amount for player 2
+   * * `player1_identity` - Player 1's uniqueness proof
+   * * `player2_identity` - Player 2's uniqueness proof
+   */
+  start_ranked_game: ({session_id, player1, player2, player1_points, player2_points, player1_identity, player2_identity}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, player1_identity: UniquenessProof, player2_identity: UniquenessProof}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_bracket_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a match restricted to players rated within `bracket`.
+   * 
+   * Each player proves their rating lies in the bracket against the
+   * ratings root the Game Hub currently publishes, without revealing it.
+   * The proof's public inputs are `[ratings_root, min_rating, max_rating,
+   * rating_key]`, checked by the verifier set with `set_rating_verifier`
+   * (without one, `VerifierNotConfigured`). The game itself then plays as
+   * one started with `start_game`.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier
+   * * `player1` - First player address
+   * * `player2` - Second player address
+   * * `player1_points` - Betting amount for player 1
+   * * `player2_points` - Betting amount for player 2
+   * * `bracket` - Inclusive rating range both players must be within
+   * * `player1_proof` - Player 1's rating proof
+   * * `player2_proof` - Player 2's rating proof
+   */
+  start_bracket_game: ({session_id, player1, player2, player1_points, player2_points, bracket, player1_proof, player2_proof}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, bracket: RatingBracket, player1_proof: Buffer, player2_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_tactic transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit tactical choice with ZK proof.
+   * 
+   * **ZK Proof validates:**
+   * - tactic ∈ [0-3]
+   * - player identity
+   * - hasn't already submitted
+   * 
+   * The tactic is not part of the submission: only its hash is stored,
+   * and the player opens it with `reveal_tactic` once both have submitted.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `inputs` - The proof's public inputs:
+   * - `session_id` - must equal `session_id`
+   * - `player_binding` - must equal `get_player_binding(player)`
+   * - `valid_until_ledger` - Last ledger the proof may be submitted in,
+   * at most `get_proof_freshness_window()` ledgers ahead; 0 for no
+   * expiry, only accepted while no window is set
+   * - `commitment` - Poseidon2(tactic, secret, session_id), the circuit's output
+   * - `nullifier` - Poseidon2(secret, session_id), consumed on success so
+   * the same proof cannot be submitted twice
+   * - `tactic_hash` - `tactic_hash(tactic, secret, session_id)`, where
+   * tactic is the choice (0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut)
+   * * `proof` - ZK proof bytes (Noir-generat
+   */
+  submit_tactic: ({session_id, player, inputs, proof}: {session_id: u32, player: string, inputs: PublicInputs, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_tactic_envelope transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit a tactic with a versioned proof.
+   * 
+   * As `submit_tactic`, but the proof comes in a `ProofEnvelope`: its
+   * version must lie between `get_min_proof_version()` and
+   * `PROOF_VERSION`, and its system must be the game's.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `inputs` - The proof's public inputs (as for `submit_tactic`)
+   * * `envelope` - The proof with its version and proof system
+   * 
+   * Fails with `UnsupportedProofVersion` for a retired or unknown
+   * version, and `WrongProofSystem` for another backend's proof.
+   */
+  submit_tactic_envelope: ({session_id, player, inputs, envelope}: {session_id: u32, player: string, inputs: PublicInputs, envelope: ProofEnvelope}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_tactic_signed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit a tactic on a player's behalf using their ed25519 signature.
+   * 
+   * Any relayer can call this and pay the fee, so players without XLM can
+   * still play. The player signs the XDR of `SignedSubmission` (see
+   * `submission_payload`) with their account's ed25519 key; the nonce must
+   * equal `get_nonce(player)` and is consumed on success.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address (must be a `G...` account)
+   * * `inputs` - The proof's public inputs (as for `submit_tactic`)
+   * * `proof` - ZK proof bytes
+   * * `nonce` - The player's current submission nonce
+   * * `expiration_ledger` - Last ledger sequence the signature is valid for
+   * * `signature` - ed25519 signature over the payload
+   * 
+   * Traps if the signature does not verify.
+   */
+  submit_tactic_signed: ({session_id, player, inputs, proof, nonce, expiration_ledger, signature}: {session_id: u32, player: string, inputs: PublicInputs, proof: Buffer, nonce: u64, expiration_ledger: u32, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submission_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bytes a player signs for `submit_tactic_signed`
+   * 
+   * Binds the signature to this contract, the session, the proof's public
+   * inputs and the exact proof (by keccak256 hash), so a relayer cannot
+   * alter any of them.
+   */
+  submission_payload: ({session_id, player, inputs, proof, nonce, expiration_ledger}: {session_id: u32, player: string, inputs: PublicInputs, proof: Buffer, nonce: u64, expiration_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_player_binding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The `player_binding` public input a proof for `player` must carry
+   */
+  get_player_binding: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a is_nullifier_used transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether `nullifier` has already been consumed by a submission
+   */
+  is_nullifier_used: ({nullifier}: {nullifier: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_rating_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The `rating_key` public input a rating proof for `player` must carry
+   */
+  get_rating_key: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a is_identity_used transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether an identity has already played a ranked game in `bracket`
+   */
+  is_identity_used: ({bracket, nullifier}: {bracket: u32, nullifier: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Next nonce `submit_tactic_signed` expects for `player`
+   */
+  get_nonce: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a commit_tactic transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to a tactic without a proof, in a `CommitReveal` game.
+   * 
+   * Nothing checks the tactic until `reveal_tactic`, so a player who
+   * commits to an out-of-range tactic simply cannot reveal.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `hash` - `tactic_hash(tactic, salt, session_id)` for a random
+   * 32-byte salt the player keeps until the reveal
+   * 
+   * Signed by `player`, or by their session key (as for `submit_tactic`).
+   */
+  commit_tactic: ({session_id, player, hash}: {session_id: u32, player: string, hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_tactic transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a submitted tactic.
+   * 
+   * Only accepted once both players have submitted, so neither can see
+   * the other's tactic before committing to their own.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `tactic` - The tactic proven at submission (0-3)
+   * * `secret` - The player secret proven with it (32-byte big-endian
+   * field element), or the salt committed to in a `CommitReveal` game
+   * 
+   * Signed by `player`, or by their session key (as for `submit_tactic`).
+   * Fails with `InvalidReveal` unless `tactic_hash(tactic, secret,
+   * session_id)` equals the hash the player's proof committed to.
+   */
+  reveal_tactic: ({session_id, player, tactic, secret}: {session_id: u32, player: string, tactic: u32, secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_rounds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit one recursive proof for every round of a multi-round match.
+   * 
+   * The proof verifies one tactic proof per round (see `submit_tactic`)
+   * and exposes their tactic hashes in round order; only the hashes are
+   * stored, and the player opens them with `reveal_rounds` once both have
+   * submitted. Public inputs are `RoundInputs::to_fields`, checked by the
+   * verifier set with `set_rounds_verifier` (without one,
+   * `VerifierNotConfigured`).
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `inputs` - The proof's public inputs; `session_id`,
+   * `player_binding` and `valid_until_ledger` are checked as for
+   * `submit_tactic`, and there must be one tactic hash per round
+   * * `proof` - Recursive proof bytes
+   * 
+   * Signed by `player`, or by their session key (as for `submit_tactic`).
+   */
+  submit_rounds: ({session_id, player, inputs, proof}: {session_id: u32, player: string, inputs: RoundInputs, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_team_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit a team's move in a 2v2 match.
+   * 
+   * Carries one tactic hash per member, in member order, and a single
+   * BLS12-381 signature aggregating each member's signature of their
+   * `team_move_payload`. The signature is its own authorization, so
+   * anyone holding it may submit the move.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `captain` - Captain of the moving team
+   * * `tactic_hashes` - `tactic_hash(tactic, secret, session_id)` of each
+   * member, for a random 32-byte secret the member keeps until the reveal
+   * * `signature` - Aggregate of the members' signatures (G2, uncompressed)
+   * 
+   * Fails with `InvalidTeamSignature` unless every member signed their
+   * own hash, and `InvalidCommitment` for a hash that is not a field
+   * element or copies one of the opposing team's.
+   */
+  submit_team_move: ({session_id, captain, tactic_hashes, signature}: {session_id: u32, captain: string, tactic_hashes: Array<Buffer>, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a team_move_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bytes a team member signs for `submit_team_move`
+   * 
+   * Names the contract, the session and the member, so a signature cannot
+   * be replayed elsewhere or credited to a teammate.
+   */
+  team_move_payload: ({session_id, player, tactic_hash}: {session_id: u32, player: string, tactic_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_teams transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get both teams of a 2v2 match, or `None` for any other game
+   */
+  get_teams: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Teams>>>
+
+  /**
+   * Construct and simulate a reveal_rounds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal every round of a multi-round match.
+   * 
+   * Only accepted once both players have submitted (`submit_rounds`).
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `tactics` - The tactic of each round, in order
+   * * `secrets` - The player secret of each round's inner proof
+   * 
+   * Signed by `player`, or by their session key (as for `submit_tactic`).
+   * Fails with `InvalidReveal` unless `tactic_hash(tactics[i],
+   * secrets[i], session_id)` equals the i-th submitted round hash for
+   * every round.
+   */
+  reveal_rounds: ({session_id, player, tactics, secrets}: {session_id: u32, player: string, tactics: Array<u32>, secrets: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal one round of a committed strategy.
+   * 
+   * Rounds are opened in order; the leaf `tactic_hash(tactic, salt,
+   * session_id)` must sit at index `round` of the tree whose root the
+   * player committed to (see `strategy_proof`). The inclusion proof is its
+   * own authorization, so anyone holding it may submit the reveal.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player whose round is revealed
+   * * `round` - Round index, equal to the number already revealed
+   * * `tactic` - The tactic committed for the round
+   * * `salt` - The salt of the round's leaf
+   * * `proof` - Sibling hashes from the leaf up to the root
+   * 
+   * Fails with `InvalidReveal` unless the proof opens the player's root.
+   */
+  reveal_round: ({session_id, player, round, tactic, salt, proof}: {session_id: u32, player: string, round: u32, tactic: u32, salt: Buffer, proof: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_multi_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Round hashes and revealed tactics of a multi-round game, if
+   * `session_id` is one
+   */
+  get_multi_round: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MultiRound>>>
+
+  /**
+   * Construct and simulate a reveal_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a hidden stake.
+   * 
+   * Only accepted once both players have submitted their tactics, so a
+   * stake cannot influence the opponent's choice. `resolve_match` fails
+   * with `StakeNotRevealed` until both stakes are revealed.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Player address
+   * * `stake` - The stake committed to at `start_hidden_stake_game`
+   * * `salt` - The salt it was hashed with
+   * 
+   * Signed by `player`, or by their session key (as for `submit_tactic`).
+   * Fails with `InvalidReveal` unless `stake_hash(stake, salt,
+   * session_id)` equals the player's committed hash.
+   */
+  reveal_stake: ({session_id, player, stake, salt}: {session_id: u32, player: string, stake: i128, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_hidden_stakes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bounds and committed stakes of a hidden-stake game, if `session_id`
+   * is one
+   */
+  get_hidden_stakes: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<HiddenStakes>>>
+
+  /**
+   * Construct and simulate a get_odds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Odds of a `start_game_with_odds` match, if `session_id` is one
+   */
+  get_odds: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Odds>>>
+
+  /**
+   * Construct and simulate a get_rating_bracket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rating range of a `start_bracket_game` match, if `session_id` is one
+   */
+  get_rating_bracket: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<RatingBracket>>>
+
+  /**
+   * Construct and simulate a authorize_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Authorize a session key to submit on the player's behalf.
+   * 
+   * Lets smart-wallet (e.g. passkey) players approve once per match: the
+   * wallet signs this grant, and the session key signs `submit_tactic`.
+   * The grant only applies to `session_id`, only while the player's stake
+   * is at most `max_stake`, and only until `expiration_ledger`.
+   * 
+   * # Arguments
+   * * `session_id` - Session the key is scoped to
+   * * `player` - Player granting the key
+   * * `key` - Address that may sign submissions
+   * * `max_stake` - Maximum player stake the key may act for
+   * * `expiration_ledger` - Last ledger sequence the grant is valid for
+   */
+  authorize_session_key: ({session_id, player, key, max_stake, expiration_ledger}: {session_id: u32, player: string, key: string, max_stake: i128, expiration_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke the player's session key for a session
+   * 
+   * # Arguments
+   * * `session_id` - Session the key is scoped to
+   * * `player` - Player that granted the key
+   */
+  revoke_session_key: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the player's session key grant for a session, if any
+   * 
+   * # Arguments
+   * * `session_id` - Session the key is scoped to
+   * * `player` - Player that granted the key
+   */
+  get_session_key: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionKeyGrant>>>
+
+  /**
+   * Construct and simulate a resolve_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve match after both players submit and reveal tactics.
+   * Computes scores using strategic matrix and determines the outcome; a
+   * draw stands or is decided per the game's `TiebreakPolicy`.
+   * 
+   * Multi-round games are won by the player who wins more rounds (see
+   * `start_multi_round_game`).
+   * 
+   * Hidden-stake games also need both stakes revealed (`reveal_stake`);
+   * they are recorded on the game and reported to the hub with the result.
+   * 
+   * For batch-verified games both proofs are checked here first. A player
+   * whose proof is invalid forfeits (the game is settled without scores),
+   * even before they reveal; if both are invalid the call fails with
+   * `InvalidProof`. Only proofs that verify have their nullifiers
+   * consumed. A proof point off the curve makes the host trap instead (see
+   * `groth16`).
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * 
+   * # Returns
+   * * `Outcome` - Who won, or `Draw`
+   */
+  resolve_match: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Outcome>>>
+
+  /**
+   * Construct and simulate a resolve_with_receipt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve a match scored by an off-chain simulation.
+   * 
+   * For games started while a simulation image ID was set (see
+   * `set_simulation_image_id`), which `resolve_match` refuses with
+   * `ReceiptRequired`. The receipt must be for this session and the
+   * revealed tactics, and its seal is checked against the game's image ID
+   * by the verifier set with `set_receipt_verifier` (without one,
+   * `VerifierNotConfigured`). Its scores then decide the outcome as usual.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `journal` - Journal committed by the simulation (see `zkvm.rs`)
+   * * `seal` - The receipt's proof
+   * 
+   * # Returns
+   * * `Outcome` - Who won, or `Draw`
+   */
+  resolve_with_receipt: ({session_id, journal, seal}: {session_id: u32, journal: Buffer, seal: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Outcome>>>
+
+  /**
+   * Construct and simulate a resolve_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve several finished matches in one transaction
+   * 
+   * Each session is resolved as `resolve_match` would, and one that
+   * fails (not yet revealed, not found, ...) is reported with its error
+   * code rather than failing the rest. Games scored by a simulation
+   * still need `resolve_with_receipt`.
+   * 
+   * # Arguments
+   * * `session_ids` - From 1 to `MAX_BATCH_SIZE` sessions (else
+   * `InvalidBatch`)
+   * 
+   * # Returns
+   * * A `BatchResult` per session, in order: `Resolved` or `Failed`
+   */
+  resolve_batch: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<BatchResult>>>>
+
+  /**
+   * Construct and simulate a claim_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out what several settled games' escrows still hold
+   * 
+   * Each session is claimed as `claim_winnings` (for a won pot) or
+   * `claim_refund` (otherwise) would, and one that fails is reported
+   * with its error code rather than failing the rest. Anyone may call
+   * it.
+   * 
+   * # Arguments
+   * * `session_ids` - From 1 to `MAX_BATCH_SIZE` sessions (else
+   * `InvalidBatch`)
+   * 
+   * # Returns
+   * * A `BatchResult` per session, in order: `Claimed` or `Failed`
+   */
+  claim_batch: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<BatchResult>>>>
+
+  /**
+   * Construct and simulate a resolve_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a game an opponent abandoned.
+   * 
+   * Once the game's `submit_deadline_ledger` has passed, the player who
+   * submitted wins by forfeit if the opponent never did, and the result is
+   * reported to the Game Hub as for `resolve_match`. Likewise, once the
+   * `resolve_deadline_ledger` has passed, a player who revealed all their
+   * game needs (every round, and any hidden stake) wins if the opponent
+   * did not. Other games return `NoForfeit`; they are resolved as usual.
+   * In batch-verified games the winner's deferred proof is checked first,
+   * and an invalid one returns `InvalidProof`. Anyone may call it.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * 
+   * # Returns
+   * * `Address` - Winner address
+   */
+  resolve_timeout: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a cancel_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Call off a game before either player has submitted.
+   * 
+   * Signed by both players (just the player, against the house). The
+   * game ends `Cancelled` and the hub is
+   * told it ended drawn, which hands both players their points back; a
+   * `GameCancelled` event is published. Nothing is added to match
+   * history. Returns `InvalidPhase` once anyone has submitted.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   */
+  cancel_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a retry_hub_notify transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Report a settled game's result to a hub that missed it.
+   * 
+   * Settlement does not wait on the hub: if the hub trapped when
+   * `resolve_match`, `resolve_with_receipt` or `resolve_timeout` called
+   * it, the result is kept here with `hub_notified` false. This reports
+   * it again, say once the hub is back or `set_hub` has pointed the
+   * contract at a working one. Returns `HubNotNotified` if the hub still
+   * rejects it, `GameNotSettled` for a game still in play, and nothing
+   * for a game the hub already has. Anyone may call it.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   */
+  retry_hub_notify: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a abandon_expired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close a session whose game entry expired before it was resolved.
+   * 
+   * The hub was told the session started but will never hear how it
+   * ended; this drops the contract's open record of it and publishes a
+   * `SessionAbandoned` event with the players and points the hub holds,
+   * so they can be returned. Returns `SessionNotExpired` while the game
+   * still exists (resolve it, or use `resolve_timeout`), and
+   * `GameNotFound` for sessions with no open record. Anyone may call it.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * 
+   * # Returns
+   * * `OpenSession` - The record that was closed
+   */
+  abandon_expired: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<OpenSession>>>
+
+  /**
+   * Construct and simulate a purge_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delete a settled game's storage once it has been archived.
+   * 
+   * Settlement copies the result into both players' match history (see
+   * `get_match_record`); after that the game entry and its per-session
+   * entries (rounds, hidden stakes, teams, rating bracket, tiebreak
+   * entropy, deferred proofs and session key grants) only serve
+   * `get_game`, `get_attestation` and repeated `resolve_match` calls.
+   * Deleting them stops their rent now instead of at expiry. Consumed
+   * nullifiers are kept: they are what stops the game's proofs being
+   * replayed into a later game under the same session ID, and they
+   * expire on their own.
+   * 
+   * Signed by `player`. Returns `GameNotSettled` for a game still in
+   * play, and `HubNotNotified` while the hub is missing its result (see
+   * `retry_hub_notify`).
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `player` - Either player of the game
+   */
+  purge_game: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a extend_game_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Keep a game's storage alive for at least `ledgers` more ledgers.
+   * 
+   * Game entries live `GAME_TTL_LEDGERS` from their last write, which a
+   * slow-moving game can outlast. Anyone (say, a frontend keeper) may
+   * bump the game entry and its per-session entries; the caller pays the
+   * rent and no signature is needed. The hub's open session record is
+   * bumped to stay `GAME_TTL_LEDGERS` ahead, so `abandon_expired` can
+   * still find it. Session key grants keep the expiry they were granted
+   * with, and deadlines are unchanged.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * * `ledgers` - Ledgers to keep the game for, up to the network's
+   * maximum TTL (else `InvalidTtl`)
+   */
+  extend_game_ttl: ({session_id, ledgers}: {session_id: u32, ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_global_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the contract-wide game totals, e.g. for a health dashboard
+   */
+  get_global_stats: (options?: MethodOptions) => Promise<AssembledTransaction<GlobalStats>>
+
+  /**
+   * Construct and simulate a get_player_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get `player`'s lifetime record, all zero before their first settled
+   * game
+   */
+  get_player_stats: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<PlayerStats>>
+
+  /**
+   * Construct and simulate a get_match_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how many settled games `player` has in their match history
+   */
+  get_match_count: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_match_record transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a game from `player`'s match history.
+   * 
+   * Results are archived in persistent storage when a game is settled
+   * (`resolve_match`, `resolve_with_receipt` or `resolve_timeout`), so
+   * they outlive the game entry `get_game` reads.
+   * 
+   * # Arguments
+   * * `player` - Either player of the game
+   * * `index` - 0 for their first settled game, up to `get_match_count`
+   */
+  get_match_record: ({player, index}: {player: string, index: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MatchRecord>>>
+
+  /**
+   * Construct and simulate a get_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Page through `player`'s match history, newest first, without an
+   * external indexer
+   * 
+   * Games whose record has been archived by the network are skipped
+   * until restored.
+   * 
+   * # Arguments
+   * * `player` - Whose history to list
+   * * `cursor` - `None` for the newest games, else the `index` of the
+   * last game of the previous page
+   * * `limit` - Games per page, capped at `MAX_HISTORY_PAGE`
+   */
+  get_history: ({player, cursor, limit}: {player: string, cursor: Option<u32>, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<GameSummary>>>
+
+  /**
+   * Construct and simulate a get_deadlines transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a game's submit and reveal deadlines, e.g. to show countdowns.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   */
+  get_deadlines: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Deadlines>>>
+
+  /**
+   * Construct and simulate a get_last_activity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ledger a game last changed in: started, a move submitted or
+   * revealed, settled, or its result reported to the hub. Keepers can
+   * compare it against `get_deadlines` to find games to `resolve_timeout`
+   * from contract state alone. Games migrated from schema 1 read 0 until
+   * their next change.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   */
+  get_last_activity: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_open_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Session IDs of `player`'s games still in play (unsettled and not
+   * expired), oldest first, so a wallet can list its active matches
+   * without an indexer. Games that expire stay indexed until
+   * `abandon_expired` closes them, but are not listed
+   */
+  get_open_games: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_open_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The open record of a session the hub has not yet heard the end of
+   */
+  get_open_session: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<OpenSession>>>
+
+  /**
+   * Construct and simulate a simulate_score transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Score `tactic1` against `tactic2` with the matrix games are settled
+   * by, so clients need not keep their own copy. Returns `InvalidTactic`
+   * for anything but 0-3
+   */
+  simulate_score: ({tactic1, tactic2}: {tactic1: u32, tactic2: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [u32, u32]>>>
+
+  /**
+   * Construct and simulate a get_score_matrix transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the whole score matrix, ordered by `tactic1` then `tactic2`
+   */
+  get_score_matrix: (options?: MethodOptions) => Promise<AssembledTransaction<Array<ScoreEntry>>>
+
+  /**
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a settled game's full state, including scores and tactics.
+   * 
+   * Returns `GameNotSettled` while the game is in play; use
+   * `get_game_public` to follow it until then.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * 
+   * # Returns
+   * * `Game` - Complete game state
+   */
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+
+  /**
+   * Construct and simulate a get_game_public transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a game's state with revealed tactics and proof hashes withheld
+   * until it is settled, so clients polling an in-progress match do not
+   * show either player the other's move.
+   * 
+   * This only shapes what the view returns: contract storage itself is
+   * public on the ledger.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   */
+  get_game_public: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PublicGame>>>
+
+  /**
+   * Construct and simulate a get_attestation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Canonical, Merkle-committed attestation of a settled session.
+   * 
+   * Bridges and partner games on other chains verify `root` (or a single
+   * leaf against it) instead of trusting a relayer's summary. See
+   * `attestation.rs` for the byte layout.
+   * 
+   * # Arguments
+   * * `session_id` - Game session ID
+   * 
+   * # Returns
+   * * `Attestation` - Leaves and keccak256 Merkle root
+   */
+  get_attestation: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Attestation>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   * 
+   * # Returns
+   * * `Address` - The admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address proposed as the next admin, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose handing the admin role to `new_admin`
+   * 
+   * Nothing changes until `new_admin` signs `accept_admin`, so a mistyped
+   * address cannot take the admin role (and with it, upgrades) out of
+   * reach. Proposing again replaces the pending proposal. Under an
+   * admin council, both proposing and accepting take its approval.
+   * 
+   * # Arguments
+   * * `new_admin` - The proposed admin address
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take over as admin after being proposed with `propose_admin`
+   * 
+   * Signed by the pending admin, and approved by the admin council if
+   * one is set. Returns `NoPendingAdmin` if nobody has been proposed.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether `account` holds `role`, directly or through the `Admin` role.
+   * The admin address itself is not listed but holds every role.
+   */
+  has_role: ({account, role}: {account: string, role: Role}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant `role` to `account`, e.g. `Pauser` to an ops bot
+   * 
+   * # Arguments
+   * * `account` - The account to delegate to
+   * * `role` - The role to grant
+   */
+  grant_role: ({account, role}: {account: string, role: Role}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take `role` back from `account`. Roles held through `Admin` stay
+   * until `Admin` itself is revoked.
+   * 
+   * # Arguments
+   * * `account` - The account to revoke from
+   * * `role` - The role to revoke
+   */
+  revoke_role: ({account, role}: {account: string, role: Role}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin_council transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin council, if one is set
+   */
+  get_admin_council: (options?: MethodOptions) => Promise<AssembledTransaction<Option<AdminCouncil>>>
+
+  /**
+   * Construct and simulate a set_admin_council transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Put the calls a leaked admin key could do most harm with under an
+   * M-of-N admin council: the hub, pausing, upgrades, the admin
+   * transfer, verifiers, roles, disputes, fees and the oracle
+   * 
+   * Once a council is set, each `CouncilAction` call goes through only
+   * after `threshold` members have approved that exact call with
+   * `approve_action`, and the admin's signature no longer does. Holders
+   * of the role a setter takes can still call it. Other admin setters
+   * keep needing the admin. Setting the first council takes the admin;
+   * changing or removing it (`None`) takes the current council's
+   * approval.
+   * 
+   * # Arguments
+   * * `council` - Up to `MAX_COUNCIL_SIZE` distinct members and a
+   * threshold from 1 to their number (else `InvalidCouncil`), or
+   * `None` to go back to the admin alone
+   */
+  set_admin_council: ({council}: {council: Option<AdminCouncil>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_approvals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Members of the admin council who have approved `action` and not yet
+   * seen it carried out
+   */
+  get_approvals: ({action}: {action: CouncilAction}, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a approve_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve `action` as a member of the admin council
+   * 
+   * Signed by `member`. The action can be carried out once `threshold`
+   * members have approved it, which uses the approvals up. Approvals
+   * lapse if the action is not carried out within `GAME_TTL_LEDGERS`.
+   * 
+   * # Arguments
+   * * `member` - Approving council member (else `NotCouncilMember`)
+   * * `action` - The exact call being approved, arguments included
+   */
+  approve_action: ({member, action}: {member: string, action: CouncilAction}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   * 
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
+   * 
+   * The new hub must answer `version()` with at least
+   * `MIN_HUB_VERSION`, else `InvalidHub`, so a mistyped address is
+   * caught here rather than by every later `start_game`. Needs the admin
+   * council's approval once one is set (see `set_admin_council`).
+   * 
+   * # Arguments
+   * * `new_hub` - The new GameHub contract address
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the external ZK verifier contract address, if configured
+   * 
+   * # Returns
+   * * `Option<Address>` - The verifier contract address
+   */
+  get_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delegate proof verification to an external verifier contract
+   * 
+   * # Arguments
+   * * `verifier` - Contract implementing `verify(proof, public_inputs) -> bool`
+   */
+  set_verifier: ({verifier}: {verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_verification_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Groth16 verification key, if one is set
+   */
+  get_verification_key: (options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a set_verification_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the Groth16 verification key used by `submit_tactic`
+   * 
+   * Lets the circuit be upgraded after a new trusted setup without
+   * redeploying. Only the length is checked here (six public inputs).
+   * A key source set with `set_vk_source` takes precedence. An admin
+   * council approves the key by its sha256.
+   * 
+   * # Arguments
+   * * `vk` - Serialized key, see `src/groth16.rs` for the layout
+   */
+  set_verification_key: ({vk}: {vk: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_vk_source transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the registry entry the verification key is resolved from, if set
+   */
+  get_vk_source: (options?: MethodOptions) => Promise<AssembledTransaction<Option<VkSource>>>
+
+  /**
+   * Construct and simulate a set_vk_source transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve the Groth16 verification key from a `vk-registry` entry
+   * 
+   * Replaces the key stored with `set_verification_key` while set, so
+   * every deployment pinned to the same entry verifies against one
+   * registered key. The entry is fetched and length-checked here; moving
+   * to a new setup means pointing at its version.
+   * 
+   * # Arguments
+   * * `source` - Registry, circuit ID and version, or `None` to go back
+   * to the stored key
+   */
+  set_vk_source: ({source}: {source: Option<VkSource>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_proof_system transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the proof system new games are started with
+   */
+  get_proof_system: (options?: MethodOptions) => Promise<AssembledTransaction<ProofSystem>>
+
+  /**
+   * Construct and simulate a set_proof_system transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the proof system new games are started with
+   * 
+   * Games already started keep the backend they were created with.
+   * 
+   * # Arguments
+   * * `proof_system` - Backend for subsequent `start_game` calls
+   */
+  set_proof_system: ({proof_system}: {proof_system: ProofSystem}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_max_proof_size transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the largest proof, in bytes, a submission may carry
+   */
+  get_max_proof_size: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_max_proof_size transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the largest proof, in bytes, a submission may carry
+   * 
+   * Larger proofs fail with `ProofTooLarge` before they are hashed or
+   * stored, so they cannot inflate a transaction's cost. Raise it before
+   * moving to a backend with bigger proofs.
+   * 
+   * # Arguments
+   * * `size` - Limit in bytes (`DEFAULT_MAX_PROOF_SIZE` until set)
+   */
+  set_max_proof_size: ({size}: {size: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_timeout_ledgers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ledgers players have to submit after a game starts
+   * (`DEFAULT_TIMEOUT_LEDGERS` until set)
+   */
+  get_timeout_ledgers: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_timeout_ledgers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the ledgers players have to submit after a game starts, after
+   * which `resolve_timeout` settles abandoned games. Players then have as
+   * long again to reveal.
+   * 
+   * Games keep the deadlines they started with. Both windows must end
+   * before the game's storage would expire, else `InvalidDeadlines`.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+   * * `ledgers` - Timeout in ledgers, below half the game storage TTL
+   */
+  set_timeout_ledgers: ({caller, ledgers}: {caller: string, ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_min_proof_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the oldest proof version `submit_tactic_envelope` accepts
+   * (`BARE_PROOF_VERSION` until set)
+   */
+  get_min_proof_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_min_proof_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the oldest proof version accepted
+   * 
+   * Raise it once provers have moved to a new circuit to stop accepting
+   * proofs from the old one, including bare `submit_tactic` proofs.
+   * 
+   * # Arguments
+   * * `version` - At most `PROOF_VERSION`, or `UnsupportedProofVersion`
+   */
+  set_min_proof_version: ({version}: {version: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_ultrahonk_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the UltraHonk verifier contract address, if configured
+   */
+  get_ultrahonk_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_ultrahonk_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verifier contract for games using `ProofSystem::UltraHonk`
+   * 
+   * # Arguments
+   * * `verifier` - Contract implementing `verify_proof(public_inputs, proof_bytes)`
+   */
+  set_ultrahonk_verifier: ({verifier}: {verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_stake_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the hidden-stake range proof verifier, if configured
+   */
+  get_stake_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_stake_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verifier for `start_hidden_stake_game` range proofs
+   * 
+   * # Arguments
+   * * `verifier` - Contract implementing `verify(proof, public_inputs) -> bool`
+   */
+  set_stake_verifier: ({verifier}: {verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_rating_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the rating proof verifier for bracket games, if configured
+   */
+  get_rating_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_rating_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verifier for `start_bracket_game` rating proofs
+   * 
+   * # Arguments
+   * * `verifier` - Contract implementing `verify(proof, public_inputs) -> bool`
+   */
+  set_rating_verifier: ({verifier}: {verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_sybil_gate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the anti-sybil gate for ranked games, if set
+   */
+  get_sybil_gate: (options?: MethodOptions) => Promise<AssembledTransaction<Option<SybilGate>>>
+
+  /**
+   * Construct and simulate a set_sybil_gate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the anti-sybil gate `start_ranked_game` checks players against
+   * 
+   * Publish a new identity root as identities register, and a new
+   * bracket when a ranking period starts. Nullifiers of earlier brackets
+   * are kept but no longer checked.
+   * 
+   * # Arguments
+   * * `gate` - Verifier, identity root and bracket, or `None` to disable
+   * ranked games
+   */
+  set_sybil_gate: ({gate}: {gate: Option<SybilGate>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_simulation_image_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the image ID new games are scored by, if one is set
+   */
+  get_simulation_image_id: (options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a set_simulation_image_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Score new games with an off-chain simulation instead of the matrix
+   * 
+   * Games started while an image ID is set must be resolved with
+   * `resolve_with_receipt`, using a receipt of that zkVM program. Games
+   * already started keep the scoring they were created with.
+   * 
+   * # Arguments
+   * * `image_id` - zkVM image ID of the simulation, or `None` for the matrix
+   */
+  set_simulation_image_id: ({image_id}: {image_id: Option<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_receipt_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the zkVM receipt verifier contract address, if configured
+   */
+  get_receipt_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_receipt_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verifier for `resolve_with_receipt` seals
+   * 
+   * # Arguments
+   * * `verifier` - Contract implementing
+   * `verify_receipt(seal, image_id, journal_digest)`
+   */
+  set_receipt_verifier: ({verifier}: {verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_rounds_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the verifier contract for multi-round recursive proofs, if one is set
+   */
+  get_rounds_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_rounds_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verifier contract for multi-round recursive proofs
+   * 
+   * # Arguments
+   * * `verifier` - Contract exposing `verify(proof, public_inputs)` for
+   * the recursive rounds circuit
+   */
+  set_rounds_verifier: ({verifier}: {verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_tiebreak_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the tiebreak policy new games are started with
+   */
+  get_tiebreak_policy: (options?: MethodOptions) => Promise<AssembledTransaction<TiebreakPolicy>>
+
+  /**
+   * Construct and simulate a set_tiebreak_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how drawn matches are decided for new games
+   * 
+   * Games already started keep the policy they were created with.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+   * * `policy` - Policy for subsequent `start_game` calls
+   */
+  set_tiebreak_policy: ({caller, policy}: {caller: string, policy: TiebreakPolicy}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_proof_freshness_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the proof freshness window, if one is set
+   */
+  get_proof_freshness_window: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a set_proof_freshness_window transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how far ahead a proof's `valid_until_ledger` may be
+   * 
+   * While a window is set, every proof must carry a non-zero
+   * `valid_until_ledger` no more than `window` ledgers after the ledger it
+   * is submitted in. `None` lifts the limit (expiries are still enforced).
+   * 
+   * # Arguments
+   * * `window` - Maximum validity in ledgers, or `None` for no limit
+   */
+  set_proof_freshness_window: ({window}: {window: Option<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_batch_verification transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether new Groth16 games defer proof verification to `resolve_match`
+   */
+  get_batch_verification: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_batch_verification transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Defer proof verification of new Groth16 games to `resolve_match`
+   * 
+   * Both proofs of a match are then checked with one batched pairing
+   * check instead of one each at submission, at the cost of catching an
+   * invalid proof only at resolution (its player forfeits). Games already
+   * started keep the mode they were created with.
+   * 
+   * # Arguments
+   * * `enabled` - Mode for subsequent `start_game` calls
+   */
+  set_batch_verification: ({enabled}: {enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_auto_resolve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether new games settle themselves on their last reveal
+   */
+  get_auto_resolve: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_auto_resolve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle new games as soon as their last reveal lands
+   * 
+   * Tactics stay hidden until both players have submitted and revealed,
+   * so the reveal completing a game (a tactic, the last round or, in
+   * hidden-stake games, the last stake) scores it and calls the hub in
+   * the same transaction, with no separate `resolve_match`. That reveal
+   * then fails if settling does, though not when only the hub call fails
+   * (see `retry_hub_notify`). Games scored by
+   * a zkVM receipt still need `resolve_with_receipt`. Games already
+   * started keep the mode they were created with.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+   * * `enabled` - Mode for subsequent `start_game` calls
+   */
+  set_auto_resolve: ({caller, enabled}: {caller: string, enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_wager_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the points a player may stake on a game (0 to `i128::MAX` until
+   * set)
+   */
+  get_wager_limits: (options?: MethodOptions) => Promise<AssembledTransaction<WagerLimits>>
+
+  /**
+   * Construct and simulate a set_wager_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the points a player may stake on games started from now on
+   * 
+   * Every `start_*` call checks both players' points, returning
+   * `WagerTooSmall` or `WagerTooLarge` for points outside the limits.
+   * Hidden-stake games check their `[min_stake, max_stake]` range
+   * instead, since the stakes themselves are not yet known. Negative
+   * points are always rejected; a `min_wager` of 1 also rules out
+   * games played for nothing.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `min_wager` - Fewest points a player may stake, at least 0
+   * * `max_wager` - Most points a player may stake, at least `min_wager`
+   * (else `InvalidWagerLimits`)
+   */
+  set_wager_limits: ({caller, min_wager, max_wager}: {caller: string, min_wager: i128, max_wager: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_wager_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the token new games are wagered in (None = points only, nothing
+   * is escrowed)
+   */
+  get_wager_token: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_native_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the native XLM Stellar Asset Contract on this network, for
+   * `start_native_game` or `set_wager_token`
+   */
+  get_native_token: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_wager_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the token games started from now on are wagered in
+   * 
+   * While set, every `start_*` call moves each player's points, as units
+   * of `token`, from the player into the contract, where they stay until
+   * the game settles (see `get_escrow`). Players authorize that transfer
+   * along with the start call. Hidden-stake games lock `max_stake` from
+   * both. Games already started keep the token they escrowed.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `token` - A Stellar Asset Contract or other SEP-41 token, or `None`
+   * to stop escrowing
+   */
+  set_wager_token: ({caller, token}: {caller: string, token: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the tokens held in escrow for a game, if it was wagered in a token
+   */
+  get_escrow: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Escrow>>>
+
+  /**
+   * Construct and simulate a claim_winnings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out winnings held when a game settled
+   * 
+   * `resolve_match` pushes each player's share of the pot to them; if the
+   * token refuses (for example a smart wallet or account not yet able to
+   * hold it), the share stays in escrow. Once the player can receive the
+   * token, anyone may call this to send it on. Shares the token still
+   * refuses stay held.
+   * 
+   * # Returns
+   * * The total amount paid out
+   */
+  claim_winnings: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a claim_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out refunds a game's escrow still owes
+   * 
+   * Draws, cancellations and timeouts refund their escrow as they
+   * settle (see `get_escrow`), holding any payout the token refuses.
+   * This sends held refunds on, and refunds a game whose entry expired
+   * before it was resolved, returning each player what they locked.
+   * It is idempotent: with nothing left to refund (or once a refused
+   * payout still cannot be sent) it pays nothing and returns 0. Returns
+   * `GameNotSettled` for a game still in play. Anyone may call it.
+   * 
+   * # Returns
+   * * The total amount refunded by this call
+   */
+  claim_refund: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a deposit_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prepay a token balance to start wagered games against
+   * 
+   * `amount` of `token` moves from `player` into the contract once.
+   * From then on, a game wagered in `token` takes the player's amount
+   * out of this balance, if it covers it, instead of a transfer the
+   * player has to authorize. Payouts are still sent to the player.
+   * 
+   * # Arguments
+   * * `player` - Who deposits, and signs the transfer
+   * * `token` - The token to deposit
+   * * `amount` - How much to deposit (else `InvalidBalanceAmount`)
+   * 
+   * # Returns
+   * * The player's balance in `token`
+   */
+  deposit_balance: ({player, token, amount}: {player: string, token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a withdraw_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take tokens back out of a prepaid balance
+   * 
+   * Amounts already staked on games have left the balance, and come
+   * back as payouts. Allowed while paused.
+   * 
+   * # Arguments
+   * * `player` - Whose balance, and signs
+   * * `token` - The balance's token
+   * * `amount` - How much to withdraw (else `InvalidBalanceAmount`), up
+   * to the balance (else `InsufficientBalance`)
+   * 
+   * # Returns
+   * * The player's remaining balance in `token`
+   */
+  withdraw_balance: ({player, token, amount}: {player: string, token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's prepaid balance in a token
+   */
+  get_balance: ({player, token}: {player: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_dispute_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get who rules on disputed settlements, and for how long after
+   * settling a pot can be disputed (None = no disputes)
+   */
+  get_dispute_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<DisputeConfig>>>
+
+  /**
+   * Construct and simulate a set_dispute_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set who rules on disputed settlements
+   * 
+   * While set, every won pot between two players opens a dispute window
+   * of `window_ledgers`, locking as much of the winner's bond in the
+   * token they won as they were paid (`post_bond`). The loser may
+   * dispute within the window, and `module` rules with
+   * `resolve_dispute`. Windows already open keep the module they opened
+   * with.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `Admin` role
+   * * `config` - The module and a nonzero `window_ledgers` (else
+   * `InvalidDisputeConfig`), or `None` for no disputes
+   */
+  set_dispute_config: ({caller, config}: {caller: string, config: Option<DisputeConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a post_bond transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Post a bond that backs a player's wins while they can be disputed
+   * 
+   * # Arguments
+   * * `player` - Who posts, and signs the transfer
+   * * `token` - The bond's token, the one the player's pots are won in
+   * * `amount` - How much to add (else `InvalidBondAmount`)
+   * 
+   * # Returns
+   * * The player's bond in `token`
+   */
+  post_bond: ({player, token, amount}: {player: string, token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a withdraw_bond transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take back the part of a bond not locked against a pot in its
+   * dispute window. Allowed while paused.
+   * 
+   * # Arguments
+   * * `player` - Whose bond, and signs
+   * * `token` - The bond's token
+   * * `amount` - How much to withdraw, up to the free part (else
+   * `InvalidBondAmount`)
+   * 
+   * # Returns
+   * * The player's remaining bond in `token`
+   */
+  withdraw_bond: ({player, token, amount}: {player: string, token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_bond transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's bond in a token
+   */
+  get_bond: ({player, token}: {player: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Bond>>
+
+  /**
+   * Construct and simulate a get_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a won pot's dispute window, while it is open or disputed
+   */
+  get_dispute: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Dispute>>>
+
+  /**
+   * Construct and simulate a dispute_settlement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Dispute a settlement as its loser, for the dispute module to rule on
+   * 
+   * # Arguments
+   * * `session_id` - A won pot in its dispute window (else
+   * `DisputeNotFound`), up to its `deadline_ledger` and not yet
+   * disputed (else `DisputeWindowClosed`). The session ID stays in
+   * use until the dispute is resolved
+   */
+  dispute_settlement: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resolve_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rule on a disputed settlement
+   * 
+   * Upholding it claws the locked part of the winner's bond back and
+   * pays it to the loser; rejecting it releases the lock. The match
+   * result itself, as recorded and reported to the hub, stands.
+   * 
+   * # Arguments
+   * * `session_id` - A disputed settlement (else `DisputeNotFound`)
+   * * `upheld` - Whether the winner cheated
+   * 
+   * # Returns
+   * * The amount clawed back
+   */
+  resolve_dispute: ({session_id, upheld}: {session_id: u32, upheld: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a finalize_settlement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close a dispute window that passed undisputed, releasing the lock
+   * on the winner's bond. Anyone may call it
+   * 
+   * # Arguments
+   * * `session_id` - A won pot in its dispute window (else
+   * `DisputeNotFound`), past its `deadline_ledger` and not disputed
+   * (else `DisputeWindowOpen`)
+   */
+  finalize_settlement: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_staking_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the staking token, tiers and waiting periods (None = no staking)
+   */
+  get_staking_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<StakingConfig>>>
+
+  /**
+   * Construct and simulate a set_staking_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how staking the studio token discounts the protocol fee
+   * 
+   * A player whose active stake in `token` reaches a tier's `min_stake`
+   * has the fee on the pots they win cut by the highest such tier's
+   * `discount_bps`, from the next settlement on. Stakes are locked for
+   * `lockup_ledgers` from their last top-up, and wait `cooldown_ledgers`
+   * between `unstake` and `withdraw_stake`. Removing staking, or moving
+   * it to another token, ends every discount; existing stakes can still
+   * be unstaked and withdrawn.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `config` - The staking rules, with 1 to `MAX_STAKE_TIERS` tiers
+   * rising in `min_stake` from above 0 and each `discount_bps` at most
+   * 10,000 (else `InvalidStakingConfig`), or `None` for no staking
+   */
+  set_staking_config: ({caller, config}: {caller: string, config: Option<StakingConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake the staking token toward a fee discount tier
+   * 
+   * The whole active stake, old and new, is then locked for the
+   * configured `lockup_ledgers`.
+   * 
+   * # Arguments
+   * * `player` - Who stakes, and signs the transfer
+   * * `amount` - How much to add (else `InvalidStakeAmount`, as when the
+   * player still has a stake in a token no longer configured)
+   * 
+   * Returns `StakingNotConfigured` without a staking configuration.
+   */
+  stake: ({player, amount}: {player: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Stake>>>
+
+  /**
+   * Construct and simulate a unstake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start withdrawing part of an active stake once its lockup is over
+   * 
+   * `amount` stops counting toward the tier at once, and joins anything
+   * already unstaking in a cooldown of the configured `cooldown_ledgers`
+   * (none without staking), restarted from now.
+   * 
+   * # Arguments
+   * * `player` - Whose stake, and signs
+   * * `amount` - Up to the active stake (else `InvalidStakeAmount`),
+   * unstaked from its `locked_until_ledger` on (else `StakeLocked`)
+   */
+  unstake: ({player, amount}: {player: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Stake>>>
+
+  /**
+   * Construct and simulate a withdraw_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw everything unstaked once its cooldown is over. Allowed
+   * while paused.
+   * 
+   * # Arguments
+   * * `player` - Whose stake, and signs; with something unstaking (else
+   * `InvalidStakeAmount`) from its `cooldown_end_ledger` on (else
+   * `StakeCoolingDown`)
+   * 
+   * # Returns
+   * * The amount withdrawn, in the stake's token
+   */
+  withdraw_stake: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's stake, if they have one
+   */
+  get_stake: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Stake>>>
+
+  /**
+   * Construct and simulate a get_stake_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fee discount tier a player's active stake earns now, if any
+   */
+  get_stake_tier: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<StakeTier>>>
+
+  /**
+   * Construct and simulate a deposit_bankroll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add liquidity to a token's house bankroll
+   * 
+   * `amount` of `token` moves from `provider` into the bankroll, which
+   * backs the house's stakes in `start_house_game`. The provider gets
+   * shares in proportion to the bankroll's current value, so they share
+   * its later wins and losses pro rata (see `withdraw_bankroll`).
+   * 
+   * # Arguments
+   * * `provider` - Who deposits, and signs the transfer
+   * * `token` - The bankroll's token
+   * * `amount` - How much to deposit (else `InvalidBankrollAmount`)
+   * 
+   * # Returns
+   * * The shares issued
+   */
+  deposit_bankroll: ({provider, token, amount}: {provider: string, token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a withdraw_bankroll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeem bankroll shares for their share of the bankroll
+   * 
+   * Stakes locked on house games in play cannot be withdrawn until the
+   * games settle: a withdrawal larger than the unlocked part returns
+   * `InsufficientBankroll`. Allowed while paused.
+   * 
+   * # Arguments
+   * * `provider` - Who holds the shares, and signs
+   * * `token` - The bankroll's token
+   * * `shares` - How many to redeem, up to all the provider holds (else
+   * `InvalidBankrollAmount`)
+   * 
+   * # Returns
+   * * The amount of `token` sent to `provider`
+   */
+  withdraw_bankroll: ({provider, token, shares}: {provider: string, token: string, shares: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a set_house_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the randomness beacon house games draw the house's tactics from
+   * 
+   * Each house game binds to the beacon's pending round when the player
+   * commits (see `start_house_game`), and keeps that beacon if it is
+   * changed later. Council-approved once a council is set.
+   * 
+   * # Arguments
+   * * `beacon` - A `contracts/randomness-beacon` deployment
+   */
+  set_house_beacon: ({beacon}: {beacon: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_house_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the house's randomness beacon, if one is set
+   */
+  get_house_beacon: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_house_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the beacon and round a house game's tactic is drawn from, once
+   * the player has committed
+   */
+  get_house_round: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<readonly [string, u64]>>>
+
+  /**
+   * Construct and simulate a get_bankroll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a token's house bankroll (empty if nobody has deposited)
+   */
+  get_bankroll: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Bankroll>>
+
+  /**
+   * Construct and simulate a get_bankroll_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get `provider`'s shares of a token's house bankroll
+   */
+  get_bankroll_shares: ({token, provider}: {token: string, provider: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-/**
- * Tactical formations: 0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut
- */
-export enum Tactic {
-  Defensive = 0,
-  Balanced = 1,
-  Aggressive = 2,
-  AllOut = 3,
-}
+  /**
+   * Construct and simulate a place_side_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake on a player of an open game
+   * 
+   * Anyone but the game's players may back either of them, until both
+   * tactics are submitted (else `SideBetsClosed`). Bets are in the
+   * game's `wager_token`; a points-only or mixed-asset game takes none
+   * (`SideBetsUnavailable`). Repeat bets add to the bettor's stake, but
+   * must back the same player. The stakes form a pari-mutuel pool: when
+   * `resolve_match` settles a win, the winner's backers share the losing
+   * side's stakes, less a rake (`get_side_bet_rake_bps`) paid to the
+   * winner. Any other ending refunds every bet. See `claim_side_bet`.
+   * 
+   * # Arguments
+   * * `session_id` - The game to bet on
+   * * `bettor` - Who stakes, and signs the transfer
+   * * `player` - The player backed to win
+   * * `amount` - How much of the game's token to stake
+   */
+  place_side_bet: ({session_id, bettor, player, amount}: {session_id: u32, bettor: string, player: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+  /**
+   * Construct and simulate a claim_side_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay out a side bet once its game has settled
+   * 
+   * Sends `bettor` their share of the pool if they backed the winner, or
+   * their stake back if the pool was refunded, settling the pool first
+   * if its game expired unresolved. Called with the match winner, it
+   * sends them a rake the token refused at settlement. Returns
+   * `GameNotSettled` while the game is in play, and `NothingToClaim`
+   * for a losing, already paid or unknown bet. Anyone may call it.
+   * 
+   * # Returns
+   * * The amount paid
+   */
+  claim_side_bet: ({session_id, bettor}: {session_id: u32, bettor: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
-export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a get_side_bet_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the side bets placed on a game, while any are unpaid
+   */
+  get_side_bet_pool: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SideBetPool>>>
+
+  /**
+   * Construct and simulate a get_side_bet transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get `bettor`'s stake on a game, until it is paid out
+   */
+  get_side_bet: ({session_id, bettor}: {session_id: u32, bettor: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SideBet>>>
+
+  /**
+   * Construct and simulate a get_side_bet_rake_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the rake on new side-bet pools, in basis points of the losing
+   * side (`DEFAULT_SIDE_BET_RAKE_BPS` by default)
+   */
+  get_side_bet_rake_bps: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_side_bet_rake_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the rake on side-bet pools opened from now on
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `rake_bps` - At most `MAX_FEE_BPS` (else `InvalidFee`)
+   */
+  set_side_bet_rake_bps: ({caller, rake_bps}: {caller: string, rake_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a register_referrer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Name the address that referred `player`
+   * 
+   * From then on `referrer` is credited `get_referral_share_bps` of the
+   * protocol fee taken from `player`'s stake in each won pot, claimable
+   * with `claim_referral_rewards`. A player names a referrer once (else
+   * `ReferrerAlreadySet`), and cannot name themselves (`InvalidReferrer`).
+   * 
+   * # Arguments
+   * * `player` - The referred player, who signs
+   * * `referrer` - Who referred them
+   */
+  register_referrer: ({player, referrer}: {player: string, referrer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_referral_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send a referrer their unclaimed rewards in `token`
+   * 
+   * Returns `NothingToClaim` if there are none. Anyone may call it.
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * The amount sent
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  claim_referral_rewards: ({referrer, token}: {referrer: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a get_referrer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get who referred `player`, if anyone
+   */
+  get_referrer: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_referral_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how many players a referrer has brought in, and how many of
+   * their stakes have earned the referrer a share
+   */
+  get_referral_stats: ({referrer}: {referrer: string}, options?: MethodOptions) => Promise<AssembledTransaction<ReferralStats>>
+
+  /**
+   * Construct and simulate a get_referral_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a referrer's rewards in `token`, earned and still claimable
+   */
+  get_referral_rewards: ({referrer, token}: {referrer: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<ReferralRewards>>
+
+  /**
+   * Construct and simulate a get_referral_share_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the referrers' share of the fee on their referees' stakes, in
+   * basis points (`DEFAULT_REFERRAL_SHARE_BPS` by default)
+   */
+  get_referral_share_bps: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_referral_share_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the referrers' share of the fee on their referees' stakes
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `share_bps` - At most 10,000 (else `InvalidFee`)
+   */
+  set_referral_share_bps: ({caller, share_bps}: {caller: string, share_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_hub_share_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the hub's share of the fee on won pots, in basis points (0 by
+   * default)
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_hub_share_bps: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a set_hub_share_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the hub's share of the fee on won pots
+   * 
+   * As a won pot settles, `share_bps` of each stake's fee, after any
+   * referral share, is sent to the treasury the hub names with its
+   * `treasury()`, and the fee collector keeps the rest. A hub that names
+   * none, or a treasury the token refuses, leaves the fee collector the
+   * whole fee.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `share_bps` - At most 10,000 (else `InvalidFee`)
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_hub_share_bps: ({caller, share_bps}: {caller: string, share_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game state including scores and tactics (after resolution).
+   * Construct and simulate a get_streak transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's current and best run of consecutive wins
+   */
+  get_streak: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Streak>>
+
+  /**
+   * Construct and simulate a get_streak_bonus transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the bonus paid for winning on a streak (None = no bonus)
+   */
+  get_streak_bonus: (options?: MethodOptions) => Promise<AssembledTransaction<Option<StreakBonus>>>
+
+  /**
+   * Construct and simulate a set_streak_bonus transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the bonus paid for winning on a streak
+   * 
+   * A player who wins a pot with a streak (`get_streak`) of at least
+   * `min_streak` is owed `step_bps` of what they won for each win from
+   * `min_streak` on, up to `max_bps`, alongside their winnings. The
+   * bonus comes out of the protocol fees accrued in that token, and is
+   * cut to what has accrued.
    * 
    * # Arguments
-   * * `session_id` - Game session ID
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `bonus` - The schedule, with `min_streak` at least 2 and `max_bps`
+   * at most 10,000 (else `InvalidStreakBonus`), or `None` for no bonus
+   */
+  set_streak_bonus: ({caller, bonus}: {caller: string, bonus: Option<StreakBonus>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_epoch_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the epoch rules (None = no epochs)
+   */
+  get_epoch_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<EpochConfig>>>
+
+  /**
+   * Construct and simulate a set_epoch_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how reward epochs run and fill their pools
+   * 
+   * Epochs last `length_ledgers`, and `share_bps` of every protocol fee
+   * taken in `token`, after any referral and hub share, goes to the
+   * running epoch's pool. Wins in games wagered in `token` rank players
+   * in the epoch they settle in; once it is over, its top `top_n`
+   * share the pool pro rata to their wins (`claim_epoch_reward`).
+   * Changing the rules, or removing them, ends the running epoch: the
+   * next one starts now.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `config` - The rules, with a nonzero `length_ledgers`, `share_bps`
+   * at most 10,000 and `top_n` from 1 to `MAX_EPOCH_LEADERS` (else
+   * `InvalidEpochConfig`), or `None` for no epochs
+   */
+  set_epoch_config: ({caller, config}: {caller: string, config: Option<EpochConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_current_epoch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the running epoch, if epochs are configured
+   */
+  get_current_epoch: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a get_epoch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an epoch's pool and leaderboard, if anything has accrued to it
+   */
+  get_epoch: ({epoch}: {epoch: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<EpochRewards>>>
+
+  /**
+   * Construct and simulate a get_epoch_wins transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's wins in an epoch
+   */
+  get_epoch_wins: ({epoch, player}: {epoch: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a claim_epoch_reward transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim a leader's share of an epoch's pool
+   * 
+   * Once the epoch is over, each of its leaders (`get_epoch`) may claim
+   * `pool * wins / total wins of the leaders` once; the last claim takes
+   * the rounding dust. Epochs paid by a reward tree (`set_epoch_root`)
+   * are claimed with `claim_epoch_reward_with_proof` instead.
+   * 
+   * # Arguments
+   * * `player` - The leader, who signs
+   * * `epoch` - An epoch that is over (else `EpochNotOver`)
    * 
    * # Returns
-   * * `Game` - Complete game state
+   * * The amount sent, or `NothingToClaim` for a player with no share
+   * left to claim
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+  claim_epoch_reward: ({player, epoch}: {player: string, epoch: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current admin address
+   * Construct and simulate a claim_epoch_reward_with_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim an epoch reward from its reward tree
+   * 
+   * # Arguments
+   * * `player` - Who the reward is for, and signs
+   * * `epoch` - An epoch with a reward tree (`set_epoch_root`)
+   * * `index` - The leaf's position in the tree
+   * * `amount` - The reward the leaf commits to (`epoch_leaf`)
+   * * `proof` - The leaf's inclusion proof, as `strategy_proof` builds
+   * it (a proof that does not check out, or a reward beyond what is
+   * left of the pool, is `InvalidEpochProof`)
    * 
    * # Returns
-   * * `Address` - The admin address
+   * * The amount sent, or `NothingToClaim` if already claimed
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  claim_epoch_reward_with_proof: ({player, epoch, index, amount, proof}: {player: string, epoch: u32, index: u32, amount: i128, proof: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a set_epoch_root transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Publish a reward tree for an epoch, to pay it by proof rather than
+   * by its on-chain leaderboard
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `epoch` - An epoch that is over (else `EpochNotOver`), with a pool
+   * and no claims paid yet (else `InvalidEpochRoot`)
+   * * `root` - Root of the tree of `epoch_leaf` leaves
+   * * `leaves` - How many leaves the tree has, at least 1
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_epoch_root: ({caller, epoch, root, leaves}: {caller: string, epoch: u32, root: Buffer, leaves: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new ZK Tactical Match between two players.
+   * Construct and simulate a get_jackpot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the jackpot in a token, paid out on a run set by
+   * `set_jackpot_config`
+   */
+  get_jackpot: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_jackpot_run transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's current run toward the jackpot
+   */
+  get_jackpot_run: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_jackpot_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the jackpot rules (None = no jackpot)
+   */
+  get_jackpot_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<JackpotConfig>>>
+
+  /**
+   * Construct and simulate a set_jackpot_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how the jackpot fills and what wins it
+   * 
+   * `share_bps` of every protocol fee, after any referral and hub share,
+   * goes to the jackpot in the fee's token (`get_jackpot`). A player who wins
+   * `wins_in_a_row` games wagered in a token in a row on a `Random`
+   * tiebreak, with both sides playing `tactic` and staking at least
+   * `min_stake`, is owed `payout_bps` of the jackpot in the token of the
+   * pot that completes the run, alongside their winnings. Any other
+   * result ends a run (`get_jackpot_run`).
+   * Removing the rules leaves the jackpot in place for when they return.
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier
-   * * `player1` - First player address
-   * * `player2` - Second player address
-   * * `player1_points` - Betting amount for player 1
-   * * `player2_points` - Betting amount for player 2
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `config` - The rules, with `share_bps` at most 10,000, a valid
+   * `tactic`, `wins_in_a_row` at least 1, `payout_bps` from 1 to
+   * 10,000 and `min_stake` at least 1 (else `InvalidJackpotConfig`), or
+   * `None` for no jackpot
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_jackpot_config: ({caller, config}: {caller: string, config: Option<JackpotConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a resolve_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Resolve match after both players submit tactics.
-   * Computes scores using strategic matrix and determines winner.
+   * Construct and simulate a get_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the protocol fee, in basis points of a won pot (0 by default)
+   */
+  get_fee_bps: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the protocol fee taken from the pot of games started from now on
+   * 
+   * When a game wagered in a token is won, `fee_bps` of the pot (both
+   * players' stakes) is kept back from the winner and accrues to the
+   * contract (see `get_accrued_fees`). Draws, cancellations, timeouts and
+   * expired games are refunded without a fee. Each game keeps the fee it
+   * started with.
    * 
    * # Arguments
-   * * `session_id` - Game session ID
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `fee_bps` - At most `MAX_FEE_BPS` (else `InvalidFee`)
+   */
+  set_fee_bps: ({caller, fee_bps}: {caller: string, fee_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_fee_collector transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the address `withdraw_fees` pays (the admin unless set)
+   */
+  get_fee_collector: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a set_fee_collector transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the address `withdraw_fees` pays
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `collector` - The new fee collector
+   */
+  set_fee_collector: ({caller, collector}: {caller: string, collector: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_accrued_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fees accrued in `token` and not yet withdrawn
+   */
+  get_accrued_fees: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a withdraw_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send every fee accrued in `token` to the fee collector
+   * 
+   * Returns `NothingToClaim` if none has accrued.
+   * 
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `token` - The token to withdraw
    * 
    * # Returns
-   * * `Address` - Winner address
+   * * The amount withdrawn
    */
-  resolve_match: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  withdraw_fees: ({caller, token}: {caller: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a submit_tactic transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Submit tactical choice with ZK proof.
+   * Construct and simulate a get_oracle_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the price oracle `start_mixed_game` values stakes with
+   */
+  get_oracle_config: (options?: MethodOptions) => Promise<AssembledTransaction<Option<OracleConfig>>>
+
+  /**
+   * Construct and simulate a set_oracle_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the price oracle for mixed-asset games
    * 
-   * **ZK Proof validates:**
-   * - tactic ∈ [0-3]
-   * - player identity
-   * - hasn't already submitted
+   * # Arguments
+   * * `caller` - The admin or a holder of the `FeeManager` (or `Admin`) role
+   * * `config` - A SEP-40 oracle (such as Reflector) pricing every token
+   * players may mix, with a tolerance of at most 10,000 bps (else
+   * `InvalidOracleConfig`), or `None` to stop mixed-asset games
+   */
+  set_oracle_config: ({caller, config}: {caller: string, config: Option<OracleConfig>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_max_open_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the most games a player may have in play at once (None = no limit)
+   */
+  get_max_open_games: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a set_max_open_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the most games a player may have in play at once
+   * 
+   * A `start_*` call that would put either player over the limit returns
+   * `TooManyOpenGames`, which bounds the storage and hub sessions one
+   * address can hold open. Games count from their start until they
+   * settle or expire; games already in play above a lowered limit are
+   * left alone. `None` lifts the limit.
    * 
    * # Arguments
-   * * `session_id` - Game session ID
-   * * `player` - Player address
-   * * `tactic` - Tactical choice (0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut)
-   * * `proof` - ZK proof bytes (Noir-generated proof for on-chain verification)
+   * * `caller` - The admin or a holder of the `Operator` (or `Admin`) role
+   * * `limit` - Maximum open games per player, from 1 to
+   * `MAX_OPEN_GAMES_LIMIT` (else `InvalidGameLimit`), or `None` for no
+   * limit
+   */
+  set_max_open_games: ({caller, limit}: {caller: string, limit: Option<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether new games and submissions are paused
+   */
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop new games and submissions during an incident, such as a broken
+   * circuit or verifier
+   * 
+   * Every `start_*` entrypoint and every submission (`submit_tactic` and
+   * its variants, `commit_tactic`, `submit_rounds`, `submit_team_move`)
+   * returns `Paused` until `unpause`. Games in play can still be
+   * revealed and settled (`resolve_match`, `resolve_timeout`) so stakes
+   * are never locked. Deadlines keep running while paused.
+   * 
+   * A holder of the `Pauser` (or `Admin`) role can pause on their own
+   * signature, even under an admin council, since pausing cannot move
+   * funds. Anyone else needs the admin, or the council's approval once
+   * one is set.
+   * 
+   * # Arguments
+   * * `caller` - The account pausing
+   */
+  pause: ({caller}: {caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept new games and submissions again after `pause`. Needs the
+   * admin council's approval once one is set.
+   */
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the contract's version, `(major, minor, patch)`. The commit it
+   * was built from and its circuit version are in the WASM's
+   * `contractmeta` entries
    */
-  submit_tactic: ({session_id, player, tactic, proof}: {session_id: u32, player: string, tactic: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_version: (options?: MethodOptions) => Promise<AssembledTransaction<readonly [u32, u32, u32]>>
+
+  /**
+   * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin, hub, verifier, pause state, limits and TTL policy in
+   * one call, for operations dashboards
+   */
+  get_config: (options?: MethodOptions) => Promise<AssembledTransaction<Result<ContractConfig>>>
+
+  /**
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the storage schema version, 0 for deployments from before
+   * versioning that have not run `migrate`
+   */
+  get_schema_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bring storage up to `SCHEMA_VERSION` after an upgrade
+   * 
+   * Runs the steps between the stored schema and this WASM's, then
+   * records the new version; a no-op once up to date. Returns
+   * `SchemaTooNew` if storage was written by a newer WASM. Games in play
+   * are temporary entries the contract cannot enumerate, so each one
+   * stored in an old layout is rewritten by `migrate_games` instead.
+   */
+  migrate: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a migrate_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rewrite games stored in an older layout (see `migration.rs`) so they
+   * can be played on after an upgrade
+   * 
+   * Until then every entrypoint reading such a game traps. Anyone may
+   * call it, e.g. a keeper walking `get_open_games` or the hub's open
+   * sessions; games already current, or not found, are skipped. Keep
+   * batches small enough for one transaction's footprint.
+   * 
+   * # Arguments
+   * * `session_ids` - Sessions to migrate
+   * 
+   * # Returns
+   * * `u32` - How many games were rewritten
+   */
+  migrate_games: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_upgrade_delay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ledgers an upgrade waits between proposal and execution
+   * (`DEFAULT_UPGRADE_DELAY_LEDGERS` until set)
+   */
+  get_upgrade_delay: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_upgrade_delay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the ledgers an upgrade waits between proposal and execution
+   * 
+   * Upgrades already proposed keep the ledger they became executable
+   * at.
+   * 
+   * # Arguments
+   * * `ledgers` - Delay in ledgers, at least `MIN_UPGRADE_DELAY_LEDGERS`
+   * (else `InvalidUpgradeDelay`)
+   */
+  set_upgrade_delay: ({ledgers}: {ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_pending_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the upgrade waiting out its delay, if any
+   */
+  get_pending_upgrade: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingUpgrade>>>
+
+  /**
+   * Construct and simulate a propose_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose upgrading the contract to `wasm_hash`
+   * 
+   * The upgrade can only be executed with `execute_upgrade` once
+   * `get_upgrade_delay` ledgers have passed, giving players a window to
+   * settle their games before the logic under their stakes changes. An
+   * `UpgradeProposed` event announces it. Proposing again replaces the
+   * pending upgrade and restarts the delay. Needs the admin council's
+   * approval once one is set; executing or cancelling an approved
+   * upgrade is left to the admin.
+   * 
+   * # Arguments
+   * * `wasm_hash` - The hash of the new WASM binary, already uploaded
+   */
+  propose_upgrade: ({wasm_hash}: {wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a execute_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Upgrade the contract to the pending WASM hash once its delay has
+   * passed
+   * 
+   * Returns `NoPendingUpgrade` without a proposal and `UpgradeNotReady`
+   * before its `executable_ledger`.
+   */
+  execute_upgrade: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw the pending upgrade
+   */
+  cancel_upgrade: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a is_initialized transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether the constructor has run (or `migrate` has, for deployments
+   * from before the flag)
+   */
+  is_initialized: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_pending_reinit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the reinitialization waiting out its delay, if any
+   */
+  get_pending_reinit: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingReinit>>>
+
+  /**
+   * Construct and simulate a propose_reinitialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose replacing the admin and hub set at initialization, for when
+   * a deployment went out with the wrong ones
+   * 
+   * Follows the upgrade timelock: `reinitialize` applies it once
+   * `get_upgrade_delay` ledgers have passed, and a `ReinitProposed`
+   * event announces it. Proposing again replaces the pending one and
+   * restarts the delay. Needs the admin council's approval once one is
+   * set.
+   * 
+   * # Arguments
+   * * `admin` - The new admin, who also signs `reinitialize`
+   * * `game_hub` - The new GameHub contract address, checked as by
+   * `set_hub`
+   */
+  propose_reinitialize: ({admin, game_hub}: {admin: string, game_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reinitialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Apply the pending reinitialization once its delay has passed
+   * 
+   * Signed by the current admin and, if it changes, the new one. Any
+   * pending admin transfer is dropped. Returns `NoPendingReinit` without
+   * a proposal and `ReinitNotReady` before its `executable_ledger`.
+   */
+  reinitialize: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_reinitialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw the pending reinitialization
+   */
+  cancel_reinitialize: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
 }
 export class Client extends ContractClient {
@@ -196,32 +3499,555 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAALAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAABJwbGF5ZXIxX3Byb29mX2hhc2gAAAAAA+gAAAPuAAAAIAAAAAAAAAANcGxheWVyMV9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3RhY3RpYwAAAAAD6AAAAAQAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAEnBsYXllcjJfcHJvb2ZfaGFzaAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIyX3Njb3JlAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjJfdGFjdGljAAAAAAPoAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAQQWxyZWFkeVN1Ym1pdHRlZAAAAAMAAAAAAAAAF0JvdGhQbGF5ZXJzTm90U3VibWl0dGVkAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAA1JbnZhbGlkVGFjdGljAAAAAAAABgAAAAAAAAAMSW52YWxpZFByb29mAAAABw==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAbgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAQQWxyZWFkeVN1Ym1pdHRlZAAAAAMAAAAAAAAAF0JvdGhQbGF5ZXJzTm90U3VibWl0dGVkAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAA1JbnZhbGlkVGFjdGljAAAAAAAABgAAAAAAAAAMSW52YWxpZFByb29mAAAABwAAAAAAAAARSW52YWxpZFNlc3Npb25LZXkAAAAAAAAIAAAAAAAAAA1JbnZhbGlkU2lnbmVyAAAAAAAACQAAAAAAAAAQU2lnbmF0dXJlRXhwaXJlZAAAAAoAAAAAAAAADEludmFsaWROb25jZQAAAAsAAAAAAAAADkdhbWVOb3RTZXR0bGVkAAAAAAAMAAAAAAAAABZJbnZhbGlkVmVyaWZpY2F0aW9uS2V5AAAAAAANAAAAAAAAABFJbnZhbGlkQ29tbWl0bWVudAAAAAAAAA4AAAAAAAAADU51bGxpZmllclVzZWQAAAAAAAAPAAAAAAAAABRQcm9vZlNlc3Npb25NaXNtYXRjaAAAABAAAAAAAAAAE1Byb29mUGxheWVyTWlzbWF0Y2gAAAAAEQAAAAAAAAAMUHJvb2ZFeHBpcmVkAAAAEgAAAAAAAAAUUHJvb2ZWYWxpZGl0eVRvb0xvbmcAAAATAAAAAAAAAA1JbnZhbGlkUmV2ZWFsAAAAAAAAFAAAAAAAAAAPQWxyZWFkeVJldmVhbGVkAAAAABUAAAAAAAAAEVRhY3RpY05vdFJldmVhbGVkAAAAAAAAFgAAAAAAAAAQV3JvbmdQcm9vZlN5c3RlbQAAABcAAAAAAAAAEUludmFsaWRTdGFrZVJhbmdlAAAAAAAAGAAAAAAAAAAQU3Rha2VOb3RSZXZlYWxlZAAAABkAAAAAAAAAD1N0YWtlc05vdEhpZGRlbgAAAAAaAAAAAAAAABFHYW1lQWxyZWFkeUV4aXN0cwAAAAAAABsAAAAAAAAADkludmFsaWRSZWNlaXB0AAAAAAAcAAAAAAAAAA9SZWNlaXB0UmVxdWlyZWQAAAAAHQAAAAAAAAARSW52YWxpZFJvdW5kQ291bnQAAAAAAAAeAAAAAAAAAA1Ob3RNdWx0aVJvdW5kAAAAAAAAHwAAAAAAAAAXVW5zdXBwb3J0ZWRQcm9vZlZlcnNpb24AAAAAIAAAAAAAAAANUHJvb2ZUb29MYXJnZQAAAAAAACEAAAAAAAAADkR1cGxpY2F0ZVByb29mAAAAAAAiAAAAAAAAABFTeWJpbEdhdGVEaXNhYmxlZAAAAAAAACMAAAAAAAAAFUlkZW50aXR5QWxyZWFkeVBsYXllZAAAAAAAACQAAAAAAAAAC0ludmFsaWRUZWFtAAAAACUAAAAAAAAAC05vdFRlYW1HYW1lAAAAACYAAAAAAAAAFEludmFsaWRUZWFtU2lnbmF0dXJlAAAAJwAAAAAAAAASUmF0aW5nc1VuYXZhaWxhYmxlAAAAAAAoAAAAAAAAABRJbnZhbGlkUmF0aW5nQnJhY2tldAAAACkAAAAAAAAAEkRlYWRsaW5lTm90UmVhY2hlZAAAAAAAKgAAAAAAAAAJTm9Gb3JmZWl0AAAAAAAAKwAAAAAAAAARU2Vzc2lvbk5vdEV4cGlyZWQAAAAAAAAsAAAAAAAAABBJbnZhbGlkRGVhZGxpbmVzAAAALQAAAAAAAAASU2VsZlBsYXlOb3RBbGxvd2VkAAAAAAAuAAAAAAAAAA5Ob3RJbml0aWFsaXplZAAAAAAALwAAAAAAAAAJSHViTm90U2V0AAAAAAAAMAAAAAAAAAAUU2Vzc2lvbkFscmVhZHlFeGlzdHMAAAAxAAAAAAAAABRTdWJtaXREZWFkbGluZVBhc3NlZAAAADIAAAAAAAAAFFJldmVhbERlYWRsaW5lUGFzc2VkAAAAMwAAAAAAAAAVVmVyaWZpZXJOb3RDb25maWd1cmVkAAAAAAAANAAAAAAAAAASVW5zdXBwb3J0ZWRBZGRyZXNzAAAAAAA1AAAAAAAAAApJbnZhbGlkVHRsAAAAAAA2AAAAAAAAAAxJbnZhbGlkUGhhc2UAAAA3AAAAAAAAAAZQYXVzZWQAAAAAADgAAAAAAAAADkh1Yk5vdE5vdGlmaWVkAAAAAAA5AAAAAAAAABBUb29NYW55T3BlbkdhbWVzAAAAOgAAAAAAAAAQSW52YWxpZEdhbWVMaW1pdAAAADsAAAAAAAAADVdhZ2VyVG9vU21hbGwAAAAAAAA8AAAAAAAAAA1XYWdlclRvb0xhcmdlAAAAAAAAPQAAAAAAAAASSW52YWxpZFdhZ2VyTGltaXRzAAAAAAA+AAAAAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAAPwAAAAAAAAAQTm9QZW5kaW5nVXBncmFkZQAAAEAAAAAAAAAAD1VwZ3JhZGVOb3RSZWFkeQAAAABBAAAAAAAAABNJbnZhbGlkVXBncmFkZURlbGF5AAAAAEIAAAAAAAAADkludmFsaWRDb3VuY2lsAAAAAABDAAAAAAAAABBOb3RDb3VuY2lsTWVtYmVyAAAARAAAAAAAAAALTm90QXBwcm92ZWQAAAAARQAAAAAAAAALTWlzc2luZ1JvbGUAAAAARgAAAAAAAAAMU2NoZW1hVG9vTmV3AAAARwAAAAAAAAASQWxyZWFkeUluaXRpYWxpemVkAAAAAABIAAAAAAAAAA9Ob1BlbmRpbmdSZWluaXQAAAAASQAAAAAAAAAOUmVpbml0Tm90UmVhZHkAAAAAAEoAAAAAAAAACkludmFsaWRIdWIAAAAAAEsAAAAAAAAADk5vdGhpbmdUb0NsYWltAAAAAABMAAAAAAAAAApJbnZhbGlkRmVlAAAAAABNAAAAAAAAABNPcmFjbGVOb3RDb25maWd1cmVkAAAAAE4AAAAAAAAAEFByaWNlVW5hdmFpbGFibGUAAABPAAAAAAAAABNTdGFrZXNOb3RFcXVpdmFsZW50AAAAAFAAAAAAAAAAE0ludmFsaWRPcmFjbGVDb25maWcAAAAAUQAAAAAAAAAOU2lkZUJldHNDbG9zZWQAAAAAAFIAAAAAAAAAE1NpZGVCZXRzVW5hdmFpbGFibGUAAAAAUwAAAAAAAAAOSW52YWxpZFNpZGVCZXQAAAAAAFQAAAAAAAAAFEluc3VmZmljaWVudEJhbmtyb2xsAAAAVQAAAAAAAAAVSW52YWxpZEJhbmtyb2xsQW1vdW50AAAAAAAAVgAAAAAAAAASUmVmZXJyZXJBbHJlYWR5U2V0AAAAAABXAAAAAAAAAA9JbnZhbGlkUmVmZXJyZXIAAAAAWAAAAAAAAAASSW52YWxpZFN0cmVha0JvbnVzAAAAAABZAAAAAAAAABRJbnZhbGlkSmFja3BvdENvbmZpZwAAAFoAAAAAAAAAFEludmFsaWRCYWxhbmNlQW1vdW50AAAAWwAAAAAAAAATSW5zdWZmaWNpZW50QmFsYW5jZQAAAABcAAAAAAAAAAxJbnZhbGlkQmF0Y2gAAABdAAAAAAAAABJJbnZhbGlkRXBvY2hDb25maWcAAAAAAF4AAAAAAAAADEVwb2NoTm90T3ZlcgAAAF8AAAAAAAAAEEludmFsaWRFcG9jaFJvb3QAAABgAAAAAAAAABFJbnZhbGlkRXBvY2hQcm9vZgAAAAAAAGEAAAAAAAAAFEludmFsaWREaXNwdXRlQ29uZmlnAAAAYgAAAAAAAAAPRGlzcHV0ZU5vdEZvdW5kAAAAAGMAAAAAAAAAE0Rpc3B1dGVXaW5kb3dDbG9zZWQAAAAAZAAAAAAAAAARRGlzcHV0ZVdpbmRvd09wZW4AAAAAAABlAAAAAAAAABFJbnZhbGlkQm9uZEFtb3VudAAAAAAAAGYAAAAAAAAAC0ludmFsaWRPZGRzAAAAAGcAAAAAAAAAFEludmFsaWRTdGFraW5nQ29uZmlnAAAAaAAAAAAAAAAUU3Rha2luZ05vdENvbmZpZ3VyZWQAAABpAAAAAAAAABJJbnZhbGlkU3Rha2VBbW91bnQAAAAAAGoAAAAAAAAAC1N0YWtlTG9ja2VkAAAAAGsAAAAAAAAAEFN0YWtlQ29vbGluZ0Rvd24AAABsAAAAAAAAABFIb3VzZUJlYWNvbk5vdFNldAAAAAAAAG0AAAAAAAAAElJhbmRvbW5lc3NOb3RSZWFkeQAAAAAAbg==",
         "AAAAAwAAAERUYWN0aWNhbCBmb3JtYXRpb25zOiAwPURlZmVuc2l2ZSwgMT1CYWxhbmNlZCwgMj1BZ2dyZXNzaXZlLCAzPUFsbE91dAAAAAAAAAAGVGFjdGljAAAAAAAEAAAAAAAAAAlEZWZlbnNpdmUAAAAAAAAAAAAAAAAAAAhCYWxhbmNlZAAAAAEAAAAAAAAACkFnZ3Jlc3NpdmUAAAAAAAIAAAAAAAAABkFsbE91dAAAAAAAAw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAJdHZXQgZ2FtZSBzdGF0ZSBpbmNsdWRpbmcgc2NvcmVzIGFuZCB0YWN0aWNzIChhZnRlciByZXNvbHV0aW9uKS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECgojIFJldHVybnMKKiBgR2FtZWAgLSBDb21wbGV0ZSBnYW1lIHN0YXRlAAAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAER2FtZQAAAAM=",
-        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAARNTdGFydCBhIG5ldyBaSyBUYWN0aWNhbCBNYXRjaCBiZXR3ZWVuIHR3byBwbGF5ZXJzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBVbmlxdWUgc2Vzc2lvbiBpZGVudGlmaWVyCiogYHBsYXllcjFgIC0gRmlyc3QgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMmAgLSBTZWNvbmQgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMV9wb2ludHNgIC0gQmV0dGluZyBhbW91bnQgZm9yIHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMgAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAMRSZXNvbHZlIG1hdGNoIGFmdGVyIGJvdGggcGxheWVycyBzdWJtaXQgdGFjdGljcy4KQ29tcHV0ZXMgc2NvcmVzIHVzaW5nIHN0cmF0ZWdpYyBtYXRyaXggYW5kIGRldGVybWluZXMgd2lubmVyLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBHYW1lIHNlc3Npb24gSUQKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIFdpbm5lciBhZGRyZXNzAAAADXJlc29sdmVfbWF0Y2gAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
-        "AAAAAAAAAWNTdWJtaXQgdGFjdGljYWwgY2hvaWNlIHdpdGggWksgcHJvb2YuCgoqKlpLIFByb29mIHZhbGlkYXRlczoqKgotIHRhY3RpYyDiiIggWzAtM10KLSBwbGF5ZXIgaWRlbnRpdHkKLSBoYXNuJ3QgYWxyZWFkeSBzdWJtaXR0ZWQKCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBQbGF5ZXIgYWRkcmVzcwoqIGB0YWN0aWNgIC0gVGFjdGljYWwgY2hvaWNlICgwPURlZmVuc2l2ZSwgMT1CYWxhbmNlZCwgMj1BZ2dyZXNzaXZlLCAzPUFsbE91dCkKKiBgcHJvb2ZgIC0gWksgcHJvb2YgYnl0ZXMgKE5vaXItZ2VuZXJhdGVkIHByb29mIGZvciBvbi1jaGFpbiB2ZXJpZmljYXRpb24pAAAAAA1zdWJtaXRfdGFjdGljAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZ0YWN0aWMAAAAAAAQAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAAAgAAAAM=" ]),
+        "AAAAAQAAADFPbmUgY2VsbCBvZiB0aGUgc2NvcmUgbWF0cml4IChgZ2V0X3Njb3JlX21hdHJpeGApAAAAAAAAAAAAAApTY29yZUVudHJ5AAAAAAAEAAAAAAAAAAZzY29yZTEAAAAAAAQAAAAAAAAABnNjb3JlMgAAAAAABAAAAAAAAAAHdGFjdGljMQAAAAAEAAAAAAAAAAd0YWN0aWMyAAAAAAQ=",
+        "AAAAAwAAANlQcm92aW5nIGJhY2tlbmQgYSBnYW1lJ3MgcHJvb2ZzIGFyZSBjaGVja2VkIHdpdGgKCmBDb21taXRSZXZlYWxgIGdhbWVzIHRha2Ugbm8gcHJvb2YgYXQgYWxsOiBwbGF5ZXJzIGBjb21taXRfdGFjdGljYCBhCnNhbHRlZCBgdGFjdGljX2hhc2hgIGFuZCBvcGVuIGl0IHdpdGggYHJldmVhbF90YWN0aWNgLCBmb3IgY2xpZW50cyB0aGF0CmNhbm5vdCBnZW5lcmF0ZSBaSyBwcm9vZnMuAAAAAAAAAAAAAAtQcm9vZlN5c3RlbQAAAAADAAAAAAAAAAdHcm90aDE2AAAAAAAAAAAAAAAACVVsdHJhSG9uawAAAAAAAAEAAAAAAAAADENvbW1pdFJldmVhbAAAAAI=",
+        "AAAAAwAAAYRIb3cgYSBkcmF3biBtYXRjaCBpcyBkZWNpZGVkCgpgRHJhd2AgKHRoZSBkZWZhdWx0KSByZXBvcnRzIGl0IHRvIHRoZSBodWIgYXMgYSBkcmF3LiBgUGxheWVyMWAgaXMgdGhlCm9yaWdpbmFsIHJ1bGUuIGBSYW5kb21gIHNlZWRzIGBlbnYucHJuZygpYCB3aXRoIGJvdGggdGFjdGljIGhhc2hlcyBhbmQKYm90aCByZXZlYWxlZCBzZWNyZXRzLCBzbyBuZWl0aGVyIHBsYXllciBjYW4gc3RlZXIgaXQ6IGVhY2ggc2VjcmV0IGlzCmNvbW1pdHRlZCBiZWZvcmUgdGhlIG90aGVyJ3MgaXMgcmV2ZWFsZWQuIEhvdXNlIGdhbWVzIGlnbm9yZSB0aGUKcG9saWN5OiB0aGUgaG91c2Ugd2lucyB0aGVpciB0aWVzLiBPbmx5IGBSYW5kb21gIHRpZXMgY291bnQgdG93YXJkIGEKamFja3BvdCBydW4uAAAAAAAAAA5UaWVicmVha1BvbGljeQAAAAAAAwAAAAAAAAAHUGxheWVyMQAAAAAAAAAAAAAAAAZSYW5kb20AAAAAAAEAAAAAAAAABERyYXcAAAAC",
+        "AAAAAwAAAGlSZXN1bHQgb2YgYSBzZXR0bGVkIGdhbWUuIERyYXdzIGFyZSByZXBvcnRlZCB0byB0aGUgaHViJ3MKYGVuZF9nYW1lX3dpdGhfb3V0Y29tZWAsIHdpbnMgdG8gaXRzIGBlbmRfZ2FtZWAAAAAAAAAAAAAAB091dGNvbWUAAAAAAwAAAAAAAAAKUGxheWVyMVdpbgAAAAAAAAAAAAAAAAAKUGxheWVyMldpbgAAAAAAAQAAAAAAAAAERHJhdwAAAAI=",
+        "AAAAAwAAAcpXaGVyZSBhIGdhbWUgaXMgaW4gaXRzIGxpZmVjeWNsZS4gRW50cnlwb2ludHMgb25seSBhY3Qgb24gZ2FtZXMgaW4gdGhlCnBoYXNlcyB0aGV5IGV4cGVjdCwgZmFpbGluZyB3aXRoIGBJbnZhbGlkUGhhc2VgIG90aGVyd2lzZQoKR2FtZXMgc3RhcnQgYENyZWF0ZWRgIGFuZCBtb3ZlIHRvIGBBd2FpdGluZ09wcG9uZW50YCBhbmQgYEJvdGhTdWJtaXR0ZWRgCmFzIHBsYXllcnMgc3VibWl0IChjb21taXR0ZWQgc3RyYXRlZ2llcyBzdGFydCBgQm90aFN1Ym1pdHRlZGApLiBSZXZlYWxzCmhhcHBlbiBpbiBgQm90aFN1Ym1pdHRlZGAsIGFuZCBzZXR0bGluZyBlbmRzIHRoZSBnYW1lIGBSZXNvbHZlZGAsIG9yCmBUaW1lZE91dGAgZm9yIGEgZm9yZmVpdCBieSBgcmVzb2x2ZV90aW1lb3V0YCwgb3IgYENhbmNlbGxlZGAgZm9yIGdhbWVzCmNhbGxlZCBvZmYgYmVmb3JlIHBsYXkgd2l0aCBgY2FuY2VsX2dhbWVgLgAAAAAAAAAAAAlHYW1lUGhhc2UAAAAAAAAGAAAAAAAAAAdDcmVhdGVkAAAAAAAAAAAAAAAAEEF3YWl0aW5nT3Bwb25lbnQAAAABAAAAAAAAAA1Cb3RoU3VibWl0dGVkAAAAAAAAAgAAAAAAAAAIUmVzb2x2ZWQAAAADAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAEAAAAAAAAAAhUaW1lZE91dAAAAAU=",
+        "AAAAAgAAAKhXaGV0aGVyIGEgZ2FtZSBoYXMgYmVlbiBzZXR0bGVkLCBhbmQgaG93LiBBIGNvbnRyYWN0IHR5cGUgY2FuJ3QgaG9sZCBhbgpgT3B0aW9uYCBvZiBhbm90aGVyIGNvbnRyYWN0IHR5cGUsIHNvIGBHYW1lYCBrZWVwcyB0aGlzIGluc3RlYWQ7IHJlYWQgaXQKdGhyb3VnaCBgR2FtZTo6b3V0Y29tZWAAAAAAAAAACkdhbWVSZXN1bHQAAAAAAAIAAAAAAAAAAAAAAAdQZW5kaW5nAAAAAAEAAAAAAAAAB1NldHRsZWQAAAAAAQAAB9AAAAAHT3V0Y29tZQA=",
+        "AAAAAQAAANBQdWJsaWMgaW5wdXRzIG9mIHRoZSB0YWN0aWMgY2lyY3VpdCwgaW4gdGhlIG9yZGVyIHRoZSBjaXJjdWl0IGRlY2xhcmVzCnRoZW0gKHB1YmxpYyBwYXJhbWV0ZXJzLCB0aGVuIGl0cyByZXR1cm4gdmFsdWVzKS4gVGhlIHRhY3RpYyBpdHNlbGYgaXMgYQpwcml2YXRlIGlucHV0OyBgdGFjdGljX2hhc2hgIGNvbW1pdHMgdG8gaXQgdW50aWwgYHJldmVhbF90YWN0aWNgAAAAAAAAAAxQdWJsaWNJbnB1dHMAAAAGAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAJbnVsbGlmaWVyAAAAAAAD7gAAACAAAAAAAAAADnBsYXllcl9iaW5kaW5nAAAAAAPuAAAAIAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAALdGFjdGljX2hhc2gAAAAD7gAAACAAAAAAAAAAEnZhbGlkX3VudGlsX2xlZGdlcgAAAAAABA==",
+        "AAAAAQAAAQRQdWJsaWMgaW5wdXRzIG9mIHRoZSByZWN1cnNpdmUgcm91bmRzIGNpcmN1aXQsIHdoaWNoIHZlcmlmaWVzIG9uZSB0YWN0aWMKcHJvb2YgcGVyIHJvdW5kIG9mIGEgbXVsdGktcm91bmQgbWF0Y2guIGBjb21taXRtZW50YCBhbmQgYG51bGxpZmllcmAKYWdncmVnYXRlIHRoZSBpbm5lciBwcm9vZnMnIG91dHB1dHM7IGB0YWN0aWNfaGFzaGVzYCBhcmUgdGhlIHJvdW5kcycgdGFjdGljCmhhc2hlcyBpbiBvcmRlciwgb3BlbmVkIGJ5IGByZXZlYWxfcm91bmRzYAAAAAAAAAALUm91bmRJbnB1dHMAAAAABgAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAACW51bGxpZmllcgAAAAAAA+4AAAAgAAAAAAAAAA5wbGF5ZXJfYmluZGluZwAAAAAD7gAAACAAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAADXRhY3RpY19oYXNoZXMAAAAAAAPqAAAD7gAAACAAAAAAAAAAEnZhbGlkX3VudGlsX2xlZGdlcgAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAaAAAAAAAAAAxhdXRvX3Jlc29sdmUAAAABAAAAAAAAABJiYXRjaF92ZXJpZmljYXRpb24AAAAAAAEAAAAAAAAADGh1Yl9ub3RpZmllZAAAAAEAAAAAAAAAFGxhc3RfYWN0aXZpdHlfbGVkZ2VyAAAABAAAAAAAAAAFcGhhc2UAAAAAAAfQAAAACUdhbWVQaGFzZQAAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABJwbGF5ZXIxX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAEnBsYXllcjFfcHJvb2ZfaGFzaAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIxX3Njb3JlAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjFfdGFjdGljAAAAAAPoAAAABAAAAAAAAAATcGxheWVyMV90YWN0aWNfaGFzaAAAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAABJwbGF5ZXIyX3Byb29mX2hhc2gAAAAAA+gAAAPuAAAAIAAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3RhY3RpYwAAAAAD6AAAAAQAAAAAAAAAE3BsYXllcjJfdGFjdGljX2hhc2gAAAAD6AAAA+4AAAAgAAAAAAAAAAxwcm9vZl9zeXN0ZW0AAAfQAAAAC1Byb29mU3lzdGVtAAAAAAAAAAAXcmVzb2x2ZV9kZWFkbGluZV9sZWRnZXIAAAAABAAAAAAAAAAGcmVzdWx0AAAAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAAAAAATc2ltdWxhdGlvbl9pbWFnZV9pZAAAAAPoAAAD7gAAACAAAAAAAAAAFnN1Ym1pdF9kZWFkbGluZV9sZWRnZXIAAAAAAAQAAAAAAAAACHRpZWJyZWFrAAAH0AAAAA5UaWVicmVha1BvbGljeQAAAAAAAAAAAAt3YWdlcl90b2tlbgAAAAPoAAAAEw==",
+        "AAAAAQAAANJBIGdhbWUgYXMgYW55b25lIG1heSBzZWUgaXQgKGBnZXRfZ2FtZV9wdWJsaWNgKQoKVW50aWwgdGhlIGdhbWUgaXMgc2V0dGxlZCwgYGdhbWVgIGhhcyBpdHMgdGFjdGljcyBhbmQgcHJvb2YgaGFzaGVzCm1hc2tlZCB0byBgTm9uZWA7IHRoZSByZXZlYWwgZmxhZ3Mgc2F5IHdobyBoYXMgcmV2ZWFsZWQgZXZlcnl0aGluZwpyZXNvbHV0aW9uIG5lZWRzIGZyb20gdGhlbS4AAAAAAAAAAAAKUHVibGljR2FtZQAAAAAAAwAAAAAAAAAEZ2FtZQAAB9AAAAAER2FtZQAAAAAAAAAQcGxheWVyMV9yZXZlYWxlZAAAAAEAAAAAAAAAEHBsYXllcjJfcmV2ZWFsZWQAAAAB",
+        "AAAAAgAAAEBIb3cgb25lIHNlc3Npb24gb2YgYSBiYXRjaCB3ZW50IChgcmVzb2x2ZV9iYXRjaGAsIGBjbGFpbV9iYXRjaGApAAAAAAAAAAtCYXRjaFJlc3VsdAAAAAADAAAAAQAAAAAAAAAIUmVzb2x2ZWQAAAACAAAABAAAB9AAAAAHT3V0Y29tZQAAAAABAAAAAAAAAAdDbGFpbWVkAAAAAAIAAAAEAAAACwAAAAEAAAAAAAAABkZhaWxlZAAAAAAAAgAAAAQAAAAE",
+        "AAAAAQAAAONSb3VuZHMgb2YgYSBnYW1lIHN0YXJ0ZWQgd2l0aCBgc3RhcnRfbXVsdGlfcm91bmRfZ2FtZWAgb3IKYHN0YXJ0X2NvbW1pdHRlZF9tdWx0aV9yb3VuZF9nYW1lYC4gVGhlIGdhbWUncyBvd24gdGFjdGljIGhhc2ggZmllbGRzIGhvbGQKdGhlIHNoYTI1NiBvZiBlYWNoIHBsYXllcidzIHJvdW5kIGhhc2hlcywgb3IgdGhlaXIgc3RyYXRlZ3kgcm9vdHMsIGFuZCBpdHMKdGFjdGljcyBzdGF5IGBOb25lYAAAAAAAAAAACk11bHRpUm91bmQAAAAAAAYAAAAAAAAAFXBsYXllcjFfdGFjdGljX2hhc2hlcwAAAAAAA+gAAAPqAAAD7gAAACAAAAAAAAAAD3BsYXllcjFfdGFjdGljcwAAAAPoAAAD6gAAAAQAAAAAAAAAFXBsYXllcjJfdGFjdGljX2hhc2hlcwAAAAAAA+gAAAPqAAAD7gAAACAAAAAAAAAAD3BsYXllcjJfdGFjdGljcwAAAAPoAAAD6gAAAAQAAAAAAAAABnJvdW5kcwAAAAAABAAAAAAAAAASc3RyYXRlZ3lfY29tbWl0dGVkAAAAAAAB",
+        "AAAAAQAAAGlBIHN1Ym1pc3Npb24gd2hvc2UgcHJvb2YgaXMgY2hlY2tlZCBhdCBgcmVzb2x2ZV9tYXRjaGAgKGJhdGNoCnZlcmlmaWNhdGlvbiksIGtlcHQgdW50aWwgdGhlIGdhbWUgcmVzb2x2ZXMAAAAAAAAAAAAADFBlbmRpbmdQcm9vZgAAAAIAAAAAAAAABmlucHV0cwAAAAAH0AAAAAxQdWJsaWNJbnB1dHMAAAAAAAAABXByb29mAAAAAAAADg==",
+        "AAAAAQAAAHJXaGVyZSB0aGUgR3JvdGgxNiB2ZXJpZmljYXRpb24ga2V5IGlzIHJlc29sdmVkIGZyb206IHRoZSBlbnRyeQpgKGNpcmN1aXRfaWQsIHZlcnNpb24pYCBvZiBhIGB2ay1yZWdpc3RyeWAgY29udHJhY3QAAAAAAAAAAAAIVmtTb3VyY2UAAAADAAAAAAAAAApjaXJjdWl0X2lkAAAAAAARAAAAAAAAAAhyZWdpc3RyeQAAABMAAAAAAAAAB3ZlcnNpb24AAAAABA==",
+        "AAAAAQAAAPlBIHRhY3RpYyBwcm9vZiB0YWdnZWQgd2l0aCB0aGUgY2lyY3VpdCB2ZXJzaW9uIGFuZCBiYWNrZW5kIGl0IHdhcyBtYWRlCmZvciwgYXMgdGFrZW4gYnkgYHN1Ym1pdF90YWN0aWNfZW52ZWxvcGVgLiBWZXJzaW9ucyBiZWxvdwpgZ2V0X21pbl9wcm9vZl92ZXJzaW9uKClgIG9yIGFib3ZlIGBQUk9PRl9WRVJTSU9OYCBhcmUgcmVqZWN0ZWQsIHNvIGEKY2lyY3VpdCB1cGdyYWRlIGNhbiByZXRpcmUgcHJvb2ZzIGZyb20gdGhlIG9sZCBvbmUAAAAAAAAAAAAADVByb29mRW52ZWxvcGUAAAAAAAADAAAAAAAAAAdwYXlsb2FkAAAAAA4AAAAAAAAABnN5c3RlbQAAAAAH0AAAAAtQcm9vZlN5c3RlbQAAAAAAAAAAB3ZlcnNpb24AAAAABA==",
+        "AAAAAQAAAIlBIHBsYXllcidzIHN0YWtlIGluIGEgaGlkZGVuLXN0YWtlIGdhbWU6IHRoZSBoYXNoIGl0IGlzIGNvbW1pdHRlZCB0bwooc2VlIGBzdGFrZV9oYXNoYCkgYW5kIGEgcHJvb2YgdGhhdCBpdCBsaWVzIHdpdGhpbiB0aGUgZ2FtZSdzIGJvdW5kcwAAAAAAAAAAAAAPU3Rha2VDb21taXRtZW50AAAAAAIAAAAAAAAABXByb29mAAAAAAAADgAAAAAAAAAKc3Rha2VfaGFzaAAAAAAD7gAAACA=",
+        "AAAAAQAAAM9XaGF0IHRoZSBodWIgd2FzIHRvbGQgd2hlbiBhIHNlc3Npb24gc3RhcnRlZC4gS2VwdCBpbiBwZXJzaXN0ZW50IHN0b3JhZ2UKdW50aWwgdGhlIHJlc3VsdCBpcyByZXBvcnRlZCwgc28gYSBzZXNzaW9uIHdob3NlIGdhbWUgZW50cnkgZXhwaXJlZApiZWZvcmUgaXQgd2FzIHJlc29sdmVkIGNhbiBzdGlsbCBiZSByZWNvbmNpbGVkIChgYWJhbmRvbl9leHBpcmVkYCkAAAAAAAAAAAtPcGVuU2Vzc2lvbgAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAAL",
+        "AAAAAQAAADRBIGdhbWUncyBkZWFkbGluZXMsIGZvciBjb3VudGRvd25zIChgZ2V0X2RlYWRsaW5lc2ApAAAAAAAAAAlEZWFkbGluZXMAAAAAAAACAAAAAAAAABdyZXNvbHZlX2RlYWRsaW5lX2xlZGdlcgAAAAAEAAAAAAAAABZzdWJtaXRfZGVhZGxpbmVfbGVkZ2VyAAAAAAAE",
+        "AAAAAQAAAE1TbWFsbGVzdCBhbmQgbGFyZ2VzdCBwb2ludHMgYSBwbGF5ZXIgbWF5IHN0YWtlIG9uIGEgZ2FtZQooYHNldF93YWdlcl9saW1pdHNgKQAAAAAAAAAAAAALV2FnZXJMaW1pdHMAAAAAAgAAAAAAAAAJbWF4X3dhZ2VyAAAAAAAACwAAAAAAAAAJbWluX3dhZ2VyAAAAAAAACw==",
+        "AAAAAQAAALdUaGUgY29udHJhY3QncyBvcGVyYXRpb25hbCBzZXR0aW5ncyBpbiBvbmUgcmVhZCAoYGdldF9jb25maWdgKQoKVGhlIFRUTHMgYXJlIHRoZSBsZWRnZXJzIGVhY2ggZ2FtZSBlbnRyeSwgYW5kIGVhY2ggcGxheWVyJ3MgbWF0Y2gKaGlzdG9yeSBhbmQgc3RhdHMsIGFyZSBrZXB0IGFsaXZlIGZvciBvbiBldmVyeSB3cml0ZS4AAAAAAAAAAA5Db250cmFjdENvbmZpZwAAAAAAEgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAADWZlZV9jb2xsZWN0b3IAAAAAAAATAAAAAAAAABBnYW1lX3R0bF9sZWRnZXJzAAAABAAAAAAAAAATaGlzdG9yeV90dGxfbGVkZ2VycwAAAAAEAAAAAAAAAANodWIAAAAAEwAAAAAAAAANaHViX3NoYXJlX2JwcwAAAAAAAAQAAAAAAAAADm1heF9vcGVuX2dhbWVzAAAAAAPoAAAABAAAAAAAAAAGb3JhY2xlAAAAAAPoAAAAEwAAAAAAAAAGcGF1c2VkAAAAAAABAAAAAAAAAAxwcm9vZl9zeXN0ZW0AAAfQAAAAC1Byb29mU3lzdGVtAAAAAAAAAAAScmVmZXJyYWxfc2hhcmVfYnBzAAAAAAAEAAAAAAAAABFzaWRlX2JldF9yYWtlX2JwcwAAAAAAAAQAAAAAAAAAD3RpbWVvdXRfbGVkZ2VycwAAAAAEAAAAAAAAABV1cGdyYWRlX2RlbGF5X2xlZGdlcnMAAAAAAAAEAAAAAAAAAAh2ZXJpZmllcgAAA+gAAAATAAAAAAAAAAx3YWdlcl9saW1pdHMAAAfQAAAAC1dhZ2VyTGltaXRzAAAAAAAAAAALd2FnZXJfdG9rZW4AAAAD6AAAABM=",
+        "AAAAAQAAAHNBIHNldHRsZWQgZ2FtZSBhcyBrZXB0IGluIGVhY2ggcGxheWVyJ3MgbWF0Y2ggaGlzdG9yeSwgYWZ0ZXIgdGhlIGdhbWUKZW50cnkgaXRzZWxmIGhhcyBleHBpcmVkIChgZ2V0X21hdGNoX3JlY29yZGApAAAAAAAAAAALTWF0Y2hSZWNvcmQAAAAACQAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAdvdXRjb21lAAAAB9AAAAAHT3V0Y29tZQAAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADXBsYXllcjFfc2NvcmUAAAAAAAPoAAAABAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAE",
+        "AAAAAQAAAPxBIHBsYXllcidzIGxpZmV0aW1lIHJlY29yZCwgZm9yIHByb2ZpbGUgcGFnZXMKCmBmb3JmZWl0c2AgYXJlIHRoZSBgbG9zc2VzYCB0aGF0IGNhbWUgZnJvbSBgcmVzb2x2ZV90aW1lb3V0YC4KYHRhY3RpY19jb3VudHNgIGhvbGRzIGhvdyBvZnRlbiBlYWNoIHJldmVhbGVkIHRhY3RpYyB3YXMgcGxheWVkLCBpbmRleGVkCmJ5IGBUYWN0aWNgOyBtdWx0aS1yb3VuZCBnYW1lcyBhbmQgdW5yZXZlYWxlZCB0YWN0aWNzIGFyZSBub3QgY291bnRlZC4AAAAAAAAAC1BsYXllclN0YXRzAAAAAAgAAAAAAAAABWRyYXdzAAAAAAAABAAAAAAAAAAPZmF2b3JpdGVfdGFjdGljAAAAA+gAAAAEAAAAAAAAAAhmb3JmZWl0cwAAAAQAAAAAAAAABWdhbWVzAAAAAAAABAAAAAAAAAAGbG9zc2VzAAAAAAAEAAAAAAAAAA10YWN0aWNfY291bnRzAAAAAAAD6gAAAAQAAAAAAAAADXRvdGFsX3dhZ2VyZWQAAAAAAAALAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAWtDb250cmFjdC13aWRlIHRvdGFscyBmb3IgZGFzaGJvYXJkcyAoYGdldF9nbG9iYWxfc3RhdHNgKQoKYHRvdGFsX3ZvbHVtZWAgaXMgdGhlIHBvaW50cyBjb21taXR0ZWQgYWNyb3NzIGV2ZXJ5IGdhbWUgc3RhcnRlZC4KYHJlc29sdXRpb25fcmF0ZV9icHNgIGlzIHRoZSBzaGFyZSBvZiBlbmRlZCBnYW1lcyB0aGF0IHdlcmUgcGxheWVkIG91dAoocmVzb2x2ZWQgb3IgdGltZWQgb3V0KSByYXRoZXIgdGhhbiBjYW5jZWxsZWQgb3IgYWJhbmRvbmVkLCBpbiBiYXNpcwpwb2ludHMuIEdhbWVzIHRoYXQgZXhwaXJlIHVucmVzb2x2ZWQgc3RheSBpbiBgYWN0aXZlX3Nlc3Npb25zYCB1bnRpbApgYWJhbmRvbl9leHBpcmVkYCBjbG9zZXMgdGhlbS4AAAAAAAAAAAtHbG9iYWxTdGF0cwAAAAAIAAAAAAAAAA9hY3RpdmVfc2Vzc2lvbnMAAAAABAAAAAAAAAAPZ2FtZXNfYWJhbmRvbmVkAAAAAAQAAAAAAAAAD2dhbWVzX2NhbmNlbGxlZAAAAAAEAAAAAAAAAA5nYW1lc19yZXNvbHZlZAAAAAAABAAAAAAAAAANZ2FtZXNfc3RhcnRlZAAAAAAAAAQAAAAAAAAAD2dhbWVzX3RpbWVkX291dAAAAAAEAAAAAAAAABNyZXNvbHV0aW9uX3JhdGVfYnBzAAAAAAQAAAAAAAAADHRvdGFsX3ZvbHVtZQAAAAs=",
+        "AAAAAwAAAB9Ib3cgYSBnYW1lIGVuZGVkIGZvciBvbmUgcGxheWVyAAAAAAAAAAALTWF0Y2hSZXN1bHQAAAAAAwAAAAAAAAADV29uAAAAAAAAAAAAAAAABExvc3QAAAABAAAAAAAAAAVEcmF3bgAAAAAAAAI=",
+        "AAAAAQAAAEJBIGBNYXRjaFJlY29yZGAgZnJvbSBvbmUgcGxheWVyJ3Mgc2lkZSwgYXMgbGlzdGVkIGJ5IGBnZXRfaGlzdG9yeWAAAAAAAAAAAAALR2FtZVN1bW1hcnkAAAAACQAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAACG9wcG9uZW50AAAAEwAAAAAAAAAPb3Bwb25lbnRfcG9pbnRzAAAAAAsAAAAAAAAADm9wcG9uZW50X3Njb3JlAAAAAAPoAAAABAAAAAAAAAAGcG9pbnRzAAAAAAALAAAAAAAAAAZyZXN1bHQAAAAAB9AAAAALTWF0Y2hSZXN1bHQAAAAAAAAAAAVzY29yZQAAAAAAA+gAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAE",
+        "AAAABQAAAIFQdWJsaXNoZWQgd2hlbiBhIGdhbWUgc3RhcnRzLCBieSBldmVyeSBgc3RhcnRfKmAgZW50cnlwb2ludC4gTGlrZSB0aGUKb3RoZXIgbGlmZWN5Y2xlIGV2ZW50cyBpdCBpcyBpbmRleGVkIGJ5IHNlc3Npb24gYW5kIHBsYXllcnMAAAAAAAAAAAAAC0dhbWVTdGFydGVkAAAAAAEAAAAMZ2FtZV9zdGFydGVkAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAEAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAEAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAHJQdWJsaXNoZWQgd2hlbiBhIHBsYXllciAob3IgdGVhbSBjYXB0YWluKSBzdWJtaXRzLCB3aXRoIHRoZSBwaGFzZSB0aGUKZ2FtZSBtb3ZlZCB0by4gU2F5cyBub3RoaW5nIGFib3V0IHRoZSB0YWN0aWMAAAAAAAAAAAAPVGFjdGljU3VibWl0dGVkAAAAAAEAAAAQdGFjdGljX3N1Ym1pdHRlZAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAVwaGFzZQAAAAAAB9AAAAAJR2FtZVBoYXNlAAAAAAAAAAAAAAI=",
+        "AAAABQAAAEZQdWJsaXNoZWQgd2hlbiBhIGdhbWUgaXMgc2V0dGxlZCBieSByZXNvbHV0aW9uLCBiZWZvcmUgdGhlIGh1YiBpcyB0b2xkAAAAAAAAAAAADU1hdGNoUmVzb2x2ZWQAAAAAAAABAAAADm1hdGNoX3Jlc29sdmVkAAAAAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAQAAAAAAAAAHb3V0Y29tZQAAAAfQAAAAB091dGNvbWUAAAAAAAAAAAAAAAANcGxheWVyMV9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAE5QdWJsaXNoZWQgd2hlbiBhIGdhbWUncyB3YWdlcnMgYXJlIGxvY2tlZCBpbiBlc2Nyb3csIHJpZ2h0IGFmdGVyCmBHYW1lU3RhcnRlZGAAAAAAAAAAAAAMRXNjcm93RnVuZGVkAAAAAQAAAA1lc2Nyb3dfZnVuZGVkAAAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAADXBsYXllcjFfdG9rZW4AAAAAAAATAAAAAAAAAAAAAAANcGxheWVyMl90b2tlbgAAAAAAABMAAAAAAAAAAAAAAA5wbGF5ZXIxX2Ftb3VudAAAAAAACwAAAAAAAAAAAAAADnBsYXllcjJfYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAEJQdWJsaXNoZWQgd2hlbiBhIHNldHRsZWQgZ2FtZSdzIGVzY3JvdyBwYXlzIGEgcGxheWVyIHN0cmFpZ2h0IGF3YXkAAAAAAAAAAAAMV2lubmluZ3NQYWlkAAAAAQAAAA13aW5uaW5nc19wYWlkAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGhQdWJsaXNoZWQgd2hlbiB0aGUgdG9rZW4gcmVmdXNlcyBhIHBheW91dCBhdCBzZXR0bGVtZW50OyB0aGUgYW1vdW50CnN0YXlzIGluIGVzY3JvdyBmb3IgYGNsYWltX3dpbm5pbmdzYAAAAAAAAAAMV2lubmluZ3NIZWxkAAAAAQAAAA13aW5uaW5nc19oZWxkAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAEVQdWJsaXNoZWQgd2hlbiBgY2xhaW1fd2lubmluZ3NgIHBheXMgb3V0IGFuIGFtb3VudCBoZWxkIGF0IHNldHRsZW1lbnQAAAAAAAAAAAAAD1dpbm5pbmdzQ2xhaW1lZAAAAAABAAAAEHdpbm5pbmdzX2NsYWltZWQAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAGRQdWJsaXNoZWQgd2hlbiBhIGRyYXduLCBjYW5jZWxsZWQsIHRpbWVkLW91dCBvciBleHBpcmVkIGdhbWUncyBlc2Nyb3cKcmVmdW5kcyBhIHBsYXllciBzdHJhaWdodCBhd2F5AAAAAAAAAApSZWZ1bmRQYWlkAAAAAAABAAAAC3JlZnVuZF9wYWlkAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAFhQdWJsaXNoZWQgd2hlbiB0aGUgdG9rZW4gcmVmdXNlcyBhIHJlZnVuZDsgdGhlIGFtb3VudCBzdGF5cyBpbiBlc2Nyb3cKZm9yIGBjbGFpbV9yZWZ1bmRgAAAAAAAAAApSZWZ1bmRIZWxkAAAAAAABAAAAC3JlZnVuZF9oZWxkAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAADRQdWJsaXNoZWQgd2hlbiBgY2xhaW1fcmVmdW5kYCBwYXlzIG91dCBhIGhlbGQgcmVmdW5kAAAAAAAAAA1SZWZ1bmRDbGFpbWVkAAAAAAAAAQAAAA5yZWZ1bmRfY2xhaW1lZAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAD5QdWJsaXNoZWQgd2hlbiBib3RoIHBsYXllcnMgY2FsbCBhIGdhbWUgb2ZmIHdpdGggYGNhbmNlbF9nYW1lYAAAAAAAAAAAAA1HYW1lQ2FuY2VsbGVkAAAAAAAAAQAAAA5nYW1lX2NhbmNlbGxlZAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAEAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAEAAAAC",
+        "AAAABQAAADpQdWJsaXNoZWQgd2hlbiBgcmVzb2x2ZV90aW1lb3V0YCBzZXR0bGVzIGEgZ2FtZSBieSBmb3JmZWl0AAAAAAAAAAAACFRpbWVkT3V0AAAAAQAAAAl0aW1lZF9vdXQAAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAQAAAAAAAAAGd2lubmVyAAAAAAATAAAAAAAAAAAAAAAHb3V0Y29tZQAAAAfQAAAAB091dGNvbWUAAAAAAAAAAAI=",
+        "AAAABQAAAGdQdWJsaXNoZWQgYnkgYGFiYW5kb25fZXhwaXJlZGA6IHRoZSBodWIgaG9sZHMgdGhlc2UgcG9pbnRzIGZvciBhIHNlc3Npb24KdGhhdCBjYW4gbm8gbG9uZ2VyIGJlIHJlc29sdmVkAAAAAAAAAAAQU2Vzc2lvbkFiYW5kb25lZAAAAAEAAAARc2Vzc2lvbl9hYmFuZG9uZWQAAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAHhQdWJsaXNoZWQgd2hlbiBhIHNldHRsZWQgZ2FtZSdzIHJlc3VsdCBjb3VsZCBub3QgYmUgcmVwb3J0ZWQgdG8gdGhlIGh1YiwKd2hpY2ggaGFzIHRvIGJlIHJldHJpZWQgd2l0aCBgcmV0cnlfaHViX25vdGlmeWAAAAAAAAAAD0h1Yk5vdGlmeUZhaWxlZAAAAAABAAAAEWh1Yl9ub3RpZnlfZmFpbGVkAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAAAAAAB291dGNvbWUAAAAH0AAAAAdPdXRjb21lAAAAAAAAAAAC",
+        "AAAABQAAAHBQdWJsaXNoZWQgd2hlbiB0aGUgYWRtaW4gcHJvcG9zZXMgaGFuZGluZyBvdmVyIHRvIGBuZXdfYWRtaW5gLCB3aG8gaGFzCnRvIGBhY2NlcHRfYWRtaW5gIGJlZm9yZSBhbnl0aGluZyBjaGFuZ2VzAAAAAAAAAA1BZG1pblByb3Bvc2VkAAAAAAAAAQAAAA5hZG1pbl9wcm9wb3NlZAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAADZQdWJsaXNoZWQgd2hlbiBhIHByb3Bvc2VkIGFkbWluIGFjY2VwdHMgYW5kIHRha2VzIG92ZXIAAAAAAAAAAAAQQWRtaW5UcmFuc2ZlcnJlZAAAAAEAAAARYWRtaW5fdHJhbnNmZXJyZWQAAAAAAAACAAAAAAAAAA5wcmV2aW91c19hZG1pbgAAAAAAEwAAAAAAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAG9QdWJsaXNoZWQgd2hlbiBhbiB1cGdyYWRlIGlzIHByb3Bvc2VkLCBnaXZpbmcgcGxheWVycyB1bnRpbApgZXhlY3V0YWJsZV9sZWRnZXJgIHRvIHNldHRsZSB0aGVpciBnYW1lcyBhbmQgbGVhdmUAAAAAAAAAAA9VcGdyYWRlUHJvcG9zZWQAAAAAAQAAABB1cGdyYWRlX3Byb3Bvc2VkAAAAAgAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAAAAAAABFleGVjdXRhYmxlX2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAC1QdWJsaXNoZWQgd2hlbiBhIHBlbmRpbmcgdXBncmFkZSBpcyB3aXRoZHJhd24AAAAAAAAAAAAAEFVwZ3JhZGVDYW5jZWxsZWQAAAABAAAAEXVwZ3JhZGVfY2FuY2VsbGVkAAAAAAAAAQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAADpQdWJsaXNoZWQgd2hlbiBgZXhlY3V0ZV91cGdyYWRlYCBzd2FwcyB0aGUgY29udHJhY3QncyBjb2RlAAAAAAAAAAAAD1VwZ3JhZGVFeGVjdXRlZAAAAAABAAAAEHVwZ3JhZGVfZXhlY3V0ZWQAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAC",
+        "AAAABQAAADtQdWJsaXNoZWQgd2hlbiBgc2V0X2h1YmAgcG9pbnRzIHRoZSBjb250cmFjdCBhdCBhbm90aGVyIGh1YgAAAAAAAAAACkh1YkNoYW5nZWQAAAAAAAEAAAALaHViX2NoYW5nZWQAAAAAAgAAAAAAAAAMcHJldmlvdXNfaHViAAAD6AAAABMAAAAAAAAAAAAAAAduZXdfaHViAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAEdQdWJsaXNoZWQgYnkgYHBhdXNlYCBhbmQgYHVucGF1c2VgLCBpbmNsdWRpbmcgY2FsbHMgdGhhdCBjaGFuZ2UKbm90aGluZwAAAAAAAAAADFBhdXNlQ2hhbmdlZAAAAAEAAAANcGF1c2VfY2hhbmdlZAAAAAAAAAIAAAAAAAAACndhc19wYXVzZWQAAAAAAAEAAAAAAAAAAAAAAAZwYXVzZWQAAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAC1QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBzZXRzIG5ldyB3YWdlciBsaW1pdHMAAAAAAAAAAAAAEldhZ2VyTGltaXRzQ2hhbmdlZAAAAAAAAQAAABR3YWdlcl9saW1pdHNfY2hhbmdlZAAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAAAAAACHByZXZpb3VzAAAH0AAAAAtXYWdlckxpbWl0cwAAAAAAAAAAAAAAAAZsaW1pdHMAAAAAB9AAAAALV2FnZXJMaW1pdHMAAAAAAAAAAAI=",
+        "AAAABQAAAEJQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSB0b2tlbiBuZXcgZ2FtZXMgYXJlIHdhZ2VyZWQgaW4AAAAAAAAAAAARV2FnZXJUb2tlbkNoYW5nZWQAAAAAAAABAAAAE3dhZ2VyX3Rva2VuX2NoYW5nZWQAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAIcHJldmlvdXMAAAPoAAAAEwAAAAAAAAAAAAAABXRva2VuAAAAAAAD6AAAABMAAAAAAAAAAg==",
+        "AAAABQAAAD1QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSBwcm90b2NvbCBmZWUgb24gbmV3IGdhbWVzAAAAAAAAAAAAAApGZWVDaGFuZ2VkAAAAAAABAAAAC2ZlZV9jaGFuZ2VkAAAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAAAAAADHByZXZpb3VzX2JwcwAAAAQAAAAAAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAD1QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHdoZXJlIHdpdGhkcmF3biBmZWVzIGFyZSBzZW50AAAAAAAAAAAAABNGZWVDb2xsZWN0b3JDaGFuZ2VkAAAAAAEAAAAVZmVlX2NvbGxlY3Rvcl9jaGFuZ2VkAAAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAIcHJldmlvdXMAAAATAAAAAAAAAAAAAAAJY29sbGVjdG9yAAAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAEVQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBzZW5kcyBhIHRva2VuJ3MgYWNjcnVlZCBmZWVzIHRvIHRoZSBjb2xsZWN0b3IAAAAAAAAAAAAADUZlZXNXaXRoZHJhd24AAAAAAAABAAAADmZlZXNfd2l0aGRyYXduAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAAAAAAljb2xsZWN0b3IAAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAIhQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSBwcmljZSBvcmFjbGUgZm9yIG1peGVkLWFzc2V0IGdhbWVzCihgb3JhY2xlYCBOb25lOiBtaXhlZC1hc3NldCBnYW1lcyBhcmUgb2ZmLCBhbmQgdGhlIGxpbWl0cyByZWFkIDApAAAAAAAAABNPcmFjbGVDb25maWdDaGFuZ2VkAAAAAAEAAAAVb3JhY2xlX2NvbmZpZ19jaGFuZ2VkAAAAAAAABQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAIcHJldmlvdXMAAAPoAAAAEwAAAAAAAAAAAAAABm9yYWNsZQAAAAAD6AAAABMAAAAAAAAAAAAAAA10b2xlcmFuY2VfYnBzAAAAAAAABAAAAAAAAAAAAAAAD21heF9hZ2Vfc2Vjb25kcwAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAEJQdWJsaXNoZWQgd2hlbiBgYmV0dG9yYCBzdGFrZXMgYGFtb3VudGAgb24gYHBsYXllcmAgd2lubmluZyBhIGdhbWUAAAAAAAAAAAANU2lkZUJldFBsYWNlZAAAAAAAAAEAAAAPc2lkZV9iZXRfcGxhY2VkAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAZiZXR0b3IAAAAAABMAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAERQdWJsaXNoZWQgd2hlbiBhIGdhbWUncyBzaWRlIGJldHMgc2V0dGxlLCBgcmFrZWAgZ29pbmcgdG8gdGhlIHdpbm5lcgAAAAAAAAAPU2lkZUJldHNTZXR0bGVkAAAAAAEAAAARc2lkZV9iZXRzX3NldHRsZWQAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcmVzdWx0AAAAAAfQAAAADVNpZGVCZXRSZXN1bHQAAAAAAAAAAAAAAAAAAARyYWtlAAAACwAAAAAAAAAC",
+        "AAAABQAAAG1QdWJsaXNoZWQgd2hlbiBhIHNpZGUtYmV0IHBvb2wgcGF5cyBgcmVjaXBpZW50YDogYSBiZXR0b3IncyBwYXlvdXQgb3IKcmVmdW5kLCBvciB0aGUgcmFrZSB0byB0aGUgbWF0Y2ggd2lubmVyAAAAAAAAAAAAAAtTaWRlQmV0UGFpZAAAAAABAAAADXNpZGVfYmV0X3BhaWQAAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAD5QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSByYWtlIG9uIG5ldyBzaWRlLWJldCBwb29scwAAAAAAAAAAABJTaWRlQmV0UmFrZUNoYW5nZWQAAAAAAAEAAAAVc2lkZV9iZXRfcmFrZV9jaGFuZ2VkAAAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAMcHJldmlvdXNfYnBzAAAABAAAAAAAAAAAAAAACHJha2VfYnBzAAAABAAAAAAAAAAC",
+        "AAAABQAAAFFQdWJsaXNoZWQgd2hlbiBgcHJvdmlkZXJgIGFkZHMgYGFtb3VudGAgdG8gYSB0b2tlbidzIGhvdXNlIGJhbmtyb2xsLApmb3IgYHNoYXJlc2AAAAAAAAAAAAAAEUJhbmtyb2xsRGVwb3NpdGVkAAAAAAAAAQAAABJiYW5rcm9sbF9kZXBvc2l0ZWQAAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAABnNoYXJlcwAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAFNQdWJsaXNoZWQgd2hlbiBgcHJvdmlkZXJgIHJlZGVlbXMgYHNoYXJlc2Agb2YgYSB0b2tlbidzIGhvdXNlIGJhbmtyb2xsCmZvciBgYW1vdW50YAAAAAAAAAAAEUJhbmtyb2xsV2l0aGRyYXduAAAAAAAAAQAAABJiYW5rcm9sbF93aXRoZHJhd24AAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAABnNoYXJlcwAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGNQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCBhZGRzIGBhbW91bnRgIHRvIHRoZWlyIHByZXBhaWQgYmFsYW5jZSBpbgpgdG9rZW5gLCBicmluZ2luZyBpdCB0byBgYmFsYW5jZWAAAAAAAAAAABBCYWxhbmNlRGVwb3NpdGVkAAAAAQAAABFiYWxhbmNlX2RlcG9zaXRlZAAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGFQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCB0YWtlcyBgYW1vdW50YCBvdXQgb2YgdGhlaXIgcHJlcGFpZCBiYWxhbmNlIGluCmB0b2tlbmAsIGxlYXZpbmcgYGJhbGFuY2VgAAAAAAAAAAAAABBCYWxhbmNlV2l0aGRyYXduAAAAAQAAABFiYWxhbmNlX3dpdGhkcmF3bgAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAC",
+        "AAAABQAAADxQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCBuYW1lcyB0aGUgYWRkcmVzcyB0aGF0IHJlZmVycmVkIHRoZW0AAAAAAAAAElJlZmVycmVyUmVnaXN0ZXJlZAAAAAAAAQAAABNyZWZlcnJlcl9yZWdpc3RlcmVkAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAACHJlZmVycmVyAAAAEwAAAAAAAAAC",
+        "AAAABQAAAFVQdWJsaXNoZWQgd2hlbiBgcmVmZXJyZXJgIGlzIGNyZWRpdGVkIGEgc2hhcmUgb2YgdGhlIGZlZSB0YWtlbiBmcm9tCmByZWZlcmVlYCdzIHN0YWtlAAAAAAAAAAAAABBSZWZlcnJhbFJld2FyZGVkAAAAAQAAABFyZWZlcnJhbF9yZXdhcmRlZAAAAAAAAAUAAAAAAAAACHJlZmVycmVyAAAAEwAAAAEAAAAAAAAAB3JlZmVyZWUAAAAAEwAAAAAAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAD9QdWJsaXNoZWQgd2hlbiBhIHJlZmVycmVyJ3MgcmV3YXJkcyBpbiBgdG9rZW5gIGFyZSBzZW50IHRvIHRoZW0AAAAAAAAAABZSZWZlcnJhbFJld2FyZHNDbGFpbWVkAAAAAAABAAAAGHJlZmVycmFsX3Jld2FyZHNfY2xhaW1lZAAAAAMAAAAAAAAACHJlZmVycmVyAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAFFQdWJsaXNoZWQgd2hlbiBhIHdvbiBwb3QncyBmZWUgc2VuZHMgdGhlIGh1YidzIHNoYXJlLCBgYW1vdW50YCwgdG8gaXRzCmB0cmVhc3VyeWAAAAAAAAAAAAAADkh1YlJldmVudWVQYWlkAAAAAAABAAAAEGh1Yl9yZXZlbnVlX3BhaWQAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAHlQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSBlcG9jaCBydWxlcyAoYHRva2VuYCBOb25lIGFuZCB0aGUKcmVzdCAwOiBubyBlcG9jaHMpLiBUaGUgbmV4dCBlcG9jaCBpcyBgZmlyc3RfZXBvY2hgAAAAAAAAAAAAABJFcG9jaENvbmZpZ0NoYW5nZWQAAAAAAAEAAAAUZXBvY2hfY29uZmlnX2NoYW5nZWQAAAAGAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAAAAAAV0b2tlbgAAAAAAA+gAAAATAAAAAAAAAAAAAAAObGVuZ3RoX2xlZGdlcnMAAAAAAAQAAAAAAAAAAAAAAAlzaGFyZV9icHMAAAAAAAAEAAAAAAAAAAAAAAAFdG9wX24AAAAAAAAEAAAAAAAAAAAAAAALZmlyc3RfZXBvY2gAAAAABAAAAAAAAAAC",
+        "AAAABQAAAD5QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBwdWJsaXNoZXMgdGhlIHJld2FyZCB0cmVlIGZvciBhbiBlcG9jaAAAAAAAAAAAAAxFcG9jaFJvb3RTZXQAAAABAAAADmVwb2NoX3Jvb3Rfc2V0AAAAAAAEAAAAAAAAAAVlcG9jaAAAAAAAAAQAAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAAAAAARyb290AAAD7gAAACAAAAAAAAAAAAAAAAZsZWF2ZXMAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAADpQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCBjbGFpbXMgYGFtb3VudGAgb2YgYW4gZXBvY2gncyBwb29sAAAAAAAAAAAAEkVwb2NoUmV3YXJkQ2xhaW1lZAAAAAAAAQAAABRlcG9jaF9yZXdhcmRfY2xhaW1lZAAAAAQAAAAAAAAABWVwb2NoAAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGVQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHdobyBydWxlcyBvbiBkaXNwdXRlcyAoYG1vZHVsZWAgTm9uZToKc2V0dGxlbWVudHMgY2Fubm90IGJlIGRpc3B1dGVkKQAAAAAAAAAAAAAURGlzcHV0ZUNvbmZpZ0NoYW5nZWQAAAABAAAAFmRpc3B1dGVfY29uZmlnX2NoYW5nZWQAAAAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAAAAAABm1vZHVsZQAAAAAD6AAAABMAAAAAAAAAAAAAAA53aW5kb3dfbGVkZ2VycwAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAFVQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCBhZGRzIGBhbW91bnRgIHRvIHRoZWlyIGJvbmQgaW4gYHRva2VuYCwKYnJpbmdpbmcgaXQgdG8gYGJvbmRgAAAAAAAAAAAAAApCb25kUG9zdGVkAAAAAAABAAAAC2JvbmRfcG9zdGVkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAABGJvbmQAAAALAAAAAAAAAAI=",
+        "AAAABQAAAFNQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCB0YWtlcyBgYW1vdW50YCBvdXQgb2YgdGhlaXIgYm9uZCBpbiBgdG9rZW5gLApsZWF2aW5nIGBib25kYAAAAAAAAAAADUJvbmRXaXRoZHJhd24AAAAAAAABAAAADmJvbmRfd2l0aGRyYXduAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAARib25kAAAACwAAAAAAAAAC",
+        "AAAABQAAAHdQdWJsaXNoZWQgd2hlbiBhIHdvbiBwb3Qgc2V0dGxlcyBpbnRvIGl0cyBkaXNwdXRlIHdpbmRvdywgbG9ja2luZwpgbG9ja2VkYCBvZiB0aGUgd2lubmVyJ3MgYm9uZCB1bnRpbCBgZGVhZGxpbmVfbGVkZ2VyYAAAAAAAAAAAE0Rpc3B1dGVXaW5kb3dPcGVuZWQAAAAAAQAAABVkaXNwdXRlX3dpbmRvd19vcGVuZWQAAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGd2lubmVyAAAAAAATAAAAAAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGbG9ja2VkAAAAAAALAAAAAAAAAAAAAAAPZGVhZGxpbmVfbGVkZ2VyAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAC5QdWJsaXNoZWQgd2hlbiB0aGUgbG9zZXIgZGlzcHV0ZXMgYSBzZXR0bGVtZW50AAAAAAAAAAAAElNldHRsZW1lbnREaXNwdXRlZAAAAAAAAQAAABNzZXR0bGVtZW50X2Rpc3B1dGVkAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAVsb3NlcgAAAAAAABMAAAAAAAAAAAAAAAZtb2R1bGUAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAHBQdWJsaXNoZWQgd2hlbiB0aGUgZGlzcHV0ZSBtb2R1bGUgcnVsZXM7IGFuIHVwaGVsZCBkaXNwdXRlIHBheXMgdGhlCmxvc2VyIGBjbGF3ZWRfYmFja2Agb3V0IG9mIHRoZSB3aW5uZXIncyBib25kAAAAAAAAAA9EaXNwdXRlUmVzb2x2ZWQAAAAAAQAAABBkaXNwdXRlX3Jlc29sdmVkAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnVwaGVsZAAAAAAAAQAAAAAAAAAAAAAABndpbm5lcgAAAAAAEwAAAAAAAAAAAAAABWxvc2VyAAAAAAAAEwAAAAAAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAAC2NsYXdlZF9iYWNrAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAFxQdWJsaXNoZWQgd2hlbiBhIGRpc3B1dGUgd2luZG93IGNsb3NlcyB1bmRpc3B1dGVkLCByZWxlYXNpbmcKYHJlbGVhc2VkYCBvZiB0aGUgd2lubmVyJ3MgYm9uZAAAAAAAAAATU2V0dGxlbWVudEZpbmFsaXplZAAAAAABAAAAFHNldHRsZW1lbnRfZmluYWxpemVkAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABndpbm5lcgAAAAAAEwAAAAAAAAAAAAAACHJlbGVhc2VkAAAACwAAAAAAAAAC",
+        "AAAABQAAAE5QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHN0YWtpbmcgKGB0b2tlbmAgTm9uZSwgdGhlIHJlc3QgMDogbm8Kc3Rha2luZykAAAAAAAAAAAAUU3Rha2luZ0NvbmZpZ0NoYW5nZWQAAAABAAAAFnN0YWtpbmdfY29uZmlnX2NoYW5nZWQAAAAAAAUAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAAAAAABXRva2VuAAAAAAAD6AAAABMAAAAAAAAAAAAAAAV0aWVycwAAAAAAAAQAAAAAAAAAAAAAAA5sb2NrdXBfbGVkZ2VycwAAAAAABAAAAAAAAAAAAAAAEGNvb2xkb3duX2xlZGdlcnMAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAHdQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCBzdGFrZXMgYGFtb3VudGAsIGJyaW5naW5nIHRoZWlyIGFjdGl2ZSBzdGFrZQp0byBgc3Rha2VgLCBhbGwgbG9ja2VkIHVudGlsIGBsb2NrZWRfdW50aWxfbGVkZ2VyYAAAAAAAAAAABlN0YWtlZAAAAAAAAQAAAAZzdGFrZWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAABXN0YWtlAAAAAAAACwAAAAAAAAAAAAAAE2xvY2tlZF91bnRpbF9sZWRnZXIAAAAABAAAAAAAAAAC",
+        "AAAABQAAAINQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCB1bnN0YWtlcyBgYW1vdW50YCwgYnJpbmdpbmcgd2hhdCBpcyBpbiBpdHMKY29vbGRvd24gdG8gYHVuc3Rha2luZ2AsIHdpdGhkcmF3YWJsZSBmcm9tIGBjb29sZG93bl9lbmRfbGVkZ2VyYAAAAAAAAAAACFVuc3Rha2VkAAAAAQAAAAh1bnN0YWtlZAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAACXVuc3Rha2luZwAAAAAAAAsAAAAAAAAAAAAAABNjb29sZG93bl9lbmRfbGVkZ2VyAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAEhQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCB3aXRoZHJhd3MgYGFtb3VudGAgb2YgYHRva2VuYCBhZnRlciBpdHMgY29vbGRvd24AAAAAAAAADlN0YWtlV2l0aGRyYXduAAAAAAABAAAAD3N0YWtlX3dpdGhkcmF3bgAAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAFVQdWJsaXNoZWQgd2hlbiBhIHBvdCdzIHdpbm5lciBwYXlzIGEgZmVlIGN1dCBieSBgZGlzY291bnRfYnBzYCBmb3IKdGhlaXIgc3Rha2luZyB0aWVyAAAAAAAAAAAAAA1GZWVEaXNjb3VudGVkAAAAAAAAAQAAAA5mZWVfZGlzY291bnRlZAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAAAAAADGRpc2NvdW50X2JwcwAAAAQAAAAAAAAAAg==",
+        "AAAABQAAADpQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSBodWIncyBzaGFyZSBvZiB0aGUgZmVlAAAAAAAAAAAAD0h1YlNoYXJlQ2hhbmdlZAAAAAABAAAAEWh1Yl9zaGFyZV9jaGFuZ2VkAAAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAMcHJldmlvdXNfYnBzAAAABAAAAAAAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAD9QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSByZWZlcnJlcnMnIHNoYXJlIG9mIHRoZSBmZWUAAAAAAAAAABRSZWZlcnJhbFNoYXJlQ2hhbmdlZAAAAAEAAAAWcmVmZXJyYWxfc2hhcmVfY2hhbmdlZAAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAMcHJldmlvdXNfYnBzAAAABAAAAAAAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAGtQdWJsaXNoZWQgd2hlbiBgcGxheWVyYCB3aW5zIGEgcG90IG9uIGEgc3RyZWFrIG9mIGBzdHJlYWtgIHdpbnMgYW5kIGlzCm93ZWQgYSBib251cyBvdXQgb2YgdGhlIGFjY3J1ZWQgZmVlcwAAAAAAAAAAElN0cmVha0JvbnVzQXdhcmRlZAAAAAAAAQAAABRzdHJlYWtfYm9udXNfYXdhcmRlZAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAZzdHJlYWsAAAAAAAQAAAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAEJQdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSBzdHJlYWsgYm9udXMgKGFsbCAwOiBubyBib251cykAAAAAAAAAAAASU3RyZWFrQm9udXNDaGFuZ2VkAAAAAAABAAAAFHN0cmVha19ib251c19jaGFuZ2VkAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAAAAAAKbWluX3N0cmVhawAAAAAABAAAAAAAAAAAAAAACHN0ZXBfYnBzAAAABAAAAAAAAAAAAAAAB21heF9icHMAAAAABAAAAAAAAAAC",
+        "AAAABQAAAF5QdWJsaXNoZWQgd2hlbiBgcGxheWVyYCBjb21wbGV0ZXMgYSBqYWNrcG90IHJ1biBhbmQgaXMgb3dlZCBgYW1vdW50YApvZiB0aGUgamFja3BvdCBpbiBgdG9rZW5gAAAAAAAAAAAACkphY2twb3RXb24AAAAAAAEAAAALamFja3BvdF93b24AAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAAA3J1bgAAAAAEAAAAAAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAF5QdWJsaXNoZWQgd2hlbiBgY2FsbGVyYCBjaGFuZ2VzIHRoZSBqYWNrcG90IHJ1bGVzIChgZW5hYmxlZGAgZmFsc2UgYW5kCnRoZSByZXN0IDA6IG5vIGphY2twb3QpAAAAAAAAAAAAFEphY2twb3RDb25maWdDaGFuZ2VkAAAAAQAAABZqYWNrcG90X2NvbmZpZ19jaGFuZ2VkAAAAAAAHAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAAAAAAdlbmFibGVkAAAAAAEAAAAAAAAAAAAAAAlzaGFyZV9icHMAAAAAAAAEAAAAAAAAAAAAAAAGdGFjdGljAAAAAAAEAAAAAAAAAAAAAAANd2luc19pbl9hX3JvdwAAAAAAAAQAAAAAAAAAAAAAAApwYXlvdXRfYnBzAAAAAAAEAAAAAAAAAAAAAAAJbWluX3N0YWtlAAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAFJQdWJsaXNoZWQgd2hlbiBhIHJlaW5pdGlhbGl6YXRpb24gaXMgcHJvcG9zZWQsIGV4ZWN1dGFibGUgZnJvbQpgZXhlY3V0YWJsZV9sZWRnZXJgAAAAAAAAAAAADlJlaW5pdFByb3Bvc2VkAAAAAAABAAAAD3JlaW5pdF9wcm9wb3NlZAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAAAAAAAAAAABFleGVjdXRhYmxlX2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAADhQdWJsaXNoZWQgd2hlbiBgcmVpbml0aWFsaXplYCByZXBsYWNlcyB0aGUgYWRtaW4gYW5kIGh1YgAAAAAAAAANUmVpbml0aWFsaXplZAAAAAAAAAEAAAANcmVpbml0aWFsaXplZAAAAAAAAAQAAAAAAAAADnByZXZpb3VzX2FkbWluAAAAAAATAAAAAAAAAAAAAAAMcHJldmlvdXNfaHViAAAD6AAAABMAAAAAAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAAAAAAAg==",
+        "AAAAAQAAAF5BZG1pbnMgd2hvIG11c3QgYXBwcm92ZSBzZW5zaXRpdmUgYWN0aW9ucywgYHRocmVzaG9sZGAgb2YgdGhlbSBhdCBhCnRpbWUgKGBzZXRfYWRtaW5fY291bmNpbGApAAAAAAAAAAAADEFkbWluQ291bmNpbAAAAAIAAAAAAAAAB21lbWJlcnMAAAAD6gAAABMAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
+        "AAAAAwAAAMNEYXktdG8tZGF5IGR1dGllcyB0aGUgYWRtaW4gY2FuIGRlbGVnYXRlIHdpdGggYGdyYW50X3JvbGVgLCBzbyB0aGV5IGRvCm5vdCBuZWVkIHRoZSBrZXkgdGhhdCBjb250cm9scyB1cGdyYWRlcy4gVGhlIGFkbWluIGhvbGRzIGV2ZXJ5IHJvbGUsIGFuZApgQWRtaW5gIGhvbGRlcnMgbWF5IGRvIHdoYXRldmVyIHRoZSBvdGhlciByb2xlcyBjYW4AAAAAAAAAAARSb2xlAAAABAAAAAAAAAAFQWRtaW4AAAAAAAAAAAAARUdhbWUgc2V0dGluZ3M6IHRpbWVvdXRzLCBhdXRvLXJlc29sdmUsIHRpZWJyZWFrcyBhbmQgb3BlbiBnYW1lIGxpbWl0cwAAAAAAAAhPcGVyYXRvcgAAAAEAAAAaUGF1c2luZyBkdXJpbmcgYW4gaW5jaWRlbnQAAAAAAAZQYXVzZXIAAAAAAAIAAAAfRWNvbm9taWMgc2V0dGluZ3M6IHdhZ2VyIGxpbWl0cwAAAAAKRmVlTWFuYWdlcgAAAAAAAw==",
+        "AAAAAgAAAEdBbiBhY3Rpb24gdGhhdCBuZWVkcyB0aGUgYWRtaW4gY291bmNpbCdzIGFwcHJvdmFsIG9uY2UgYSBjb3VuY2lsIGlzIHNldAAAAAAAAAAADUNvdW5jaWxBY3Rpb24AAAAAAAAjAAAAAQAAAAAAAAAGU2V0SHViAAAAAAABAAAAEwAAAAAAAAAAAAAABVBhdXNlAAAAAAAAAAAAAAAAAAAHVW5wYXVzZQAAAAABAAAAAAAAAA5Qcm9wb3NlVXBncmFkZQAAAAAAAQAAA+4AAAAgAAAAAQAAAAAAAAAMUmVpbml0aWFsaXplAAAAAgAAABMAAAATAAAAAQAAAAAAAAAKU2V0Q291bmNpbAAAAAAAAQAAB9AAAAAMQWRtaW5Db3VuY2lsAAAAAAAAAAAAAAANUmVtb3ZlQ291bmNpbAAAAAAAAAEAAAAAAAAADFByb3Bvc2VBZG1pbgAAAAEAAAATAAAAAQAAAAAAAAALQWNjZXB0QWRtaW4AAAAAAQAAABMAAAABAAAAAAAAAAtTZXRWZXJpZmllcgAAAAABAAAAEwAAAAEAAAAAAAAAElNldFZlcmlmaWNhdGlvbktleQAAAAAAAQAAA+4AAAAgAAAAAQAAAAAAAAAJR3JhbnRSb2xlAAAAAAAAAgAAABMAAAfQAAAABFJvbGUAAAABAAAAAAAAAApSZXZva2VSb2xlAAAAAAACAAAAEwAAB9AAAAAEUm9sZQAAAAEAAAAAAAAAEFNldERpc3B1dGVDb25maWcAAAABAAAH0AAAAA1EaXNwdXRlQ29uZmlnAAAAAAAAAAAAAAAAAAATUmVtb3ZlRGlzcHV0ZUNvbmZpZwAAAAABAAAAAAAAAAlTZXRGZWVCcHMAAAAAAAABAAAABAAAAAEAAAAAAAAAD1NldEZlZUNvbGxlY3RvcgAAAAABAAAAEwAAAAEAAAAAAAAAD1NldE9yYWNsZUNvbmZpZwAAAAABAAAH0AAAAAxPcmFjbGVDb25maWcAAAAAAAAAAAAAABJSZW1vdmVPcmFjbGVDb25maWcAAAAAAAEAAAAAAAAADlNldEhvdXNlQmVhY29uAAAAAAABAAAAEwAAAAEAAAAAAAAAC1NldFZrU291cmNlAAAAAAEAAAfQAAAACFZrU291cmNlAAAAAAAAAAAAAAAOUmVtb3ZlVmtTb3VyY2UAAAAAAAEAAAAAAAAAFFNldFVsdHJhSG9ua1ZlcmlmaWVyAAAAAQAAABMAAAABAAAAAAAAAA5TZXRQcm9vZlN5c3RlbQAAAAAAAQAAB9AAAAALUHJvb2ZTeXN0ZW0AAAAAAQAAAAAAAAAQU2V0U3Rha2VWZXJpZmllcgAAAAEAAAATAAAAAQAAAAAAAAARU2V0UmF0aW5nVmVyaWZpZXIAAAAAAAABAAAAEwAAAAEAAAAAAAAADFNldFN5YmlsR2F0ZQAAAAEAAAPuAAAAIAAAAAAAAAAAAAAAD1JlbW92ZVN5YmlsR2F0ZQAAAAABAAAAAAAAABRTZXRTaW11bGF0aW9uSW1hZ2VJZAAAAAEAAAPuAAAAIAAAAAAAAAAAAAAAF1JlbW92ZVNpbXVsYXRpb25JbWFnZUlkAAAAAAEAAAAAAAAAElNldFJlY2VpcHRWZXJpZmllcgAAAAAAAQAAABMAAAABAAAAAAAAABFTZXRSb3VuZHNWZXJpZmllcgAAAAAAAAEAAAATAAAAAQAAAAAAAAAUU2V0QmF0Y2hWZXJpZmljYXRpb24AAAABAAAAAQAAAAAAAAAAAAAAB01pZ3JhdGUAAAAAAQAAAAAAAAAPU2V0VXBncmFkZURlbGF5AAAAAAEAAAAE",
+        "AAAAAQAAADhBbiB1cGdyYWRlIHdhaXRpbmcgb3V0IGl0cyBkZWxheSAoYGdldF9wZW5kaW5nX3VwZ3JhZGVgKQAAAAAAAAAOUGVuZGluZ1VwZ3JhZGUAAAAAAAIAAAApRmlyc3QgbGVkZ2VyIGBleGVjdXRlX3VwZ3JhZGVgIG1heSBydW4gaW4AAAAAAAARZXhlY3V0YWJsZV9sZWRnZXIAAAAAAAAEAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
+        "AAAAAQAAAEdBIHJlaW5pdGlhbGl6YXRpb24gd2FpdGluZyBvdXQgdGhlIHVwZ3JhZGUgZGVsYXkKKGBnZXRfcGVuZGluZ19yZWluaXRgKQAAAAAAAAAADVBlbmRpbmdSZWluaXQAAAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAmRmlyc3QgbGVkZ2VyIGByZWluaXRpYWxpemVgIG1heSBydW4gaW4AAAAAABFleGVjdXRhYmxlX2xlZGdlcgAAAAAAAAQAAAAAAAAACGdhbWVfaHViAAAAEw==",
+        "AAAAAQAAAJlCb3VuZHMgYW5kIGNvbW1pdHRlZCBzdGFrZXMgb2YgYSBnYW1lIHN0YXJ0ZWQgd2l0aApgc3RhcnRfaGlkZGVuX3N0YWtlX2dhbWVgLiBFYWNoIHN0YWtlIHN0YXlzIGBOb25lYCB1bnRpbCBpdHMgcGxheWVyIG9wZW5zCnRoZSBoYXNoIHdpdGggYHJldmVhbF9zdGFrZWAAAAAAAAAAAAAADEhpZGRlblN0YWtlcwAAAAYAAAAAAAAACW1heF9zdGFrZQAAAAAAAAsAAAAAAAAACW1pbl9zdGFrZQAAAAAAAAsAAAAAAAAADXBsYXllcjFfc3Rha2UAAAAAAAPoAAAACwAAAAAAAAAScGxheWVyMV9zdGFrZV9oYXNoAAAAAAPuAAAAIAAAAAAAAAANcGxheWVyMl9zdGFrZQAAAAAAA+gAAAALAAAAAAAAABJwbGF5ZXIyX3N0YWtlX2hhc2gAAAAAA+4AAAAg",
+        "AAAAAQAAAM1UaGUgb2RkcyBvZiBhIGBzdGFydF9nYW1lX3dpdGhfb2Rkc2AgbWF0Y2g6IHBsYXllcjEgcmlza3MgYHBsYXllcjFgCnVuaXRzIGZvciBldmVyeSBgcGxheWVyMmAgdW5pdHMgcGxheWVyMiByaXNrcywgc28gYXQgMjoxIHBsYXllcjEgaXMgdGhlCmZhdm91cml0ZSBhbmQgd2lucyBoYWxmIHdoYXQgdGhleSBzdGFrZSwgYW5kIHBsYXllcjIgdHdpY2UgdGhlaXJzAAAAAAAAAAAAAARPZGRzAAAAAgAAAAAAAAAHcGxheWVyMQAAAAAEAAAAAAAAAAdwbGF5ZXIyAAAAAAQ=",
+        "AAAAAQAAAIZBIG1lbWJlciBvZiBhIDJ2MiB0ZWFtIChgc3RhcnRfdGVhbV9nYW1lYCkgYW5kIHRoZSBCTFMxMi0zODEgcHVibGljIGtleQooRzEsIHVuY29tcHJlc3NlZCkgdGhlaXIgcGFydCBvZiBlYWNoIHRlYW0gbW92ZSBpcyBzaWduZWQgd2l0aAAAAAAAAAAAAApUZWFtTWVtYmVyAAAAAAACAAAAAAAAAAdibHNfa2V5AAAAA+4AAABgAAAAAAAAAAZwbGF5ZXIAAAAAABM=",
+        "AAAAAQAAAL1Cb3RoIHNpZGVzIG9mIGEgMnYyIG1hdGNoLCBpbiBtZW1iZXIgb3JkZXIuIEVhY2ggdGVhbSdzIGZpcnN0IG1lbWJlciBpcwppdHMgY2FwdGFpbiBhbmQgaG9sZHMgdGhlIGdhbWUncyBgcGxheWVyMWAvYHBsYXllcjJgIHNsb3Q7IG1lbWJlciBgaWAKcGxheXMgcm91bmQgYGlgIGFnYWluc3QgdGhlIG9wcG9zaW5nIG1lbWJlciBgaWAAAAAAAAAAAAAABVRlYW1zAAAAAAAAAgAAAAAAAAAFdGVhbTEAAAAAAAPqAAAH0AAAAApUZWFtTWVtYmVyAAAAAAAAAAAABXRlYW0yAAAAAAAD6gAAB9AAAAAKVGVhbU1lbWJlcgAA",
+        "AAAAAQAAAIxXaGF0IGVhY2ggdGVhbW1hdGUgc2lnbnMgZm9yIGBzdWJtaXRfdGVhbV9tb3ZlYDogdGhlaXIgb3duIHRhY3RpYyBoYXNoCmluIG9uZSBzZXNzaW9uLiBUaGUgc2lnbmVkIG1lc3NhZ2UgaXMgdGhlIFhEUiBlbmNvZGluZyBvZiB0aGlzIHN0cnVjdAAAAAAAAAAIVGVhbU1vdmUAAAAEAAAAAAAAAAhjb250cmFjdAAAABMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAALdGFjdGljX2hhc2gAAAAD7gAAACA=",
+        "AAAAAQAAAOVBbnRpLXN5YmlsIGNvbmZpZ3VyYXRpb24gZm9yIGBzdGFydF9yYW5rZWRfZ2FtZWA6IHRoZSB2ZXJpZmllciBvZgp1bmlxdWVuZXNzIHByb29mcywgdGhlIGlkZW50aXR5IHNldCB0aGV5IHByb3ZlIG1lbWJlcnNoaXAgb2YgYW5kIHRoZQpicmFja2V0IHRoZWlyIG51bGxpZmllcnMgYXJlIHNjb3BlZCB0by4gTW92aW5nIHRvIGEgbmV3IGJyYWNrZXQgbGV0cyBldmVyeQppZGVudGl0eSBwbGF5IGFnYWluAAAAAAAAAAAAAAlTeWJpbEdhdGUAAAAAAAADAAAAAAAAAAdicmFja2V0AAAAAAQAAAAAAAAADWlkZW50aXR5X3Jvb3QAAAAAAAPuAAAAIAAAAAAAAAAIdmVyaWZpZXIAAAAT",
+        "AAAAAQAAAMtBIHBsYXllcidzIHVuaXF1ZW5lc3MgcHJvb2YgZm9yIGBzdGFydF9yYW5rZWRfZ2FtZWAuIFRoZSBudWxsaWZpZXIgaXMKZGVyaXZlZCBmcm9tIHRoZSBwbGF5ZXIncyBpZGVudGl0eSBhbmQgdGhlIGdhdGUncyBicmFja2V0LCBzbyBhbiBpZGVudGl0eQpoYXMgZXhhY3RseSBvbmUgcGVyIGJyYWNrZXQgd2hpY2hldmVyIGFkZHJlc3MgaXQgcGxheXMgZnJvbQAAAAAAAAAAD1VuaXF1ZW5lc3NQcm9vZgAAAAACAAAAAAAAAAludWxsaWZpZXIAAAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAO",
+        "AAAAAQAAAERJbmNsdXNpdmUgcmF0aW5nIHJhbmdlIGEgYHN0YXJ0X2JyYWNrZXRfZ2FtZWAgbWF0Y2ggaXMgcmVzdHJpY3RlZCB0bwAAAAAAAAANUmF0aW5nQnJhY2tldAAAAAAAAAIAAAAAAAAACm1heF9yYXRpbmcAAAAAAAQAAAAAAAAACm1pbl9yYXRpbmcAAAAAAAQ=",
+        "AAAAAQAAAK1TZXNzaW9uIGtleSBncmFudDogbGV0cyBga2V5YCBzaWduIGBzdWJtaXRfdGFjdGljYCBvbiB0aGUgcGxheWVyJ3MgYmVoYWxmCmZvciBvbmUgc2Vzc2lvbiwgYXMgbG9uZyBhcyB0aGUgcGxheWVyJ3Mgc3Rha2UgaXMgd2l0aGluIGBtYXhfc3Rha2VgIGFuZAp0aGUgZ3JhbnQgaGFzIG5vdCBleHBpcmVkLgAAAAAAAAAAAAAPU2Vzc2lvbktleUdyYW50AAAAAAMAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAAAAAADa2V5AAAAABMAAAAAAAAACW1heF9zdGFrZQAAAAAAAAs=",
+        "AAAAAQAAAIpQYXlsb2FkIGEgcGxheWVyIHNpZ25zIGZvciBgc3VibWl0X3RhY3RpY19zaWduZWRgLiBUaGUgc2lnbmVkIG1lc3NhZ2UgaXMKdGhlIFhEUiBlbmNvZGluZyBvZiB0aGlzIHN0cnVjdCAoYW4gYFNjTWFwYCBrZXllZCBieSBmaWVsZCBuYW1lKS4AAAAAAAAAAAAQU2lnbmVkU3VibWlzc2lvbgAAAAcAAAAAAAAACGNvbnRyYWN0AAAAEwAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAAAAAAZpbnB1dHMAAAAAB9AAAAAMUHVibGljSW5wdXRzAAAAAAAAAAVub25jZQAAAAAAAAYAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKcHJvb2ZfaGFzaAAAAAAD7gAAACAAAAAAAAAACnNlc3Npb25faWQAAAAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAVAAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAACFZlcmlmaWVyAAAAAQAAAAAAAAAKU2Vzc2lvbktleQAAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAFTm9uY2UAAAAAAAABAAAAEwAAAAAAAAAAAAAAD1ZlcmlmaWNhdGlvbktleQAAAAAAAAAAAAAAAAtQcm9vZlN5c3RlbQAAAAAAAAAAAAAAABFVbHRyYUhvbmtWZXJpZmllcgAAAAAAAAEAAAAAAAAACU51bGxpZmllcgAAAAAAAAEAAAPuAAAAIAAAAAAAAAAAAAAAFFByb29mRnJlc2huZXNzV2luZG93AAAAAAAAAAAAAAARQmF0Y2hWZXJpZmljYXRpb24AAAAAAAABAAAAAAAAAAxQZW5kaW5nUHJvb2YAAAACAAAABAAAABMAAAABAAAAAAAAAAxIaWRkZW5TdGFrZXMAAAABAAAABAAAAAAAAAAAAAAADVN0YWtlVmVyaWZpZXIAAAAAAAAAAAAAAAAAABFTaW11bGF0aW9uSW1hZ2VJZAAAAAAAAAAAAAAAAAAAD1JlY2VpcHRWZXJpZmllcgAAAAAAAAAAAAAAAA5UaWVicmVha1BvbGljeQAAAAAAAQAAAAAAAAAPVGllYnJlYWtFbnRyb3B5AAAAAAEAAAAEAAAAAQAAAAAAAAAKTXVsdGlSb3VuZAAAAAAAAQAAAAQAAAAAAAAAAAAAAA5Sb3VuZHNWZXJpZmllcgAAAAAAAAAAAAAAAAAPTWluUHJvb2ZWZXJzaW9uAAAAAAAAAAAAAAAACFZrU291cmNlAAAAAAAAAAAAAAAMTWF4UHJvb2ZTaXplAAAAAAAAAAAAAAAJU3liaWxHYXRlAAAAAAAAAQAAAAAAAAARSWRlbnRpdHlOdWxsaWZpZXIAAAAAAAACAAAABAAAA+4AAAAgAAAAAQAAAAAAAAAFVGVhbXMAAAAAAAABAAAABAAAAAAAAAAAAAAADlJhdGluZ1ZlcmlmaWVyAAAAAAABAAAAAAAAAA1SYXRpbmdCcmFja2V0AAAAAAAAAQAAAAQAAAAAAAAAAAAAAA5UaW1lb3V0TGVkZ2VycwAAAAAAAQAAAAAAAAALT3BlblNlc3Npb24AAAAAAQAAAAQAAAAAAAAAAAAAAAtBdXRvUmVzb2x2ZQAAAAABAAAAAAAAAApNYXRjaENvdW50AAAAAAABAAAAEwAAAAEAAAAAAAAAC01hdGNoUmVjb3JkAAAAAAIAAAATAAAABAAAAAAAAAAAAAAABlBhdXNlZAAAAAAAAAAAAAAAAAAMTWF4T3BlbkdhbWVzAAAAAQAAAAAAAAALUGxheWVyR2FtZXMAAAAAAQAAABMAAAAAAAAAAAAAAAtXYWdlckxpbWl0cwAAAAAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAAAAAAAAAAAAAxVcGdyYWRlRGVsYXkAAAAAAAAAAAAAAA5QZW5kaW5nVXBncmFkZQAAAAAAAAAAAAAAAAAMQWRtaW5Db3VuY2lsAAAAAQAAAAAAAAAJQXBwcm92YWxzAAAAAAAAAQAAB9AAAAANQ291bmNpbEFjdGlvbgAAAAAAAAEAAAAAAAAABFJvbGUAAAACAAAAEwAAB9AAAAAEUm9sZQAAAAAAAAAAAAAADVNjaGVtYVZlcnNpb24AAAAAAAAAAAAAAAAAAAtJbml0aWFsaXplZAAAAAAAAAAAAAAAAA1QZW5kaW5nUmVpbml0AAAAAAAAAQAAAAAAAAALUGxheWVyU3RhdHMAAAAAAQAAABMAAAAAAAAAAAAAAAtHbG9iYWxTdGF0cwAAAAAAAAAAAAAAAApXYWdlclRva2VuAAAAAAABAAAAAAAAAAZFc2Nyb3cAAAAAAAEAAAAEAAAAAAAAAAAAAAAGRmVlQnBzAAAAAAAAAAAAAAAAAAxGZWVDb2xsZWN0b3IAAAABAAAAAAAAAAtBY2NydWVkRmVlcwAAAAABAAAAEwAAAAAAAAAAAAAADE9yYWNsZUNvbmZpZwAAAAAAAAAAAAAADlNpZGVCZXRSYWtlQnBzAAAAAAABAAAAAAAAAAtTaWRlQmV0UG9vbAAAAAABAAAABAAAAAEAAAAAAAAAB1NpZGVCZXQAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAIQmFua3JvbGwAAAABAAAAEwAAAAEAAAAAAAAADkJhbmtyb2xsU2hhcmVzAAAAAAACAAAAEwAAABMAAAAAAAAAAAAAAAtIb3VzZUJlYWNvbgAAAAABAAAAAAAAAApIb3VzZVJvdW5kAAAAAAABAAAABAAAAAAAAAAAAAAAEFJlZmVycmFsU2hhcmVCcHMAAAABAAAAAAAAAAhSZWZlcnJlcgAAAAEAAAATAAAAAQAAAAAAAAANUmVmZXJyYWxTdGF0cwAAAAAAAAEAAAATAAAAAQAAAAAAAAAPUmVmZXJyYWxSZXdhcmRzAAAAAAIAAAATAAAAEwAAAAEAAAAAAAAABlN0cmVhawAAAAAAAQAAABMAAAAAAAAAAAAAAAtTdHJlYWtCb251cwAAAAABAAAAAAAAAAdKYWNrcG90AAAAAAEAAAATAAAAAAAAAAAAAAANSmFja3BvdENvbmZpZwAAAAAAAAEAAAAAAAAACkphY2twb3RSdW4AAAAAAAEAAAATAAAAAQAAAAAAAAAHQmFsYW5jZQAAAAACAAAAEwAAABMAAAAAAAAAAAAAAAtIdWJTaGFyZUJwcwAAAAAAAAAAAAAAAAtFcG9jaENvbmZpZwAAAAAAAAAAAAAAAA1FcG9jaFNjaGVkdWxlAAAAAAAAAQAAAAAAAAAMRXBvY2hSZXdhcmRzAAAAAQAAAAQAAAABAAAAAAAAAAlFcG9jaFdpbnMAAAAAAAACAAAABAAAABMAAAABAAAAAAAAAAxFcG9jaENsYWltZWQAAAACAAAABAAAABMAAAAAAAAAAAAAAA1EaXNwdXRlQ29uZmlnAAAAAAAAAQAAAAAAAAAEQm9uZAAAAAIAAAATAAAAEwAAAAEAAAAAAAAAB0Rpc3B1dGUAAAAAAQAAAAQAAAABAAAAAAAAAARPZGRzAAAAAQAAAAQAAAAAAAAAAAAAAA1TdGFraW5nQ29uZmlnAAAAAAAAAQAAAAAAAAAFU3Rha2UAAAAAAAABAAAAEw==",
+        "AAAAAQAAADdDYW5vbmljYWwsIE1lcmtsZS1jb21taXR0ZWQgcmVjb3JkIG9mIGEgc2V0dGxlZCBzZXNzaW9uAAAAAAAAAAALQXR0ZXN0YXRpb24AAAAAAgAAADJMZWF2ZXMgaW4gb3JkZXI6IGhlYWRlciwgcGxheWVyMSwgcGxheWVyMiwgb3V0Y29tZQAAAAAABmxlYXZlcwAAAAAD6gAAAA4AAAAla2VjY2FrMjU2IE1lcmtsZSByb290IG92ZXIgdGhlIGxlYXZlcwAAAAAAAARyb290AAAD7gAAACA=",
+        "AAAAAQAAAClBIHRva2VuJ3MgaG91c2UgYmFua3JvbGwgKGBnZXRfYmFua3JvbGxgKQAAAAAAAAAAAAAIQmFua3JvbGwAAAADAAAAAAAAAAZhc3NldHMAAAAAAAsAAAAAAAAABmxvY2tlZAAAAAAACwAAAAAAAAAGc2hhcmVzAAAAAAAL",
+        "AAAAAQAAAFtXaG8gcnVsZXMgb24gZGlzcHV0ZXMsIGFuZCBmb3IgaG93IGxvbmcgYSBzZXR0bGVtZW50IGNhbiBiZSBkaXNwdXRlZAooYHNldF9kaXNwdXRlX2NvbmZpZ2ApAAAAAAAAAAANRGlzcHV0ZUNvbmZpZwAAAAAAAAIAAAAAAAAABm1vZHVsZQAAAAAAEwAAAAAAAAAOd2luZG93X2xlZGdlcnMAAAAAAAQ=",
+        "AAAAAQAAAClBIHBsYXllcidzIGJvbmQgaW4gb25lIHRva2VuIChgZ2V0X2JvbmRgKQAAAAAAAAAAAAAEQm9uZAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGbG9ja2VkAAAAAAAL",
+        "AAAAAQAAAC9BIHdvbiBwb3QgaW4gaXRzIGRpc3B1dGUgd2luZG93IChgZ2V0X2Rpc3B1dGVgKQAAAAAAAAAAB0Rpc3B1dGUAAAAABwAAAAAAAAAPZGVhZGxpbmVfbGVkZ2VyAAAAAAQAAAAAAAAABmxvY2tlZAAAAAAACwAAAAAAAAAFbG9zZXIAAAAAAAATAAAAAAAAAAZtb2R1bGUAAAAAABMAAAAAAAAABnN0YXR1cwAAAAAH0AAAAA1EaXNwdXRlU3RhdHVzAAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABndpbm5lcgAAAAAAEw==",
+        "AAAAAgAAAB1XaGVyZSBhIGRpc3B1dGUgd2luZG93IHN0YW5kcwAAAAAAAAAAAAANRGlzcHV0ZVN0YXR1cwAAAAAAAAIAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAFRmlsZWQAAAA=",
+        "AAAAAQAAADhIb3cgZXBvY2hzIHJ1biBhbmQgZmlsbCB0aGVpciBwb29scyAoYHNldF9lcG9jaF9jb25maWdgKQAAAAAAAAALRXBvY2hDb25maWcAAAAABAAAAAAAAAAObGVuZ3RoX2xlZGdlcnMAAAAAAAQAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAFdG9wX24AAAAAAAAE",
+        "AAAAAQAAAHtXaGVuIHRoZSBlcG9jaHMgb2YgdGhlIGN1cnJlbnQgY29uZmlndXJhdGlvbiBiZWdhbi4gUmVjb25maWd1cmluZyBlbmRzCnRoZSBydW5uaW5nIGVwb2NoLCBhbmQgdGhlIG5leHQgb25lIHN0YXJ0cyBmcm9tIHRoZW4AAAAAAAAAAA1FcG9jaFNjaGVkdWxlAAAAAAAAAgAAAAAAAAALZmlyc3RfZXBvY2gAAAAABAAAAAAAAAAMc3RhcnRfbGVkZ2VyAAAABA==",
+        "AAAAAQAAAC5PbmUgZXBvY2gncyBwb29sIGFuZCBsZWFkZXJib2FyZCAoYGdldF9lcG9jaGApAAAAAAAAAAAADEVwb2NoUmV3YXJkcwAAAAcAAAAAAAAAB2NsYWltZWQAAAAACwAAAAAAAAAGY2xhaW1zAAAAAAAEAAAAAAAAAAdsZWFkZXJzAAAAA+oAAAfQAAAAC0Vwb2NoTGVhZGVyAAAAAAAAAAAGbGVhdmVzAAAAAAAEAAAAAAAAAARwb29sAAAACwAAAAAAAAAEcm9vdAAAA+gAAAPuAAAAIAAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAACpBIHJhbmtlZCBwbGF5ZXIgYW5kIHRoZWlyIHdpbnMgaW4gYW4gZXBvY2gAAAAAAAAAAAALRXBvY2hMZWFkZXIAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAEJUb2tlbnMgYSBnYW1lJ3MgcGxheWVycyBoYXZlIGxvY2tlZCBpbiB0aGUgY29udHJhY3QgKGBnZXRfZXNjcm93YCkAAAAAAAAAAAAGRXNjcm93AAAAAAAJAAAAAAAAAARkdWVzAAAD6gAAB9AAAAADRHVlAAAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfYW1vdW50AAAAAAALAAAAAAAAAA1wbGF5ZXIxX3Rva2VuAAAAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIyX2Ftb3VudAAAAAAACwAAAAAAAAANcGxheWVyMl90b2tlbgAAAAAAABMAAAAAAAAACnNldHRsZW1lbnQAAAAAB9AAAAAKU2V0dGxlbWVudAAA",
+        "AAAAAQAAACJBbiBhbW91bnQgdGhlIGVzY3JvdyBvd2VzIGEgcGxheWVyAAAAAAAAAAAAA0R1ZQAAAAADAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAgAAACBIb3cgYSBnYW1lJ3MgZXNjcm93IHdhcyBwYWlkIG91dAAAAAAAAAAKU2V0dGxlbWVudAAAAAAAAwAAAAAAAAAAAAAAB1BlbmRpbmcAAAAAAAAAAAAAAAAIV2lubmluZ3MAAAAAAAAAAAAAAAZSZWZ1bmQAAA==",
+        "AAAAAQAAAD1Ib3cgdGhlIGphY2twb3QgZmlsbHMgYW5kIHdoYXQgd2lucyBpdCAoYHNldF9qYWNrcG90X2NvbmZpZ2ApAAAAAAAAAAAAAA1KYWNrcG90Q29uZmlnAAAAAAAABQAAAAAAAAAJbWluX3N0YWtlAAAAAAAACwAAAAAAAAAKcGF5b3V0X2JwcwAAAAAABAAAAAAAAAAJc2hhcmVfYnBzAAAAAAAABAAAAAAAAAAGdGFjdGljAAAAAAAEAAAAAAAAAA13aW5zX2luX2Ffcm93AAAAAAAABA==",
+        "AAAAAQAAABxgR2FtZWAgYXMgc3RvcmVkIGF0IHNjaGVtYSAwAAAAAAAAAAZHYW1lVjAAAAAAABYAAAAAAAAADGF1dG9fcmVzb2x2ZQAAAAEAAAAAAAAAEmJhdGNoX3ZlcmlmaWNhdGlvbgAAAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABJwbGF5ZXIxX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAEnBsYXllcjFfcHJvb2ZfaGFzaAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIxX3Njb3JlAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjFfdGFjdGljAAAAAAPoAAAABAAAAAAAAAATcGxheWVyMV90YWN0aWNfaGFzaAAAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAABJwbGF5ZXIyX3Byb29mX2hhc2gAAAAAA+gAAAPuAAAAIAAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3RhY3RpYwAAAAAD6AAAAAQAAAAAAAAAE3BsYXllcjJfdGFjdGljX2hhc2gAAAAD6AAAA+4AAAAgAAAAAAAAAAxwcm9vZl9zeXN0ZW0AAAfQAAAAC1Byb29mU3lzdGVtAAAAAAAAAAAXcmVzb2x2ZV9kZWFkbGluZV9sZWRnZXIAAAAABAAAAAAAAAAGcmVzdWx0AAAAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAAAAAATc2ltdWxhdGlvbl9pbWFnZV9pZAAAAAPoAAAD7gAAACAAAAAAAAAAFnN1Ym1pdF9kZWFkbGluZV9sZWRnZXIAAAAAAAQAAAAAAAAACHRpZWJyZWFrAAAH0AAAAA5UaWVicmVha1BvbGljeQAA",
+        "AAAAAQAAABxgR2FtZWAgYXMgc3RvcmVkIGF0IHNjaGVtYSAxAAAAAAAAAAZHYW1lVjEAAAAAABgAAAAAAAAADGF1dG9fcmVzb2x2ZQAAAAEAAAAAAAAAEmJhdGNoX3ZlcmlmaWNhdGlvbgAAAAAAAQAAAAAAAAAMaHViX25vdGlmaWVkAAAAAQAAAAAAAAAFcGhhc2UAAAAAAAfQAAAACUdhbWVQaGFzZQAAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABJwbGF5ZXIxX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAEnBsYXllcjFfcHJvb2ZfaGFzaAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIxX3Njb3JlAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjFfdGFjdGljAAAAAAPoAAAABAAAAAAAAAATcGxheWVyMV90YWN0aWNfaGFzaAAAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAABJwbGF5ZXIyX3Byb29mX2hhc2gAAAAAA+gAAAPuAAAAIAAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3RhY3RpYwAAAAAD6AAAAAQAAAAAAAAAE3BsYXllcjJfdGFjdGljX2hhc2gAAAAD6AAAA+4AAAAgAAAAAAAAAAxwcm9vZl9zeXN0ZW0AAAfQAAAAC1Byb29mU3lzdGVtAAAAAAAAAAAXcmVzb2x2ZV9kZWFkbGluZV9sZWRnZXIAAAAABAAAAAAAAAAGcmVzdWx0AAAAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAAAAAATc2ltdWxhdGlvbl9pbWFnZV9pZAAAAAPoAAAD7gAAACAAAAAAAAAAFnN1Ym1pdF9kZWFkbGluZV9sZWRnZXIAAAAAAAQAAAAAAAAACHRpZWJyZWFrAAAH0AAAAA5UaWVicmVha1BvbGljeQAA",
+        "AAAAAQAAABxgR2FtZWAgYXMgc3RvcmVkIGF0IHNjaGVtYSAyAAAAAAAAAAZHYW1lVjIAAAAAABkAAAAAAAAADGF1dG9fcmVzb2x2ZQAAAAEAAAAAAAAAEmJhdGNoX3ZlcmlmaWNhdGlvbgAAAAAAAQAAAAAAAAAMaHViX25vdGlmaWVkAAAAAQAAAAAAAAAUbGFzdF9hY3Rpdml0eV9sZWRnZXIAAAAEAAAAAAAAAAVwaGFzZQAAAAAAB9AAAAAJR2FtZVBoYXNlAAAAAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAEnBsYXllcjFfY29tbWl0bWVudAAAAAAD6AAAA+4AAAAgAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAScGxheWVyMV9wcm9vZl9oYXNoAAAAAAPoAAAD7gAAACAAAAAAAAAADXBsYXllcjFfc2NvcmUAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMV90YWN0aWMAAAAAA+gAAAAEAAAAAAAAABNwbGF5ZXIxX3RhY3RpY19oYXNoAAAAA+gAAAPuAAAAIAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABJwbGF5ZXIyX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAEnBsYXllcjJfcHJvb2ZfaGFzaAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIyX3Njb3JlAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjJfdGFjdGljAAAAAAPoAAAABAAAAAAAAAATcGxheWVyMl90YWN0aWNfaGFzaAAAAAPoAAAD7gAAACAAAAAAAAAADHByb29mX3N5c3RlbQAAB9AAAAALUHJvb2ZTeXN0ZW0AAAAAAAAAABdyZXNvbHZlX2RlYWRsaW5lX2xlZGdlcgAAAAAEAAAAAAAAAAZyZXN1bHQAAAAAB9AAAAAKR2FtZVJlc3VsdAAAAAAAAAAAABNzaW11bGF0aW9uX2ltYWdlX2lkAAAAA+gAAAPuAAAAIAAAAAAAAAAWc3VibWl0X2RlYWRsaW5lX2xlZGdlcgAAAAAABAAAAAAAAAAIdGllYnJlYWsAAAfQAAAADlRpZWJyZWFrUG9saWN5AAA=",
+        "AAAAAgAAAB9Bc3NldCBhcyBTRVAtNDAgb3JhY2xlcyBuYW1lIGl0AAAAAAAAAAAFQXNzZXQAAAAAAAACAAAAAQAAAAAAAAAHU3RlbGxhcgAAAAABAAAAEwAAAAEAAAAAAAAABU90aGVyAAAAAAAAAQAAABE=",
+        "AAAAAQAAACxBIFNFUC00MCBwcmljZSBxdW90ZSwgYHRpbWVzdGFtcGAgaW4gc2Vjb25kcwAAAAAAAAAJUHJpY2VEYXRhAAAAAAAAAgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAQAAADxPcmFjbGUgY29uc3VsdGVkIGJ5IGBzdGFydF9taXhlZF9nYW1lYCAoYHNldF9vcmFjbGVfY29uZmlnYCkAAAAAAAAADE9yYWNsZUNvbmZpZwAAAAMAAAAAAAAAD21heF9hZ2Vfc2Vjb25kcwAAAAAGAAAAAAAAAAZvcmFjbGUAAAAAABMAAAAAAAAADXRvbGVyYW5jZV9icHMAAAAAAAAE",
+        "AAAAAQAAADVXaGF0IGEgcmVmZXJyZXIgaGFzIGJyb3VnaHQgaW4gKGBnZXRfcmVmZXJyYWxfc3RhdHNgKQAAAAAAAAAAAAANUmVmZXJyYWxTdGF0cwAAAAAAAAIAAAAAAAAAC3Jha2VkX2dhbWVzAAAAAAQAAAAAAAAACHJlZmVyZWVzAAAABA==",
+        "AAAAAQAAADpBIHJlZmVycmVyJ3MgcmV3YXJkcyBpbiBvbmUgdG9rZW4gKGBnZXRfcmVmZXJyYWxfcmV3YXJkc2ApAAAAAAAAAAAAD1JlZmVycmFsUmV3YXJkcwAAAAACAAAAAAAAAAljbGFpbWFibGUAAAAAAAALAAAAAAAAAAZlYXJuZWQAAAAAAAs=",
+        "AAAAAQAAAC9UaGUgc2lkZSBiZXRzIG9uIG9uZSBnYW1lIChgZ2V0X3NpZGVfYmV0X3Bvb2xgKQAAAAAAAAAAC1NpZGVCZXRQb29sAAAAAAoAAAAAAAAACm9wZW5fc3Rha2UAAAAAAAsAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAANcGxheWVyMV90b3RhbAAAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl90b3RhbAAAAAAAAAsAAAAAAAAABHJha2UAAAALAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAAAAAAABnJlc3VsdAAAAAAH0AAAAA1TaWRlQmV0UmVzdWx0AAAAAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAACXVuY2xhaW1lZAAAAAAAAAs=",
+        "AAAAAgAAAB9Ib3cgYSBzaWRlLWJldCBwb29sIHdhcyBzZXR0bGVkAAAAAAAAAAANU2lkZUJldFJlc3VsdAAAAAAAAAQAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAKUGxheWVyMVdvbgAAAAAAAAAAAAAAAAAKUGxheWVyMldvbgAAAAAAAAAAAAAAAAAGUmVmdW5kAAA=",
+        "AAAAAQAAAC1PbmUgYmV0dG9yJ3Mgc3Rha2Ugb24gYSBnYW1lIChgZ2V0X3NpZGVfYmV0YCkAAAAAAAAAAAAAB1NpZGVCZXQAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAZwbGF5ZXIAAAAAABM=",
+        "AAAAAQAAAEtUaGUgc3Rha2luZyB0b2tlbiwgaXRzIHRpZXJzIGFuZCBpdHMgd2FpdGluZyBwZXJpb2RzCihgc2V0X3N0YWtpbmdfY29uZmlnYCkAAAAAAAAAAA1TdGFraW5nQ29uZmlnAAAAAAAABAAAAAAAAAAQY29vbGRvd25fbGVkZ2VycwAAAAQAAAAAAAAADmxvY2t1cF9sZWRnZXJzAAAAAAAEAAAAAAAAAAV0aWVycwAAAAAAA+oAAAfQAAAACVN0YWtlVGllcgAAAAAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAACZBIGRpc2NvdW50IGFuZCB0aGUgc3Rha2UgdGhhdCBlYXJucyBpdAAAAAAAAAAAAAlTdGFrZVRpZXIAAAAAAAACAAAAAAAAAAxkaXNjb3VudF9icHMAAAAEAAAAAAAAAAltaW5fc3Rha2UAAAAAAAAL",
+        "AAAAAQAAAB5BIHBsYXllcidzIHN0YWtlIChgZ2V0X3N0YWtlYCkAAAAAAAAAAAAFU3Rha2UAAAAAAAAFAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAE2Nvb2xkb3duX2VuZF9sZWRnZXIAAAAABAAAAAAAAAATbG9ja2VkX3VudGlsX2xlZGdlcgAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAACXVuc3Rha2luZwAAAAAAAAs=",
+        "AAAAAQAAACpBIHBsYXllcidzIGNvbnNlY3V0aXZlIHdpbnMgKGBnZXRfc3RyZWFrYCkAAAAAAAAAAAAGU3RyZWFrAAAAAAACAAAAAAAAAARiZXN0AAAABAAAAAAAAAAHY3VycmVudAAAAAAE",
+        "AAAAAQAAADZUaGUgYm9udXMgZm9yIHdpbm5pbmcgb24gYSBzdHJlYWsgKGBzZXRfc3RyZWFrX2JvbnVzYCkAAAAAAAAAAAALU3RyZWFrQm9udXMAAAAAAwAAAAAAAAAHbWF4X2JwcwAAAAAEAAAAAAAAAAptaW5fc3RyZWFrAAAAAAAEAAAAAAAAAAhzdGVwX2JwcwAAAAQ=",
+        "AAAAAAAAAT9Jbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KClBhbmljcyB3aXRoIGBBbHJlYWR5SW5pdGlhbGl6ZWRgIGlmIHRoZSBjb250cmFjdCB3YXMgaW5pdGlhbGl6ZWQKYmVmb3JlOyB0aGUgYWRtaW4gYW5kIGh1YiBjYW4gb25seSBiZSByZXBsYWNlZCBhZnRlcndhcmRzIHRocm91Z2gKYHByb3Bvc2VfcmVpbml0aWFsaXplYC4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAR9BbGxvY2F0ZSB0aGlzIGdhbWUgYSBzZXNzaW9uIElEIGF0IHRoZSBHYW1lIEh1YiwgdW5pcXVlIGFjcm9zcwpldmVyeSBnYW1lLCBmb3IgdGhlIHBsYXllcnMgdG8gc2lnbiBpbnRvIGBzdGFydF9nYW1lYCBvciBhbm90aGVyCmBzdGFydF8qYCBlbnRyeXBvaW50LiBWZXJzaW9uIDQgaHVicyBvcGVuIHNlc3Npb25zIG9ubHkgdW5kZXIgSURzCmFsbG9jYXRlZCB0byB0aGUgZ2FtZTsgYSBodWIgdGhhdCBhbGxvY2F0ZXMgbm9uZSBmYWlscyB3aXRoCmBJbnZhbGlkSHViYC4gQW55b25lIG1heSBjYWxsIGl0LgAAAAAQYWxsb2NhdGVfc2Vzc2lvbgAAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAvxTdGFydCBhIG5ldyBaSyBUYWN0aWNhbCBNYXRjaCBiZXR3ZWVuIHR3byBwbGF5ZXJzLgoKQm90aCBwbGF5ZXJzIHNpZ24gdGhlIGZ1bGwgdGVybXM6IHRoZSBzZXNzaW9uLCBib3RoIHBsYXllcnMsIGJvdGgKcG9pbnRzIGFuZCB0aGUgd2FnZXIgdG9rZW4gKGBnZXRfd2FnZXJfdG9rZW5gKSwgc28gbmVpdGhlciBzaWduYXR1cmUKY2FuIGJlIHBhaXJlZCB3aXRoIGFub3RoZXIgb3Bwb25lbnQgb3Igc3Rha2UuIFJldHVybnMKYFNlbGZQbGF5Tm90QWxsb3dlZGAgaWYgYm90aCBwbGF5ZXJzIGFyZSB0aGUgc2FtZSBhZGRyZXNzLCBhbmQKYEluc3VmZmljaWVudEJhbGFuY2VgIGlmIHRoZSBHYW1lIEh1YiBob2xkcyBsZXNzIHRoYW4gYSBwbGF5ZXIncwpwb2ludHMgZm9yIHRoZW0sIGFzIGRvIHRoZSBvdGhlciBgc3RhcnRfKmAgZW50cnlwb2ludHMuIEEgZ2FtZQp3YWdlcmVkIGluIGEgdG9rZW4gc3Rha2VzIG5vdGhpbmcgYXQgdGhlIGh1YjogaXRzIGVzY3JvdyBob2xkcyB0aGUKc3Rha2VzIGluc3RlYWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIsIGZyb20gYGFsbG9jYXRlX3Nlc3Npb25gCiogYHBsYXllcjFgIC0gRmlyc3QgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMmAgLSBTZWNvbmQgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMV9wb2ludHNgIC0gQmV0dGluZyBhbW91bnQgZm9yIHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMgAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAtxTdGFydCBhIG1hdGNoIHdpdGggaXRzIG93biBzdWJtaXQgYW5kIHJldmVhbCB3aW5kb3dzLgoKUGxheXMgYXMgYHN0YXJ0X2dhbWVgLCBidXQgaW5zdGVhZCBvZiB0aGUgYWRtaW4ncyB0aW1lb3V0IHBsYXllcnMKaGF2ZSBgc3VibWl0X2xlZGdlcnNgIHRvIHN1Ym1pdCBhbmQgYSBmdXJ0aGVyIGByZXNvbHZlX2xlZGdlcnNgIHRvCnJldmVhbCwgYWZ0ZXIgd2hpY2ggYHJlc29sdmVfdGltZW91dGAgc2V0dGxlcyB0aGUgZ2FtZSBieSBmb3JmZWl0LgpCb3RoIHBsYXllcnMgc2lnbiB0aGUgd2luZG93cyB3aXRoIHRoZSB0ZXJtcy4gUmV0dXJucyBgSW52YWxpZERlYWRsaW5lc2AgaWYgZWl0aGVyCmlzIDAgb3IgdG9nZXRoZXIgdGhleSBvdXRsYXN0IHRoZSBnYW1lJ3Mgc3RvcmFnZS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllcgoqIGBwbGF5ZXIxYCAtIEZpcnN0IHBsYXllciBhZGRyZXNzCiogYHBsYXllcjJgIC0gU2Vjb25kIHBsYXllciBhZGRyZXNzCiogYHBsYXllcjFfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDIKKiBgc3VibWl0X2xlZGdlcnNgIC0gTGVkZ2VycyBmcm9tIG5vdyB0byBzdWJtaXQgaW4KKiBgcmVzb2x2ZV9sZWRnZXJzYCAtIExlZGdlcnMgYWZ0ZXIgdGhlIHN1Ym1pdCBkZWFkbGluZSB0byByZXZlYWwgaW4AAAAZc3RhcnRfZ2FtZV93aXRoX2RlYWRsaW5lcwAAAAAAAAcAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnN1Ym1pdF9sZWRnZXJzAAAAAAAEAAAAAAAAAA9yZXNvbHZlX2xlZGdlcnMAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAqtTdGFydCBhIG1hdGNoIHdhZ2VyZWQgaW4gYSB0b2tlbiBvZiB0aGUgcGxheWVycycgY2hvb3NpbmcuCgpQbGF5cyBhcyBgc3RhcnRfZ2FtZWAsIGJ1dCB0aGUgcG9pbnRzIGFyZSBlc2Nyb3dlZCBpbiBgdG9rZW5gCmluc3RlYWQgb2YgdGhlIGNvbnRyYWN0LXdpZGUgYGdldF93YWdlcl90b2tlbmAgKHNlZSBgZ2V0X2VzY3Jvd2ApLCBzbwphIG1hdGNoIGNhbiBiZSBwbGF5ZWQgZm9yIFVTREMsIFhMTSBvciBhIGNvbW11bml0eSB0b2tlbi4gQm90aApwbGF5ZXJzIHNpZ24gdGhpcyB0b2tlbiBpbiB0aGUgdGVybXMuIFRoZSB0b2tlbiBpcyBrZXB0IGluCnRoZSBnYW1lJ3MgYHdhZ2VyX3Rva2VuYCBhbmQgcGF5cyBvdXQgdGhlIHJlc3VsdC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllcgoqIGBwbGF5ZXIxYCAtIEZpcnN0IHBsYXllciBhZGRyZXNzCiogYHBsYXllcjJgIC0gU2Vjb25kIHBsYXllciBhZGRyZXNzCiogYHBsYXllcjFfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMSwgaW4gYHRva2VuYCB1bml0cwoqIGBwbGF5ZXIyX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDIsIGluIGB0b2tlbmAgdW5pdHMKKiBgdG9rZW5gIC0gQSBTdGVsbGFyIEFzc2V0IENvbnRyYWN0IG9yIG90aGVyIFNFUC00MSB0b2tlbgAAAAAVc3RhcnRfZ2FtZV93aXRoX3Rva2VuAAAAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAdlTdGFydCBhIG1hdGNoIHdhZ2VyZWQgaW4gbmF0aXZlIFhMTS4KCkFzIGBzdGFydF9nYW1lX3dpdGhfdG9rZW5gIHdpdGggdGhlIG5hdGl2ZSBTdGVsbGFyIEFzc2V0IENvbnRyYWN0CihgZ2V0X25hdGl2ZV90b2tlbmApIGFzIHRoZSB0b2tlbiwgd2hpY2ggcGxheWVycyBzaWduIGFsb25nIHdpdGgKdGhlaXIgcG9pbnRzLiBBbW91bnRzIGFyZSBzdHJvb3BzICgxIFhMTSA9IDEwLDAwMCwwMDApLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBVbmlxdWUgc2Vzc2lvbiBpZGVudGlmaWVyCiogYHBsYXllcjFgIC0gRmlyc3QgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMmAgLSBTZWNvbmQgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMV9wb2ludHNgIC0gQmV0dGluZyBhbW91bnQgZm9yIHBsYXllciAxLCBpbiBzdHJvb3BzCiogYHBsYXllcjJfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMiwgaW4gc3Ryb29wcwAAAAAAABFzdGFydF9uYXRpdmVfZ2FtZQAAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAABABTdGFydCBhIG1hdGNoIGFnYWluc3QgdGhlIGhvdXNlLgoKVGhlIGNvbnRyYWN0IGl0c2VsZiBpcyBwbGF5ZXIyLCBzdGFraW5nIGBwb2ludHNgIG9mIGB0b2tlbmAgZnJvbSB0aGUKdG9rZW4ncyBiYW5rcm9sbCAoc2VlIGBkZXBvc2l0X2Jhbmtyb2xsYCkgYWdhaW5zdCB0aGUgcGxheWVyJ3MKYHBvaW50c2A7IGEgYmFua3JvbGwgd2l0aG91dCB0aGF0IG11Y2ggdW5sb2NrZWQgcmV0dXJucwpgSW5zdWZmaWNpZW50QmFua3JvbGxgLiBUaGUgcGxheWVyIHN1Ym1pdHMgb3IgY29tbWl0cyBhbmQgcmV2ZWFscwphcyB1c3VhbC4gVGhlIGhvdXNlJ3MgdGFjdGljIGNvbWVzIGZyb20gdGhlIHJvdW5kIG9mIHRoZSBob3VzZQpiZWFjb24gKGBzZXRfaG91c2VfYmVhY29uYCwgZWxzZSBgSG91c2VCZWFjb25Ob3RTZXRgKSB0aGF0IGlzIHBlbmRpbmcKd2hlbiB0aGUgcGxheWVyIGNvbW1pdHMsIHNvIGl0IGlzIGZpeGVkIGJlZm9yZSB0aGUgcGxheWVyJ3MgdGFjdGljCmJ1dCB1bmtub3duIHVudGlsIHRoZSByb3VuZCBpcyByZXZlYWxlZDsgd2l0aCBubyByb3VuZCBwZW5kaW5nIHRoZQpjb21taXQgcmV0dXJucyBgUmFuZG9tbmVzc05vdFJlYWR5YC4gVGhlIGhvdXNlIHdpbnMgZHJhd24gc2NvcmVzCndoYXRldmVyIHRoZSBnYW1lJ3MgdGllYnJlYWssIHdoaWNoIGlzIGl0cyBlZGdlLiBBIHBsYXllciB3aG8gbmV2ZXIKcmV2ZWFscyBmb3JmZWl0cyB0aGVpciB3aG9sZSBzdGFrZSB0byBgcmVzb2x2ZV90aW1lb3V0YC4gVGhlCmJhbmtyb2xsIHRha2VzIHRoZSBob3VzZSdzIHNoYXJlIG9mIHRoZSByZXN1bHQgd2hlbiB0aGUgZ2FtZQpzZXR0bGVzLiBUaGUgcGxheWVyIHNpZ25zIHRoZSB0b2tlbiBhbG9uZyB3aXRoIHRoZWlyIHBvaW50cy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllcgoqIGBwbGF5ZXJgIC0gVGhlIHBsYXllciwgYXMgcGxheWVyMQoqIGBwb2ludHNgIC0gRWFjaCBzaWRlJ3Mgc3Rha2UsIGluIGB0b2tlbmAgdW5pdHMKKiBgdG9rZW5gIC0gAAAAEHN0YXJ0X2hvdXNlX2dhbWUAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABnBvaW50cwAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAA8dTdGFydCBhIG1hdGNoIHdoZXJlIGVhY2ggcGxheWVyIHN0YWtlcyBhIGRpZmZlcmVudCB0b2tlbi4KClBsYXlzIGFzIGBzdGFydF9nYW1lX3dpdGhfdG9rZW5gLCBidXQgcGxheWVyMSBkZXBvc2l0cwpgcGxheWVyMV9wb2ludHNgIG9mIGBwbGF5ZXIxX3Rva2VuYCBhbmQgcGxheWVyMiBgcGxheWVyMl9wb2ludHNgIG9mCmBwbGF5ZXIyX3Rva2VuYC4gVGhlIHByaWNlIG9yYWNsZSAoYGdldF9vcmFjbGVfY29uZmlnYCkgdmFsdWVzIGJvdGgKc3Rha2VzIHdoZW4gdGhlIGdhbWUgc3RhcnRzLCBhbmQgdGhleSBtdXN0IGJlIHdvcnRoIHRoZSBzYW1lIHdpdGhpbgppdHMgdG9sZXJhbmNlLCBlbHNlIGBTdGFrZXNOb3RFcXVpdmFsZW50YC4gQSBtaXNzaW5nIG9yIHN0YWxlIHByaWNlCnJldHVybnMgYFByaWNlVW5hdmFpbGFibGVgLCBhbmQgbm8gb3JhY2xlIGBPcmFjbGVOb3RDb25maWd1cmVkYC4KRWFjaCBkZXBvc2l0IGlzIHBhaWQgb3V0IGluIGl0cyBvd24gdG9rZW46IHRoZSB3aW5uZXIgZ2V0cyBib3RoLAphbmQgYSByZWZ1bmQgcmV0dXJucyBlYWNoIHBsYXllcidzIG93bi4gQm90aCBwbGF5ZXJzIHNpZ24gYm90aAp0b2tlbnMgYWxvbmcgd2l0aCB0aGVpciBwb2ludHMuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIKKiBgcGxheWVyMWAgLSBGaXJzdCBwbGF5ZXIgYWRkcmVzcwoqIGBwbGF5ZXIyYCAtIFNlY29uZCBwbGF5ZXIgYWRkcmVzcwoqIGBwbGF5ZXIxX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDEsIGluIGBwbGF5ZXIxX3Rva2VuYAoqIGBwbGF5ZXIyX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDIsIGluIGBwbGF5ZXIyX3Rva2VuYAoqIGBwbGF5ZXIxX3Rva2VuYCAtIFRoZSB0b2tlbiBwbGF5ZXIgMSBzdGFrZXMKKiBgcGxheWVyMl90b2tlbmAgLSBUaGUgdG9rZW4gcGxheWVyIDIgc3Rha2VzAAAAABBzdGFydF9taXhlZF9nYW1lAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMV90b2tlbgAAAAAAABMAAAAAAAAADXBsYXllcjJfdG9rZW4AAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAABABTdGFydCBhIG1hdGNoIGF0IGhhbmRpY2FwIG9kZHMuCgpQbGF5cyBhcyBgc3RhcnRfZ2FtZV93aXRoX3Rva2VuYCwgYnV0IHRoZSBzdGFrZXMgYXJlIG5vdCBhc3N1bWVkIHRvCmJlIGV2ZW46IGVhY2ggcGxheWVyJ3MgcG9pbnRzIGFyZSB0aGUgbW9zdCB0aGV5IHdpbGwgcmlzaywgYW5kIHRoZQpnYW1lIGlzIHBsYXllZCBmb3IgdGhlIGxhcmdlc3Qgc3Rha2VzIHdpdGhpbiB0aGVtIHRoYXQgYXJlIGV4YWN0bHkKYXQgYG9kZHNgOgoKYGBgdGV4dAp1bml0cyAgICAgICAgICA9IG1pbihwbGF5ZXIxX3BvaW50cyAvIG9kZHMucGxheWVyMSwgcGxheWVyMl9wb2ludHMgLyBvZGRzLnBsYXllcjIpCnBsYXllcjEgc3Rha2VzID0gdW5pdHMgKiBvZGRzLnBsYXllcjEKcGxheWVyMiBzdGFrZXMgPSB1bml0cyAqIG9kZHMucGxheWVyMgpgYGAKCk9ubHkgdGhvc2Ugc3Rha2VzIGFyZSBlc2Nyb3dlZCwgYW5kIHRoZSB3aW5uZXIgdGFrZXMgdGhlIHBvdCwgc28gYQp3aW4gcGF5cyBleGFjdGx5IHdoYXQgdGhlIG9kZHMgZ2l2ZS4gT2RkcyB3aXRoIGEgemVybyBzaWRlLCBvcgpwb2ludHMgdG9vIHNtYWxsIHRvIGNvdmVyIG9uZSB1bml0LCByZXR1cm4gYEludmFsaWRPZGRzYC4gQm90aApwbGF5ZXJzIHNpZ24gdGhlIHRva2VuIGFuZCB0aGUgb2RkcyB3aXRoIHRoZSB0ZXJtczsgdGhlCm9kZHMgYXJlIGtlcHQgZm9yIHRoZSBnYW1lIChgZ2V0X29kZHNgKS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllcgoqIGBwbGF5ZXIxYCAtIEZpcnN0IHBsYXllciBhZGRyZXNzCiogYHBsYXllcjJgIC0gU2Vjb25kIHBsYXllciBhZGRyZXNzCiogYHBsYXllcjFfcG9pbnRzYCAtIFRoZSBtb3N0IHBsYXllciAxIHJpc2tzLCBpbiBgdG9rZW5gIHVuaXRzCiogYHBsYXllcjJfcG9pbnRzYCAtIFRoZSBtb3N0IHBsYXllciAyIHJpc2tzLCBpbiBgdG9rZW5gIHVuaXRzCiogYHRva2VuYCAtIEEgU3RlbGxhciBBc3NldCBDb250cmFjdCBvciBvdGhlciBTRVAtNDEgdG9rZW4KKiBgAAAAFHN0YXJ0X2dhbWVfd2l0aF9vZGRzAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAARvZGRzAAAH0AAAAARPZGRzAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAy9TdGFydCBhIGJlc3Qtb2YtTiBtYXRjaC4KCkluc3RlYWQgb2Ygb25lIHRhY3RpYyBwZXIgcGxheWVyLCBlYWNoIHBsYXllciBzdWJtaXRzIGEgc2luZ2xlCnJlY3Vyc2l2ZSBwcm9vZiBhdHRlc3RpbmcgdG8gYWxsIGByb3VuZHNgIHRhY3RpY3MgKGBzdWJtaXRfcm91bmRzYCkKYW5kIG9wZW5zIHRoZW0gdG9nZXRoZXIgd2l0aCBgcmV2ZWFsX3JvdW5kc2AuIGByZXNvbHZlX21hdGNoYCBzY29yZXMKZXZlcnkgcm91bmQgd2l0aCB0aGUgbWF0cml4OyB0aGUgcGxheWVyIHdobyB3aW5zIG1vcmUgcm91bmRzIHdpbnMgdGhlCm1hdGNoLCBhbmQgYSB0aWUgaW4gcm91bmRzIHdvbiBpcyBkZWNpZGVkIGJ5IHRoZSBgVGllYnJlYWtQb2xpY3lgLgoKUm91bmQgcHJvb2ZzIGFyZSBjaGVja2VkIGJ5IHRoZSB2ZXJpZmllciBzZXQgd2l0aApgc2V0X3JvdW5kc192ZXJpZmllcmAsIHdoYXRldmVyIHRoZSBnYW1lJ3MgYFByb29mU3lzdGVtYDsgbXVsdGktcm91bmQKZ2FtZXMgYXJlIG5ldmVyIGJhdGNoLXZlcmlmaWVkIG9yIHNjb3JlZCBieSBhIHNpbXVsYXRpb24uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIKKiBgcGxheWVyMWAgLSBGaXJzdCBwbGF5ZXIgYWRkcmVzcwoqIGBwbGF5ZXIyYCAtIFNlY29uZCBwbGF5ZXIgYWRkcmVzcwoqIGBwbGF5ZXIxX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDEKKiBgcGxheWVyMl9wb2ludHNgIC0gQmV0dGluZyBhbW91bnQgZm9yIHBsYXllciAyCiogYHJvdW5kc2AgLSBOdW1iZXIgb2Ygcm91bmRzLCAxIHRvIGBNQVhfUk9VTkRTYAAAAAAWc3RhcnRfbXVsdGlfcm91bmRfZ2FtZQAAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGcm91bmRzAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAqxTdGFydCBhIGJlc3Qtb2YtTiBtYXRjaCB3aXRoIGV2ZXJ5IHJvdW5kIGNvbW1pdHRlZCB1cCBmcm9udC4KCkJvdGggcGxheWVycyBzaWduIHRoZSBzdGFydCB3aXRoIHRoZSByb290cyBvZiBNZXJrbGUgdHJlZXMgb3ZlciB0aGVpcgpyb3VuZCB0YWN0aWNzIChzZWUgYHN0cmF0ZWd5X3Jvb3RgKSwgc28gbm8gc3VibWlzc2lvbnMgZm9sbG93OiByb3VuZHMKYXJlIG9wZW5lZCBvbmUgYXQgYSB0aW1lLCBpbiBvcmRlciwgd2l0aCBgcmV2ZWFsX3JvdW5kYC4gT3RoZXJ3aXNlIGl0CnBsYXlzIGFzIGBzdGFydF9tdWx0aV9yb3VuZF9nYW1lYC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllcgoqIGBwbGF5ZXIxYCAtIEZpcnN0IHBsYXllciBhZGRyZXNzCiogYHBsYXllcjJgIC0gU2Vjb25kIHBsYXllciBhZGRyZXNzCiogYHBsYXllcjFfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDIKKiBgcm91bmRzYCAtIE51bWJlciBvZiByb3VuZHMsIDEgdG8gYE1BWF9ST1VORFNgCiogYHBsYXllcjFfcm9vdGAgLSBSb290IG9mIHBsYXllciAxJ3Mgc3RyYXRlZ3kgdHJlZQoqIGBwbGF5ZXIyX3Jvb3RgIC0gUm9vdCBvZiBwbGF5ZXIgMidzIHN0cmF0ZWd5IHRyZWUAAAAgc3RhcnRfY29tbWl0dGVkX211bHRpX3JvdW5kX2dhbWUAAAAIAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAZyb3VuZHMAAAAAAAQAAAAAAAAADHBsYXllcjFfcm9vdAAAA+4AAAAgAAAAAAAAAAxwbGF5ZXIyX3Jvb3QAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAuJTdGFydCBhIDJ2MiBtYXRjaC4KClBsYXllZCBhcyBhIGBURUFNX1NJWkVgLXJvdW5kIG1hdGNoOiBtZW1iZXIgYGlgIG9mIGVhY2ggdGVhbSBwbGF5cwpyb3VuZCBgaWAsIHRoZSB0ZWFtIHdpbm5pbmcgbW9yZSByb3VuZHMgd2lucywgYW5kIGEgdGllIGlzIGRlY2lkZWQgYnkKdGhlIGBUaWVicmVha1BvbGljeWAuIFRoZSBodWIgc2VlcyB0aGUgY2FwdGFpbnMgKGVhY2ggdGVhbSdzIGZpcnN0Cm1lbWJlcikgYW5kIHRoZSB0ZWFtIHN0YWtlcy4gVGVhbXMgbW92ZSB3aXRoIGBzdWJtaXRfdGVhbV9tb3ZlYCBhbmQKY2FwdGFpbnMgcmV2ZWFsIGV2ZXJ5IG1lbWJlcidzIHRhY3RpYyB3aXRoIGByZXZlYWxfcm91bmRzYC4KCkV2ZXJ5IG1lbWJlciBzaWducyBgKHNlc3Npb25faWQsIHRlYW0gcG9pbnRzLCBibHNfa2V5KWAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIKKiBgdGVhbTFgIC0gVGVhbSAxJ3MgbWVtYmVycywgY2FwdGFpbiBmaXJzdAoqIGB0ZWFtMmAgLSBUZWFtIDIncyBtZW1iZXJzLCBjYXB0YWluIGZpcnN0CiogYHRlYW0xX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgdGVhbSAxCiogYHRlYW0yX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgdGVhbSAyCgpGYWlscyB3aXRoIGBJbnZhbGlkVGVhbWAgdW5sZXNzIGJvdGggdGVhbXMgaGF2ZSBgVEVBTV9TSVpFYCBkaXN0aW5jdAptZW1iZXJzIHdpdGggdmFsaWQga2V5cy4AAAAAAA9zdGFydF90ZWFtX2dhbWUAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFdGVhbTEAAAAAAAPqAAAH0AAAAApUZWFtTWVtYmVyAAAAAAAAAAAABXRlYW0yAAAAAAAD6gAAB9AAAAAKVGVhbU1lbWJlcgAAAAAAAAAAAAx0ZWFtMV9wb2ludHMAAAALAAAAAAAAAAx0ZWFtMl9wb2ludHMAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAABABTdGFydCBhIG1hdGNoIHdob3NlIHN0YWtlcyBzdGF5IGhpZGRlbiB1bnRpbCBzZXR0bGVtZW50LgoKRWFjaCBwbGF5ZXIgY29tbWl0cyB0byB0aGVpciBzdGFrZSB3aXRoIGBzdGFrZV9oYXNoYCBhbmQgcHJvdmVzIGl0CmxpZXMgd2l0aGluIGBbbWluX3N0YWtlLCBtYXhfc3Rha2VdYDsgdGhlIHJhbmdlIHByb29mJ3MgcHVibGljIGlucHV0cwphcmUgYFtzZXNzaW9uX2lkLCBwbGF5ZXJfYmluZGluZywgbWluX3N0YWtlLCBtYXhfc3Rha2UsIHN0YWtlX2hhc2hdYAphcyAzMi1ieXRlIGZpZWxkIGVsZW1lbnRzLCBjaGVja2VkIGJ5IHRoZSB2ZXJpZmllciBzZXQgd2l0aApgc2V0X3N0YWtlX3ZlcmlmaWVyYCAod2l0aG91dCBvbmUsIGBWZXJpZmllck5vdENvbmZpZ3VyZWRgKS4gVGhlIGdhbWUKdGhlbiBwbGF5cyBhcyB1c3VhbCwgd2l0aCBzdGFrZXMgb2YgMCB1bnRpbCBlYWNoIHBsYXllciBvcGVucyB0aGVpcgpoYXNoIHdpdGggYHJldmVhbF9zdGFrZWAuIFVubGVzcyB0aGUgZ2FtZSBpcyB3YWdlcmVkIGluIGEgdG9rZW4sIHRoZQpHYW1lIEh1YiBzZXNzaW9uIG9wZW5zIHdpdGggYG1heF9zdGFrZWAgZnJvbSBlYWNoIHBsYXllciwgc28gYSBwbGF5ZXIKY2Fubm90IHdpdGhkcmF3IHRoZWlyIGh1YiBiYWxhbmNlIGZyb20gdW5kZXIgdGhlIHJlc3VsdCwgYW5kIGlzIGxvd2VyZWQgdG8gdGhlIHJldmVhbGVkIHN0YWtlcyBhdApzZXR0bGVtZW50IChgcmVkdWNlX3N0YWtlc2AsIHdoaWNoIG5lZWRzIGEgdmVyc2lvbiAzIGh1YikuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIsIG5vdCB5ZXQgaW4gdXNlCiogYHBsYXllcjFgIC0gRmlyc3QgcGxheWVyIGFkZHJlc3MKKiBgcGxheWVyMmAgLSBTZWNvbmQgcGxheWVyIGFkZHJlc3MKKiBgbWluX3N0YWtlYCAtIExvd2VzdCBzdGFrZSBlaXRoZXIgcGxheWVyIG1heSBoYXZlIGNvbW1pdHRlZCB0bwoqIGBtYXhfc3Rha2VgIC0gSGlnaGVzdCBzdGFrZSBlaXRoZXIgcGxheWVyIG1heSBoYXZlIGNvbW1pAAAAF3N0YXJ0X2hpZGRlbl9zdGFrZV9nYW1lAAAAAAcAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAltaW5fc3Rha2UAAAAAAAALAAAAAAAAAAltYXhfc3Rha2UAAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0YWtlAAAAAAAH0AAAAA9TdGFrZUNvbW1pdG1lbnQAAAAAAAAAAA1wbGF5ZXIyX3N0YWtlAAAAAAAH0AAAAA9TdGFrZUNvbW1pdG1lbnQAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAx5TdGFydCBhIHJhbmtlZCBtYXRjaCwgb3BlbiBvbmx5IHRvIHBsYXllcnMgd2hvIHByb3ZlIHRoZXkgYXJlIHVuaXF1ZS4KCkVhY2ggcGxheWVyIHN1cHBsaWVzIGEgdW5pcXVlbmVzcyBwcm9vZiBhZ2FpbnN0IHRoZSBjdXJyZW50CmBTeWJpbEdhdGVgOiB0aGF0IHRoZXkgaG9sZCBhbiBpZGVudGl0eSBpbiBpdHMgaWRlbnRpdHkgc2V0LCBhbmQgdGhhdApgbnVsbGlmaWVyYCBpcyB0aGF0IGlkZW50aXR5J3MgbnVsbGlmaWVyIGZvciBpdHMgYnJhY2tldC4gVGhlCm51bGxpZmllcnMgYXJlIGNvbnN1bWVkLCBzbyBhbiBpZGVudGl0eSBwbGF5cyBvbmUgcmFua2VkIGdhbWUgcGVyCmJyYWNrZXQsIGFuZCBhIHNlY29uZCBhZGRyZXNzIG9mIHRoZSBzYW1lIHBlcnNvbiByZXR1cm5zCmBJZGVudGl0eUFscmVhZHlQbGF5ZWRgLiBUaGUgZ2FtZSBpdHNlbGYgdGhlbiBwbGF5cyBhcyBvbmUgc3RhcnRlZAp3aXRoIGBzdGFydF9nYW1lYC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVW5pcXVlIHNlc3Npb24gaWRlbnRpZmllcgoqIGBwbGF5ZXIxYCAtIEZpcnN0IHBsYXllciBhZGRyZXNzCiogYHBsYXllcjJgIC0gU2Vjb25kIHBsYXllciBhZGRyZXNzCiogYHBsYXllcjFfcG9pbnRzYCAtIEJldHRpbmcgYW1vdW50IGZvciBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDIKKiBgcGxheWVyMV9pZGVudGl0eWAgLSBQbGF5ZXIgMSdzIHVuaXF1ZW5lc3MgcHJvb2YKKiBgcGxheWVyMl9pZGVudGl0eWAgLSBQbGF5ZXIgMidzIHVuaXF1ZW5lc3MgcHJvb2YAAAAAABFzdGFydF9yYW5rZWRfZ2FtZQAAAAAAAAcAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAEHBsYXllcjFfaWRlbnRpdHkAAAfQAAAAD1VuaXF1ZW5lc3NQcm9vZgAAAAAAAAAAEHBsYXllcjJfaWRlbnRpdHkAAAfQAAAAD1VuaXF1ZW5lc3NQcm9vZgAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAytTdGFydCBhIG1hdGNoIHJlc3RyaWN0ZWQgdG8gcGxheWVycyByYXRlZCB3aXRoaW4gYGJyYWNrZXRgLgoKRWFjaCBwbGF5ZXIgcHJvdmVzIHRoZWlyIHJhdGluZyBsaWVzIGluIHRoZSBicmFja2V0IGFnYWluc3QgdGhlCnJhdGluZ3Mgcm9vdCB0aGUgR2FtZSBIdWIgY3VycmVudGx5IHB1Ymxpc2hlcywgd2l0aG91dCByZXZlYWxpbmcgaXQuClRoZSBwcm9vZidzIHB1YmxpYyBpbnB1dHMgYXJlIGBbcmF0aW5nc19yb290LCBtaW5fcmF0aW5nLCBtYXhfcmF0aW5nLApyYXRpbmdfa2V5XWAsIGNoZWNrZWQgYnkgdGhlIHZlcmlmaWVyIHNldCB3aXRoIGBzZXRfcmF0aW5nX3ZlcmlmaWVyYAood2l0aG91dCBvbmUsIGBWZXJpZmllck5vdENvbmZpZ3VyZWRgKS4gVGhlIGdhbWUgaXRzZWxmIHRoZW4gcGxheXMgYXMKb25lIHN0YXJ0ZWQgd2l0aCBgc3RhcnRfZ2FtZWAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIKKiBgcGxheWVyMWAgLSBGaXJzdCBwbGF5ZXIgYWRkcmVzcwoqIGBwbGF5ZXIyYCAtIFNlY29uZCBwbGF5ZXIgYWRkcmVzcwoqIGBwbGF5ZXIxX3BvaW50c2AgLSBCZXR0aW5nIGFtb3VudCBmb3IgcGxheWVyIDEKKiBgcGxheWVyMl9wb2ludHNgIC0gQmV0dGluZyBhbW91bnQgZm9yIHBsYXllciAyCiogYGJyYWNrZXRgIC0gSW5jbHVzaXZlIHJhdGluZyByYW5nZSBib3RoIHBsYXllcnMgbXVzdCBiZSB3aXRoaW4KKiBgcGxheWVyMV9wcm9vZmAgLSBQbGF5ZXIgMSdzIHJhdGluZyBwcm9vZgoqIGBwbGF5ZXIyX3Byb29mYCAtIFBsYXllciAyJ3MgcmF0aW5nIHByb29mAAAAABJzdGFydF9icmFja2V0X2dhbWUAAAAAAAgAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAB2JyYWNrZXQAAAAH0AAAAA1SYXRpbmdCcmFja2V0AAAAAAAAAAAAAA1wbGF5ZXIxX3Byb29mAAAAAAAADgAAAAAAAAANcGxheWVyMl9wcm9vZgAAAAAAAA4AAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAABABTdWJtaXQgdGFjdGljYWwgY2hvaWNlIHdpdGggWksgcHJvb2YuCgoqKlpLIFByb29mIHZhbGlkYXRlczoqKgotIHRhY3RpYyDiiIggWzAtM10KLSBwbGF5ZXIgaWRlbnRpdHkKLSBoYXNuJ3QgYWxyZWFkeSBzdWJtaXR0ZWQKClRoZSB0YWN0aWMgaXMgbm90IHBhcnQgb2YgdGhlIHN1Ym1pc3Npb246IG9ubHkgaXRzIGhhc2ggaXMgc3RvcmVkLAphbmQgdGhlIHBsYXllciBvcGVucyBpdCB3aXRoIGByZXZlYWxfdGFjdGljYCBvbmNlIGJvdGggaGF2ZSBzdWJtaXR0ZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAoqIGBwbGF5ZXJgIC0gUGxheWVyIGFkZHJlc3MKKiBgaW5wdXRzYCAtIFRoZSBwcm9vZidzIHB1YmxpYyBpbnB1dHM6Ci0gYHNlc3Npb25faWRgIC0gbXVzdCBlcXVhbCBgc2Vzc2lvbl9pZGAKLSBgcGxheWVyX2JpbmRpbmdgIC0gbXVzdCBlcXVhbCBgZ2V0X3BsYXllcl9iaW5kaW5nKHBsYXllcilgCi0gYHZhbGlkX3VudGlsX2xlZGdlcmAgLSBMYXN0IGxlZGdlciB0aGUgcHJvb2YgbWF5IGJlIHN1Ym1pdHRlZCBpbiwKYXQgbW9zdCBgZ2V0X3Byb29mX2ZyZXNobmVzc193aW5kb3coKWAgbGVkZ2VycyBhaGVhZDsgMCBmb3Igbm8KZXhwaXJ5LCBvbmx5IGFjY2VwdGVkIHdoaWxlIG5vIHdpbmRvdyBpcyBzZXQKLSBgY29tbWl0bWVudGAgLSBQb3NlaWRvbjIodGFjdGljLCBzZWNyZXQsIHNlc3Npb25faWQpLCB0aGUgY2lyY3VpdCdzIG91dHB1dAotIGBudWxsaWZpZXJgIC0gUG9zZWlkb24yKHNlY3JldCwgc2Vzc2lvbl9pZCksIGNvbnN1bWVkIG9uIHN1Y2Nlc3Mgc28KdGhlIHNhbWUgcHJvb2YgY2Fubm90IGJlIHN1Ym1pdHRlZCB0d2ljZQotIGB0YWN0aWNfaGFzaGAgLSBgdGFjdGljX2hhc2godGFjdGljLCBzZWNyZXQsIHNlc3Npb25faWQpYCwgd2hlcmUKdGFjdGljIGlzIHRoZSBjaG9pY2UgKDA9RGVmZW5zaXZlLCAxPUJhbGFuY2VkLCAyPUFnZ3Jlc3NpdmUsIDM9QWxsT3V0KQoqIGBwcm9vZmAgLSBaSyBwcm9vZiBieXRlcyAoTm9pci1nZW5lcmF0AAAADXN1Ym1pdF90YWN0aWMAAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmlucHV0cwAAAAAH0AAAAAxQdWJsaWNJbnB1dHMAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAhZTdWJtaXQgYSB0YWN0aWMgd2l0aCBhIHZlcnNpb25lZCBwcm9vZi4KCkFzIGBzdWJtaXRfdGFjdGljYCwgYnV0IHRoZSBwcm9vZiBjb21lcyBpbiBhIGBQcm9vZkVudmVsb3BlYDogaXRzCnZlcnNpb24gbXVzdCBsaWUgYmV0d2VlbiBgZ2V0X21pbl9wcm9vZl92ZXJzaW9uKClgIGFuZApgUFJPT0ZfVkVSU0lPTmAsIGFuZCBpdHMgc3lzdGVtIG11c3QgYmUgdGhlIGdhbWUncy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBQbGF5ZXIgYWRkcmVzcwoqIGBpbnB1dHNgIC0gVGhlIHByb29mJ3MgcHVibGljIGlucHV0cyAoYXMgZm9yIGBzdWJtaXRfdGFjdGljYCkKKiBgZW52ZWxvcGVgIC0gVGhlIHByb29mIHdpdGggaXRzIHZlcnNpb24gYW5kIHByb29mIHN5c3RlbQoKRmFpbHMgd2l0aCBgVW5zdXBwb3J0ZWRQcm9vZlZlcnNpb25gIGZvciBhIHJldGlyZWQgb3IgdW5rbm93bgp2ZXJzaW9uLCBhbmQgYFdyb25nUHJvb2ZTeXN0ZW1gIGZvciBhbm90aGVyIGJhY2tlbmQncyBwcm9vZi4AAAAAABZzdWJtaXRfdGFjdGljX2VudmVsb3BlAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmlucHV0cwAAAAAH0AAAAAxQdWJsaWNJbnB1dHMAAAAAAAAACGVudmVsb3BlAAAH0AAAAA1Qcm9vZkVudmVsb3BlAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAt1TdWJtaXQgYSB0YWN0aWMgb24gYSBwbGF5ZXIncyBiZWhhbGYgdXNpbmcgdGhlaXIgZWQyNTUxOSBzaWduYXR1cmUuCgpBbnkgcmVsYXllciBjYW4gY2FsbCB0aGlzIGFuZCBwYXkgdGhlIGZlZSwgc28gcGxheWVycyB3aXRob3V0IFhMTSBjYW4Kc3RpbGwgcGxheS4gVGhlIHBsYXllciBzaWducyB0aGUgWERSIG9mIGBTaWduZWRTdWJtaXNzaW9uYCAoc2VlCmBzdWJtaXNzaW9uX3BheWxvYWRgKSB3aXRoIHRoZWlyIGFjY291bnQncyBlZDI1NTE5IGtleTsgdGhlIG5vbmNlIG11c3QKZXF1YWwgYGdldF9ub25jZShwbGF5ZXIpYCBhbmQgaXMgY29uc3VtZWQgb24gc3VjY2Vzcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBQbGF5ZXIgYWRkcmVzcyAobXVzdCBiZSBhIGBHLi4uYCBhY2NvdW50KQoqIGBpbnB1dHNgIC0gVGhlIHByb29mJ3MgcHVibGljIGlucHV0cyAoYXMgZm9yIGBzdWJtaXRfdGFjdGljYCkKKiBgcHJvb2ZgIC0gWksgcHJvb2YgYnl0ZXMKKiBgbm9uY2VgIC0gVGhlIHBsYXllcidzIGN1cnJlbnQgc3VibWlzc2lvbiBub25jZQoqIGBleHBpcmF0aW9uX2xlZGdlcmAgLSBMYXN0IGxlZGdlciBzZXF1ZW5jZSB0aGUgc2lnbmF0dXJlIGlzIHZhbGlkIGZvcgoqIGBzaWduYXR1cmVgIC0gZWQyNTUxOSBzaWduYXR1cmUgb3ZlciB0aGUgcGF5bG9hZAoKVHJhcHMgaWYgdGhlIHNpZ25hdHVyZSBkb2VzIG5vdCB2ZXJpZnkuAAAAAAAAFHN1Ym1pdF90YWN0aWNfc2lnbmVkAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZpbnB1dHMAAAAAB9AAAAAMUHVibGljSW5wdXRzAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAM1CeXRlcyBhIHBsYXllciBzaWducyBmb3IgYHN1Ym1pdF90YWN0aWNfc2lnbmVkYAoKQmluZHMgdGhlIHNpZ25hdHVyZSB0byB0aGlzIGNvbnRyYWN0LCB0aGUgc2Vzc2lvbiwgdGhlIHByb29mJ3MgcHVibGljCmlucHV0cyBhbmQgdGhlIGV4YWN0IHByb29mIChieSBrZWNjYWsyNTYgaGFzaCksIHNvIGEgcmVsYXllciBjYW5ub3QKYWx0ZXIgYW55IG9mIHRoZW0uAAAAAAAAEnN1Ym1pc3Npb25fcGF5bG9hZAAAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZpbnB1dHMAAAAAB9AAAAAMUHVibGljSW5wdXRzAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAAAAAAABW5vbmNlAAAAAAAABgAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAQAAAA4=",
+        "AAAAAAAAAEFUaGUgYHBsYXllcl9iaW5kaW5nYCBwdWJsaWMgaW5wdXQgYSBwcm9vZiBmb3IgYHBsYXllcmAgbXVzdCBjYXJyeQAAAAAAABJnZXRfcGxheWVyX2JpbmRpbmcAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAD1XaGV0aGVyIGBudWxsaWZpZXJgIGhhcyBhbHJlYWR5IGJlZW4gY29uc3VtZWQgYnkgYSBzdWJtaXNzaW9uAAAAAAAAEWlzX251bGxpZmllcl91c2VkAAAAAAAAAQAAAAAAAAAJbnVsbGlmaWVyAAAAAAAD7gAAACAAAAABAAAAAQ==",
+        "AAAAAAAAAERUaGUgYHJhdGluZ19rZXlgIHB1YmxpYyBpbnB1dCBhIHJhdGluZyBwcm9vZiBmb3IgYHBsYXllcmAgbXVzdCBjYXJyeQAAAA5nZXRfcmF0aW5nX2tleQAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+4AAAAg",
+        "AAAAAAAAAEFXaGV0aGVyIGFuIGlkZW50aXR5IGhhcyBhbHJlYWR5IHBsYXllZCBhIHJhbmtlZCBnYW1lIGluIGBicmFja2V0YAAAAAAAABBpc19pZGVudGl0eV91c2VkAAAAAgAAAAAAAAAHYnJhY2tldAAAAAAEAAAAAAAAAAludWxsaWZpZXIAAAAAAAPuAAAAIAAAAAEAAAAB",
+        "AAAAAAAAADZOZXh0IG5vbmNlIGBzdWJtaXRfdGFjdGljX3NpZ25lZGAgZXhwZWN0cyBmb3IgYHBsYXllcmAAAAAAAAlnZXRfbm9uY2UAAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABg==",
+        "AAAAAAAAAbdDb21taXQgdG8gYSB0YWN0aWMgd2l0aG91dCBhIHByb29mLCBpbiBhIGBDb21taXRSZXZlYWxgIGdhbWUuCgpOb3RoaW5nIGNoZWNrcyB0aGUgdGFjdGljIHVudGlsIGByZXZlYWxfdGFjdGljYCwgc28gYSBwbGF5ZXIgd2hvCmNvbW1pdHMgdG8gYW4gb3V0LW9mLXJhbmdlIHRhY3RpYyBzaW1wbHkgY2Fubm90IHJldmVhbC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBQbGF5ZXIgYWRkcmVzcwoqIGBoYXNoYCAtIGB0YWN0aWNfaGFzaCh0YWN0aWMsIHNhbHQsIHNlc3Npb25faWQpYCBmb3IgYSByYW5kb20KMzItYnl0ZSBzYWx0IHRoZSBwbGF5ZXIga2VlcHMgdW50aWwgdGhlIHJldmVhbAoKU2lnbmVkIGJ5IGBwbGF5ZXJgLCBvciBieSB0aGVpciBzZXNzaW9uIGtleSAoYXMgZm9yIGBzdWJtaXRfdGFjdGljYCkuAAAAAA1jb21taXRfdGFjdGljAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARoYXNoAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAlZSZXZlYWwgYSBzdWJtaXR0ZWQgdGFjdGljLgoKT25seSBhY2NlcHRlZCBvbmNlIGJvdGggcGxheWVycyBoYXZlIHN1Ym1pdHRlZCwgc28gbmVpdGhlciBjYW4gc2VlCnRoZSBvdGhlcidzIHRhY3RpYyBiZWZvcmUgY29tbWl0dGluZyB0byB0aGVpciBvd24uCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAoqIGBwbGF5ZXJgIC0gUGxheWVyIGFkZHJlc3MKKiBgdGFjdGljYCAtIFRoZSB0YWN0aWMgcHJvdmVuIGF0IHN1Ym1pc3Npb24gKDAtMykKKiBgc2VjcmV0YCAtIFRoZSBwbGF5ZXIgc2VjcmV0IHByb3ZlbiB3aXRoIGl0ICgzMi1ieXRlIGJpZy1lbmRpYW4KZmllbGQgZWxlbWVudCksIG9yIHRoZSBzYWx0IGNvbW1pdHRlZCB0byBpbiBhIGBDb21taXRSZXZlYWxgIGdhbWUKClNpZ25lZCBieSBgcGxheWVyYCwgb3IgYnkgdGhlaXIgc2Vzc2lvbiBrZXkgKGFzIGZvciBgc3VibWl0X3RhY3RpY2ApLgpGYWlscyB3aXRoIGBJbnZhbGlkUmV2ZWFsYCB1bmxlc3MgYHRhY3RpY19oYXNoKHRhY3RpYywgc2VjcmV0LApzZXNzaW9uX2lkKWAgZXF1YWxzIHRoZSBoYXNoIHRoZSBwbGF5ZXIncyBwcm9vZiBjb21taXR0ZWQgdG8uAAAAAAANcmV2ZWFsX3RhY3RpYwAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGdGFjdGljAAAAAAAEAAAAAAAAAAZzZWNyZXQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAwpTdWJtaXQgb25lIHJlY3Vyc2l2ZSBwcm9vZiBmb3IgZXZlcnkgcm91bmQgb2YgYSBtdWx0aS1yb3VuZCBtYXRjaC4KClRoZSBwcm9vZiB2ZXJpZmllcyBvbmUgdGFjdGljIHByb29mIHBlciByb3VuZCAoc2VlIGBzdWJtaXRfdGFjdGljYCkKYW5kIGV4cG9zZXMgdGhlaXIgdGFjdGljIGhhc2hlcyBpbiByb3VuZCBvcmRlcjsgb25seSB0aGUgaGFzaGVzIGFyZQpzdG9yZWQsIGFuZCB0aGUgcGxheWVyIG9wZW5zIHRoZW0gd2l0aCBgcmV2ZWFsX3JvdW5kc2Agb25jZSBib3RoIGhhdmUKc3VibWl0dGVkLiBQdWJsaWMgaW5wdXRzIGFyZSBgUm91bmRJbnB1dHM6OnRvX2ZpZWxkc2AsIGNoZWNrZWQgYnkgdGhlCnZlcmlmaWVyIHNldCB3aXRoIGBzZXRfcm91bmRzX3ZlcmlmaWVyYCAod2l0aG91dCBvbmUsCmBWZXJpZmllck5vdENvbmZpZ3VyZWRgKS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBQbGF5ZXIgYWRkcmVzcwoqIGBpbnB1dHNgIC0gVGhlIHByb29mJ3MgcHVibGljIGlucHV0czsgYHNlc3Npb25faWRgLApgcGxheWVyX2JpbmRpbmdgIGFuZCBgdmFsaWRfdW50aWxfbGVkZ2VyYCBhcmUgY2hlY2tlZCBhcyBmb3IKYHN1Ym1pdF90YWN0aWNgLCBhbmQgdGhlcmUgbXVzdCBiZSBvbmUgdGFjdGljIGhhc2ggcGVyIHJvdW5kCiogYHByb29mYCAtIFJlY3Vyc2l2ZSBwcm9vZiBieXRlcwoKU2lnbmVkIGJ5IGBwbGF5ZXJgLCBvciBieSB0aGVpciBzZXNzaW9uIGtleSAoYXMgZm9yIGBzdWJtaXRfdGFjdGljYCkuAAAAAAANc3VibWl0X3JvdW5kcwAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGaW5wdXRzAAAAAAfQAAAAC1JvdW5kSW5wdXRzAAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAu1TdWJtaXQgYSB0ZWFtJ3MgbW92ZSBpbiBhIDJ2MiBtYXRjaC4KCkNhcnJpZXMgb25lIHRhY3RpYyBoYXNoIHBlciBtZW1iZXIsIGluIG1lbWJlciBvcmRlciwgYW5kIGEgc2luZ2xlCkJMUzEyLTM4MSBzaWduYXR1cmUgYWdncmVnYXRpbmcgZWFjaCBtZW1iZXIncyBzaWduYXR1cmUgb2YgdGhlaXIKYHRlYW1fbW92ZV9wYXlsb2FkYC4gVGhlIHNpZ25hdHVyZSBpcyBpdHMgb3duIGF1dGhvcml6YXRpb24sIHNvCmFueW9uZSBob2xkaW5nIGl0IG1heSBzdWJtaXQgdGhlIG1vdmUuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAoqIGBjYXB0YWluYCAtIENhcHRhaW4gb2YgdGhlIG1vdmluZyB0ZWFtCiogYHRhY3RpY19oYXNoZXNgIC0gYHRhY3RpY19oYXNoKHRhY3RpYywgc2VjcmV0LCBzZXNzaW9uX2lkKWAgb2YgZWFjaAptZW1iZXIsIGZvciBhIHJhbmRvbSAzMi1ieXRlIHNlY3JldCB0aGUgbWVtYmVyIGtlZXBzIHVudGlsIHRoZSByZXZlYWwKKiBgc2lnbmF0dXJlYCAtIEFnZ3JlZ2F0ZSBvZiB0aGUgbWVtYmVycycgc2lnbmF0dXJlcyAoRzIsIHVuY29tcHJlc3NlZCkKCkZhaWxzIHdpdGggYEludmFsaWRUZWFtU2lnbmF0dXJlYCB1bmxlc3MgZXZlcnkgbWVtYmVyIHNpZ25lZCB0aGVpcgpvd24gaGFzaCwgYW5kIGBJbnZhbGlkQ29tbWl0bWVudGAgZm9yIGEgaGFzaCB0aGF0IGlzIG5vdCBhIGZpZWxkCmVsZW1lbnQgb3IgY29waWVzIG9uZSBvZiB0aGUgb3Bwb3NpbmcgdGVhbSdzLgAAAAAAABBzdWJtaXRfdGVhbV9tb3ZlAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHY2FwdGFpbgAAAAATAAAAAAAAAA10YWN0aWNfaGFzaGVzAAAAAAAD6gAAA+4AAAAgAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAwAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAKhCeXRlcyBhIHRlYW0gbWVtYmVyIHNpZ25zIGZvciBgc3VibWl0X3RlYW1fbW92ZWAKCk5hbWVzIHRoZSBjb250cmFjdCwgdGhlIHNlc3Npb24gYW5kIHRoZSBtZW1iZXIsIHNvIGEgc2lnbmF0dXJlIGNhbm5vdApiZSByZXBsYXllZCBlbHNld2hlcmUgb3IgY3JlZGl0ZWQgdG8gYSB0ZWFtbWF0ZS4AAAARdGVhbV9tb3ZlX3BheWxvYWQAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC3RhY3RpY19oYXNoAAAAA+4AAAAgAAAAAQAAAA4=",
+        "AAAAAAAAADtHZXQgYm90aCB0ZWFtcyBvZiBhIDJ2MiBtYXRjaCwgb3IgYE5vbmVgIGZvciBhbnkgb3RoZXIgZ2FtZQAAAAAJZ2V0X3RlYW1zAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAVUZWFtcwAAAA==",
+        "AAAAAAAAAfVSZXZlYWwgZXZlcnkgcm91bmQgb2YgYSBtdWx0aS1yb3VuZCBtYXRjaC4KCk9ubHkgYWNjZXB0ZWQgb25jZSBib3RoIHBsYXllcnMgaGF2ZSBzdWJtaXR0ZWQgKGBzdWJtaXRfcm91bmRzYCkuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAoqIGBwbGF5ZXJgIC0gUGxheWVyIGFkZHJlc3MKKiBgdGFjdGljc2AgLSBUaGUgdGFjdGljIG9mIGVhY2ggcm91bmQsIGluIG9yZGVyCiogYHNlY3JldHNgIC0gVGhlIHBsYXllciBzZWNyZXQgb2YgZWFjaCByb3VuZCdzIGlubmVyIHByb29mCgpTaWduZWQgYnkgYHBsYXllcmAsIG9yIGJ5IHRoZWlyIHNlc3Npb24ga2V5IChhcyBmb3IgYHN1Ym1pdF90YWN0aWNgKS4KRmFpbHMgd2l0aCBgSW52YWxpZFJldmVhbGAgdW5sZXNzIGB0YWN0aWNfaGFzaCh0YWN0aWNzW2ldLApzZWNyZXRzW2ldLCBzZXNzaW9uX2lkKWAgZXF1YWxzIHRoZSBpLXRoIHN1Ym1pdHRlZCByb3VuZCBoYXNoIGZvcgpldmVyeSByb3VuZC4AAAAAAAANcmV2ZWFsX3JvdW5kcwAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAHdGFjdGljcwAAAAPqAAAABAAAAAAAAAAHc2VjcmV0cwAAAAPqAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAqBSZXZlYWwgb25lIHJvdW5kIG9mIGEgY29tbWl0dGVkIHN0cmF0ZWd5LgoKUm91bmRzIGFyZSBvcGVuZWQgaW4gb3JkZXI7IHRoZSBsZWFmIGB0YWN0aWNfaGFzaCh0YWN0aWMsIHNhbHQsCnNlc3Npb25faWQpYCBtdXN0IHNpdCBhdCBpbmRleCBgcm91bmRgIG9mIHRoZSB0cmVlIHdob3NlIHJvb3QgdGhlCnBsYXllciBjb21taXR0ZWQgdG8gKHNlZSBgc3RyYXRlZ3lfcHJvb2ZgKS4gVGhlIGluY2x1c2lvbiBwcm9vZiBpcyBpdHMKb3duIGF1dGhvcml6YXRpb24sIHNvIGFueW9uZSBob2xkaW5nIGl0IG1heSBzdWJtaXQgdGhlIHJldmVhbC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBQbGF5ZXIgd2hvc2Ugcm91bmQgaXMgcmV2ZWFsZWQKKiBgcm91bmRgIC0gUm91bmQgaW5kZXgsIGVxdWFsIHRvIHRoZSBudW1iZXIgYWxyZWFkeSByZXZlYWxlZAoqIGB0YWN0aWNgIC0gVGhlIHRhY3RpYyBjb21taXR0ZWQgZm9yIHRoZSByb3VuZAoqIGBzYWx0YCAtIFRoZSBzYWx0IG9mIHRoZSByb3VuZCdzIGxlYWYKKiBgcHJvb2ZgIC0gU2libGluZyBoYXNoZXMgZnJvbSB0aGUgbGVhZiB1cCB0byB0aGUgcm9vdAoKRmFpbHMgd2l0aCBgSW52YWxpZFJldmVhbGAgdW5sZXNzIHRoZSBwcm9vZiBvcGVucyB0aGUgcGxheWVyJ3Mgcm9vdC4AAAAMcmV2ZWFsX3JvdW5kAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAABnRhY3RpYwAAAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAA+oAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAE9Sb3VuZCBoYXNoZXMgYW5kIHJldmVhbGVkIHRhY3RpY3Mgb2YgYSBtdWx0aS1yb3VuZCBnYW1lLCBpZgpgc2Vzc2lvbl9pZGAgaXMgb25lAAAAAA9nZXRfbXVsdGlfcm91bmQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAApNdWx0aVJvdW5kAAA=",
+        "AAAAAAAAAjpSZXZlYWwgYSBoaWRkZW4gc3Rha2UuCgpPbmx5IGFjY2VwdGVkIG9uY2UgYm90aCBwbGF5ZXJzIGhhdmUgc3VibWl0dGVkIHRoZWlyIHRhY3RpY3MsIHNvIGEKc3Rha2UgY2Fubm90IGluZmx1ZW5jZSB0aGUgb3Bwb25lbnQncyBjaG9pY2UuIGByZXNvbHZlX21hdGNoYCBmYWlscwp3aXRoIGBTdGFrZU5vdFJldmVhbGVkYCB1bnRpbCBib3RoIHN0YWtlcyBhcmUgcmV2ZWFsZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAoqIGBwbGF5ZXJgIC0gUGxheWVyIGFkZHJlc3MKKiBgc3Rha2VgIC0gVGhlIHN0YWtlIGNvbW1pdHRlZCB0byBhdCBgc3RhcnRfaGlkZGVuX3N0YWtlX2dhbWVgCiogYHNhbHRgIC0gVGhlIHNhbHQgaXQgd2FzIGhhc2hlZCB3aXRoCgpTaWduZWQgYnkgYHBsYXllcmAsIG9yIGJ5IHRoZWlyIHNlc3Npb24ga2V5IChhcyBmb3IgYHN1Ym1pdF90YWN0aWNgKS4KRmFpbHMgd2l0aCBgSW52YWxpZFJldmVhbGAgdW5sZXNzIGBzdGFrZV9oYXNoKHN0YWtlLCBzYWx0LApzZXNzaW9uX2lkKWAgZXF1YWxzIHRoZSBwbGF5ZXIncyBjb21taXR0ZWQgaGFzaC4AAAAAAAxyZXZlYWxfc3Rha2UAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXN0YWtlAAAAAAAACwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEpCb3VuZHMgYW5kIGNvbW1pdHRlZCBzdGFrZXMgb2YgYSBoaWRkZW4tc3Rha2UgZ2FtZSwgaWYgYHNlc3Npb25faWRgCmlzIG9uZQAAAAAAEWdldF9oaWRkZW5fc3Rha2VzAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAxIaWRkZW5TdGFrZXM=",
+        "AAAAAAAAAD5PZGRzIG9mIGEgYHN0YXJ0X2dhbWVfd2l0aF9vZGRzYCBtYXRjaCwgaWYgYHNlc3Npb25faWRgIGlzIG9uZQAAAAAACGdldF9vZGRzAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAARPZGRz",
+        "AAAAAAAAAERSYXRpbmcgcmFuZ2Ugb2YgYSBgc3RhcnRfYnJhY2tldF9nYW1lYCBtYXRjaCwgaWYgYHNlc3Npb25faWRgIGlzIG9uZQAAABJnZXRfcmF0aW5nX2JyYWNrZXQAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAANUmF0aW5nQnJhY2tldAAAAA==",
+        "AAAAAAAAAk5BdXRob3JpemUgYSBzZXNzaW9uIGtleSB0byBzdWJtaXQgb24gdGhlIHBsYXllcidzIGJlaGFsZi4KCkxldHMgc21hcnQtd2FsbGV0IChlLmcuIHBhc3NrZXkpIHBsYXllcnMgYXBwcm92ZSBvbmNlIHBlciBtYXRjaDogdGhlCndhbGxldCBzaWducyB0aGlzIGdyYW50LCBhbmQgdGhlIHNlc3Npb24ga2V5IHNpZ25zIGBzdWJtaXRfdGFjdGljYC4KVGhlIGdyYW50IG9ubHkgYXBwbGllcyB0byBgc2Vzc2lvbl9pZGAsIG9ubHkgd2hpbGUgdGhlIHBsYXllcidzIHN0YWtlCmlzIGF0IG1vc3QgYG1heF9zdGFrZWAsIGFuZCBvbmx5IHVudGlsIGBleHBpcmF0aW9uX2xlZGdlcmAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFNlc3Npb24gdGhlIGtleSBpcyBzY29wZWQgdG8KKiBgcGxheWVyYCAtIFBsYXllciBncmFudGluZyB0aGUga2V5CiogYGtleWAgLSBBZGRyZXNzIHRoYXQgbWF5IHNpZ24gc3VibWlzc2lvbnMKKiBgbWF4X3N0YWtlYCAtIE1heGltdW0gcGxheWVyIHN0YWtlIHRoZSBrZXkgbWF5IGFjdCBmb3IKKiBgZXhwaXJhdGlvbl9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgc2VxdWVuY2UgdGhlIGdyYW50IGlzIHZhbGlkIGZvcgAAAAAAFWF1dGhvcml6ZV9zZXNzaW9uX2tleQAAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAADa2V5AAAAABMAAAAAAAAACW1heF9zdGFrZQAAAAAAAAsAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJFSZXZva2UgdGhlIHBsYXllcidzIHNlc3Npb24ga2V5IGZvciBhIHNlc3Npb24KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gU2Vzc2lvbiB0aGUga2V5IGlzIHNjb3BlZCB0bwoqIGBwbGF5ZXJgIC0gUGxheWVyIHRoYXQgZ3JhbnRlZCB0aGUga2V5AAAAAAAAEnJldm9rZV9zZXNzaW9uX2tleQAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAJxHZXQgdGhlIHBsYXllcidzIHNlc3Npb24ga2V5IGdyYW50IGZvciBhIHNlc3Npb24sIGlmIGFueQoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBTZXNzaW9uIHRoZSBrZXkgaXMgc2NvcGVkIHRvCiogYHBsYXllcmAgLSBQbGF5ZXIgdGhhdCBncmFudGVkIHRoZSBrZXkAAAAPZ2V0X3Nlc3Npb25fa2V5AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPoAAAH0AAAAA9TZXNzaW9uS2V5R3JhbnQA",
+        "AAAAAAAAA15SZXNvbHZlIG1hdGNoIGFmdGVyIGJvdGggcGxheWVycyBzdWJtaXQgYW5kIHJldmVhbCB0YWN0aWNzLgpDb21wdXRlcyBzY29yZXMgdXNpbmcgc3RyYXRlZ2ljIG1hdHJpeCBhbmQgZGV0ZXJtaW5lcyB0aGUgb3V0Y29tZTsgYQpkcmF3IHN0YW5kcyBvciBpcyBkZWNpZGVkIHBlciB0aGUgZ2FtZSdzIGBUaWVicmVha1BvbGljeWAuCgpNdWx0aS1yb3VuZCBnYW1lcyBhcmUgd29uIGJ5IHRoZSBwbGF5ZXIgd2hvIHdpbnMgbW9yZSByb3VuZHMgKHNlZQpgc3RhcnRfbXVsdGlfcm91bmRfZ2FtZWApLgoKSGlkZGVuLXN0YWtlIGdhbWVzIGFsc28gbmVlZCBib3RoIHN0YWtlcyByZXZlYWxlZCAoYHJldmVhbF9zdGFrZWApOwp0aGV5IGFyZSByZWNvcmRlZCBvbiB0aGUgZ2FtZSBhbmQgcmVwb3J0ZWQgdG8gdGhlIGh1YiB3aXRoIHRoZSByZXN1bHQuCgpGb3IgYmF0Y2gtdmVyaWZpZWQgZ2FtZXMgYm90aCBwcm9vZnMgYXJlIGNoZWNrZWQgaGVyZSBmaXJzdC4gQSBwbGF5ZXIKd2hvc2UgcHJvb2YgaXMgaW52YWxpZCBmb3JmZWl0cyAodGhlIGdhbWUgaXMgc2V0dGxlZCB3aXRob3V0IHNjb3JlcyksCmV2ZW4gYmVmb3JlIHRoZXkgcmV2ZWFsOyBpZiBib3RoIGFyZSBpbnZhbGlkIHRoZSBjYWxsIGZhaWxzIHdpdGgKYEludmFsaWRQcm9vZmAuIE9ubHkgcHJvb2ZzIHRoYXQgdmVyaWZ5IGhhdmUgdGhlaXIgbnVsbGlmaWVycwpjb25zdW1lZC4gQSBwcm9vZiBwb2ludCBvZmYgdGhlIGN1cnZlIG1ha2VzIHRoZSBob3N0IHRyYXAgaW5zdGVhZCAoc2VlCmBncm90aDE2YCkuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAoKIyBSZXR1cm5zCiogYE91dGNvbWVgIC0gV2hvIHdvbiwgb3IgYERyYXdgAAAAAAANcmVzb2x2ZV9tYXRjaAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAHT3V0Y29tZQAAAAAD",
+        "AAAAAAAAAnNSZXNvbHZlIGEgbWF0Y2ggc2NvcmVkIGJ5IGFuIG9mZi1jaGFpbiBzaW11bGF0aW9uLgoKRm9yIGdhbWVzIHN0YXJ0ZWQgd2hpbGUgYSBzaW11bGF0aW9uIGltYWdlIElEIHdhcyBzZXQgKHNlZQpgc2V0X3NpbXVsYXRpb25faW1hZ2VfaWRgKSwgd2hpY2ggYHJlc29sdmVfbWF0Y2hgIHJlZnVzZXMgd2l0aApgUmVjZWlwdFJlcXVpcmVkYC4gVGhlIHJlY2VpcHQgbXVzdCBiZSBmb3IgdGhpcyBzZXNzaW9uIGFuZCB0aGUKcmV2ZWFsZWQgdGFjdGljcywgYW5kIGl0cyBzZWFsIGlzIGNoZWNrZWQgYWdhaW5zdCB0aGUgZ2FtZSdzIGltYWdlIElECmJ5IHRoZSB2ZXJpZmllciBzZXQgd2l0aCBgc2V0X3JlY2VpcHRfdmVyaWZpZXJgICh3aXRob3V0IG9uZSwKYFZlcmlmaWVyTm90Q29uZmlndXJlZGApLiBJdHMgc2NvcmVzIHRoZW4gZGVjaWRlIHRoZSBvdXRjb21lIGFzIHVzdWFsLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBHYW1lIHNlc3Npb24gSUQKKiBgam91cm5hbGAgLSBKb3VybmFsIGNvbW1pdHRlZCBieSB0aGUgc2ltdWxhdGlvbiAoc2VlIGB6a3ZtLnJzYCkKKiBgc2VhbGAgLSBUaGUgcmVjZWlwdCdzIHByb29mCgojIFJldHVybnMKKiBgT3V0Y29tZWAgLSBXaG8gd29uLCBvciBgRHJhd2AAAAAAFHJlc29sdmVfd2l0aF9yZWNlaXB0AAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHam91cm5hbAAAAAAOAAAAAAAAAARzZWFsAAAADgAAAAEAAAPpAAAH0AAAAAdPdXRjb21lAAAAAAM=",
+        "AAAAAAAAAb9SZXNvbHZlIHNldmVyYWwgZmluaXNoZWQgbWF0Y2hlcyBpbiBvbmUgdHJhbnNhY3Rpb24KCkVhY2ggc2Vzc2lvbiBpcyByZXNvbHZlZCBhcyBgcmVzb2x2ZV9tYXRjaGAgd291bGQsIGFuZCBvbmUgdGhhdApmYWlscyAobm90IHlldCByZXZlYWxlZCwgbm90IGZvdW5kLCAuLi4pIGlzIHJlcG9ydGVkIHdpdGggaXRzIGVycm9yCmNvZGUgcmF0aGVyIHRoYW4gZmFpbGluZyB0aGUgcmVzdC4gR2FtZXMgc2NvcmVkIGJ5IGEgc2ltdWxhdGlvbgpzdGlsbCBuZWVkIGByZXNvbHZlX3dpdGhfcmVjZWlwdGAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkc2AgLSBGcm9tIDEgdG8gYE1BWF9CQVRDSF9TSVpFYCBzZXNzaW9ucyAoZWxzZQpgSW52YWxpZEJhdGNoYCkKCiMgUmV0dXJucwoqIEEgYEJhdGNoUmVzdWx0YCBwZXIgc2Vzc2lvbiwgaW4gb3JkZXI6IGBSZXNvbHZlZGAgb3IgYEZhaWxlZGAAAAAADXJlc29sdmVfYmF0Y2gAAAAAAAABAAAAAAAAAAtzZXNzaW9uX2lkcwAAAAPqAAAABAAAAAEAAAPpAAAD6gAAB9AAAAALQmF0Y2hSZXN1bHQAAAAAAw==",
+        "AAAAAAAAAaBQYXkgb3V0IHdoYXQgc2V2ZXJhbCBzZXR0bGVkIGdhbWVzJyBlc2Nyb3dzIHN0aWxsIGhvbGQKCkVhY2ggc2Vzc2lvbiBpcyBjbGFpbWVkIGFzIGBjbGFpbV93aW5uaW5nc2AgKGZvciBhIHdvbiBwb3QpIG9yCmBjbGFpbV9yZWZ1bmRgIChvdGhlcndpc2UpIHdvdWxkLCBhbmQgb25lIHRoYXQgZmFpbHMgaXMgcmVwb3J0ZWQKd2l0aCBpdHMgZXJyb3IgY29kZSByYXRoZXIgdGhhbiBmYWlsaW5nIHRoZSByZXN0LiBBbnlvbmUgbWF5IGNhbGwKaXQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkc2AgLSBGcm9tIDEgdG8gYE1BWF9CQVRDSF9TSVpFYCBzZXNzaW9ucyAoZWxzZQpgSW52YWxpZEJhdGNoYCkKCiMgUmV0dXJucwoqIEEgYEJhdGNoUmVzdWx0YCBwZXIgc2Vzc2lvbiwgaW4gb3JkZXI6IGBDbGFpbWVkYCBvciBgRmFpbGVkYAAAAAtjbGFpbV9iYXRjaAAAAAABAAAAAAAAAAtzZXNzaW9uX2lkcwAAAAPqAAAABAAAAAEAAAPpAAAD6gAAB9AAAAALQmF0Y2hSZXN1bHQAAAAAAw==",
+        "AAAAAAAAAp5TZXR0bGUgYSBnYW1lIGFuIG9wcG9uZW50IGFiYW5kb25lZC4KCk9uY2UgdGhlIGdhbWUncyBgc3VibWl0X2RlYWRsaW5lX2xlZGdlcmAgaGFzIHBhc3NlZCwgdGhlIHBsYXllciB3aG8Kc3VibWl0dGVkIHdpbnMgYnkgZm9yZmVpdCBpZiB0aGUgb3Bwb25lbnQgbmV2ZXIgZGlkLCBhbmQgdGhlIHJlc3VsdCBpcwpyZXBvcnRlZCB0byB0aGUgR2FtZSBIdWIgYXMgZm9yIGByZXNvbHZlX21hdGNoYC4gTGlrZXdpc2UsIG9uY2UgdGhlCmByZXNvbHZlX2RlYWRsaW5lX2xlZGdlcmAgaGFzIHBhc3NlZCwgYSBwbGF5ZXIgd2hvIHJldmVhbGVkIGFsbCB0aGVpcgpnYW1lIG5lZWRzIChldmVyeSByb3VuZCwgYW5kIGFueSBoaWRkZW4gc3Rha2UpIHdpbnMgaWYgdGhlIG9wcG9uZW50CmRpZCBub3QuIE90aGVyIGdhbWVzIHJldHVybiBgTm9Gb3JmZWl0YDsgdGhleSBhcmUgcmVzb2x2ZWQgYXMgdXN1YWwuCkluIGJhdGNoLXZlcmlmaWVkIGdhbWVzIHRoZSB3aW5uZXIncyBkZWZlcnJlZCBwcm9vZiBpcyBjaGVja2VkIGZpcnN0LAphbmQgYW4gaW52YWxpZCBvbmUgcmV0dXJucyBgSW52YWxpZFByb29mYC4gQW55b25lIG1heSBjYWxsIGl0LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBHYW1lIHNlc3Npb24gSUQKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIFdpbm5lciBhZGRyZXNzAAAAAAAPcmVzb2x2ZV90aW1lb3V0AAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD",
+        "AAAAAAAAAYRDYWxsIG9mZiBhIGdhbWUgYmVmb3JlIGVpdGhlciBwbGF5ZXIgaGFzIHN1Ym1pdHRlZC4KClNpZ25lZCBieSBib3RoIHBsYXllcnMgKGp1c3QgdGhlIHBsYXllciwgYWdhaW5zdCB0aGUgaG91c2UpLiBUaGUKZ2FtZSBlbmRzIGBDYW5jZWxsZWRgIGFuZCB0aGUgaHViIGlzCnRvbGQgaXQgZW5kZWQgZHJhd24sIHdoaWNoIGhhbmRzIGJvdGggcGxheWVycyB0aGVpciBwb2ludHMgYmFjazsgYQpgR2FtZUNhbmNlbGxlZGAgZXZlbnQgaXMgcHVibGlzaGVkLiBOb3RoaW5nIGlzIGFkZGVkIHRvIG1hdGNoCmhpc3RvcnkuIFJldHVybnMgYEludmFsaWRQaGFzZWAgb25jZSBhbnlvbmUgaGFzIHN1Ym1pdHRlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElEAAAAC2NhbmNlbF9nYW1lAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAidSZXBvcnQgYSBzZXR0bGVkIGdhbWUncyByZXN1bHQgdG8gYSBodWIgdGhhdCBtaXNzZWQgaXQuCgpTZXR0bGVtZW50IGRvZXMgbm90IHdhaXQgb24gdGhlIGh1YjogaWYgdGhlIGh1YiB0cmFwcGVkIHdoZW4KYHJlc29sdmVfbWF0Y2hgLCBgcmVzb2x2ZV93aXRoX3JlY2VpcHRgIG9yIGByZXNvbHZlX3RpbWVvdXRgIGNhbGxlZAppdCwgdGhlIHJlc3VsdCBpcyBrZXB0IGhlcmUgd2l0aCBgaHViX25vdGlmaWVkYCBmYWxzZS4gVGhpcyByZXBvcnRzCml0IGFnYWluLCBzYXkgb25jZSB0aGUgaHViIGlzIGJhY2sgb3IgYHNldF9odWJgIGhhcyBwb2ludGVkIHRoZQpjb250cmFjdCBhdCBhIHdvcmtpbmcgb25lLiBSZXR1cm5zIGBIdWJOb3ROb3RpZmllZGAgaWYgdGhlIGh1YiBzdGlsbApyZWplY3RzIGl0LCBgR2FtZU5vdFNldHRsZWRgIGZvciBhIGdhbWUgc3RpbGwgaW4gcGxheSwgYW5kIG5vdGhpbmcKZm9yIGEgZ2FtZSB0aGUgaHViIGFscmVhZHkgaGFzLiBBbnlvbmUgbWF5IGNhbGwgaXQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEdhbWUgc2Vzc2lvbiBJRAAAAAAQcmV0cnlfaHViX25vdGlmeQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAjBDbG9zZSBhIHNlc3Npb24gd2hvc2UgZ2FtZSBlbnRyeSBleHBpcmVkIGJlZm9yZSBpdCB3YXMgcmVzb2x2ZWQuCgpUaGUgaHViIHdhcyB0b2xkIHRoZSBzZXNzaW9uIHN0YXJ0ZWQgYnV0IHdpbGwgbmV2ZXIgaGVhciBob3cgaXQKZW5kZWQ7IHRoaXMgZHJvcHMgdGhlIGNvbnRyYWN0J3Mgb3BlbiByZWNvcmQgb2YgaXQgYW5kIHB1Ymxpc2hlcyBhCmBTZXNzaW9uQWJhbmRvbmVkYCBldmVudCB3aXRoIHRoZSBwbGF5ZXJzIGFuZCBwb2ludHMgdGhlIGh1YiBob2xkcywKc28gdGhleSBjYW4gYmUgcmV0dXJuZWQuIFJldHVybnMgYFNlc3Npb25Ob3RFeHBpcmVkYCB3aGlsZSB0aGUgZ2FtZQpzdGlsbCBleGlzdHMgKHJlc29sdmUgaXQsIG9yIHVzZSBgcmVzb2x2ZV90aW1lb3V0YCksIGFuZApgR2FtZU5vdEZvdW5kYCBmb3Igc2Vzc2lvbnMgd2l0aCBubyBvcGVuIHJlY29yZC4gQW55b25lIG1heSBjYWxsIGl0LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBHYW1lIHNlc3Npb24gSUQKCiMgUmV0dXJucwoqIGBPcGVuU2Vzc2lvbmAgLSBUaGUgcmVjb3JkIHRoYXQgd2FzIGNsb3NlZAAAAA9hYmFuZG9uX2V4cGlyZWQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAtPcGVuU2Vzc2lvbgAAAAAD",
+        "AAAAAAAAA0ZEZWxldGUgYSBzZXR0bGVkIGdhbWUncyBzdG9yYWdlIG9uY2UgaXQgaGFzIGJlZW4gYXJjaGl2ZWQuCgpTZXR0bGVtZW50IGNvcGllcyB0aGUgcmVzdWx0IGludG8gYm90aCBwbGF5ZXJzJyBtYXRjaCBoaXN0b3J5IChzZWUKYGdldF9tYXRjaF9yZWNvcmRgKTsgYWZ0ZXIgdGhhdCB0aGUgZ2FtZSBlbnRyeSBhbmQgaXRzIHBlci1zZXNzaW9uCmVudHJpZXMgKHJvdW5kcywgaGlkZGVuIHN0YWtlcywgdGVhbXMsIHJhdGluZyBicmFja2V0LCB0aWVicmVhawplbnRyb3B5LCBkZWZlcnJlZCBwcm9vZnMgYW5kIHNlc3Npb24ga2V5IGdyYW50cykgb25seSBzZXJ2ZQpgZ2V0X2dhbWVgLCBgZ2V0X2F0dGVzdGF0aW9uYCBhbmQgcmVwZWF0ZWQgYHJlc29sdmVfbWF0Y2hgIGNhbGxzLgpEZWxldGluZyB0aGVtIHN0b3BzIHRoZWlyIHJlbnQgbm93IGluc3RlYWQgb2YgYXQgZXhwaXJ5LiBDb25zdW1lZApudWxsaWZpZXJzIGFyZSBrZXB0OiB0aGV5IGFyZSB3aGF0IHN0b3BzIHRoZSBnYW1lJ3MgcHJvb2ZzIGJlaW5nCnJlcGxheWVkIGludG8gYSBsYXRlciBnYW1lIHVuZGVyIHRoZSBzYW1lIHNlc3Npb24gSUQsIGFuZCB0aGV5CmV4cGlyZSBvbiB0aGVpciBvd24uCgpTaWduZWQgYnkgYHBsYXllcmAuIFJldHVybnMgYEdhbWVOb3RTZXR0bGVkYCBmb3IgYSBnYW1lIHN0aWxsIGluCnBsYXksIGFuZCBgSHViTm90Tm90aWZpZWRgIHdoaWxlIHRoZSBodWIgaXMgbWlzc2luZyBpdHMgcmVzdWx0IChzZWUKYHJldHJ5X2h1Yl9ub3RpZnlgKS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECiogYHBsYXllcmAgLSBFaXRoZXIgcGxheWVyIG9mIHRoZSBnYW1lAAAAAAAKcHVyZ2VfZ2FtZQAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAoVLZWVwIGEgZ2FtZSdzIHN0b3JhZ2UgYWxpdmUgZm9yIGF0IGxlYXN0IGBsZWRnZXJzYCBtb3JlIGxlZGdlcnMuCgpHYW1lIGVudHJpZXMgbGl2ZSBgR0FNRV9UVExfTEVER0VSU2AgZnJvbSB0aGVpciBsYXN0IHdyaXRlLCB3aGljaCBhCnNsb3ctbW92aW5nIGdhbWUgY2FuIG91dGxhc3QuIEFueW9uZSAoc2F5LCBhIGZyb250ZW5kIGtlZXBlcikgbWF5CmJ1bXAgdGhlIGdhbWUgZW50cnkgYW5kIGl0cyBwZXItc2Vzc2lvbiBlbnRyaWVzOyB0aGUgY2FsbGVyIHBheXMgdGhlCnJlbnQgYW5kIG5vIHNpZ25hdHVyZSBpcyBuZWVkZWQuIFRoZSBodWIncyBvcGVuIHNlc3Npb24gcmVjb3JkIGlzCmJ1bXBlZCB0byBzdGF5IGBHQU1FX1RUTF9MRURHRVJTYCBhaGVhZCwgc28gYGFiYW5kb25fZXhwaXJlZGAgY2FuCnN0aWxsIGZpbmQgaXQuIFNlc3Npb24ga2V5IGdyYW50cyBrZWVwIHRoZSBleHBpcnkgdGhleSB3ZXJlIGdyYW50ZWQKd2l0aCwgYW5kIGRlYWRsaW5lcyBhcmUgdW5jaGFuZ2VkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBHYW1lIHNlc3Npb24gSUQKKiBgbGVkZ2Vyc2AgLSBMZWRnZXJzIHRvIGtlZXAgdGhlIGdhbWUgZm9yLCB1cCB0byB0aGUgbmV0d29yaydzCm1heGltdW0gVFRMIChlbHNlIGBJbnZhbGlkVHRsYCkAAAAAAAAPZXh0ZW5kX2dhbWVfdHRsAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB2xlZGdlcnMAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAD5HZXQgdGhlIGNvbnRyYWN0LXdpZGUgZ2FtZSB0b3RhbHMsIGUuZy4gZm9yIGEgaGVhbHRoIGRhc2hib2FyZAAAAAAAEGdldF9nbG9iYWxfc3RhdHMAAAAAAAAAAQAAB9AAAAALR2xvYmFsU3RhdHMA",
+        "AAAAAAAAAEhHZXQgYHBsYXllcmAncyBsaWZldGltZSByZWNvcmQsIGFsbCB6ZXJvIGJlZm9yZSB0aGVpciBmaXJzdCBzZXR0bGVkCmdhbWUAAAAQZ2V0X3BsYXllcl9zdGF0cwAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAfQAAAAC1BsYXllclN0YXRzAA==",
+        "AAAAAAAAAD5HZXQgaG93IG1hbnkgc2V0dGxlZCBnYW1lcyBgcGxheWVyYCBoYXMgaW4gdGhlaXIgbWF0Y2ggaGlzdG9yeQAAAAAAD2dldF9tYXRjaF9jb3VudAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAVVHZXQgYSBnYW1lIGZyb20gYHBsYXllcmAncyBtYXRjaCBoaXN0b3J5LgoKUmVzdWx0cyBhcmUgYXJjaGl2ZWQgaW4gcGVyc2lzdGVudCBzdG9yYWdlIHdoZW4gYSBnYW1lIGlzIHNldHRsZWQKKGByZXNvbHZlX21hdGNoYCwgYHJlc29sdmVfd2l0aF9yZWNlaXB0YCBvciBgcmVzb2x2ZV90aW1lb3V0YCksIHNvCnRoZXkgb3V0bGl2ZSB0aGUgZ2FtZSBlbnRyeSBgZ2V0X2dhbWVgIHJlYWRzLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIEVpdGhlciBwbGF5ZXIgb2YgdGhlIGdhbWUKKiBgaW5kZXhgIC0gMCBmb3IgdGhlaXIgZmlyc3Qgc2V0dGxlZCBnYW1lLCB1cCB0byBgZ2V0X21hdGNoX2NvdW50YAAAAAAAABBnZXRfbWF0Y2hfcmVjb3JkAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAD6AAAB9AAAAALTWF0Y2hSZWNvcmQA",
+        "AAAAAAAAAWtQYWdlIHRocm91Z2ggYHBsYXllcmAncyBtYXRjaCBoaXN0b3J5LCBuZXdlc3QgZmlyc3QsIHdpdGhvdXQgYW4KZXh0ZXJuYWwgaW5kZXhlcgoKR2FtZXMgd2hvc2UgcmVjb3JkIGhhcyBiZWVuIGFyY2hpdmVkIGJ5IHRoZSBuZXR3b3JrIGFyZSBza2lwcGVkCnVudGlsIHJlc3RvcmVkLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFdob3NlIGhpc3RvcnkgdG8gbGlzdAoqIGBjdXJzb3JgIC0gYE5vbmVgIGZvciB0aGUgbmV3ZXN0IGdhbWVzLCBlbHNlIHRoZSBgaW5kZXhgIG9mIHRoZQpsYXN0IGdhbWUgb2YgdGhlIHByZXZpb3VzIHBhZ2UKKiBgbGltaXRgIC0gR2FtZXMgcGVyIHBhZ2UsIGNhcHBlZCBhdCBgTUFYX0hJU1RPUllfUEFHRWAAAAAAC2dldF9oaXN0b3J5AAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGY3Vyc29yAAAAAAPoAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAAC0dhbWVTdW1tYXJ5AA==",
+        "AAAAAAAAAHBHZXQgYSBnYW1lJ3Mgc3VibWl0IGFuZCByZXZlYWwgZGVhZGxpbmVzLCBlLmcuIHRvIHNob3cgY291bnRkb3ducy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElEAAAADWdldF9kZWFkbGluZXMAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACURlYWRsaW5lcwAAAAAAAAM=",
+        "AAAAAAAAAVFHZXQgdGhlIGxlZGdlciBhIGdhbWUgbGFzdCBjaGFuZ2VkIGluOiBzdGFydGVkLCBhIG1vdmUgc3VibWl0dGVkIG9yCnJldmVhbGVkLCBzZXR0bGVkLCBvciBpdHMgcmVzdWx0IHJlcG9ydGVkIHRvIHRoZSBodWIuIEtlZXBlcnMgY2FuCmNvbXBhcmUgaXQgYWdhaW5zdCBgZ2V0X2RlYWRsaW5lc2AgdG8gZmluZCBnYW1lcyB0byBgcmVzb2x2ZV90aW1lb3V0YApmcm9tIGNvbnRyYWN0IHN0YXRlIGFsb25lLiBHYW1lcyBtaWdyYXRlZCBmcm9tIHNjaGVtYSAxIHJlYWQgMCB1bnRpbAp0aGVpciBuZXh0IGNoYW5nZS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElEAAAAAAAAEWdldF9sYXN0X2FjdGl2aXR5AAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAOtTZXNzaW9uIElEcyBvZiBgcGxheWVyYCdzIGdhbWVzIHN0aWxsIGluIHBsYXkgKHVuc2V0dGxlZCBhbmQgbm90CmV4cGlyZWQpLCBvbGRlc3QgZmlyc3QsIHNvIGEgd2FsbGV0IGNhbiBsaXN0IGl0cyBhY3RpdmUgbWF0Y2hlcwp3aXRob3V0IGFuIGluZGV4ZXIuIEdhbWVzIHRoYXQgZXhwaXJlIHN0YXkgaW5kZXhlZCB1bnRpbApgYWJhbmRvbl9leHBpcmVkYCBjbG9zZXMgdGhlbSwgYnV0IGFyZSBub3QgbGlzdGVkAAAAAA5nZXRfb3Blbl9nYW1lcwAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+oAAAAE",
+        "AAAAAAAAAEFUaGUgb3BlbiByZWNvcmQgb2YgYSBzZXNzaW9uIHRoZSBodWIgaGFzIG5vdCB5ZXQgaGVhcmQgdGhlIGVuZCBvZgAAAAAAABBnZXRfb3Blbl9zZXNzaW9uAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAtPcGVuU2Vzc2lvbgA=",
+        "AAAAAAAAAJ1TY29yZSBgdGFjdGljMWAgYWdhaW5zdCBgdGFjdGljMmAgd2l0aCB0aGUgbWF0cml4IGdhbWVzIGFyZSBzZXR0bGVkCmJ5LCBzbyBjbGllbnRzIG5lZWQgbm90IGtlZXAgdGhlaXIgb3duIGNvcHkuIFJldHVybnMgYEludmFsaWRUYWN0aWNgCmZvciBhbnl0aGluZyBidXQgMC0zAAAAAAAADnNpbXVsYXRlX3Njb3JlAAAAAAACAAAAAAAAAAd0YWN0aWMxAAAAAAQAAAAAAAAAB3RhY3RpYzIAAAAABAAAAAEAAAPpAAAD7QAAAAIAAAAEAAAABAAAAAM=",
+        "AAAAAAAAAD9HZXQgdGhlIHdob2xlIHNjb3JlIG1hdHJpeCwgb3JkZXJlZCBieSBgdGFjdGljMWAgdGhlbiBgdGFjdGljMmAAAAAAEGdldF9zY29yZV9tYXRyaXgAAAAAAAAAAQAAA+oAAAfQAAAAClNjb3JlRW50cnkAAA==",
+        "AAAAAAAAAPpHZXQgYSBzZXR0bGVkIGdhbWUncyBmdWxsIHN0YXRlLCBpbmNsdWRpbmcgc2NvcmVzIGFuZCB0YWN0aWNzLgoKUmV0dXJucyBgR2FtZU5vdFNldHRsZWRgIHdoaWxlIHRoZSBnYW1lIGlzIGluIHBsYXk7IHVzZQpgZ2V0X2dhbWVfcHVibGljYCB0byBmb2xsb3cgaXQgdW50aWwgdGhlbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECgojIFJldHVybnMKKiBgR2FtZWAgLSBDb21wbGV0ZSBnYW1lIHN0YXRlAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABEdhbWUAAAAD",
+        "AAAAAAAAATNHZXQgYSBnYW1lJ3Mgc3RhdGUgd2l0aCByZXZlYWxlZCB0YWN0aWNzIGFuZCBwcm9vZiBoYXNoZXMgd2l0aGhlbGQKdW50aWwgaXQgaXMgc2V0dGxlZCwgc28gY2xpZW50cyBwb2xsaW5nIGFuIGluLXByb2dyZXNzIG1hdGNoIGRvIG5vdApzaG93IGVpdGhlciBwbGF5ZXIgdGhlIG90aGVyJ3MgbW92ZS4KClRoaXMgb25seSBzaGFwZXMgd2hhdCB0aGUgdmlldyByZXR1cm5zOiBjb250cmFjdCBzdG9yYWdlIGl0c2VsZiBpcwpwdWJsaWMgb24gdGhlIGxlZGdlci4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElEAAAAAA9nZXRfZ2FtZV9wdWJsaWMAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAApQdWJsaWNHYW1lAAAAAAAD",
+        "AAAAAAAAAVNDYW5vbmljYWwsIE1lcmtsZS1jb21taXR0ZWQgYXR0ZXN0YXRpb24gb2YgYSBzZXR0bGVkIHNlc3Npb24uCgpCcmlkZ2VzIGFuZCBwYXJ0bmVyIGdhbWVzIG9uIG90aGVyIGNoYWlucyB2ZXJpZnkgYHJvb3RgIChvciBhIHNpbmdsZQpsZWFmIGFnYWluc3QgaXQpIGluc3RlYWQgb2YgdHJ1c3RpbmcgYSByZWxheWVyJ3Mgc3VtbWFyeS4gU2VlCmBhdHRlc3RhdGlvbi5yc2AgZm9yIHRoZSBieXRlIGxheW91dC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gR2FtZSBzZXNzaW9uIElECgojIFJldHVybnMKKiBgQXR0ZXN0YXRpb25gIC0gTGVhdmVzIGFuZCBrZWNjYWsyNTYgTWVya2xlIHJvb3QAAAAAD2dldF9hdHRlc3RhdGlvbgAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAAC0F0dGVzdGF0aW9uAAAAAAM=",
+        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAADJHZXQgdGhlIGFkZHJlc3MgcHJvcG9zZWQgYXMgdGhlIG5leHQgYWRtaW4sIGlmIGFueQAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAWxQcm9wb3NlIGhhbmRpbmcgdGhlIGFkbWluIHJvbGUgdG8gYG5ld19hZG1pbmAKCk5vdGhpbmcgY2hhbmdlcyB1bnRpbCBgbmV3X2FkbWluYCBzaWducyBgYWNjZXB0X2FkbWluYCwgc28gYSBtaXN0eXBlZAphZGRyZXNzIGNhbm5vdCB0YWtlIHRoZSBhZG1pbiByb2xlIChhbmQgd2l0aCBpdCwgdXBncmFkZXMpIG91dCBvZgpyZWFjaC4gUHJvcG9zaW5nIGFnYWluIHJlcGxhY2VzIHRoZSBwZW5kaW5nIHByb3Bvc2FsLiBVbmRlciBhbgphZG1pbiBjb3VuY2lsLCBib3RoIHByb3Bvc2luZyBhbmQgYWNjZXB0aW5nIHRha2UgaXRzIGFwcHJvdmFsLgoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBwcm9wb3NlZCBhZG1pbiBhZGRyZXNzAAAADXByb3Bvc2VfYWRtaW4AAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAMFUYWtlIG92ZXIgYXMgYWRtaW4gYWZ0ZXIgYmVpbmcgcHJvcG9zZWQgd2l0aCBgcHJvcG9zZV9hZG1pbmAKClNpZ25lZCBieSB0aGUgcGVuZGluZyBhZG1pbiwgYW5kIGFwcHJvdmVkIGJ5IHRoZSBhZG1pbiBjb3VuY2lsIGlmCm9uZSBpcyBzZXQuIFJldHVybnMgYE5vUGVuZGluZ0FkbWluYCBpZiBub2JvZHkgaGFzIGJlZW4gcHJvcG9zZWQuAAAAAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAIJXaGV0aGVyIGBhY2NvdW50YCBob2xkcyBgcm9sZWAsIGRpcmVjdGx5IG9yIHRocm91Z2ggdGhlIGBBZG1pbmAgcm9sZS4KVGhlIGFkbWluIGFkZHJlc3MgaXRzZWxmIGlzIG5vdCBsaXN0ZWQgYnV0IGhvbGRzIGV2ZXJ5IHJvbGUuAAAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAABAAAAAQ==",
+        "AAAAAAAAAIlHcmFudCBgcm9sZWAgdG8gYGFjY291bnRgLCBlLmcuIGBQYXVzZXJgIHRvIGFuIG9wcyBib3QKCiMgQXJndW1lbnRzCiogYGFjY291bnRgIC0gVGhlIGFjY291bnQgdG8gZGVsZWdhdGUgdG8KKiBgcm9sZWAgLSBUaGUgcm9sZSB0byBncmFudAAAAAAAAApncmFudF9yb2xlAAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAALVUYWtlIGByb2xlYCBiYWNrIGZyb20gYGFjY291bnRgLiBSb2xlcyBoZWxkIHRocm91Z2ggYEFkbWluYCBzdGF5CnVudGlsIGBBZG1pbmAgaXRzZWxmIGlzIHJldm9rZWQuCgojIEFyZ3VtZW50cwoqIGBhY2NvdW50YCAtIFRoZSBhY2NvdW50IHRvIHJldm9rZSBmcm9tCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gcmV2b2tlAAAAAAAAC3Jldm9rZV9yb2xlAAAAAAIAAAAAAAAAB2FjY291bnQAAAAAEwAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAACRHZXQgdGhlIGFkbWluIGNvdW5jaWwsIGlmIG9uZSBpcyBzZXQAAAARZ2V0X2FkbWluX2NvdW5jaWwAAAAAAAAAAAAAAQAAA+gAAAfQAAAADEFkbWluQ291bmNpbA==",
+        "AAAAAAAAAvVQdXQgdGhlIGNhbGxzIGEgbGVha2VkIGFkbWluIGtleSBjb3VsZCBkbyBtb3N0IGhhcm0gd2l0aCB1bmRlciBhbgpNLW9mLU4gYWRtaW4gY291bmNpbDogdGhlIGh1YiwgcGF1c2luZywgdXBncmFkZXMsIHRoZSBhZG1pbgp0cmFuc2ZlciwgdmVyaWZpZXJzLCByb2xlcywgZGlzcHV0ZXMsIGZlZXMgYW5kIHRoZSBvcmFjbGUKCk9uY2UgYSBjb3VuY2lsIGlzIHNldCwgZWFjaCBgQ291bmNpbEFjdGlvbmAgY2FsbCBnb2VzIHRocm91Z2ggb25seQphZnRlciBgdGhyZXNob2xkYCBtZW1iZXJzIGhhdmUgYXBwcm92ZWQgdGhhdCBleGFjdCBjYWxsIHdpdGgKYGFwcHJvdmVfYWN0aW9uYCwgYW5kIHRoZSBhZG1pbidzIHNpZ25hdHVyZSBubyBsb25nZXIgZG9lcy4gSG9sZGVycwpvZiB0aGUgcm9sZSBhIHNldHRlciB0YWtlcyBjYW4gc3RpbGwgY2FsbCBpdC4gT3RoZXIgYWRtaW4gc2V0dGVycwprZWVwIG5lZWRpbmcgdGhlIGFkbWluLiBTZXR0aW5nIHRoZSBmaXJzdCBjb3VuY2lsIHRha2VzIHRoZSBhZG1pbjsKY2hhbmdpbmcgb3IgcmVtb3ZpbmcgaXQgKGBOb25lYCkgdGFrZXMgdGhlIGN1cnJlbnQgY291bmNpbCdzCmFwcHJvdmFsLgoKIyBBcmd1bWVudHMKKiBgY291bmNpbGAgLSBVcCB0byBgTUFYX0NPVU5DSUxfU0laRWAgZGlzdGluY3QgbWVtYmVycyBhbmQgYQp0aHJlc2hvbGQgZnJvbSAxIHRvIHRoZWlyIG51bWJlciAoZWxzZSBgSW52YWxpZENvdW5jaWxgKSwgb3IKYE5vbmVgIHRvIGdvIGJhY2sgdG8gdGhlIGFkbWluIGFsb25lAAAAAAAAEXNldF9hZG1pbl9jb3VuY2lsAAAAAAAAAQAAAAAAAAAHY291bmNpbAAAAAPoAAAH0AAAAAxBZG1pbkNvdW5jaWwAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAFdNZW1iZXJzIG9mIHRoZSBhZG1pbiBjb3VuY2lsIHdobyBoYXZlIGFwcHJvdmVkIGBhY3Rpb25gIGFuZCBub3QgeWV0CnNlZW4gaXQgY2FycmllZCBvdXQAAAAADWdldF9hcHByb3ZhbHMAAAAAAAABAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAANQ291bmNpbEFjdGlvbgAAAAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAAYRBcHByb3ZlIGBhY3Rpb25gIGFzIGEgbWVtYmVyIG9mIHRoZSBhZG1pbiBjb3VuY2lsCgpTaWduZWQgYnkgYG1lbWJlcmAuIFRoZSBhY3Rpb24gY2FuIGJlIGNhcnJpZWQgb3V0IG9uY2UgYHRocmVzaG9sZGAKbWVtYmVycyBoYXZlIGFwcHJvdmVkIGl0LCB3aGljaCB1c2VzIHRoZSBhcHByb3ZhbHMgdXAuIEFwcHJvdmFscwpsYXBzZSBpZiB0aGUgYWN0aW9uIGlzIG5vdCBjYXJyaWVkIG91dCB3aXRoaW4gYEdBTUVfVFRMX0xFREdFUlNgLgoKIyBBcmd1bWVudHMKKiBgbWVtYmVyYCAtIEFwcHJvdmluZyBjb3VuY2lsIG1lbWJlciAoZWxzZSBgTm90Q291bmNpbE1lbWJlcmApCiogYGFjdGlvbmAgLSBUaGUgZXhhY3QgY2FsbCBiZWluZyBhcHByb3ZlZCwgYXJndW1lbnRzIGluY2x1ZGVkAAAADmFwcHJvdmVfYWN0aW9uAAAAAAACAAAAAAAAAAZtZW1iZXIAAAAAABMAAAAAAAAABmFjdGlvbgAAAAAH0AAAAA1Db3VuY2lsQWN0aW9uAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAVNTZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgpUaGUgbmV3IGh1YiBtdXN0IGFuc3dlciBgdmVyc2lvbigpYCB3aXRoIGF0IGxlYXN0CmBNSU5fSFVCX1ZFUlNJT05gLCBlbHNlIGBJbnZhbGlkSHViYCwgc28gYSBtaXN0eXBlZCBhZGRyZXNzIGlzCmNhdWdodCBoZXJlIHJhdGhlciB0aGFuIGJ5IGV2ZXJ5IGxhdGVyIGBzdGFydF9nYW1lYC4gTmVlZHMgdGhlIGFkbWluCmNvdW5jaWwncyBhcHByb3ZhbCBvbmNlIG9uZSBpcyBzZXQgKHNlZSBgc2V0X2FkbWluX2NvdW5jaWxgKS4KCiMgQXJndW1lbnRzCiogYG5ld19odWJgIC0gVGhlIG5ldyBHYW1lSHViIGNvbnRyYWN0IGFkZHJlc3MAAAAAB3NldF9odWIAAAAAAQAAAAAAAAAHbmV3X2h1YgAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAHtHZXQgdGhlIGV4dGVybmFsIFpLIHZlcmlmaWVyIGNvbnRyYWN0IGFkZHJlc3MsIGlmIGNvbmZpZ3VyZWQKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gVGhlIHZlcmlmaWVyIGNvbnRyYWN0IGFkZHJlc3MAAAAADGdldF92ZXJpZmllcgAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAJVEZWxlZ2F0ZSBwcm9vZiB2ZXJpZmljYXRpb24gdG8gYW4gZXh0ZXJuYWwgdmVyaWZpZXIgY29udHJhY3QKCiMgQXJndW1lbnRzCiogYHZlcmlmaWVyYCAtIENvbnRyYWN0IGltcGxlbWVudGluZyBgdmVyaWZ5KHByb29mLCBwdWJsaWNfaW5wdXRzKSAtPiBib29sYAAAAAAAAAxzZXRfdmVyaWZpZXIAAAABAAAAAAAAAAh2ZXJpZmllcgAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAC9HZXQgdGhlIEdyb3RoMTYgdmVyaWZpY2F0aW9uIGtleSwgaWYgb25lIGlzIHNldAAAAAAUZ2V0X3ZlcmlmaWNhdGlvbl9rZXkAAAAAAAAAAQAAA+gAAAAO",
+        "AAAAAAAAAW1TZXQgdGhlIEdyb3RoMTYgdmVyaWZpY2F0aW9uIGtleSB1c2VkIGJ5IGBzdWJtaXRfdGFjdGljYAoKTGV0cyB0aGUgY2lyY3VpdCBiZSB1cGdyYWRlZCBhZnRlciBhIG5ldyB0cnVzdGVkIHNldHVwIHdpdGhvdXQKcmVkZXBsb3lpbmcuIE9ubHkgdGhlIGxlbmd0aCBpcyBjaGVja2VkIGhlcmUgKHNpeCBwdWJsaWMgaW5wdXRzKS4KQSBrZXkgc291cmNlIHNldCB3aXRoIGBzZXRfdmtfc291cmNlYCB0YWtlcyBwcmVjZWRlbmNlLiBBbiBhZG1pbgpjb3VuY2lsIGFwcHJvdmVzIHRoZSBrZXkgYnkgaXRzIHNoYTI1Ni4KCiMgQXJndW1lbnRzCiogYHZrYCAtIFNlcmlhbGl6ZWQga2V5LCBzZWUgYHNyYy9ncm90aDE2LnJzYCBmb3IgdGhlIGxheW91dAAAAAAAABRzZXRfdmVyaWZpY2F0aW9uX2tleQAAAAEAAAAAAAAAAnZrAAAAAAAOAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAERHZXQgdGhlIHJlZ2lzdHJ5IGVudHJ5IHRoZSB2ZXJpZmljYXRpb24ga2V5IGlzIHJlc29sdmVkIGZyb20sIGlmIHNldAAAAA1nZXRfdmtfc291cmNlAAAAAAAAAAAAAAEAAAPoAAAH0AAAAAhWa1NvdXJjZQ==",
+        "AAAAAAAAAZdSZXNvbHZlIHRoZSBHcm90aDE2IHZlcmlmaWNhdGlvbiBrZXkgZnJvbSBhIGB2ay1yZWdpc3RyeWAgZW50cnkKClJlcGxhY2VzIHRoZSBrZXkgc3RvcmVkIHdpdGggYHNldF92ZXJpZmljYXRpb25fa2V5YCB3aGlsZSBzZXQsIHNvCmV2ZXJ5IGRlcGxveW1lbnQgcGlubmVkIHRvIHRoZSBzYW1lIGVudHJ5IHZlcmlmaWVzIGFnYWluc3Qgb25lCnJlZ2lzdGVyZWQga2V5LiBUaGUgZW50cnkgaXMgZmV0Y2hlZCBhbmQgbGVuZ3RoLWNoZWNrZWQgaGVyZTsgbW92aW5nCnRvIGEgbmV3IHNldHVwIG1lYW5zIHBvaW50aW5nIGF0IGl0cyB2ZXJzaW9uLgoKIyBBcmd1bWVudHMKKiBgc291cmNlYCAtIFJlZ2lzdHJ5LCBjaXJjdWl0IElEIGFuZCB2ZXJzaW9uLCBvciBgTm9uZWAgdG8gZ28gYmFjawp0byB0aGUgc3RvcmVkIGtleQAAAAANc2V0X3ZrX3NvdXJjZQAAAAAAAAEAAAAAAAAABnNvdXJjZQAAAAAD6AAAB9AAAAAIVmtTb3VyY2UAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAC9HZXQgdGhlIHByb29mIHN5c3RlbSBuZXcgZ2FtZXMgYXJlIHN0YXJ0ZWQgd2l0aAAAAAAQZ2V0X3Byb29mX3N5c3RlbQAAAAAAAAABAAAH0AAAAAtQcm9vZlN5c3RlbQA=",
+        "AAAAAAAAALlTZXQgdGhlIHByb29mIHN5c3RlbSBuZXcgZ2FtZXMgYXJlIHN0YXJ0ZWQgd2l0aAoKR2FtZXMgYWxyZWFkeSBzdGFydGVkIGtlZXAgdGhlIGJhY2tlbmQgdGhleSB3ZXJlIGNyZWF0ZWQgd2l0aC4KCiMgQXJndW1lbnRzCiogYHByb29mX3N5c3RlbWAgLSBCYWNrZW5kIGZvciBzdWJzZXF1ZW50IGBzdGFydF9nYW1lYCBjYWxscwAAAAAAABBzZXRfcHJvb2Zfc3lzdGVtAAAAAQAAAAAAAAAMcHJvb2Zfc3lzdGVtAAAH0AAAAAtQcm9vZlN5c3RlbQAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADdHZXQgdGhlIGxhcmdlc3QgcHJvb2YsIGluIGJ5dGVzLCBhIHN1Ym1pc3Npb24gbWF5IGNhcnJ5AAAAABJnZXRfbWF4X3Byb29mX3NpemUAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAATNTZXQgdGhlIGxhcmdlc3QgcHJvb2YsIGluIGJ5dGVzLCBhIHN1Ym1pc3Npb24gbWF5IGNhcnJ5CgpMYXJnZXIgcHJvb2ZzIGZhaWwgd2l0aCBgUHJvb2ZUb29MYXJnZWAgYmVmb3JlIHRoZXkgYXJlIGhhc2hlZCBvcgpzdG9yZWQsIHNvIHRoZXkgY2Fubm90IGluZmxhdGUgYSB0cmFuc2FjdGlvbidzIGNvc3QuIFJhaXNlIGl0IGJlZm9yZQptb3ZpbmcgdG8gYSBiYWNrZW5kIHdpdGggYmlnZ2VyIHByb29mcy4KCiMgQXJndW1lbnRzCiogYHNpemVgIC0gTGltaXQgaW4gYnl0ZXMgKGBERUZBVUxUX01BWF9QUk9PRl9TSVpFYCB1bnRpbCBzZXQpAAAAABJzZXRfbWF4X3Byb29mX3NpemUAAAAAAAEAAAAAAAAABHNpemUAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAGBHZXQgdGhlIGxlZGdlcnMgcGxheWVycyBoYXZlIHRvIHN1Ym1pdCBhZnRlciBhIGdhbWUgc3RhcnRzCihgREVGQVVMVF9USU1FT1VUX0xFREdFUlNgIHVudGlsIHNldCkAAAATZ2V0X3RpbWVvdXRfbGVkZ2VycwAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAbdTZXQgdGhlIGxlZGdlcnMgcGxheWVycyBoYXZlIHRvIHN1Ym1pdCBhZnRlciBhIGdhbWUgc3RhcnRzLCBhZnRlcgp3aGljaCBgcmVzb2x2ZV90aW1lb3V0YCBzZXR0bGVzIGFiYW5kb25lZCBnYW1lcy4gUGxheWVycyB0aGVuIGhhdmUgYXMKbG9uZyBhZ2FpbiB0byByZXZlYWwuCgpHYW1lcyBrZWVwIHRoZSBkZWFkbGluZXMgdGhleSBzdGFydGVkIHdpdGguIEJvdGggd2luZG93cyBtdXN0IGVuZApiZWZvcmUgdGhlIGdhbWUncyBzdG9yYWdlIHdvdWxkIGV4cGlyZSwgZWxzZSBgSW52YWxpZERlYWRsaW5lc2AuCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgT3BlcmF0b3JgIChvciBgQWRtaW5gKSByb2xlCiogYGxlZGdlcnNgIC0gVGltZW91dCBpbiBsZWRnZXJzLCBiZWxvdyBoYWxmIHRoZSBnYW1lIHN0b3JhZ2UgVFRMAAAAABNzZXRfdGltZW91dF9sZWRnZXJzAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAF5HZXQgdGhlIG9sZGVzdCBwcm9vZiB2ZXJzaW9uIGBzdWJtaXRfdGFjdGljX2VudmVsb3BlYCBhY2NlcHRzCihgQkFSRV9QUk9PRl9WRVJTSU9OYCB1bnRpbCBzZXQpAAAAAAAVZ2V0X21pbl9wcm9vZl92ZXJzaW9uAAAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAPtTZXQgdGhlIG9sZGVzdCBwcm9vZiB2ZXJzaW9uIGFjY2VwdGVkCgpSYWlzZSBpdCBvbmNlIHByb3ZlcnMgaGF2ZSBtb3ZlZCB0byBhIG5ldyBjaXJjdWl0IHRvIHN0b3AgYWNjZXB0aW5nCnByb29mcyBmcm9tIHRoZSBvbGQgb25lLCBpbmNsdWRpbmcgYmFyZSBgc3VibWl0X3RhY3RpY2AgcHJvb2ZzLgoKIyBBcmd1bWVudHMKKiBgdmVyc2lvbmAgLSBBdCBtb3N0IGBQUk9PRl9WRVJTSU9OYCwgb3IgYFVuc3VwcG9ydGVkUHJvb2ZWZXJzaW9uYAAAAAAVc2V0X21pbl9wcm9vZl92ZXJzaW9uAAAAAAAAAQAAAAAAAAAHdmVyc2lvbgAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADpHZXQgdGhlIFVsdHJhSG9uayB2ZXJpZmllciBjb250cmFjdCBhZGRyZXNzLCBpZiBjb25maWd1cmVkAAAAAAAWZ2V0X3VsdHJhaG9ua192ZXJpZmllcgAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAJ9TZXQgdGhlIHZlcmlmaWVyIGNvbnRyYWN0IGZvciBnYW1lcyB1c2luZyBgUHJvb2ZTeXN0ZW06OlVsdHJhSG9ua2AKCiMgQXJndW1lbnRzCiogYHZlcmlmaWVyYCAtIENvbnRyYWN0IGltcGxlbWVudGluZyBgdmVyaWZ5X3Byb29mKHB1YmxpY19pbnB1dHMsIHByb29mX2J5dGVzKWAAAAAAFnNldF91bHRyYWhvbmtfdmVyaWZpZXIAAAAAAAEAAAAAAAAACHZlcmlmaWVyAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADhHZXQgdGhlIGhpZGRlbi1zdGFrZSByYW5nZSBwcm9vZiB2ZXJpZmllciwgaWYgY29uZmlndXJlZAAAABJnZXRfc3Rha2VfdmVyaWZpZXIAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAJRTZXQgdGhlIHZlcmlmaWVyIGZvciBgc3RhcnRfaGlkZGVuX3N0YWtlX2dhbWVgIHJhbmdlIHByb29mcwoKIyBBcmd1bWVudHMKKiBgdmVyaWZpZXJgIC0gQ29udHJhY3QgaW1wbGVtZW50aW5nIGB2ZXJpZnkocHJvb2YsIHB1YmxpY19pbnB1dHMpIC0+IGJvb2xgAAAAEnNldF9zdGFrZV92ZXJpZmllcgAAAAAAAQAAAAAAAAAIdmVyaWZpZXIAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAD5HZXQgdGhlIHJhdGluZyBwcm9vZiB2ZXJpZmllciBmb3IgYnJhY2tldCBnYW1lcywgaWYgY29uZmlndXJlZAAAAAAAE2dldF9yYXRpbmdfdmVyaWZpZXIAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAJBTZXQgdGhlIHZlcmlmaWVyIGZvciBgc3RhcnRfYnJhY2tldF9nYW1lYCByYXRpbmcgcHJvb2ZzCgojIEFyZ3VtZW50cwoqIGB2ZXJpZmllcmAgLSBDb250cmFjdCBpbXBsZW1lbnRpbmcgYHZlcmlmeShwcm9vZiwgcHVibGljX2lucHV0cykgLT4gYm9vbGAAAAATc2V0X3JhdGluZ192ZXJpZmllcgAAAAABAAAAAAAAAAh2ZXJpZmllcgAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADBHZXQgdGhlIGFudGktc3liaWwgZ2F0ZSBmb3IgcmFua2VkIGdhbWVzLCBpZiBzZXQAAAAOZ2V0X3N5YmlsX2dhdGUAAAAAAAAAAAABAAAD6AAAB9AAAAAJU3liaWxHYXRlAAAA",
+        "AAAAAAAAAUVTZXQgdGhlIGFudGktc3liaWwgZ2F0ZSBgc3RhcnRfcmFua2VkX2dhbWVgIGNoZWNrcyBwbGF5ZXJzIGFnYWluc3QKClB1Ymxpc2ggYSBuZXcgaWRlbnRpdHkgcm9vdCBhcyBpZGVudGl0aWVzIHJlZ2lzdGVyLCBhbmQgYSBuZXcKYnJhY2tldCB3aGVuIGEgcmFua2luZyBwZXJpb2Qgc3RhcnRzLiBOdWxsaWZpZXJzIG9mIGVhcmxpZXIgYnJhY2tldHMKYXJlIGtlcHQgYnV0IG5vIGxvbmdlciBjaGVja2VkLgoKIyBBcmd1bWVudHMKKiBgZ2F0ZWAgLSBWZXJpZmllciwgaWRlbnRpdHkgcm9vdCBhbmQgYnJhY2tldCwgb3IgYE5vbmVgIHRvIGRpc2FibGUKcmFua2VkIGdhbWVzAAAAAAAADnNldF9zeWJpbF9nYXRlAAAAAAABAAAAAAAAAARnYXRlAAAD6AAAB9AAAAAJU3liaWxHYXRlAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADdHZXQgdGhlIGltYWdlIElEIG5ldyBnYW1lcyBhcmUgc2NvcmVkIGJ5LCBpZiBvbmUgaXMgc2V0AAAAABdnZXRfc2ltdWxhdGlvbl9pbWFnZV9pZAAAAAAAAAAAAQAAA+gAAAPuAAAAIA==",
+        "AAAAAAAAAVNTY29yZSBuZXcgZ2FtZXMgd2l0aCBhbiBvZmYtY2hhaW4gc2ltdWxhdGlvbiBpbnN0ZWFkIG9mIHRoZSBtYXRyaXgKCkdhbWVzIHN0YXJ0ZWQgd2hpbGUgYW4gaW1hZ2UgSUQgaXMgc2V0IG11c3QgYmUgcmVzb2x2ZWQgd2l0aApgcmVzb2x2ZV93aXRoX3JlY2VpcHRgLCB1c2luZyBhIHJlY2VpcHQgb2YgdGhhdCB6a1ZNIHByb2dyYW0uIEdhbWVzCmFscmVhZHkgc3RhcnRlZCBrZWVwIHRoZSBzY29yaW5nIHRoZXkgd2VyZSBjcmVhdGVkIHdpdGguCgojIEFyZ3VtZW50cwoqIGBpbWFnZV9pZGAgLSB6a1ZNIGltYWdlIElEIG9mIHRoZSBzaW11bGF0aW9uLCBvciBgTm9uZWAgZm9yIHRoZSBtYXRyaXgAAAAAF3NldF9zaW11bGF0aW9uX2ltYWdlX2lkAAAAAAEAAAAAAAAACGltYWdlX2lkAAAD6AAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAD1HZXQgdGhlIHprVk0gcmVjZWlwdCB2ZXJpZmllciBjb250cmFjdCBhZGRyZXNzLCBpZiBjb25maWd1cmVkAAAAAAAAFGdldF9yZWNlaXB0X3ZlcmlmaWVyAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAJRTZXQgdGhlIHZlcmlmaWVyIGZvciBgcmVzb2x2ZV93aXRoX3JlY2VpcHRgIHNlYWxzCgojIEFyZ3VtZW50cwoqIGB2ZXJpZmllcmAgLSBDb250cmFjdCBpbXBsZW1lbnRpbmcKYHZlcmlmeV9yZWNlaXB0KHNlYWwsIGltYWdlX2lkLCBqb3VybmFsX2RpZ2VzdClgAAAAFHNldF9yZWNlaXB0X3ZlcmlmaWVyAAAAAQAAAAAAAAAIdmVyaWZpZXIAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAElHZXQgdGhlIHZlcmlmaWVyIGNvbnRyYWN0IGZvciBtdWx0aS1yb3VuZCByZWN1cnNpdmUgcHJvb2ZzLCBpZiBvbmUgaXMgc2V0AAAAAAAAE2dldF9yb3VuZHNfdmVyaWZpZXIAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAKhTZXQgdGhlIHZlcmlmaWVyIGNvbnRyYWN0IGZvciBtdWx0aS1yb3VuZCByZWN1cnNpdmUgcHJvb2ZzCgojIEFyZ3VtZW50cwoqIGB2ZXJpZmllcmAgLSBDb250cmFjdCBleHBvc2luZyBgdmVyaWZ5KHByb29mLCBwdWJsaWNfaW5wdXRzKWAgZm9yCnRoZSByZWN1cnNpdmUgcm91bmRzIGNpcmN1aXQAAAATc2V0X3JvdW5kc192ZXJpZmllcgAAAAABAAAAAAAAAAh2ZXJpZmllcgAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADJHZXQgdGhlIHRpZWJyZWFrIHBvbGljeSBuZXcgZ2FtZXMgYXJlIHN0YXJ0ZWQgd2l0aAAAAAAAE2dldF90aWVicmVha19wb2xpY3kAAAAAAAAAAAEAAAfQAAAADlRpZWJyZWFrUG9saWN5AAA=",
+        "AAAAAAAAAPhTZXQgaG93IGRyYXduIG1hdGNoZXMgYXJlIGRlY2lkZWQgZm9yIG5ldyBnYW1lcwoKR2FtZXMgYWxyZWFkeSBzdGFydGVkIGtlZXAgdGhlIHBvbGljeSB0aGV5IHdlcmUgY3JlYXRlZCB3aXRoLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYE9wZXJhdG9yYCAob3IgYEFkbWluYCkgcm9sZQoqIGBwb2xpY3lgIC0gUG9saWN5IGZvciBzdWJzZXF1ZW50IGBzdGFydF9nYW1lYCBjYWxscwAAABNzZXRfdGllYnJlYWtfcG9saWN5AAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAGcG9saWN5AAAAAAfQAAAADlRpZWJyZWFrUG9saWN5AAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAC1HZXQgdGhlIHByb29mIGZyZXNobmVzcyB3aW5kb3csIGlmIG9uZSBpcyBzZXQAAAAAAAAaZ2V0X3Byb29mX2ZyZXNobmVzc193aW5kb3cAAAAAAAAAAAABAAAD6AAAAAQ=",
+        "AAAAAAAAAU1TZXQgaG93IGZhciBhaGVhZCBhIHByb29mJ3MgYHZhbGlkX3VudGlsX2xlZGdlcmAgbWF5IGJlCgpXaGlsZSBhIHdpbmRvdyBpcyBzZXQsIGV2ZXJ5IHByb29mIG11c3QgY2FycnkgYSBub24temVybwpgdmFsaWRfdW50aWxfbGVkZ2VyYCBubyBtb3JlIHRoYW4gYHdpbmRvd2AgbGVkZ2VycyBhZnRlciB0aGUgbGVkZ2VyIGl0CmlzIHN1Ym1pdHRlZCBpbi4gYE5vbmVgIGxpZnRzIHRoZSBsaW1pdCAoZXhwaXJpZXMgYXJlIHN0aWxsIGVuZm9yY2VkKS4KCiMgQXJndW1lbnRzCiogYHdpbmRvd2AgLSBNYXhpbXVtIHZhbGlkaXR5IGluIGxlZGdlcnMsIG9yIGBOb25lYCBmb3Igbm8gbGltaXQAAAAAAAAac2V0X3Byb29mX2ZyZXNobmVzc193aW5kb3cAAAAAAAEAAAAAAAAABndpbmRvdwAAAAAD6AAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEVXaGV0aGVyIG5ldyBHcm90aDE2IGdhbWVzIGRlZmVyIHByb29mIHZlcmlmaWNhdGlvbiB0byBgcmVzb2x2ZV9tYXRjaGAAAAAAAAAWZ2V0X2JhdGNoX3ZlcmlmaWNhdGlvbgAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAXxEZWZlciBwcm9vZiB2ZXJpZmljYXRpb24gb2YgbmV3IEdyb3RoMTYgZ2FtZXMgdG8gYHJlc29sdmVfbWF0Y2hgCgpCb3RoIHByb29mcyBvZiBhIG1hdGNoIGFyZSB0aGVuIGNoZWNrZWQgd2l0aCBvbmUgYmF0Y2hlZCBwYWlyaW5nCmNoZWNrIGluc3RlYWQgb2Ygb25lIGVhY2ggYXQgc3VibWlzc2lvbiwgYXQgdGhlIGNvc3Qgb2YgY2F0Y2hpbmcgYW4KaW52YWxpZCBwcm9vZiBvbmx5IGF0IHJlc29sdXRpb24gKGl0cyBwbGF5ZXIgZm9yZmVpdHMpLiBHYW1lcyBhbHJlYWR5CnN0YXJ0ZWQga2VlcCB0aGUgbW9kZSB0aGV5IHdlcmUgY3JlYXRlZCB3aXRoLgoKIyBBcmd1bWVudHMKKiBgZW5hYmxlZGAgLSBNb2RlIGZvciBzdWJzZXF1ZW50IGBzdGFydF9nYW1lYCBjYWxscwAAABZzZXRfYmF0Y2hfdmVyaWZpY2F0aW9uAAAAAAABAAAAAAAAAAdlbmFibGVkAAAAAAEAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADhXaGV0aGVyIG5ldyBnYW1lcyBzZXR0bGUgdGhlbXNlbHZlcyBvbiB0aGVpciBsYXN0IHJldmVhbAAAABBnZXRfYXV0b19yZXNvbHZlAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAqZTZXR0bGUgbmV3IGdhbWVzIGFzIHNvb24gYXMgdGhlaXIgbGFzdCByZXZlYWwgbGFuZHMKClRhY3RpY3Mgc3RheSBoaWRkZW4gdW50aWwgYm90aCBwbGF5ZXJzIGhhdmUgc3VibWl0dGVkIGFuZCByZXZlYWxlZCwKc28gdGhlIHJldmVhbCBjb21wbGV0aW5nIGEgZ2FtZSAoYSB0YWN0aWMsIHRoZSBsYXN0IHJvdW5kIG9yLCBpbgpoaWRkZW4tc3Rha2UgZ2FtZXMsIHRoZSBsYXN0IHN0YWtlKSBzY29yZXMgaXQgYW5kIGNhbGxzIHRoZSBodWIgaW4KdGhlIHNhbWUgdHJhbnNhY3Rpb24sIHdpdGggbm8gc2VwYXJhdGUgYHJlc29sdmVfbWF0Y2hgLiBUaGF0IHJldmVhbAp0aGVuIGZhaWxzIGlmIHNldHRsaW5nIGRvZXMsIHRob3VnaCBub3Qgd2hlbiBvbmx5IHRoZSBodWIgY2FsbCBmYWlscwooc2VlIGByZXRyeV9odWJfbm90aWZ5YCkuIEdhbWVzIHNjb3JlZCBieQphIHprVk0gcmVjZWlwdCBzdGlsbCBuZWVkIGByZXNvbHZlX3dpdGhfcmVjZWlwdGAuIEdhbWVzIGFscmVhZHkKc3RhcnRlZCBrZWVwIHRoZSBtb2RlIHRoZXkgd2VyZSBjcmVhdGVkIHdpdGguCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgT3BlcmF0b3JgIChvciBgQWRtaW5gKSByb2xlCiogYGVuYWJsZWRgIC0gTW9kZSBmb3Igc3Vic2VxdWVudCBgc3RhcnRfZ2FtZWAgY2FsbHMAAAAAABBzZXRfYXV0b19yZXNvbHZlAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAdlbmFibGVkAAAAAAEAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEhHZXQgdGhlIHBvaW50cyBhIHBsYXllciBtYXkgc3Rha2Ugb24gYSBnYW1lICgwIHRvIGBpMTI4OjpNQVhgIHVudGlsCnNldCkAAAAQZ2V0X3dhZ2VyX2xpbWl0cwAAAAAAAAABAAAH0AAAAAtXYWdlckxpbWl0cwA=",
+        "AAAAAAAAAohTZXQgdGhlIHBvaW50cyBhIHBsYXllciBtYXkgc3Rha2Ugb24gZ2FtZXMgc3RhcnRlZCBmcm9tIG5vdyBvbgoKRXZlcnkgYHN0YXJ0XypgIGNhbGwgY2hlY2tzIGJvdGggcGxheWVycycgcG9pbnRzLCByZXR1cm5pbmcKYFdhZ2VyVG9vU21hbGxgIG9yIGBXYWdlclRvb0xhcmdlYCBmb3IgcG9pbnRzIG91dHNpZGUgdGhlIGxpbWl0cy4KSGlkZGVuLXN0YWtlIGdhbWVzIGNoZWNrIHRoZWlyIGBbbWluX3N0YWtlLCBtYXhfc3Rha2VdYCByYW5nZQppbnN0ZWFkLCBzaW5jZSB0aGUgc3Rha2VzIHRoZW1zZWx2ZXMgYXJlIG5vdCB5ZXQga25vd24uIE5lZ2F0aXZlCnBvaW50cyBhcmUgYWx3YXlzIHJlamVjdGVkOyBhIGBtaW5fd2FnZXJgIG9mIDEgYWxzbyBydWxlcyBvdXQKZ2FtZXMgcGxheWVkIGZvciBub3RoaW5nLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYEZlZU1hbmFnZXJgIChvciBgQWRtaW5gKSByb2xlCiogYG1pbl93YWdlcmAgLSBGZXdlc3QgcG9pbnRzIGEgcGxheWVyIG1heSBzdGFrZSwgYXQgbGVhc3QgMAoqIGBtYXhfd2FnZXJgIC0gTW9zdCBwb2ludHMgYSBwbGF5ZXIgbWF5IHN0YWtlLCBhdCBsZWFzdCBgbWluX3dhZ2VyYAooZWxzZSBgSW52YWxpZFdhZ2VyTGltaXRzYCkAAAAQc2V0X3dhZ2VyX2xpbWl0cwAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAJbWluX3dhZ2VyAAAAAAAACwAAAAAAAAAJbWF4X3dhZ2VyAAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFBHZXQgdGhlIHRva2VuIG5ldyBnYW1lcyBhcmUgd2FnZXJlZCBpbiAoTm9uZSA9IHBvaW50cyBvbmx5LCBub3RoaW5nCmlzIGVzY3Jvd2VkKQAAAA9nZXRfd2FnZXJfdG9rZW4AAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAGdHZXQgdGhlIG5hdGl2ZSBYTE0gU3RlbGxhciBBc3NldCBDb250cmFjdCBvbiB0aGlzIG5ldHdvcmssIGZvcgpgc3RhcnRfbmF0aXZlX2dhbWVgIG9yIGBzZXRfd2FnZXJfdG9rZW5gAAAAABBnZXRfbmF0aXZlX3Rva2VuAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAjJTZXQgdGhlIHRva2VuIGdhbWVzIHN0YXJ0ZWQgZnJvbSBub3cgb24gYXJlIHdhZ2VyZWQgaW4KCldoaWxlIHNldCwgZXZlcnkgYHN0YXJ0XypgIGNhbGwgbW92ZXMgZWFjaCBwbGF5ZXIncyBwb2ludHMsIGFzIHVuaXRzCm9mIGB0b2tlbmAsIGZyb20gdGhlIHBsYXllciBpbnRvIHRoZSBjb250cmFjdCwgd2hlcmUgdGhleSBzdGF5IHVudGlsCnRoZSBnYW1lIHNldHRsZXMgKHNlZSBgZ2V0X2VzY3Jvd2ApLiBQbGF5ZXJzIGF1dGhvcml6ZSB0aGF0IHRyYW5zZmVyCmFsb25nIHdpdGggdGhlIHN0YXJ0IGNhbGwuIEhpZGRlbi1zdGFrZSBnYW1lcyBsb2NrIGBtYXhfc3Rha2VgIGZyb20KYm90aC4gR2FtZXMgYWxyZWFkeSBzdGFydGVkIGtlZXAgdGhlIHRva2VuIHRoZXkgZXNjcm93ZWQuCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgRmVlTWFuYWdlcmAgKG9yIGBBZG1pbmApIHJvbGUKKiBgdG9rZW5gIC0gQSBTdGVsbGFyIEFzc2V0IENvbnRyYWN0IG9yIG90aGVyIFNFUC00MSB0b2tlbiwgb3IgYE5vbmVgCnRvIHN0b3AgZXNjcm93aW5nAAAAAAAPc2V0X3dhZ2VyX3Rva2VuAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAPoAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAEZHZXQgdGhlIHRva2VucyBoZWxkIGluIGVzY3JvdyBmb3IgYSBnYW1lLCBpZiBpdCB3YXMgd2FnZXJlZCBpbiBhIHRva2VuAAAAAAAKZ2V0X2VzY3JvdwAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAZFc2Nyb3cAAA==",
+        "AAAAAAAAAXZQYXkgb3V0IHdpbm5pbmdzIGhlbGQgd2hlbiBhIGdhbWUgc2V0dGxlZAoKYHJlc29sdmVfbWF0Y2hgIHB1c2hlcyBlYWNoIHBsYXllcidzIHNoYXJlIG9mIHRoZSBwb3QgdG8gdGhlbTsgaWYgdGhlCnRva2VuIHJlZnVzZXMgKGZvciBleGFtcGxlIGEgc21hcnQgd2FsbGV0IG9yIGFjY291bnQgbm90IHlldCBhYmxlIHRvCmhvbGQgaXQpLCB0aGUgc2hhcmUgc3RheXMgaW4gZXNjcm93LiBPbmNlIHRoZSBwbGF5ZXIgY2FuIHJlY2VpdmUgdGhlCnRva2VuLCBhbnlvbmUgbWF5IGNhbGwgdGhpcyB0byBzZW5kIGl0IG9uLiBTaGFyZXMgdGhlIHRva2VuIHN0aWxsCnJlZnVzZXMgc3RheSBoZWxkLgoKIyBSZXR1cm5zCiogVGhlIHRvdGFsIGFtb3VudCBwYWlkIG91dAAAAAAADmNsYWltX3dpbm5pbmdzAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAiVQYXkgb3V0IHJlZnVuZHMgYSBnYW1lJ3MgZXNjcm93IHN0aWxsIG93ZXMKCkRyYXdzLCBjYW5jZWxsYXRpb25zIGFuZCB0aW1lb3V0cyByZWZ1bmQgdGhlaXIgZXNjcm93IGFzIHRoZXkKc2V0dGxlIChzZWUgYGdldF9lc2Nyb3dgKSwgaG9sZGluZyBhbnkgcGF5b3V0IHRoZSB0b2tlbiByZWZ1c2VzLgpUaGlzIHNlbmRzIGhlbGQgcmVmdW5kcyBvbiwgYW5kIHJlZnVuZHMgYSBnYW1lIHdob3NlIGVudHJ5IGV4cGlyZWQKYmVmb3JlIGl0IHdhcyByZXNvbHZlZCwgcmV0dXJuaW5nIGVhY2ggcGxheWVyIHdoYXQgdGhleSBsb2NrZWQuCkl0IGlzIGlkZW1wb3RlbnQ6IHdpdGggbm90aGluZyBsZWZ0IHRvIHJlZnVuZCAob3Igb25jZSBhIHJlZnVzZWQKcGF5b3V0IHN0aWxsIGNhbm5vdCBiZSBzZW50KSBpdCBwYXlzIG5vdGhpbmcgYW5kIHJldHVybnMgMC4gUmV0dXJucwpgR2FtZU5vdFNldHRsZWRgIGZvciBhIGdhbWUgc3RpbGwgaW4gcGxheS4gQW55b25lIG1heSBjYWxsIGl0LgoKIyBSZXR1cm5zCiogVGhlIHRvdGFsIGFtb3VudCByZWZ1bmRlZCBieSB0aGlzIGNhbGwAAAAAAAAMY2xhaW1fcmVmdW5kAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAgNQcmVwYXkgYSB0b2tlbiBiYWxhbmNlIHRvIHN0YXJ0IHdhZ2VyZWQgZ2FtZXMgYWdhaW5zdAoKYGFtb3VudGAgb2YgYHRva2VuYCBtb3ZlcyBmcm9tIGBwbGF5ZXJgIGludG8gdGhlIGNvbnRyYWN0IG9uY2UuCkZyb20gdGhlbiBvbiwgYSBnYW1lIHdhZ2VyZWQgaW4gYHRva2VuYCB0YWtlcyB0aGUgcGxheWVyJ3MgYW1vdW50Cm91dCBvZiB0aGlzIGJhbGFuY2UsIGlmIGl0IGNvdmVycyBpdCwgaW5zdGVhZCBvZiBhIHRyYW5zZmVyIHRoZQpwbGF5ZXIgaGFzIHRvIGF1dGhvcml6ZS4gUGF5b3V0cyBhcmUgc3RpbGwgc2VudCB0byB0aGUgcGxheWVyLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFdobyBkZXBvc2l0cywgYW5kIHNpZ25zIHRoZSB0cmFuc2ZlcgoqIGB0b2tlbmAgLSBUaGUgdG9rZW4gdG8gZGVwb3NpdAoqIGBhbW91bnRgIC0gSG93IG11Y2ggdG8gZGVwb3NpdCAoZWxzZSBgSW52YWxpZEJhbGFuY2VBbW91bnRgKQoKIyBSZXR1cm5zCiogVGhlIHBsYXllcidzIGJhbGFuY2UgaW4gYHRva2VuYAAAAAAPZGVwb3NpdF9iYWxhbmNlAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAYtUYWtlIHRva2VucyBiYWNrIG91dCBvZiBhIHByZXBhaWQgYmFsYW5jZQoKQW1vdW50cyBhbHJlYWR5IHN0YWtlZCBvbiBnYW1lcyBoYXZlIGxlZnQgdGhlIGJhbGFuY2UsIGFuZCBjb21lCmJhY2sgYXMgcGF5b3V0cy4gQWxsb3dlZCB3aGlsZSBwYXVzZWQuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXJgIC0gV2hvc2UgYmFsYW5jZSwgYW5kIHNpZ25zCiogYHRva2VuYCAtIFRoZSBiYWxhbmNlJ3MgdG9rZW4KKiBgYW1vdW50YCAtIEhvdyBtdWNoIHRvIHdpdGhkcmF3IChlbHNlIGBJbnZhbGlkQmFsYW5jZUFtb3VudGApLCB1cAp0byB0aGUgYmFsYW5jZSAoZWxzZSBgSW5zdWZmaWNpZW50QmFsYW5jZWApCgojIFJldHVybnMKKiBUaGUgcGxheWVyJ3MgcmVtYWluaW5nIGJhbGFuY2UgaW4gYHRva2VuYAAAAAAQd2l0aGRyYXdfYmFsYW5jZQAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAClHZXQgYSBwbGF5ZXIncyBwcmVwYWlkIGJhbGFuY2UgaW4gYSB0b2tlbgAAAAAAAAtnZXRfYmFsYW5jZQAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAHFHZXQgd2hvIHJ1bGVzIG9uIGRpc3B1dGVkIHNldHRsZW1lbnRzLCBhbmQgZm9yIGhvdyBsb25nIGFmdGVyCnNldHRsaW5nIGEgcG90IGNhbiBiZSBkaXNwdXRlZCAoTm9uZSA9IG5vIGRpc3B1dGVzKQAAAAAAABJnZXRfZGlzcHV0ZV9jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAANRGlzcHV0ZUNvbmZpZwAAAA==",
+        "AAAAAAAAAhpTZXQgd2hvIHJ1bGVzIG9uIGRpc3B1dGVkIHNldHRsZW1lbnRzCgpXaGlsZSBzZXQsIGV2ZXJ5IHdvbiBwb3QgYmV0d2VlbiB0d28gcGxheWVycyBvcGVucyBhIGRpc3B1dGUgd2luZG93Cm9mIGB3aW5kb3dfbGVkZ2Vyc2AsIGxvY2tpbmcgYXMgbXVjaCBvZiB0aGUgd2lubmVyJ3MgYm9uZCBpbiB0aGUKdG9rZW4gdGhleSB3b24gYXMgdGhleSB3ZXJlIHBhaWQgKGBwb3N0X2JvbmRgKS4gVGhlIGxvc2VyIG1heQpkaXNwdXRlIHdpdGhpbiB0aGUgd2luZG93LCBhbmQgYG1vZHVsZWAgcnVsZXMgd2l0aApgcmVzb2x2ZV9kaXNwdXRlYC4gV2luZG93cyBhbHJlYWR5IG9wZW4ga2VlcCB0aGUgbW9kdWxlIHRoZXkgb3BlbmVkCndpdGguCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgQWRtaW5gIHJvbGUKKiBgY29uZmlnYCAtIFRoZSBtb2R1bGUgYW5kIGEgbm9uemVybyBgd2luZG93X2xlZGdlcnNgIChlbHNlCmBJbnZhbGlkRGlzcHV0ZUNvbmZpZ2ApLCBvciBgTm9uZWAgZm9yIG5vIGRpc3B1dGVzAAAAAAASc2V0X2Rpc3B1dGVfY29uZmlnAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABmNvbmZpZwAAAAAD6AAAB9AAAAANRGlzcHV0ZUNvbmZpZwAAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAASJQb3N0IGEgYm9uZCB0aGF0IGJhY2tzIGEgcGxheWVyJ3Mgd2lucyB3aGlsZSB0aGV5IGNhbiBiZSBkaXNwdXRlZAoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFdobyBwb3N0cywgYW5kIHNpZ25zIHRoZSB0cmFuc2ZlcgoqIGB0b2tlbmAgLSBUaGUgYm9uZCdzIHRva2VuLCB0aGUgb25lIHRoZSBwbGF5ZXIncyBwb3RzIGFyZSB3b24gaW4KKiBgYW1vdW50YCAtIEhvdyBtdWNoIHRvIGFkZCAoZWxzZSBgSW52YWxpZEJvbmRBbW91bnRgKQoKIyBSZXR1cm5zCiogVGhlIHBsYXllcidzIGJvbmQgaW4gYHRva2VuYAAAAAAACXBvc3RfYm9uZAAAAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAATVUYWtlIGJhY2sgdGhlIHBhcnQgb2YgYSBib25kIG5vdCBsb2NrZWQgYWdhaW5zdCBhIHBvdCBpbiBpdHMKZGlzcHV0ZSB3aW5kb3cuIEFsbG93ZWQgd2hpbGUgcGF1c2VkLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFdob3NlIGJvbmQsIGFuZCBzaWducwoqIGB0b2tlbmAgLSBUaGUgYm9uZCdzIHRva2VuCiogYGFtb3VudGAgLSBIb3cgbXVjaCB0byB3aXRoZHJhdywgdXAgdG8gdGhlIGZyZWUgcGFydCAoZWxzZQpgSW52YWxpZEJvbmRBbW91bnRgKQoKIyBSZXR1cm5zCiogVGhlIHBsYXllcidzIHJlbWFpbmluZyBib25kIGluIGB0b2tlbmAAAAAAAAANd2l0aGRyYXdfYm9uZAAAAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAB5HZXQgYSBwbGF5ZXIncyBib25kIGluIGEgdG9rZW4AAAAAAAhnZXRfYm9uZAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAB9AAAAAEQm9uZA==",
+        "AAAAAAAAADxHZXQgYSB3b24gcG90J3MgZGlzcHV0ZSB3aW5kb3csIHdoaWxlIGl0IGlzIG9wZW4gb3IgZGlzcHV0ZWQAAAALZ2V0X2Rpc3B1dGUAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAdEaXNwdXRlAA==",
+        "AAAAAAAAASVEaXNwdXRlIGEgc2V0dGxlbWVudCBhcyBpdHMgbG9zZXIsIGZvciB0aGUgZGlzcHV0ZSBtb2R1bGUgdG8gcnVsZSBvbgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBBIHdvbiBwb3QgaW4gaXRzIGRpc3B1dGUgd2luZG93IChlbHNlCmBEaXNwdXRlTm90Rm91bmRgKSwgdXAgdG8gaXRzIGBkZWFkbGluZV9sZWRnZXJgIGFuZCBub3QgeWV0CmRpc3B1dGVkIChlbHNlIGBEaXNwdXRlV2luZG93Q2xvc2VkYCkuIFRoZSBzZXNzaW9uIElEIHN0YXlzIGluCnVzZSB1bnRpbCB0aGUgZGlzcHV0ZSBpcyByZXNvbHZlZAAAAAAAABJkaXNwdXRlX3NldHRsZW1lbnQAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAXRSdWxlIG9uIGEgZGlzcHV0ZWQgc2V0dGxlbWVudAoKVXBob2xkaW5nIGl0IGNsYXdzIHRoZSBsb2NrZWQgcGFydCBvZiB0aGUgd2lubmVyJ3MgYm9uZCBiYWNrIGFuZApwYXlzIGl0IHRvIHRoZSBsb3NlcjsgcmVqZWN0aW5nIGl0IHJlbGVhc2VzIHRoZSBsb2NrLiBUaGUgbWF0Y2gKcmVzdWx0IGl0c2VsZiwgYXMgcmVjb3JkZWQgYW5kIHJlcG9ydGVkIHRvIHRoZSBodWIsIHN0YW5kcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gQSBkaXNwdXRlZCBzZXR0bGVtZW50IChlbHNlIGBEaXNwdXRlTm90Rm91bmRgKQoqIGB1cGhlbGRgIC0gV2hldGhlciB0aGUgd2lubmVyIGNoZWF0ZWQKCiMgUmV0dXJucwoqIFRoZSBhbW91bnQgY2xhd2VkIGJhY2sAAAAPcmVzb2x2ZV9kaXNwdXRlAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnVwaGVsZAAAAAAAAQAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAQlDbG9zZSBhIGRpc3B1dGUgd2luZG93IHRoYXQgcGFzc2VkIHVuZGlzcHV0ZWQsIHJlbGVhc2luZyB0aGUgbG9jawpvbiB0aGUgd2lubmVyJ3MgYm9uZC4gQW55b25lIG1heSBjYWxsIGl0CgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIEEgd29uIHBvdCBpbiBpdHMgZGlzcHV0ZSB3aW5kb3cgKGVsc2UKYERpc3B1dGVOb3RGb3VuZGApLCBwYXN0IGl0cyBgZGVhZGxpbmVfbGVkZ2VyYCBhbmQgbm90IGRpc3B1dGVkCihlbHNlIGBEaXNwdXRlV2luZG93T3BlbmApAAAAAAAAE2ZpbmFsaXplX3NldHRsZW1lbnQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAERHZXQgdGhlIHN0YWtpbmcgdG9rZW4sIHRpZXJzIGFuZCB3YWl0aW5nIHBlcmlvZHMgKE5vbmUgPSBubyBzdGFraW5nKQAAABJnZXRfc3Rha2luZ19jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAANU3Rha2luZ0NvbmZpZwAAAA==",
+        "AAAAAAAAAwVTZXQgaG93IHN0YWtpbmcgdGhlIHN0dWRpbyB0b2tlbiBkaXNjb3VudHMgdGhlIHByb3RvY29sIGZlZQoKQSBwbGF5ZXIgd2hvc2UgYWN0aXZlIHN0YWtlIGluIGB0b2tlbmAgcmVhY2hlcyBhIHRpZXIncyBgbWluX3N0YWtlYApoYXMgdGhlIGZlZSBvbiB0aGUgcG90cyB0aGV5IHdpbiBjdXQgYnkgdGhlIGhpZ2hlc3Qgc3VjaCB0aWVyJ3MKYGRpc2NvdW50X2Jwc2AsIGZyb20gdGhlIG5leHQgc2V0dGxlbWVudCBvbi4gU3Rha2VzIGFyZSBsb2NrZWQgZm9yCmBsb2NrdXBfbGVkZ2Vyc2AgZnJvbSB0aGVpciBsYXN0IHRvcC11cCwgYW5kIHdhaXQgYGNvb2xkb3duX2xlZGdlcnNgCmJldHdlZW4gYHVuc3Rha2VgIGFuZCBgd2l0aGRyYXdfc3Rha2VgLiBSZW1vdmluZyBzdGFraW5nLCBvciBtb3ZpbmcKaXQgdG8gYW5vdGhlciB0b2tlbiwgZW5kcyBldmVyeSBkaXNjb3VudDsgZXhpc3Rpbmcgc3Rha2VzIGNhbiBzdGlsbApiZSB1bnN0YWtlZCBhbmQgd2l0aGRyYXduLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYEZlZU1hbmFnZXJgIChvciBgQWRtaW5gKSByb2xlCiogYGNvbmZpZ2AgLSBUaGUgc3Rha2luZyBydWxlcywgd2l0aCAxIHRvIGBNQVhfU1RBS0VfVElFUlNgIHRpZXJzCnJpc2luZyBpbiBgbWluX3N0YWtlYCBmcm9tIGFib3ZlIDAgYW5kIGVhY2ggYGRpc2NvdW50X2Jwc2AgYXQgbW9zdAoxMCwwMDAgKGVsc2UgYEludmFsaWRTdGFraW5nQ29uZmlnYCksIG9yIGBOb25lYCBmb3Igbm8gc3Rha2luZwAAAAAAABJzZXRfc3Rha2luZ19jb25maWcAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAPoAAAH0AAAAA1TdGFraW5nQ29uZmlnAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAYlTdGFrZSB0aGUgc3Rha2luZyB0b2tlbiB0b3dhcmQgYSBmZWUgZGlzY291bnQgdGllcgoKVGhlIHdob2xlIGFjdGl2ZSBzdGFrZSwgb2xkIGFuZCBuZXcsIGlzIHRoZW4gbG9ja2VkIGZvciB0aGUKY29uZmlndXJlZCBgbG9ja3VwX2xlZGdlcnNgLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFdobyBzdGFrZXMsIGFuZCBzaWducyB0aGUgdHJhbnNmZXIKKiBgYW1vdW50YCAtIEhvdyBtdWNoIHRvIGFkZCAoZWxzZSBgSW52YWxpZFN0YWtlQW1vdW50YCwgYXMgd2hlbiB0aGUKcGxheWVyIHN0aWxsIGhhcyBhIHN0YWtlIGluIGEgdG9rZW4gbm8gbG9uZ2VyIGNvbmZpZ3VyZWQpCgpSZXR1cm5zIGBTdGFraW5nTm90Q29uZmlndXJlZGAgd2l0aG91dCBhIHN0YWtpbmcgY29uZmlndXJhdGlvbi4AAAAAAAAFc3Rha2UAAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAH0AAAAAVTdGFrZQAAAAAAAAM=",
+        "AAAAAAAAAalTdGFydCB3aXRoZHJhd2luZyBwYXJ0IG9mIGFuIGFjdGl2ZSBzdGFrZSBvbmNlIGl0cyBsb2NrdXAgaXMgb3ZlcgoKYGFtb3VudGAgc3RvcHMgY291bnRpbmcgdG93YXJkIHRoZSB0aWVyIGF0IG9uY2UsIGFuZCBqb2lucyBhbnl0aGluZwphbHJlYWR5IHVuc3Rha2luZyBpbiBhIGNvb2xkb3duIG9mIHRoZSBjb25maWd1cmVkIGBjb29sZG93bl9sZWRnZXJzYAoobm9uZSB3aXRob3V0IHN0YWtpbmcpLCByZXN0YXJ0ZWQgZnJvbSBub3cuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXJgIC0gV2hvc2Ugc3Rha2UsIGFuZCBzaWducwoqIGBhbW91bnRgIC0gVXAgdG8gdGhlIGFjdGl2ZSBzdGFrZSAoZWxzZSBgSW52YWxpZFN0YWtlQW1vdW50YCksCnVuc3Rha2VkIGZyb20gaXRzIGBsb2NrZWRfdW50aWxfbGVkZ2VyYCBvbiAoZWxzZSBgU3Rha2VMb2NrZWRgKQAAAAAAAAd1bnN0YWtlAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAfQAAAABVN0YWtlAAAAAAAAAw==",
+        "AAAAAAAAAShXaXRoZHJhdyBldmVyeXRoaW5nIHVuc3Rha2VkIG9uY2UgaXRzIGNvb2xkb3duIGlzIG92ZXIuIEFsbG93ZWQKd2hpbGUgcGF1c2VkLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFdob3NlIHN0YWtlLCBhbmQgc2lnbnM7IHdpdGggc29tZXRoaW5nIHVuc3Rha2luZyAoZWxzZQpgSW52YWxpZFN0YWtlQW1vdW50YCkgZnJvbSBpdHMgYGNvb2xkb3duX2VuZF9sZWRnZXJgIG9uIChlbHNlCmBTdGFrZUNvb2xpbmdEb3duYCkKCiMgUmV0dXJucwoqIFRoZSBhbW91bnQgd2l0aGRyYXduLCBpbiB0aGUgc3Rha2UncyB0b2tlbgAAAA53aXRoZHJhd19zdGFrZQAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAACZHZXQgYSBwbGF5ZXIncyBzdGFrZSwgaWYgdGhleSBoYXZlIG9uZQAAAAAACWdldF9zdGFrZQAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPoAAAH0AAAAAVTdGFrZQAAAA==",
+        "AAAAAAAAAENHZXQgdGhlIGZlZSBkaXNjb3VudCB0aWVyIGEgcGxheWVyJ3MgYWN0aXZlIHN0YWtlIGVhcm5zIG5vdywgaWYgYW55AAAAAA5nZXRfc3Rha2VfdGllcgAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAfQAAAACVN0YWtlVGllcgAAAA==",
+        "AAAAAAAAAfJBZGQgbGlxdWlkaXR5IHRvIGEgdG9rZW4ncyBob3VzZSBiYW5rcm9sbAoKYGFtb3VudGAgb2YgYHRva2VuYCBtb3ZlcyBmcm9tIGBwcm92aWRlcmAgaW50byB0aGUgYmFua3JvbGwsIHdoaWNoCmJhY2tzIHRoZSBob3VzZSdzIHN0YWtlcyBpbiBgc3RhcnRfaG91c2VfZ2FtZWAuIFRoZSBwcm92aWRlciBnZXRzCnNoYXJlcyBpbiBwcm9wb3J0aW9uIHRvIHRoZSBiYW5rcm9sbCdzIGN1cnJlbnQgdmFsdWUsIHNvIHRoZXkgc2hhcmUKaXRzIGxhdGVyIHdpbnMgYW5kIGxvc3NlcyBwcm8gcmF0YSAoc2VlIGB3aXRoZHJhd19iYW5rcm9sbGApLgoKIyBBcmd1bWVudHMKKiBgcHJvdmlkZXJgIC0gV2hvIGRlcG9zaXRzLCBhbmQgc2lnbnMgdGhlIHRyYW5zZmVyCiogYHRva2VuYCAtIFRoZSBiYW5rcm9sbCdzIHRva2VuCiogYGFtb3VudGAgLSBIb3cgbXVjaCB0byBkZXBvc2l0IChlbHNlIGBJbnZhbGlkQmFua3JvbGxBbW91bnRgKQoKIyBSZXR1cm5zCiogVGhlIHNoYXJlcyBpc3N1ZWQAAAAAABBkZXBvc2l0X2Jhbmtyb2xsAAAAAwAAAAAAAAAIcHJvdmlkZXIAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAdlSZWRlZW0gYmFua3JvbGwgc2hhcmVzIGZvciB0aGVpciBzaGFyZSBvZiB0aGUgYmFua3JvbGwKClN0YWtlcyBsb2NrZWQgb24gaG91c2UgZ2FtZXMgaW4gcGxheSBjYW5ub3QgYmUgd2l0aGRyYXduIHVudGlsIHRoZQpnYW1lcyBzZXR0bGU6IGEgd2l0aGRyYXdhbCBsYXJnZXIgdGhhbiB0aGUgdW5sb2NrZWQgcGFydCByZXR1cm5zCmBJbnN1ZmZpY2llbnRCYW5rcm9sbGAuIEFsbG93ZWQgd2hpbGUgcGF1c2VkLgoKIyBBcmd1bWVudHMKKiBgcHJvdmlkZXJgIC0gV2hvIGhvbGRzIHRoZSBzaGFyZXMsIGFuZCBzaWducwoqIGB0b2tlbmAgLSBUaGUgYmFua3JvbGwncyB0b2tlbgoqIGBzaGFyZXNgIC0gSG93IG1hbnkgdG8gcmVkZWVtLCB1cCB0byBhbGwgdGhlIHByb3ZpZGVyIGhvbGRzIChlbHNlCmBJbnZhbGlkQmFua3JvbGxBbW91bnRgKQoKIyBSZXR1cm5zCiogVGhlIGFtb3VudCBvZiBgdG9rZW5gIHNlbnQgdG8gYHByb3ZpZGVyYAAAAAAAABF3aXRoZHJhd19iYW5rcm9sbAAAAAAAAAMAAAAAAAAACHByb3ZpZGVyAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZzaGFyZXMAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAUVTZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGhvdXNlIGdhbWVzIGRyYXcgdGhlIGhvdXNlJ3MgdGFjdGljcyBmcm9tCgpFYWNoIGhvdXNlIGdhbWUgYmluZHMgdG8gdGhlIGJlYWNvbidzIHBlbmRpbmcgcm91bmQgd2hlbiB0aGUgcGxheWVyCmNvbW1pdHMgKHNlZSBgc3RhcnRfaG91c2VfZ2FtZWApLCBhbmQga2VlcHMgdGhhdCBiZWFjb24gaWYgaXQgaXMKY2hhbmdlZCBsYXRlci4gQ291bmNpbC1hcHByb3ZlZCBvbmNlIGEgY291bmNpbCBpcyBzZXQuCgojIEFyZ3VtZW50cwoqIGBiZWFjb25gIC0gQSBgY29udHJhY3RzL3JhbmRvbW5lc3MtYmVhY29uYCBkZXBsb3ltZW50AAAAAAAAEHNldF9ob3VzZV9iZWFjb24AAAABAAAAAAAAAAZiZWFjb24AAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADBHZXQgdGhlIGhvdXNlJ3MgcmFuZG9tbmVzcyBiZWFjb24sIGlmIG9uZSBpcyBzZXQAAAAQZ2V0X2hvdXNlX2JlYWNvbgAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAFtHZXQgdGhlIGJlYWNvbiBhbmQgcm91bmQgYSBob3VzZSBnYW1lJ3MgdGFjdGljIGlzIGRyYXduIGZyb20sIG9uY2UKdGhlIHBsYXllciBoYXMgY29tbWl0dGVkAAAAAA9nZXRfaG91c2Vfcm91bmQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAD7QAAAAIAAAATAAAABg==",
+        "AAAAAAAAADxHZXQgYSB0b2tlbidzIGhvdXNlIGJhbmtyb2xsIChlbXB0eSBpZiBub2JvZHkgaGFzIGRlcG9zaXRlZCkAAAAMZ2V0X2Jhbmtyb2xsAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAB9AAAAAIQmFua3JvbGw=",
+        "AAAAAAAAADNHZXQgYHByb3ZpZGVyYCdzIHNoYXJlcyBvZiBhIHRva2VuJ3MgaG91c2UgYmFua3JvbGwAAAAAE2dldF9iYW5rcm9sbF9zaGFyZXMAAAAAAgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAhwcm92aWRlcgAAABMAAAABAAAACw==",
+        "AAAAAAAAAvBTdGFrZSBvbiBhIHBsYXllciBvZiBhbiBvcGVuIGdhbWUKCkFueW9uZSBidXQgdGhlIGdhbWUncyBwbGF5ZXJzIG1heSBiYWNrIGVpdGhlciBvZiB0aGVtLCB1bnRpbCBib3RoCnRhY3RpY3MgYXJlIHN1Ym1pdHRlZCAoZWxzZSBgU2lkZUJldHNDbG9zZWRgKS4gQmV0cyBhcmUgaW4gdGhlCmdhbWUncyBgd2FnZXJfdG9rZW5gOyBhIHBvaW50cy1vbmx5IG9yIG1peGVkLWFzc2V0IGdhbWUgdGFrZXMgbm9uZQooYFNpZGVCZXRzVW5hdmFpbGFibGVgKS4gUmVwZWF0IGJldHMgYWRkIHRvIHRoZSBiZXR0b3IncyBzdGFrZSwgYnV0Cm11c3QgYmFjayB0aGUgc2FtZSBwbGF5ZXIuIFRoZSBzdGFrZXMgZm9ybSBhIHBhcmktbXV0dWVsIHBvb2w6IHdoZW4KYHJlc29sdmVfbWF0Y2hgIHNldHRsZXMgYSB3aW4sIHRoZSB3aW5uZXIncyBiYWNrZXJzIHNoYXJlIHRoZSBsb3NpbmcKc2lkZSdzIHN0YWtlcywgbGVzcyBhIHJha2UgKGBnZXRfc2lkZV9iZXRfcmFrZV9icHNgKSBwYWlkIHRvIHRoZQp3aW5uZXIuIEFueSBvdGhlciBlbmRpbmcgcmVmdW5kcyBldmVyeSBiZXQuIFNlZSBgY2xhaW1fc2lkZV9iZXRgLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgZ2FtZSB0byBiZXQgb24KKiBgYmV0dG9yYCAtIFdobyBzdGFrZXMsIGFuZCBzaWducyB0aGUgdHJhbnNmZXIKKiBgcGxheWVyYCAtIFRoZSBwbGF5ZXIgYmFja2VkIHRvIHdpbgoqIGBhbW91bnRgIC0gSG93IG11Y2ggb2YgdGhlIGdhbWUncyB0b2tlbiB0byBzdGFrZQAAAA5wbGFjZV9zaWRlX2JldAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGYmV0dG9yAAAAAAATAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAc5QYXkgb3V0IGEgc2lkZSBiZXQgb25jZSBpdHMgZ2FtZSBoYXMgc2V0dGxlZAoKU2VuZHMgYGJldHRvcmAgdGhlaXIgc2hhcmUgb2YgdGhlIHBvb2wgaWYgdGhleSBiYWNrZWQgdGhlIHdpbm5lciwgb3IKdGhlaXIgc3Rha2UgYmFjayBpZiB0aGUgcG9vbCB3YXMgcmVmdW5kZWQsIHNldHRsaW5nIHRoZSBwb29sIGZpcnN0CmlmIGl0cyBnYW1lIGV4cGlyZWQgdW5yZXNvbHZlZC4gQ2FsbGVkIHdpdGggdGhlIG1hdGNoIHdpbm5lciwgaXQKc2VuZHMgdGhlbSBhIHJha2UgdGhlIHRva2VuIHJlZnVzZWQgYXQgc2V0dGxlbWVudC4gUmV0dXJucwpgR2FtZU5vdFNldHRsZWRgIHdoaWxlIHRoZSBnYW1lIGlzIGluIHBsYXksIGFuZCBgTm90aGluZ1RvQ2xhaW1gCmZvciBhIGxvc2luZywgYWxyZWFkeSBwYWlkIG9yIHVua25vd24gYmV0LiBBbnlvbmUgbWF5IGNhbGwgaXQuCgojIFJldHVybnMKKiBUaGUgYW1vdW50IHBhaWQAAAAAAA5jbGFpbV9zaWRlX2JldAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGYmV0dG9yAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAADhHZXQgdGhlIHNpZGUgYmV0cyBwbGFjZWQgb24gYSBnYW1lLCB3aGlsZSBhbnkgYXJlIHVucGFpZAAAABFnZXRfc2lkZV9iZXRfcG9vbAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAALU2lkZUJldFBvb2wA",
+        "AAAAAAAAADRHZXQgYGJldHRvcmAncyBzdGFrZSBvbiBhIGdhbWUsIHVudGlsIGl0IGlzIHBhaWQgb3V0AAAADGdldF9zaWRlX2JldAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABmJldHRvcgAAAAAAEwAAAAEAAAPoAAAH0AAAAAdTaWRlQmV0AA==",
+        "AAAAAAAAAG9HZXQgdGhlIHJha2Ugb24gbmV3IHNpZGUtYmV0IHBvb2xzLCBpbiBiYXNpcyBwb2ludHMgb2YgdGhlIGxvc2luZwpzaWRlIChgREVGQVVMVF9TSURFX0JFVF9SQUtFX0JQU2AgYnkgZGVmYXVsdCkAAAAAFWdldF9zaWRlX2JldF9yYWtlX2JwcwAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAMBTZXQgdGhlIHJha2Ugb24gc2lkZS1iZXQgcG9vbHMgb3BlbmVkIGZyb20gbm93IG9uCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgRmVlTWFuYWdlcmAgKG9yIGBBZG1pbmApIHJvbGUKKiBgcmFrZV9icHNgIC0gQXQgbW9zdCBgTUFYX0ZFRV9CUFNgIChlbHNlIGBJbnZhbGlkRmVlYCkAAAAVc2V0X3NpZGVfYmV0X3Jha2VfYnBzAAAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAhyYWtlX2JwcwAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAZVOYW1lIHRoZSBhZGRyZXNzIHRoYXQgcmVmZXJyZWQgYHBsYXllcmAKCkZyb20gdGhlbiBvbiBgcmVmZXJyZXJgIGlzIGNyZWRpdGVkIGBnZXRfcmVmZXJyYWxfc2hhcmVfYnBzYCBvZiB0aGUKcHJvdG9jb2wgZmVlIHRha2VuIGZyb20gYHBsYXllcmAncyBzdGFrZSBpbiBlYWNoIHdvbiBwb3QsIGNsYWltYWJsZQp3aXRoIGBjbGFpbV9yZWZlcnJhbF9yZXdhcmRzYC4gQSBwbGF5ZXIgbmFtZXMgYSByZWZlcnJlciBvbmNlIChlbHNlCmBSZWZlcnJlckFscmVhZHlTZXRgKSwgYW5kIGNhbm5vdCBuYW1lIHRoZW1zZWx2ZXMgKGBJbnZhbGlkUmVmZXJyZXJgKS4KCiMgQXJndW1lbnRzCiogYHBsYXllcmAgLSBUaGUgcmVmZXJyZWQgcGxheWVyLCB3aG8gc2lnbnMKKiBgcmVmZXJyZXJgIC0gV2hvIHJlZmVycmVkIHRoZW0AAAAAAAARcmVnaXN0ZXJfcmVmZXJyZXIAAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHJlZmVycmVyAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJBTZW5kIGEgcmVmZXJyZXIgdGhlaXIgdW5jbGFpbWVkIHJld2FyZHMgaW4gYHRva2VuYAoKUmV0dXJucyBgTm90aGluZ1RvQ2xhaW1gIGlmIHRoZXJlIGFyZSBub25lLiBBbnlvbmUgbWF5IGNhbGwgaXQuCgojIFJldHVybnMKKiBUaGUgYW1vdW50IHNlbnQAAAAWY2xhaW1fcmVmZXJyYWxfcmV3YXJkcwAAAAAAAgAAAAAAAAAIcmVmZXJyZXIAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAACRHZXQgd2hvIHJlZmVycmVkIGBwbGF5ZXJgLCBpZiBhbnlvbmUAAAAMZ2V0X3JlZmVycmVyAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAG1HZXQgaG93IG1hbnkgcGxheWVycyBhIHJlZmVycmVyIGhhcyBicm91Z2h0IGluLCBhbmQgaG93IG1hbnkgb2YKdGhlaXIgc3Rha2VzIGhhdmUgZWFybmVkIHRoZSByZWZlcnJlciBhIHNoYXJlAAAAAAAAEmdldF9yZWZlcnJhbF9zdGF0cwAAAAAAAQAAAAAAAAAIcmVmZXJyZXIAAAATAAAAAQAAB9AAAAANUmVmZXJyYWxTdGF0cwAAAA==",
+        "AAAAAAAAAD9HZXQgYSByZWZlcnJlcidzIHJld2FyZHMgaW4gYHRva2VuYCwgZWFybmVkIGFuZCBzdGlsbCBjbGFpbWFibGUAAAAAFGdldF9yZWZlcnJhbF9yZXdhcmRzAAAAAgAAAAAAAAAIcmVmZXJyZXIAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAH0AAAAA9SZWZlcnJhbFJld2FyZHMA",
+        "AAAAAAAAAHhHZXQgdGhlIHJlZmVycmVycycgc2hhcmUgb2YgdGhlIGZlZSBvbiB0aGVpciByZWZlcmVlcycgc3Rha2VzLCBpbgpiYXNpcyBwb2ludHMgKGBERUZBVUxUX1JFRkVSUkFMX1NIQVJFX0JQU2AgYnkgZGVmYXVsdCkAAAAWZ2V0X3JlZmVycmFsX3NoYXJlX2JwcwAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAMZTZXQgdGhlIHJlZmVycmVycycgc2hhcmUgb2YgdGhlIGZlZSBvbiB0aGVpciByZWZlcmVlcycgc3Rha2VzCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgRmVlTWFuYWdlcmAgKG9yIGBBZG1pbmApIHJvbGUKKiBgc2hhcmVfYnBzYCAtIEF0IG1vc3QgMTAsMDAwIChlbHNlIGBJbnZhbGlkRmVlYCkAAAAAABZzZXRfcmVmZXJyYWxfc2hhcmVfYnBzAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEpHZXQgdGhlIGh1YidzIHNoYXJlIG9mIHRoZSBmZWUgb24gd29uIHBvdHMsIGluIGJhc2lzIHBvaW50cyAoMCBieQpkZWZhdWx0KQAAAAAAEWdldF9odWJfc2hhcmVfYnBzAAAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAchTZXQgdGhlIGh1YidzIHNoYXJlIG9mIHRoZSBmZWUgb24gd29uIHBvdHMKCkFzIGEgd29uIHBvdCBzZXR0bGVzLCBgc2hhcmVfYnBzYCBvZiBlYWNoIHN0YWtlJ3MgZmVlLCBhZnRlciBhbnkKcmVmZXJyYWwgc2hhcmUsIGlzIHNlbnQgdG8gdGhlIHRyZWFzdXJ5IHRoZSBodWIgbmFtZXMgd2l0aCBpdHMKYHRyZWFzdXJ5KClgLCBhbmQgdGhlIGZlZSBjb2xsZWN0b3Iga2VlcHMgdGhlIHJlc3QuIEEgaHViIHRoYXQgbmFtZXMKbm9uZSwgb3IgYSB0cmVhc3VyeSB0aGUgdG9rZW4gcmVmdXNlcywgbGVhdmVzIHRoZSBmZWUgY29sbGVjdG9yIHRoZQp3aG9sZSBmZWUuCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgRmVlTWFuYWdlcmAgKG9yIGBBZG1pbmApIHJvbGUKKiBgc2hhcmVfYnBzYCAtIEF0IG1vc3QgMTAsMDAwIChlbHNlIGBJbnZhbGlkRmVlYCkAAAARc2V0X2h1Yl9zaGFyZV9icHMAAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADdHZXQgYSBwbGF5ZXIncyBjdXJyZW50IGFuZCBiZXN0IHJ1biBvZiBjb25zZWN1dGl2ZSB3aW5zAAAAAApnZXRfc3RyZWFrAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAZTdHJlYWsAAA==",
+        "AAAAAAAAADxHZXQgdGhlIGJvbnVzIHBhaWQgZm9yIHdpbm5pbmcgb24gYSBzdHJlYWsgKE5vbmUgPSBubyBib251cykAAAAQZ2V0X3N0cmVha19ib251cwAAAAAAAAABAAAD6AAAB9AAAAALU3RyZWFrQm9udXMA",
+        "AAAAAAAAAilTZXQgdGhlIGJvbnVzIHBhaWQgZm9yIHdpbm5pbmcgb24gYSBzdHJlYWsKCkEgcGxheWVyIHdobyB3aW5zIGEgcG90IHdpdGggYSBzdHJlYWsgKGBnZXRfc3RyZWFrYCkgb2YgYXQgbGVhc3QKYG1pbl9zdHJlYWtgIGlzIG93ZWQgYHN0ZXBfYnBzYCBvZiB3aGF0IHRoZXkgd29uIGZvciBlYWNoIHdpbiBmcm9tCmBtaW5fc3RyZWFrYCBvbiwgdXAgdG8gYG1heF9icHNgLCBhbG9uZ3NpZGUgdGhlaXIgd2lubmluZ3MuIFRoZQpib251cyBjb21lcyBvdXQgb2YgdGhlIHByb3RvY29sIGZlZXMgYWNjcnVlZCBpbiB0aGF0IHRva2VuLCBhbmQgaXMKY3V0IHRvIHdoYXQgaGFzIGFjY3J1ZWQuCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgRmVlTWFuYWdlcmAgKG9yIGBBZG1pbmApIHJvbGUKKiBgYm9udXNgIC0gVGhlIHNjaGVkdWxlLCB3aXRoIGBtaW5fc3RyZWFrYCBhdCBsZWFzdCAyIGFuZCBgbWF4X2Jwc2AKYXQgbW9zdCAxMCwwMDAgKGVsc2UgYEludmFsaWRTdHJlYWtCb251c2ApLCBvciBgTm9uZWAgZm9yIG5vIGJvbnVzAAAAAAAAEHNldF9zdHJlYWtfYm9udXMAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABWJvbnVzAAAAAAAD6AAAB9AAAAALU3RyZWFrQm9udXMAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACZHZXQgdGhlIGVwb2NoIHJ1bGVzIChOb25lID0gbm8gZXBvY2hzKQAAAAAAEGdldF9lcG9jaF9jb25maWcAAAAAAAAAAQAAA+gAAAfQAAAAC0Vwb2NoQ29uZmlnAA==",
+        "AAAAAAAAAtNTZXQgaG93IHJld2FyZCBlcG9jaHMgcnVuIGFuZCBmaWxsIHRoZWlyIHBvb2xzCgpFcG9jaHMgbGFzdCBgbGVuZ3RoX2xlZGdlcnNgLCBhbmQgYHNoYXJlX2Jwc2Agb2YgZXZlcnkgcHJvdG9jb2wgZmVlCnRha2VuIGluIGB0b2tlbmAsIGFmdGVyIGFueSByZWZlcnJhbCBhbmQgaHViIHNoYXJlLCBnb2VzIHRvIHRoZQpydW5uaW5nIGVwb2NoJ3MgcG9vbC4gV2lucyBpbiBnYW1lcyB3YWdlcmVkIGluIGB0b2tlbmAgcmFuayBwbGF5ZXJzCmluIHRoZSBlcG9jaCB0aGV5IHNldHRsZSBpbjsgb25jZSBpdCBpcyBvdmVyLCBpdHMgdG9wIGB0b3BfbmAKc2hhcmUgdGhlIHBvb2wgcHJvIHJhdGEgdG8gdGhlaXIgd2lucyAoYGNsYWltX2Vwb2NoX3Jld2FyZGApLgpDaGFuZ2luZyB0aGUgcnVsZXMsIG9yIHJlbW92aW5nIHRoZW0sIGVuZHMgdGhlIHJ1bm5pbmcgZXBvY2g6IHRoZQpuZXh0IG9uZSBzdGFydHMgbm93LgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYEZlZU1hbmFnZXJgIChvciBgQWRtaW5gKSByb2xlCiogYGNvbmZpZ2AgLSBUaGUgcnVsZXMsIHdpdGggYSBub256ZXJvIGBsZW5ndGhfbGVkZ2Vyc2AsIGBzaGFyZV9icHNgCmF0IG1vc3QgMTAsMDAwIGFuZCBgdG9wX25gIGZyb20gMSB0byBgTUFYX0VQT0NIX0xFQURFUlNgIChlbHNlCmBJbnZhbGlkRXBvY2hDb25maWdgKSwgb3IgYE5vbmVgIGZvciBubyBlcG9jaHMAAAAAEHNldF9lcG9jaF9jb25maWcAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABmNvbmZpZwAAAAAD6AAAB9AAAAALRXBvY2hDb25maWcAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAC9HZXQgdGhlIHJ1bm5pbmcgZXBvY2gsIGlmIGVwb2NocyBhcmUgY29uZmlndXJlZAAAAAARZ2V0X2N1cnJlbnRfZXBvY2gAAAAAAAAAAAAAAQAAA+gAAAAE",
+        "AAAAAAAAAEJHZXQgYW4gZXBvY2gncyBwb29sIGFuZCBsZWFkZXJib2FyZCwgaWYgYW55dGhpbmcgaGFzIGFjY3J1ZWQgdG8gaXQAAAAAAAlnZXRfZXBvY2gAAAAAAAABAAAAAAAAAAVlcG9jaAAAAAAAAAQAAAABAAAD6AAAB9AAAAAMRXBvY2hSZXdhcmRz",
+        "AAAAAAAAAB9HZXQgYSBwbGF5ZXIncyB3aW5zIGluIGFuIGVwb2NoAAAAAA5nZXRfZXBvY2hfd2lucwAAAAAAAgAAAAAAAAAFZXBvY2gAAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAfNDbGFpbSBhIGxlYWRlcidzIHNoYXJlIG9mIGFuIGVwb2NoJ3MgcG9vbAoKT25jZSB0aGUgZXBvY2ggaXMgb3ZlciwgZWFjaCBvZiBpdHMgbGVhZGVycyAoYGdldF9lcG9jaGApIG1heSBjbGFpbQpgcG9vbCAqIHdpbnMgLyB0b3RhbCB3aW5zIG9mIHRoZSBsZWFkZXJzYCBvbmNlOyB0aGUgbGFzdCBjbGFpbSB0YWtlcwp0aGUgcm91bmRpbmcgZHVzdC4gRXBvY2hzIHBhaWQgYnkgYSByZXdhcmQgdHJlZSAoYHNldF9lcG9jaF9yb290YCkKYXJlIGNsYWltZWQgd2l0aCBgY2xhaW1fZXBvY2hfcmV3YXJkX3dpdGhfcHJvb2ZgIGluc3RlYWQuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXJgIC0gVGhlIGxlYWRlciwgd2hvIHNpZ25zCiogYGVwb2NoYCAtIEFuIGVwb2NoIHRoYXQgaXMgb3ZlciAoZWxzZSBgRXBvY2hOb3RPdmVyYCkKCiMgUmV0dXJucwoqIFRoZSBhbW91bnQgc2VudCwgb3IgYE5vdGhpbmdUb0NsYWltYCBmb3IgYSBwbGF5ZXIgd2l0aCBubyBzaGFyZQpsZWZ0IHRvIGNsYWltAAAAABJjbGFpbV9lcG9jaF9yZXdhcmQAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFZXBvY2gAAAAAAAAEAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAflDbGFpbSBhbiBlcG9jaCByZXdhcmQgZnJvbSBpdHMgcmV3YXJkIHRyZWUKCiMgQXJndW1lbnRzCiogYHBsYXllcmAgLSBXaG8gdGhlIHJld2FyZCBpcyBmb3IsIGFuZCBzaWducwoqIGBlcG9jaGAgLSBBbiBlcG9jaCB3aXRoIGEgcmV3YXJkIHRyZWUgKGBzZXRfZXBvY2hfcm9vdGApCiogYGluZGV4YCAtIFRoZSBsZWFmJ3MgcG9zaXRpb24gaW4gdGhlIHRyZWUKKiBgYW1vdW50YCAtIFRoZSByZXdhcmQgdGhlIGxlYWYgY29tbWl0cyB0byAoYGVwb2NoX2xlYWZgKQoqIGBwcm9vZmAgLSBUaGUgbGVhZidzIGluY2x1c2lvbiBwcm9vZiwgYXMgYHN0cmF0ZWd5X3Byb29mYCBidWlsZHMKaXQgKGEgcHJvb2YgdGhhdCBkb2VzIG5vdCBjaGVjayBvdXQsIG9yIGEgcmV3YXJkIGJleW9uZCB3aGF0IGlzCmxlZnQgb2YgdGhlIHBvb2wsIGlzIGBJbnZhbGlkRXBvY2hQcm9vZmApCgojIFJldHVybnMKKiBUaGUgYW1vdW50IHNlbnQsIG9yIGBOb3RoaW5nVG9DbGFpbWAgaWYgYWxyZWFkeSBjbGFpbWVkAAAAAAAAHWNsYWltX2Vwb2NoX3Jld2FyZF93aXRoX3Byb29mAAAAAAAABQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVlcG9jaAAAAAAAAAQAAAAAAAAABWluZGV4AAAAAAAABAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVwcm9vZgAAAAAAA+oAAAPuAAAAIAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAZNQdWJsaXNoIGEgcmV3YXJkIHRyZWUgZm9yIGFuIGVwb2NoLCB0byBwYXkgaXQgYnkgcHJvb2YgcmF0aGVyIHRoYW4KYnkgaXRzIG9uLWNoYWluIGxlYWRlcmJvYXJkCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGEgaG9sZGVyIG9mIHRoZSBgRmVlTWFuYWdlcmAgKG9yIGBBZG1pbmApIHJvbGUKKiBgZXBvY2hgIC0gQW4gZXBvY2ggdGhhdCBpcyBvdmVyIChlbHNlIGBFcG9jaE5vdE92ZXJgKSwgd2l0aCBhIHBvb2wKYW5kIG5vIGNsYWltcyBwYWlkIHlldCAoZWxzZSBgSW52YWxpZEVwb2NoUm9vdGApCiogYHJvb3RgIC0gUm9vdCBvZiB0aGUgdHJlZSBvZiBgZXBvY2hfbGVhZmAgbGVhdmVzCiogYGxlYXZlc2AgLSBIb3cgbWFueSBsZWF2ZXMgdGhlIHRyZWUgaGFzLCBhdCBsZWFzdCAxAAAAAA5zZXRfZXBvY2hfcm9vdAAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAVlcG9jaAAAAAAAAAQAAAAAAAAABHJvb3QAAAPuAAAAIAAAAAAAAAAGbGVhdmVzAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAElHZXQgdGhlIGphY2twb3QgaW4gYSB0b2tlbiwgcGFpZCBvdXQgb24gYSBydW4gc2V0IGJ5CmBzZXRfamFja3BvdF9jb25maWdgAAAAAAAAC2dldF9qYWNrcG90AAAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAC1HZXQgYSBwbGF5ZXIncyBjdXJyZW50IHJ1biB0b3dhcmQgdGhlIGphY2twb3QAAAAAAAAPZ2V0X2phY2twb3RfcnVuAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAClHZXQgdGhlIGphY2twb3QgcnVsZXMgKE5vbmUgPSBubyBqYWNrcG90KQAAAAAAABJnZXRfamFja3BvdF9jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAANSmFja3BvdENvbmZpZwAAAA==",
+        "AAAAAAAAA1lTZXQgaG93IHRoZSBqYWNrcG90IGZpbGxzIGFuZCB3aGF0IHdpbnMgaXQKCmBzaGFyZV9icHNgIG9mIGV2ZXJ5IHByb3RvY29sIGZlZSwgYWZ0ZXIgYW55IHJlZmVycmFsIGFuZCBodWIgc2hhcmUsCmdvZXMgdG8gdGhlIGphY2twb3QgaW4gdGhlIGZlZSdzIHRva2VuIChgZ2V0X2phY2twb3RgKS4gQSBwbGF5ZXIgd2hvIHdpbnMKYHdpbnNfaW5fYV9yb3dgIGdhbWVzIHdhZ2VyZWQgaW4gYSB0b2tlbiBpbiBhIHJvdyBvbiBhIGBSYW5kb21gCnRpZWJyZWFrLCB3aXRoIGJvdGggc2lkZXMgcGxheWluZyBgdGFjdGljYCBhbmQgc3Rha2luZyBhdCBsZWFzdApgbWluX3N0YWtlYCwgaXMgb3dlZCBgcGF5b3V0X2Jwc2Agb2YgdGhlIGphY2twb3QgaW4gdGhlIHRva2VuIG9mIHRoZQpwb3QgdGhhdCBjb21wbGV0ZXMgdGhlIHJ1biwgYWxvbmdzaWRlIHRoZWlyIHdpbm5pbmdzLiBBbnkgb3RoZXIKcmVzdWx0IGVuZHMgYSBydW4gKGBnZXRfamFja3BvdF9ydW5gKS4KUmVtb3ZpbmcgdGhlIHJ1bGVzIGxlYXZlcyB0aGUgamFja3BvdCBpbiBwbGFjZSBmb3Igd2hlbiB0aGV5IHJldHVybi4KCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYSBob2xkZXIgb2YgdGhlIGBGZWVNYW5hZ2VyYCAob3IgYEFkbWluYCkgcm9sZQoqIGBjb25maWdgIC0gVGhlIHJ1bGVzLCB3aXRoIGBzaGFyZV9icHNgIGF0IG1vc3QgMTAsMDAwLCBhIHZhbGlkCmB0YWN0aWNgLCBgd2luc19pbl9hX3Jvd2AgYXQgbGVhc3QgMSwgYHBheW91dF9icHNgIGZyb20gMSB0bwoxMCwwMDAgYW5kIGBtaW5fc3Rha2VgIGF0IGxlYXN0IDEgKGVsc2UgYEludmFsaWRKYWNrcG90Q29uZmlnYCksIG9yCmBOb25lYCBmb3Igbm8gamFja3BvdAAAAAAAABJzZXRfamFja3BvdF9jb25maWcAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAPoAAAH0AAAAA1KYWNrcG90Q29uZmlnAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEFHZXQgdGhlIHByb3RvY29sIGZlZSwgaW4gYmFzaXMgcG9pbnRzIG9mIGEgd29uIHBvdCAoMCBieSBkZWZhdWx0KQAAAAAAAAtnZXRfZmVlX2JwcwAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAe9TZXQgdGhlIHByb3RvY29sIGZlZSB0YWtlbiBmcm9tIHRoZSBwb3Qgb2YgZ2FtZXMgc3RhcnRlZCBmcm9tIG5vdyBvbgoKV2hlbiBhIGdhbWUgd2FnZXJlZCBpbiBhIHRva2VuIGlzIHdvbiwgYGZlZV9icHNgIG9mIHRoZSBwb3QgKGJvdGgKcGxheWVycycgc3Rha2VzKSBpcyBrZXB0IGJhY2sgZnJvbSB0aGUgd2lubmVyIGFuZCBhY2NydWVzIHRvIHRoZQpjb250cmFjdCAoc2VlIGBnZXRfYWNjcnVlZF9mZWVzYCkuIERyYXdzLCBjYW5jZWxsYXRpb25zLCB0aW1lb3V0cyBhbmQKZXhwaXJlZCBnYW1lcyBhcmUgcmVmdW5kZWQgd2l0aG91dCBhIGZlZS4gRWFjaCBnYW1lIGtlZXBzIHRoZSBmZWUgaXQKc3RhcnRlZCB3aXRoLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYEZlZU1hbmFnZXJgIChvciBgQWRtaW5gKSByb2xlCiogYGZlZV9icHNgIC0gQXQgbW9zdCBgTUFYX0ZFRV9CUFNgIChlbHNlIGBJbnZhbGlkRmVlYCkAAAAAC3NldF9mZWVfYnBzAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADtHZXQgdGhlIGFkZHJlc3MgYHdpdGhkcmF3X2ZlZXNgIHBheXMgKHRoZSBhZG1pbiB1bmxlc3Mgc2V0KQAAAAARZ2V0X2ZlZV9jb2xsZWN0b3IAAAAAAAAAAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAKBTZXQgdGhlIGFkZHJlc3MgYHdpdGhkcmF3X2ZlZXNgIHBheXMKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYSBob2xkZXIgb2YgdGhlIGBGZWVNYW5hZ2VyYCAob3IgYEFkbWluYCkgcm9sZQoqIGBjb2xsZWN0b3JgIC0gVGhlIG5ldyBmZWUgY29sbGVjdG9yAAAAEXNldF9mZWVfY29sbGVjdG9yAAAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAljb2xsZWN0b3IAAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADVHZXQgdGhlIGZlZXMgYWNjcnVlZCBpbiBgdG9rZW5gIGFuZCBub3QgeWV0IHdpdGhkcmF3bgAAAAAAABBnZXRfYWNjcnVlZF9mZWVzAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAP9TZW5kIGV2ZXJ5IGZlZSBhY2NydWVkIGluIGB0b2tlbmAgdG8gdGhlIGZlZSBjb2xsZWN0b3IKClJldHVybnMgYE5vdGhpbmdUb0NsYWltYCBpZiBub25lIGhhcyBhY2NydWVkLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYEZlZU1hbmFnZXJgIChvciBgQWRtaW5gKSByb2xlCiogYHRva2VuYCAtIFRoZSB0b2tlbiB0byB3aXRoZHJhdwoKIyBSZXR1cm5zCiogVGhlIGFtb3VudCB3aXRoZHJhd24AAAAADXdpdGhkcmF3X2ZlZXMAAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAADpHZXQgdGhlIHByaWNlIG9yYWNsZSBgc3RhcnRfbWl4ZWRfZ2FtZWAgdmFsdWVzIHN0YWtlcyB3aXRoAAAAAAARZ2V0X29yYWNsZV9jb25maWcAAAAAAAAAAAAAAQAAA+gAAAfQAAAADE9yYWNsZUNvbmZpZw==",
+        "AAAAAAAAAT9TZXQgdGhlIHByaWNlIG9yYWNsZSBmb3IgbWl4ZWQtYXNzZXQgZ2FtZXMKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYSBob2xkZXIgb2YgdGhlIGBGZWVNYW5hZ2VyYCAob3IgYEFkbWluYCkgcm9sZQoqIGBjb25maWdgIC0gQSBTRVAtNDAgb3JhY2xlIChzdWNoIGFzIFJlZmxlY3RvcikgcHJpY2luZyBldmVyeSB0b2tlbgpwbGF5ZXJzIG1heSBtaXgsIHdpdGggYSB0b2xlcmFuY2Ugb2YgYXQgbW9zdCAxMCwwMDAgYnBzIChlbHNlCmBJbnZhbGlkT3JhY2xlQ29uZmlnYCksIG9yIGBOb25lYCB0byBzdG9wIG1peGVkLWFzc2V0IGdhbWVzAAAAABFzZXRfb3JhY2xlX2NvbmZpZwAAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAPoAAAH0AAAAAxPcmFjbGVDb25maWcAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEZHZXQgdGhlIG1vc3QgZ2FtZXMgYSBwbGF5ZXIgbWF5IGhhdmUgaW4gcGxheSBhdCBvbmNlIChOb25lID0gbm8gbGltaXQpAAAAAAASZ2V0X21heF9vcGVuX2dhbWVzAAAAAAAAAAAAAQAAA+gAAAAE",
+        "AAAAAAAAAjNTZXQgdGhlIG1vc3QgZ2FtZXMgYSBwbGF5ZXIgbWF5IGhhdmUgaW4gcGxheSBhdCBvbmNlCgpBIGBzdGFydF8qYCBjYWxsIHRoYXQgd291bGQgcHV0IGVpdGhlciBwbGF5ZXIgb3ZlciB0aGUgbGltaXQgcmV0dXJucwpgVG9vTWFueU9wZW5HYW1lc2AsIHdoaWNoIGJvdW5kcyB0aGUgc3RvcmFnZSBhbmQgaHViIHNlc3Npb25zIG9uZQphZGRyZXNzIGNhbiBob2xkIG9wZW4uIEdhbWVzIGNvdW50IGZyb20gdGhlaXIgc3RhcnQgdW50aWwgdGhleQpzZXR0bGUgb3IgZXhwaXJlOyBnYW1lcyBhbHJlYWR5IGluIHBsYXkgYWJvdmUgYSBsb3dlcmVkIGxpbWl0IGFyZQpsZWZ0IGFsb25lLiBgTm9uZWAgbGlmdHMgdGhlIGxpbWl0LgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhIGhvbGRlciBvZiB0aGUgYE9wZXJhdG9yYCAob3IgYEFkbWluYCkgcm9sZQoqIGBsaW1pdGAgLSBNYXhpbXVtIG9wZW4gZ2FtZXMgcGVyIHBsYXllciwgZnJvbSAxIHRvCmBNQVhfT1BFTl9HQU1FU19MSU1JVGAgKGVsc2UgYEludmFsaWRHYW1lTGltaXRgKSwgb3IgYE5vbmVgIGZvciBubwpsaW1pdAAAAAASc2V0X21heF9vcGVuX2dhbWVzAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABWxpbWl0AAAAAAAD6AAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAACxXaGV0aGVyIG5ldyBnYW1lcyBhbmQgc3VibWlzc2lvbnMgYXJlIHBhdXNlZAAAAAlpc19wYXVzZWQAAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAptTdG9wIG5ldyBnYW1lcyBhbmQgc3VibWlzc2lvbnMgZHVyaW5nIGFuIGluY2lkZW50LCBzdWNoIGFzIGEgYnJva2VuCmNpcmN1aXQgb3IgdmVyaWZpZXIKCkV2ZXJ5IGBzdGFydF8qYCBlbnRyeXBvaW50IGFuZCBldmVyeSBzdWJtaXNzaW9uIChgc3VibWl0X3RhY3RpY2AgYW5kCml0cyB2YXJpYW50cywgYGNvbW1pdF90YWN0aWNgLCBgc3VibWl0X3JvdW5kc2AsIGBzdWJtaXRfdGVhbV9tb3ZlYCkKcmV0dXJucyBgUGF1c2VkYCB1bnRpbCBgdW5wYXVzZWAuIEdhbWVzIGluIHBsYXkgY2FuIHN0aWxsIGJlCnJldmVhbGVkIGFuZCBzZXR0bGVkIChgcmVzb2x2ZV9tYXRjaGAsIGByZXNvbHZlX3RpbWVvdXRgKSBzbyBzdGFrZXMKYXJlIG5ldmVyIGxvY2tlZC4gRGVhZGxpbmVzIGtlZXAgcnVubmluZyB3aGlsZSBwYXVzZWQuCgpBIGhvbGRlciBvZiB0aGUgYFBhdXNlcmAgKG9yIGBBZG1pbmApIHJvbGUgY2FuIHBhdXNlIG9uIHRoZWlyIG93bgpzaWduYXR1cmUsIGV2ZW4gdW5kZXIgYW4gYWRtaW4gY291bmNpbCwgc2luY2UgcGF1c2luZyBjYW5ub3QgbW92ZQpmdW5kcy4gQW55b25lIGVsc2UgbmVlZHMgdGhlIGFkbWluLCBvciB0aGUgY291bmNpbCdzIGFwcHJvdmFsIG9uY2UKb25lIGlzIHNldC4KCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWNjb3VudCBwYXVzaW5nAAAAAAVwYXVzZQAAAAAAAAEAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAGlBY2NlcHQgbmV3IGdhbWVzIGFuZCBzdWJtaXNzaW9ucyBhZ2FpbiBhZnRlciBgcGF1c2VgLiBOZWVkcyB0aGUKYWRtaW4gY291bmNpbCdzIGFwcHJvdmFsIG9uY2Ugb25lIGlzIHNldC4AAAAAAAAHdW5wYXVzZQAAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJJHZXQgdGhlIGNvbnRyYWN0J3MgdmVyc2lvbiwgYChtYWpvciwgbWlub3IsIHBhdGNoKWAuIFRoZSBjb21taXQgaXQKd2FzIGJ1aWx0IGZyb20gYW5kIGl0cyBjaXJjdWl0IHZlcnNpb24gYXJlIGluIHRoZSBXQVNNJ3MKYGNvbnRyYWN0bWV0YWAgZW50cmllcwAAAAAAC2dldF92ZXJzaW9uAAAAAAAAAAABAAAD7QAAAAMAAAAEAAAABAAAAAQ=",
+        "AAAAAAAAAGdHZXQgdGhlIGFkbWluLCBodWIsIHZlcmlmaWVyLCBwYXVzZSBzdGF0ZSwgbGltaXRzIGFuZCBUVEwgcG9saWN5IGluCm9uZSBjYWxsLCBmb3Igb3BlcmF0aW9ucyBkYXNoYm9hcmRzAAAAAApnZXRfY29uZmlnAAAAAAAAAAAAAQAAA+kAAAfQAAAADkNvbnRyYWN0Q29uZmlnAAAAAAAD",
+        "AAAAAAAAAGRHZXQgdGhlIHN0b3JhZ2Ugc2NoZW1hIHZlcnNpb24sIDAgZm9yIGRlcGxveW1lbnRzIGZyb20gYmVmb3JlCnZlcnNpb25pbmcgdGhhdCBoYXZlIG5vdCBydW4gYG1pZ3JhdGVgAAAAEmdldF9zY2hlbWFfdmVyc2lvbgAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAXZCcmluZyBzdG9yYWdlIHVwIHRvIGBTQ0hFTUFfVkVSU0lPTmAgYWZ0ZXIgYW4gdXBncmFkZQoKUnVucyB0aGUgc3RlcHMgYmV0d2VlbiB0aGUgc3RvcmVkIHNjaGVtYSBhbmQgdGhpcyBXQVNNJ3MsIHRoZW4KcmVjb3JkcyB0aGUgbmV3IHZlcnNpb247IGEgbm8tb3Agb25jZSB1cCB0byBkYXRlLiBSZXR1cm5zCmBTY2hlbWFUb29OZXdgIGlmIHN0b3JhZ2Ugd2FzIHdyaXR0ZW4gYnkgYSBuZXdlciBXQVNNLiBHYW1lcyBpbiBwbGF5CmFyZSB0ZW1wb3JhcnkgZW50cmllcyB0aGUgY29udHJhY3QgY2Fubm90IGVudW1lcmF0ZSwgc28gZWFjaCBvbmUKc3RvcmVkIGluIGFuIG9sZCBsYXlvdXQgaXMgcmV3cml0dGVuIGJ5IGBtaWdyYXRlX2dhbWVzYCBpbnN0ZWFkLgAAAAAAB21pZ3JhdGUAAAAAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAchSZXdyaXRlIGdhbWVzIHN0b3JlZCBpbiBhbiBvbGRlciBsYXlvdXQgKHNlZSBgbWlncmF0aW9uLnJzYCkgc28gdGhleQpjYW4gYmUgcGxheWVkIG9uIGFmdGVyIGFuIHVwZ3JhZGUKClVudGlsIHRoZW4gZXZlcnkgZW50cnlwb2ludCByZWFkaW5nIHN1Y2ggYSBnYW1lIHRyYXBzLiBBbnlvbmUgbWF5CmNhbGwgaXQsIGUuZy4gYSBrZWVwZXIgd2Fsa2luZyBgZ2V0X29wZW5fZ2FtZXNgIG9yIHRoZSBodWIncyBvcGVuCnNlc3Npb25zOyBnYW1lcyBhbHJlYWR5IGN1cnJlbnQsIG9yIG5vdCBmb3VuZCwgYXJlIHNraXBwZWQuIEtlZXAKYmF0Y2hlcyBzbWFsbCBlbm91Z2ggZm9yIG9uZSB0cmFuc2FjdGlvbidzIGZvb3RwcmludC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRzYCAtIFNlc3Npb25zIHRvIG1pZ3JhdGUKCiMgUmV0dXJucwoqIGB1MzJgIC0gSG93IG1hbnkgZ2FtZXMgd2VyZSByZXdyaXR0ZW4AAAANbWlncmF0ZV9nYW1lcwAAAAAAAAEAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAAGtHZXQgdGhlIGxlZGdlcnMgYW4gdXBncmFkZSB3YWl0cyBiZXR3ZWVuIHByb3Bvc2FsIGFuZCBleGVjdXRpb24KKGBERUZBVUxUX1VQR1JBREVfREVMQVlfTEVER0VSU2AgdW50aWwgc2V0KQAAAAARZ2V0X3VwZ3JhZGVfZGVsYXkAAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAPRTZXQgdGhlIGxlZGdlcnMgYW4gdXBncmFkZSB3YWl0cyBiZXR3ZWVuIHByb3Bvc2FsIGFuZCBleGVjdXRpb24KClVwZ3JhZGVzIGFscmVhZHkgcHJvcG9zZWQga2VlcCB0aGUgbGVkZ2VyIHRoZXkgYmVjYW1lIGV4ZWN1dGFibGUKYXQuCgojIEFyZ3VtZW50cwoqIGBsZWRnZXJzYCAtIERlbGF5IGluIGxlZGdlcnMsIGF0IGxlYXN0IGBNSU5fVVBHUkFERV9ERUxBWV9MRURHRVJTYAooZWxzZSBgSW52YWxpZFVwZ3JhZGVEZWxheWApAAAAEXNldF91cGdyYWRlX2RlbGF5AAAAAAAAAQAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAC1HZXQgdGhlIHVwZ3JhZGUgd2FpdGluZyBvdXQgaXRzIGRlbGF5LCBpZiBhbnkAAAAAAAATZ2V0X3BlbmRpbmdfdXBncmFkZQAAAAAAAAAAAQAAA+gAAAfQAAAADlBlbmRpbmdVcGdyYWRlAAA=",
+        "AAAAAAAAAiJQcm9wb3NlIHVwZ3JhZGluZyB0aGUgY29udHJhY3QgdG8gYHdhc21faGFzaGAKClRoZSB1cGdyYWRlIGNhbiBvbmx5IGJlIGV4ZWN1dGVkIHdpdGggYGV4ZWN1dGVfdXBncmFkZWAgb25jZQpgZ2V0X3VwZ3JhZGVfZGVsYXlgIGxlZGdlcnMgaGF2ZSBwYXNzZWQsIGdpdmluZyBwbGF5ZXJzIGEgd2luZG93IHRvCnNldHRsZSB0aGVpciBnYW1lcyBiZWZvcmUgdGhlIGxvZ2ljIHVuZGVyIHRoZWlyIHN0YWtlcyBjaGFuZ2VzLiBBbgpgVXBncmFkZVByb3Bvc2VkYCBldmVudCBhbm5vdW5jZXMgaXQuIFByb3Bvc2luZyBhZ2FpbiByZXBsYWNlcyB0aGUKcGVuZGluZyB1cGdyYWRlIGFuZCByZXN0YXJ0cyB0aGUgZGVsYXkuIE5lZWRzIHRoZSBhZG1pbiBjb3VuY2lsJ3MKYXBwcm92YWwgb25jZSBvbmUgaXMgc2V0OyBleGVjdXRpbmcgb3IgY2FuY2VsbGluZyBhbiBhcHByb3ZlZAp1cGdyYWRlIGlzIGxlZnQgdG8gdGhlIGFkbWluLgoKIyBBcmd1bWVudHMKKiBgd2FzbV9oYXNoYCAtIFRoZSBoYXNoIG9mIHRoZSBuZXcgV0FTTSBiaW5hcnksIGFscmVhZHkgdXBsb2FkZWQAAAAAAA9wcm9wb3NlX3VwZ3JhZGUAAAAAAQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAKxVcGdyYWRlIHRoZSBjb250cmFjdCB0byB0aGUgcGVuZGluZyBXQVNNIGhhc2ggb25jZSBpdHMgZGVsYXkgaGFzCnBhc3NlZAoKUmV0dXJucyBgTm9QZW5kaW5nVXBncmFkZWAgd2l0aG91dCBhIHByb3Bvc2FsIGFuZCBgVXBncmFkZU5vdFJlYWR5YApiZWZvcmUgaXRzIGBleGVjdXRhYmxlX2xlZGdlcmAuAAAAD2V4ZWN1dGVfdXBncmFkZQAAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAABxXaXRoZHJhdyB0aGUgcGVuZGluZyB1cGdyYWRlAAAADmNhbmNlbF91cGdyYWRlAAAAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAFhXaGV0aGVyIHRoZSBjb25zdHJ1Y3RvciBoYXMgcnVuIChvciBgbWlncmF0ZWAgaGFzLCBmb3IgZGVwbG95bWVudHMKZnJvbSBiZWZvcmUgdGhlIGZsYWcpAAAADmlzX2luaXRpYWxpemVkAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAADZHZXQgdGhlIHJlaW5pdGlhbGl6YXRpb24gd2FpdGluZyBvdXQgaXRzIGRlbGF5LCBpZiBhbnkAAAAAABJnZXRfcGVuZGluZ19yZWluaXQAAAAAAAAAAAABAAAD6AAAB9AAAAANUGVuZGluZ1JlaW5pdAAAAA==",
+        "AAAAAAAAAgNQcm9wb3NlIHJlcGxhY2luZyB0aGUgYWRtaW4gYW5kIGh1YiBzZXQgYXQgaW5pdGlhbGl6YXRpb24sIGZvciB3aGVuCmEgZGVwbG95bWVudCB3ZW50IG91dCB3aXRoIHRoZSB3cm9uZyBvbmVzCgpGb2xsb3dzIHRoZSB1cGdyYWRlIHRpbWVsb2NrOiBgcmVpbml0aWFsaXplYCBhcHBsaWVzIGl0IG9uY2UKYGdldF91cGdyYWRlX2RlbGF5YCBsZWRnZXJzIGhhdmUgcGFzc2VkLCBhbmQgYSBgUmVpbml0UHJvcG9zZWRgCmV2ZW50IGFubm91bmNlcyBpdC4gUHJvcG9zaW5nIGFnYWluIHJlcGxhY2VzIHRoZSBwZW5kaW5nIG9uZSBhbmQKcmVzdGFydHMgdGhlIGRlbGF5LiBOZWVkcyB0aGUgYWRtaW4gY291bmNpbCdzIGFwcHJvdmFsIG9uY2Ugb25lIGlzCnNldC4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIFRoZSBuZXcgYWRtaW4sIHdobyBhbHNvIHNpZ25zIGByZWluaXRpYWxpemVgCiogYGdhbWVfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzLCBjaGVja2VkIGFzIGJ5CmBzZXRfaHViYAAAAAAUcHJvcG9zZV9yZWluaXRpYWxpemUAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACGdhbWVfaHViAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAQNBcHBseSB0aGUgcGVuZGluZyByZWluaXRpYWxpemF0aW9uIG9uY2UgaXRzIGRlbGF5IGhhcyBwYXNzZWQKClNpZ25lZCBieSB0aGUgY3VycmVudCBhZG1pbiBhbmQsIGlmIGl0IGNoYW5nZXMsIHRoZSBuZXcgb25lLiBBbnkKcGVuZGluZyBhZG1pbiB0cmFuc2ZlciBpcyBkcm9wcGVkLiBSZXR1cm5zIGBOb1BlbmRpbmdSZWluaXRgIHdpdGhvdXQKYSBwcm9wb3NhbCBhbmQgYFJlaW5pdE5vdFJlYWR5YCBiZWZvcmUgaXRzIGBleGVjdXRhYmxlX2xlZGdlcmAuAAAAAAxyZWluaXRpYWxpemUAAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACVXaXRoZHJhdyB0aGUgcGVuZGluZyByZWluaXRpYWxpemF0aW9uAAAAAAAAE2NhbmNlbF9yZWluaXRpYWxpemUAAAAAAAAAAAEAAAPpAAAAAgAAAAM=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
-        get_game: this.txFromJSON<Result<Game>>,
-        get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
+    allocate_session: this.txFromJSON<Result<u32>>,
         start_game: this.txFromJSON<Result<void>>,
-        resolve_match: this.txFromJSON<Result<string>>,
-        submit_tactic: this.txFromJSON<Result<void>>
+        start_game_with_deadlines: this.txFromJSON<Result<void>>,
+        start_game_with_token: this.txFromJSON<Result<void>>,
+        start_native_game: this.txFromJSON<Result<void>>,
+        start_house_game: this.txFromJSON<Result<void>>,
+        start_mixed_game: this.txFromJSON<Result<void>>,
+        start_game_with_odds: this.txFromJSON<Result<void>>,
+        start_multi_round_game: this.txFromJSON<Result<void>>,
+        start_committed_multi_round_game: this.txFromJSON<Result<void>>,
+        start_team_game: this.txFromJSON<Result<void>>,
+        start_hidden_stake_game: this.txFromJSON<Result<void>>,
+        start_ranked_game: this.txFromJSON<Result<void>>,
+        start_bracket_game: this.txFromJSON<Result<void>>,
+        submit_tactic: this.txFromJSON<Result<void>>,
+        submit_tactic_envelope: this.txFromJSON<Result<void>>,
+        submit_tactic_signed: this.txFromJSON<Result<void>>,
+        submission_payload: this.txFromJSON<Buffer>,
+        get_player_binding: this.txFromJSON<Buffer>,
+        is_nullifier_used: this.txFromJSON<boolean>,
+        get_rating_key: this.txFromJSON<Buffer>,
+        is_identity_used: this.txFromJSON<boolean>,
+        get_nonce: this.txFromJSON<u64>,
+        commit_tactic: this.txFromJSON<Result<void>>,
+        reveal_tactic: this.txFromJSON<Result<void>>,
+        submit_rounds: this.txFromJSON<Result<void>>,
+        submit_team_move: this.txFromJSON<Result<void>>,
+        team_move_payload: this.txFromJSON<Buffer>,
+        get_teams: this.txFromJSON<Option<Teams>>,
+        reveal_rounds: this.txFromJSON<Result<void>>,
+        reveal_round: this.txFromJSON<Result<void>>,
+        get_multi_round: this.txFromJSON<Option<MultiRound>>,
+        reveal_stake: this.txFromJSON<Result<void>>,
+        get_hidden_stakes: this.txFromJSON<Option<HiddenStakes>>,
+        get_odds: this.txFromJSON<Option<Odds>>,
+        get_rating_bracket: this.txFromJSON<Option<RatingBracket>>,
+        authorize_session_key: this.txFromJSON<Result<void>>,
+        revoke_session_key: this.txFromJSON<null>,
+        get_session_key: this.txFromJSON<Option<SessionKeyGrant>>,
+        resolve_match: this.txFromJSON<Result<Outcome>>,
+        resolve_with_receipt: this.txFromJSON<Result<Outcome>>,
+        resolve_batch: this.txFromJSON<Result<Array<BatchResult>>>,
+        claim_batch: this.txFromJSON<Result<Array<BatchResult>>>,
+        resolve_timeout: this.txFromJSON<Result<string>>,
+        cancel_game: this.txFromJSON<Result<void>>,
+        retry_hub_notify: this.txFromJSON<Result<void>>,
+        abandon_expired: this.txFromJSON<Result<OpenSession>>,
+        purge_game: this.txFromJSON<Result<void>>,
+        extend_game_ttl: this.txFromJSON<Result<void>>,
+        get_global_stats: this.txFromJSON<GlobalStats>,
+        get_player_stats: this.txFromJSON<PlayerStats>,
+        get_match_count: this.txFromJSON<u32>,
+        get_match_record: this.txFromJSON<Option<MatchRecord>>,
+        get_history: this.txFromJSON<Array<GameSummary>>,
+        get_deadlines: this.txFromJSON<Result<Deadlines>>,
+        get_last_activity: this.txFromJSON<Result<u32>>,
+        get_open_games: this.txFromJSON<Array<u32>>,
+        get_open_session: this.txFromJSON<Option<OpenSession>>,
+        simulate_score: this.txFromJSON<Result<readonly [u32, u32]>>,
+        get_score_matrix: this.txFromJSON<Array<ScoreEntry>>,
+        get_game: this.txFromJSON<Result<Game>>,
+        get_game_public: this.txFromJSON<Result<PublicGame>>,
+        get_attestation: this.txFromJSON<Result<Attestation>>,
+        get_admin: this.txFromJSON<Result<string>>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<Result<void>>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        has_role: this.txFromJSON<boolean>,
+        grant_role: this.txFromJSON<Result<void>>,
+        revoke_role: this.txFromJSON<Result<void>>,
+        get_admin_council: this.txFromJSON<Option<AdminCouncil>>,
+        set_admin_council: this.txFromJSON<Result<void>>,
+        get_approvals: this.txFromJSON<Array<string>>,
+        approve_action: this.txFromJSON<Result<void>>,
+        get_hub: this.txFromJSON<Result<string>>,
+        set_hub: this.txFromJSON<Result<void>>,
+        get_verifier: this.txFromJSON<Option<string>>,
+        set_verifier: this.txFromJSON<Result<void>>,
+        get_verification_key: this.txFromJSON<Option<Buffer>>,
+        set_verification_key: this.txFromJSON<Result<void>>,
+        get_vk_source: this.txFromJSON<Option<VkSource>>,
+        set_vk_source: this.txFromJSON<Result<void>>,
+        get_proof_system: this.txFromJSON<ProofSystem>,
+        set_proof_system: this.txFromJSON<Result<void>>,
+        get_max_proof_size: this.txFromJSON<u32>,
+        set_max_proof_size: this.txFromJSON<Result<void>>,
+        get_timeout_ledgers: this.txFromJSON<u32>,
+        set_timeout_ledgers: this.txFromJSON<Result<void>>,
+        get_min_proof_version: this.txFromJSON<u32>,
+        set_min_proof_version: this.txFromJSON<Result<void>>,
+        get_ultrahonk_verifier: this.txFromJSON<Option<string>>,
+        set_ultrahonk_verifier: this.txFromJSON<Result<void>>,
+        get_stake_verifier: this.txFromJSON<Option<string>>,
+        set_stake_verifier: this.txFromJSON<Result<void>>,
+        get_rating_verifier: this.txFromJSON<Option<string>>,
+        set_rating_verifier: this.txFromJSON<Result<void>>,
+        get_sybil_gate: this.txFromJSON<Option<SybilGate>>,
+        set_sybil_gate: this.txFromJSON<Result<void>>,
+        get_simulation_image_id: this.txFromJSON<Option<Buffer>>,
+        set_simulation_image_id: this.txFromJSON<Result<void>>,
+        get_receipt_verifier: this.txFromJSON<Option<string>>,
+        set_receipt_verifier: this.txFromJSON<Result<void>>,
+        get_rounds_verifier: this.txFromJSON<Option<string>>,
+        set_rounds_verifier: this.txFromJSON<Result<void>>,
+        get_tiebreak_policy: this.txFromJSON<TiebreakPolicy>,
+        set_tiebreak_policy: this.txFromJSON<Result<void>>,
+        get_proof_freshness_window: this.txFromJSON<Option<u32>>,
+        set_proof_freshness_window: this.txFromJSON<Result<void>>,
+        get_batch_verification: this.txFromJSON<boolean>,
+        set_batch_verification: this.txFromJSON<Result<void>>,
+        get_auto_resolve: this.txFromJSON<boolean>,
+        set_auto_resolve: this.txFromJSON<Result<void>>,
+        get_wager_limits: this.txFromJSON<WagerLimits>,
+        set_wager_limits: this.txFromJSON<Result<void>>,
+        get_wager_token: this.txFromJSON<Option<string>>,
+        get_native_token: this.txFromJSON<string>,
+        set_wager_token: this.txFromJSON<Result<void>>,
+        get_escrow: this.txFromJSON<Option<Escrow>>,
+        claim_winnings: this.txFromJSON<Result<i128>>,
+        claim_refund: this.txFromJSON<Result<i128>>,
+        deposit_balance: this.txFromJSON<Result<i128>>,
+        withdraw_balance: this.txFromJSON<Result<i128>>,
+        get_balance: this.txFromJSON<i128>,
+        get_dispute_config: this.txFromJSON<Option<DisputeConfig>>,
+        set_dispute_config: this.txFromJSON<Result<void>>,
+        post_bond: this.txFromJSON<Result<i128>>,
+        withdraw_bond: this.txFromJSON<Result<i128>>,
+        get_bond: this.txFromJSON<Bond>,
+        get_dispute: this.txFromJSON<Option<Dispute>>,
+        dispute_settlement: this.txFromJSON<Result<void>>,
+        resolve_dispute: this.txFromJSON<Result<i128>>,
+        finalize_settlement: this.txFromJSON<Result<void>>,
+        get_staking_config: this.txFromJSON<Option<StakingConfig>>,
+        set_staking_config: this.txFromJSON<Result<void>>,
+        stake: this.txFromJSON<Result<Stake>>,
+        unstake: this.txFromJSON<Result<Stake>>,
+        withdraw_stake: this.txFromJSON<Result<i128>>,
+        get_stake: this.txFromJSON<Option<Stake>>,
+        get_stake_tier: this.txFromJSON<Option<StakeTier>>,
+        deposit_bankroll: this.txFromJSON<Result<i128>>,
+        withdraw_bankroll: this.txFromJSON<Result<i128>>,
+        set_house_beacon: this.txFromJSON<Result<void>>,
+        get_house_beacon: this.txFromJSON<Option<string>>,
+        get_house_round: this.txFromJSON<Option<readonly [string, u64]>>,
+        get_bankroll: this.txFromJSON<Bankroll>,
+        get_bankroll_shares: this.txFromJSON<i128>,
+        place_side_bet: this.txFromJSON<Result<void>>,
+        claim_side_bet: this.txFromJSON<Result<i128>>,
+        get_side_bet_pool: this.txFromJSON<Option<SideBetPool>>,
+        get_side_bet: this.txFromJSON<Option<SideBet>>,
+        get_side_bet_rake_bps: this.txFromJSON<u32>,
+        set_side_bet_rake_bps: this.txFromJSON<Result<void>>,
+        register_referrer: this.txFromJSON<Result<void>>,
+        claim_referral_rewards: this.txFromJSON<Result<i128>>,
+        get_referrer: this.txFromJSON<Option<string>>,
+        get_referral_stats: this.txFromJSON<ReferralStats>,
+        get_referral_rewards: this.txFromJSON<ReferralRewards>,
+        get_referral_share_bps: this.txFromJSON<u32>,
+        set_referral_share_bps: this.txFromJSON<Result<void>>,
+        get_hub_share_bps: this.txFromJSON<u32>,
+        set_hub_share_bps: this.txFromJSON<Result<void>>,
+        get_streak: this.txFromJSON<Streak>,
+        get_streak_bonus: this.txFromJSON<Option<StreakBonus>>,
+        set_streak_bonus: this.txFromJSON<Result<void>>,
+        get_epoch_config: this.txFromJSON<Option<EpochConfig>>,
+        set_epoch_config: this.txFromJSON<Result<void>>,
+        get_current_epoch: this.txFromJSON<Option<u32>>,
+        get_epoch: this.txFromJSON<Option<EpochRewards>>,
+        get_epoch_wins: this.txFromJSON<u32>,
+        claim_epoch_reward: this.txFromJSON<Result<i128>>,
+        claim_epoch_reward_with_proof: this.txFromJSON<Result<i128>>,
+        set_epoch_root: this.txFromJSON<Result<void>>,
+        get_jackpot: this.txFromJSON<i128>,
+        get_jackpot_run: this.txFromJSON<u32>,
+        get_jackpot_config: this.txFromJSON<Option<JackpotConfig>>,
+        set_jackpot_config: this.txFromJSON<Result<void>>,
+        get_fee_bps: this.txFromJSON<u32>,
+        set_fee_bps: this.txFromJSON<Result<void>>,
+        get_fee_collector: this.txFromJSON<Result<string>>,
+        set_fee_collector: this.txFromJSON<Result<void>>,
+        get_accrued_fees: this.txFromJSON<i128>,
+        withdraw_fees: this.txFromJSON<Result<i128>>,
+        get_oracle_config: this.txFromJSON<Option<OracleConfig>>,
+        set_oracle_config: this.txFromJSON<Result<void>>,
+        get_max_open_games: this.txFromJSON<Option<u32>>,
+        set_max_open_games: this.txFromJSON<Result<void>>,
+        is_paused: this.txFromJSON<boolean>,
+        pause: this.txFromJSON<Result<void>>,
+        unpause: this.txFromJSON<Result<void>>,
+        get_version: this.txFromJSON<readonly [u32, u32, u32]>,
+        get_config: this.txFromJSON<Result<ContractConfig>>,
+        get_schema_version: this.txFromJSON<u32>,
+        migrate: this.txFromJSON<Result<void>>,
+        migrate_games: this.txFromJSON<u32>,
+        get_upgrade_delay: this.txFromJSON<u32>,
+        set_upgrade_delay: this.txFromJSON<Result<void>>,
+        get_pending_upgrade: this.txFromJSON<Option<PendingUpgrade>>,
+        propose_upgrade: this.txFromJSON<Result<void>>,
+        execute_upgrade: this.txFromJSON<Result<void>>,
+        cancel_upgrade: this.txFromJSON<Result<void>>,
+        is_initialized: this.txFromJSON<boolean>,
+        get_pending_reinit: this.txFromJSON<Option<PendingReinit>>,
+        propose_reinitialize: this.txFromJSON<Result<void>>,
+        reinitialize: this.txFromJSON<Result<void>>,
+        cancel_reinitialize: this.txFromJSON<Result<void>>
   }
 }
\ No newline at end of file
@@ -55,19 +55,53 @@ async function initializeCircuit(): Promise<{ noir: Noir; backend: BarretenbergB
   }
 }
 
+/**
+ * Proof plus the Poseidon2 commitment and nullifier and the sha256 tactic
+ * hash it attests to
+ */
+export interface TacticProof {
+  proof: Uint8Array;
+  commitment: Uint8Array;
+  nullifier: Uint8Array;
+  tacticHash: Uint8Array;
+}
+
+/**
+ * Convert a hex field element (as returned in public inputs) to 32 bytes
+ */
+function fieldToBytes(field: string): Uint8Array {
+  const hex = field.replace(/^0x/, '').padStart(64, '0');
+  const bytes = new Uint8Array(32);
+  for (let i = 0; i < 32; i++) {
+    bytes[i] = parseInt(hex.slice(i * 2, i * 2 + 2), 16);
+  }
+  return bytes;
+}
+
+/**
+ * Convert 32 bytes to a hex field element (the inverse of fieldToBytes)
+ */
+function bytesToField(bytes: Uint8Array): string {
+  return '0x' + Array.from(bytes, (b) => b.toString(16).padStart(2, '0')).join('');
+}
+
 /**
  * Generate a ZK proof for a tactical choice
  *
  * @param tactic - The tactical choice (0=Defensive, 1=Balanced, 2=Aggressive, 3=AllOut)
  * @param playerSecret - A secret number chosen by the player (for commitment)
  * @param sessionId - The game session ID
- * @returns Proof as Uint8Array
+ * @param playerBinding - The contract's `get_player_binding` value for the submitting player
+ * @param validUntilLedger - Last ledger the contract may accept the proof in (0 = no expiry)
+ * @returns Proof bytes, the commitment, the nullifier and the tactic hash (the circuit's return values)
  */
 export async function generateTacticProof(
   tactic: number,
   playerSecret: bigint,
-  sessionId: number
-): Promise<Uint8Array> {
+  sessionId: number,
+  playerBinding: Uint8Array,
+  validUntilLedger: number
+): Promise<TacticProof> {
   console.log('[zkProofService] Generating proof for tactic:', tactic, 'session:', sessionId);
 
   // Validate inputs
@@ -87,6 +121,8 @@ export async function generateTacticProof(
       tactic,
       player_secret: playerSecret.toString(),
       session_id: sessionId,
+      player_binding: bytesToField(playerBinding),
+      valid_until_ledger: validUntilLedger,
     });
 
     // Execute the circuit to generate witness
@@ -94,6 +130,8 @@ export async function generateTacticProof(
       tactic,
       player_secret: playerSecret.toString(),
       session_id: sessionId,
+      player_binding: bytesToField(playerBinding),
+      valid_until_ledger: validUntilLedger,
     });
 
     console.log('[zkProofService] Witness generated, creating proof...');
@@ -103,7 +141,16 @@ export async function generateTacticProof(
 
     console.log('[zkProofService] ✅ Proof generated successfully (length:', proof.proof.length, 'bytes)');
 
-    return proof.proof;
+    // Public inputs are [session_id, player_binding, valid_until_ledger,
+    // commitment, nullifier, tactic_hash]; the contract stores the commitment,
+    // rejects any reuse of the nullifier and checks the reveal against the
+    // tactic hash
+    const inputs = proof.publicInputs;
+    const commitment = fieldToBytes(inputs[inputs.length - 3]);
+    const nullifier = fieldToBytes(inputs[inputs.length - 2]);
+    const tacticHash = fieldToBytes(inputs[inputs.length - 1]);
+
+    return { proof: proof.proof, commitment, nullifier, tacticHash };
   } catch (error) {
     console.error('[zkProofService] Error generating proof:', error);
     throw new Error(`Failed to generate ZK proof: ${error instanceof Error ? error.message : 'Unknown error'}`);
@@ -114,13 +161,18 @@ export async function generateTacticProof(
  * Verify a ZK proof (optional, mainly for testing)
  *
  * @param proof - The proof to verify
- * @param publicInputs - The public inputs (session_id, commitment)
+ * @param publicInputs - The public inputs (session_id, player_binding, valid_until_ledger, commitment, nullifier, tactic_hash)
  * @returns True if proof is valid
  */
 export async function verifyTacticProof(
   proof: Uint8Array,
   publicInputs: {
     session_id: number;
+    player_binding: Uint8Array;
+    valid_until_ledger: number;
+    commitment: Uint8Array;
+    nullifier: Uint8Array;
+    tactic_hash: Uint8Array;
   }
 ): Promise<boolean> {
   console.log('[zkProofService] Verifying proof...');
@@ -130,7 +182,14 @@ export async function verifyTacticProof(
 
     const isValid = await backend.verifyProof({
       proof,
-      publicInputs: [publicInputs.session_id.toString()],
+      publicInputs: [
+        publicInputs.session_id.toString(),
+        bytesToField(publicInputs.player_binding),
+        publicInputs.valid_until_ledger.toString(),
+        bytesToField(publicInputs.commitment),
+        bytesToField(publicInputs.nullifier),
+        bytesToField(publicInputs.tactic_hash),
+      ],
     });
 
     console.log('[zkProofService] Proof verification result:', isValid);
@@ -148,9 +207,11 @@ export async function verifyTacticProof(
  * @returns A random bigint to use as player_secret
  */
 export function generatePlayerSecret(): bigint {
-  // Generate a random 32-byte value
+  // Generate a random 32-byte value, top byte cleared so it is a BN254
+  // field element (the circuit and `reveal_tactic` must see the same bytes)
   const randomBytes = new Uint8Array(32);
   crypto.getRandomValues(randomBytes);
+  randomBytes[0] = 0;
 
   // Convert to bigint
   let secret = 0n;
@@ -160,3 +221,30 @@ export function generatePlayerSecret(): bigint {
 
   return secret;
 }
+
+/**
+ * Encode a player secret as the 32-byte big-endian value `reveal_tactic` takes
+ */
+export function secretToBytes(secret: bigint): Uint8Array {
+  return fieldToBytes(secret.toString(16));
+}
+
+/**
+ * The tactic hash the circuit outputs and `reveal_tactic` checks:
+ * sha256(tactic u32 BE || secret 32 bytes || session_id u32 BE), top byte cleared
+ */
+export async function computeTacticHash(
+  tactic: number,
+  secret: Uint8Array,
+  sessionId: number
+): Promise<Uint8Array> {
+  const preimage = new Uint8Array(40);
+  const view = new DataView(preimage.buffer);
+  view.setUint32(0, tactic);
+  preimage.set(secret, 4);
+  view.setUint32(36, sessionId);
+
+  const hash = new Uint8Array(await crypto.subtle.digest('SHA-256', preimage));
+  hash[0] = 0;
+  return hash;
+}
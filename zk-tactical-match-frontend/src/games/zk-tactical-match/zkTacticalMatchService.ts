@@ -1,4 +1,4 @@
-import { Client as ZkTacticalMatchClient, type Game } from './bindings';
+import { Client as ZkTacticalMatchClient, type Game, type PublicGame } from './bindings';
 import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, Address, authorizeEntry, xdr } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
@@ -36,6 +36,9 @@ export class ZkTacticalMatchService {
     return new ZkTacticalMatchClient(options);
   }
 
+  /**
+   * Full state of a settled game; null while it is still in play
+   */
   async getGame(sessionId: number): Promise<Game | null> {
     try {
       const tx = await this.baseClient.get_game({ session_id: sessionId });
@@ -50,6 +53,63 @@ export class ZkTacticalMatchService {
     }
   }
 
+  /**
+   * A game in any phase, with tactics and proof hashes withheld until it is
+   * settled and flags for who has revealed
+   */
+  async getGamePublic(sessionId: number): Promise<PublicGame | null> {
+    try {
+      const tx = await this.baseClient.get_game_public({ session_id: sessionId });
+      const result = await tx.simulate();
+      if (result.result.isOk()) {
+        return result.result.unwrap();
+      }
+      return null;
+    } catch (err) {
+      console.log('[getGamePublic] Error:', err);
+      return null;
+    }
+  }
+
+  /**
+   * The value a player's proof must carry as its `player_binding` public input
+   */
+  async getPlayerBinding(playerAddress: string): Promise<Uint8Array> {
+    const tx = await this.baseClient.get_player_binding({ player: playerAddress });
+    const result = await tx.simulate();
+    return new Uint8Array(result.result);
+  }
+
+  /**
+   * The `valid_until_ledger` to prove with now: the latest expiry the
+   * contract's freshness window allows, or 0 (no expiry) when none is set
+   */
+  async getProofValidUntil(): Promise<number> {
+    const tx = await this.baseClient.get_proof_freshness_window();
+    const result = await tx.simulate();
+    const window = result.result;
+    if (window === undefined) {
+      return 0;
+    }
+    return (await calculateValidUntilLedger(RPC_URL, 0)) + window;
+  }
+
+  /**
+   * Allocate a session ID at the Game Hub, unique across every game. The hub
+   * opens sessions only under IDs it allocated to this game
+   */
+  async allocateSession(
+    callerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ): Promise<number> {
+    const client = this.createSigningClient(callerAddress, signer);
+    const tx = await client.allocate_session(DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+    return sentTx.result.unwrap();
+  }
+
   async startGame(
     sessionId: number,
     player1Address: string,
@@ -76,7 +136,11 @@ export class ZkTacticalMatchService {
   async submitTactic(
     sessionId: number,
     playerAddress: string,
-    tactic: number,
+    playerBinding: Uint8Array,
+    validUntilLedger: number,
+    commitment: Uint8Array,
+    nullifier: Uint8Array,
+    tacticHash: Uint8Array,
     proof: Uint8Array,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
   ) {
@@ -85,7 +149,14 @@ export class ZkTacticalMatchService {
     const tx = await client.submit_tactic({
       session_id: sessionId,
       player: playerAddress,
-      tactic,
+      inputs: {
+        session_id: sessionId,
+        player_binding: Buffer.from(playerBinding),
+        valid_until_ledger: validUntilLedger,
+        commitment: Buffer.from(commitment),
+        nullifier: Buffer.from(nullifier),
+        tactic_hash: Buffer.from(tacticHash),
+      },
       proof: Buffer.from(proof),
     }, DEFAULT_METHOD_OPTIONS);
 
@@ -94,6 +165,53 @@ export class ZkTacticalMatchService {
     return sentTx.result;
   }
 
+  /**
+   * Commit to a tactic hash without a proof, for `CommitReveal` games
+   * (see `computeTacticHash`)
+   */
+  async commitTactic(
+    sessionId: number,
+    playerAddress: string,
+    tacticHash: Uint8Array,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.commit_tactic({
+      session_id: sessionId,
+      player: playerAddress,
+      hash: Buffer.from(tacticHash),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+    return sentTx.result;
+  }
+
+  /**
+   * Open a submitted tactic. Only accepted once both players have submitted;
+   * `secret` is the 32-byte secret the proof was generated with, or the salt
+   * of a `commitTactic` commitment
+   */
+  async revealTactic(
+    sessionId: number,
+    playerAddress: string,
+    tactic: number,
+    secret: Uint8Array,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_tactic({
+      session_id: sessionId,
+      player: playerAddress,
+      tactic,
+      secret: Buffer.from(secret),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+    return sentTx.result;
+  }
+
   async resolveMatch(
     sessionId: number,
     callerAddress: string,
@@ -251,6 +369,15 @@ export class ZkTacticalMatchService {
       throw new Error('Auth entry session ID mismatch');
     }
 
+    // Player 2 signed the whole match, which must be the one we start
+    if (
+      parsedAuthEntry.player1 !== player1Address ||
+      parsedAuthEntry.player1Points !== player1Points ||
+      parsedAuthEntry.player2Points !== player2Points
+    ) {
+      throw new Error('Auth entry terms mismatch');
+    }
+
     console.log('[importAndFinalizeAsPlayer1] Rebuilding transaction with Player 1 as source');
 
     // Rebuild transaction with Player 1 as source (we are Player 1)
@@ -360,6 +487,9 @@ export class ZkTacticalMatchService {
     if (player2Address === gameParams.playerAddress) {
       throw new Error('Cannot play against yourself');
     }
+    if (gameParams.player2 !== player2Address || gameParams.player2Points !== player2Points) {
+      throw new Error('Player 1 signed a match with other terms');
+    }
 
     const buildClient = new ZkTacticalMatchClient({
       contractId: this.contractId,
@@ -417,15 +547,21 @@ export class ZkTacticalMatchService {
   /**
    * Parse auth entry to extract game parameters (generic for any player)
    *
-   * Auth entries from require_auth_for_args contain:
+   * Both players sign the full match terms, so auth entries contain:
    * - Player address (from credentials)
    * - Session ID (arg 0)
-   * - Player's points (arg 1)
+   * - Player 1 and Player 2 addresses (args 1 and 2)
+   * - Player 1's and Player 2's points (args 3 and 4)
+   * - The contract's wager token, if any (arg 5)
    */
   private parseAuthEntry(authEntryXdr: string): {
     sessionId: number;
     playerAddress: string;
     playerPoints: bigint;
+    player1: string;
+    player2: string;
+    player1Points: bigint;
+    player2Points: bigint;
     functionName: string;
   } {
     try {
@@ -452,15 +588,23 @@ export class ZkTacticalMatchService {
       // Extract arguments
       // For start_game with require_auth_for_args:
       // 0: session_id (u32)
-      // 1: player_points (i128)
+      // 1: player1 (Address)
+      // 2: player2 (Address)
+      // 3: player1_points (i128)
+      // 4: player2_points (i128)
+      // 5: wager token (Option<Address>)
       const args = contractFn.args();
 
-      if (args.length !== 2) {
-        throw new Error(`Expected 2 arguments for start_game auth entry, got ${args.length}`);
+      if (args.length !== 6) {
+        throw new Error(`Expected 6 arguments for start_game auth entry, got ${args.length}`);
       }
 
       const sessionId = args[0].u32();
-      const playerPoints = args[1].i128().lo().toBigInt();
+      const player1 = Address.fromScAddress(args[1].address()).toString();
+      const player2 = Address.fromScAddress(args[2].address()).toString();
+      const player1Points = args[3].i128().lo().toBigInt();
+      const player2Points = args[4].i128().lo().toBigInt();
+      const playerPoints = playerAddress === player1 ? player1Points : player2Points;
 
       console.log('[parseAuthEntry] Parsed:', {
         sessionId,
@@ -473,6 +617,10 @@ export class ZkTacticalMatchService {
         sessionId,
         playerAddress,
         playerPoints,
+        player1,
+        player2,
+        player1Points,
+        player2Points,
         functionName,
       };
     } catch (err: any) {